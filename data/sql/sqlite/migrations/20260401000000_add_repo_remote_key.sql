@@ -0,0 +1,9 @@
+-- Repo Remote Key Migration
+-- Adds a normalized remote key so equivalent remote spellings
+-- (github:user/repo, git@github.com:user/repo.git, https://github.com/user/repo)
+-- resolve to the same repo. Existing rows are backfilled by the application
+-- after migrations run, since normalization lives in Rust.
+
+ALTER TABLE repo ADD COLUMN remote_key TEXT;
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_repo_remote_key ON repo(remote_key);
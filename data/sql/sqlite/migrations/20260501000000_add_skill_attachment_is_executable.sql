@@ -0,0 +1,6 @@
+-- Skill Attachment Executable Bit Migration
+-- Records whether an attachment was executable when ingested so the mode
+-- survives sync and is restored when attachments are extracted to the cache.
+-- Existing rows default to 0; extraction still marks .sh/.bash executable.
+
+ALTER TABLE skill_attachment ADD COLUMN is_executable INTEGER NOT NULL DEFAULT 0;
@@ -0,0 +1,15 @@
+-- Soft Delete Migration
+-- Deleting a project, repo, task list, task or note stamps deleted_at instead
+-- of removing the row. Repositories hide stamped rows; purge removes them.
+
+ALTER TABLE project ADD COLUMN deleted_at TEXT;
+ALTER TABLE repo ADD COLUMN deleted_at TEXT;
+ALTER TABLE task_list ADD COLUMN deleted_at TEXT;
+ALTER TABLE task ADD COLUMN deleted_at TEXT;
+ALTER TABLE note ADD COLUMN deleted_at TEXT;
+
+CREATE INDEX IF NOT EXISTS idx_project_deleted_at ON project(deleted_at);
+CREATE INDEX IF NOT EXISTS idx_repo_deleted_at ON repo(deleted_at);
+CREATE INDEX IF NOT EXISTS idx_task_list_deleted_at ON task_list(deleted_at);
+CREATE INDEX IF NOT EXISTS idx_task_deleted_at ON task(deleted_at);
+CREATE INDEX IF NOT EXISTS idx_note_deleted_at ON note(deleted_at);
@@ -0,0 +1,9 @@
+-- Note Content Hash Migration
+-- Stores a SHA-256 of each note's content so creating a note with
+-- `?dedup=true` can find an identical one without comparing bodies.
+-- Existing rows are backfilled by the application after migrations run,
+-- since hashing lives in Rust.
+
+ALTER TABLE note ADD COLUMN content_hash TEXT;
+
+CREATE INDEX IF NOT EXISTS idx_note_content_hash ON note(content_hash);
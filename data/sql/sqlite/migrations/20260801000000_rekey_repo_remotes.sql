@@ -0,0 +1,7 @@
+-- Repo Remote Rekey Migration
+-- Remote keys no longer lowercase the path part of a remote, so
+-- case-sensitive paths (/home/me/Proj vs /home/me/proj) stay distinct.
+-- Clearing the keys makes the application backfill recompute every key
+-- with the current normalization after migrations run.
+
+UPDATE repo SET remote_key = NULL;
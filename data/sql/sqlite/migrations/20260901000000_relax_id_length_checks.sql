@@ -0,0 +1,410 @@
+-- no-transaction
+-- Relax ID Length Checks Migration
+-- IDs can be configured to 8, 12 or 16 characters, but every ID and
+-- foreign key column was created with CHECK(length(...) == 8). SQLite can't
+-- alter a CHECK constraint, so each table is rebuilt with the check relaxed
+-- to BETWEEN 8 AND 16 and its data, indexes and triggers carried over.
+--
+-- Foreign keys are switched off while tables are dropped and renamed so the
+-- drops don't cascade into child tables; PRAGMA foreign_keys has no effect
+-- inside a transaction, hence the explicit one below.
+
+PRAGMA foreign_keys = OFF;
+
+BEGIN;
+
+-- ========== repo ==========
+CREATE TABLE repo_new (
+    id TEXT PRIMARY KEY CHECK(length(id) BETWEEN 8 AND 16),
+    remote TEXT UNIQUE NOT NULL,        -- e.g. "github:ck3mp3r/nu-mcp"
+    path TEXT,                          -- local absolute path (last known)
+    tags TEXT DEFAULT '[]',             -- JSON array
+    created_at TEXT DEFAULT (datetime('now')),
+    remote_key TEXT,
+    deleted_at TEXT
+);
+INSERT INTO repo_new (id, remote, path, tags, created_at, remote_key, deleted_at)
+SELECT id, remote, path, tags, created_at, remote_key, deleted_at FROM repo;
+DROP TABLE repo;
+ALTER TABLE repo_new RENAME TO repo;
+
+-- ========== project ==========
+CREATE TABLE project_new (
+    id TEXT PRIMARY KEY CHECK(length(id) BETWEEN 8 AND 16),
+    title TEXT NOT NULL,
+    description TEXT,
+    tags TEXT DEFAULT '[]',             -- JSON array
+    created_at TEXT DEFAULT (datetime('now')),
+    updated_at TEXT DEFAULT (datetime('now')),
+    external_refs TEXT NOT NULL DEFAULT '[]',
+    deleted_at TEXT
+);
+INSERT INTO project_new (id, title, description, tags, created_at, updated_at, external_refs, deleted_at)
+SELECT id, title, description, tags, created_at, updated_at, external_refs, deleted_at FROM project;
+DROP TABLE project;
+ALTER TABLE project_new RENAME TO project;
+
+-- ========== task_list ==========
+CREATE TABLE task_list_new (
+    id TEXT PRIMARY KEY CHECK(length(id) BETWEEN 8 AND 16),
+    title TEXT NOT NULL,
+    description TEXT,
+    notes TEXT,
+    tags TEXT DEFAULT '[]',             -- JSON array
+    status TEXT DEFAULT 'active' CHECK(status IN ('active', 'archived')),
+    project_id TEXT NOT NULL CHECK(length(project_id) BETWEEN 8 AND 16),
+    created_at TEXT DEFAULT (datetime('now')),
+    updated_at TEXT DEFAULT (datetime('now')),
+    archived_at TEXT,
+    external_refs TEXT NOT NULL DEFAULT '[]',
+    deleted_at TEXT,
+    FOREIGN KEY (project_id) REFERENCES project(id) ON DELETE CASCADE
+);
+INSERT INTO task_list_new (id, title, description, notes, tags, status, project_id, created_at, updated_at, archived_at, external_refs, deleted_at)
+SELECT id, title, description, notes, tags, status, project_id, created_at, updated_at, archived_at, external_refs, deleted_at FROM task_list;
+DROP TABLE task_list;
+ALTER TABLE task_list_new RENAME TO task_list;
+
+-- ========== task ==========
+CREATE TABLE task_new (
+    id TEXT PRIMARY KEY CHECK(length(id) BETWEEN 8 AND 16),
+    list_id TEXT NOT NULL CHECK(length(list_id) BETWEEN 8 AND 16),
+    parent_id TEXT CHECK(parent_id IS NULL OR length(parent_id) BETWEEN 8 AND 16),  -- NULL = root task
+    title TEXT NOT NULL CHECK(length(title) <= 500),
+    description TEXT CHECK(description IS NULL OR length(description) <= 10000),
+    status TEXT NOT NULL CHECK(status IN ('backlog', 'todo', 'in_progress', 'review', 'done', 'cancelled')),
+    priority INTEGER CHECK(priority BETWEEN 1 AND 5),
+    tags TEXT NOT NULL DEFAULT '[]' CHECK(json_valid(tags)),   -- JSON array
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    external_refs TEXT NOT NULL DEFAULT '[]',
+    deleted_at TEXT,
+    FOREIGN KEY (list_id) REFERENCES task_list(id) ON DELETE CASCADE,
+    FOREIGN KEY (parent_id) REFERENCES task(id) ON DELETE CASCADE
+);
+INSERT INTO task_new (id, list_id, parent_id, title, description, status, priority, tags, created_at, updated_at, external_refs, deleted_at)
+SELECT id, list_id, parent_id, title, description, status, priority, tags, created_at, updated_at, external_refs, deleted_at FROM task;
+DROP TABLE task;
+ALTER TABLE task_new RENAME TO task;
+
+-- ========== note ==========
+-- note_fts is an external-content index keyed on note's rowid, so rowids are
+-- copied across unchanged.
+CREATE TABLE note_new (
+    id TEXT PRIMARY KEY CHECK(length(id) BETWEEN 8 AND 16),
+    title TEXT NOT NULL,
+    content TEXT NOT NULL,
+    tags TEXT DEFAULT '[]',             -- JSON array
+    created_at TEXT DEFAULT (datetime('now')),
+    updated_at TEXT DEFAULT (datetime('now')),
+    parent_id TEXT CHECK(parent_id IS NULL OR length(parent_id) BETWEEN 8 AND 16),
+    idx INTEGER,
+    deleted_at TEXT,
+    content_hash TEXT
+);
+INSERT INTO note_new (rowid, id, title, content, tags, created_at, updated_at, parent_id, idx, deleted_at, content_hash)
+SELECT rowid, id, title, content, tags, created_at, updated_at, parent_id, idx, deleted_at, content_hash FROM note;
+DROP TABLE note;
+ALTER TABLE note_new RENAME TO note;
+
+-- ========== skill ==========
+CREATE TABLE skill_new (
+    id TEXT PRIMARY KEY CHECK(length(id) BETWEEN 8 AND 16),
+    name TEXT NOT NULL,
+    description TEXT NOT NULL,
+    content TEXT NOT NULL,          -- Full SKILL.md (frontmatter + body)
+    tags TEXT NOT NULL DEFAULT '[]',
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL
+);
+INSERT INTO skill_new (id, name, description, content, tags, created_at, updated_at)
+SELECT id, name, description, content, tags, created_at, updated_at FROM skill;
+DROP TABLE skill;
+ALTER TABLE skill_new RENAME TO skill;
+
+-- ========== skill_attachment ==========
+CREATE TABLE skill_attachment_new (
+    id TEXT PRIMARY KEY CHECK(length(id) BETWEEN 8 AND 16),
+    skill_id TEXT NOT NULL CHECK(length(skill_id) BETWEEN 8 AND 16),
+    type TEXT NOT NULL CHECK(type IN ('script', 'reference', 'asset')),
+    filename TEXT NOT NULL,
+    content TEXT NOT NULL,              -- Base64-encoded file content
+    content_hash TEXT NOT NULL,         -- SHA256 hash for cache invalidation
+    mime_type TEXT,                     -- MIME type (e.g., "text/x-shellscript", "image/png")
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    is_executable INTEGER NOT NULL DEFAULT 0,
+    FOREIGN KEY (skill_id) REFERENCES skill(id) ON DELETE CASCADE,
+    UNIQUE(skill_id, type, filename)    -- One file per type per skill
+);
+INSERT INTO skill_attachment_new (id, skill_id, type, filename, content, content_hash, mime_type, created_at, updated_at, is_executable)
+SELECT id, skill_id, type, filename, content, content_hash, mime_type, created_at, updated_at, is_executable FROM skill_attachment;
+DROP TABLE skill_attachment;
+ALTER TABLE skill_attachment_new RENAME TO skill_attachment;
+
+-- ========== task_transition_log ==========
+CREATE TABLE task_transition_log_new (
+    id TEXT PRIMARY KEY CHECK(length(id) BETWEEN 8 AND 16),
+    task_id TEXT NOT NULL CHECK(length(task_id) BETWEEN 8 AND 16),
+    status TEXT NOT NULL CHECK(status IN ('backlog', 'todo', 'in_progress', 'review', 'done', 'cancelled')),
+    transitioned_at TEXT NOT NULL DEFAULT (datetime('now')),
+    FOREIGN KEY (task_id) REFERENCES task(id) ON DELETE CASCADE
+);
+INSERT INTO task_transition_log_new (id, task_id, status, transitioned_at)
+SELECT id, task_id, status, transitioned_at FROM task_transition_log;
+DROP TABLE task_transition_log;
+ALTER TABLE task_transition_log_new RENAME TO task_transition_log;
+
+-- ========== Join tables ==========
+CREATE TABLE project_repo_new (
+    project_id TEXT NOT NULL CHECK(length(project_id) BETWEEN 8 AND 16),
+    repo_id TEXT NOT NULL CHECK(length(repo_id) BETWEEN 8 AND 16),
+    created_at TEXT DEFAULT (datetime('now')),
+    PRIMARY KEY (project_id, repo_id),
+    FOREIGN KEY (project_id) REFERENCES project(id) ON DELETE CASCADE,
+    FOREIGN KEY (repo_id) REFERENCES repo(id) ON DELETE CASCADE
+);
+INSERT INTO project_repo_new (project_id, repo_id, created_at)
+SELECT project_id, repo_id, created_at FROM project_repo;
+DROP TABLE project_repo;
+ALTER TABLE project_repo_new RENAME TO project_repo;
+
+CREATE TABLE project_note_new (
+    project_id TEXT NOT NULL CHECK(length(project_id) BETWEEN 8 AND 16),
+    note_id TEXT NOT NULL CHECK(length(note_id) BETWEEN 8 AND 16),
+    created_at TEXT DEFAULT (datetime('now')),
+    PRIMARY KEY (project_id, note_id),
+    FOREIGN KEY (project_id) REFERENCES project(id) ON DELETE CASCADE,
+    FOREIGN KEY (note_id) REFERENCES note(id) ON DELETE CASCADE
+);
+INSERT INTO project_note_new (project_id, note_id, created_at)
+SELECT project_id, note_id, created_at FROM project_note;
+DROP TABLE project_note;
+ALTER TABLE project_note_new RENAME TO project_note;
+
+CREATE TABLE task_list_repo_new (
+    task_list_id TEXT NOT NULL CHECK(length(task_list_id) BETWEEN 8 AND 16),
+    repo_id TEXT NOT NULL CHECK(length(repo_id) BETWEEN 8 AND 16),
+    created_at TEXT DEFAULT (datetime('now')),
+    PRIMARY KEY (task_list_id, repo_id),
+    FOREIGN KEY (task_list_id) REFERENCES task_list(id) ON DELETE CASCADE,
+    FOREIGN KEY (repo_id) REFERENCES repo(id) ON DELETE CASCADE
+);
+INSERT INTO task_list_repo_new (task_list_id, repo_id, created_at)
+SELECT task_list_id, repo_id, created_at FROM task_list_repo;
+DROP TABLE task_list_repo;
+ALTER TABLE task_list_repo_new RENAME TO task_list_repo;
+
+CREATE TABLE note_repo_new (
+    note_id TEXT NOT NULL CHECK(length(note_id) BETWEEN 8 AND 16),
+    repo_id TEXT NOT NULL CHECK(length(repo_id) BETWEEN 8 AND 16),
+    created_at TEXT DEFAULT (datetime('now')),
+    PRIMARY KEY (note_id, repo_id),
+    FOREIGN KEY (note_id) REFERENCES note(id) ON DELETE CASCADE,
+    FOREIGN KEY (repo_id) REFERENCES repo(id) ON DELETE CASCADE
+);
+INSERT INTO note_repo_new (note_id, repo_id, created_at)
+SELECT note_id, repo_id, created_at FROM note_repo;
+DROP TABLE note_repo;
+ALTER TABLE note_repo_new RENAME TO note_repo;
+
+CREATE TABLE project_skill_new (
+    project_id TEXT NOT NULL CHECK(length(project_id) BETWEEN 8 AND 16),
+    skill_id TEXT NOT NULL CHECK(length(skill_id) BETWEEN 8 AND 16),
+    PRIMARY KEY (project_id, skill_id),
+    FOREIGN KEY (project_id) REFERENCES project(id) ON DELETE CASCADE,
+    FOREIGN KEY (skill_id) REFERENCES skill(id) ON DELETE CASCADE
+);
+INSERT INTO project_skill_new (project_id, skill_id)
+SELECT project_id, skill_id FROM project_skill;
+DROP TABLE project_skill;
+ALTER TABLE project_skill_new RENAME TO project_skill;
+
+-- ========== Indexes ==========
+CREATE INDEX idx_repo_remote ON repo(remote);
+CREATE UNIQUE INDEX idx_repo_remote_key ON repo(remote_key);
+CREATE INDEX idx_repo_deleted_at ON repo(deleted_at);
+
+CREATE INDEX idx_project_deleted_at ON project(deleted_at);
+
+CREATE INDEX idx_task_list_status ON task_list(status);
+CREATE INDEX idx_task_list_project ON task_list(project_id);
+CREATE INDEX idx_task_list_deleted_at ON task_list(deleted_at);
+
+CREATE INDEX idx_task_list_id ON task(list_id);
+CREATE INDEX idx_task_status ON task(status);
+CREATE INDEX idx_task_priority ON task(priority);
+CREATE INDEX idx_task_list_priority ON task(list_id, priority);
+CREATE INDEX idx_task_parent_id ON task(parent_id);
+CREATE INDEX idx_task_created_at ON task(created_at);
+CREATE INDEX idx_task_updated_at ON task(updated_at);
+CREATE INDEX idx_task_parent_updated ON task(parent_id, updated_at DESC);
+CREATE INDEX idx_task_deleted_at ON task(deleted_at);
+
+CREATE INDEX idx_note_parent_id ON note(parent_id);
+CREATE INDEX idx_note_parent_idx ON note(parent_id, idx);
+CREATE INDEX idx_note_parent_updated ON note(parent_id, updated_at DESC);
+CREATE INDEX idx_note_updated_at ON note(updated_at);
+CREATE INDEX idx_note_deleted_at ON note(deleted_at);
+CREATE INDEX idx_note_content_hash ON note(content_hash);
+
+CREATE INDEX idx_skill_name ON skill(name);
+CREATE INDEX idx_skill_created_at ON skill(created_at);
+CREATE INDEX idx_skill_updated_at ON skill(updated_at);
+CREATE INDEX idx_skill_parent_updated ON skill(created_at, updated_at DESC);
+
+CREATE INDEX idx_skill_attachment_skill_id ON skill_attachment(skill_id);
+CREATE INDEX idx_skill_attachment_type ON skill_attachment(skill_id, type);
+
+CREATE INDEX idx_task_transition_log_task_id ON task_transition_log(task_id);
+CREATE INDEX idx_task_transition_log_transitioned_at ON task_transition_log(transitioned_at);
+CREATE INDEX idx_task_transition_log_task_time ON task_transition_log(task_id, transitioned_at DESC);
+
+CREATE INDEX idx_project_repo_project ON project_repo(project_id);
+CREATE INDEX idx_project_repo_repo ON project_repo(repo_id);
+CREATE INDEX idx_project_note_project ON project_note(project_id);
+CREATE INDEX idx_project_note_note ON project_note(note_id);
+CREATE INDEX idx_task_list_repo_task_list ON task_list_repo(task_list_id);
+CREATE INDEX idx_task_list_repo_repo ON task_list_repo(repo_id);
+CREATE INDEX idx_note_repo_note ON note_repo(note_id);
+CREATE INDEX idx_note_repo_repo ON note_repo(repo_id);
+CREATE INDEX idx_project_skill_project_id ON project_skill(project_id);
+CREATE INDEX idx_project_skill_skill_id ON project_skill(skill_id);
+
+-- ========== FTS triggers ==========
+CREATE TRIGGER repo_fts_insert AFTER INSERT ON repo BEGIN
+    INSERT INTO repo_fts (id, remote, path, tags)
+    VALUES (NEW.id, NEW.remote, COALESCE(NEW.path, ''), NEW.tags);
+END;
+
+CREATE TRIGGER repo_fts_update AFTER UPDATE ON repo BEGIN
+    UPDATE repo_fts
+    SET remote = NEW.remote,
+        path = COALESCE(NEW.path, ''),
+        tags = NEW.tags
+    WHERE id = NEW.id;
+END;
+
+CREATE TRIGGER repo_fts_delete AFTER DELETE ON repo BEGIN
+    DELETE FROM repo_fts WHERE id = OLD.id;
+END;
+
+CREATE TRIGGER project_fts_insert AFTER INSERT ON project BEGIN
+    INSERT INTO project_fts(id, title, description, tags, external_refs)
+    VALUES (new.id, new.title, new.description, new.tags, new.external_refs);
+END;
+
+CREATE TRIGGER project_fts_delete AFTER DELETE ON project BEGIN
+    DELETE FROM project_fts WHERE id = old.id;
+END;
+
+CREATE TRIGGER project_fts_update AFTER UPDATE ON project BEGIN
+    DELETE FROM project_fts WHERE id = old.id;
+    INSERT INTO project_fts(id, title, description, tags, external_refs)
+    VALUES (new.id, new.title, new.description, new.tags, new.external_refs);
+END;
+
+CREATE TRIGGER task_list_fts_insert AFTER INSERT ON task_list BEGIN
+    INSERT INTO task_list_fts (id, title, description, notes, tags, external_refs)
+    VALUES (
+        new.id,
+        new.title,
+        COALESCE(new.description, ''),
+        COALESCE(new.notes, ''),
+        COALESCE(new.tags, '[]'),
+        COALESCE(new.external_refs, '[]')
+    );
+END;
+
+CREATE TRIGGER task_list_fts_update AFTER UPDATE ON task_list BEGIN
+    DELETE FROM task_list_fts WHERE id = old.id;
+    INSERT INTO task_list_fts (id, title, description, notes, tags, external_refs)
+    VALUES (
+        new.id,
+        new.title,
+        COALESCE(new.description, ''),
+        COALESCE(new.notes, ''),
+        COALESCE(new.tags, '[]'),
+        COALESCE(new.external_refs, '[]')
+    );
+END;
+
+CREATE TRIGGER task_list_fts_delete AFTER DELETE ON task_list BEGIN
+    DELETE FROM task_list_fts WHERE id = old.id;
+END;
+
+CREATE TRIGGER task_fts_insert AFTER INSERT ON task BEGIN
+    INSERT INTO task_fts (id, title, description, tags, external_refs)
+    VALUES (
+        new.id,
+        new.title,
+        COALESCE(new.description, ''),
+        COALESCE(new.tags, '[]'),
+        COALESCE(new.external_refs, '[]')
+    );
+END;
+
+CREATE TRIGGER task_fts_update AFTER UPDATE ON task BEGIN
+    DELETE FROM task_fts WHERE id = old.id;
+    INSERT INTO task_fts (id, title, description, tags, external_refs)
+    VALUES (
+        new.id,
+        new.title,
+        COALESCE(new.description, ''),
+        COALESCE(new.tags, '[]'),
+        COALESCE(new.external_refs, '[]')
+    );
+END;
+
+CREATE TRIGGER task_fts_delete AFTER DELETE ON task BEGIN
+    DELETE FROM task_fts WHERE id = old.id;
+END;
+
+CREATE TRIGGER note_ai AFTER INSERT ON note BEGIN
+    INSERT INTO note_fts(rowid, title, content, tags)
+    VALUES (new.rowid, new.title, new.content, new.tags);
+END;
+
+CREATE TRIGGER note_au AFTER UPDATE ON note
+WHEN old.title != new.title OR old.content != new.content OR old.tags != new.tags BEGIN
+    INSERT INTO note_fts(note_fts, rowid, title, content, tags)
+    VALUES('delete', old.rowid, old.title, old.content, old.tags);
+    INSERT INTO note_fts(rowid, title, content, tags)
+    VALUES (new.rowid, new.title, new.content, new.tags);
+END;
+
+CREATE TRIGGER note_ad AFTER DELETE ON note BEGIN
+    INSERT INTO note_fts(note_fts, rowid, title, content, tags)
+    VALUES('delete', old.rowid, old.title, old.content, old.tags);
+END;
+
+CREATE TRIGGER skill_fts_insert AFTER INSERT ON skill BEGIN
+    INSERT INTO skill_fts (id, name, description, content, tags)
+    VALUES (
+        new.id,
+        new.name,
+        new.description,
+        new.content,
+        COALESCE(new.tags, '[]')
+    );
+END;
+
+CREATE TRIGGER skill_fts_update AFTER UPDATE ON skill BEGIN
+    DELETE FROM skill_fts WHERE id = old.id;
+    INSERT INTO skill_fts (id, name, description, content, tags)
+    VALUES (
+        new.id,
+        new.name,
+        new.description,
+        new.content,
+        COALESCE(new.tags, '[]')
+    );
+END;
+
+CREATE TRIGGER skill_fts_delete AFTER DELETE ON skill BEGIN
+    DELETE FROM skill_fts WHERE id = old.id;
+END;
+
+COMMIT;
+
+PRAGMA foreign_keys = ON;
@@ -0,0 +1,8 @@
+-- Repo Updated At Migration
+-- Repos get an updated_at like the other synced entities, so an import can
+-- tell a repo restored after its deletion from a stale copy of the deleted
+-- one. Existing rows start from their creation time.
+
+ALTER TABLE repo ADD COLUMN updated_at TEXT;
+
+UPDATE repo SET updated_at = created_at;
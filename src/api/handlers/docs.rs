@@ -0,0 +1,68 @@
+//! OpenAPI documentation handlers.
+
+use std::sync::Arc;
+
+use axum::{
+    extract::State,
+    http::{HeaderMap, header},
+    response::{Html, IntoResponse, Response},
+};
+use utoipa::openapi::OpenApi;
+use utoipa_scalar::Scalar;
+
+/// Path of the raw OpenAPI spec.
+pub const OPENAPI_JSON_PATH: &str = "/docs/openapi.json";
+
+/// API docs rendered once at startup and shared by the docs routes.
+#[derive(Clone)]
+pub struct ApiDocs {
+    html: Arc<str>,
+    spec: Arc<str>,
+}
+
+impl ApiDocs {
+    pub fn new(openapi: OpenApi) -> Self {
+        let spec = openapi
+            .to_json()
+            .expect("generated OpenAPI spec must serialize");
+        let html = Scalar::new(openapi).to_html();
+
+        Self {
+            html: html.into(),
+            spec: spec.into(),
+        }
+    }
+}
+
+/// API documentation
+///
+/// Serves the HTML docs UI to browsers (`Accept: text/html`) and the raw
+/// OpenAPI JSON to everything else.
+pub async fn docs(State(docs): State<ApiDocs>, headers: HeaderMap) -> Response {
+    if accepts_html(&headers) {
+        Html(docs.html.to_string()).into_response()
+    } else {
+        openapi_json(State(docs)).await
+    }
+}
+
+/// Raw OpenAPI spec as JSON
+pub async fn openapi_json(State(docs): State<ApiDocs>) -> Response {
+    (
+        [(header::CONTENT_TYPE, "application/json")],
+        docs.spec.to_string(),
+    )
+        .into_response()
+}
+
+fn accepts_html(headers: &HeaderMap) -> bool {
+    headers
+        .get_all(header::ACCEPT)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .any(|media| {
+            let media = media.split(';').next().unwrap_or("").trim();
+            media.eq_ignore_ascii_case("text/html")
+        })
+}
@@ -1,5 +1,7 @@
 //! System handlers (non-versioned).
 
+mod docs;
 mod system;
 
+pub use docs::*;
 pub use system::*;
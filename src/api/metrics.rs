@@ -0,0 +1,188 @@
+//! Prometheus-style request metrics.
+//!
+//! A middleware counts requests and times them per route; `GET /metrics`
+//! renders those alongside the database pool usage in the Prometheus text
+//! exposition format. Only mounted when the server runs with `--metrics`.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use axum::{
+    extract::{MatchedPath, Request, State},
+    http::header,
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+
+use super::AppState;
+use crate::db::{Database, PoolStats};
+use crate::sync::GitOps;
+
+/// Upper bounds (in seconds) of the request latency histogram buckets.
+const LATENCY_BUCKETS: [f64; 10] = [0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0];
+
+/// Content type of the Prometheus text exposition format.
+const CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+/// Requests and latencies seen for one method and route.
+#[derive(Default)]
+struct RouteStats {
+    /// Request count per response status
+    statuses: BTreeMap<u16, u64>,
+    /// Cumulative count per entry of [`LATENCY_BUCKETS`]
+    buckets: [u64; LATENCY_BUCKETS.len()],
+    seconds: f64,
+    count: u64,
+}
+
+/// Request counters and latency histograms, keyed by method and route.
+///
+/// Routes are the matched path templates (`/api/v1/tasks/{id}`), so the
+/// number of series stays bounded whatever IDs clients request.
+#[derive(Default)]
+pub struct Metrics {
+    routes: Mutex<BTreeMap<(String, String), RouteStats>>,
+}
+
+impl Metrics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record one finished request.
+    pub fn record(&self, method: &str, route: &str, status: u16, elapsed: Duration) {
+        let seconds = elapsed.as_secs_f64();
+        let mut routes = self.routes.lock().unwrap_or_else(|e| e.into_inner());
+        let stats = routes
+            .entry((method.to_string(), route.to_string()))
+            .or_default();
+
+        *stats.statuses.entry(status).or_default() += 1;
+        for (bucket, bound) in stats.buckets.iter_mut().zip(LATENCY_BUCKETS) {
+            if seconds <= bound {
+                *bucket += 1;
+            }
+        }
+        stats.seconds += seconds;
+        stats.count += 1;
+    }
+
+    /// Render every metric in the Prometheus text format.
+    pub fn render(&self, pool: PoolStats) -> String {
+        let routes = self.routes.lock().unwrap_or_else(|e| e.into_inner());
+        let mut out = String::new();
+
+        out.push_str("# HELP c5t_http_requests_total HTTP requests handled.\n");
+        out.push_str("# TYPE c5t_http_requests_total counter\n");
+        for ((method, route), stats) in routes.iter() {
+            for (status, count) in &stats.statuses {
+                let _ = writeln!(
+                    out,
+                    "c5t_http_requests_total{{method=\"{}\",route=\"{}\",status=\"{}\"}} {}",
+                    escape(method),
+                    escape(route),
+                    status,
+                    count
+                );
+            }
+        }
+
+        out.push_str("# HELP c5t_http_request_duration_seconds HTTP request latency.\n");
+        out.push_str("# TYPE c5t_http_request_duration_seconds histogram\n");
+        for ((method, route), stats) in routes.iter() {
+            let labels = format!("method=\"{}\",route=\"{}\"", escape(method), escape(route));
+            for (count, bound) in stats.buckets.iter().zip(LATENCY_BUCKETS) {
+                let _ = writeln!(
+                    out,
+                    "c5t_http_request_duration_seconds_bucket{{{},le=\"{}\"}} {}",
+                    labels, bound, count
+                );
+            }
+            let _ = writeln!(
+                out,
+                "c5t_http_request_duration_seconds_bucket{{{},le=\"+Inf\"}} {}",
+                labels, stats.count
+            );
+            let _ = writeln!(
+                out,
+                "c5t_http_request_duration_seconds_sum{{{}}} {}",
+                labels, stats.seconds
+            );
+            let _ = writeln!(
+                out,
+                "c5t_http_request_duration_seconds_count{{{}}} {}",
+                labels, stats.count
+            );
+        }
+
+        let in_use = (pool.size as usize).saturating_sub(pool.idle);
+        out.push_str("# HELP c5t_db_pool_connections Open database connections.\n");
+        out.push_str("# TYPE c5t_db_pool_connections gauge\n");
+        let _ = writeln!(
+            out,
+            "c5t_db_pool_connections{{state=\"idle\"}} {}",
+            pool.idle
+        );
+        let _ = writeln!(
+            out,
+            "c5t_db_pool_connections{{state=\"in_use\"}} {}",
+            in_use
+        );
+        out.push_str("# HELP c5t_db_pool_max_connections Most connections the pool opens.\n");
+        out.push_str("# TYPE c5t_db_pool_max_connections gauge\n");
+        let _ = writeln!(out, "c5t_db_pool_max_connections {}", pool.max_size);
+
+        out
+    }
+}
+
+/// Escape a label value (backslash, quote and newline).
+fn escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// Middleware recording each request's route, status and latency.
+pub async fn track<D: Database, G: GitOps + Send + Sync>(
+    State(state): State<AppState<D, G>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let Some(metrics) = state.metrics() else {
+        return next.run(request).await;
+    };
+    let method = request.method().to_string();
+    let route = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map_or("unmatched", MatchedPath::as_str)
+        .to_string();
+
+    let started = Instant::now();
+    let response = next.run(request).await;
+    metrics.record(
+        &method,
+        &route,
+        response.status().as_u16(),
+        started.elapsed(),
+    );
+    response
+}
+
+/// Metrics endpoint
+///
+/// Request counts and latencies per route, and database pool usage, in the
+/// Prometheus text format.
+pub async fn metrics<D: Database, G: GitOps + Send + Sync>(
+    State(state): State<AppState<D, G>>,
+) -> Response {
+    let body = state
+        .metrics()
+        .map(|metrics| metrics.render(state.db().pool_stats()))
+        .unwrap_or_default();
+    ([(header::CONTENT_TYPE, CONTENT_TYPE)], body).into_response()
+}
@@ -0,0 +1,86 @@
+//! Tests for the /metrics endpoint.
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode, header},
+};
+use http_body_util::BodyExt;
+use std::sync::Arc;
+use tempfile::TempDir;
+use tower::ServiceExt;
+
+use crate::a6s::store::surrealdb;
+use crate::api::{AppState, routes};
+use crate::db::{Database, SqliteDatabase};
+
+async fn test_app(enable_metrics: bool) -> axum::Router {
+    let db = SqliteDatabase::in_memory().await.unwrap();
+    db.migrate().unwrap();
+    let temp_dir = TempDir::new().unwrap();
+    let state = AppState::new(
+        db,
+        crate::sync::SyncManager::new(crate::sync::MockGitOps::new()),
+        crate::api::notifier::ChangeNotifier::new(),
+        temp_dir.path().join("skills"),
+        Arc::new(surrealdb::init_db(None).await.unwrap()),
+        crate::a6s::tracker::AnalysisTracker::new(crate::api::notifier::ChangeNotifier::new()),
+    )
+    .with_metrics(enable_metrics);
+    routes::create_router(state, false)
+}
+
+async fn get(app: &axum::Router, uri: &str) -> axum::response::Response {
+    app.clone()
+        .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+        .await
+        .unwrap()
+}
+
+async fn scrape(app: &axum::Router) -> String {
+    let response = get(app, "/metrics").await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(
+        response.headers()[header::CONTENT_TYPE]
+            .to_str()
+            .unwrap()
+            .starts_with("text/plain")
+    );
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    String::from_utf8(body.to_vec()).unwrap()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn metrics_count_requests_per_route() {
+    let app = test_app(true).await;
+    let health = r#"c5t_http_requests_total{method="GET",route="/health",status="200"}"#;
+
+    let before = scrape(&app).await;
+    assert!(!before.contains(health));
+    assert!(before.contains("c5t_db_pool_max_connections"));
+
+    get(&app, "/health").await;
+    let after = scrape(&app).await;
+    assert!(after.contains(&format!("{} 1", health)), "{}", after);
+
+    get(&app, "/health").await;
+    let after = scrape(&app).await;
+    assert!(after.contains(&format!("{} 2", health)), "{}", after);
+    assert!(
+        after
+            .contains(r#"c5t_http_request_duration_seconds_count{method="GET",route="/health"} 2"#)
+    );
+
+    // Routes are labelled by template, not by the requested ID
+    get(&app, "/api/v1/tasks/abcd1234").await;
+    let after = scrape(&app).await;
+    assert!(after.contains(
+        r#"c5t_http_requests_total{method="GET",route="/api/v1/tasks/{id}",status="404"} 1"#
+    ));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn metrics_not_mounted_by_default() {
+    let app = test_app(false).await;
+    let response = get(&app, "/metrics").await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
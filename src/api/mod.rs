@@ -3,12 +3,17 @@
 //! Provides REST API endpoints for managing context data.
 
 mod handlers;
+mod metrics;
+#[cfg(test)]
+mod metrics_test;
 #[cfg(test)]
 mod mod_test;
 pub mod notifier;
 #[cfg(test)]
 mod notifier_test;
 pub(crate) mod routes;
+#[cfg(test)]
+mod routes_test;
 mod state;
 #[cfg(feature = "embed-frontend")]
 pub mod static_assets;
@@ -24,15 +29,23 @@ use std::net::IpAddr;
 use std::path::PathBuf;
 use std::sync::Arc;
 
+use axum::{
+    Router,
+    extract::Request,
+    http::{HeaderValue, Method, StatusCode, header},
+    middleware::{self, Next},
+    response::Response,
+};
 use miette::Diagnostic;
 use thiserror::Error;
+use tower_http::cors::{AllowOrigin, CorsLayer};
 use tower_http::trace::TraceLayer;
 use tracing::info;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
 pub use state::AppState;
 
-use crate::db::Database;
+use crate::db::{Database, NOTE_HARD_MAX, TaskStatus};
 use crate::sync::get_data_dir;
 
 #[cfg(debug_assertions)]
@@ -45,16 +58,33 @@ const DEFAULT_API_PORT: u16 = 3737;
 #[derive(Error, Diagnostic, Debug)]
 pub enum ApiError {
     #[error("Failed to bind to address {addr}: {source}")]
-    #[diagnostic(code(c5t::api::bind_failed))]
+    #[diagnostic(
+        code(c5t::api::bind_failed),
+        help("Is another process already using this port? Try a different --port or --host.")
+    )]
     BindFailed {
         addr: String,
         #[source]
         source: std::io::Error,
     },
 
-    #[error("Server error: {0}")]
-    #[diagnostic(code(c5t::api::server_error))]
-    ServerError(#[from] std::io::Error),
+    #[error("Server on {addr} stopped unexpectedly: {source}")]
+    #[diagnostic(
+        code(c5t::api::serve_failed),
+        help("The listener failed after startup. Check the logs above and restart the server.")
+    )]
+    ServeFailed {
+        addr: String,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("Invalid CORS origin '{origin}'")]
+    #[diagnostic(
+        code(c5t::api::invalid_origin),
+        help("Origins look like https://example.com or http://localhost:8080, without a path.")
+    )]
+    InvalidOrigin { origin: String },
 }
 
 /// API server configuration
@@ -67,8 +97,27 @@ pub struct Config {
     pub verbosity: u8,
     /// Enable OpenAPI documentation endpoint at /docs
     pub enable_docs: bool,
+    /// Serve Prometheus-style metrics at /metrics
+    pub enable_metrics: bool,
     /// Skills cache directory (where attachments are extracted)
     pub skills_dir: PathBuf,
+    /// Project ID used when a task list is created without one
+    pub default_project: Option<String>,
+    /// Largest note content (in bytes) accepted by the API
+    pub max_note_size: usize,
+    /// Complete a parent task once all of its subtasks are done or cancelled
+    pub auto_complete_parents: bool,
+    /// Status given to tasks created over REST without one
+    pub default_task_status: TaskStatus,
+    /// Fold tags to lowercase on create/update (duplicates are always dropped)
+    pub lowercase_tags: bool,
+    /// Write sync files gzip-compressed (`.jsonl.gz`)
+    pub compress_sync: bool,
+    /// Origins allowed to call the API cross-origin (e.g. a separately
+    /// hosted frontend); empty means same-origin only
+    pub allowed_origins: Vec<String>,
+    /// Allow any origin (`*`) when `allowed_origins` is empty
+    pub cors_permissive: bool,
 }
 
 impl Config {
@@ -79,10 +128,19 @@ impl Config {
             port: DEFAULT_API_PORT,
             verbosity: 0,
             enable_docs: false,
+            enable_metrics: false,
             skills_dir: match std::env::var("C5T_SKILLS_DIR") {
                 Ok(dir) => PathBuf::from(dir),
                 Err(_) => get_data_dir().join("skills"),
             },
+            default_project: std::env::var("C5T_DEFAULT_PROJECT").ok(),
+            max_note_size: max_note_size_from_env(),
+            auto_complete_parents: auto_complete_parents_from_env(),
+            default_task_status: default_task_status_from_env(),
+            lowercase_tags: lowercase_tags_from_env(),
+            compress_sync: compress_sync_from_env(),
+            allowed_origins: allowed_origins_from_env(),
+            cors_permissive: cors_permissive_from_env(),
         }
     }
 
@@ -91,6 +149,77 @@ impl Config {
         self.skills_dir = skills_dir;
         self
     }
+
+    /// Builder method to override default_project (CLI flag > env var)
+    pub fn with_default_project(mut self, project_id: String) -> Self {
+        self.default_project = Some(project_id);
+        self
+    }
+
+    /// Builder method to override max_note_size (CLI flag > env var)
+    ///
+    /// Capped at [`NOTE_HARD_MAX`], which the store always enforces.
+    pub fn with_max_note_size(mut self, max_note_size: usize) -> Self {
+        self.max_note_size = max_note_size.min(NOTE_HARD_MAX);
+        self
+    }
+}
+
+/// Read C5T_MAX_NOTE_SIZE, falling back to (and capped at) [`NOTE_HARD_MAX`]
+pub fn max_note_size_from_env() -> usize {
+    std::env::var("C5T_MAX_NOTE_SIZE")
+        .ok()
+        .and_then(|v| v.parse::<usize>().ok())
+        .map_or(NOTE_HARD_MAX, |size| size.min(NOTE_HARD_MAX))
+}
+
+/// Read C5T_DEFAULT_TASK_STATUS, falling back to `todo` when unset or unknown
+pub fn default_task_status_from_env() -> TaskStatus {
+    std::env::var("C5T_DEFAULT_TASK_STATUS")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(TaskStatus::Todo)
+}
+
+/// Read C5T_AUTO_COMPLETE_PARENTS ("1" or "true" enables it)
+pub fn auto_complete_parents_from_env() -> bool {
+    std::env::var("C5T_AUTO_COMPLETE_PARENTS")
+        .map(|v| matches!(v.to_ascii_lowercase().as_str(), "1" | "true"))
+        .unwrap_or(false)
+}
+
+/// Read C5T_LOWERCASE_TAGS ("1" or "true" enables it)
+pub fn lowercase_tags_from_env() -> bool {
+    std::env::var("C5T_LOWERCASE_TAGS")
+        .map(|v| matches!(v.to_ascii_lowercase().as_str(), "1" | "true"))
+        .unwrap_or(false)
+}
+
+/// Read C5T_SYNC_COMPRESS ("1" or "true" enables it)
+pub fn compress_sync_from_env() -> bool {
+    std::env::var("C5T_SYNC_COMPRESS")
+        .map(|v| matches!(v.to_ascii_lowercase().as_str(), "1" | "true"))
+        .unwrap_or(false)
+}
+
+/// Read C5T_ALLOWED_ORIGINS (comma-separated)
+pub fn allowed_origins_from_env() -> Vec<String> {
+    std::env::var("C5T_ALLOWED_ORIGINS")
+        .map(|v| {
+            v.split(',')
+                .map(str::trim)
+                .filter(|origin| !origin.is_empty())
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Read C5T_CORS_PERMISSIVE ("1" or "true" enables it)
+pub fn cors_permissive_from_env() -> bool {
+    std::env::var("C5T_CORS_PERMISSIVE")
+        .map(|v| matches!(v.to_ascii_lowercase().as_str(), "1" | "true"))
+        .unwrap_or(false)
 }
 
 impl Default for Config {
@@ -100,7 +229,16 @@ impl Default for Config {
             port: DEFAULT_API_PORT,
             verbosity: 0,
             enable_docs: false,
+            enable_metrics: false,
             skills_dir: get_data_dir().join("skills"),
+            default_project: None,
+            max_note_size: NOTE_HARD_MAX,
+            auto_complete_parents: false,
+            default_task_status: TaskStatus::Todo,
+            lowercase_tags: false,
+            compress_sync: false,
+            allowed_origins: vec![],
+            cors_permissive: false,
         }
     }
 }
@@ -130,7 +268,8 @@ pub async fn run<D: Database + 'static>(config: Config, db: D) -> Result<(), Api
     init_tracing(config.verbosity);
 
     // Create sync manager (uses RealGit for production)
-    let sync_manager = crate::sync::SyncManager::new(crate::sync::RealGit::new());
+    let sync_manager = crate::sync::SyncManager::new(crate::sync::RealGit::new())
+        .with_compression(config.compress_sync);
 
     // Create change notifier for WebSocket pub/sub
     let notifier = notifier::ChangeNotifier::new();
@@ -153,21 +292,102 @@ pub async fn run<D: Database + 'static>(config: Config, db: D) -> Result<(), Api
         config.skills_dir,
         analysis_db,
         tracker,
-    );
+    )
+    .with_default_project(config.default_project)
+    .with_max_note_size(config.max_note_size)
+    .with_auto_complete_parents(config.auto_complete_parents)
+    .with_default_task_status(config.default_task_status)
+    .with_lowercase_tags(config.lowercase_tags)
+    .with_metrics(config.enable_metrics);
 
-    let app = routes::create_router(state, config.enable_docs).layer(TraceLayer::new_for_http());
+    let app = routes::create_router(state, config.enable_docs);
+    let app = with_cors(app, &config.allowed_origins, config.cors_permissive)?
+        .layer(TraceLayer::new_for_http());
 
     let addr = format!("{}:{}", config.host, config.port);
-    let listener =
-        tokio::net::TcpListener::bind(&addr)
-            .await
-            .map_err(|e| ApiError::BindFailed {
-                addr: addr.clone(),
-                source: e,
-            })?;
+    let listener = bind_listener(&addr).await?;
     info!("API server listening on http://{}", addr);
     info!("API docs available at http://{}/docs", addr);
+    if config.enable_metrics {
+        info!("Metrics available at http://{}/metrics", addr);
+    }
+
+    axum::serve(listener, app)
+        .await
+        .map_err(|e| ApiError::ServeFailed { addr, source: e })
+}
+
+/// Let the given origins call the API from a browser.
+///
+/// Origins are reflected back only when listed; with no origins and
+/// `permissive` set any origin is allowed. With neither, the router is
+/// returned unchanged and browsers keep the API same-origin.
+pub(crate) fn with_cors(
+    router: Router,
+    allowed_origins: &[String],
+    permissive: bool,
+) -> Result<Router, ApiError> {
+    let allow_origin = if !allowed_origins.is_empty() {
+        let origins = allowed_origins
+            .iter()
+            .map(|origin| {
+                HeaderValue::from_str(origin.trim_end_matches('/')).map_err(|_| {
+                    ApiError::InvalidOrigin {
+                        origin: origin.clone(),
+                    }
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        AllowOrigin::list(origins)
+    } else if permissive {
+        AllowOrigin::any()
+    } else {
+        return Ok(router);
+    };
+
+    let cors = CorsLayer::new()
+        .allow_origin(allow_origin)
+        .allow_methods([
+            Method::GET,
+            Method::POST,
+            Method::PUT,
+            Method::PATCH,
+            Method::DELETE,
+        ])
+        .allow_headers([
+            header::AUTHORIZATION,
+            header::CONTENT_TYPE,
+            header::IF_NONE_MATCH,
+        ])
+        // Let cross-origin clients read ETags for conditional GETs
+        .expose_headers([header::ETAG]);
+
+    Ok(router
+        .layer(cors)
+        .layer(middleware::from_fn(preflight_no_content)))
+}
+
+/// Answer CORS preflights with 204 instead of the layer's default 200.
+///
+/// The CORS layer answers preflights itself, so they never reach a handler.
+async fn preflight_no_content(request: Request, next: Next) -> Response {
+    let preflight = request.method() == Method::OPTIONS
+        && request
+            .headers()
+            .contains_key(header::ACCESS_CONTROL_REQUEST_METHOD);
+    let mut response = next.run(request).await;
+    if preflight && response.status() == StatusCode::OK {
+        *response.status_mut() = StatusCode::NO_CONTENT;
+    }
+    response
+}
 
-    axum::serve(listener, app).await?;
-    Ok(())
+/// Bind a TCP listener, reporting the address on failure.
+pub(crate) async fn bind_listener(addr: &str) -> Result<tokio::net::TcpListener, ApiError> {
+    tokio::net::TcpListener::bind(addr)
+        .await
+        .map_err(|e| ApiError::BindFailed {
+            addr: addr.to_string(),
+            source: e,
+        })
 }
@@ -1,8 +1,17 @@
-use super::Config;
+use super::{ApiError, Config, bind_listener, with_cors};
 use crate::sync::get_data_dir;
+use axum::{
+    Router,
+    body::Body,
+    http::{Method, Request, StatusCode, header},
+    routing::get,
+};
 use serial_test::serial;
 use std::env;
 use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use tower::ServiceExt;
 
 #[test]
 fn test_config_default_skills_dir() {
@@ -71,3 +80,180 @@ fn test_config_precedence_cli_over_env() {
         env::remove_var("C5T_SKILLS_DIR");
     }
 }
+
+#[tokio::test]
+async fn test_bind_failure_reports_address() {
+    let taken = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = taken.local_addr().unwrap().to_string();
+
+    match bind_listener(&addr).await {
+        Err(ApiError::BindFailed {
+            addr: failed,
+            source,
+        }) => {
+            assert_eq!(failed, addr);
+            assert_eq!(source.kind(), std::io::ErrorKind::AddrInUse);
+        }
+        other => panic!("expected BindFailed, got {:?}", other.map(|_| ())),
+    }
+}
+
+/// Router whose only handler counts how often it runs.
+fn counting_router(hits: Arc<AtomicUsize>) -> Router {
+    Router::new().route(
+        "/api/v1/projects",
+        get(move || {
+            hits.fetch_add(1, Ordering::SeqCst);
+            async { "ok" }
+        }),
+    )
+}
+
+fn preflight(origin: &str) -> Request<Body> {
+    Request::builder()
+        .method(Method::OPTIONS)
+        .uri("/api/v1/projects")
+        .header(header::ORIGIN, origin)
+        .header(header::ACCESS_CONTROL_REQUEST_METHOD, "PATCH")
+        .header(
+            header::ACCESS_CONTROL_REQUEST_HEADERS,
+            "authorization,content-type",
+        )
+        .body(Body::empty())
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_cors_preflight_returns_no_content_without_handler() {
+    let hits = Arc::new(AtomicUsize::new(0));
+    let origins = vec!["http://localhost:8080".to_string()];
+    let app = with_cors(counting_router(hits.clone()), &origins, false).unwrap();
+
+    let response = app
+        .clone()
+        .oneshot(preflight("http://localhost:8080"))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    let headers = response.headers();
+    assert_eq!(
+        headers[header::ACCESS_CONTROL_ALLOW_ORIGIN],
+        "http://localhost:8080"
+    );
+    let methods = headers[header::ACCESS_CONTROL_ALLOW_METHODS]
+        .to_str()
+        .unwrap();
+    for method in ["GET", "POST", "PUT", "PATCH", "DELETE"] {
+        assert!(
+            methods.contains(method),
+            "{} missing from {}",
+            method,
+            methods
+        );
+    }
+    let allowed = headers[header::ACCESS_CONTROL_ALLOW_HEADERS]
+        .to_str()
+        .unwrap();
+    assert!(allowed.contains("authorization"));
+    assert!(allowed.contains("content-type"));
+    assert_eq!(hits.load(Ordering::SeqCst), 0);
+
+    // Actual requests reach the handler and are tagged for allowed origins only
+    let request = |origin: &str| {
+        Request::builder()
+            .uri("/api/v1/projects")
+            .header(header::ORIGIN, origin)
+            .body(Body::empty())
+            .unwrap()
+    };
+    let allowed = app
+        .clone()
+        .oneshot(request("http://localhost:8080"))
+        .await
+        .unwrap();
+    assert_eq!(allowed.status(), StatusCode::OK);
+    assert_eq!(
+        allowed.headers()[header::ACCESS_CONTROL_ALLOW_ORIGIN],
+        "http://localhost:8080"
+    );
+    let other = app.oneshot(request("https://evil.example")).await.unwrap();
+    assert!(
+        !other
+            .headers()
+            .contains_key(header::ACCESS_CONTROL_ALLOW_ORIGIN)
+    );
+    assert_eq!(hits.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn test_cors_allows_conditional_requests() {
+    let origins = vec!["http://localhost:8080".to_string()];
+    let router = Router::new().route(
+        "/api/v1/projects",
+        get(|| async { ([(header::ETAG, "\"abc\"")], "ok") }),
+    );
+    let app = with_cors(router, &origins, false).unwrap();
+
+    let preflight = Request::builder()
+        .method(Method::OPTIONS)
+        .uri("/api/v1/projects")
+        .header(header::ORIGIN, "http://localhost:8080")
+        .header(header::ACCESS_CONTROL_REQUEST_METHOD, "GET")
+        .header(header::ACCESS_CONTROL_REQUEST_HEADERS, "if-none-match")
+        .body(Body::empty())
+        .unwrap();
+    let response = app.clone().oneshot(preflight).await.unwrap();
+    let allowed = response.headers()[header::ACCESS_CONTROL_ALLOW_HEADERS]
+        .to_str()
+        .unwrap();
+    assert!(allowed.contains("if-none-match"), "got {}", allowed);
+
+    let request = Request::builder()
+        .uri("/api/v1/projects")
+        .header(header::ORIGIN, "http://localhost:8080")
+        .body(Body::empty())
+        .unwrap();
+    let response = app.oneshot(request).await.unwrap();
+    let exposed = response.headers()[header::ACCESS_CONTROL_EXPOSE_HEADERS]
+        .to_str()
+        .unwrap();
+    assert!(exposed.contains("etag"), "got {}", exposed);
+}
+
+#[tokio::test]
+async fn test_cors_permissive_allows_any_origin() {
+    let hits = Arc::new(AtomicUsize::new(0));
+    let app = with_cors(counting_router(hits), &[], true).unwrap();
+
+    let response = app
+        .oneshot(preflight("https://anywhere.example"))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    assert_eq!(response.headers()[header::ACCESS_CONTROL_ALLOW_ORIGIN], "*");
+}
+
+#[tokio::test]
+async fn test_cors_disabled_by_default() {
+    let hits = Arc::new(AtomicUsize::new(0));
+    let app = with_cors(counting_router(hits), &[], false).unwrap();
+
+    let response = app
+        .oneshot(preflight("https://anywhere.example"))
+        .await
+        .unwrap();
+    assert!(
+        !response
+            .headers()
+            .contains_key(header::ACCESS_CONTROL_ALLOW_ORIGIN)
+    );
+}
+
+#[test]
+fn test_cors_rejects_invalid_origin() {
+    let origins = vec!["http://bad\norigin".to_string()];
+    match with_cors(Router::new(), &origins, false) {
+        Err(ApiError::InvalidOrigin { origin }) => assert_eq!(origin, origins[0]),
+        other => panic!("expected InvalidOrigin, got {:?}", other.map(|_| ())),
+    }
+}
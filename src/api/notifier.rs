@@ -8,39 +8,90 @@ use tokio::sync::broadcast;
 #[serde(tag = "type", content = "data")]
 pub enum UpdateMessage {
     // Notes
-    NoteCreated { note_id: String },
-    NoteUpdated { note_id: String },
-    NoteDeleted { note_id: String },
+    NoteCreated {
+        note_id: String,
+    },
+    /// `changed` names the fields that differ from the prior row, when known;
+    /// an empty list means subscribers should refetch the whole note.
+    NoteUpdated {
+        note_id: String,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        changed: Vec<String>,
+    },
+    NoteDeleted {
+        note_id: String,
+    },
 
     // Projects
-    ProjectCreated { project_id: String },
-    ProjectUpdated { project_id: String },
-    ProjectDeleted { project_id: String },
+    ProjectCreated {
+        project_id: String,
+    },
+    ProjectUpdated {
+        project_id: String,
+    },
+    ProjectDeleted {
+        project_id: String,
+    },
 
     // Repos
-    RepoCreated { repo_id: String },
-    RepoUpdated { repo_id: String },
-    RepoDeleted { repo_id: String },
+    RepoCreated {
+        repo_id: String,
+    },
+    RepoUpdated {
+        repo_id: String,
+    },
+    RepoDeleted {
+        repo_id: String,
+    },
 
     // TaskLists
-    TaskListCreated { task_list_id: String },
-    TaskListUpdated { task_list_id: String },
-    TaskListDeleted { task_list_id: String },
+    TaskListCreated {
+        task_list_id: String,
+    },
+    TaskListUpdated {
+        task_list_id: String,
+    },
+    TaskListDeleted {
+        task_list_id: String,
+    },
 
     // Tasks
-    TaskCreated { task_id: String },
-    TaskUpdated { task_id: String },
-    TaskDeleted { task_id: String },
+    TaskCreated {
+        task_id: String,
+    },
+    /// `changed` names the fields that differ from the prior row, when known;
+    /// an empty list means subscribers should refetch the whole task.
+    TaskUpdated {
+        task_id: String,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        changed: Vec<String>,
+    },
+    TaskDeleted {
+        task_id: String,
+    },
 
     // Skills
-    SkillCreated { skill_id: String },
-    SkillUpdated { skill_id: String },
-    SkillDeleted { skill_id: String },
+    SkillCreated {
+        skill_id: String,
+    },
+    SkillUpdated {
+        skill_id: String,
+    },
+    SkillDeleted {
+        skill_id: String,
+    },
 
     // Analysis
-    AnalysisStarted { repo_id: String },
-    AnalysisCompleted { repo_id: String },
-    AnalysisFailed { repo_id: String, error: String },
+    AnalysisStarted {
+        repo_id: String,
+    },
+    AnalysisCompleted {
+        repo_id: String,
+    },
+    AnalysisFailed {
+        repo_id: String,
+        error: String,
+    },
 }
 
 /// Pub/sub notifier for broadcasting database changes to all subscribers.
@@ -72,3 +123,33 @@ impl ChangeNotifier {
         let _ = self.tx.send(msg);
     }
 }
+
+/// Fields that move on every write or are computed, so never count as edits
+const UNTRACKED_FIELDS: &[&str] = &["updated_at", "subnote_count"];
+
+/// Top-level fields whose serialized values differ between two versions of an entity.
+///
+/// Used to fill `changed` on update messages. Returns an empty list if either
+/// side does not serialize to a JSON object.
+pub fn changed_fields<T: Serialize>(before: &T, after: &T) -> Vec<String> {
+    let (Ok(serde_json::Value::Object(before)), Ok(serde_json::Value::Object(after))) =
+        (serde_json::to_value(before), serde_json::to_value(after))
+    else {
+        return Vec::new();
+    };
+
+    let mut changed: Vec<String> = after
+        .iter()
+        .filter(|(key, value)| before.get(key.as_str()) != Some(value))
+        .map(|(key, _)| key.clone())
+        .chain(
+            before
+                .keys()
+                .filter(|key| !after.contains_key(key.as_str()))
+                .cloned(),
+        )
+        .filter(|key| !UNTRACKED_FIELDS.contains(&key.as_str()))
+        .collect();
+    changed.sort();
+    changed
+}
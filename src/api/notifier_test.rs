@@ -39,6 +39,7 @@ async fn test_all_message_types_are_cloneable() {
         },
         UpdateMessage::NoteUpdated {
             note_id: "n2".to_string(),
+            changed: vec![],
         },
         UpdateMessage::NoteDeleted {
             note_id: "n3".to_string(),
@@ -75,6 +76,7 @@ async fn test_all_message_types_are_cloneable() {
         },
         UpdateMessage::TaskUpdated {
             task_id: "t2".to_string(),
+            changed: vec![],
         },
         UpdateMessage::TaskDeleted {
             task_id: "t3".to_string(),
@@ -123,6 +125,7 @@ async fn test_subscriber_receives_multiple_messages_in_order() {
     };
     let msg2 = UpdateMessage::NoteUpdated {
         note_id: "note2".to_string(),
+        changed: vec![],
     };
     let msg3 = UpdateMessage::NoteDeleted {
         note_id: "note3".to_string(),
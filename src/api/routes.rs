@@ -3,7 +3,6 @@
 use axum::Router;
 use axum::routing::{any, delete, get, patch, post, put};
 use utoipa::OpenApi;
-use utoipa_scalar::{Scalar, Servable};
 
 use super::handlers::{self, HealthResponse};
 use super::state::AppState;
@@ -14,9 +13,10 @@ use super::v1::{
     CreateTaskListRequest, CreateTaskRequest, DisableSkillResponse, EnableSkillResponse,
     ErrorResponse, GraphEdge, GraphNode, GraphResponse, GraphStats, ImportSkillRequest,
     NoteResponse, PatchNoteRequest, PatchProjectRequest, PatchRepoRequest, PatchTaskListRequest,
-    PatchTaskRequest, ProjectResponse, ReplaceSkillRequest, RepoResponse, SkillResponse,
-    TaskListResponse, TaskResponse, UpdateNoteRequest, UpdateProjectRequest, UpdateRepoRequest,
-    UpdateSkillRequest, UpdateTaskListRequest, UpdateTaskRequest,
+    PatchTaskRequest, ProjectResponse, ReplaceSkillRequest, RepoResponse,
+    SkillAttachmentManifestEntry, SkillImportPreviewResponse, SkillResponse, TaskListResponse,
+    TaskResponse, UpdateNoteRequest, UpdateProjectRequest, UpdateRepoRequest, UpdateSkillRequest,
+    UpdateTaskListRequest, UpdateTaskRequest,
 };
 
 use crate::db::Database;
@@ -50,6 +50,9 @@ macro_rules! routes {
         handlers::health,
         super::v1::list_projects,
         super::v1::get_project,
+        super::v1::get_project_tree,
+        super::v1::get_default_project,
+        super::v1::get_projects_task_stats,
         super::v1::create_project,
         super::v1::update_project,
         super::v1::patch_project,
@@ -67,16 +70,21 @@ macro_rules! routes {
         super::v1::create_task_list,
         super::v1::update_task_list,
         super::v1::patch_task_list,
+        super::v1::archive_task_list,
         super::v1::delete_task_list,
         super::v1::get_task_list_stats,
          super::v1::list_tasks,
          super::v1::get_task,
          super::v1::create_task,
+         super::v1::create_tasks_batch,
          super::v1::update_task,
          super::v1::patch_task,
          super::v1::delete_task,
          super::v1::get_task_transitions,
+         super::v1::complete_task,
+         super::v1::reparent_tasks,
          super::v1::list_notes,
+         super::v1::search_notes,
          super::v1::get_note,
          super::v1::create_note,
          super::v1::update_note,
@@ -94,7 +102,9 @@ macro_rules! routes {
          super::v1::init_sync,
         super::v1::export_sync,
         super::v1::import_sync,
+        super::v1::diff_sync,
         super::v1::get_sync_status,
+        super::v1::search,
     ),
     components(
         schemas(
@@ -104,6 +114,10 @@ macro_rules! routes {
             UpdateProjectRequest,
             PatchProjectRequest,
             super::v1::PaginatedProjects,
+            super::v1::ProjectTreeResponse,
+            super::v1::ProjectTreeTaskList,
+            super::v1::ProjectTreeNote,
+            super::v1::ProjectTaskStatsResponse,
             RepoResponse,
             CreateRepoRequest,
             UpdateRepoRequest,
@@ -119,20 +133,27 @@ macro_rules! routes {
             CreateTaskRequest,
             UpdateTaskRequest,
             PatchTaskRequest,
+            super::v1::ReparentTasksRequest,
             super::v1::PaginatedTasks,
             NoteResponse,
             CreateNoteRequest,
             UpdateNoteRequest,
             PatchNoteRequest,
             super::v1::PaginatedNotes,
+            super::v1::NoteSearchHit,
+            super::v1::PaginatedNoteSearchResults,
+            super::v1::SearchResponse,
             super::v1::InitSyncRequest,
             super::v1::ExportSyncRequest,
+            super::v1::DiffSyncRequest,
             super::v1::SyncResponse,
              ErrorResponse,
              // --- Skills ---
              SkillResponse,
              CreateSkillRequest,
              ImportSkillRequest,
+             SkillImportPreviewResponse,
+             SkillAttachmentManifestEntry,
              ReplaceSkillRequest,
              UpdateSkillRequest,
              EnableSkillResponse,
@@ -153,6 +174,7 @@ macro_rules! routes {
         (name = "notes", description = "Note management endpoints with FTS search"),
          (name = "sync", description = "Git-based sync operations"),
          (name = "skills", description = "Skills management endpoints"),
+         (name = "search", description = "Cross-entity search"),
 ))]
 pub struct ApiDoc;
 
@@ -173,6 +195,7 @@ pub fn create_router<D: Database + 'static, G: crate::sync::GitOps + Send + Sync
         state.skills_dir().clone(),
         state.analysis_db(),
         state.tracker().clone(),
+        state.auto_complete_parents(),
         ct,
     );
 
@@ -185,7 +208,10 @@ pub fn create_router<D: Database + 'static, G: crate::sync::GitOps + Send + Sync
     let v1_routes = routes!(D, G => {
         // Projects
         get "/projects" => super::v1::list_projects,
+        get "/projects/default" => super::v1::get_default_project,
+        get "/projects/task-stats" => super::v1::get_projects_task_stats,
         get "/projects/{id}" => super::v1::get_project,
+        get "/projects/{id}/tree" => super::v1::get_project_tree,
         post "/projects" => super::v1::create_project,
         put "/projects/{id}" => super::v1::update_project,
         patch "/projects/{id}" => super::v1::patch_project,
@@ -206,17 +232,22 @@ pub fn create_router<D: Database + 'static, G: crate::sync::GitOps + Send + Sync
         post "/task-lists" => super::v1::create_task_list,
         put "/task-lists/{id}" => super::v1::update_task_list,
         patch "/task-lists/{id}" => super::v1::patch_task_list,
+        post "/task-lists/{id}/archive" => super::v1::archive_task_list,
         delete "/task-lists/{id}" => super::v1::delete_task_list,
         // Tasks
         get "/task-lists/{list_id}/tasks" => super::v1::list_tasks,
         post "/task-lists/{list_id}/tasks" => super::v1::create_task,
+        post "/task-lists/{list_id}/tasks/batch" => super::v1::create_tasks_batch,
         get "/tasks/{id}" => super::v1::get_task,
         put "/tasks/{id}" => super::v1::update_task,
         patch "/tasks/{id}" => super::v1::patch_task,
         delete "/tasks/{id}" => super::v1::delete_task,
         get "/tasks/{id}/transitions" => super::v1::get_task_transitions,
+        patch "/tasks/{id}/complete" => super::v1::complete_task,
+        post "/tasks/reparent" => super::v1::reparent_tasks,
         // Notes
         get "/notes" => super::v1::list_notes,
+        get "/notes/search" => super::v1::search_notes,
         get "/notes/{id}" => super::v1::get_note,
         post "/notes" => super::v1::create_note,
         put "/notes/{id}" => super::v1::update_note,
@@ -236,18 +267,35 @@ pub fn create_router<D: Database + 'static, G: crate::sync::GitOps + Send + Sync
         post "/sync/init" => super::v1::init_sync,
         post "/sync/export" => super::v1::export_sync,
         post "/sync/import" => super::v1::import_sync,
+        post "/sync/diff" => super::v1::diff_sync,
         get "/sync/status" => super::v1::get_sync_status,
         get "/task-lists/{id}/stats" => super::v1::get_task_list_stats,
+        // Search
+        get "/search" => super::v1::search,
     });
 
     let mut router = system_routes
         .nest("/api/v1", v1_routes)
         .nest_service("/mcp", mcp_service); // MCP server endpoint
 
-    // Conditionally add OpenAPI docs endpoint
+    // Conditionally add OpenAPI docs endpoints
     if enable_docs {
-        let api = ApiDoc::openapi();
-        router = router.merge(Scalar::with_url("/docs", api));
+        let docs_routes = Router::new()
+            .route("/docs", get(handlers::docs))
+            .route(handlers::OPENAPI_JSON_PATH, get(handlers::openapi_json))
+            .with_state(handlers::ApiDocs::new(ApiDoc::openapi()));
+        router = router.merge(docs_routes);
+    }
+
+    // Metrics stay outside /api/v1; the layer goes on last so it times every
+    // route, keyed by the matched path
+    if state.metrics().is_some() {
+        router = router
+            .route("/metrics", get(super::metrics::metrics::<D, G>))
+            .route_layer(axum::middleware::from_fn_with_state(
+                state.clone(),
+                super::metrics::track::<D, G>,
+            ));
     }
 
     #[cfg(feature = "embed-frontend")]
@@ -0,0 +1,93 @@
+//! Tests for router-level behaviour (docs mounting and negotiation).
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode, header},
+};
+use http_body_util::BodyExt;
+use std::sync::Arc;
+use tempfile::TempDir;
+use tower::ServiceExt;
+
+use crate::a6s::store::surrealdb;
+use crate::api::{AppState, routes};
+use crate::db::{Database, SqliteDatabase};
+
+async fn test_app(enable_docs: bool) -> axum::Router {
+    let db = SqliteDatabase::in_memory().await.unwrap();
+    db.migrate().unwrap();
+    let temp_dir = TempDir::new().unwrap();
+    let analysis_db = Arc::new(surrealdb::init_db(None).await.unwrap());
+    let state = AppState::new(
+        db,
+        crate::sync::SyncManager::new(crate::sync::MockGitOps::new()),
+        crate::api::notifier::ChangeNotifier::new(),
+        temp_dir.path().join("skills"),
+        analysis_db,
+        crate::a6s::tracker::AnalysisTracker::new(crate::api::notifier::ChangeNotifier::new()),
+    );
+    routes::create_router(state, enable_docs)
+}
+
+async fn get(app: axum::Router, uri: &str, accept: &str) -> axum::response::Response {
+    app.oneshot(
+        Request::builder()
+            .uri(uri)
+            .header(header::ACCEPT, accept)
+            .body(Body::empty())
+            .unwrap(),
+    )
+    .await
+    .unwrap()
+}
+
+fn content_type(response: &axum::response::Response) -> String {
+    response
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or_default()
+        .to_string()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn docs_negotiates_html_and_json() {
+    let app = test_app(true).await;
+
+    let response = get(
+        app.clone(),
+        "/docs",
+        "text/html,application/xhtml+xml;q=0.9,*/*;q=0.8",
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(content_type(&response).starts_with("text/html"));
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    assert!(String::from_utf8_lossy(&body).contains("<html"));
+
+    let response = get(app.clone(), "/docs", "application/json").await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(content_type(&response).starts_with("application/json"));
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let spec: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert!(spec["openapi"].is_string());
+    assert!(spec["paths"]["/api/v1/projects"].is_object());
+
+    // The raw spec endpoint serves the same document
+    let response = get(app, "/docs/openapi.json", "*/*").await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let raw: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(raw, spec);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn docs_not_mounted_when_disabled() {
+    let app = test_app(false).await;
+
+    let response = get(app.clone(), "/docs", "text/html").await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    let response = get(app, "/docs/openapi.json", "application/json").await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
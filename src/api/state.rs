@@ -3,10 +3,12 @@
 use std::path::PathBuf;
 use std::sync::Arc;
 
+use super::metrics::Metrics;
 use super::notifier::ChangeNotifier;
 use crate::a6s::store::surrealdb;
 use crate::a6s::tracker::AnalysisTracker;
-use crate::db::Database;
+use crate::db::clock::{Clock, SystemClock};
+use crate::db::{Database, NOTE_HARD_MAX, TaskStatus};
 use crate::sync::{GitOps, SyncManager};
 
 /// Shared application state.
@@ -27,6 +29,13 @@ pub struct AppState<D: Database, G: GitOps + Send + Sync> {
     skills_dir: PathBuf,
     analysis_db: Arc<surrealdb::SurrealDbConnection>,
     tracker: AnalysisTracker,
+    default_project: Option<String>,
+    max_note_size: usize,
+    auto_complete_parents: bool,
+    default_task_status: TaskStatus,
+    lowercase_tags: bool,
+    clock: Arc<dyn Clock>,
+    metrics: Option<Arc<Metrics>>,
 }
 
 impl<D: Database, G: GitOps + Send + Sync> Clone for AppState<D, G> {
@@ -38,6 +47,13 @@ impl<D: Database, G: GitOps + Send + Sync> Clone for AppState<D, G> {
             skills_dir: self.skills_dir.clone(),
             analysis_db: Arc::clone(&self.analysis_db),
             tracker: self.tracker.clone(),
+            default_project: self.default_project.clone(),
+            max_note_size: self.max_note_size,
+            auto_complete_parents: self.auto_complete_parents,
+            default_task_status: self.default_task_status.clone(),
+            lowercase_tags: self.lowercase_tags,
+            clock: Arc::clone(&self.clock),
+            metrics: self.metrics.clone(),
         }
     }
 }
@@ -58,9 +74,58 @@ impl<D: Database, G: GitOps + Send + Sync> AppState<D, G> {
             skills_dir,
             analysis_db,
             tracker,
+            default_project: None,
+            max_note_size: NOTE_HARD_MAX,
+            auto_complete_parents: false,
+            default_task_status: TaskStatus::Todo,
+            lowercase_tags: false,
+            clock: Arc::new(SystemClock),
+            metrics: None,
         }
     }
 
+    /// Set the project used when a task list is created without one.
+    pub fn with_default_project(mut self, project_id: Option<String>) -> Self {
+        self.default_project = project_id;
+        self
+    }
+
+    /// Set the largest note content (in bytes) the API accepts.
+    pub fn with_max_note_size(mut self, max_note_size: usize) -> Self {
+        self.max_note_size = max_note_size;
+        self
+    }
+
+    /// Complete a parent task once all of its subtasks are finished.
+    pub fn with_auto_complete_parents(mut self, enabled: bool) -> Self {
+        self.auto_complete_parents = enabled;
+        self
+    }
+
+    /// Set the status given to tasks created without one.
+    pub fn with_default_task_status(mut self, status: TaskStatus) -> Self {
+        self.default_task_status = status;
+        self
+    }
+
+    /// Fold tags to lowercase when entities are created or updated.
+    pub fn with_lowercase_tags(mut self, enabled: bool) -> Self {
+        self.lowercase_tags = enabled;
+        self
+    }
+
+    /// Collect request metrics and serve them at `/metrics`.
+    pub fn with_metrics(mut self, enabled: bool) -> Self {
+        self.metrics = enabled.then(|| Arc::new(Metrics::new()));
+        self
+    }
+
+    /// Replace the system clock, e.g. with a `MockClock` in tests.
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
     pub fn db(&self) -> &D {
         &self.db
     }
@@ -88,4 +153,31 @@ impl<D: Database, G: GitOps + Send + Sync> AppState<D, G> {
     pub fn tracker(&self) -> &AnalysisTracker {
         &self.tracker
     }
+
+    pub fn default_project(&self) -> Option<&str> {
+        self.default_project.as_deref()
+    }
+
+    pub fn max_note_size(&self) -> usize {
+        self.max_note_size
+    }
+
+    pub fn auto_complete_parents(&self) -> bool {
+        self.auto_complete_parents
+    }
+    pub fn default_task_status(&self) -> &TaskStatus {
+        &self.default_task_status
+    }
+
+    pub fn lowercase_tags(&self) -> bool {
+        self.lowercase_tags
+    }
+
+    pub fn clock(&self) -> &dyn Clock {
+        self.clock.as_ref()
+    }
+
+    pub fn metrics(&self) -> Option<&Metrics> {
+        self.metrics.as_deref()
+    }
 }
@@ -0,0 +1,64 @@
+//! Weak ETags and conditional GET for single-entity endpoints.
+//!
+//! The tag is a hash of the serialized response, so it changes whenever
+//! anything in the body does, including linked IDs that don't bump
+//! `updated_at` (and repos, which have no `updated_at` at all).
+
+use axum::{
+    Json,
+    http::{HeaderMap, StatusCode, header},
+    response::{IntoResponse, Response},
+};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+use super::ErrorResponse;
+
+/// Weak ETag for a serialized response body.
+pub(crate) fn weak_etag(body: &[u8]) -> String {
+    let hash = Sha256::digest(body);
+    let hex: String = hash[..8].iter().map(|b| format!("{:02x}", b)).collect();
+    format!("W/\"{}\"", hex)
+}
+
+/// Whether the request's `If-None-Match` names `etag` (weak comparison).
+fn if_none_match(headers: &HeaderMap, etag: &str) -> bool {
+    let opaque = |tag: &str| tag.trim().trim_start_matches("W/").to_string();
+    headers
+        .get_all(header::IF_NONE_MATCH)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .any(|tag| tag.trim() == "*" || opaque(tag) == opaque(etag))
+}
+
+/// Respond with `body` as JSON tagged with a weak ETag, or with an empty
+/// 304 Not Modified when the client's `If-None-Match` already matches it.
+pub(crate) fn conditional_json<T: Serialize>(headers: &HeaderMap, body: &T) -> Response {
+    let bytes = match serde_json::to_vec(body) {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: e.to_string(),
+                }),
+            )
+                .into_response();
+        }
+    };
+    let etag = weak_etag(&bytes);
+
+    if if_none_match(headers, &etag) {
+        return (StatusCode::NOT_MODIFIED, [(header::ETAG, etag)]).into_response();
+    }
+
+    (
+        [
+            (header::CONTENT_TYPE, "application/json".to_string()),
+            (header::ETAG, etag),
+        ],
+        bytes,
+    )
+        .into_response()
+}
@@ -0,0 +1,183 @@
+//! Tests for ETags and conditional GET.
+
+use axum::{
+    body::Body,
+    http::{HeaderMap, HeaderValue, Request, StatusCode, header},
+};
+use http_body_util::BodyExt;
+use serde_json::{Value, json};
+use std::sync::Arc;
+use tempfile::TempDir;
+use tower::ServiceExt;
+
+use super::etag::{conditional_json, weak_etag};
+use crate::a6s::store::surrealdb;
+use crate::api::{AppState, routes};
+use crate::db::{Database, SqliteDatabase};
+
+async fn test_app() -> axum::Router {
+    let db = SqliteDatabase::in_memory().await.unwrap();
+    db.migrate().unwrap();
+    let temp_dir = TempDir::new().unwrap();
+    let state = AppState::new(
+        db,
+        crate::sync::SyncManager::new(crate::sync::MockGitOps::new()),
+        crate::api::notifier::ChangeNotifier::new(),
+        temp_dir.path().join("skills"),
+        Arc::new(surrealdb::init_db(None).await.unwrap()),
+        crate::a6s::tracker::AnalysisTracker::new(crate::api::notifier::ChangeNotifier::new()),
+    );
+    routes::create_router(state, false)
+}
+
+async fn send(
+    app: &axum::Router,
+    method: &str,
+    uri: &str,
+    body: Option<Value>,
+    if_none_match: Option<&str>,
+) -> axum::response::Response {
+    let mut request = Request::builder().method(method).uri(uri);
+    if let Some(etag) = if_none_match {
+        request = request.header(header::IF_NONE_MATCH, etag);
+    }
+    let body = match body {
+        Some(json) => {
+            request = request.header(header::CONTENT_TYPE, "application/json");
+            Body::from(serde_json::to_vec(&json).unwrap())
+        }
+        None => Body::empty(),
+    };
+    app.clone()
+        .oneshot(request.body(body).unwrap())
+        .await
+        .unwrap()
+}
+
+async fn body_bytes(response: axum::response::Response) -> Vec<u8> {
+    response
+        .into_body()
+        .collect()
+        .await
+        .unwrap()
+        .to_bytes()
+        .to_vec()
+}
+
+fn etag_of(response: &axum::response::Response) -> String {
+    response.headers()[header::ETAG]
+        .to_str()
+        .unwrap()
+        .to_string()
+}
+
+#[test]
+fn weak_etag_is_stable_and_content_sensitive() {
+    let etag = weak_etag(b"{\"id\":\"a\"}");
+    assert!(etag.starts_with("W/\"") && etag.ends_with('"'));
+    assert_eq!(etag, weak_etag(b"{\"id\":\"a\"}"));
+    assert_ne!(etag, weak_etag(b"{\"id\":\"b\"}"));
+}
+
+#[tokio::test]
+async fn if_none_match_accepts_lists_strong_form_and_wildcard() {
+    let body = json!({"id": "abcd1234"});
+    let etag = weak_etag(&serde_json::to_vec(&body).unwrap());
+    let strong = etag.trim_start_matches("W/").to_string();
+
+    for value in [
+        etag.clone(),
+        strong,
+        format!("W/\"other\", {}", etag),
+        "*".to_string(),
+    ] {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::IF_NONE_MATCH,
+            HeaderValue::from_str(&value).unwrap(),
+        );
+        let response = conditional_json(&headers, &body);
+        assert_eq!(response.status(), StatusCode::NOT_MODIFIED, "{}", value);
+        assert!(body_bytes(response).await.is_empty());
+    }
+
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        header::IF_NONE_MATCH,
+        HeaderValue::from_static("W/\"stale\""),
+    );
+    let response = conditional_json(&headers, &body);
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(etag_of(&response), etag);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn get_project_supports_conditional_requests() {
+    let app = test_app().await;
+    let created = send(
+        &app,
+        "POST",
+        "/api/v1/projects",
+        Some(json!({"title": "Cached"})),
+        None,
+    )
+    .await;
+    let id: Value = serde_json::from_slice(&body_bytes(created).await).unwrap();
+    let uri = format!("/api/v1/projects/{}", id["id"].as_str().unwrap());
+
+    let first = send(&app, "GET", &uri, None, None).await;
+    assert_eq!(first.status(), StatusCode::OK);
+    let etag = etag_of(&first);
+    let project: Value = serde_json::from_slice(&body_bytes(first).await).unwrap();
+    assert_eq!(project["title"], "Cached");
+
+    let unchanged = send(&app, "GET", &uri, None, Some(&etag)).await;
+    assert_eq!(unchanged.status(), StatusCode::NOT_MODIFIED);
+    assert_eq!(etag_of(&unchanged), etag);
+    assert!(body_bytes(unchanged).await.is_empty());
+
+    // Any change to the entity yields a new tag and a full response
+    send(&app, "PATCH", &uri, Some(json!({"title": "Renamed"})), None).await;
+    let changed = send(&app, "GET", &uri, None, Some(&etag)).await;
+    assert_eq!(changed.status(), StatusCode::OK);
+    assert_ne!(etag_of(&changed), etag);
+    let project: Value = serde_json::from_slice(&body_bytes(changed).await).unwrap();
+    assert_eq!(project["title"], "Renamed");
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn entity_gets_return_etags() {
+    let app = test_app().await;
+    let project = send(
+        &app,
+        "POST",
+        "/api/v1/projects",
+        Some(json!({"title": "Owner"})),
+        None,
+    )
+    .await;
+    let project: Value = serde_json::from_slice(&body_bytes(project).await).unwrap();
+    let project_id = project["id"].as_str().unwrap();
+
+    let creates = [
+        ("/api/v1/repos", json!({"remote": "github:acme/etag"})),
+        (
+            "/api/v1/task-lists",
+            json!({"title": "List", "project_id": project_id}),
+        ),
+        ("/api/v1/notes", json!({"title": "Note", "content": "body"})),
+    ];
+    for (create_uri, payload) in creates {
+        let created = send(&app, "POST", create_uri, Some(payload), None).await;
+        assert_eq!(created.status(), StatusCode::CREATED, "{}", create_uri);
+        let created: Value = serde_json::from_slice(&body_bytes(created).await).unwrap();
+        let uri = format!("{}/{}", create_uri, created["id"].as_str().unwrap());
+
+        let first = send(&app, "GET", &uri, None, None).await;
+        assert_eq!(first.status(), StatusCode::OK, "{}", uri);
+        let etag = etag_of(&first);
+
+        let again = send(&app, "GET", &uri, None, Some(&etag)).await;
+        assert_eq!(again.status(), StatusCode::NOT_MODIFIED, "{}", uri);
+    }
+}
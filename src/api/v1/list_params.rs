@@ -0,0 +1,35 @@
+//! Extractor for the list parameters shared by every list endpoint.
+
+use std::collections::HashMap;
+
+use axum::{
+    Json,
+    extract::{FromRequestParts, Query},
+    http::{StatusCode, request::Parts},
+};
+
+use super::ErrorResponse;
+use crate::db::ListQuery;
+use crate::db::pagination::LIST_LIMITS;
+
+/// `limit`/`offset`/`sort`/`order`/`tags` parsed with [`LIST_LIMITS`].
+///
+/// Malformed values are rejected with `400 Bad Request` before the handler
+/// runs. Entity-specific filters stay on each handler's own query struct.
+#[derive(Debug, Clone)]
+pub struct ListParams(pub ListQuery);
+
+impl<S: Send + Sync> FromRequestParts<S> for ListParams {
+    type Rejection = (StatusCode, Json<ErrorResponse>);
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let bad_request = |error: String| (StatusCode::BAD_REQUEST, Json(ErrorResponse { error }));
+
+        let Query(params) = Query::<HashMap<String, String>>::try_from_uri(&parts.uri)
+            .map_err(|e| bad_request(e.body_text()))?;
+
+        ListQuery::from_params(&params, LIST_LIMITS)
+            .map(ListParams)
+            .map_err(|e| bad_request(e.to_string()))
+    }
+}
@@ -1,14 +1,20 @@
 //! V1 API handlers.
 
+mod etag;
 mod graph;
+mod list_params;
 mod notes;
 mod projects;
 mod repos;
+mod search;
 mod skills;
+mod sorting;
 mod sync;
 mod task_lists;
 mod tasks;
 
+#[cfg(test)]
+mod etag_test;
 #[cfg(test)]
 mod graph_test;
 #[cfg(test)]
@@ -18,8 +24,12 @@ mod projects_test;
 #[cfg(test)]
 mod repos_test;
 #[cfg(test)]
+mod search_test;
+#[cfg(test)]
 mod skills_test;
 #[cfg(test)]
+mod sorting_test;
+#[cfg(test)]
 mod task_lists_test;
 #[cfg(test)]
 mod tasks_test;
@@ -28,6 +38,7 @@ pub use graph::*;
 pub use notes::*;
 pub use projects::*;
 pub use repos::*;
+pub use search::*;
 pub use skills::*;
 pub use sync::*;
 pub use task_lists::*;
@@ -4,17 +4,23 @@ use crate::sync::GitOps;
 use axum::{
     Json,
     extract::{Path, Query, State},
-    http::StatusCode,
+    http::{HeaderMap, StatusCode},
+    response::Response,
 };
 use serde::{Deserialize, Serialize};
 use tracing::instrument;
 use utoipa::{IntoParams, ToSchema};
 
 use crate::api::AppState;
-use crate::api::notifier::UpdateMessage;
-use crate::db::{Database, DbError, Note, NoteQuery, NoteRepository, PageSort, SortOrder};
+use crate::api::notifier::{UpdateMessage, changed_fields};
+use crate::db::pagination::DEFAULT_PAGE_SIZE;
+use crate::db::utils::normalize_tags;
+use crate::db::{Database, DbError, Note, NoteQuery, NoteRepository};
 
-use super::ErrorResponse;
+use super::etag::conditional_json;
+use super::list_params::ListParams;
+use super::sorting::{NOTE_SORT, page_sort};
+use super::{DeleteQuery, ErrorResponse};
 
 // =============================================================================
 // DTOs
@@ -67,6 +73,13 @@ impl From<Note> for NoteResponse {
     }
 }
 
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct CreateNoteQuery {
+    /// Return an existing note with identical content instead of creating one
+    #[serde(default)]
+    pub dedup: bool,
+}
+
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct CreateNoteRequest {
     #[schema(example = "My Note")]
@@ -191,7 +204,7 @@ pub struct ListNotesQuery {
     /// Number of items to skip
     #[param(example = 0)]
     pub offset: Option<usize>,
-    /// Field to sort by (title, note_type, created_at, updated_at, last_activity_at)
+    /// Field to sort by (title, note_type, created_at, updated_at, last_activity_at; default updated_at desc)
     #[param(example = "created_at")]
     pub sort: Option<String>,
     /// Sort order (asc, desc)
@@ -207,6 +220,56 @@ pub struct PaginatedNotes {
     pub offset: usize,
 }
 
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct SearchNotesQuery {
+    /// FTS5 search query
+    #[param(example = "rust programming")]
+    pub q: String,
+    /// Filter by tags (comma-separated)
+    #[param(example = "api,session")]
+    pub tags: Option<String>,
+    /// Filter by project ID
+    #[param(example = "a1b2c3d4")]
+    pub project_id: Option<String>,
+    /// Filter by parent note ID to search subnotes
+    #[param(example = "parent123")]
+    pub parent_id: Option<String>,
+    /// Filter by note type: "note" (parent notes only) or "subnote" (subnotes only)
+    #[param(example = "note")]
+    #[serde(rename = "type")]
+    pub note_type: Option<String>,
+    /// Maximum number of items to return
+    #[param(example = 20)]
+    pub limit: Option<usize>,
+    /// Number of items to skip
+    #[param(example = 0)]
+    pub offset: Option<usize>,
+    /// Field to sort by (title, created_at, updated_at, last_activity_at; default updated_at desc)
+    #[param(example = "created_at")]
+    pub sort: Option<String>,
+    /// Sort order (asc, desc)
+    #[param(example = "desc")]
+    pub order: Option<String>,
+}
+
+/// A search hit: the note plus the fragment that matched
+#[derive(Serialize, ToSchema)]
+pub struct NoteSearchHit {
+    pub note: NoteResponse,
+    /// Matched fragment with hits wrapped in `<mark>` tags. Note text is not
+    /// HTML-escaped.
+    #[schema(example = "uses <mark>tokio</mark> for async IO")]
+    pub snippet: String,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct PaginatedNoteSearchResults {
+    pub items: Vec<NoteSearchHit>,
+    pub total: usize,
+    pub limit: usize,
+    pub offset: usize,
+}
+
 // =============================================================================
 // Handlers
 // =============================================================================
@@ -218,6 +281,7 @@ pub struct PaginatedNotes {
     params(ListNotesQuery),
     responses(
         (status = 200, description = "Paginated list of notes", body = PaginatedNotes),
+        (status = 400, description = "Invalid list parameters", body = ErrorResponse),
         (status = 500, description = "Internal server error", body = ErrorResponse)
     )
 )]
@@ -225,6 +289,7 @@ pub struct PaginatedNotes {
 pub async fn list_notes<D: Database, G: GitOps + Send + Sync>(
     State(state): State<AppState<D, G>>,
     Query(query): Query<ListNotesQuery>,
+    ListParams(list): ListParams,
 ) -> Result<Json<PaginatedNotes>, (StatusCode, Json<ErrorResponse>)> {
     let internal_error = |e: crate::db::DbError| {
         (
@@ -235,26 +300,14 @@ pub async fn list_notes<D: Database, G: GitOps + Send + Sync>(
         )
     };
 
-    // Build database query with tag filtering at DB level
-    let tags = query.tags.as_ref().map(|t| {
-        t.split(',')
-            .map(|s| s.trim().to_string())
-            .filter(|s| !s.is_empty())
-            .collect::<Vec<_>>()
-    });
+    // Subnotes keep their manual `idx` order and `type=note` its last-activity
+    // order; the repository applies both when no sort is given.
+    let note_default = (query.parent_id.is_none() && query.note_type.as_deref() != Some("note"))
+        .then_some(NOTE_SORT);
 
     let db_query = NoteQuery {
-        page: PageSort {
-            limit: query.limit,
-            offset: query.offset,
-            sort_by: query.sort.clone(),
-            sort_order: match query.order.as_deref() {
-                Some("desc") => Some(SortOrder::Desc),
-                Some("asc") => Some(SortOrder::Asc),
-                _ => None,
-            },
-        },
-        tags,
+        page: page_sort(note_default, &list),
+        tags: list.tags.clone(),
         project_id: query.project_id.clone(),
         parent_id: query.parent_id.clone(),
         note_type: query.note_type.clone(),
@@ -267,8 +320,9 @@ pub async fn list_notes<D: Database, G: GitOps + Send + Sync>(
             crate::db::ListResult {
                 items: vec![],
                 total: 0,
-                limit: query.limit,
-                offset: query.offset.unwrap_or(0),
+                limit: list.limit,
+                offset: list.offset.unwrap_or(0),
+                next_cursor: None,
             }
         } else {
             state
@@ -292,7 +346,67 @@ pub async fn list_notes<D: Database, G: GitOps + Send + Sync>(
     Ok(Json(PaginatedNotes {
         items,
         total: result.total,
-        limit: result.limit.unwrap_or(50),
+        limit: result.limit.unwrap_or(DEFAULT_PAGE_SIZE),
+        offset: result.offset,
+    }))
+}
+
+/// Search notes with highlighted snippets
+///
+/// Full-text search like `GET /notes?q=`, but each hit carries the matched
+/// fragment instead of relying on the full content.
+#[utoipa::path(
+    get,
+    path = "/api/v1/notes/search",
+    tag = "notes",
+    params(SearchNotesQuery),
+    responses(
+        (status = 200, description = "Paginated search hits", body = PaginatedNoteSearchResults),
+        (status = 400, description = "Invalid query parameters", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+#[instrument(skip(state))]
+pub async fn search_notes<D: Database, G: GitOps + Send + Sync>(
+    State(state): State<AppState<D, G>>,
+    Query(query): Query<SearchNotesQuery>,
+    ListParams(list): ListParams,
+) -> Result<Json<PaginatedNoteSearchResults>, (StatusCode, Json<ErrorResponse>)> {
+    let db_query = NoteQuery {
+        page: page_sort(Some(NOTE_SORT), &list),
+        tags: list.tags.clone(),
+        project_id: query.project_id.clone(),
+        parent_id: query.parent_id.clone(),
+        note_type: query.note_type.clone(),
+    };
+
+    let result = state
+        .db()
+        .notes()
+        .search_snippets(&query.q, Some(&db_query))
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: e.to_string(),
+                }),
+            )
+        })?;
+
+    let items = result
+        .items
+        .into_iter()
+        .map(|hit| NoteSearchHit {
+            note: NoteResponse::from(hit.note),
+            snippet: hit.snippet,
+        })
+        .collect();
+
+    Ok(Json(PaginatedNoteSearchResults {
+        items,
+        total: result.total,
+        limit: result.limit.unwrap_or(DEFAULT_PAGE_SIZE),
         offset: result.offset,
     }))
 }
@@ -304,6 +418,7 @@ pub async fn list_notes<D: Database, G: GitOps + Send + Sync>(
     params(("id" = String, Path, description = "Note ID")),
     responses(
         (status = 200, description = "Note found", body = NoteResponse),
+        (status = 304, description = "Not modified (If-None-Match matched the ETag)"),
         (status = 404, description = "Note not found", body = ErrorResponse),
         (status = 500, description = "Internal server error", body = ErrorResponse)
     )
@@ -312,7 +427,8 @@ pub async fn list_notes<D: Database, G: GitOps + Send + Sync>(
 pub async fn get_note<D: Database, G: GitOps + Send + Sync>(
     State(state): State<AppState<D, G>>,
     Path(id): Path<String>,
-) -> Result<Json<NoteResponse>, (StatusCode, Json<ErrorResponse>)> {
+    headers: HeaderMap,
+) -> Result<Response, (StatusCode, Json<ErrorResponse>)> {
     let note = state.db().notes().get(&id).await.map_err(|e| match e {
         DbError::NotFound { .. } => (
             StatusCode::NOT_FOUND,
@@ -328,30 +444,57 @@ pub async fn get_note<D: Database, G: GitOps + Send + Sync>(
         ),
     })?;
 
-    Ok(Json(NoteResponse::from(note)))
+    Ok(conditional_json(&headers, &NoteResponse::from(note)))
 }
 
 #[utoipa::path(
     post,
     path = "/api/v1/notes",
     tag = "notes",
+    params(CreateNoteQuery),
     request_body = CreateNoteRequest,
     responses(
+        (status = 200, description = "With dedup: an existing note has identical content and is returned instead", body = NoteResponse),
         (status = 201, description = "Note created", body = NoteResponse),
+        (status = 404, description = "Linked repo or project not found", body = ErrorResponse),
+        (status = 422, description = "Invalid link (e.g. note is its own parent) or content too large", body = ErrorResponse),
         (status = 500, description = "Internal server error", body = ErrorResponse)
     )
 )]
 #[instrument(skip(state))]
 pub async fn create_note<D: Database, G: GitOps + Send + Sync>(
     State(state): State<AppState<D, G>>,
+    Query(query): Query<CreateNoteQuery>,
     Json(req): Json<CreateNoteRequest>,
 ) -> Result<(StatusCode, Json<NoteResponse>), (StatusCode, Json<ErrorResponse>)> {
+    check_content_size(&req.content, state.max_note_size())?;
+
+    // A match is reported with 200 instead of 201; nothing is written
+    if query.dedup {
+        let existing = state
+            .db()
+            .notes()
+            .find_by_content(&req.content)
+            .await
+            .map_err(|e| {
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(ErrorResponse {
+                        error: e.to_string(),
+                    }),
+                )
+            })?;
+        if let Some(note) = existing {
+            return Ok((StatusCode::OK, Json(NoteResponse::from(note))));
+        }
+    }
+
     // Create note with placeholder values - repository will generate ID and timestamps
     let note = Note {
         id: String::new(), // Repository will generate this
         title: req.title,
         content: req.content,
-        tags: req.tags,
+        tags: normalize_tags(req.tags, state.lowercase_tags()),
         parent_id: req.parent_id,
         idx: req.idx,
         repo_ids: req.repo_ids,
@@ -361,14 +504,7 @@ pub async fn create_note<D: Database, G: GitOps + Send + Sync>(
         updated_at: None, // Repository will generate this
     };
 
-    let created_note = state.db().notes().create(&note).await.map_err(|e| {
-        (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ErrorResponse {
-                error: e.to_string(),
-            }),
-        )
-    })?;
+    let created_note = state.db().notes().create(&note).await.map_err(link_error)?;
 
     // Broadcast notification
     state.notifier().notify(UpdateMessage::NoteCreated {
@@ -386,7 +522,8 @@ pub async fn create_note<D: Database, G: GitOps + Send + Sync>(
     request_body = UpdateNoteRequest,
     responses(
         (status = 200, description = "Note updated", body = NoteResponse),
-        (status = 404, description = "Note not found", body = ErrorResponse),
+        (status = 404, description = "Note, linked repo or linked project not found", body = ErrorResponse),
+        (status = 422, description = "Invalid link (e.g. note is its own parent) or content too large", body = ErrorResponse),
         (status = 500, description = "Internal server error", body = ErrorResponse)
     )
 )]
@@ -396,6 +533,8 @@ pub async fn update_note<D: Database, G: GitOps + Send + Sync>(
     Path(id): Path<String>,
     Json(req): Json<UpdateNoteRequest>,
 ) -> Result<Json<NoteResponse>, (StatusCode, Json<ErrorResponse>)> {
+    check_content_size(&req.content, state.max_note_size())?;
+
     let mut note = state.db().notes().get(&id).await.map_err(|e| match e {
         DbError::NotFound { .. } => (
             StatusCode::NOT_FOUND,
@@ -410,10 +549,11 @@ pub async fn update_note<D: Database, G: GitOps + Send + Sync>(
             }),
         ),
     })?;
+    let before = note.clone();
 
     note.title = req.title;
     note.content = req.content;
-    note.tags = req.tags;
+    note.tags = normalize_tags(req.tags, state.lowercase_tags());
     note.parent_id = req.parent_id;
     note.idx = req.idx;
     note.repo_ids = req.repo_ids;
@@ -421,18 +561,12 @@ pub async fn update_note<D: Database, G: GitOps + Send + Sync>(
     // Clear updated_at to ensure proper timestamp refresh on PUT
     note.updated_at = None;
 
-    state.db().notes().update(&note).await.map_err(|e| {
-        (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ErrorResponse {
-                error: e.to_string(),
-            }),
-        )
-    })?;
+    state.db().notes().update(&note).await.map_err(link_error)?;
 
     // Broadcast notification
     state.notifier().notify(UpdateMessage::NoteUpdated {
         note_id: note.id.clone(),
+        changed: changed_fields(&before, &note),
     });
 
     Ok(Json(NoteResponse::from(note)))
@@ -446,7 +580,8 @@ pub async fn update_note<D: Database, G: GitOps + Send + Sync>(
     request_body = PatchNoteRequest,
     responses(
         (status = 200, description = "Note partially updated", body = NoteResponse),
-        (status = 404, description = "Note not found", body = ErrorResponse),
+        (status = 404, description = "Note, linked repo or linked project not found", body = ErrorResponse),
+        (status = 422, description = "Invalid link (e.g. note is its own parent) or content too large", body = ErrorResponse),
         (status = 500, description = "Internal server error", body = ErrorResponse)
     )
 )]
@@ -454,8 +589,12 @@ pub async fn update_note<D: Database, G: GitOps + Send + Sync>(
 pub async fn patch_note<D: Database, G: GitOps + Send + Sync>(
     State(state): State<AppState<D, G>>,
     Path(id): Path<String>,
-    Json(req): Json<PatchNoteRequest>,
+    Json(mut req): Json<PatchNoteRequest>,
 ) -> Result<Json<NoteResponse>, (StatusCode, Json<ErrorResponse>)> {
+    if let Some(content) = &req.content {
+        check_content_size(content, state.max_note_size())?;
+    }
+
     // Fetch existing note
     let mut note = state.db().notes().get(&id).await.map_err(|e| match e {
         DbError::NotFound { .. } => (
@@ -472,21 +611,19 @@ pub async fn patch_note<D: Database, G: GitOps + Send + Sync>(
         ),
     })?;
 
+    let before = note.clone();
+
     // Merge PATCH changes
+    req.tags = req
+        .tags
+        .map(|tags| normalize_tags(tags, state.lowercase_tags()));
     req.merge_into(&mut note);
 
     // Clear updated_at to ensure proper timestamp refresh on PATCH
     note.updated_at = None;
 
     // Save
-    state.db().notes().update(&note).await.map_err(|e| {
-        (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ErrorResponse {
-                error: e.to_string(),
-            }),
-        )
-    })?;
+    state.db().notes().update(&note).await.map_err(link_error)?;
 
     // Re-fetch to get auto-generated updated_at timestamp
     let note = state.db().notes().get(&id).await.map_err(|e| {
@@ -501,6 +638,7 @@ pub async fn patch_note<D: Database, G: GitOps + Send + Sync>(
     // Broadcast notification
     state.notifier().notify(UpdateMessage::NoteUpdated {
         note_id: note.id.clone(),
+        changed: changed_fields(&before, &note),
     });
 
     Ok(Json(NoteResponse::from(note)))
@@ -510,7 +648,10 @@ pub async fn patch_note<D: Database, G: GitOps + Send + Sync>(
     delete,
     path = "/api/v1/notes/{id}",
     tag = "notes",
-    params(("id" = String, Path, description = "Note ID")),
+    params(
+        ("id" = String, Path, description = "Note ID"),
+        DeleteQuery
+    ),
     responses(
         (status = 204, description = "Note deleted"),
         (status = 404, description = "Note not found", body = ErrorResponse),
@@ -521,8 +662,15 @@ pub async fn patch_note<D: Database, G: GitOps + Send + Sync>(
 pub async fn delete_note<D: Database, G: GitOps + Send + Sync>(
     State(state): State<AppState<D, G>>,
     Path(id): Path<String>,
+    Query(query): Query<DeleteQuery>,
 ) -> Result<StatusCode, (StatusCode, Json<ErrorResponse>)> {
-    state.db().notes().delete(&id).await.map_err(|e| match e {
+    let notes = state.db().notes();
+    let result = if query.purge {
+        notes.purge(&id).await
+    } else {
+        notes.delete(&id).await
+    };
+    result.map_err(|e| match e {
         DbError::NotFound { .. } => (
             StatusCode::NOT_FOUND,
             Json(ErrorResponse {
@@ -548,3 +696,44 @@ pub async fn delete_note<D: Database, G: GitOps + Send + Sync>(
 // =============================================================================
 // Helpers
 // =============================================================================
+
+/// Map errors from writing a note and its links to HTTP responses.
+///
+/// A missing linked entity is a 404, an invalid link (such as a note being its
+/// own parent) is a 422.
+fn link_error(e: DbError) -> (StatusCode, Json<ErrorResponse>) {
+    let status = match e {
+        DbError::NotFound { .. } => StatusCode::NOT_FOUND,
+        DbError::Validation { .. } => StatusCode::UNPROCESSABLE_ENTITY,
+        _ => StatusCode::INTERNAL_SERVER_ERROR,
+    };
+    (
+        status,
+        Json(ErrorResponse {
+            error: e.to_string(),
+        }),
+    )
+}
+
+/// Reject note content larger than the server's configured limit.
+///
+/// Size is measured in UTF-8 bytes, the same unit the store enforces.
+fn check_content_size(
+    content: &str,
+    limit: usize,
+) -> Result<(), (StatusCode, Json<ErrorResponse>)> {
+    let size = content.len();
+    if size > limit {
+        return Err((
+            StatusCode::UNPROCESSABLE_ENTITY,
+            Json(ErrorResponse {
+                error: format!(
+                    "Note content is {} bytes, which exceeds the limit of {} bytes. \
+                     Split it into sub-notes using parent_id.",
+                    size, limit
+                ),
+            }),
+        ));
+    }
+    Ok(())
+}
@@ -646,6 +646,63 @@ async fn fts5_search() {
     assert_eq!(body["total"], 2);
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn search_returns_snippets() {
+    let app = test_app().await;
+
+    app.clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/v1/notes")
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    serde_json::to_vec(&json!({
+                        "title": "Rust Programming",
+                        "content": "Learning Axum routing and extractors",
+                        "tags": ["rust"]
+                    }))
+                    .unwrap(),
+                ))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri("/api/v1/notes/search?q=axum")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = json_body(response).await;
+    assert_eq!(body["total"], 1);
+    assert_eq!(body["items"][0]["note"]["title"], "Rust Programming");
+    assert!(
+        body["items"][0]["snippet"]
+            .as_str()
+            .unwrap()
+            .contains("<mark>Axum</mark>")
+    );
+
+    // The query is required
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/api/v1/notes/search")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn websocket_broadcasts() {
     let (app, notifier) = test_app_with_notifier().await;
@@ -695,7 +752,8 @@ async fn websocket_broadcasts() {
     assert_eq!(
         msg,
         UpdateMessage::NoteUpdated {
-            note_id: note_id.clone()
+            note_id: note_id.clone(),
+            changed: vec!["content".to_string(), "title".to_string()],
         }
     );
 
@@ -713,3 +771,319 @@ async fn websocket_broadcasts() {
     let msg = rx.try_recv().expect("Should receive delete broadcast");
     assert_eq!(msg, UpdateMessage::NoteDeleted { note_id });
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn link_validation() {
+    let app = test_app().await;
+
+    let send = |method: &'static str, uri: String, body: Value| {
+        let app = app.clone();
+        async move {
+            app.oneshot(
+                Request::builder()
+                    .method(method)
+                    .uri(uri)
+                    .header("content-type", "application/json")
+                    .body(Body::from(body.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap()
+        }
+    };
+
+    // Linking a nonexistent repo is a 404, not a foreign key 500
+    let response = send(
+        "POST",
+        "/api/v1/notes".to_string(),
+        json!({"title": "Orphan", "content": "x", "repo_ids": ["nonexist"]}),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    let response = send(
+        "POST",
+        "/api/v1/repos".to_string(),
+        json!({"remote": "https://github.com/test/linked"}),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let repo_id = json_body(response).await["id"]
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    // Double-linking the same repo is idempotent
+    let response = send(
+        "POST",
+        "/api/v1/notes".to_string(),
+        json!({"title": "Linked", "content": "x", "repo_ids": [repo_id, repo_id]}),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let note_id = json_body(response).await["id"]
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    for _ in 0..2 {
+        let response = send(
+            "PUT",
+            format!("/api/v1/notes/{}", note_id),
+            json!({"title": "Linked", "content": "x", "repo_ids": [repo_id]}),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri(format!("/api/v1/notes/{}", note_id))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(json_body(response).await["repo_ids"], json!([repo_id]));
+
+    // A note cannot be its own parent
+    let response = send(
+        "PATCH",
+        format!("/api/v1/notes/{}", note_id),
+        json!({"parent_id": note_id}),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+}
+
+// =============================================================================
+// Content Size Limit
+// =============================================================================
+
+async fn test_app_with_max_note_size(max_note_size: usize) -> axum::Router {
+    let db = SqliteDatabase::in_memory().await.unwrap();
+    db.migrate().unwrap();
+    let temp_dir = TempDir::new().unwrap();
+    let analysis_db = Arc::new(surrealdb::init_db(None).await.unwrap());
+
+    let state = AppState::new(
+        db,
+        crate::sync::SyncManager::new(crate::sync::MockGitOps::new()),
+        ChangeNotifier::new(),
+        temp_dir.path().join("skills"),
+        analysis_db,
+        crate::a6s::tracker::AnalysisTracker::new(ChangeNotifier::new()),
+    )
+    .with_max_note_size(max_note_size);
+    routes::create_router(state, false)
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn content_size_limit_counts_utf8_bytes() {
+    let app = test_app_with_max_note_size(16).await;
+
+    let post = |content: String| {
+        Request::builder()
+            .method("POST")
+            .uri("/api/v1/notes")
+            .header("content-type", "application/json")
+            .body(Body::from(
+                serde_json::to_vec(&json!({"title": "Sized", "content": content})).unwrap(),
+            ))
+            .unwrap()
+    };
+
+    // 8 × 'é' = 16 bytes: exactly at the limit
+    let response = app.clone().oneshot(post("é".repeat(8))).await.unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let note_id = json_body(response).await["id"]
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    // 9 × 'é' is only 9 characters but 18 bytes
+    let response = app.clone().oneshot(post("é".repeat(9))).await.unwrap();
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let error = json_body(response).await["error"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    assert!(error.contains("18 bytes"), "missing actual size: {}", error);
+    assert!(error.contains("16 bytes"), "missing limit: {}", error);
+
+    // PATCH with oversized content is rejected and leaves the note untouched
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("PATCH")
+                .uri(format!("/api/v1/notes/{}", note_id))
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    serde_json::to_vec(&json!({"content": "x".repeat(17)})).unwrap(),
+                ))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri(format!("/api/v1/notes/{}", note_id))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(json_body(response).await["content"], "é".repeat(8));
+}
+
+async fn test_app_with_lowercase_tags() -> axum::Router {
+    let db = SqliteDatabase::in_memory().await.unwrap();
+    db.migrate().unwrap();
+    let temp_dir = TempDir::new().unwrap();
+    let analysis_db = Arc::new(surrealdb::init_db(None).await.unwrap());
+
+    let state = AppState::new(
+        db,
+        crate::sync::SyncManager::new(crate::sync::MockGitOps::new()),
+        ChangeNotifier::new(),
+        temp_dir.path().join("skills"),
+        analysis_db,
+        crate::a6s::tracker::AnalysisTracker::new(ChangeNotifier::new()),
+    )
+    .with_lowercase_tags(true);
+    routes::create_router(state, false)
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn duplicate_tags_are_collapsed() {
+    let app = test_app().await;
+    let send = |method: &str, uri: String, body: Value| {
+        let app = app.clone();
+        let request = Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("content-type", "application/json")
+            .body(Body::from(serde_json::to_vec(&body).unwrap()))
+            .unwrap();
+        async move { app.oneshot(request).await.unwrap() }
+    };
+
+    // Duplicates collapse to the first occurrence and whitespace is trimmed
+    let response = send(
+        "POST",
+        "/api/v1/notes".to_string(),
+        json!({"title": "Tagged", "content": "x", "tags": ["work", " rust ", "work", "Work", ""]}),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let body = json_body(response).await;
+    assert_eq!(body["tags"], json!(["work", "rust", "Work"]));
+    let note_id = body["id"].as_str().unwrap().to_string();
+
+    let response = send(
+        "PATCH",
+        format!("/api/v1/notes/{}", note_id),
+        json!({"tags": ["api", "work", "api"]}),
+    )
+    .await;
+    assert_eq!(json_body(response).await["tags"], json!(["api", "work"]));
+
+    let response = send(
+        "PUT",
+        format!("/api/v1/notes/{}", note_id),
+        json!({"title": "Tagged", "content": "x", "tags": ["b", "a", "b"]}),
+    )
+    .await;
+    assert_eq!(json_body(response).await["tags"], json!(["b", "a"]));
+
+    // With case folding enabled, tags differing only in case are one tag
+    let app = test_app_with_lowercase_tags().await;
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/v1/notes")
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    serde_json::to_vec(&json!({
+                        "title": "Folded",
+                        "content": "x",
+                        "tags": ["Rust", "rust", " RUST", "API"]
+                    }))
+                    .unwrap(),
+                ))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(json_body(response).await["tags"], json!(["rust", "api"]));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn create_with_dedup_reuses_identical_note() {
+    let app = test_app().await;
+
+    let create = |title: &str| {
+        Request::builder()
+            .method("POST")
+            .uri("/api/v1/notes?dedup=true")
+            .header("content-type", "application/json")
+            .body(Body::from(
+                serde_json::to_vec(&json!({
+                    "title": title,
+                    "content": "Same content"
+                }))
+                .unwrap(),
+            ))
+            .unwrap()
+    };
+
+    let first = app.clone().oneshot(create("First")).await.unwrap();
+    assert_eq!(first.status(), StatusCode::CREATED);
+    let first = json_body(first).await;
+
+    let second = app.clone().oneshot(create("Second")).await.unwrap();
+    assert_eq!(second.status(), StatusCode::OK);
+    let second = json_body(second).await;
+    assert_eq!(second["id"], first["id"]);
+    assert_eq!(second["title"], "First");
+
+    let list = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri("/api/v1/notes")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(json_body(list).await["total"], 1);
+
+    // Without dedup a second note is always created
+    let third = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/v1/notes")
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    serde_json::to_vec(&json!({
+                        "title": "Third",
+                        "content": "Same content"
+                    }))
+                    .unwrap(),
+                ))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(third.status(), StatusCode::CREATED);
+}
@@ -4,15 +4,26 @@ use crate::sync::GitOps;
 use axum::{
     Json,
     extract::{Path, Query, State},
-    http::StatusCode,
+    http::{HeaderMap, StatusCode},
+    response::Response,
 };
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use tracing::instrument;
 use utoipa::{IntoParams, ToSchema};
 
+use super::TaskStatsResponse;
+use super::etag::conditional_json;
+use super::list_params::ListParams;
+use super::sorting::{PROJECT_SORT, page_sort};
 use crate::api::AppState;
 use crate::api::notifier::UpdateMessage;
-use crate::db::{Database, DbError, PageSort, Project, ProjectQuery, ProjectRepository, SortOrder};
+use crate::db::pagination::DEFAULT_PAGE_SIZE;
+use crate::db::utils::normalize_tags;
+use crate::db::{
+    Database, DbError, NoteQuery, NoteRepository, Project, ProjectQuery, ProjectRepository,
+    ProjectTaskStats, TaskListQuery, TaskListRepository, TaskListStatus, TaskRepository, TaskStats,
+};
 
 // =============================================================================
 // DTOs (Data Transfer Objects)
@@ -87,6 +98,10 @@ pub struct CreateProjectRequest {
     #[schema(example = json!(["owner/repo#123", "PROJ-456"]))]
     #[serde(default)]
     pub external_refs: Vec<String>,
+    /// Repositories to link on creation (all must exist)
+    #[schema(example = json!(["repo0001", "repo0002"]))]
+    #[serde(default)]
+    pub repo_ids: Vec<String>,
 }
 
 /// Update project request DTO
@@ -156,6 +171,14 @@ pub struct ErrorResponse {
     pub error: String,
 }
 
+/// Query parameters for the delete endpoints
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct DeleteQuery {
+    /// Remove the row for good instead of marking it deleted
+    #[serde(default)]
+    pub purge: bool,
+}
+
 #[derive(Debug, Deserialize, IntoParams)]
 pub struct ListProjectsQuery {
     /// FTS5 search query (optional)
@@ -167,7 +190,7 @@ pub struct ListProjectsQuery {
     /// Number of items to skip
     #[param(example = 0)]
     pub offset: Option<usize>,
-    /// Field to sort by (title, created_at, updated_at)
+    /// Field to sort by (title, created_at, updated_at; default updated_at desc)
     #[param(example = "created_at")]
     pub sort: Option<String>,
     /// Sort order (asc, desc)
@@ -186,6 +209,88 @@ pub struct PaginatedProjects {
     pub offset: usize,
 }
 
+/// Task list entry in a project tree
+#[derive(Serialize, ToSchema)]
+pub struct ProjectTreeTaskList {
+    #[schema(example = "list0001")]
+    pub id: String,
+    #[schema(example = "Sprint 1")]
+    pub title: String,
+    #[schema(example = "active")]
+    pub status: String,
+    /// Task counts by status
+    pub stats: TaskStatsResponse,
+}
+
+/// Note entry in a project tree
+#[derive(Serialize, ToSchema)]
+pub struct ProjectTreeNote {
+    #[schema(example = "note0001")]
+    pub id: String,
+    #[schema(example = "Architecture")]
+    pub title: String,
+    /// Parent note ID for subnotes
+    pub parent_id: Option<String>,
+}
+
+/// Largest number of project IDs accepted by one task-stats request
+pub const MAX_TASK_STATS_PROJECTS: usize = 100;
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct ProjectTaskStatsQuery {
+    /// Comma-separated project IDs (at most 100)
+    #[param(example = "a1b2c3d4,e5f6a7b8")]
+    pub ids: String,
+}
+
+/// Task counts by status across all task lists of a project
+#[derive(Serialize, ToSchema)]
+pub struct ProjectTaskStatsResponse {
+    #[schema(example = "a1b2c3d4")]
+    pub project_id: String,
+    /// Number of task lists in the project (active and archived)
+    #[schema(example = 2)]
+    pub task_lists: usize,
+    #[schema(example = 15)]
+    pub total: usize,
+    #[schema(example = 3)]
+    pub backlog: usize,
+    #[schema(example = 5)]
+    pub todo: usize,
+    #[schema(example = 4)]
+    pub in_progress: usize,
+    #[schema(example = 1)]
+    pub review: usize,
+    #[schema(example = 2)]
+    pub done: usize,
+    #[schema(example = 0)]
+    pub cancelled: usize,
+}
+
+impl From<ProjectTaskStats> for ProjectTaskStatsResponse {
+    fn from(stats: ProjectTaskStats) -> Self {
+        Self {
+            project_id: stats.project_id,
+            task_lists: stats.task_lists,
+            total: stats.total,
+            backlog: stats.backlog,
+            todo: stats.todo,
+            in_progress: stats.in_progress,
+            review: stats.review,
+            done: stats.done,
+            cancelled: stats.cancelled,
+        }
+    }
+}
+
+/// Project overview with its task lists (and their stats) and notes
+#[derive(Serialize, ToSchema)]
+pub struct ProjectTreeResponse {
+    pub project: ProjectResponse,
+    pub task_lists: Vec<ProjectTreeTaskList>,
+    pub notes: Vec<ProjectTreeNote>,
+}
+
 // =============================================================================
 // Handlers
 // =============================================================================
@@ -200,6 +305,7 @@ pub struct PaginatedProjects {
     params(ListProjectsQuery),
     responses(
         (status = 200, description = "Paginated list of projects", body = PaginatedProjects),
+        (status = 400, description = "Invalid list parameters", body = ErrorResponse),
         (status = 500, description = "Internal server error", body = ErrorResponse)
     )
 )]
@@ -207,26 +313,12 @@ pub struct PaginatedProjects {
 pub async fn list_projects<D: Database, G: GitOps + Send + Sync>(
     State(state): State<AppState<D, G>>,
     Query(query): Query<ListProjectsQuery>,
+    ListParams(list): ListParams,
 ) -> Result<Json<PaginatedProjects>, (StatusCode, Json<ErrorResponse>)> {
-    // Parse tags from comma-separated string
-    let tags = query
-        .tags
-        .as_ref()
-        .map(|t| t.split(',').map(|s| s.trim().to_string()).collect());
-
     // Build database query
     let db_query = ProjectQuery {
-        page: PageSort {
-            limit: query.limit,
-            offset: query.offset,
-            sort_by: query.sort.clone(),
-            sort_order: match query.order.as_deref() {
-                Some("desc") => Some(SortOrder::Desc),
-                Some("asc") => Some(SortOrder::Asc),
-                _ => None,
-            },
-        },
-        tags,
+        page: page_sort(Some(PROJECT_SORT), &list),
+        tags: list.tags.clone(),
     };
 
     // Use search if query provided, otherwise list
@@ -262,7 +354,7 @@ pub async fn list_projects<D: Database, G: GitOps + Send + Sync>(
     Ok(Json(PaginatedProjects {
         items,
         total: result.total,
-        limit: result.limit.unwrap_or(50),
+        limit: result.limit.unwrap_or(DEFAULT_PAGE_SIZE),
         offset: result.offset,
     }))
 }
@@ -279,6 +371,7 @@ pub async fn list_projects<D: Database, G: GitOps + Send + Sync>(
     ),
     responses(
         (status = 200, description = "Project found", body = ProjectResponse),
+        (status = 304, description = "Not modified (If-None-Match matched the ETag)"),
         (status = 404, description = "Project not found", body = ErrorResponse),
         (status = 500, description = "Internal server error", body = ErrorResponse)
     )
@@ -287,7 +380,57 @@ pub async fn list_projects<D: Database, G: GitOps + Send + Sync>(
 pub async fn get_project<D: Database, G: GitOps + Send + Sync>(
     State(state): State<AppState<D, G>>,
     Path(id): Path<String>,
-) -> Result<Json<ProjectResponse>, (StatusCode, Json<ErrorResponse>)> {
+    headers: HeaderMap,
+) -> Result<Response, (StatusCode, Json<ErrorResponse>)> {
+    let project = state.db().projects().get(&id).await.map_err(|e| match e {
+        DbError::NotFound { .. } => (
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse {
+                error: format!("Project '{}' not found", id),
+            }),
+        ),
+        _ => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse {
+                error: e.to_string(),
+            }),
+        ),
+    })?;
+
+    Ok(conditional_json(&headers, &ProjectResponse::from(project)))
+}
+
+/// Get a project tree
+///
+/// Returns the project with its task lists, per-list task stats and notes.
+/// Stats for all lists are fetched in a single query.
+#[utoipa::path(
+    get,
+    path = "/api/v1/projects/{id}/tree",
+    tag = "projects",
+    params(
+        ("id" = String, Path, description = "Project ID (8-character hex)")
+    ),
+    responses(
+        (status = 200, description = "Project tree", body = ProjectTreeResponse),
+        (status = 404, description = "Project not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+#[instrument(skip(state))]
+pub async fn get_project_tree<D: Database, G: GitOps + Send + Sync>(
+    State(state): State<AppState<D, G>>,
+    Path(id): Path<String>,
+) -> Result<Json<ProjectTreeResponse>, (StatusCode, Json<ErrorResponse>)> {
+    let internal_error = |e: DbError| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse {
+                error: e.to_string(),
+            }),
+        )
+    };
+
     let project = state.db().projects().get(&id).await.map_err(|e| match e {
         DbError::NotFound { .. } => (
             StatusCode::NOT_FOUND,
@@ -295,6 +438,183 @@ pub async fn get_project<D: Database, G: GitOps + Send + Sync>(
                 error: format!("Project '{}' not found", id),
             }),
         ),
+        _ => internal_error(e),
+    })?;
+
+    let task_lists = state
+        .db()
+        .task_lists()
+        .list(Some(&TaskListQuery {
+            project_id: Some(id.clone()),
+            ..Default::default()
+        }))
+        .await
+        .map_err(internal_error)?;
+
+    let mut stats = state
+        .db()
+        .tasks()
+        .get_stats_for_project_lists(&id)
+        .await
+        .map_err(internal_error)?;
+
+    let notes = state
+        .db()
+        .notes()
+        .list(Some(&NoteQuery {
+            project_id: Some(id.clone()),
+            ..Default::default()
+        }))
+        .await
+        .map_err(internal_error)?;
+
+    let task_lists = task_lists
+        .items
+        .into_iter()
+        .map(|list| {
+            let list_stats = stats
+                .iter()
+                .position(|s| s.list_id == list.id)
+                .map(|i| stats.swap_remove(i))
+                .unwrap_or_else(|| TaskStats {
+                    list_id: list.id.clone(),
+                    ..Default::default()
+                });
+            ProjectTreeTaskList {
+                id: list.id,
+                title: list.title,
+                status: match list.status {
+                    TaskListStatus::Active => "active".to_string(),
+                    TaskListStatus::Archived => "archived".to_string(),
+                },
+                stats: TaskStatsResponse::from(list_stats),
+            }
+        })
+        .collect();
+
+    let notes = notes
+        .items
+        .into_iter()
+        .map(|note| ProjectTreeNote {
+            id: note.id,
+            title: note.title,
+            parent_id: note.parent_id,
+        })
+        .collect();
+
+    Ok(Json(ProjectTreeResponse {
+        project: ProjectResponse::from(project),
+        task_lists,
+        notes,
+    }))
+}
+
+/// Get task stats for several projects
+///
+/// Returns a map of project ID to task counts by status, computed in one
+/// query. Unknown or deleted project IDs are left out of the map.
+#[utoipa::path(
+    get,
+    path = "/api/v1/projects/task-stats",
+    tag = "projects",
+    params(ProjectTaskStatsQuery),
+    responses(
+        (status = 200, description = "Task stats keyed by project ID", body = HashMap<String, ProjectTaskStatsResponse>),
+        (status = 400, description = "No IDs given, or more than 100", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+#[instrument(skip(state))]
+pub async fn get_projects_task_stats<D: Database, G: GitOps + Send + Sync>(
+    State(state): State<AppState<D, G>>,
+    Query(query): Query<ProjectTaskStatsQuery>,
+) -> Result<Json<HashMap<String, ProjectTaskStatsResponse>>, (StatusCode, Json<ErrorResponse>)> {
+    let mut ids: Vec<String> = query
+        .ids
+        .split(',')
+        .map(str::trim)
+        .filter(|id| !id.is_empty())
+        .map(String::from)
+        .collect();
+    ids.sort();
+    ids.dedup();
+
+    if ids.is_empty() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse {
+                error: "ids must name at least one project".to_string(),
+            }),
+        ));
+    }
+    if ids.len() > MAX_TASK_STATS_PROJECTS {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse {
+                error: format!(
+                    "At most {} project IDs per request, got {}",
+                    MAX_TASK_STATS_PROJECTS,
+                    ids.len()
+                ),
+            }),
+        ));
+    }
+
+    let stats = state
+        .db()
+        .tasks()
+        .get_stats_for_projects(&ids)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: e.to_string(),
+                }),
+            )
+        })?;
+
+    Ok(Json(
+        stats
+            .into_iter()
+            .map(|s| (s.project_id.clone(), ProjectTaskStatsResponse::from(s)))
+            .collect(),
+    ))
+}
+
+/// Get the default project
+///
+/// Returns the project configured as the default for new task lists
+#[utoipa::path(
+    get,
+    path = "/api/v1/projects/default",
+    tag = "projects",
+    responses(
+        (status = 200, description = "Default project", body = ProjectResponse),
+        (status = 404, description = "No default project configured or it no longer exists", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+#[instrument(skip(state))]
+pub async fn get_default_project<D: Database, G: GitOps + Send + Sync>(
+    State(state): State<AppState<D, G>>,
+) -> Result<Json<ProjectResponse>, (StatusCode, Json<ErrorResponse>)> {
+    let Some(id) = state.default_project() else {
+        return Err((
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse {
+                error: "No default project configured".to_string(),
+            }),
+        ));
+    };
+
+    let project = state.db().projects().get(id).await.map_err(|e| match e {
+        DbError::NotFound { .. } => (
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse {
+                error: format!("Default project '{}' not found", id),
+            }),
+        ),
         _ => (
             StatusCode::INTERNAL_SERVER_ERROR,
             Json(ErrorResponse {
@@ -308,7 +628,8 @@ pub async fn get_project<D: Database, G: GitOps + Send + Sync>(
 
 /// Create a new project
 ///
-/// Creates a new project and returns it
+/// Creates a new project, linking any given repos in the same transaction,
+/// and returns it
 #[utoipa::path(
     post,
     path = "/api/v1/projects",
@@ -316,6 +637,7 @@ pub async fn get_project<D: Database, G: GitOps + Send + Sync>(
     request_body = CreateProjectRequest,
     responses(
         (status = 201, description = "Project created", body = ProjectResponse),
+        (status = 404, description = "A linked repo was not found", body = ErrorResponse),
         (status = 500, description = "Internal server error", body = ErrorResponse)
     )
 )]
@@ -329,23 +651,34 @@ pub async fn create_project<D: Database, G: GitOps + Send + Sync>(
         id: String::new(), // Repository will generate this
         title: req.title,
         description: req.description,
-        tags: req.tags,
+        tags: normalize_tags(req.tags, state.lowercase_tags()),
         external_refs: req.external_refs,
-        repo_ids: vec![],
+        repo_ids: req.repo_ids,
         task_list_ids: vec![],
         note_ids: vec![],
         created_at: None, // Repository will generate this
         updated_at: None, // Repository will generate this
     };
 
-    let created_project = state.db().projects().create(&project).await.map_err(|e| {
-        (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ErrorResponse {
-                error: e.to_string(),
-            }),
-        )
-    })?;
+    let created_project = state
+        .db()
+        .projects()
+        .create(&project)
+        .await
+        .map_err(|e| match e {
+            DbError::NotFound { .. } => (
+                StatusCode::NOT_FOUND,
+                Json(ErrorResponse {
+                    error: e.to_string(),
+                }),
+            ),
+            _ => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: e.to_string(),
+                }),
+            ),
+        })?;
 
     // Broadcast notification
     state.notifier().notify(UpdateMessage::ProjectCreated {
@@ -400,7 +733,7 @@ pub async fn update_project<D: Database, G: GitOps + Send + Sync>(
     // Update fields
     project.title = req.title;
     project.description = req.description;
-    project.tags = req.tags;
+    project.tags = normalize_tags(req.tags, state.lowercase_tags());
     project.external_refs = req.external_refs;
     // Clear updated_at to ensure proper timestamp refresh on PUT
     project.updated_at = None;
@@ -453,7 +786,7 @@ pub async fn update_project<D: Database, G: GitOps + Send + Sync>(
 pub async fn patch_project<D: Database, G: GitOps + Send + Sync>(
     State(state): State<AppState<D, G>>,
     Path(id): Path<String>,
-    Json(req): Json<PatchProjectRequest>,
+    Json(mut req): Json<PatchProjectRequest>,
 ) -> Result<Json<ProjectResponse>, (StatusCode, Json<ErrorResponse>)> {
     // Fetch existing project
     let mut project = state.db().projects().get(&id).await.map_err(|e| match e {
@@ -472,6 +805,9 @@ pub async fn patch_project<D: Database, G: GitOps + Send + Sync>(
     })?;
 
     // Merge PATCH changes
+    req.tags = req
+        .tags
+        .map(|tags| normalize_tags(tags, state.lowercase_tags()));
     req.merge_into(&mut project);
 
     // Save
@@ -510,7 +846,8 @@ pub async fn patch_project<D: Database, G: GitOps + Send + Sync>(
     path = "/api/v1/projects/{id}",
     tag = "projects",
     params(
-        ("id" = String, Path, description = "Project ID (8-character hex)")
+        ("id" = String, Path, description = "Project ID (8-character hex)"),
+        DeleteQuery
     ),
     responses(
         (status = 204, description = "Project deleted"),
@@ -522,26 +859,28 @@ pub async fn patch_project<D: Database, G: GitOps + Send + Sync>(
 pub async fn delete_project<D: Database, G: GitOps + Send + Sync>(
     State(state): State<AppState<D, G>>,
     Path(id): Path<String>,
+    Query(query): Query<DeleteQuery>,
 ) -> Result<StatusCode, (StatusCode, Json<ErrorResponse>)> {
-    state
-        .db()
-        .projects()
-        .delete(&id)
-        .await
-        .map_err(|e| match e {
-            DbError::NotFound { .. } => (
-                StatusCode::NOT_FOUND,
-                Json(ErrorResponse {
-                    error: format!("Project '{}' not found", id),
-                }),
-            ),
-            _ => (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ErrorResponse {
-                    error: e.to_string(),
-                }),
-            ),
-        })?;
+    let projects = state.db().projects();
+    let result = if query.purge {
+        projects.purge(&id).await
+    } else {
+        projects.delete(&id).await
+    };
+    result.map_err(|e| match e {
+        DbError::NotFound { .. } => (
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse {
+                error: format!("Project '{}' not found", id),
+            }),
+        ),
+        _ => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse {
+                error: e.to_string(),
+            }),
+        ),
+    })?;
 
     // Broadcast notification
     state.notifier().notify(UpdateMessage::ProjectDeleted {
@@ -140,6 +140,21 @@ async fn list_and_relationships_comprehensive() {
     assert_eq!(body["total"], 1);
     assert_eq!(body["items"][0]["title"], "Project A");
 
+    // Several tags match projects carrying any of them
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri("/api/v1/projects?tags=backend,react")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = json_body(response).await;
+    assert_eq!(body["total"], 2);
+
     // Test 2b: List with sorting
     let response = app
         .clone()
@@ -863,3 +878,214 @@ async fn fts5_search_comprehensive() {
     let body = json_body(response).await;
     assert_eq!(body["total"], 0);
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn list_limit_follows_shared_policy() {
+    use crate::db::pagination::{DEFAULT_PAGE_SIZE, MAX_PAGE_SIZE};
+
+    let app = test_app().await;
+
+    // One more project than the default page holds
+    for i in 0..=DEFAULT_PAGE_SIZE {
+        app.clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/v1/projects")
+                    .header("content-type", "application/json")
+                    .body(Body::from(
+                        serde_json::to_string(&json!({"title": format!("Project {}", i)})).unwrap(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+    }
+
+    // Unspecified limit: the shared default applies, as it does for MCP tools
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri("/api/v1/projects")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let body = json_body(response).await;
+    assert_eq!(body["limit"], DEFAULT_PAGE_SIZE);
+    assert_eq!(body["items"].as_array().unwrap().len(), DEFAULT_PAGE_SIZE);
+    assert_eq!(body["total"], DEFAULT_PAGE_SIZE + 1);
+
+    // Over-large limit is capped at the shared maximum
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri("/api/v1/projects?limit=100000")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let body = json_body(response).await;
+    assert_eq!(body["limit"], MAX_PAGE_SIZE);
+    assert_eq!(
+        body["items"].as_array().unwrap().len(),
+        DEFAULT_PAGE_SIZE + 1
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn list_rejects_invalid_order() {
+    let app = test_app().await;
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/api/v1/projects?order=sideways")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let body = json_body(response).await;
+    assert!(body["error"].as_str().unwrap().contains("sideways"));
+}
+
+// =============================================================================
+// Bulk Task Stats
+// =============================================================================
+
+/// POST a JSON body and return the created entity's ID
+async fn post_id(app: &axum::Router, uri: &str, body: Value) -> String {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(uri)
+                .header("content-type", "application/json")
+                .body(Body::from(body.to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    json_body(response).await["id"]
+        .as_str()
+        .unwrap()
+        .to_string()
+}
+
+async fn get_json(app: &axum::Router, uri: &str) -> (StatusCode, Value) {
+    let response = app
+        .clone()
+        .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    let status = response.status();
+    (status, json_body(response).await)
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn task_stats_batch_matches_per_project_rollup() {
+    let app = test_app().await;
+
+    // Two projects with two lists each, plus an empty project
+    let seed: [&[&[&str]]; 2] = [
+        &[&["todo", "todo", "done"], &["in_progress", "cancelled"]],
+        &[&["backlog"], &[]],
+    ];
+    let mut project_ids = Vec::new();
+    for (p, lists) in seed.iter().enumerate() {
+        let project_id = post_id(
+            &app,
+            "/api/v1/projects",
+            json!({"title": format!("Project {}", p)}),
+        )
+        .await;
+        for (l, statuses) in lists.iter().enumerate() {
+            let list_id = post_id(
+                &app,
+                "/api/v1/task-lists",
+                json!({"title": format!("List {}", l), "project_id": &project_id}),
+            )
+            .await;
+            for status in statuses.iter() {
+                post_id(
+                    &app,
+                    &format!("/api/v1/task-lists/{}/tasks", list_id),
+                    json!({"title": "Task", "status": status}),
+                )
+                .await;
+            }
+        }
+        project_ids.push(project_id);
+    }
+    let empty_id = post_id(&app, "/api/v1/projects", json!({"title": "Empty"})).await;
+
+    let (status, batch) = get_json(
+        &app,
+        &format!(
+            "/api/v1/projects/task-stats?ids={},{},{},missing0",
+            project_ids[0], project_ids[1], empty_id
+        ),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(
+        batch.as_object().unwrap().len(),
+        3,
+        "unknown IDs are omitted"
+    );
+
+    // Per-project rollup: sum the per-list stats from the project tree
+    let fields = [
+        "total",
+        "backlog",
+        "todo",
+        "in_progress",
+        "review",
+        "done",
+        "cancelled",
+    ];
+    for project_id in project_ids.iter().chain([&empty_id]) {
+        let (_, tree) = get_json(&app, &format!("/api/v1/projects/{}/tree", project_id)).await;
+        let lists = tree["task_lists"].as_array().unwrap();
+        let stats = &batch[project_id.as_str()];
+
+        assert_eq!(stats["project_id"], project_id.as_str());
+        assert_eq!(stats["task_lists"], lists.len());
+        for field in fields {
+            let expected: u64 = lists
+                .iter()
+                .map(|l| l["stats"][field].as_u64().unwrap())
+                .sum();
+            assert_eq!(stats[field], expected, "{} for {}", field, project_id);
+        }
+    }
+
+    assert_eq!(batch[project_ids[0].as_str()]["total"], 5);
+    assert_eq!(batch[project_ids[0].as_str()]["todo"], 2);
+    assert_eq!(batch[project_ids[1].as_str()]["backlog"], 1);
+    assert_eq!(batch[empty_id.as_str()]["total"], 0);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn task_stats_batch_rejects_too_many_or_no_ids() {
+    let app = test_app().await;
+
+    let ids = (0..=super::MAX_TASK_STATS_PROJECTS)
+        .map(|i| format!("{:08x}", i))
+        .collect::<Vec<_>>()
+        .join(",");
+    let (status, body) = get_json(&app, &format!("/api/v1/projects/task-stats?ids={}", ids)).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert!(body["error"].as_str().unwrap().contains("At most"));
+
+    let (status, _) = get_json(&app, "/api/v1/projects/task-stats?ids=").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
@@ -4,7 +4,8 @@ use crate::sync::GitOps;
 use axum::{
     Json,
     extract::{Path, Query, State},
-    http::StatusCode,
+    http::{HeaderMap, StatusCode},
+    response::Response,
 };
 use serde::{Deserialize, Serialize};
 use tracing::instrument;
@@ -12,14 +13,31 @@ use utoipa::{IntoParams, ToSchema};
 
 use crate::api::AppState;
 use crate::api::notifier::UpdateMessage;
-use crate::db::{Database, DbError, PageSort, Repo, RepoQuery, RepoRepository, SortOrder};
+use crate::db::pagination::DEFAULT_PAGE_SIZE;
+use crate::db::utils::normalize_tags;
+use crate::db::{Database, DbError, Repo, RepoDependents, RepoQuery, RepoRepository};
 
 use super::ErrorResponse;
+use super::etag::conditional_json;
+use super::list_params::ListParams;
+use super::sorting::{REPO_SORT, page_sort};
 
 // =============================================================================
 // DTOs (Data Transfer Objects)
 // =============================================================================
 
+/// Query parameters for deleting a repo
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct DeleteRepoQuery {
+    /// Remove the row for good instead of marking it deleted
+    #[serde(default)]
+    pub purge: bool,
+    /// Delete even when projects, task lists or notes still link to the repo
+    /// (the links are removed)
+    #[serde(default)]
+    pub force: bool,
+}
+
 /// Repo response DTO
 #[derive(Serialize, ToSchema)]
 pub struct RepoResponse {
@@ -139,7 +157,7 @@ pub struct ListReposQuery {
     /// Number of items to skip
     #[param(example = 0)]
     pub offset: Option<usize>,
-    /// Field to sort by (remote, created_at)
+    /// Field to sort by (remote, created_at; default created_at desc)
     #[param(example = "created_at")]
     pub sort: Option<String>,
     /// Sort order (asc, desc)
@@ -148,7 +166,7 @@ pub struct ListReposQuery {
     /// Filter by tags (comma-separated)
     #[param(example = "work,active")]
     pub tags: Option<String>,
-    /// Search query for filtering by remote URL or tags (case-insensitive partial match)
+    /// Search query: case-insensitive substring of the remote URL or path, or an FTS5 query over remote, path and tags
     #[param(example = "github")]
     pub q: Option<String>,
 }
@@ -175,6 +193,7 @@ pub struct PaginatedRepos {
     params(ListReposQuery),
     responses(
         (status = 200, description = "Paginated list of repos", body = PaginatedRepos),
+        (status = 400, description = "Invalid list parameters", body = ErrorResponse),
         (status = 500, description = "Internal server error", body = ErrorResponse)
     )
 )]
@@ -182,50 +201,36 @@ pub struct PaginatedRepos {
 pub async fn list_repos<D: Database, G: GitOps + Send + Sync>(
     State(state): State<AppState<D, G>>,
     Query(query): Query<ListReposQuery>,
+    ListParams(list): ListParams,
 ) -> Result<Json<PaginatedRepos>, (StatusCode, Json<ErrorResponse>)> {
-    // Parse tags from comma-separated string
-    let tags = query
-        .tags
-        .as_ref()
-        .map(|t| t.split(',').map(|s| s.trim().to_string()).collect());
-
     // Build database query
     let db_query = RepoQuery {
-        page: PageSort {
-            limit: query.limit,
-            offset: query.offset,
-            sort_by: query.sort.clone(),
-            sort_order: match query.order.as_deref() {
-                Some("desc") => Some(SortOrder::Desc),
-                Some("asc") => Some(SortOrder::Asc),
-                _ => None,
-            },
-        },
-        tags,
+        page: page_sort(Some(REPO_SORT), &list),
+        tags: list.tags.clone(),
         project_id: query.project_id.clone(),
-        search_query: query.q.clone(),
+        search_query: None,
     };
 
-    let result = state
-        .db()
-        .repos()
-        .list(Some(&db_query))
-        .await
-        .map_err(|e| {
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ErrorResponse {
-                    error: e.to_string(),
-                }),
-            )
-        })?;
+    let repos = state.db().repos();
+    let result = match query.q.as_deref().filter(|q| !q.trim().is_empty()) {
+        Some(q) => repos.search(q, Some(&db_query)).await,
+        None => repos.list(Some(&db_query)).await,
+    }
+    .map_err(|e| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse {
+                error: e.to_string(),
+            }),
+        )
+    })?;
 
     let items: Vec<RepoResponse> = result.items.into_iter().map(RepoResponse::from).collect();
 
     Ok(Json(PaginatedRepos {
         items,
         total: result.total,
-        limit: result.limit.unwrap_or(50),
+        limit: result.limit.unwrap_or(DEFAULT_PAGE_SIZE),
         offset: result.offset,
     }))
 }
@@ -242,6 +247,7 @@ pub async fn list_repos<D: Database, G: GitOps + Send + Sync>(
     ),
     responses(
         (status = 200, description = "Repo found", body = RepoResponse),
+        (status = 304, description = "Not modified (If-None-Match matched the ETag)"),
         (status = 404, description = "Repo not found", body = ErrorResponse),
         (status = 500, description = "Internal server error", body = ErrorResponse)
     )
@@ -250,7 +256,8 @@ pub async fn list_repos<D: Database, G: GitOps + Send + Sync>(
 pub async fn get_repo<D: Database, G: GitOps + Send + Sync>(
     State(state): State<AppState<D, G>>,
     Path(id): Path<String>,
-) -> Result<Json<RepoResponse>, (StatusCode, Json<ErrorResponse>)> {
+    headers: HeaderMap,
+) -> Result<Response, (StatusCode, Json<ErrorResponse>)> {
     let repo = state.db().repos().get(&id).await.map_err(|e| match e {
         DbError::NotFound { .. } => (
             StatusCode::NOT_FOUND,
@@ -266,7 +273,7 @@ pub async fn get_repo<D: Database, G: GitOps + Send + Sync>(
         ),
     })?;
 
-    Ok(Json(RepoResponse::from(repo)))
+    Ok(conditional_json(&headers, &RepoResponse::from(repo)))
 }
 
 /// Create a new repo
@@ -279,6 +286,7 @@ pub async fn get_repo<D: Database, G: GitOps + Send + Sync>(
     request_body = CreateRepoRequest,
     responses(
         (status = 201, description = "Repo created", body = RepoResponse),
+        (status = 409, description = "A repo with an equivalent remote already exists", body = ErrorResponse),
         (status = 500, description = "Internal server error", body = ErrorResponse)
     )
 )]
@@ -292,19 +300,31 @@ pub async fn create_repo<D: Database, G: GitOps + Send + Sync>(
         id: String::new(), // Repository will generate this
         remote: req.remote,
         path: req.path,
-        tags: req.tags,
+        tags: normalize_tags(req.tags, state.lowercase_tags()),
         project_ids: req.project_ids,
         created_at: None, // Repository will generate this
+        updated_at: None, // Repository will generate this
     };
 
-    let created_repo = state.db().repos().create(&repo).await.map_err(|e| {
-        (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ErrorResponse {
-                error: e.to_string(),
-            }),
-        )
-    })?;
+    let created_repo = state
+        .db()
+        .repos()
+        .create(&repo)
+        .await
+        .map_err(|e| match e {
+            DbError::AlreadyExists { .. } => (
+                StatusCode::CONFLICT,
+                Json(ErrorResponse {
+                    error: e.to_string(),
+                }),
+            ),
+            _ => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: e.to_string(),
+                }),
+            ),
+        })?;
 
     // Broadcast notification
     state.notifier().notify(UpdateMessage::RepoCreated {
@@ -356,7 +376,7 @@ pub async fn update_repo<D: Database, G: GitOps + Send + Sync>(
     // Update fields
     repo.remote = req.remote;
     repo.path = req.path;
-    repo.tags = req.tags;
+    repo.tags = normalize_tags(req.tags, state.lowercase_tags());
     repo.project_ids = req.project_ids;
 
     state.db().repos().update(&repo).await.map_err(|e| {
@@ -397,7 +417,7 @@ pub async fn update_repo<D: Database, G: GitOps + Send + Sync>(
 pub async fn patch_repo<D: Database, G: GitOps + Send + Sync>(
     State(state): State<AppState<D, G>>,
     Path(id): Path<String>,
-    Json(req): Json<PatchRepoRequest>,
+    Json(mut req): Json<PatchRepoRequest>,
 ) -> Result<Json<RepoResponse>, (StatusCode, Json<ErrorResponse>)> {
     // Fetch existing repo
     let mut repo = state.db().repos().get(&id).await.map_err(|e| match e {
@@ -416,6 +436,9 @@ pub async fn patch_repo<D: Database, G: GitOps + Send + Sync>(
     })?;
 
     // Merge PATCH changes
+    req.tags = req
+        .tags
+        .map(|tags| normalize_tags(tags, state.lowercase_tags()));
     req.merge_into(&mut repo);
 
     // Save
@@ -438,17 +461,20 @@ pub async fn patch_repo<D: Database, G: GitOps + Send + Sync>(
 
 /// Delete a repo
 ///
-/// Deletes a repository by its ID
+/// Deletes a repository by its ID and unlinks it from projects, task lists and
+/// notes. Refuses with 409 while anything still links to it, unless `force` is set.
 #[utoipa::path(
     delete,
     path = "/api/v1/repos/{id}",
     tag = "repos",
     params(
-        ("id" = String, Path, description = "Repo ID (8-character hex)")
+        ("id" = String, Path, description = "Repo ID (8-character hex)"),
+        DeleteRepoQuery
     ),
     responses(
         (status = 204, description = "Repo deleted"),
         (status = 404, description = "Repo not found", body = ErrorResponse),
+        (status = 409, description = "Repo is still linked; the error lists the dependents", body = ErrorResponse),
         (status = 500, description = "Internal server error", body = ErrorResponse)
     )
 )]
@@ -456,8 +482,10 @@ pub async fn patch_repo<D: Database, G: GitOps + Send + Sync>(
 pub async fn delete_repo<D: Database, G: GitOps + Send + Sync>(
     State(state): State<AppState<D, G>>,
     Path(id): Path<String>,
+    Query(query): Query<DeleteRepoQuery>,
 ) -> Result<StatusCode, (StatusCode, Json<ErrorResponse>)> {
-    state.db().repos().delete(&id).await.map_err(|e| match e {
+    let repos = state.db().repos();
+    let not_found = |e: DbError| match e {
         DbError::NotFound { .. } => (
             StatusCode::NOT_FOUND,
             Json(ErrorResponse {
@@ -470,7 +498,33 @@ pub async fn delete_repo<D: Database, G: GitOps + Send + Sync>(
                 error: e.to_string(),
             }),
         ),
-    })?;
+    };
+
+    // A soft-deleted repo has no live links left, and purging it must still work
+    let dependents = match repos.dependents(&id).await {
+        Ok(dependents) => dependents,
+        Err(DbError::NotFound { .. }) if query.purge => RepoDependents::default(),
+        Err(e) => return Err(not_found(e)),
+    };
+    if !query.force && !dependents.is_empty() {
+        return Err((
+            StatusCode::CONFLICT,
+            Json(ErrorResponse {
+                error: format!(
+                    "Repo '{}' is still linked to {}; delete with force=true to unlink it",
+                    id,
+                    describe_dependents(&dependents)
+                ),
+            }),
+        ));
+    }
+
+    let result = if query.purge {
+        repos.purge(&id).await
+    } else {
+        repos.delete(&id).await
+    };
+    result.map_err(not_found)?;
 
     // Broadcast notification
     state.notifier().notify(UpdateMessage::RepoDeleted {
@@ -480,6 +534,20 @@ pub async fn delete_repo<D: Database, G: GitOps + Send + Sync>(
     Ok(StatusCode::NO_CONTENT)
 }
 
+/// List dependents by kind, e.g. "projects [a1b2c3d4], notes [e5f6a7b8, c9d0e1f2]".
+fn describe_dependents(dependents: &RepoDependents) -> String {
+    [
+        ("projects", &dependents.project_ids),
+        ("task lists", &dependents.task_list_ids),
+        ("notes", &dependents.note_ids),
+    ]
+    .into_iter()
+    .filter(|(_, ids)| !ids.is_empty())
+    .map(|(kind, ids)| format!("{} [{}]", kind, ids.join(", ")))
+    .collect::<Vec<_>>()
+    .join(", ")
+}
+
 /// Trigger code analysis for a repository
 ///
 /// Starts background analysis of the repository's code using the a6s pipeline.
@@ -400,6 +400,191 @@ async fn crud_operations() {
     assert_eq!(delete_404.status(), StatusCode::NOT_FOUND);
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn delete_is_soft_unless_purged() {
+    let app = test_app().await;
+
+    let create = |remote: &str| {
+        Request::builder()
+            .method("POST")
+            .uri("/api/v1/repos")
+            .header("content-type", "application/json")
+            .body(Body::from(
+                serde_json::to_vec(&json!({"remote": remote})).unwrap(),
+            ))
+            .unwrap()
+    };
+    let delete = |uri: String| {
+        Request::builder()
+            .method("DELETE")
+            .uri(uri)
+            .body(Body::empty())
+            .unwrap()
+    };
+
+    let response = app
+        .clone()
+        .oneshot(create("https://github.com/soft/delete"))
+        .await
+        .unwrap();
+    let first_id = json_body(response).await["id"]
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    let response = app
+        .clone()
+        .oneshot(delete(format!("/api/v1/repos/{}", first_id)))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    // A soft-deleted repo no longer holds on to its remote
+    let response = app
+        .clone()
+        .oneshot(create("https://github.com/soft/delete"))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let second_id = json_body(response).await["id"]
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    let response = app
+        .clone()
+        .oneshot(delete(format!("/api/v1/repos/{}?purge=true", second_id)))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    let response = app
+        .clone()
+        .oneshot(delete(format!("/api/v1/repos/{}?purge=true", second_id)))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/api/v1/repos")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(json_body(response).await["total"], 0);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn delete_refuses_linked_repo_unless_forced() {
+    let app = test_app().await;
+
+    let send = |method: &str, uri: String, body: Option<Value>| {
+        let builder = Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("content-type", "application/json");
+        let body = body
+            .map(|b| Body::from(serde_json::to_vec(&b).unwrap()))
+            .unwrap_or_else(Body::empty);
+        builder.body(body).unwrap()
+    };
+
+    let response = app
+        .clone()
+        .oneshot(send(
+            "POST",
+            "/api/v1/projects".to_string(),
+            Some(json!({"title": "Linked project"})),
+        ))
+        .await
+        .unwrap();
+    let project_id = json_body(response).await["id"]
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    let response = app
+        .clone()
+        .oneshot(send(
+            "POST",
+            "/api/v1/repos".to_string(),
+            Some(json!({"remote": "https://github.com/linked/repo", "project_ids": [project_id]})),
+        ))
+        .await
+        .unwrap();
+    let repo_id = json_body(response).await["id"]
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    let response = app
+        .clone()
+        .oneshot(send(
+            "POST",
+            "/api/v1/notes".to_string(),
+            Some(json!({"title": "Linked note", "content": "Body", "repo_ids": [repo_id]})),
+        ))
+        .await
+        .unwrap();
+    let note_id = json_body(response).await["id"]
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    // Refused while linked, naming the dependents
+    let response = app
+        .clone()
+        .oneshot(send("DELETE", format!("/api/v1/repos/{}", repo_id), None))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+    let error = json_body(response).await["error"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    assert!(error.contains(&format!("projects [{}]", project_id)));
+    assert!(error.contains(&format!("notes [{}]", note_id)));
+
+    let response = app
+        .clone()
+        .oneshot(send("GET", format!("/api/v1/repos/{}", repo_id), None))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // Forced: deleted and unlinked everywhere
+    let response = app
+        .clone()
+        .oneshot(send(
+            "DELETE",
+            format!("/api/v1/repos/{}?force=true", repo_id),
+            None,
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    let response = app
+        .clone()
+        .oneshot(send(
+            "GET",
+            format!("/api/v1/projects/{}", project_id),
+            None,
+        ))
+        .await
+        .unwrap();
+    assert_eq!(json_body(response).await["repo_ids"], json!([]));
+
+    let response = app
+        .oneshot(send("GET", format!("/api/v1/notes/{}", note_id), None))
+        .await
+        .unwrap();
+    assert_eq!(json_body(response).await["repo_ids"], json!([]));
+}
+
 // =============================================================================
 // WebSocket Broadcast Tests
 // =============================================================================
@@ -0,0 +1,145 @@
+//! Cross-entity search handler.
+
+use crate::sync::GitOps;
+use axum::{
+    Json,
+    extract::{Query, State},
+    http::StatusCode,
+};
+use serde::{Deserialize, Serialize};
+use tracing::instrument;
+use utoipa::{IntoParams, ToSchema};
+
+use crate::api::AppState;
+use crate::db::{
+    Database, NoteQuery, NoteRepository, PageSort, ProjectQuery, ProjectRepository, TaskQuery,
+    TaskRepository,
+};
+
+use super::{ErrorResponse, NoteResponse, ProjectResponse, TaskResponse};
+
+/// Default number of results returned per entity group
+const DEFAULT_SEARCH_LIMIT: usize = 5;
+/// Upper bound for the per-group limit
+const MAX_SEARCH_LIMIT: usize = 20;
+
+// =============================================================================
+// DTOs
+// =============================================================================
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct SearchQuery {
+    /// FTS5 search query
+    #[param(example = "rust backend")]
+    pub q: String,
+    /// Maximum number of results per entity group (default 5, max 20)
+    #[param(example = 5)]
+    pub limit: Option<usize>,
+}
+
+/// Search results grouped by entity type
+#[derive(Serialize, ToSchema)]
+pub struct SearchResponse {
+    pub notes: Vec<NoteResponse>,
+    pub tasks: Vec<TaskResponse>,
+    pub projects: Vec<ProjectResponse>,
+}
+
+// =============================================================================
+// Handlers
+// =============================================================================
+
+/// Search across notes, tasks and projects
+///
+/// Runs the FTS5 search of each entity and returns the top matches per group
+#[utoipa::path(
+    get,
+    path = "/api/v1/search",
+    tag = "search",
+    params(SearchQuery),
+    responses(
+        (status = 200, description = "Grouped search results", body = SearchResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+#[instrument(skip(state))]
+pub async fn search<D: Database, G: GitOps + Send + Sync>(
+    State(state): State<AppState<D, G>>,
+    Query(query): Query<SearchQuery>,
+) -> Result<Json<SearchResponse>, (StatusCode, Json<ErrorResponse>)> {
+    let internal_error = |e: crate::db::DbError| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse {
+                error: e.to_string(),
+            }),
+        )
+    };
+
+    if query.q.trim().is_empty() {
+        return Ok(Json(SearchResponse {
+            notes: vec![],
+            tasks: vec![],
+            projects: vec![],
+        }));
+    }
+
+    let page = PageSort {
+        limit: Some(
+            query
+                .limit
+                .unwrap_or(DEFAULT_SEARCH_LIMIT)
+                .clamp(1, MAX_SEARCH_LIMIT),
+        ),
+        ..Default::default()
+    };
+
+    let notes = state
+        .db()
+        .notes()
+        .search(
+            &query.q,
+            Some(&NoteQuery {
+                page: page.clone(),
+                ..Default::default()
+            }),
+        )
+        .await
+        .map_err(internal_error)?;
+
+    let tasks = state
+        .db()
+        .tasks()
+        .search(
+            &query.q,
+            Some(&TaskQuery {
+                page: page.clone(),
+                ..Default::default()
+            }),
+        )
+        .await
+        .map_err(internal_error)?;
+
+    let projects = state
+        .db()
+        .projects()
+        .search(
+            &query.q,
+            Some(&ProjectQuery {
+                page,
+                ..Default::default()
+            }),
+        )
+        .await
+        .map_err(internal_error)?;
+
+    Ok(Json(SearchResponse {
+        notes: notes.items.into_iter().map(NoteResponse::from).collect(),
+        tasks: tasks.items.into_iter().map(TaskResponse::from).collect(),
+        projects: projects
+            .items
+            .into_iter()
+            .map(ProjectResponse::from)
+            .collect(),
+    }))
+}
@@ -0,0 +1,148 @@
+//! Integration tests for the cross-entity search endpoint.
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+};
+use http_body_util::BodyExt;
+use serde_json::{Value, json};
+use std::sync::Arc;
+use tower::ServiceExt;
+
+use crate::a6s::store::surrealdb;
+use crate::api::notifier::ChangeNotifier;
+use crate::api::{AppState, routes};
+use crate::db::{Database, SqliteDatabase};
+use tempfile::TempDir;
+
+/// Create a test app with an in-memory database
+async fn test_app() -> axum::Router {
+    let db = SqliteDatabase::in_memory().await.unwrap();
+    db.migrate().unwrap();
+    let temp_dir = TempDir::new().unwrap();
+    let analysis_db = Arc::new(surrealdb::init_db(None).await.unwrap());
+
+    let state = AppState::new(
+        db,
+        crate::sync::SyncManager::new(crate::sync::MockGitOps::new()),
+        ChangeNotifier::new(),
+        temp_dir.path().join("skills"),
+        analysis_db,
+        crate::a6s::tracker::AnalysisTracker::new(crate::api::notifier::ChangeNotifier::new()),
+    );
+    routes::create_router(state, false)
+}
+
+/// Helper to parse JSON response body
+async fn json_body(response: axum::response::Response) -> Value {
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    serde_json::from_slice(&body).unwrap()
+}
+
+async fn post(app: &axum::Router, uri: &str, body: Value) -> Value {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(uri)
+                .header("content-type", "application/json")
+                .body(Body::from(body.to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    json_body(response).await
+}
+
+async fn get(app: &axum::Router, uri: &str) -> (StatusCode, Value) {
+    let response = app
+        .clone()
+        .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    let status = response.status();
+    (status, json_body(response).await)
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn search_returns_grouped_results() {
+    let app = test_app().await;
+
+    let project = post(
+        &app,
+        "/api/v1/projects",
+        json!({"title": "Kraken project", "description": "Sea monsters"}),
+    )
+    .await;
+    let project_id = project["id"].as_str().unwrap();
+
+    let list = post(
+        &app,
+        "/api/v1/task-lists",
+        json!({"title": "Backlog", "project_id": project_id}),
+    )
+    .await;
+    let list_id = list["id"].as_str().unwrap();
+
+    post(
+        &app,
+        &format!("/api/v1/task-lists/{}/tasks", list_id),
+        json!({"title": "Feed the kraken"}),
+    )
+    .await;
+    post(
+        &app,
+        "/api/v1/notes",
+        json!({"title": "Kraken notes", "content": "Tentacles everywhere"}),
+    )
+    .await;
+    post(
+        &app,
+        "/api/v1/notes",
+        json!({"title": "Unrelated", "content": "Nothing to see"}),
+    )
+    .await;
+
+    let (status, body) = get(&app, "/api/v1/search?q=kraken").await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["notes"].as_array().unwrap().len(), 1);
+    assert_eq!(body["notes"][0]["title"], "Kraken notes");
+    assert_eq!(body["tasks"].as_array().unwrap().len(), 1);
+    assert_eq!(body["tasks"][0]["title"], "Feed the kraken");
+    assert_eq!(body["projects"].as_array().unwrap().len(), 1);
+    assert_eq!(body["projects"][0]["id"], project_id);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn search_respects_limit_per_group() {
+    let app = test_app().await;
+
+    for i in 0..4 {
+        post(
+            &app,
+            "/api/v1/notes",
+            json!({"title": format!("Widget {}", i), "content": "widget"}),
+        )
+        .await;
+    }
+
+    let (status, body) = get(&app, "/api/v1/search?q=widget&limit=2").await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["notes"].as_array().unwrap().len(), 2);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn search_with_blank_query_returns_empty_groups() {
+    let app = test_app().await;
+
+    let (status, body) = get(&app, "/api/v1/search?q=%20").await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert!(body["notes"].as_array().unwrap().is_empty());
+    assert!(body["tasks"].as_array().unwrap().is_empty());
+    assert!(body["projects"].as_array().unwrap().is_empty());
+}
@@ -5,15 +5,19 @@ use axum::{
     Json,
     extract::{Path, Query, State},
     http::StatusCode,
+    response::IntoResponse,
 };
 use serde::{Deserialize, Serialize};
 use utoipa::{IntoParams, ToSchema};
 
 use crate::api::AppState;
 use crate::api::notifier::UpdateMessage;
-use crate::db::{Database, DbError, PageSort, Skill, SkillQuery, SkillRepository, SortOrder};
+use crate::db::pagination::DEFAULT_PAGE_SIZE;
+use crate::db::{Database, DbError, Skill, SkillQuery, SkillRepository};
 
 use super::ErrorResponse;
+use super::list_params::ListParams;
+use super::sorting::{SKILL_SORT, page_sort};
 
 // =============================================================================
 // DTOs
@@ -84,7 +88,7 @@ pub struct ListSkillsQuery {
     /// Number of items to skip
     #[param(example = 0)]
     pub offset: Option<usize>,
-    /// Field to sort by (name, created_at)
+    /// Field to sort by (name, created_at, updated_at; default updated_at desc)
     #[param(example = "created_at")]
     pub sort: Option<String>,
     /// Sort order (asc, desc)
@@ -220,6 +224,7 @@ pub async fn replace_skill<D: Database, G: GitOps + Send + Sync>(
     params(ListSkillsQuery),
     responses(
         (status = 200, description = "List of skills", body = PaginatedSkills),
+        (status = 400, description = "Invalid list parameters", body = ErrorResponse),
         (status = 500, description = "Internal server error", body = ErrorResponse)
     )
 )]
@@ -227,30 +232,15 @@ pub async fn replace_skill<D: Database, G: GitOps + Send + Sync>(
 pub async fn list_skills<D: Database, G: GitOps + Send + Sync>(
     State(state): State<AppState<D, G>>,
     Query(api_query): Query<ListSkillsQuery>,
+    ListParams(list): ListParams,
 ) -> Result<Json<PaginatedSkills>, (StatusCode, Json<ErrorResponse>)> {
     let db = state.db();
     let repo = db.skills();
 
     // Convert API query to DB query
-    let tags = api_query.tags.as_ref().map(|t| {
-        t.split(',')
-            .map(|s| s.trim().to_string())
-            .filter(|s| !s.is_empty())
-            .collect::<Vec<_>>()
-    });
-
     let db_query = SkillQuery {
-        page: PageSort {
-            limit: api_query.limit,
-            offset: api_query.offset,
-            sort_by: api_query.sort.clone(),
-            sort_order: match api_query.order.as_deref() {
-                Some("desc") => Some(SortOrder::Desc),
-                Some("asc") => Some(SortOrder::Asc),
-                _ => None,
-            },
-        },
-        tags,
+        page: page_sort(Some(SKILL_SORT), &list),
+        tags: list.tags.clone(),
         project_id: api_query.project_id.clone(),
     };
 
@@ -276,7 +266,7 @@ pub async fn list_skills<D: Database, G: GitOps + Send + Sync>(
     Ok(Json(PaginatedSkills {
         items: results.items.into_iter().map(SkillResponse::from).collect(),
         total: results.total,
-        limit: results.limit.unwrap_or(50),
+        limit: results.limit.unwrap_or(DEFAULT_PAGE_SIZE),
         offset: results.offset,
     }))
 }
@@ -335,7 +325,7 @@ pub async fn create_skill<D: Database, G: GitOps + Send + Sync>(
     let db = state.db();
     let repo = db.skills();
     let skill = Skill {
-        id: crate::db::utils::generate_entity_id(),
+        id: crate::db::utils::generate_entity_id_with_length(db.id_length()),
         name: req.name,
         description: req.description,
         content: req.content,
@@ -509,9 +499,60 @@ pub struct ImportSkillRequest {
     /// If true, update existing skill; if false, fail on duplicate
     #[serde(default)]
     pub update: bool,
+    /// If true, parse the source and return a preview without storing anything
+    #[serde(default)]
+    pub preview: bool,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct SkillAttachmentManifestEntry {
+    #[serde(rename = "type")]
+    #[schema(example = "script")]
+    pub type_: String,
+    #[schema(example = "scripts/deploy.sh")]
+    pub filename: String,
+    pub content_hash: String,
+    pub mime_type: Option<String>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct SkillImportPreviewResponse {
+    #[schema(example = "skl00123")]
+    pub id: String,
+    #[schema(example = "deploy-kubernetes")]
+    pub name: String,
+    #[schema(example = "Deploy apps to K8s cluster")]
+    pub description: String,
+    /// Whether a skill with this ID already exists (import needs `update`)
+    pub exists: bool,
+    pub attachments: Vec<SkillAttachmentManifestEntry>,
+}
+
+impl From<crate::skills::SkillPreview> for SkillImportPreviewResponse {
+    fn from(p: crate::skills::SkillPreview) -> Self {
+        Self {
+            id: p.id,
+            name: p.name,
+            description: p.description,
+            exists: p.exists,
+            attachments: p
+                .attachments
+                .into_iter()
+                .map(|a| SkillAttachmentManifestEntry {
+                    type_: a.type_,
+                    filename: a.filename,
+                    content_hash: a.content_hash,
+                    mime_type: a.mime_type,
+                })
+                .collect(),
+        }
+    }
 }
 
 /// Import a skill from a source (local filesystem or git repository)
+///
+/// With `preview: true` the source is parsed and validated but nothing is
+/// stored; the response is the parsed metadata and attachment manifest.
 #[utoipa::path(
     post,
     path = "/api/v1/skills/import",
@@ -519,6 +560,7 @@ pub struct ImportSkillRequest {
     request_body = ImportSkillRequest,
     responses(
         (status = 201, description = "Skill imported successfully", body = SkillResponse),
+        (status = 200, description = "Import preview (nothing stored)", body = SkillImportPreviewResponse),
         (status = 400, description = "Invalid request", body = ErrorResponse),
         (status = 500, description = "Import failed", body = ErrorResponse)
     )
@@ -526,9 +568,23 @@ pub struct ImportSkillRequest {
 pub async fn import_skill<D: Database, G: GitOps + Send + Sync>(
     State(state): State<AppState<D, G>>,
     Json(req): Json<ImportSkillRequest>,
-) -> Result<(StatusCode, Json<SkillResponse>), (StatusCode, Json<ErrorResponse>)> {
+) -> Result<axum::response::Response, (StatusCode, Json<ErrorResponse>)> {
     let db = state.db();
 
+    if req.preview {
+        let preview = crate::skills::preview_skill(db, &req.source, req.path.as_deref())
+            .await
+            .map_err(|e| {
+                (
+                    StatusCode::BAD_REQUEST,
+                    Json(ErrorResponse {
+                        error: format!("Import preview failed: {}", e),
+                    }),
+                )
+            })?;
+        return Ok(Json(SkillImportPreviewResponse::from(preview)).into_response());
+    }
+
     // Call the import function from skills module
     let skill = crate::skills::import_skill(
         db,
@@ -580,7 +636,7 @@ pub async fn import_skill<D: Database, G: GitOps + Send + Sync>(
         skill_id: skill.id.clone(),
     });
 
-    Ok((StatusCode::CREATED, Json(skill.into())))
+    Ok((StatusCode::CREATED, Json(SkillResponse::from(skill))).into_response())
 }
 
 // =============================================================================
@@ -633,6 +689,7 @@ pub async fn enable_skill<D: Database, G: GitOps + Send + Sync>(
                     offset: None,
                     sort_by: None,
                     sort_order: None,
+                    after: None,
                 },
                 tags: None,
                 project_id: None,
@@ -745,6 +802,7 @@ pub async fn disable_skill<D: Database, G: GitOps + Send + Sync>(
                     offset: None,
                     sort_by: None,
                     sort_order: None,
+                    after: None,
                 },
                 tags: None,
                 project_id: None,
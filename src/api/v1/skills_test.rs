@@ -58,7 +58,7 @@ async fn test_list_skills_empty() {
     let body = json_body(response).await;
     assert_eq!(body["items"].as_array().unwrap().len(), 0);
     assert_eq!(body["total"], 0);
-    assert_eq!(body["limit"], 50);
+    assert_eq!(body["limit"], crate::db::pagination::DEFAULT_PAGE_SIZE);
     assert_eq!(body["offset"], 0);
 }
 
@@ -417,7 +417,7 @@ async fn test_list_skills_non_empty() {
     let body = json_body(response).await;
     assert_eq!(body["items"].as_array().unwrap().len(), 3);
     assert_eq!(body["total"], 3);
-    assert_eq!(body["limit"], 50);
+    assert_eq!(body["limit"], crate::db::pagination::DEFAULT_PAGE_SIZE);
     assert_eq!(body["offset"], 0);
 }
 
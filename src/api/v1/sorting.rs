@@ -0,0 +1,103 @@
+//! Default list ordering per entity.
+//!
+//! List handlers resolve the client's `sort`/`order` parameters through
+//! [`page_sort`] so the order a list comes back in when the client asks for
+//! none is defined here rather than in every client.
+
+use crate::db::{ListQuery, PageSort, SortOrder};
+
+/// Sort applied when a list request names no `sort` field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DefaultSort {
+    pub field: &'static str,
+    pub order: SortOrder,
+}
+
+/// Projects: most recently updated first.
+pub const PROJECT_SORT: DefaultSort = DefaultSort {
+    field: "updated_at",
+    order: SortOrder::Desc,
+};
+
+/// Task lists: most recently updated first.
+pub const TASK_LIST_SORT: DefaultSort = DefaultSort {
+    field: "updated_at",
+    order: SortOrder::Desc,
+};
+
+/// Tasks with no (or a mixed) status filter: oldest first.
+pub const TASK_SORT: DefaultSort = DefaultSort {
+    field: "created_at",
+    order: SortOrder::Asc,
+};
+
+/// Notes: most recently updated first.
+pub const NOTE_SORT: DefaultSort = DefaultSort {
+    field: "updated_at",
+    order: SortOrder::Desc,
+};
+
+/// Repositories: most recently registered first.
+pub const REPO_SORT: DefaultSort = DefaultSort {
+    field: "created_at",
+    order: SortOrder::Desc,
+};
+
+/// Skills: most recently updated first.
+pub const SKILL_SORT: DefaultSort = DefaultSort {
+    field: "updated_at",
+    order: SortOrder::Desc,
+};
+
+/// Default task ordering for a (comma-separated) status filter.
+///
+/// Work that hasn't started reads best by priority; started or closed work
+/// reads best newest-first. Filters spanning both groups fall back to
+/// [`TASK_SORT`].
+pub fn task_sort(status: Option<&str>) -> DefaultSort {
+    const QUEUED: &[&str] = &["backlog", "todo"];
+    const STARTED: &[&str] = &["in_progress", "review", "done", "cancelled"];
+
+    let statuses: Vec<&str> = status
+        .map(|s| s.split(',').map(str::trim).collect())
+        .unwrap_or_default();
+
+    if statuses.is_empty() {
+        TASK_SORT
+    } else if statuses.iter().all(|s| QUEUED.contains(s)) {
+        DefaultSort {
+            field: "priority",
+            order: SortOrder::Asc,
+        }
+    } else if statuses.iter().all(|s| STARTED.contains(s)) {
+        DefaultSort {
+            field: "updated_at",
+            order: SortOrder::Desc,
+        }
+    } else {
+        TASK_SORT
+    }
+}
+
+/// Build the [`PageSort`] for a list request.
+///
+/// Without a `sort` field the default applies, and an explicit `order` only
+/// flips its direction. An explicit `sort` is passed through unchanged. With
+/// no default (`None`) the repository's own ordering is used.
+pub fn page_sort(default: Option<DefaultSort>, list: &ListQuery) -> PageSort {
+    let (sort_by, sort_order) = match (&list.sort, default) {
+        (None, Some(default)) => (
+            Some(default.field.to_string()),
+            Some(list.order.unwrap_or(default.order)),
+        ),
+        (sort, _) => (sort.clone(), list.order),
+    };
+
+    PageSort {
+        limit: list.limit,
+        offset: list.offset,
+        sort_by,
+        sort_order,
+        after: list.after.clone(),
+    }
+}
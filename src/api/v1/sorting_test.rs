@@ -0,0 +1,427 @@
+//! Tests for default list ordering.
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+};
+use http_body_util::BodyExt;
+use serde_json::Value;
+use std::sync::Arc;
+use tower::ServiceExt;
+
+use super::sorting::*;
+use crate::a6s::store::surrealdb;
+use crate::api::{AppState, routes};
+use crate::db::{
+    Database, ListQuery, Note, NoteRepository, Project, ProjectRepository, Repo, RepoRepository,
+    Skill, SkillRepository, SortOrder, SqliteDatabase, Task, TaskList, TaskListRepository,
+    TaskListStatus, TaskRepository, TaskStatus,
+};
+use tempfile::TempDir;
+
+async fn setup_db() -> SqliteDatabase {
+    let db = SqliteDatabase::in_memory().await.unwrap();
+    db.migrate().unwrap();
+    db
+}
+
+async fn app(db: SqliteDatabase) -> axum::Router {
+    let temp_dir = TempDir::new().unwrap();
+    let analysis_db = surrealdb::init_db(None).await.unwrap();
+    let state = AppState::new(
+        db,
+        crate::sync::SyncManager::new(crate::sync::MockGitOps::new()),
+        crate::api::notifier::ChangeNotifier::new(),
+        temp_dir.path().join("skills"),
+        Arc::new(analysis_db),
+        crate::a6s::tracker::AnalysisTracker::new(crate::api::notifier::ChangeNotifier::new()),
+    );
+    routes::create_router(state, false)
+}
+
+/// GET `uri` and return the `id` of every listed item, in order
+async fn listed_ids(app: &axum::Router, uri: &str) -> Vec<String> {
+    let response = app
+        .clone()
+        .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let body: Value = serde_json::from_slice(&body).unwrap();
+    body["items"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|item| item["id"].as_str().unwrap().to_string())
+        .collect()
+}
+
+fn project(id: &str, created_at: &str, updated_at: &str) -> Project {
+    Project {
+        id: id.to_string(),
+        title: format!("Project {}", id),
+        description: None,
+        tags: vec![],
+        external_refs: vec![],
+        repo_ids: vec![],
+        task_list_ids: vec![],
+        note_ids: vec![],
+        created_at: Some(created_at.to_string()),
+        updated_at: Some(updated_at.to_string()),
+    }
+}
+
+fn task_list(id: &str, project_id: &str, created_at: &str, updated_at: &str) -> TaskList {
+    TaskList {
+        id: id.to_string(),
+        title: format!("List {}", id),
+        description: None,
+        notes: None,
+        tags: vec![],
+        external_refs: vec![],
+        status: TaskListStatus::Active,
+        repo_ids: vec![],
+        project_id: project_id.to_string(),
+        created_at: Some(created_at.to_string()),
+        updated_at: Some(updated_at.to_string()),
+        archived_at: None,
+    }
+}
+
+fn task(id: &str, status: TaskStatus, priority: i32, created_at: &str, updated_at: &str) -> Task {
+    Task {
+        id: id.to_string(),
+        list_id: "sortlst1".to_string(),
+        parent_id: None,
+        title: format!("Task {}", id),
+        description: None,
+        status,
+        priority: Some(priority),
+        tags: vec![],
+        external_refs: vec![],
+        created_at: Some(created_at.to_string()),
+        updated_at: Some(updated_at.to_string()),
+    }
+}
+
+// =============================================================================
+// page_sort / task_sort
+// =============================================================================
+
+#[test]
+fn page_sort_uses_default_when_unspecified() {
+    let page = page_sort(Some(NOTE_SORT), &ListQuery::default());
+    assert_eq!(page.sort_by.as_deref(), Some("updated_at"));
+    assert_eq!(page.sort_order, Some(SortOrder::Desc));
+
+    // An explicit order flips the default field's direction
+    let list = ListQuery {
+        order: Some(SortOrder::Asc),
+        ..Default::default()
+    };
+    let page = page_sort(Some(NOTE_SORT), &list);
+    assert_eq!(page.sort_by.as_deref(), Some("updated_at"));
+    assert_eq!(page.sort_order, Some(SortOrder::Asc));
+}
+
+#[test]
+fn page_sort_passes_explicit_sort_through() {
+    let list = ListQuery {
+        limit: Some(5),
+        offset: Some(10),
+        sort: Some("title".to_string()),
+        ..Default::default()
+    };
+    let page = page_sort(Some(NOTE_SORT), &list);
+    assert_eq!(page.sort_by.as_deref(), Some("title"));
+    assert_eq!(page.sort_order, None);
+    assert_eq!(page.limit, Some(5));
+    assert_eq!(page.offset, Some(10));
+
+    let list = ListQuery {
+        order: Some(SortOrder::Desc),
+        ..Default::default()
+    };
+    let page = page_sort(None, &list);
+    assert_eq!(page.sort_by, None);
+    assert_eq!(page.sort_order, Some(SortOrder::Desc));
+}
+
+#[test]
+fn task_sort_depends_on_status_filter() {
+    assert_eq!(task_sort(None), TASK_SORT);
+    assert_eq!(task_sort(Some("backlog,todo")).field, "priority");
+    assert_eq!(task_sort(Some("backlog,todo")).order, SortOrder::Asc);
+    assert_eq!(task_sort(Some("done,cancelled")).field, "updated_at");
+    assert_eq!(task_sort(Some("done,cancelled")).order, SortOrder::Desc);
+    assert_eq!(task_sort(Some("todo,done")), TASK_SORT);
+}
+
+// =============================================================================
+// Endpoint defaults
+// =============================================================================
+
+#[tokio::test(flavor = "multi_thread")]
+async fn projects_default_to_updated_at_desc() {
+    let db = setup_db().await;
+    let projects = db.projects();
+    projects
+        .create(&project(
+            "sortprj1",
+            "2025-01-01 00:00:00",
+            "2025-01-01 00:00:00",
+        ))
+        .await
+        .unwrap();
+    projects
+        .create(&project(
+            "sortprj2",
+            "2025-01-02 00:00:00",
+            "2025-01-05 00:00:00",
+        ))
+        .await
+        .unwrap();
+    projects
+        .create(&project(
+            "sortprj3",
+            "2025-01-03 00:00:00",
+            "2025-01-03 00:00:00",
+        ))
+        .await
+        .unwrap();
+
+    let app = app(db).await;
+    assert_eq!(
+        listed_ids(&app, "/api/v1/projects").await,
+        ["sortprj2", "sortprj3", "sortprj1"]
+    );
+    assert_eq!(
+        listed_ids(&app, "/api/v1/projects?order=asc").await,
+        ["sortprj1", "sortprj3", "sortprj2"]
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn task_lists_default_to_updated_at_desc() {
+    let db = setup_db().await;
+    db.projects()
+        .create(&project(
+            "sortprj1",
+            "2025-01-01 00:00:00",
+            "2025-01-01 00:00:00",
+        ))
+        .await
+        .unwrap();
+    let lists = db.task_lists();
+    lists
+        .create(&task_list(
+            "sortlst1",
+            "sortprj1",
+            "2025-01-01 00:00:00",
+            "2025-01-04 00:00:00",
+        ))
+        .await
+        .unwrap();
+    lists
+        .create(&task_list(
+            "sortlst2",
+            "sortprj1",
+            "2025-01-02 00:00:00",
+            "2025-01-02 00:00:00",
+        ))
+        .await
+        .unwrap();
+    lists
+        .create(&task_list(
+            "sortlst3",
+            "sortprj1",
+            "2025-01-03 00:00:00",
+            "2025-01-06 00:00:00",
+        ))
+        .await
+        .unwrap();
+
+    let app = app(db).await;
+    assert_eq!(
+        listed_ids(&app, "/api/v1/task-lists").await,
+        ["sortlst3", "sortlst1", "sortlst2"]
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn tasks_default_by_status_filter() {
+    let db = setup_db().await;
+    db.projects()
+        .create(&project(
+            "sortprj1",
+            "2025-01-01 00:00:00",
+            "2025-01-01 00:00:00",
+        ))
+        .await
+        .unwrap();
+    db.task_lists()
+        .create(&task_list(
+            "sortlst1",
+            "sortprj1",
+            "2025-01-01 00:00:00",
+            "2025-01-01 00:00:00",
+        ))
+        .await
+        .unwrap();
+    let tasks = db.tasks();
+    for task in [
+        task(
+            "sorttsk1",
+            TaskStatus::Todo,
+            3,
+            "2025-01-01 00:00:00",
+            "2025-01-01 00:00:00",
+        ),
+        task(
+            "sorttsk2",
+            TaskStatus::Backlog,
+            1,
+            "2025-01-02 00:00:00",
+            "2025-01-02 00:00:00",
+        ),
+        task(
+            "sorttsk3",
+            TaskStatus::Done,
+            2,
+            "2025-01-03 00:00:00",
+            "2025-01-03 00:00:00",
+        ),
+        task(
+            "sorttsk4",
+            TaskStatus::Done,
+            5,
+            "2025-01-04 00:00:00",
+            "2025-01-09 00:00:00",
+        ),
+    ] {
+        tasks.create(&task).await.unwrap();
+    }
+
+    let app = app(db).await;
+    let uri = "/api/v1/task-lists/sortlst1/tasks";
+
+    // No status filter: oldest first
+    assert_eq!(
+        listed_ids(&app, uri).await,
+        ["sorttsk1", "sorttsk2", "sorttsk3", "sorttsk4"]
+    );
+
+    // Queued work: by priority
+    assert_eq!(
+        listed_ids(&app, &format!("{}?status=backlog,todo", uri)).await,
+        ["sorttsk2", "sorttsk1"]
+    );
+
+    // Finished work: most recently updated first
+    assert_eq!(
+        listed_ids(&app, &format!("{}?status=done", uri)).await,
+        ["sorttsk4", "sorttsk3"]
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn notes_default_to_updated_at_desc() {
+    let db = setup_db().await;
+    let notes = db.notes();
+    for (id, created_at, updated_at) in [
+        ("sortnot1", "2025-01-01 00:00:00", "2025-01-07 00:00:00"),
+        ("sortnot2", "2025-01-02 00:00:00", "2025-01-02 00:00:00"),
+        ("sortnot3", "2025-01-03 00:00:00", "2025-01-04 00:00:00"),
+    ] {
+        notes
+            .create(&Note {
+                id: id.to_string(),
+                title: format!("Note {}", id),
+                content: "Content".to_string(),
+                tags: vec![],
+                parent_id: None,
+                idx: None,
+                repo_ids: vec![],
+                project_ids: vec![],
+                subnote_count: None,
+                created_at: Some(created_at.to_string()),
+                updated_at: Some(updated_at.to_string()),
+            })
+            .await
+            .unwrap();
+    }
+
+    let app = app(db).await;
+    assert_eq!(
+        listed_ids(&app, "/api/v1/notes").await,
+        ["sortnot1", "sortnot3", "sortnot2"]
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn repos_default_to_created_at_desc() {
+    let db = setup_db().await;
+    let repos = db.repos();
+    for (id, created_at) in [
+        ("sortrep1", "2025-01-01 00:00:00"),
+        ("sortrep2", "2025-01-03 00:00:00"),
+        ("sortrep3", "2025-01-02 00:00:00"),
+    ] {
+        repos
+            .create(&Repo {
+                id: id.to_string(),
+                remote: format!("https://github.com/test/{}", id),
+                path: None,
+                tags: vec![],
+                project_ids: vec![],
+                created_at: Some(created_at.to_string()),
+                updated_at: None,
+            })
+            .await
+            .unwrap();
+    }
+
+    let app = app(db).await;
+    assert_eq!(
+        listed_ids(&app, "/api/v1/repos").await,
+        ["sortrep2", "sortrep3", "sortrep1"]
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn skills_default_to_updated_at_desc() {
+    let db = setup_db().await;
+    let skills = db.skills();
+    for (id, created_at, updated_at) in [
+        ("sortskl1", "2025-01-01 00:00:00", "2025-01-02 00:00:00"),
+        ("sortskl2", "2025-01-02 00:00:00", "2025-01-08 00:00:00"),
+        ("sortskl3", "2025-01-03 00:00:00", "2025-01-03 00:00:00"),
+    ] {
+        skills
+            .create(&Skill {
+                id: id.to_string(),
+                name: id.to_string(),
+                description: "Sorting fixture".to_string(),
+                content: format!(
+                    "---\nname: {}\ndescription: Sorting fixture\n---\n\nBody\n",
+                    id
+                ),
+                tags: vec![],
+                project_ids: vec![],
+                scripts: vec![],
+                references: vec![],
+                assets: vec![],
+                created_at: Some(created_at.to_string()),
+                updated_at: Some(updated_at.to_string()),
+            })
+            .await
+            .unwrap();
+    }
+
+    let app = app(db).await;
+    assert_eq!(
+        listed_ids(&app, "/api/v1/skills").await,
+        ["sortskl2", "sortskl3", "sortskl1"]
+    );
+}
@@ -8,7 +8,7 @@ use utoipa::ToSchema;
 
 use crate::api::state::AppState;
 use crate::db::Database;
-use crate::sync::GitOps;
+use crate::sync::{ConflictStrategy, GitOps, SyncEntity, SyncError, SyncManager};
 
 use super::ErrorResponse;
 
@@ -18,6 +18,10 @@ pub struct InitSyncRequest {
     /// Git remote URL (optional)
     #[schema(example = "git@github.com:user/c5t-sync.git")]
     pub remote_url: Option<String>,
+
+    /// Initialize this project's own sync workspace instead of the default one (optional)
+    #[schema(example = "a1b2c3d4")]
+    pub project: Option<String>,
 }
 
 /// Request to export sync data
@@ -31,6 +35,19 @@ pub struct ExportSyncRequest {
     #[serde(default)]
     #[schema(example = false)]
     pub remote: bool,
+
+    /// Export only this entity type (repos, projects, task_lists, tasks, notes, skills)
+    #[schema(example = "notes")]
+    pub only: Option<String>,
+
+    /// Only rewrite records changed since the last export (optional, default: false)
+    #[serde(default)]
+    #[schema(example = false)]
+    pub incremental: bool,
+
+    /// Export only this project's data, to its own sync workspace (optional)
+    #[schema(example = "a1b2c3d4")]
+    pub project: Option<String>,
 }
 
 /// Request to import sync data
@@ -40,6 +57,37 @@ pub struct ImportSyncRequest {
     #[serde(default)]
     #[schema(example = false)]
     pub remote: bool,
+
+    /// Import only this entity type (repos, projects, task_lists, tasks, notes, skills)
+    #[schema(example = "notes")]
+    pub only: Option<String>,
+
+    /// Report field-level changes without importing (optional, default: false)
+    #[serde(default)]
+    #[schema(example = false)]
+    pub preview: bool,
+
+    /// Import from this project's sync workspace instead of the default one (optional)
+    #[schema(example = "a1b2c3d4")]
+    pub project: Option<String>,
+
+    /// What to do with records changed on both sides since the last sync:
+    /// skip (default, reported as conflicts), last-write-wins or force
+    #[schema(example = "skip")]
+    pub strategy: Option<String>,
+}
+
+/// Request to compare the sync files with the database
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct DiffSyncRequest {
+    /// Pull from remote before comparing (optional, default: false)
+    #[serde(default)]
+    #[schema(example = false)]
+    pub remote: bool,
+
+    /// Compare this project's sync workspace instead of the default one (optional)
+    #[schema(example = "a1b2c3d4")]
+    pub project: Option<String>,
 }
 
 /// Response from sync operations
@@ -63,6 +111,7 @@ pub struct SyncResponse {
     responses(
         (status = 201, description = "Sync created successfully", body = SyncResponse),
         (status = 200, description = "Sync already initialized", body = SyncResponse),
+        (status = 400, description = "Invalid remote URL or project id", body = ErrorResponse),
         (status = 500, description = "Internal server error", body = ErrorResponse)
     )
 )]
@@ -70,23 +119,28 @@ pub async fn init_sync<D: Database, G: GitOps + Send + Sync>(
     State(state): State<AppState<D, G>>,
     Json(req): Json<InitSyncRequest>,
 ) -> Result<(StatusCode, Json<SyncResponse>), (StatusCode, Json<ErrorResponse>)> {
-    let result = state
-        .sync_manager()
-        .init(req.remote_url.clone())
-        .await
-        .map_err(|e| {
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ErrorResponse {
-                    error: e.to_string(),
-                }),
-            )
-        })?;
+    let manager = scoped_manager(&state, req.project.as_deref())?;
+    let result = manager.init(req.remote_url.clone()).await.map_err(|e| {
+        let status = match e {
+            SyncError::InvalidRemoteUrl { .. } => StatusCode::BAD_REQUEST,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        };
+        (
+            status,
+            Json(ErrorResponse {
+                error: e.to_string(),
+            }),
+        )
+    })?;
 
     use crate::sync::InitResult;
     let (status_code, message) = match result {
         InitResult::Created => (StatusCode::CREATED, "Sync initialized successfully"),
         InitResult::AlreadyInitialized => (StatusCode::OK, "Sync already initialized"),
+        InitResult::RemoteUpdated => (
+            StatusCode::OK,
+            "Sync already initialized, remote 'origin' updated",
+        ),
     };
 
     Ok((
@@ -95,14 +149,62 @@ pub async fn init_sync<D: Database, G: GitOps + Send + Sync>(
             status: "success".to_string(),
             message: message.to_string(),
             data: Some(serde_json::json!({
-                "sync_dir": crate::sync::get_sync_dir().display().to_string(),
+                "sync_dir": manager.sync_dir().display().to_string(),
                 "remote_url": req.remote_url,
+                "project": req.project,
                 "created": matches!(result, InitResult::Created),
             })),
         }),
     ))
 }
 
+/// The state's sync manager, or one scoped to `project`'s workspace.
+///
+/// The project need not exist locally yet (importing a project workspace on a
+/// fresh machine creates it), but its id becomes a directory name, so only
+/// alphanumeric ids are accepted.
+fn scoped_manager<D: Database, G: GitOps + Send + Sync>(
+    state: &AppState<D, G>,
+    project: Option<&str>,
+) -> Result<SyncManager<G>, (StatusCode, Json<ErrorResponse>)> {
+    match project {
+        Some(project_id)
+            if project_id.is_empty() || !project_id.chars().all(|c| c.is_ascii_alphanumeric()) =>
+        {
+            Err((
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse {
+                    error: format!("Invalid project id '{}'", project_id),
+                }),
+            ))
+        }
+        Some(project_id) => Ok(state.sync_manager().for_project(project_id)),
+        None => Ok(state.sync_manager().clone()),
+    }
+}
+
+/// Parse the optional `only` entity filter of export/import requests.
+fn parse_only(only: Option<&str>) -> Result<Option<SyncEntity>, (StatusCode, Json<ErrorResponse>)> {
+    only.map(|s| {
+        s.parse::<SyncEntity>()
+            .map_err(|error| (StatusCode::BAD_REQUEST, Json(ErrorResponse { error })))
+    })
+    .transpose()
+}
+
+/// Parse the optional conflict `strategy` of import requests.
+fn parse_strategy(
+    strategy: Option<&str>,
+) -> Result<ConflictStrategy, (StatusCode, Json<ErrorResponse>)> {
+    strategy
+        .map(|s| {
+            s.parse::<ConflictStrategy>()
+                .map_err(|error| (StatusCode::BAD_REQUEST, Json(ErrorResponse { error })))
+        })
+        .transpose()
+        .map(Option::unwrap_or_default)
+}
+
 /// Export database to sync
 #[utoipa::path(
     post,
@@ -111,6 +213,7 @@ pub async fn init_sync<D: Database, G: GitOps + Send + Sync>(
     request_body = ExportSyncRequest,
     responses(
         (status = 200, description = "Export completed successfully", body = SyncResponse),
+        (status = 400, description = "Invalid entity type or project id, or incremental combined with only", body = ErrorResponse),
         (status = 500, description = "Internal server error", body = ErrorResponse)
     )
 )]
@@ -118,18 +221,37 @@ pub async fn export_sync<D: Database, G: GitOps + Send + Sync>(
     State(state): State<AppState<D, G>>,
     Json(req): Json<ExportSyncRequest>,
 ) -> Result<Json<SyncResponse>, (StatusCode, Json<ErrorResponse>)> {
-    let summary = state
-        .sync_manager()
-        .export(state.db(), req.message, req.remote)
-        .await
-        .map_err(|e| {
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
+    let manager = scoped_manager(&state, req.project.as_deref())?;
+    let summary = match parse_only(req.only.as_deref())? {
+        Some(_) if req.incremental => {
+            return Err((
+                StatusCode::BAD_REQUEST,
                 Json(ErrorResponse {
-                    error: e.to_string(),
+                    error: "incremental export always covers every entity type; drop 'only'"
+                        .to_string(),
                 }),
-            )
-        })?;
+            ));
+        }
+        Some(entity) => {
+            manager
+                .export_entity(state.db(), entity, req.message, req.remote)
+                .await
+        }
+        None if req.incremental => {
+            manager
+                .export_incremental(state.db(), req.message, req.remote)
+                .await
+        }
+        None => manager.export(state.db(), req.message, req.remote).await,
+    }
+    .map_err(|e| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse {
+                error: e.to_string(),
+            }),
+        )
+    })?;
 
     Ok(Json(SyncResponse {
         status: "success".to_string(),
@@ -140,6 +262,7 @@ pub async fn export_sync<D: Database, G: GitOps + Send + Sync>(
                 "projects": summary.projects,
                 "task_lists": summary.task_lists,
                 "tasks": summary.tasks,
+                "transitions": summary.transitions,
                 "notes": summary.notes,
                 "skills": summary.skills,
                 "attachments": summary.attachments,
@@ -156,7 +279,8 @@ pub async fn export_sync<D: Database, G: GitOps + Send + Sync>(
     tag = "sync",
     request_body = ImportSyncRequest,
     responses(
-        (status = 200, description = "Import completed successfully", body = SyncResponse),
+        (status = 200, description = "Import completed (or previewed) successfully", body = SyncResponse),
+        (status = 400, description = "Invalid entity type, project id or conflict strategy, or preview combined with remote", body = ErrorResponse),
         (status = 500, description = "Internal server error", body = ErrorResponse)
     )
 )]
@@ -164,18 +288,52 @@ pub async fn import_sync<D: Database, G: GitOps + Send + Sync>(
     State(state): State<AppState<D, G>>,
     Json(req): Json<ImportSyncRequest>,
 ) -> Result<Json<SyncResponse>, (StatusCode, Json<ErrorResponse>)> {
-    let summary = state
-        .sync_manager()
-        .import(state.db(), req.remote)
-        .await
-        .map_err(|e| {
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
+    let manager = scoped_manager(&state, req.project.as_deref())?
+        .with_conflict_strategy(parse_strategy(req.strategy.as_deref())?);
+    let only = parse_only(req.only.as_deref())?;
+
+    if req.preview {
+        if req.remote {
+            return Err((
+                StatusCode::BAD_REQUEST,
                 Json(ErrorResponse {
-                    error: e.to_string(),
+                    error: "preview reads the local sync files and cannot be combined with remote"
+                        .to_string(),
                 }),
-            )
-        })?;
+            ));
+        }
+
+        let preview = manager
+            .preview_import(state.db(), only)
+            .await
+            .map_err(|e| {
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(ErrorResponse {
+                        error: e.to_string(),
+                    }),
+                )
+            })?;
+
+        return Ok(Json(SyncResponse {
+            status: "success".to_string(),
+            message: "Import preview (nothing imported)".to_string(),
+            data: Some(serde_json::json!({ "preview": preview })),
+        }));
+    }
+
+    let summary = match only {
+        Some(entity) => manager.import_entity(state.db(), entity, req.remote).await,
+        None => manager.import(state.db(), req.remote).await,
+    }
+    .map_err(|e| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse {
+                error: e.to_string(),
+            }),
+        )
+    })?;
 
     Ok(Json(SyncResponse {
         status: "success".to_string(),
@@ -186,11 +344,52 @@ pub async fn import_sync<D: Database, G: GitOps + Send + Sync>(
                 "projects": summary.projects,
                 "task_lists": summary.task_lists,
                 "tasks": summary.tasks,
+                "transitions": summary.transitions,
                 "notes": summary.notes,
                 "skills": summary.skills,
                 "attachments": summary.attachments,
                 "total": summary.total(),
-            }
+            },
+            "warnings": summary.warnings,
+            "conflicts": summary.conflicts,
+        })),
+    }))
+}
+
+/// Compare sync files with the database
+#[utoipa::path(
+    post,
+    path = "/api/v1/sync/diff",
+    tag = "sync",
+    request_body = DiffSyncRequest,
+    responses(
+        (status = 200, description = "Per-entity added, modified and deleted record IDs (nothing imported)", body = SyncResponse),
+        (status = 400, description = "Invalid project id", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+pub async fn diff_sync<D: Database, G: GitOps + Send + Sync>(
+    State(state): State<AppState<D, G>>,
+    Json(req): Json<DiffSyncRequest>,
+) -> Result<Json<SyncResponse>, (StatusCode, Json<ErrorResponse>)> {
+    let manager = scoped_manager(&state, req.project.as_deref())?;
+    let diff = manager.diff(state.db(), req.remote).await.map_err(|e| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse {
+                error: e.to_string(),
+            }),
+        )
+    })?;
+
+    Ok(Json(SyncResponse {
+        status: "success".to_string(),
+        message: "Sync diff (nothing imported)".to_string(),
+        data: Some(serde_json::json!({
+            "added": diff.added(),
+            "modified": diff.modified(),
+            "deleted": diff.deleted(),
+            "entities": diff.entities,
         })),
     }))
 }
@@ -233,6 +432,8 @@ pub async fn get_sync_status<D: Database, G: GitOps + Send + Sync>(
         "git": status.git_status.as_ref().map(|git_status| serde_json::json!({
             "clean": git_status.clean,
             "status": if git_status.clean { "No changes" } else { &git_status.status_output },
+            "ahead": git_status.ahead,
+            "behind": git_status.behind,
         })),
         "database": status.db_counts.as_ref().map(|counts| serde_json::json!({
             "repos": counts.repos,
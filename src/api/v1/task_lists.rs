@@ -4,7 +4,8 @@ use crate::sync::GitOps;
 use axum::{
     Json,
     extract::{Path, Query, State},
-    http::StatusCode,
+    http::{HeaderMap, StatusCode},
+    response::Response,
 };
 use serde::{Deserialize, Serialize};
 use tracing::instrument;
@@ -12,13 +13,17 @@ use utoipa::{IntoParams, ToSchema};
 
 use crate::api::AppState;
 use crate::api::notifier::UpdateMessage;
-use crate::db::utils::current_timestamp;
+use crate::db::pagination::DEFAULT_PAGE_SIZE;
+use crate::db::utils::normalize_tags;
 use crate::db::{
-    Database, DbError, PageSort, SortOrder, TaskList, TaskListQuery, TaskListRepository,
-    TaskListStatus, TaskRepository, TaskStats,
+    Database, DbError, TaskList, TaskListQuery, TaskListRepository, TaskListStatus, TaskRepository,
+    TaskStats,
 };
 
-use super::ErrorResponse;
+use super::etag::conditional_json;
+use super::list_params::ListParams;
+use super::sorting::{TASK_LIST_SORT, page_sort};
+use super::{DeleteQuery, ErrorResponse};
 
 // =============================================================================
 // DTOs
@@ -78,8 +83,9 @@ pub struct CreateTaskListRequest {
     pub tags: Vec<String>,
     #[serde(default)]
     pub external_refs: Vec<String>,
-    /// Project ID this task list belongs to (REQUIRED - one project per task list)
-    pub project_id: String,
+    /// Project ID this task list belongs to (one project per task list).
+    /// Falls back to the configured default project when omitted.
+    pub project_id: Option<String>,
     #[serde(default)]
     pub repo_ids: Vec<String>,
 }
@@ -172,7 +178,7 @@ pub struct ListTaskListsQuery {
     /// Number of items to skip
     #[param(example = 0)]
     pub offset: Option<usize>,
-    /// Field to sort by (name, status, created_at, updated_at)
+    /// Field to sort by (name, status, created_at, updated_at; default updated_at desc)
     #[param(example = "created_at")]
     pub sort: Option<String>,
     /// Sort order (asc, desc)
@@ -180,6 +186,13 @@ pub struct ListTaskListsQuery {
     pub order: Option<String>,
 }
 
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct ArchiveTaskListQuery {
+    /// Also cancel every task still in backlog, todo, in_progress or review
+    #[serde(default)]
+    pub complete_open: bool,
+}
+
 #[derive(Serialize, ToSchema)]
 pub struct TaskStatsResponse {
     #[schema(example = "a1b2c3d4")]
@@ -234,6 +247,7 @@ pub struct PaginatedTaskLists {
     params(ListTaskListsQuery),
     responses(
         (status = 200, description = "Paginated list of task lists", body = PaginatedTaskLists),
+        (status = 400, description = "Invalid list parameters", body = ErrorResponse),
         (status = 500, description = "Internal server error", body = ErrorResponse)
     )
 )]
@@ -241,28 +255,13 @@ pub struct PaginatedTaskLists {
 pub async fn list_task_lists<D: Database, G: GitOps + Send + Sync>(
     State(state): State<AppState<D, G>>,
     Query(query): Query<ListTaskListsQuery>,
+    ListParams(list): ListParams,
 ) -> Result<Json<PaginatedTaskLists>, (StatusCode, Json<ErrorResponse>)> {
     // Build database query with tag filtering at DB level
-    let tags = query.tags.as_ref().map(|t| {
-        t.split(',')
-            .map(|s| s.trim().to_string())
-            .filter(|s| !s.is_empty())
-            .collect::<Vec<_>>()
-    });
-
     let db_query = TaskListQuery {
-        page: PageSort {
-            limit: query.limit,
-            offset: query.offset,
-            sort_by: query.sort.clone(),
-            sort_order: match query.order.as_deref() {
-                Some("desc") => Some(SortOrder::Desc),
-                Some("asc") => Some(SortOrder::Asc),
-                _ => None,
-            },
-        },
+        page: page_sort(Some(TASK_LIST_SORT), &list),
         status: query.status.clone(),
-        tags,
+        tags: list.tags.clone(),
         project_id: query.project_id.clone(),
     };
 
@@ -298,7 +297,7 @@ pub async fn list_task_lists<D: Database, G: GitOps + Send + Sync>(
     Ok(Json(PaginatedTaskLists {
         items,
         total: result.total,
-        limit: result.limit.unwrap_or(50),
+        limit: result.limit.unwrap_or(DEFAULT_PAGE_SIZE),
         offset: result.offset,
     }))
 }
@@ -310,6 +309,7 @@ pub async fn list_task_lists<D: Database, G: GitOps + Send + Sync>(
     params(("id" = String, Path, description = "TaskList ID")),
     responses(
         (status = 200, description = "TaskList found", body = TaskListResponse),
+        (status = 304, description = "Not modified (If-None-Match matched the ETag)"),
         (status = 404, description = "TaskList not found", body = ErrorResponse),
         (status = 500, description = "Internal server error", body = ErrorResponse)
     )
@@ -318,7 +318,8 @@ pub async fn list_task_lists<D: Database, G: GitOps + Send + Sync>(
 pub async fn get_task_list<D: Database, G: GitOps + Send + Sync>(
     State(state): State<AppState<D, G>>,
     Path(id): Path<String>,
-) -> Result<Json<TaskListResponse>, (StatusCode, Json<ErrorResponse>)> {
+    headers: HeaderMap,
+) -> Result<Response, (StatusCode, Json<ErrorResponse>)> {
     let list = state
         .db()
         .task_lists()
@@ -339,7 +340,7 @@ pub async fn get_task_list<D: Database, G: GitOps + Send + Sync>(
             ),
         })?;
 
-    Ok(Json(TaskListResponse::from(list)))
+    Ok(conditional_json(&headers, &TaskListResponse::from(list)))
 }
 
 #[utoipa::path(
@@ -349,6 +350,7 @@ pub async fn get_task_list<D: Database, G: GitOps + Send + Sync>(
     request_body = CreateTaskListRequest,
     responses(
         (status = 201, description = "TaskList created", body = TaskListResponse),
+        (status = 422, description = "No project_id given and no default project configured", body = ErrorResponse),
         (status = 500, description = "Internal server error", body = ErrorResponse)
     )
 )]
@@ -357,17 +359,30 @@ pub async fn create_task_list<D: Database, G: GitOps + Send + Sync>(
     State(state): State<AppState<D, G>>,
     Json(req): Json<CreateTaskListRequest>,
 ) -> Result<(StatusCode, Json<TaskListResponse>), (StatusCode, Json<ErrorResponse>)> {
+    let project_id = req
+        .project_id
+        .or_else(|| state.default_project().map(str::to_string))
+        .ok_or_else(|| {
+            (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                Json(ErrorResponse {
+                    error: "project_id is required when no default project is configured"
+                        .to_string(),
+                }),
+            )
+        })?;
+
     // Create task list with placeholder values - repository will generate ID and timestamps
     let list = TaskList {
         id: String::new(), // Repository will generate this
         title: req.title,
         description: req.description,
         notes: req.notes,
-        tags: req.tags,
+        tags: normalize_tags(req.tags, state.lowercase_tags()),
         external_refs: req.external_refs,
         status: TaskListStatus::Active,
         repo_ids: req.repo_ids,
-        project_id: req.project_id,
+        project_id,
         created_at: None, // Repository will generate this
         updated_at: None, // Repository will generate this
         archived_at: None,
@@ -434,7 +449,7 @@ pub async fn update_task_list<D: Database, G: GitOps + Send + Sync>(
     list.title = req.title;
     list.description = req.description;
     list.notes = req.notes;
-    list.tags = req.tags;
+    list.tags = normalize_tags(req.tags, state.lowercase_tags());
     list.external_refs = req.external_refs;
     list.repo_ids = req.repo_ids;
     if let Some(project_id) = req.project_id {
@@ -444,7 +459,7 @@ pub async fn update_task_list<D: Database, G: GitOps + Send + Sync>(
     if let Some(status) = req.status {
         list.status = match status.as_str() {
             "archived" => {
-                list.archived_at = Some(current_timestamp());
+                list.archived_at = Some(state.clock().timestamp());
                 TaskListStatus::Archived
             }
             _ => TaskListStatus::Active,
@@ -486,7 +501,7 @@ pub async fn update_task_list<D: Database, G: GitOps + Send + Sync>(
 pub async fn patch_task_list<D: Database, G: GitOps + Send + Sync>(
     State(state): State<AppState<D, G>>,
     Path(id): Path<String>,
-    Json(req): Json<PatchTaskListRequest>,
+    Json(mut req): Json<PatchTaskListRequest>,
 ) -> Result<Json<TaskListResponse>, (StatusCode, Json<ErrorResponse>)> {
     // Fetch existing task list
     let mut list = state
@@ -510,9 +525,19 @@ pub async fn patch_task_list<D: Database, G: GitOps + Send + Sync>(
         })?;
 
     // Merge PATCH changes
+    let was_archived = list.status == TaskListStatus::Archived;
+    req.tags = req
+        .tags
+        .map(|tags| normalize_tags(tags, state.lowercase_tags()));
     req.merge_into(&mut list);
 
-    // Save (repository handles auto-timestamps for archived_at)
+    // Stamp archived_at here so it follows the injected clock; the
+    // repository clears it again on unarchive
+    if !was_archived && list.status == TaskListStatus::Archived {
+        list.archived_at = Some(state.clock().timestamp());
+    }
+
+    // Save
     state.db().task_lists().update(&list).await.map_err(|e| {
         (
             StatusCode::INTERNAL_SERVER_ERROR,
@@ -540,26 +565,34 @@ pub async fn patch_task_list<D: Database, G: GitOps + Send + Sync>(
     Ok(Json(TaskListResponse::from(updated)))
 }
 
+/// Archive a task list
+///
+/// With `complete_open=true`, tasks that are still open are cancelled in the
+/// same transaction, so the archived list's stats add up. Archiving an
+/// archived list keeps its `archived_at`.
 #[utoipa::path(
-    delete,
-    path = "/api/v1/task-lists/{id}",
+    post,
+    path = "/api/v1/task-lists/{id}/archive",
     tag = "task-lists",
-    params(("id" = String, Path, description = "TaskList ID")),
+    params(
+        ("id" = String, Path, description = "TaskList ID"),
+        ArchiveTaskListQuery
+    ),
     responses(
-        (status = 204, description = "TaskList deleted"),
+        (status = 200, description = "TaskList archived", body = TaskListResponse),
         (status = 404, description = "TaskList not found", body = ErrorResponse),
         (status = 500, description = "Internal server error", body = ErrorResponse)
     )
 )]
 #[instrument(skip(state))]
-pub async fn delete_task_list<D: Database, G: GitOps + Send + Sync>(
+pub async fn archive_task_list<D: Database, G: GitOps + Send + Sync>(
     State(state): State<AppState<D, G>>,
     Path(id): Path<String>,
-) -> Result<StatusCode, (StatusCode, Json<ErrorResponse>)> {
-    state
-        .db()
-        .task_lists()
-        .delete(&id)
+    Query(query): Query<ArchiveTaskListQuery>,
+) -> Result<Json<TaskListResponse>, (StatusCode, Json<ErrorResponse>)> {
+    let task_lists = state.db().task_lists();
+    let cancelled = task_lists
+        .archive(&id, &state.clock().timestamp(), query.complete_open)
         .await
         .map_err(|e| match e {
             DbError::NotFound { .. } => (
@@ -576,6 +609,70 @@ pub async fn delete_task_list<D: Database, G: GitOps + Send + Sync>(
             ),
         })?;
 
+    // Broadcast notifications
+    for task_id in cancelled {
+        state.notifier().notify(UpdateMessage::TaskUpdated {
+            task_id,
+            changed: vec!["status".to_string()],
+        });
+    }
+    state.notifier().notify(UpdateMessage::TaskListUpdated {
+        task_list_id: id.clone(),
+    });
+
+    let archived = task_lists.get(&id).await.map_err(|e| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse {
+                error: e.to_string(),
+            }),
+        )
+    })?;
+
+    Ok(Json(TaskListResponse::from(archived)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/task-lists/{id}",
+    tag = "task-lists",
+    params(
+        ("id" = String, Path, description = "TaskList ID"),
+        DeleteQuery
+    ),
+    responses(
+        (status = 204, description = "TaskList deleted"),
+        (status = 404, description = "TaskList not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+#[instrument(skip(state))]
+pub async fn delete_task_list<D: Database, G: GitOps + Send + Sync>(
+    State(state): State<AppState<D, G>>,
+    Path(id): Path<String>,
+    Query(query): Query<DeleteQuery>,
+) -> Result<StatusCode, (StatusCode, Json<ErrorResponse>)> {
+    let task_lists = state.db().task_lists();
+    let result = if query.purge {
+        task_lists.purge(&id).await
+    } else {
+        task_lists.delete(&id).await
+    };
+    result.map_err(|e| match e {
+        DbError::NotFound { .. } => (
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse {
+                error: format!("TaskList '{}' not found", id),
+            }),
+        ),
+        _ => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse {
+                error: e.to_string(),
+            }),
+        ),
+    })?;
+
     // Broadcast notification
     state.notifier().notify(UpdateMessage::TaskListDeleted {
         task_list_id: id.clone(),
@@ -12,6 +12,7 @@ use tower::ServiceExt;
 use crate::a6s::store::surrealdb;
 use crate::api::notifier::{ChangeNotifier, UpdateMessage};
 use crate::api::{AppState, routes};
+use crate::db::clock::MockClock;
 use crate::db::utils::generate_entity_id;
 use crate::db::{Database, SqliteDatabase, TaskList, TaskListRepository};
 use tempfile::TempDir;
@@ -500,7 +501,7 @@ async fn crud_operations() {
         let task = json_body(task_response).await;
         let task_id = task["id"].as_str().unwrap();
 
-        if *status != "backlog" {
+        if *status != "todo" {
             app.clone()
                 .oneshot(
                     Request::builder()
@@ -828,3 +829,283 @@ async fn websocket_broadcasts() {
         _ => panic!("Expected TaskListDeleted, got {:?}", msg),
     }
 }
+
+/// Create a test app whose state has the given default project configured
+async fn test_app_with_default_project(default_project: Option<&str>) -> axum::Router {
+    let db = SqliteDatabase::in_memory()
+        .await
+        .expect("Failed to create test database");
+    db.migrate().expect("Failed to run migrations");
+
+    let temp_dir = TempDir::new().unwrap();
+
+    sqlx::query("INSERT OR IGNORE INTO project (id, title, description, tags, created_at, updated_at) VALUES (?, ?, ?, ?, ?, ?)")
+        .bind("test0000")
+        .bind("Test Project")
+        .bind("Default project for tests")
+        .bind("[]")
+        .bind("2025-01-01 00:00:00")
+        .bind("2025-01-01 00:00:00")
+        .execute(db.pool())
+        .await
+        .expect("Create test project should succeed");
+
+    let state = AppState::new(
+        db,
+        crate::sync::SyncManager::new(crate::sync::MockGitOps::new()),
+        ChangeNotifier::new(),
+        temp_dir.path().join("skills"),
+        Arc::new(surrealdb::init_db(None).await.unwrap()),
+        crate::a6s::tracker::AnalysisTracker::new(crate::api::notifier::ChangeNotifier::new()),
+    )
+    .with_default_project(default_project.map(str::to_string));
+    routes::create_router(state, false)
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn create_without_project_uses_default_project() {
+    let app = test_app_with_default_project(Some("test0000")).await;
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri("/api/v1/projects/default")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(json_body(response).await["id"], "test0000");
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/v1/task-lists")
+                .header("content-type", "application/json")
+                .body(Body::from(json!({"title": "No Project"}).to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    assert_eq!(json_body(response).await["project_id"], "test0000");
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn create_without_project_and_no_default_is_rejected() {
+    let app = test_app_with_default_project(None).await;
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri("/api/v1/projects/default")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/v1/task-lists")
+                .header("content-type", "application/json")
+                .body(Body::from(json!({"title": "No Project"}).to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn archive_stamps_archived_at_from_clock() {
+    let db = SqliteDatabase::in_memory()
+        .await
+        .expect("Failed to create test database");
+    db.migrate().expect("Failed to run migrations");
+    sqlx::query("INSERT OR IGNORE INTO project (id, title, description, tags, created_at, updated_at) VALUES (?, ?, ?, ?, ?, ?)")
+        .bind("test0000")
+        .bind("Test Project")
+        .bind("Default project for tests")
+        .bind("[]")
+        .bind("2025-01-01 00:00:00")
+        .bind("2025-01-01 00:00:00")
+        .execute(db.pool())
+        .await
+        .expect("Create test project should succeed");
+
+    let at = chrono::DateTime::parse_from_rfc3339("2030-06-15T12:30:45Z")
+        .unwrap()
+        .with_timezone(&chrono::Utc);
+    let temp_dir = TempDir::new().unwrap();
+    let state = AppState::new(
+        db,
+        crate::sync::SyncManager::new(crate::sync::MockGitOps::new()),
+        ChangeNotifier::new(),
+        temp_dir.path().join("skills"),
+        Arc::new(surrealdb::init_db(None).await.unwrap()),
+        crate::a6s::tracker::AnalysisTracker::new(ChangeNotifier::new()),
+    )
+    .with_clock(Arc::new(MockClock::new(at)));
+    let app = routes::create_router(state, false);
+
+    let request = |method: &str, uri: String, body: Value| {
+        Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("content-type", "application/json")
+            .body(Body::from(serde_json::to_vec(&body).unwrap()))
+            .unwrap()
+    };
+
+    let response = app
+        .clone()
+        .oneshot(request(
+            "POST",
+            "/api/v1/task-lists".to_string(),
+            json!({"title": "Clocked", "project_id": "test0000"}),
+        ))
+        .await
+        .unwrap();
+    let list_id = json_body(response).await["id"]
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    let response = app
+        .clone()
+        .oneshot(request(
+            "PATCH",
+            format!("/api/v1/task-lists/{}", list_id),
+            json!({"status": "archived"}),
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = json_body(response).await;
+    assert_eq!(body["status"], "archived");
+    assert_eq!(body["archived_at"], "2030-06-15 12:30:45");
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn archive_with_and_without_complete_open() {
+    let app = test_app().await;
+
+    let request = |method: &str, uri: String, body: Value| {
+        Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("content-type", "application/json")
+            .body(Body::from(serde_json::to_vec(&body).unwrap()))
+            .unwrap()
+    };
+
+    for complete_open in [false, true] {
+        let response = app
+            .clone()
+            .oneshot(request(
+                "POST",
+                "/api/v1/task-lists".to_string(),
+                json!({"title": "Sprint", "project_id": "test0000"}),
+            ))
+            .await
+            .unwrap();
+        let list_id = json_body(response).await["id"]
+            .as_str()
+            .unwrap()
+            .to_string();
+
+        for status in ["todo", "in_progress", "done"] {
+            let response = app
+                .clone()
+                .oneshot(request(
+                    "POST",
+                    format!("/api/v1/task-lists/{}/tasks", list_id),
+                    json!({"title": status, "status": status}),
+                ))
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::CREATED);
+        }
+
+        let response = app
+            .clone()
+            .oneshot(request(
+                "POST",
+                format!(
+                    "/api/v1/task-lists/{}/archive?complete_open={}",
+                    list_id, complete_open
+                ),
+                json!({}),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = json_body(response).await;
+        assert_eq!(body["status"], "archived");
+        assert!(body["archived_at"].is_string());
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .uri(format!("/api/v1/task-lists/{}/tasks", list_id))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let body = json_body(response).await;
+        let mut statuses: Vec<(String, String)> = body["items"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|t| {
+                (
+                    t["title"].as_str().unwrap().to_string(),
+                    t["status"].as_str().unwrap().to_string(),
+                )
+            })
+            .collect();
+        statuses.sort();
+
+        let expected = if complete_open {
+            [
+                ("done", "done"),
+                ("in_progress", "cancelled"),
+                ("todo", "cancelled"),
+            ]
+        } else {
+            [
+                ("done", "done"),
+                ("in_progress", "in_progress"),
+                ("todo", "todo"),
+            ]
+        };
+        let expected: Vec<(String, String)> = expected
+            .iter()
+            .map(|(t, s)| (t.to_string(), s.to_string()))
+            .collect();
+        assert_eq!(statuses, expected, "complete_open={}", complete_open);
+    }
+
+    let response = app
+        .clone()
+        .oneshot(request(
+            "POST",
+            "/api/v1/task-lists/nope0000/archive".to_string(),
+            json!({}),
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
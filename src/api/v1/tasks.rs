@@ -7,17 +7,22 @@ use axum::{
     http::StatusCode,
 };
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use tracing::instrument;
 use utoipa::{IntoParams, ToSchema};
 
 use crate::api::AppState;
-use crate::api::notifier::UpdateMessage;
+use crate::api::notifier::{UpdateMessage, changed_fields};
+use crate::db::pagination::DEFAULT_PAGE_SIZE;
+use crate::db::utils::normalize_tags;
 use crate::db::{
-    Database, DbError, PageSort, SortOrder, Task, TaskQuery, TaskRepository, TaskStatus,
+    Database, DbError, Task, TaskListRepository, TaskQuery, TaskRepository, TaskStatus,
     TransitionLog,
 };
 
-use super::ErrorResponse;
+use super::list_params::ListParams;
+use super::sorting::{page_sort, task_sort};
+use super::{DeleteQuery, ErrorResponse};
 
 // =============================================================================
 // Validation Helpers
@@ -55,6 +60,10 @@ pub struct TaskResponse {
     pub external_refs: Vec<String>,
     pub created_at: Option<String>,
     pub updated_at: Option<String>,
+    /// Nested subtasks, only present in `tree=true` listings
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(no_recursion)]
+    pub subtasks: Option<Vec<TaskResponse>>,
 }
 
 impl From<Task> for TaskResponse {
@@ -79,6 +88,7 @@ impl From<Task> for TaskResponse {
             external_refs: t.external_refs,
             created_at: t.created_at,
             updated_at: t.updated_at,
+            subtasks: None,
         }
     }
 }
@@ -128,12 +138,23 @@ pub struct TransitionsQueryParams {
     pub offset: Option<usize>,
 }
 
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct CompleteTaskQuery {
+    /// Also mark every open subtask (recursively) as done
+    #[serde(default)]
+    pub cascade: bool,
+}
+
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct CreateTaskRequest {
     #[schema(example = "Complete the feature")]
     pub title: String,
     pub description: Option<String>,
     pub parent_id: Option<String>,
+    /// Initial status: backlog, todo, in_progress, review, done or cancelled.
+    /// Defaults to the server's configured default (`todo` unless changed).
+    #[schema(example = "todo")]
+    pub status: Option<String>,
     /// Priority: 1 (highest) to 5 (lowest). Defaults to 5 (P5) if not provided.
     #[schema(example = 2)]
     pub priority: Option<i32>,
@@ -226,14 +247,62 @@ impl PatchTaskRequest {
     }
 }
 
+/// Bulk reparent request DTO
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ReparentTasksRequest {
+    /// Tasks to move
+    #[schema(example = json!(["a1b2c3d4", "e5f6a7b8"]))]
+    pub ids: Vec<String>,
+    /// New parent task ID, or null to move the tasks to the top level
+    #[schema(example = "abc123de")]
+    pub new_parent: Option<String>,
+}
+
+/// Comma-separated list of statuses used as a list filter.
+///
+/// Unlike the lenient parsing used when reading rows back, every entry must be
+/// a known status so client typos surface as a 400 instead of an empty page.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaskStatusFilter(pub Vec<TaskStatus>);
+
+impl TaskStatusFilter {
+    /// Render back into the comma-separated form expected by `TaskQuery`
+    fn to_query(&self) -> String {
+        self.0
+            .iter()
+            .map(TaskStatus::to_string)
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
+
+impl std::str::FromStr for TaskStatusFilter {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split(',')
+            .map(|part| part.trim().parse::<TaskStatus>())
+            .collect::<Result<Vec<_>, _>>()
+            .map(TaskStatusFilter)
+    }
+}
+
+impl<'de> Deserialize<'de> for TaskStatusFilter {
+    fn deserialize<De: serde::Deserializer<'de>>(deserializer: De) -> Result<Self, De::Error> {
+        let raw = String::deserialize(deserializer)?;
+        raw.parse().map_err(serde::de::Error::custom)
+    }
+}
+
 #[derive(Debug, Deserialize, IntoParams)]
 pub struct ListTasksQuery {
     /// FTS5 search query (optional)
     #[param(example = "rust backend")]
     pub q: Option<String>,
-    /// Filter by status (backlog, todo, in_progress, review, done, cancelled)
-    #[param(example = "in_progress")]
-    pub status: Option<String>,
+    /// Filter by status, comma-separated (backlog, todo, in_progress, review, done, cancelled).
+    /// Unknown values are rejected with 400 rather than silently ignored.
+    #[param(value_type = Option<String>, example = "todo,in_progress")]
+    pub status: Option<TaskStatusFilter>,
     /// Filter by parent task ID (for subtasks)
     #[param(example = "a1b2c3d4")]
     pub parent_id: Option<String>,
@@ -243,7 +312,10 @@ pub struct ListTasksQuery {
     /// Number of items to skip
     #[param(example = 0)]
     pub offset: Option<usize>,
-    /// Field to sort by (content, status, priority, created_at)
+    /// Cursor from a previous page's `next_cursor`; takes precedence over `offset`.
+    /// Only valid when sorting by created_at.
+    pub cursor: Option<String>,
+    /// Field to sort by (content, status, priority, created_at; default depends on the status filter)
     #[param(example = "created_at")]
     pub sort: Option<String>,
     /// Sort order (asc, desc)
@@ -254,6 +326,10 @@ pub struct ListTasksQuery {
     #[param(example = "task")]
     #[serde(rename = "type")]
     pub task_type: Option<String>,
+    /// Return top-level tasks with their subtasks nested under `subtasks`.
+    /// Pagination and filters apply to the top-level tasks only.
+    #[serde(default)]
+    pub tree: bool,
 }
 
 #[derive(Serialize, ToSchema)]
@@ -262,6 +338,9 @@ pub struct PaginatedTasks {
     pub total: usize,
     pub limit: usize,
     pub offset: usize,
+    /// Pass as `cursor` with the same `sort` to fetch the next page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
 }
 
 // =============================================================================
@@ -278,6 +357,7 @@ pub struct PaginatedTasks {
     ),
     responses(
         (status = 200, description = "Paginated list of tasks", body = PaginatedTasks),
+        (status = 400, description = "Invalid query parameters"),
         (status = 500, description = "Internal server error", body = ErrorResponse)
     )
 )]
@@ -286,24 +366,31 @@ pub async fn list_tasks<D: Database, G: GitOps + Send + Sync>(
     State(state): State<AppState<D, G>>,
     Path(list_id): Path<String>,
     Query(query): Query<ListTasksQuery>,
+    ListParams(list): ListParams,
 ) -> Result<Json<PaginatedTasks>, (StatusCode, Json<ErrorResponse>)> {
-    // Build database query
+    let status = query.status.as_ref().map(TaskStatusFilter::to_query);
+
+    if query.tree && (query.q.is_some() || query.parent_id.is_some() || query.task_type.is_some()) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse {
+                error: "tree cannot be combined with q, parent_id or type".to_string(),
+            }),
+        ));
+    }
+
+    // Build database query; a tree is paged by its top-level tasks
     let db_query = TaskQuery {
-        page: PageSort {
-            limit: query.limit,
-            offset: query.offset,
-            sort_by: query.sort.clone(),
-            sort_order: match query.order.as_deref() {
-                Some("desc") => Some(SortOrder::Desc),
-                Some("asc") => Some(SortOrder::Asc),
-                _ => None,
-            },
-        },
+        page: page_sort(Some(task_sort(status.as_deref())), &list),
         list_id: Some(list_id),
         parent_id: query.parent_id.clone(),
-        status: query.status.clone(),
+        status,
         tags: None,
-        task_type: query.task_type.clone(),
+        task_type: if query.tree {
+            Some("task".to_string())
+        } else {
+            query.task_type.clone()
+        },
     };
 
     // Use search if query provided, otherwise list
@@ -321,21 +408,44 @@ pub async fn list_tasks<D: Database, G: GitOps + Send + Sync>(
         state.db().tasks().list(Some(&db_query)).await
     }
     .map_err(|e| {
+        let status = match e {
+            DbError::Validation { .. } => StatusCode::BAD_REQUEST,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        };
         (
-            StatusCode::INTERNAL_SERVER_ERROR,
+            status,
             Json(ErrorResponse {
                 error: e.to_string(),
             }),
         )
     })?;
 
-    let items: Vec<TaskResponse> = result.items.into_iter().map(TaskResponse::from).collect();
+    let items: Vec<TaskResponse> = if query.tree {
+        let root_ids: Vec<String> = result.items.iter().map(|t| t.id.clone()).collect();
+        let descendants = state
+            .db()
+            .tasks()
+            .list_descendants(&root_ids)
+            .await
+            .map_err(|e| {
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(ErrorResponse {
+                        error: e.to_string(),
+                    }),
+                )
+            })?;
+        nest_subtasks(result.items, descendants)
+    } else {
+        result.items.into_iter().map(TaskResponse::from).collect()
+    };
 
     Ok(Json(PaginatedTasks {
         items,
         total: result.total,
-        limit: result.limit.unwrap_or(50),
+        limit: result.limit.unwrap_or(DEFAULT_PAGE_SIZE),
         offset: result.offset,
+        next_cursor: result.next_cursor,
     }))
 }
 
@@ -381,6 +491,9 @@ pub async fn get_task<D: Database, G: GitOps + Send + Sync>(
     request_body = CreateTaskRequest,
     responses(
         (status = 201, description = "Task created", body = TaskResponse),
+        (status = 400, description = "Invalid priority", body = ErrorResponse),
+        (status = 404, description = "Task list or parent task not found", body = ErrorResponse),
+        (status = 422, description = "Unknown status", body = ErrorResponse),
         (status = 500, description = "Internal server error", body = ErrorResponse)
     )
 )]
@@ -390,23 +503,8 @@ pub async fn create_task<D: Database, G: GitOps + Send + Sync>(
     Path(list_id): Path<String>,
     Json(req): Json<CreateTaskRequest>,
 ) -> Result<(StatusCode, Json<TaskResponse>), (StatusCode, Json<ErrorResponse>)> {
-    // Validate priority before applying default
-    validate_priority(req.priority)
-        .map_err(|e| (StatusCode::BAD_REQUEST, Json(ErrorResponse { error: e })))?;
-
-    let task = Task {
-        id: String::new(), // Repository will generate this
-        list_id: list_id.clone(),
-        parent_id: req.parent_id,
-        title: req.title,
-        description: req.description,
-        status: TaskStatus::Backlog,
-        priority: req.priority.or(Some(5)), // Default to P5 (lowest priority)
-        tags: req.tags,
-        external_refs: req.external_refs,
-        created_at: None, // Repository will generate this
-        updated_at: None, // Repository will generate this
-    };
+    let task = task_from_request(&state, &list_id, req)?;
+    ensure_task_refs(&state, &list_id, task.parent_id.as_deref()).await?;
 
     let created_task = state.db().tasks().create(&task).await.map_err(|e| {
         (
@@ -425,6 +523,78 @@ pub async fn create_task<D: Database, G: GitOps + Send + Sync>(
     Ok((StatusCode::CREATED, Json(TaskResponse::from(created_task))))
 }
 
+/// Create tasks in bulk
+///
+/// Inserts every task in one transaction and returns them in request order.
+/// If any task is rejected nothing is created, and the error names the
+/// index of the failing task.
+#[utoipa::path(
+    post,
+    path = "/api/v1/task-lists/{list_id}/tasks/batch",
+    tag = "tasks",
+    params(("list_id" = String, Path, description = "TaskList ID")),
+    request_body = Vec<CreateTaskRequest>,
+    responses(
+        (status = 201, description = "Tasks created, in request order", body = Vec<TaskResponse>),
+        (status = 400, description = "Invalid priority or nesting", body = ErrorResponse),
+        (status = 404, description = "Task list or parent task not found", body = ErrorResponse),
+        (status = 422, description = "Unknown status", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+#[instrument(skip(state, reqs))]
+pub async fn create_tasks_batch<D: Database, G: GitOps + Send + Sync>(
+    State(state): State<AppState<D, G>>,
+    Path(list_id): Path<String>,
+    Json(reqs): Json<Vec<CreateTaskRequest>>,
+) -> Result<(StatusCode, Json<Vec<TaskResponse>>), (StatusCode, Json<ErrorResponse>)> {
+    ensure_task_refs(&state, &list_id, None).await?;
+
+    let tasks = reqs
+        .into_iter()
+        .enumerate()
+        .map(|(index, req)| {
+            task_from_request(&state, &list_id, req).map_err(|(status, Json(e))| {
+                (
+                    status,
+                    Json(ErrorResponse {
+                        error: format!("Task at index {}: {}", index, e.error),
+                    }),
+                )
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let created = state.db().tasks().create_many(&tasks).await.map_err(|e| {
+        let status = match &e {
+            DbError::BatchItem { source, .. } => match source.as_ref() {
+                DbError::NotFound { .. } => StatusCode::NOT_FOUND,
+                DbError::Validation { .. } => StatusCode::BAD_REQUEST,
+                _ => StatusCode::INTERNAL_SERVER_ERROR,
+            },
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        };
+        let error = match e {
+            DbError::BatchItem { index, source } => {
+                format!("Task at index {}: {}", index, source)
+            }
+            e => e.to_string(),
+        };
+        (status, Json(ErrorResponse { error }))
+    })?;
+
+    for task in &created {
+        state.notifier().notify(UpdateMessage::TaskCreated {
+            task_id: task.id.clone(),
+        });
+    }
+
+    Ok((
+        StatusCode::CREATED,
+        Json(created.into_iter().map(TaskResponse::from).collect()),
+    ))
+}
+
 #[utoipa::path(
     put,
     path = "/api/v1/tasks/{id}",
@@ -461,11 +631,12 @@ pub async fn update_task<D: Database, G: GitOps + Send + Sync>(
             }),
         ),
     })?;
+    let before = task.clone();
 
     task.title = req.title;
     task.description = req.description;
     task.priority = req.priority;
-    task.tags = req.tags;
+    task.tags = normalize_tags(req.tags, state.lowercase_tags());
     task.external_refs = req.external_refs;
     task.updated_at = None;
 
@@ -486,8 +657,11 @@ pub async fn update_task<D: Database, G: GitOps + Send + Sync>(
     // Broadcast TaskUpdated notification
     state.notifier().notify(UpdateMessage::TaskUpdated {
         task_id: id.clone(),
+        changed: changed_fields(&before, &task),
     });
 
+    rollup_parents(&state, &id).await?;
+
     Ok(Json(TaskResponse::from(task)))
 }
 
@@ -502,7 +676,8 @@ pub async fn update_task<D: Database, G: GitOps + Send + Sync>(
     request_body = PatchTaskRequest,
     responses(
         (status = 200, description = "Task updated", body = TaskResponse),
-        (status = 404, description = "Task not found", body = ErrorResponse),
+        (status = 400, description = "Invalid priority, or the new parent would nest too deep or make the task its own ancestor", body = ErrorResponse),
+        (status = 404, description = "Task, target task list or parent task not found", body = ErrorResponse),
         (status = 500, description = "Internal server error", body = ErrorResponse)
     )
 )]
@@ -510,7 +685,7 @@ pub async fn update_task<D: Database, G: GitOps + Send + Sync>(
 pub async fn patch_task<D: Database, G: GitOps + Send + Sync>(
     State(state): State<AppState<D, G>>,
     Path(id): Path<String>,
-    Json(req): Json<PatchTaskRequest>,
+    Json(mut req): Json<PatchTaskRequest>,
 ) -> Result<Json<TaskResponse>, (StatusCode, Json<ErrorResponse>)> {
     // Validate priority if provided
     validate_priority(req.priority)
@@ -532,13 +707,32 @@ pub async fn patch_task<D: Database, G: GitOps + Send + Sync>(
         ),
     })?;
 
+    let before = task.clone();
+
+    // Only re-check references the request actually changes
+    let moves_list = req.list_id.is_some();
+    let new_parent = req.parent_id.clone().flatten();
+
     // Merge PATCH changes
+    req.tags = req
+        .tags
+        .map(|tags| normalize_tags(tags, state.lowercase_tags()));
     req.merge_into(&mut task);
 
+    if moves_list || new_parent.is_some() {
+        ensure_task_refs(&state, &task.list_id, new_parent.as_deref()).await?;
+    }
+
     // Save (repository will log transition if status changed)
+    // Moves that nest too deep or would make the task its own ancestor are
+    // rejected by the repository
     state.db().tasks().update(&task).await.map_err(|e| {
+        let status = match e {
+            DbError::Validation { .. } => StatusCode::BAD_REQUEST,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        };
         (
-            StatusCode::INTERNAL_SERVER_ERROR,
+            status,
             Json(ErrorResponse {
                 error: e.to_string(),
             }),
@@ -558,8 +752,11 @@ pub async fn patch_task<D: Database, G: GitOps + Send + Sync>(
     // Broadcast TaskUpdated notification
     state.notifier().notify(UpdateMessage::TaskUpdated {
         task_id: id.clone(),
+        changed: changed_fields(&before, &updated),
     });
 
+    rollup_parents(&state, &id).await?;
+
     Ok(Json(TaskResponse::from(updated)))
 }
 
@@ -567,7 +764,10 @@ pub async fn patch_task<D: Database, G: GitOps + Send + Sync>(
     delete,
     path = "/api/v1/tasks/{id}",
     tag = "tasks",
-    params(("id" = String, Path, description = "Task ID")),
+    params(
+        ("id" = String, Path, description = "Task ID"),
+        DeleteQuery
+    ),
     responses(
         (status = 204, description = "Task deleted"),
         (status = 404, description = "Task not found", body = ErrorResponse),
@@ -578,8 +778,15 @@ pub async fn patch_task<D: Database, G: GitOps + Send + Sync>(
 pub async fn delete_task<D: Database, G: GitOps + Send + Sync>(
     State(state): State<AppState<D, G>>,
     Path(id): Path<String>,
+    Query(query): Query<DeleteQuery>,
 ) -> Result<StatusCode, (StatusCode, Json<ErrorResponse>)> {
-    state.db().tasks().delete(&id).await.map_err(|e| match e {
+    let tasks = state.db().tasks();
+    let result = if query.purge {
+        tasks.purge(&id).await
+    } else {
+        tasks.delete(&id).await
+    };
+    result.map_err(|e| match e {
         DbError::NotFound { .. } => (
             StatusCode::NOT_FOUND,
             Json(ErrorResponse {
@@ -602,6 +809,135 @@ pub async fn delete_task<D: Database, G: GitOps + Send + Sync>(
     Ok(StatusCode::NO_CONTENT)
 }
 
+/// Complete a task
+///
+/// Marks the task done. With `cascade=true`, every open descendant is marked
+/// done in the same transaction.
+#[utoipa::path(
+    patch,
+    path = "/api/v1/tasks/{id}/complete",
+    tag = "tasks",
+    params(
+        ("id" = String, Path, description = "Task ID"),
+        CompleteTaskQuery
+    ),
+    responses(
+        (status = 200, description = "Task completed", body = TaskResponse),
+        (status = 404, description = "Task not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+#[instrument(skip(state))]
+pub async fn complete_task<D: Database, G: GitOps + Send + Sync>(
+    State(state): State<AppState<D, G>>,
+    Path(id): Path<String>,
+    Query(query): Query<CompleteTaskQuery>,
+) -> Result<Json<TaskResponse>, (StatusCode, Json<ErrorResponse>)> {
+    let completed = state
+        .db()
+        .tasks()
+        .complete_task(&id, query.cascade)
+        .await
+        .map_err(|e| match e {
+            DbError::NotFound { .. } => (
+                StatusCode::NOT_FOUND,
+                Json(ErrorResponse {
+                    error: format!("Task '{}' not found", id),
+                }),
+            ),
+            _ => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: e.to_string(),
+                }),
+            ),
+        })?;
+
+    for task in &completed {
+        state.notifier().notify(UpdateMessage::TaskUpdated {
+            task_id: task.id.clone(),
+            changed: vec!["status".to_string()],
+        });
+    }
+
+    rollup_parents(&state, &id).await?;
+
+    let task = completed.into_iter().next().ok_or_else(|| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse {
+                error: format!("Task '{}' missing after completion", id),
+            }),
+        )
+    })?;
+
+    Ok(Json(TaskResponse::from(task)))
+}
+
+/// Reparent tasks
+///
+/// Moves every listed task under `new_parent` (or to the top level when it
+/// is null) in one transaction. The parent must be a top-level task in the
+/// same list as the moved tasks.
+#[utoipa::path(
+    post,
+    path = "/api/v1/tasks/reparent",
+    tag = "tasks",
+    request_body = ReparentTasksRequest,
+    responses(
+        (status = 200, description = "Tasks moved, in request order", body = Vec<TaskResponse>),
+        (status = 400, description = "Empty ids, tasks in another list than the parent, or a move that would nest too deep or make a task its own ancestor", body = ErrorResponse),
+        (status = 404, description = "Task or parent task not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+#[instrument(skip(state))]
+pub async fn reparent_tasks<D: Database, G: GitOps + Send + Sync>(
+    State(state): State<AppState<D, G>>,
+    Json(req): Json<ReparentTasksRequest>,
+) -> Result<Json<Vec<TaskResponse>>, (StatusCode, Json<ErrorResponse>)> {
+    // Snapshot the tasks so each notification can name the fields that moved;
+    // unknown IDs are left to reparent_tasks to reject
+    let mut before = HashMap::new();
+    for id in &req.ids {
+        if let Ok(task) = state.db().tasks().get(id).await {
+            before.insert(id.clone(), task);
+        }
+    }
+
+    let moved = state
+        .db()
+        .tasks()
+        .reparent_tasks(&req.ids, req.new_parent.as_deref())
+        .await
+        .map_err(|e| {
+            let status = match e {
+                DbError::NotFound { .. } => StatusCode::NOT_FOUND,
+                DbError::Validation { .. } => StatusCode::BAD_REQUEST,
+                _ => StatusCode::INTERNAL_SERVER_ERROR,
+            };
+            (
+                status,
+                Json(ErrorResponse {
+                    error: e.to_string(),
+                }),
+            )
+        })?;
+
+    for task in &moved {
+        state.notifier().notify(UpdateMessage::TaskUpdated {
+            task_id: task.id.clone(),
+            changed: before
+                .get(&task.id)
+                .map(|before| changed_fields(before, task))
+                .unwrap_or_else(|| vec!["parent_id".to_string()]),
+        });
+        rollup_parents(&state, &task.id).await?;
+    }
+
+    Ok(Json(moved.into_iter().map(TaskResponse::from).collect()))
+}
+
 /// Get task transitions
 ///
 /// Returns the list of all state transitions for a task, ordered by newest first.
@@ -672,6 +1008,145 @@ pub async fn get_task_transitions<D: Database, G: GitOps + Send + Sync>(
 // Helpers
 // =============================================================================
 
+/// Validate a create request and build the task to insert.
+fn task_from_request<D: Database, G: GitOps + Send + Sync>(
+    state: &AppState<D, G>,
+    list_id: &str,
+    req: CreateTaskRequest,
+) -> Result<Task, (StatusCode, Json<ErrorResponse>)> {
+    // Validate priority before applying default
+    validate_priority(req.priority)
+        .map_err(|e| (StatusCode::BAD_REQUEST, Json(ErrorResponse { error: e })))?;
+    let status = match req.status.as_deref() {
+        Some(s) => s.parse::<TaskStatus>().map_err(|_| {
+            (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                Json(ErrorResponse {
+                    error: format!(
+                        "Invalid status '{}'. Expected one of: backlog, todo, in_progress, review, done, cancelled",
+                        s
+                    ),
+                }),
+            )
+        })?,
+        None => state.default_task_status().clone(),
+    };
+
+    Ok(Task {
+        id: String::new(), // Repository will generate this
+        list_id: list_id.to_string(),
+        parent_id: req.parent_id,
+        title: req.title,
+        description: req.description,
+        status,
+        priority: req.priority.or(Some(5)), // Default to P5 (lowest priority)
+        tags: normalize_tags(req.tags, state.lowercase_tags()),
+        external_refs: req.external_refs,
+        created_at: None, // Repository will generate this
+        updated_at: None, // Repository will generate this
+    })
+}
+
+/// Complete finished parent tasks after a status change, if the server opts in.
+/// Check that the list and parent a task points at exist.
+///
+/// Without this a dangling reference only surfaces as a foreign key failure
+/// (500); callers get a 404 naming the missing entity instead.
+async fn ensure_task_refs<D: Database, G: GitOps + Send + Sync>(
+    state: &AppState<D, G>,
+    list_id: &str,
+    parent_id: Option<&str>,
+) -> Result<(), (StatusCode, Json<ErrorResponse>)> {
+    let not_found = |error: String| (StatusCode::NOT_FOUND, Json(ErrorResponse { error }));
+    let internal = |e: DbError| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse {
+                error: e.to_string(),
+            }),
+        )
+    };
+
+    match state.db().task_lists().get(list_id).await {
+        Ok(_) => {}
+        Err(DbError::NotFound { .. }) => {
+            return Err(not_found(format!("Task list '{}' not found", list_id)));
+        }
+        Err(e) => return Err(internal(e)),
+    }
+
+    if let Some(parent_id) = parent_id {
+        match state.db().tasks().get(parent_id).await {
+            Ok(_) => {}
+            Err(DbError::NotFound { .. }) => {
+                return Err(not_found(format!("Parent task '{}' not found", parent_id)));
+            }
+            Err(e) => return Err(internal(e)),
+        }
+    }
+
+    Ok(())
+}
+
+/// Embed each task's descendants under it, keeping their order.
+fn nest_subtasks(roots: Vec<Task>, descendants: Vec<Task>) -> Vec<TaskResponse> {
+    fn attach(task: Task, children: &mut HashMap<String, Vec<Task>>) -> TaskResponse {
+        let subtasks = children
+            .remove(&task.id)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|child| attach(child, children))
+            .collect();
+        TaskResponse {
+            subtasks: Some(subtasks),
+            ..TaskResponse::from(task)
+        }
+    }
+
+    let mut children: HashMap<String, Vec<Task>> = HashMap::new();
+    for task in descendants {
+        if let Some(parent_id) = task.parent_id.clone() {
+            children.entry(parent_id).or_default().push(task);
+        }
+    }
+
+    roots
+        .into_iter()
+        .map(|task| attach(task, &mut children))
+        .collect()
+}
+
+async fn rollup_parents<D: Database, G: GitOps + Send + Sync>(
+    state: &AppState<D, G>,
+    task_id: &str,
+) -> Result<(), (StatusCode, Json<ErrorResponse>)> {
+    if !state.auto_complete_parents() {
+        return Ok(());
+    }
+
+    let completed = state
+        .db()
+        .tasks()
+        .complete_finished_parents(task_id)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: e.to_string(),
+                }),
+            )
+        })?;
+    for parent in completed {
+        state.notifier().notify(UpdateMessage::TaskUpdated {
+            task_id: parent.id,
+            changed: vec!["status".to_string()],
+        });
+    }
+
+    Ok(())
+}
+
 fn parse_status(s: &str) -> TaskStatus {
     match s {
         "todo" => TaskStatus::Todo,
@@ -276,7 +276,7 @@ async fn status_and_cascade_operations() {
         .await
         .unwrap();
     let sub2_body = json_body(sub2_check).await;
-    assert_eq!(sub2_body["status"], "backlog"); // Remains in backlog - no cascading!
+    assert_eq!(sub2_body["status"], "todo"); // Keeps its initial status - no cascading!
 }
 
 // =============================================================================
@@ -650,7 +650,7 @@ async fn websocket_broadcasts() {
         .await
         .expect("Should receive update broadcast");
     match msg {
-        crate::api::notifier::UpdateMessage::TaskUpdated { task_id: id } => {
+        crate::api::notifier::UpdateMessage::TaskUpdated { task_id: id, .. } => {
             assert_eq!(id, task_id);
         }
         _ => panic!("Expected TaskUpdated, got {:?}", msg),
@@ -680,6 +680,79 @@ async fn websocket_broadcasts() {
     }
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn status_patch_broadcasts_only_status_as_changed() {
+    let (app, notifier) = test_app_with_notifier().await;
+
+    let list = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/v1/task-lists")
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    serde_json::to_vec(&json!({
+                        "title": "Test List",
+                        "project_id": "test0000"
+                    }))
+                    .unwrap(),
+                ))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let list_id = json_body(list).await["id"].as_str().unwrap().to_string();
+
+    let task = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(format!("/api/v1/task-lists/{}/tasks", list_id))
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    serde_json::to_vec(&json!({"title": "Test", "tags": ["a"]})).unwrap(),
+                ))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let task_id = json_body(task).await["id"].as_str().unwrap().to_string();
+
+    let mut subscriber = notifier.subscribe();
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("PATCH")
+                .uri(format!("/api/v1/tasks/{}", task_id))
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    serde_json::to_vec(&json!({"status": "in_progress"})).unwrap(),
+                ))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let msg = subscriber
+        .recv()
+        .await
+        .expect("Should receive update broadcast");
+    assert_eq!(
+        msg,
+        crate::api::notifier::UpdateMessage::TaskUpdated {
+            task_id,
+            changed: vec!["status".to_string()],
+        }
+    );
+
+    // The wire format carries the field list next to the id
+    let wire = serde_json::to_value(&msg).unwrap();
+    assert_eq!(wire["data"]["changed"], json!(["status"]));
+}
+
 // =============================================================================
 // FTS5 Search
 // =============================================================================
@@ -890,7 +963,7 @@ async fn test_get_task_transitions() {
     let task_body = json_body(task_response).await;
     let task_id = task_body["id"].as_str().unwrap();
 
-    // Should have initial backlog transition
+    // Should have the initial transition into the default status
     let response = app
         .clone()
         .oneshot(
@@ -905,7 +978,7 @@ async fn test_get_task_transitions() {
     let body = json_body(response).await;
     let items = body["items"].as_array().unwrap();
     assert_eq!(items.len(), 1);
-    assert_eq!(items[0]["status"], "backlog");
+    assert_eq!(items[0]["status"], "todo");
 
     // Transition to in_progress
     app.clone()
@@ -941,7 +1014,7 @@ async fn test_get_task_transitions() {
         .iter()
         .map(|i| i["status"].as_str().unwrap())
         .collect();
-    assert!(statuses.contains(&"backlog"));
+    assert!(statuses.contains(&"todo"));
     assert!(statuses.contains(&"in_progress"));
 
     // Transition to done
@@ -977,7 +1050,608 @@ async fn test_get_task_transitions() {
         .iter()
         .map(|i| i["status"].as_str().unwrap())
         .collect();
-    assert!(statuses.contains(&"backlog"));
+    assert!(statuses.contains(&"todo"));
     assert!(statuses.contains(&"in_progress"));
     assert!(statuses.contains(&"done"));
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn list_tasks_status_filter_is_validated() {
+    let app = test_app().await;
+
+    let list = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/v1/task-lists")
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    serde_json::to_vec(&json!({
+                        "title": "Status Filter List",
+                        "project_id": "test0000"
+                    }))
+                    .unwrap(),
+                ))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let list_id = json_body(list).await["id"].as_str().unwrap().to_string();
+
+    let mut ids = Vec::new();
+    for title in ["Stays in todo", "Goes to review"] {
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!("/api/v1/task-lists/{}/tasks", list_id))
+                    .header("content-type", "application/json")
+                    .body(Body::from(
+                        serde_json::to_vec(&json!({"title": title})).unwrap(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        ids.push(
+            json_body(response).await["id"]
+                .as_str()
+                .unwrap()
+                .to_string(),
+        );
+    }
+    let review_id = &ids[1];
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("PATCH")
+                .uri(format!("/api/v1/tasks/{}", review_id))
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    serde_json::to_vec(&json!({"status": "review"})).unwrap(),
+                ))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // Known status filters correctly
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri(format!(
+                    "/api/v1/task-lists/{}/tasks?status=review",
+                    list_id
+                ))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = json_body(response).await;
+    assert_eq!(body["total"], 1);
+    assert_eq!(body["items"][0]["id"], review_id.as_str());
+
+    // Unknown status is rejected instead of matching nothing
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri(format!("/api/v1/task-lists/{}/tasks?status=bogus", list_id))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    // A single bad entry in a comma-separated list is rejected too
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri(format!(
+                    "/api/v1/task-lists/{}/tasks?status=review,revew",
+                    list_id
+                ))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn patch_task_rejects_moves_that_create_a_cycle() {
+    let app = test_app().await;
+
+    let list = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/v1/task-lists")
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    serde_json::to_vec(&json!({
+                        "title": "Move List",
+                        "project_id": "test0000"
+                    }))
+                    .unwrap(),
+                ))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let list_id = json_body(list).await["id"].as_str().unwrap().to_string();
+
+    let mut parent_id: Option<String> = None;
+    for title in ["Parent", "Subtask"] {
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!("/api/v1/task-lists/{}/tasks", list_id))
+                    .header("content-type", "application/json")
+                    .body(Body::from(
+                        serde_json::to_vec(&json!({"title": title, "parent_id": parent_id}))
+                            .unwrap(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let id = json_body(response).await["id"]
+            .as_str()
+            .unwrap()
+            .to_string();
+        parent_id.get_or_insert(id);
+    }
+    let parent_id = parent_id.unwrap();
+
+    // Self-parenting is a validation error, not a server error
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("PATCH")
+                .uri(format!("/api/v1/tasks/{}", parent_id))
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    serde_json::to_vec(&json!({"parent_id": parent_id})).unwrap(),
+                ))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let body = json_body(response).await;
+    assert!(
+        body["error"].as_str().unwrap().contains("own ancestor"),
+        "unexpected error: {}",
+        body["error"]
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn create_task_defaults_and_validates_status() {
+    let app = test_app().await;
+
+    let list = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/v1/task-lists")
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    serde_json::to_vec(&json!({
+                        "title": "Default Status List",
+                        "project_id": "test0000"
+                    }))
+                    .unwrap(),
+                ))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let list_id = json_body(list).await["id"].as_str().unwrap().to_string();
+
+    let create = |body: Value| {
+        app.clone().oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(format!("/api/v1/task-lists/{}/tasks", list_id))
+                .header("content-type", "application/json")
+                .body(Body::from(serde_json::to_vec(&body).unwrap()))
+                .unwrap(),
+        )
+    };
+
+    // Omitted status lands in the documented default
+    let response = create(json!({"title": "No status"})).await.unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    assert_eq!(json_body(response).await["status"], "todo");
+
+    // An explicit known status is used as-is
+    let response = create(json!({"title": "Straight to review", "status": "review"}))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    assert_eq!(json_body(response).await["status"], "review");
+
+    // An unknown status is rejected rather than silently becoming backlog
+    let response = create(json!({"title": "Bogus", "status": "someday"}))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let body = json_body(response).await;
+    assert!(
+        body["error"].as_str().unwrap().contains("someday"),
+        "unexpected error: {}",
+        body["error"]
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn list_tasks_follows_cursor() {
+    let app = test_app().await;
+
+    let list = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/v1/task-lists")
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    serde_json::to_vec(&json!({
+                        "title": "Cursor List",
+                        "project_id": "test0000"
+                    }))
+                    .unwrap(),
+                ))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let list_id = json_body(list).await["id"].as_str().unwrap().to_string();
+
+    for title in ["First", "Second", "Third"] {
+        app.clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!("/api/v1/task-lists/{}/tasks", list_id))
+                    .header("content-type", "application/json")
+                    .body(Body::from(
+                        serde_json::to_vec(&json!({"title": title})).unwrap(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+    }
+
+    let get = |uri: String| {
+        let app = app.clone();
+        async move {
+            app.oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+                .await
+                .unwrap()
+        }
+    };
+
+    let response = get(format!(
+        "/api/v1/task-lists/{}/tasks?limit=2&sort=created_at",
+        list_id
+    ))
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let first = json_body(response).await;
+    assert_eq!(first["items"].as_array().unwrap().len(), 2);
+    let cursor = first["next_cursor"].as_str().unwrap().to_string();
+
+    // The cursor wins over a conflicting offset
+    let response = get(format!(
+        "/api/v1/task-lists/{}/tasks?limit=2&sort=created_at&offset=50&cursor={}",
+        list_id, cursor
+    ))
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let second = json_body(response).await;
+    assert_eq!(second["items"].as_array().unwrap().len(), 1);
+    assert!(second.get("next_cursor").is_none());
+
+    let response = get(format!(
+        "/api/v1/task-lists/{}/tasks?cursor=bogus!",
+        list_id
+    ))
+    .await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    // A cursor only resumes the sort it was issued for
+    let response = get(format!(
+        "/api/v1/task-lists/{}/tasks?sort=priority&cursor={}",
+        list_id, cursor
+    ))
+    .await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn reparent_tasks_moves_batch_under_parent() {
+    let app = test_app().await;
+    let post = |uri: String, body: Value| {
+        let app = app.clone();
+        async move {
+            app.oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(uri)
+                    .header("content-type", "application/json")
+                    .body(Body::from(serde_json::to_vec(&body).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap()
+        }
+    };
+
+    let mut lists = Vec::new();
+    for title in ["Reparent List", "Other List"] {
+        let response = post(
+            "/api/v1/task-lists".to_string(),
+            json!({"title": title, "project_id": "test0000"}),
+        )
+        .await;
+        lists.push(
+            json_body(response).await["id"]
+                .as_str()
+                .unwrap()
+                .to_string(),
+        );
+    }
+
+    let mut ids = Vec::new();
+    for (title, list_id) in [
+        ("Parent", &lists[0]),
+        ("First", &lists[0]),
+        ("Second", &lists[0]),
+        ("Elsewhere", &lists[1]),
+    ] {
+        let response = post(
+            format!("/api/v1/task-lists/{}/tasks", list_id),
+            json!({"title": title}),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::CREATED);
+        ids.push(
+            json_body(response).await["id"]
+                .as_str()
+                .unwrap()
+                .to_string(),
+        );
+    }
+    let (parent, first, second, elsewhere) = (&ids[0], &ids[1], &ids[2], &ids[3]);
+
+    // Successful move returns the updated tasks in request order
+    let response = post(
+        "/api/v1/tasks/reparent".to_string(),
+        json!({"ids": [second, first], "new_parent": parent}),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = json_body(response).await;
+    let moved = body.as_array().unwrap();
+    assert_eq!(moved.len(), 2);
+    assert_eq!(moved[0]["id"], json!(second));
+    assert_eq!(moved[1]["id"], json!(first));
+    assert!(moved.iter().all(|t| t["parent_id"] == json!(parent)));
+
+    // Tasks from another list are rejected and nothing moves
+    let response = post(
+        "/api/v1/tasks/reparent".to_string(),
+        json!({"ids": [elsewhere], "new_parent": parent}),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let task = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri(format!("/api/v1/tasks/{}", elsewhere))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(json_body(task).await["parent_id"], Value::Null);
+
+    // Moving the parent under one of its subtasks would create a cycle
+    let response = post(
+        "/api/v1/tasks/reparent".to_string(),
+        json!({"ids": [parent], "new_parent": first}),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let body = json_body(response).await;
+    assert!(
+        body["error"].as_str().unwrap().contains("own ancestor"),
+        "unexpected error: {}",
+        body["error"]
+    );
+
+    // Unknown tasks are a 404
+    let response = post(
+        "/api/v1/tasks/reparent".to_string(),
+        json!({"ids": ["missing0"], "new_parent": null}),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn reparent_broadcasts_parent_id_as_changed() {
+    let (app, notifier) = test_app_with_notifier().await;
+    let post = |uri: String, body: Value| {
+        let app = app.clone();
+        async move {
+            app.oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(uri)
+                    .header("content-type", "application/json")
+                    .body(Body::from(serde_json::to_vec(&body).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap()
+        }
+    };
+
+    let list = post(
+        "/api/v1/task-lists".to_string(),
+        json!({"title": "Reparent List", "project_id": "test0000"}),
+    )
+    .await;
+    let list_id = json_body(list).await["id"].as_str().unwrap().to_string();
+
+    let mut ids = Vec::new();
+    for title in ["Parent", "Child"] {
+        let response = post(
+            format!("/api/v1/task-lists/{}/tasks", list_id),
+            json!({"title": title}),
+        )
+        .await;
+        ids.push(
+            json_body(response).await["id"]
+                .as_str()
+                .unwrap()
+                .to_string(),
+        );
+    }
+
+    let mut subscriber = notifier.subscribe();
+    let response = post(
+        "/api/v1/tasks/reparent".to_string(),
+        json!({"ids": [ids[1]], "new_parent": ids[0]}),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let msg = subscriber
+        .recv()
+        .await
+        .expect("Should receive update broadcast");
+    assert_eq!(
+        msg,
+        crate::api::notifier::UpdateMessage::TaskUpdated {
+            task_id: ids[1].clone(),
+            changed: vec!["parent_id".to_string()],
+        }
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn list_tasks_tree_nests_subtasks() {
+    let app = test_app().await;
+    let send = |method: &str, uri: String, body: Option<Value>| {
+        let app = app.clone();
+        let request = Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("content-type", "application/json")
+            .body(match body {
+                Some(body) => Body::from(serde_json::to_vec(&body).unwrap()),
+                None => Body::empty(),
+            })
+            .unwrap();
+        async move { app.oneshot(request).await.unwrap() }
+    };
+
+    let response = send(
+        "POST",
+        "/api/v1/task-lists".to_string(),
+        Some(json!({"title": "Tree List", "project_id": "test0000"})),
+    )
+    .await;
+    let list_id = json_body(response).await["id"]
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    let mut ids: Vec<String> = Vec::new();
+    for (title, is_subtask) in [
+        ("Parent", false),
+        ("First subtask", true),
+        ("Second subtask", true),
+        ("Loose task", false),
+    ] {
+        let parent_id = if is_subtask { ids.first() } else { None };
+        let response = send(
+            "POST",
+            format!("/api/v1/task-lists/{}/tasks", list_id),
+            Some(json!({"title": title, "parent_id": parent_id})),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::CREATED);
+        ids.push(
+            json_body(response).await["id"]
+                .as_str()
+                .unwrap()
+                .to_string(),
+        );
+    }
+
+    let response = send(
+        "GET",
+        format!("/api/v1/task-lists/{}/tasks?tree=true", list_id),
+        None,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = json_body(response).await;
+
+    // Only root tasks at the top level, subtasks under their parent
+    let roots = body["items"].as_array().unwrap();
+    assert_eq!(body["total"], 2);
+    assert_eq!(roots.len(), 2);
+    let root = |id: &str| roots.iter().find(|t| t["id"] == json!(id)).unwrap();
+
+    let subtasks = root(&ids[0])["subtasks"].as_array().unwrap();
+    let mut subtask_ids: Vec<&str> = subtasks.iter().map(|t| t["id"].as_str().unwrap()).collect();
+    subtask_ids.sort();
+    let mut expected = vec![ids[1].as_str(), ids[2].as_str()];
+    expected.sort();
+    assert_eq!(subtask_ids, expected);
+    assert!(subtasks.iter().all(|t| t["subtasks"] == json!([])));
+    assert_eq!(root(&ids[3])["subtasks"], json!([]));
+
+    // The flat default is unchanged and has no subtasks field
+    let response = send("GET", format!("/api/v1/task-lists/{}/tasks", list_id), None).await;
+    let body = json_body(response).await;
+    assert_eq!(body["items"].as_array().unwrap().len(), 4);
+    assert!(body["items"][0].get("subtasks").is_none());
+
+    let response = send(
+        "GET",
+        format!(
+            "/api/v1/task-lists/{}/tasks?tree=true&type=subtask",
+            list_id
+        ),
+        None,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
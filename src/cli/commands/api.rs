@@ -6,19 +6,54 @@ use std::path::PathBuf;
 use miette::{IntoDiagnostic, Result};
 
 use crate::api::{self, Config};
-use crate::db::Database;
 use crate::db::sqlite::SqliteDatabase;
+use crate::db::utils::{IdLength, id_length_from_env};
+use crate::db::{Database, TaskStatus};
 use crate::sync::{get_db_path, set_base_path};
 
+/// Options for `c5t api`, as given on the command line.
+///
+/// Unset options fall back to their environment variables in [`run`].
+pub struct ApiArgs {
+    pub host: IpAddr,
+    pub port: u16,
+    pub home: Option<PathBuf>,
+    pub skills_dir: Option<PathBuf>,
+    pub default_project: Option<String>,
+    pub max_note_size: Option<usize>,
+    pub auto_complete_parents: bool,
+    pub default_task_status: Option<TaskStatus>,
+    pub lowercase_tags: bool,
+    pub compress_sync: bool,
+    pub allowed_origins: Vec<String>,
+    pub cors_permissive: bool,
+    pub id_length: Option<IdLength>,
+    pub verbosity: u8,
+    pub enable_docs: bool,
+    pub enable_metrics: bool,
+}
+
 /// Run the API server
-pub async fn run(
-    host: IpAddr,
-    port: u16,
-    home: Option<PathBuf>,
-    skills_dir: Option<PathBuf>,
-    verbosity: u8,
-    enable_docs: bool,
-) -> Result<()> {
+pub async fn run(args: ApiArgs) -> Result<()> {
+    let ApiArgs {
+        host,
+        port,
+        home,
+        skills_dir,
+        default_project,
+        max_note_size,
+        auto_complete_parents,
+        default_task_status,
+        lowercase_tags,
+        compress_sync,
+        allowed_origins,
+        cors_permissive,
+        id_length,
+        verbosity,
+        enable_docs,
+        enable_metrics,
+    } = args;
+
     // Set the global base path if provided (API startup singleton pattern)
     if let Some(home_path) = home {
         set_base_path(home_path);
@@ -34,7 +69,9 @@ pub async fn run(
         std::fs::create_dir_all(parent).into_diagnostic()?;
     }
 
-    let db = SqliteDatabase::open(&db_path).await?;
+    let db = SqliteDatabase::open(&db_path)
+        .await?
+        .with_id_length(id_length.unwrap_or_else(id_length_from_env));
 
     // Run migrations before starting the server
     db.migrate()?;
@@ -49,29 +86,46 @@ pub async fn run(
     if enable_docs {
         println!("   Docs:     http://{}:{}/docs", host, port);
     }
+    if enable_metrics {
+        println!("   Metrics:  http://{}:{}/metrics", host, port);
+    }
     println!();
     println!("   Database: {}", db_path.display());
     println!();
 
-    // Pass the abstract Database to the API layer
-    api::run(
-        Config {
-            host,
-            port,
-            verbosity,
-            enable_docs,
-            skills_dir: match skills_dir {
-                Some(dir) => dir,
-                None => match std::env::var("C5T_SKILLS_DIR") {
-                    Ok(dir) => PathBuf::from(dir),
-                    Err(_) => crate::sync::get_data_dir().join("skills"),
-                },
+    let config = Config {
+        host,
+        port,
+        verbosity,
+        enable_docs,
+        enable_metrics,
+        skills_dir: match skills_dir {
+            Some(dir) => dir,
+            None => match std::env::var("C5T_SKILLS_DIR") {
+                Ok(dir) => PathBuf::from(dir),
+                Err(_) => crate::sync::get_data_dir().join("skills"),
             },
         },
-        db,
-    )
-    .await
-    .into_diagnostic()?;
+        default_project: default_project.or_else(|| std::env::var("C5T_DEFAULT_PROJECT").ok()),
+        max_note_size: api::max_note_size_from_env(),
+        auto_complete_parents: auto_complete_parents || api::auto_complete_parents_from_env(),
+        default_task_status: default_task_status.unwrap_or_else(api::default_task_status_from_env),
+        lowercase_tags: lowercase_tags || api::lowercase_tags_from_env(),
+        compress_sync: compress_sync || api::compress_sync_from_env(),
+        allowed_origins: if allowed_origins.is_empty() {
+            api::allowed_origins_from_env()
+        } else {
+            allowed_origins
+        },
+        cors_permissive: cors_permissive || api::cors_permissive_from_env(),
+    };
+    let config = match max_note_size {
+        Some(size) => config.with_max_note_size(size),
+        None => config,
+    };
+
+    // Pass the abstract Database to the API layer
+    api::run(config, db).await.into_diagnostic()?;
 
     Ok(())
 }
@@ -18,6 +18,7 @@
 // =============================================================================
 
 use crate::api::Config;
+use crate::db::{NOTE_HARD_MAX, TaskStatus};
 use std::net::IpAddr;
 
 #[test]
@@ -28,7 +29,16 @@ fn test_config_structure() {
         port: 3000,
         verbosity: 0,
         enable_docs: false,
+        enable_metrics: false,
         skills_dir: std::path::PathBuf::from("/tmp/skills"),
+        default_project: None,
+        max_note_size: NOTE_HARD_MAX,
+        auto_complete_parents: false,
+        default_task_status: TaskStatus::Todo,
+        lowercase_tags: false,
+        compress_sync: false,
+        allowed_origins: vec![],
+        cors_permissive: false,
     };
 
     assert_eq!(config.host.to_string(), "127.0.0.1");
@@ -45,7 +55,16 @@ fn test_config_with_docs_enabled() {
         port: 8080,
         verbosity: 2,
         enable_docs: true,
+        enable_metrics: false,
         skills_dir: std::path::PathBuf::from("/tmp/skills"),
+        default_project: None,
+        max_note_size: NOTE_HARD_MAX,
+        auto_complete_parents: false,
+        default_task_status: TaskStatus::Todo,
+        lowercase_tags: false,
+        compress_sync: false,
+        allowed_origins: vec![],
+        cors_permissive: false,
     };
 
     assert_eq!(config.host.to_string(), "0.0.0.0");
@@ -54,6 +73,16 @@ fn test_config_with_docs_enabled() {
     assert!(config.enable_docs);
 }
 
+#[test]
+fn test_config_max_note_size_is_capped() {
+    let config = Config::default().with_max_note_size(4_096);
+    assert_eq!(config.max_note_size, 4_096);
+
+    // The store rejects anything above NOTE_HARD_MAX regardless
+    let config = Config::default().with_max_note_size(NOTE_HARD_MAX * 10);
+    assert_eq!(config.max_note_size, NOTE_HARD_MAX);
+}
+
 #[test]
 fn test_ipv4_address_parsing() {
     // Test that IPv4 addresses can be parsed
@@ -102,7 +131,16 @@ fn test_port_ranges() {
             port,
             verbosity: 0,
             enable_docs: false,
+            enable_metrics: false,
             skills_dir: std::path::PathBuf::from("/tmp/skills"),
+            default_project: None,
+            max_note_size: NOTE_HARD_MAX,
+            auto_complete_parents: false,
+            default_task_status: TaskStatus::Todo,
+            lowercase_tags: false,
+            compress_sync: false,
+            allowed_origins: vec![],
+            cors_permissive: false,
         };
         assert_eq!(config.port, port);
     }
@@ -119,7 +157,16 @@ fn test_verbosity_levels() {
             port: 3000,
             verbosity: level,
             enable_docs: false,
+            enable_metrics: false,
             skills_dir: std::path::PathBuf::from("/tmp/skills"),
+            default_project: None,
+            max_note_size: NOTE_HARD_MAX,
+            auto_complete_parents: false,
+            default_task_status: TaskStatus::Todo,
+            lowercase_tags: false,
+            compress_sync: false,
+            allowed_origins: vec![],
+            cors_permissive: false,
         };
         assert_eq!(config.verbosity, level);
     }
@@ -0,0 +1,60 @@
+//! Output formats for list commands.
+
+use clap::ValueEnum;
+
+/// Output format of a list command.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Human-readable table
+    #[default]
+    Table,
+    /// Pretty-printed JSON page: `items`, `total`, `limit`, `offset`
+    Json,
+    /// CSV with a header row (RFC 4180)
+    Csv,
+}
+
+impl OutputFormat {
+    /// Name passed to the `format` parameter of the command functions.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            OutputFormat::Table => "table",
+            OutputFormat::Json => "json",
+            OutputFormat::Csv => "csv",
+        }
+    }
+}
+
+/// An entity that can be written as a CSV row.
+pub trait CsvRecord {
+    /// Column names, in the order [`CsvRecord::record`] returns the fields.
+    const HEADER: &'static [&'static str];
+
+    /// One field per column.
+    fn record(&self) -> Vec<String>;
+}
+
+/// Render items as CSV: a header row plus one row per item, CRLF-separated.
+pub fn to_csv<T: CsvRecord>(items: &[T]) -> String {
+    let header = T::HEADER.iter().map(|h| escape_field(h));
+    let mut lines = vec![header.collect::<Vec<_>>().join(",")];
+    for item in items {
+        let fields: Vec<String> = item.record().iter().map(|f| escape_field(f)).collect();
+        lines.push(fields.join(","));
+    }
+    lines.join("\r\n")
+}
+
+/// Quote a field if it contains a comma, quote or line break, doubling any quotes.
+pub fn escape_field(field: &str) -> String {
+    if field.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Join a list (tags, linked ids) into one cell so the column count stays fixed.
+pub fn join_list(values: &[String]) -> String {
+    values.join(";")
+}
@@ -0,0 +1,84 @@
+use crate::cli::commands::format::*;
+use crate::cli::commands::repo::Repo;
+use crate::cli::commands::task::Task;
+
+#[test]
+fn test_escape_field_follows_rfc_4180() {
+    assert_eq!(escape_field("plain"), "plain");
+    assert_eq!(escape_field(""), "");
+    assert_eq!(escape_field("a,b"), "\"a,b\"");
+    assert_eq!(escape_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    assert_eq!(escape_field("line\nbreak"), "\"line\nbreak\"");
+}
+
+#[test]
+fn test_output_format_names() {
+    assert_eq!(OutputFormat::default(), OutputFormat::Table);
+    assert_eq!(OutputFormat::Table.as_str(), "table");
+    assert_eq!(OutputFormat::Json.as_str(), "json");
+    assert_eq!(OutputFormat::Csv.as_str(), "csv");
+}
+
+#[test]
+fn test_to_csv_writes_header_and_rows() {
+    let repos = vec![
+        Repo {
+            id: "repo0001".to_string(),
+            remote: "https://github.com/acme/api".to_string(),
+            path: Some("/srv/api, main".to_string()),
+            tags: vec!["backend".to_string(), "rust".to_string()],
+            project_ids: vec![],
+            created_at: "2025-01-01 00:00:00".to_string(),
+        },
+        Repo {
+            id: "repo0002".to_string(),
+            remote: "https://github.com/acme/web".to_string(),
+            path: None,
+            tags: vec![],
+            project_ids: vec!["proj0001".to_string()],
+            created_at: "2025-01-02 00:00:00".to_string(),
+        },
+    ];
+
+    let csv = to_csv(&repos);
+    let lines: Vec<&str> = csv.split("\r\n").collect();
+    assert_eq!(
+        lines,
+        vec![
+            "id,remote,path,tags,project_ids,created_at",
+            "repo0001,https://github.com/acme/api,\"/srv/api, main\",backend;rust,,2025-01-01 00:00:00",
+            "repo0002,https://github.com/acme/web,,,proj0001,2025-01-02 00:00:00",
+        ]
+    );
+
+    // An empty list still has the header
+    assert_eq!(
+        to_csv::<Repo>(&[]),
+        "id,remote,path,tags,project_ids,created_at"
+    );
+}
+
+#[test]
+fn test_to_csv_keeps_column_count_stable() {
+    let task = Task {
+        id: "task0001".to_string(),
+        list_id: "list0001".to_string(),
+        parent_id: None,
+        title: "Fix \"quoted\", comma".to_string(),
+        description: None,
+        status: "todo".to_string(),
+        priority: Some(2),
+        tags: Some(vec!["a".to_string(), "b".to_string(), "c".to_string()]),
+        external_refs: vec![],
+        created_at: "2025-01-01 00:00:00".to_string(),
+        subtasks: None,
+    };
+
+    let csv = to_csv(&[task]);
+    let row = csv.split("\r\n").nth(1).unwrap();
+    assert_eq!(
+        row,
+        "task0001,list0001,,\"Fix \"\"quoted\"\", comma\",,todo,2,a;b;c,,2025-01-01 00:00:00"
+    );
+    assert_eq!(Task::HEADER.len(), 10);
+}
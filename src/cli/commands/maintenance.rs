@@ -0,0 +1,52 @@
+//! Maintenance commands that work on the database file directly.
+
+use std::path::PathBuf;
+
+use miette::Result;
+
+use crate::db::SqliteDatabase;
+use crate::db::sqlite::SchemaMismatch;
+use crate::sync::{get_db_path, set_base_path};
+
+/// Render a schema check result as text or JSON
+pub(crate) fn format_schema_report(mismatches: &[SchemaMismatch], json: bool) -> String {
+    if json {
+        return serde_json::to_string_pretty(mismatches).unwrap_or_default();
+    }
+
+    if mismatches.is_empty() {
+        return "Schema matches the current models".to_string();
+    }
+
+    let mut output = format!("Found {} schema mismatch(es):\n", mismatches.len());
+    for mismatch in mismatches {
+        output.push_str(&format!("  - {}\n", mismatch));
+    }
+    output.push_str("Start the API server (c5t api) to apply pending migrations.");
+    output
+}
+
+/// Compare the database schema against the models without migrating it.
+///
+/// Prints the report and fails when any mismatch is found, so the command
+/// can gate upgrades in scripts.
+pub async fn migrate_check(home: Option<PathBuf>, json: bool) -> Result<()> {
+    if let Some(home_path) = home {
+        set_base_path(home_path);
+    }
+
+    let db_path = get_db_path();
+    if !db_path.exists() {
+        miette::bail!("No database found at {}", db_path.display());
+    }
+
+    let db = SqliteDatabase::open(&db_path).await?;
+    let mismatches = db.verify_schema().await?;
+
+    println!("{}", format_schema_report(&mismatches, json));
+
+    if !mismatches.is_empty() {
+        miette::bail!("Database schema at {} has drifted", db_path.display());
+    }
+    Ok(())
+}
@@ -1,12 +1,35 @@
 pub mod api;
+pub mod format;
+pub mod maintenance;
 pub mod note;
 pub mod project;
 pub mod repo;
 pub mod skill;
+pub mod status;
 pub mod sync;
 pub mod task;
 pub mod task_list;
 
+use crate::cli::error::{CliError, CliResult};
+use crate::db::pagination::DEFAULT_PAGE_SIZE;
+use serde::{Deserialize, Serialize};
+
+/// Envelope of a paginated list, as returned by the API list endpoints.
+///
+/// List commands print the whole envelope with `--json`, also when `items`
+/// is empty, so `jq '.items[]'` and `.total` always have something to read.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Paginated<T> {
+    pub items: Vec<T>,
+    pub total: usize,
+    #[serde(default)]
+    pub limit: Option<usize>,
+    pub offset: usize,
+    /// Keyset cursor for the next page, on endpoints that support it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
+}
+
 /// Common pagination and sorting parameters for all list commands
 #[derive(Debug, Default)]
 pub struct PageParams<'a> {
@@ -14,8 +37,97 @@ pub struct PageParams<'a> {
     pub offset: Option<u32>,
     pub sort: Option<&'a str>,
     pub order: Option<&'a str>,
+    /// Configured default limit, used when `limit` is not given
+    pub page_size: Option<u32>,
+}
+
+impl PageParams<'_> {
+    /// Limit to send: the explicit `--limit`, else the configured page size
+    pub fn effective_limit(&self) -> Option<u32> {
+        self.limit.or(self.page_size)
+    }
+
+    /// See [`truncation_hint`]
+    pub fn truncation_hint(&self, shown: usize, total: usize) -> Option<String> {
+        truncation_hint(self.limit, self.page_size, self.offset, shown, total)
+    }
+
+    /// Check sort field and order against what the endpoint supports
+    pub fn validate(&self, allowed_sort: &[&str]) -> CliResult<()> {
+        if let Some(sort) = self.sort
+            && !allowed_sort.contains(&sort)
+        {
+            return Err(CliError::InvalidArgument {
+                message: format!(
+                    "unknown sort field '{}' (expected one of: {})",
+                    sort,
+                    allowed_sort.join(", ")
+                ),
+            });
+        }
+        if let Some(order) = self.order
+            && !matches!(order, "asc" | "desc")
+        {
+            return Err(CliError::InvalidArgument {
+                message: format!("unknown sort order '{}' (expected asc or desc)", order),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Read C5T_PAGE_SIZE; unset, unparsable or zero means no default limit
+pub fn page_size_from_env() -> Option<u32> {
+    std::env::var("C5T_PAGE_SIZE")
+        .ok()
+        .and_then(|v| v.parse::<u32>().ok())
+        .filter(|size| *size > 0)
+}
+
+/// Hint for a list cut short by the page size.
+///
+/// Only given when no explicit limit was passed and items remain past this
+/// page. Without a configured page size the server default applies.
+pub fn truncation_hint(
+    limit: Option<u32>,
+    page_size: Option<u32>,
+    offset: Option<u32>,
+    shown: usize,
+    total: usize,
+) -> Option<String> {
+    if limit.is_some() {
+        return None;
+    }
+    let page_size = page_size.unwrap_or(DEFAULT_PAGE_SIZE as u32);
+    let offset = offset.unwrap_or(0) as usize;
+    (offset + shown < total).then(|| {
+        format!(
+            "Showing {} of {} (page size {}). Use --limit or --offset {} to see more.",
+            shown,
+            total,
+            page_size,
+            offset + shown
+        )
+    })
+}
+
+/// Attach a truncation hint to list output: appended to tables, printed to
+/// stderr for json/csv so piped output stays parseable.
+pub fn with_truncation_hint(output: String, format: &str, hint: Option<String>) -> String {
+    match hint {
+        Some(hint) if matches!(format, "json" | "csv") => {
+            eprintln!("{}", hint);
+            output
+        }
+        Some(hint) => format!("{}\n\n{}", output, hint),
+        None => output,
+    }
 }
 
+#[cfg(test)]
+#[path = "format_test.rs"]
+mod format_test;
+
 #[cfg(test)]
 #[path = "note_test.rs"]
 mod note_test;
@@ -40,6 +152,10 @@ mod task_test;
 #[path = "task_list_test.rs"]
 mod task_list_test;
 
+#[cfg(test)]
+#[path = "status_test.rs"]
+mod status_test;
+
 #[cfg(test)]
 #[path = "sync_test.rs"]
 mod sync_test;
@@ -1,8 +1,10 @@
 use crate::cli::api_client::ApiClient;
-use crate::cli::commands::PageParams;
+use crate::cli::commands::format::{CsvRecord, join_list, to_csv};
+use crate::cli::commands::{PageParams, Paginated, with_truncation_hint};
 use crate::cli::error::{CliError, CliResult};
-use crate::cli::utils::{apply_table_style, format_tags, truncate_with_ellipsis};
+use crate::cli::utils::{apply_table_style, format_tags, truncate_display};
 use serde::{Deserialize, Serialize};
+use std::io::Read;
 use tabled::{Table, Tabled};
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -19,6 +21,35 @@ pub struct Note {
     pub updated_at: String,
 }
 
+/// Content is left out of CSV rows; use `--format json` for full notes.
+impl CsvRecord for Note {
+    const HEADER: &'static [&'static str] = &[
+        "id",
+        "title",
+        "tags",
+        "parent_id",
+        "idx",
+        "project_ids",
+        "repo_ids",
+        "created_at",
+        "updated_at",
+    ];
+
+    fn record(&self) -> Vec<String> {
+        vec![
+            self.id.clone(),
+            self.title.clone(),
+            join_list(&self.tags),
+            self.parent_id.clone().unwrap_or_default(),
+            self.idx.map(|i| i.to_string()).unwrap_or_default(),
+            join_list(self.project_ids.as_deref().unwrap_or_default()),
+            join_list(self.repo_ids.as_deref().unwrap_or_default()),
+            self.created_at.clone(),
+            self.updated_at.clone(),
+        ]
+    }
+}
+
 #[derive(Debug, Serialize)]
 pub struct CreateNoteRequest {
     pub title: String,
@@ -67,20 +98,12 @@ impl From<&Note> for NoteDisplay {
     fn from(note: &Note) -> Self {
         Self {
             id: note.id.clone(),
-            title: truncate_with_ellipsis(&note.title, 50),
+            title: truncate_display(&note.title, 50),
             tags: format_tags(Some(&note.tags)),
         }
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-struct NoteListResponse {
-    items: Vec<Note>,
-    total: usize,
-    limit: usize,
-    offset: usize,
-}
-
 /// List notes with optional filtering
 // TODO: Refactor to reduce parameter count (use builder pattern or params struct)
 #[allow(clippy::too_many_arguments)]
@@ -94,6 +117,14 @@ pub async fn list_notes(
     page: PageParams<'_>,
     format: &str,
 ) -> CliResult<String> {
+    page.validate(&[
+        "title",
+        "created_at",
+        "updated_at",
+        "last_activity_at",
+        "idx",
+    ])?;
+
     let mut request = api_client.get("/api/v1/notes");
 
     if let Some(q) = query {
@@ -111,7 +142,7 @@ pub async fn list_notes(
     if let Some(nt) = note_type {
         request = request.query(&[("note_type", nt)]);
     }
-    if let Some(l) = page.limit {
+    if let Some(l) = page.effective_limit() {
         request = request.query(&[("limit", l.to_string())]);
     }
     if let Some(o) = page.offset {
@@ -124,12 +155,15 @@ pub async fn list_notes(
         request = request.query(&[("order", ord)]);
     }
 
-    let response: NoteListResponse = request.send().await?.json().await?;
+    let response: Paginated<Note> = request.send().await?.json().await?;
 
-    match format {
-        "json" => Ok(serde_json::to_string_pretty(&response.items)?),
-        _ => Ok(format_table(&response.items)),
-    }
+    let hint = page.truncation_hint(response.items.len(), response.total);
+    let output = match format {
+        "json" => serde_json::to_string_pretty(&response)?,
+        "csv" => to_csv(&response.items),
+        _ => format_table(&response.items),
+    };
+    Ok(with_truncation_hint(output, format, hint))
 }
 
 pub(crate) fn format_table(notes: &[Note]) -> String {
@@ -143,6 +177,97 @@ pub(crate) fn format_table(notes: &[Note]) -> String {
     table.to_string()
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct NoteSearchHit {
+    pub note: Note,
+    /// Matched fragment with hits wrapped in `<mark>` tags
+    pub snippet: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct NoteSearchResponse {
+    items: Vec<NoteSearchHit>,
+}
+
+#[derive(Tabled)]
+struct NoteSearchDisplay {
+    #[tabled(rename = "ID")]
+    id: String,
+    #[tabled(rename = "Title")]
+    title: String,
+    #[tabled(rename = "Match")]
+    snippet: String,
+}
+
+/// Render a snippet for the terminal: hits become `**bold**`, escaped
+/// characters are restored and the fragment is flattened onto one line.
+fn snippet_for_terminal(snippet: &str) -> String {
+    let flattened = snippet
+        .replace("<mark>", "**")
+        .replace("</mark>", "**")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ");
+    truncate_display(&flattened, 80)
+}
+
+/// Full-text search over notes, showing the matched fragment of each hit
+pub async fn search_notes(
+    api_client: &ApiClient,
+    query: &str,
+    project_id: Option<&str>,
+    tags: Option<&str>,
+    limit: Option<u32>,
+    format: &str,
+) -> CliResult<String> {
+    let mut request = api_client
+        .get("/api/v1/notes/search")
+        .query(&[("q", query)]);
+
+    if let Some(p) = project_id {
+        request = request.query(&[("project_id", p)]);
+    }
+    if let Some(tag_str) = tags {
+        request = request.query(&[("tags", tag_str)]);
+    }
+    if let Some(l) = limit {
+        request = request.query(&[("limit", l.to_string())]);
+    }
+
+    let response: NoteSearchResponse = ApiClient::handle_response(request.send().await?).await?;
+
+    match format {
+        "json" => Ok(serde_json::to_string_pretty(&response.items)?),
+        "csv" => {
+            let notes: Vec<Note> = response.items.into_iter().map(|hit| hit.note).collect();
+            Ok(to_csv(&notes))
+        }
+        _ => {
+            if response.items.is_empty() {
+                return Ok("No notes found.".to_string());
+            }
+
+            let rows: Vec<NoteSearchDisplay> = response
+                .items
+                .iter()
+                .map(|hit| NoteSearchDisplay {
+                    id: hit.note.id.clone(),
+                    title: truncate_display(&hit.note.title, 40),
+                    snippet: snippet_for_terminal(&hit.snippet),
+                })
+                .collect();
+            let mut table = Table::new(rows);
+            apply_table_style(&mut table);
+            Ok(table.to_string())
+        }
+    }
+}
+
 /// Get a single note by ID
 pub async fn get_note(api_client: &ApiClient, id: &str, format: &str) -> CliResult<String> {
     let response = api_client
@@ -167,7 +292,7 @@ pub async fn get_note(api_client: &ApiClient, id: &str, format: &str) -> CliResu
             if let Some(idx) = note.idx {
                 builder.push_record(["Index", &idx.to_string()]);
             }
-            builder.push_record(["Content", &truncate_with_ellipsis(&note.content, 200)]);
+            builder.push_record(["Content", &truncate_display(&note.content, 200)]);
             builder.push_record(["Tags", &format_tags(Some(&note.tags))]);
             builder.push_record(["Created", &note.created_at]);
             builder.push_record(["Updated", &note.updated_at]);
@@ -180,15 +305,32 @@ pub async fn get_note(api_client: &ApiClient, id: &str, format: &str) -> CliResu
 }
 
 /// Create a new note
-pub async fn create_note(api_client: &ApiClient, request: CreateNoteRequest) -> CliResult<String> {
-    let response = api_client
-        .post("/api/v1/notes")
-        .json(&request)
-        .send()
-        .await?;
+///
+/// With `dedup`, a note whose content is identical is returned instead of
+/// creating a new one, and the output says it was matched.
+pub async fn create_note(
+    api_client: &ApiClient,
+    request: CreateNoteRequest,
+    dedup: bool,
+) -> CliResult<String> {
+    let url = if dedup {
+        "/api/v1/notes?dedup=true"
+    } else {
+        "/api/v1/notes"
+    };
+    let response = api_client.post(url).json(&request).send().await?;
 
+    // The API answers 201 for a new note and 200 for a deduplicated match
+    let matched = response.status() == reqwest::StatusCode::OK;
     let note: Note = ApiClient::handle_response(response).await?;
-    Ok(format!("✓ Created note: {} ({})", note.title, note.id))
+    if matched {
+        Ok(format!(
+            "✓ Matched existing note: {} ({})",
+            note.title, note.id
+        ))
+    } else {
+        Ok(format!("✓ Created note: {} ({})", note.title, note.id))
+    }
 }
 
 /// Update a note
@@ -207,8 +349,112 @@ pub async fn update_note(
     Ok(format!("✓ Updated note: {} ({})", note.title, note.id))
 }
 
+/// Resolve note content from `--content` or `--file`.
+///
+/// `--file -` reads the content from stdin so Markdown can be piped in.
+pub fn resolve_content(content: Option<String>, file: Option<&str>) -> CliResult<Option<String>> {
+    resolve_content_from(content, file, std::io::stdin().lock())
+}
+
+/// [`resolve_content`] with an explicit stdin, for tests.
+pub(crate) fn resolve_content_from(
+    content: Option<String>,
+    file: Option<&str>,
+    mut stdin: impl Read,
+) -> CliResult<Option<String>> {
+    let Some(file) = file else {
+        return Ok(content);
+    };
+
+    let text = if file == "-" {
+        let mut buf = String::new();
+        stdin
+            .read_to_string(&mut buf)
+            .map_err(|e| CliError::InvalidArgument {
+                message: format!("failed to read content from stdin: {}", e),
+            })?;
+        buf
+    } else {
+        std::fs::read_to_string(file).map_err(|e| CliError::InvalidArgument {
+            message: format!("failed to read content from '{}': {}", file, e),
+        })?
+    };
+    Ok(Some(text))
+}
+
+/// Current content of a note, used to seed `note update --edit`.
+pub async fn note_content(api_client: &ApiClient, id: &str) -> CliResult<String> {
+    let response = api_client
+        .get(&format!("/api/v1/notes/{}", id))
+        .send()
+        .await?;
+
+    let note: Note = ApiClient::handle_response(response).await?;
+    Ok(note.content)
+}
+
+/// Edit note content in `$EDITOR` (falling back to `vi`), like `git commit`.
+///
+/// The editor opens a temp file seeded with `initial`. Fails, so nothing is
+/// sent, if the editor exits non-zero or the content comes back empty or
+/// unchanged.
+pub fn edit_content(initial: &str) -> CliResult<String> {
+    let editor = std::env::var("EDITOR")
+        .ok()
+        .filter(|e| !e.trim().is_empty())
+        .unwrap_or_else(|| "vi".to_string());
+    edit_content_with(&editor, initial)
+}
+
+/// [`edit_content`] with an explicit editor command, for tests.
+///
+/// The command runs through `sh` with the file path appended, so editors
+/// with arguments (`code --wait`) work.
+pub(crate) fn edit_content_with(editor: &str, initial: &str) -> CliResult<String> {
+    let io_error = |e: std::io::Error| CliError::InvalidArgument {
+        message: format!("failed to prepare the note for editing: {}", e),
+    };
+
+    let file = tempfile::Builder::new()
+        .prefix("c5t-note-")
+        .suffix(".md")
+        .tempfile()
+        .map_err(io_error)?;
+    std::fs::write(file.path(), initial).map_err(io_error)?;
+
+    let status = std::process::Command::new("sh")
+        .arg("-c")
+        .arg(format!("{} \"$1\"", editor))
+        .arg("c5t-editor")
+        .arg(file.path())
+        .status()
+        .map_err(|e| CliError::InvalidArgument {
+            message: format!("failed to run editor '{}': {}", editor, e),
+        })?;
+    if !status.success() {
+        return Err(CliError::InvalidArgument {
+            message: format!("editor '{}' exited with {}; note not saved", editor, status),
+        });
+    }
+
+    let text = std::fs::read_to_string(file.path()).map_err(io_error)?;
+    if text.trim().is_empty() || text == initial {
+        return Err(CliError::InvalidArgument {
+            message: "note content is empty or unchanged; note not saved".to_string(),
+        });
+    }
+    Ok(text)
+}
+
 /// Delete a note (requires --force flag for safety)
-pub async fn delete_note(api_client: &ApiClient, id: &str, force: bool) -> CliResult<String> {
+///
+/// With `purge` the row is removed for good instead of being marked deleted.
+pub async fn delete_note(
+    api_client: &ApiClient,
+    id: &str,
+    force: bool,
+    purge: bool,
+) -> CliResult<String> {
     // Safety check: require --force flag
     if !force {
         return Err(CliError::InvalidResponse {
@@ -216,10 +462,11 @@ pub async fn delete_note(api_client: &ApiClient, id: &str, force: bool) -> CliRe
         });
     }
 
-    let response = api_client
-        .delete(&format!("/api/v1/notes/{}", id))
-        .send()
-        .await?;
+    let mut request = api_client.delete(&format!("/api/v1/notes/{}", id));
+    if purge {
+        request = request.query(&[("purge", "true")]);
+    }
+    let response = request.send().await?;
 
     // For delete, we expect no body on success, so we don't use handle_response
     if response.status().is_success() {
@@ -1,8 +1,8 @@
 use crate::a6s::store::surrealdb;
 use crate::api::{AppState, routes};
 use crate::cli::api_client::ApiClient;
-use crate::cli::commands::PageParams;
 use crate::cli::commands::note::*;
+use crate::cli::commands::{PageParams, Paginated};
 use crate::db::{Database, SqliteDatabase};
 use crate::sync::MockGitOps;
 use serde_json::json;
@@ -72,7 +72,7 @@ async fn test_note_crud_operations() {
         project_ids: Some(vec![project_id.clone()]),
         repo_ids: None,
     };
-    let create_result = create_note(&api_client, create_request).await;
+    let create_result = create_note(&api_client, create_request, false).await;
     assert!(create_result.is_ok(), "Should create note with full data");
 
     // Extract note ID
@@ -136,12 +136,12 @@ async fn test_note_crud_operations() {
     assert_eq!(updated_note["idx"], 2);
 
     // DELETE: Requires force flag
-    let delete_no_force = delete_note(&api_client, note_id, false).await;
+    let delete_no_force = delete_note(&api_client, note_id, false, false).await;
     assert!(delete_no_force.is_err(), "Should require --force flag");
     assert!(delete_no_force.unwrap_err().to_string().contains("--force"));
 
     // DELETE: Successful with force
-    let delete_result = delete_note(&api_client, note_id, true).await;
+    let delete_result = delete_note(&api_client, note_id, true, false).await;
     assert!(delete_result.is_ok(), "Should delete with --force");
 
     // Verify deletion
@@ -183,7 +183,7 @@ async fn test_note_list_with_comprehensive_filters() {
             project_ids: None,
             repo_ids: None,
         };
-        create_note(&api_client, request)
+        create_note(&api_client, request, false)
             .await
             .expect("Failed to create note");
     }
@@ -202,7 +202,7 @@ async fn test_note_list_with_comprehensive_filters() {
     .await;
     assert!(result.is_ok());
     let parsed: serde_json::Value = serde_json::from_str(&result.unwrap()).unwrap();
-    let rust_note = parsed
+    let rust_note = parsed["items"]
         .as_array()
         .unwrap()
         .iter()
@@ -215,11 +215,12 @@ async fn test_note_list_with_comprehensive_filters() {
         offset: None,
         sort: Some("title"),
         order: Some("asc"),
+        page_size: None,
     };
     let result_asc = list_notes(&api_client, None, None, None, None, None, page_asc, "json").await;
     assert!(result_asc.is_ok());
     let parsed_asc: serde_json::Value = serde_json::from_str(&result_asc.unwrap()).unwrap();
-    let notes_asc = parsed_asc.as_array().unwrap();
+    let notes_asc = parsed_asc["items"].as_array().unwrap();
     assert_eq!(notes_asc[0]["title"], "Alpha Rust Notes");
     assert_eq!(
         notes_asc[notes_asc.len() - 1]["title"],
@@ -232,12 +233,13 @@ async fn test_note_list_with_comprehensive_filters() {
         offset: None,
         sort: Some("title"),
         order: Some("desc"),
+        page_size: None,
     };
     let result_desc =
         list_notes(&api_client, None, None, None, None, None, page_desc, "json").await;
     assert!(result_desc.is_ok());
     let parsed_desc: serde_json::Value = serde_json::from_str(&result_desc.unwrap()).unwrap();
-    let notes_desc = parsed_desc.as_array().unwrap();
+    let notes_desc = parsed_desc["items"].as_array().unwrap();
     assert_eq!(notes_desc[0]["title"], "Zebra DevOps Notes");
     assert_eq!(
         notes_desc[notes_desc.len() - 1]["title"],
@@ -250,6 +252,7 @@ async fn test_note_list_with_comprehensive_filters() {
         offset: Some(1),
         sort: Some("title"),
         order: Some("asc"),
+        page_size: None,
     };
     let result_offset = list_notes(
         &api_client,
@@ -265,7 +268,7 @@ async fn test_note_list_with_comprehensive_filters() {
     assert!(result_offset.is_ok());
     let parsed_offset: serde_json::Value = serde_json::from_str(&result_offset.unwrap()).unwrap();
     assert_eq!(
-        parsed_offset.as_array().unwrap().len(),
+        parsed_offset["items"].as_array().unwrap().len(),
         2,
         "Should return 2 notes after skipping 1"
     );
@@ -306,7 +309,7 @@ async fn test_note_hierarchical_structure() {
         project_ids: None,
         repo_ids: None,
     };
-    let parent_result = create_note(&api_client, parent_request)
+    let parent_result = create_note(&api_client, parent_request, false)
         .await
         .expect("Failed to create parent");
 
@@ -326,7 +329,7 @@ async fn test_note_hierarchical_structure() {
         project_ids: None,
         repo_ids: None,
     };
-    create_note(&api_client, child1_request)
+    create_note(&api_client, child1_request, false)
         .await
         .expect("Failed to create child 1");
 
@@ -339,7 +342,7 @@ async fn test_note_hierarchical_structure() {
         project_ids: None,
         repo_ids: None,
     };
-    let child2_result = create_note(&api_client, child2_request)
+    let child2_result = create_note(&api_client, child2_request, false)
         .await
         .expect("Failed to create child 2");
 
@@ -372,7 +375,7 @@ async fn test_note_hierarchical_structure() {
     assert!(result.is_ok());
 
     let parsed: serde_json::Value = serde_json::from_str(&result.unwrap()).unwrap();
-    let children = parsed.as_array().unwrap();
+    let children = parsed["items"].as_array().unwrap();
     assert_eq!(children.len(), 2);
     // Verify ordering by idx: Frontend (idx=1) before Backend (idx=2)
     assert_eq!(children[0]["title"], "Frontend Application");
@@ -424,7 +427,7 @@ async fn test_note_project_and_repo_linking() {
         project_ids: Some(vec![project_id.clone(), project2_id.to_string()]),
         repo_ids: None,
     };
-    let create_result = create_note(&api_client, multi_project_request).await;
+    let create_result = create_note(&api_client, multi_project_request, false).await;
     assert!(
         create_result.is_ok(),
         "Should create note with multiple projects"
@@ -453,7 +456,7 @@ async fn test_note_project_and_repo_linking() {
         project_ids: None,
         repo_ids: Some(vec![repo_id.to_string()]),
     };
-    let create_result2 = create_note(&api_client, repo_link_request).await;
+    let create_result2 = create_note(&api_client, repo_link_request, false).await;
     assert!(create_result2.is_ok(), "Should create note with repo link");
 
     let output2 = create_result2.unwrap();
@@ -478,7 +481,7 @@ async fn test_note_project_and_repo_linking() {
         project_ids: Some(vec![project_id.clone()]),
         repo_ids: Some(vec![repo_id.to_string()]),
     };
-    let create_result3 = create_note(&api_client, combo_request).await;
+    let create_result3 = create_note(&api_client, combo_request, false).await;
     assert!(create_result3.is_ok(), "Should create note with both links");
 
     let output3 = create_result3.unwrap();
@@ -495,6 +498,118 @@ async fn test_note_project_and_repo_linking() {
     assert_eq!(note3["repo_ids"][0], json!(repo_id));
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_note_list_filters_by_project_and_tags() {
+    let (url, project_id, _handle) = spawn_test_server().await;
+    let api_client = ApiClient::new(Some(url.clone()));
+
+    let notes = [
+        ("In project, tagged", vec!["rust"], Some(project_id.clone())),
+        (
+            "In project, other tag",
+            vec!["python"],
+            Some(project_id.clone()),
+        ),
+        ("Outside project, tagged", vec!["rust"], None),
+    ];
+    for (title, tags, project) in notes {
+        let request = CreateNoteRequest {
+            title: title.to_string(),
+            content: "Content".to_string(),
+            tags: Some(tags.iter().map(|s| s.to_string()).collect()),
+            parent_id: None,
+            idx: None,
+            project_ids: project.map(|p| vec![p]),
+            repo_ids: None,
+        };
+        create_note(&api_client, request, false)
+            .await
+            .expect("Failed to create note");
+    }
+
+    let project_only = list_notes(
+        &api_client,
+        None,
+        Some(&project_id),
+        None,
+        None,
+        None,
+        PageParams::default(),
+        "json",
+    )
+    .await
+    .unwrap();
+    let parsed: serde_json::Value = serde_json::from_str(&project_only).unwrap();
+    let titles: Vec<&str> = parsed["items"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|n| n["title"].as_str().unwrap())
+        .collect();
+    assert_eq!(titles.len(), 2);
+    assert!(!titles.contains(&"Outside project, tagged"));
+
+    let combined = list_notes(
+        &api_client,
+        None,
+        Some(&project_id),
+        Some("rust"),
+        None,
+        None,
+        PageParams::default(),
+        "json",
+    )
+    .await
+    .unwrap();
+    let parsed: serde_json::Value = serde_json::from_str(&combined).unwrap();
+    let items = parsed["items"].as_array().unwrap();
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0]["title"], "In project, tagged");
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_note_search_shows_snippet() {
+    let (url, _project_id, _handle) = spawn_test_server().await;
+    let api_client = ApiClient::new(Some(url));
+
+    create_note(
+        &api_client,
+        CreateNoteRequest {
+            title: "Runtime".to_string(),
+            content: "The server uses tokio for async IO".to_string(),
+            tags: None,
+            parent_id: None,
+            idx: None,
+            project_ids: None,
+            repo_ids: None,
+        },
+    )
+    .await
+    .expect("Create should succeed");
+
+    let table = search_notes(&api_client, "tokio", None, None, None, "table")
+        .await
+        .expect("Search should succeed");
+    assert!(table.contains("Runtime"));
+    assert!(
+        table.contains("**tokio**"),
+        "Table should show the hit: {}",
+        table
+    );
+
+    let json = search_notes(&api_client, "tokio", None, None, None, "json")
+        .await
+        .unwrap();
+    let hits: Vec<NoteSearchHit> = serde_json::from_str(&json).unwrap();
+    assert_eq!(hits.len(), 1);
+    assert!(hits[0].snippet.contains("<mark>tokio</mark>"));
+
+    let empty = search_notes(&api_client, "nomatch", None, None, None, "table")
+        .await
+        .unwrap();
+    assert_eq!(empty, "No notes found.");
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn test_note_error_handling() {
     let (url, _project_id, _handle) = spawn_test_server().await;
@@ -536,7 +651,7 @@ async fn test_note_error_handling() {
     );
 
     // DELETE: Non-existent note (with force)
-    let delete_result = delete_note(&api_client, "nonexist", true).await;
+    let delete_result = delete_note(&api_client, "nonexist", true, false).await;
     assert!(
         delete_result.is_err(),
         "Should return error for non-existent note"
@@ -564,7 +679,7 @@ async fn test_note_display_formats_and_filters() {
         project_ids: Some(vec![project_id.clone()]),
         repo_ids: None,
     };
-    let create_result = create_note(&api_client, create_request).await;
+    let create_result = create_note(&api_client, create_request, false).await;
     assert!(create_result.is_ok());
     let output = create_result.unwrap();
     let note_id = output
@@ -605,7 +720,7 @@ async fn test_note_display_formats_and_filters() {
         project_ids: None,
         repo_ids: None,
     };
-    let child_result = create_note(&api_client, child_request).await;
+    let child_result = create_note(&api_client, child_request, false).await;
     assert!(child_result.is_ok());
     let child_output = child_result.unwrap();
     let child_id = child_output
@@ -632,7 +747,7 @@ async fn test_note_display_formats_and_filters() {
         project_ids: None,
         repo_ids: None,
     };
-    create_note(&api_client, note2_request)
+    create_note(&api_client, note2_request, false)
         .await
         .expect("Failed to create note 2");
 
@@ -678,7 +793,7 @@ async fn test_note_display_formats_and_filters() {
     .await;
     assert!(list_query.is_ok(), "Should filter by query");
     let query_result: serde_json::Value = serde_json::from_str(&list_query.unwrap()).unwrap();
-    let query_notes = query_result.as_array().unwrap();
+    let query_notes = query_result["items"].as_array().unwrap();
     assert!(!query_notes.is_empty(), "Should find notes matching query");
 
     // Test LIST with project_id filter (covers line 103)
@@ -695,7 +810,7 @@ async fn test_note_display_formats_and_filters() {
     .await;
     assert!(list_project.is_ok(), "Should filter by project_id");
     let project_result: serde_json::Value = serde_json::from_str(&list_project.unwrap()).unwrap();
-    let project_notes = project_result.as_array().unwrap();
+    let project_notes = project_result["items"].as_array().unwrap();
     assert!(!project_notes.is_empty(), "Should find notes in project");
 
     // Test LIST with note_type filter (covers line 112)
@@ -734,3 +849,84 @@ async fn test_note_display_formats_and_filters() {
         "Should show empty message"
     );
 }
+
+#[test]
+fn test_resolve_content_sources() {
+    // --content passes through untouched
+    let content = resolve_content_from(Some("inline".to_string()), None, std::io::empty());
+    assert_eq!(content.unwrap().as_deref(), Some("inline"));
+
+    // Neither flag: no content
+    assert_eq!(
+        resolve_content_from(None, None, std::io::empty()).unwrap(),
+        None
+    );
+
+    // --file reads the file
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("note.md");
+    std::fs::write(&path, "# From file\n\nBody").unwrap();
+    let content = resolve_content_from(None, Some(path.to_str().unwrap()), std::io::empty());
+    assert_eq!(content.unwrap().as_deref(), Some("# From file\n\nBody"));
+
+    // --file - reads stdin
+    let stdin = std::io::Cursor::new("piped markdown");
+    let content = resolve_content_from(None, Some("-"), stdin);
+    assert_eq!(content.unwrap().as_deref(), Some("piped markdown"));
+
+    // Missing file is an argument error naming the path
+    let missing = dir.path().join("missing.md");
+    let err =
+        resolve_content_from(None, Some(missing.to_str().unwrap()), std::io::empty()).unwrap_err();
+    assert!(err.to_string().contains("missing.md"));
+}
+
+#[test]
+fn test_edit_content_uses_editor_result() {
+    // The editor command gets the temp file path appended
+    let content = edit_content_with("printf '# Edited' >", "").unwrap();
+    assert_eq!(content, "# Edited");
+
+    // Seeded content is what the editor sees
+    let content = edit_content_with("printf ' more' >>", "# Existing").unwrap();
+    assert_eq!(content, "# Existing more");
+}
+
+#[test]
+fn test_edit_content_aborts() {
+    // Editor failure
+    let err = edit_content_with("false", "").unwrap_err();
+    assert!(err.to_string().contains("note not saved"));
+
+    // Left unchanged
+    let err = edit_content_with("true", "# Existing").unwrap_err();
+    assert!(err.to_string().contains("empty or unchanged"));
+
+    // Emptied
+    let err = edit_content_with("printf '  ' >", "# Existing").unwrap_err();
+    assert!(err.to_string().contains("empty or unchanged"));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_list_notes_json_empty_result() {
+    let (url, _project_id, _handle) = spawn_test_server().await;
+    let api_client = ApiClient::new(Some(url));
+
+    let json = list_notes(
+        &api_client,
+        None,
+        None,
+        Some("no-such-tag"),
+        None,
+        None,
+        PageParams::default(),
+        "json",
+    )
+    .await
+    .unwrap();
+    let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed["items"], serde_json::json!([]));
+    assert_eq!(parsed["total"], 0);
+    let page: Paginated<Note> = serde_json::from_str(&json).unwrap();
+    assert!(page.items.is_empty());
+}
@@ -1,18 +1,11 @@
 use crate::cli::api_client::ApiClient;
-use crate::cli::commands::PageParams;
+use crate::cli::commands::format::{CsvRecord, join_list, to_csv};
+use crate::cli::commands::{PageParams, Paginated, with_truncation_hint};
 use crate::cli::error::CliResult;
-use crate::cli::utils::{apply_table_style, format_tags, truncate_with_ellipsis};
+use crate::cli::utils::{apply_table_style, format_tags, truncate_display};
 use serde::{Deserialize, Serialize};
 use tabled::{Table, Tabled};
 
-#[derive(Debug, Serialize, Deserialize)]
-struct ListProjectsResponse {
-    items: Vec<Project>,
-    total: usize,
-    limit: usize,
-    offset: usize,
-}
-
 #[derive(Debug, Serialize)]
 pub struct CreateProjectRequest {
     pub title: String,
@@ -22,6 +15,9 @@ pub struct CreateProjectRequest {
     pub tags: Option<Vec<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub external_refs: Option<Vec<String>>,
+    /// Repos linked in the same transaction as the create
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub repo_ids: Option<Vec<String>>,
 }
 
 #[derive(Debug, Serialize)]
@@ -47,6 +43,30 @@ pub struct Project {
     pub updated_at: String,
 }
 
+impl CsvRecord for Project {
+    const HEADER: &'static [&'static str] = &[
+        "id",
+        "title",
+        "description",
+        "tags",
+        "external_refs",
+        "created_at",
+        "updated_at",
+    ];
+
+    fn record(&self) -> Vec<String> {
+        vec![
+            self.id.clone(),
+            self.title.clone(),
+            self.description.clone().unwrap_or_default(),
+            join_list(self.tags.as_deref().unwrap_or_default()),
+            join_list(&self.external_refs),
+            self.created_at.clone(),
+            self.updated_at.clone(),
+        ]
+    }
+}
+
 #[derive(Tabled)]
 struct ProjectDisplay {
     #[tabled(rename = "ID")]
@@ -63,11 +83,11 @@ impl From<&Project> for ProjectDisplay {
     fn from(project: &Project) -> Self {
         Self {
             id: project.id.clone(),
-            title: truncate_with_ellipsis(&project.title, 40),
+            title: truncate_display(&project.title, 40),
             description: project
                 .description
                 .as_ref()
-                .map(|d| truncate_with_ellipsis(d, 50))
+                .map(|d| truncate_display(d, 50))
                 .unwrap_or_else(|| "-".to_string()),
             tags: format_tags(project.tags.as_ref()),
         }
@@ -82,6 +102,8 @@ pub async fn list_projects(
     page: PageParams<'_>,
     format: &str,
 ) -> CliResult<String> {
+    page.validate(&["title", "created_at", "updated_at"])?;
+
     let mut request = api_client.get("/api/v1/projects");
 
     if let Some(q) = query {
@@ -90,7 +112,7 @@ pub async fn list_projects(
     if let Some(t) = tags {
         request = request.query(&[("tags", t)]);
     }
-    if let Some(l) = page.limit {
+    if let Some(l) = page.effective_limit() {
         request = request.query(&[("limit", l.to_string())]);
     }
     if let Some(o) = page.offset {
@@ -103,12 +125,15 @@ pub async fn list_projects(
         request = request.query(&[("order", ord)]);
     }
 
-    let response: ListProjectsResponse = request.send().await?.json().await?;
+    let response: Paginated<Project> = request.send().await?.json().await?;
 
-    match format {
-        "json" => Ok(serde_json::to_string_pretty(&response.items)?),
-        _ => Ok(format_table(&response.items)),
-    }
+    let hint = page.truncation_hint(response.items.len(), response.total);
+    let output = match format {
+        "json" => serde_json::to_string_pretty(&response)?,
+        "csv" => to_csv(&response.items),
+        _ => format_table(&response.items),
+    };
+    Ok(with_truncation_hint(output, format, hint))
 }
 
 fn format_table(projects: &[Project]) -> String {
@@ -167,6 +192,121 @@ fn format_project_detail(project: &Project) -> String {
     table.to_string()
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+struct ProjectTree {
+    project: Project,
+    task_lists: Vec<ProjectTreeTaskList>,
+    notes: Vec<ProjectTreeNote>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ProjectTreeTaskList {
+    id: String,
+    title: String,
+    status: String,
+    stats: ProjectTreeStats,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ProjectTreeStats {
+    total: usize,
+    backlog: usize,
+    todo: usize,
+    in_progress: usize,
+    review: usize,
+    done: usize,
+    cancelled: usize,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ProjectTreeNote {
+    id: String,
+    title: String,
+    parent_id: Option<String>,
+}
+
+/// Show a project with its task lists and notes as an indented outline
+pub async fn project_tree(
+    api_client: &ApiClient,
+    id: &str,
+    depth: u32,
+    format: &str,
+) -> CliResult<String> {
+    let response = api_client
+        .get(&format!("/api/v1/projects/{}/tree", id))
+        .send()
+        .await?;
+
+    let tree: ProjectTree = ApiClient::handle_response(response).await?;
+
+    match format {
+        "json" => Ok(serde_json::to_string_pretty(&tree)?),
+        _ => Ok(format_project_tree(&tree, depth)),
+    }
+}
+
+fn format_project_tree(tree: &ProjectTree, depth: u32) -> String {
+    let mut lines = vec![format!("{} ({})", tree.project.title, tree.project.id)];
+
+    if depth == 0 {
+        return lines.join("\n");
+    }
+
+    lines.push("  Task lists:".to_string());
+    if tree.task_lists.is_empty() {
+        lines.push("    (none)".to_string());
+    }
+    for list in &tree.task_lists {
+        lines.push(format!(
+            "    {} ({}) [{}]",
+            list.title, list.id, list.status
+        ));
+        if depth >= 2 {
+            lines.push(format!("      {}", format_stats_summary(&list.stats)));
+        }
+    }
+
+    // Subnotes nest under their parent when the parent is part of the project
+    let is_listed = |id: &str| tree.notes.iter().any(|n| n.id == id);
+    let top_level: Vec<&ProjectTreeNote> = tree
+        .notes
+        .iter()
+        .filter(|n| n.parent_id.as_deref().is_none_or(|p| !is_listed(p)))
+        .collect();
+
+    lines.push("  Notes:".to_string());
+    if top_level.is_empty() {
+        lines.push("    (none)".to_string());
+    }
+    for note in top_level {
+        lines.push(format!("    {} ({})", note.title, note.id));
+        if depth >= 2 {
+            for subnote in tree
+                .notes
+                .iter()
+                .filter(|n| n.parent_id.as_deref() == Some(note.id.as_str()))
+            {
+                lines.push(format!("      {} ({})", subnote.title, subnote.id));
+            }
+        }
+    }
+
+    lines.join("\n")
+}
+
+fn format_stats_summary(stats: &ProjectTreeStats) -> String {
+    format!(
+        "{} tasks: {} backlog, {} todo, {} in progress, {} review, {} done, {} cancelled",
+        stats.total,
+        stats.backlog,
+        stats.todo,
+        stats.in_progress,
+        stats.review,
+        stats.done,
+        stats.cancelled
+    )
+}
+
 /// Create a new project
 pub async fn create_project(
     api_client: &ApiClient,
@@ -205,7 +345,14 @@ pub async fn update_project(
 }
 
 /// Delete a project (requires --force flag for safety)
-pub async fn delete_project(api_client: &ApiClient, id: &str, force: bool) -> CliResult<String> {
+///
+/// With `purge` the row is removed for good instead of being marked deleted.
+pub async fn delete_project(
+    api_client: &ApiClient,
+    id: &str,
+    force: bool,
+    purge: bool,
+) -> CliResult<String> {
     // Safety check: require --force flag
     if !force {
         return Err(crate::cli::error::CliError::InvalidResponse {
@@ -213,10 +360,11 @@ pub async fn delete_project(api_client: &ApiClient, id: &str, force: bool) -> Cl
         });
     }
 
-    let response = api_client
-        .delete(&format!("/api/v1/projects/{}", id))
-        .send()
-        .await?;
+    let mut request = api_client.delete(&format!("/api/v1/projects/{}", id));
+    if purge {
+        request = request.query(&[("purge", "true")]);
+    }
+    let response = request.send().await?;
 
     // For delete, we expect no body on success, so we don't use handle_response
     // Just check status
@@ -1,8 +1,9 @@
 use crate::a6s::store::surrealdb;
 use crate::api::{AppState, routes};
 use crate::cli::api_client::ApiClient;
-use crate::cli::commands::PageParams;
 use crate::cli::commands::project::*;
+use crate::cli::commands::{PageParams, Paginated};
+use crate::cli::error::CliError;
 use crate::db::{Database, SqliteDatabase};
 use crate::sync::MockGitOps;
 use serde_json::json;
@@ -57,6 +58,7 @@ async fn test_project_crud_operations() {
         description: Some("Migrate monolithic backend to microservices architecture using Kubernetes and service mesh".to_string()),
         tags: Some(vec!["backend".to_string(), "microservices".to_string(), "kubernetes".to_string(), "2026-q1".to_string()]),
         external_refs: Some(vec!["ARCH-2026".to_string(), "github/acme/backend#456".to_string()]),
+        repo_ids: None,
     };
     let create_result = create_project(&api_client, create_request).await;
     assert!(
@@ -131,12 +133,12 @@ async fn test_project_crud_operations() {
     );
 
     // DELETE: Requires force flag
-    let delete_no_force = delete_project(&api_client, project_id, false).await;
+    let delete_no_force = delete_project(&api_client, project_id, false, false).await;
     assert!(delete_no_force.is_err(), "Should require --force flag");
     assert!(delete_no_force.unwrap_err().to_string().contains("--force"));
 
     // DELETE: Successful with force
-    let delete_result = delete_project(&api_client, project_id, true).await;
+    let delete_result = delete_project(&api_client, project_id, true, false).await;
     assert!(delete_result.is_ok(), "Should delete with --force");
 
     // Verify deletion
@@ -180,6 +182,7 @@ async fn test_project_list_with_comprehensive_filters() {
                 "{}-REF",
                 title.split_whitespace().next().unwrap()
             )]),
+            repo_ids: None,
         };
         create_project(&api_client, request)
             .await
@@ -190,7 +193,7 @@ async fn test_project_list_with_comprehensive_filters() {
     let result_all = list_projects(&api_client, None, None, PageParams::default(), "json").await;
     assert!(result_all.is_ok());
     let parsed_all: serde_json::Value = serde_json::from_str(&result_all.unwrap()).unwrap();
-    assert_eq!(parsed_all.as_array().unwrap().len(), 3);
+    assert_eq!(parsed_all["items"].as_array().unwrap().len(), 3);
 
     // Test sort ascending
     let page_asc = PageParams {
@@ -198,11 +201,12 @@ async fn test_project_list_with_comprehensive_filters() {
         offset: None,
         sort: Some("title"),
         order: Some("asc"),
+        page_size: None,
     };
     let result_asc = list_projects(&api_client, None, None, page_asc, "json").await;
     assert!(result_asc.is_ok());
     let parsed_asc: serde_json::Value = serde_json::from_str(&result_asc.unwrap()).unwrap();
-    let projects_asc = parsed_asc.as_array().unwrap();
+    let projects_asc = parsed_asc["items"].as_array().unwrap();
     assert_eq!(projects_asc[0]["title"], "Alpha Frontend Redesign");
     assert_eq!(
         projects_asc[projects_asc.len() - 1]["title"],
@@ -215,11 +219,12 @@ async fn test_project_list_with_comprehensive_filters() {
         offset: None,
         sort: Some("title"),
         order: Some("desc"),
+        page_size: None,
     };
     let result_desc = list_projects(&api_client, None, None, page_desc, "json").await;
     assert!(result_desc.is_ok());
     let parsed_desc: serde_json::Value = serde_json::from_str(&result_desc.unwrap()).unwrap();
-    let projects_desc = parsed_desc.as_array().unwrap();
+    let projects_desc = parsed_desc["items"].as_array().unwrap();
     assert_eq!(projects_desc[0]["title"], "Zebra Mobile App");
     assert_eq!(
         projects_desc[projects_desc.len() - 1]["title"],
@@ -232,17 +237,94 @@ async fn test_project_list_with_comprehensive_filters() {
         offset: Some(1),
         sort: Some("title"),
         order: Some("asc"),
+        page_size: None,
     };
     let result_offset = list_projects(&api_client, None, None, page_offset, "json").await;
     assert!(result_offset.is_ok());
     let parsed_offset: serde_json::Value = serde_json::from_str(&result_offset.unwrap()).unwrap();
     assert_eq!(
-        parsed_offset.as_array().unwrap().len(),
+        parsed_offset["items"].as_array().unwrap().len(),
         2,
         "Should return 2 projects after skipping 1"
     );
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_project_list_applies_page_size_unless_limit_given() {
+    let (url, _handle) = spawn_test_server().await;
+    let api_client = ApiClient::new(Some(url));
+
+    for title in ["First", "Second", "Third"] {
+        let request = CreateProjectRequest {
+            title: title.to_string(),
+            description: None,
+            tags: None,
+            external_refs: None,
+            repo_ids: None,
+        };
+        create_project(&api_client, request).await.unwrap();
+    }
+
+    // No --limit: the page size is sent as the limit
+    let paged = PageParams {
+        page_size: Some(2),
+        ..Default::default()
+    };
+    let json = list_projects(&api_client, None, None, paged, "json")
+        .await
+        .unwrap();
+    let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed["items"].as_array().unwrap().len(), 2);
+
+    let paged = PageParams {
+        page_size: Some(2),
+        ..Default::default()
+    };
+    let table = list_projects(&api_client, None, None, paged, "table")
+        .await
+        .unwrap();
+    assert!(table.contains("Showing 2 of 3 (page size 2)"));
+    assert!(table.contains("--offset 2"));
+
+    // An explicit --limit overrides the page size and drops the hint
+    let limited = PageParams {
+        limit: Some(3),
+        page_size: Some(2),
+        ..Default::default()
+    };
+    let table = list_projects(&api_client, None, None, limited, "table")
+        .await
+        .unwrap();
+    assert!(table.contains("Third"));
+    assert!(!table.contains("Showing"));
+}
+
+#[tokio::test]
+async fn test_project_list_rejects_invalid_sort() {
+    // Validation happens before any request is made
+    let api_client = ApiClient::new(Some("http://localhost:9999".to_string()));
+
+    let bad_field = PageParams {
+        sort: Some("name"),
+        ..Default::default()
+    };
+    let err = list_projects(&api_client, None, None, bad_field, "json")
+        .await
+        .unwrap_err();
+    assert!(matches!(err, CliError::InvalidArgument { .. }));
+    assert!(err.to_string().contains("title, created_at, updated_at"));
+
+    let bad_order = PageParams {
+        sort: Some("title"),
+        order: Some("down"),
+        ..Default::default()
+    };
+    let err = list_projects(&api_client, None, None, bad_order, "json")
+        .await
+        .unwrap_err();
+    assert!(matches!(err, CliError::InvalidArgument { .. }));
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn test_project_error_handling() {
     let (url, _handle) = spawn_test_server().await;
@@ -275,7 +357,7 @@ async fn test_project_error_handling() {
     );
 
     // DELETE: Non-existent project (with force)
-    let delete_result = delete_project(&api_client, "nonexist", true).await;
+    let delete_result = delete_project(&api_client, "nonexist", true, false).await;
     assert!(
         delete_result.is_err(),
         "Should return error for non-existent project"
@@ -292,7 +374,7 @@ async fn test_project_error_handling() {
 async fn test_delete_project_force_flag_validation() {
     // Test the --force flag validation (pure logic, no HTTP needed)
     let api_client = ApiClient::new(None);
-    let result = delete_project(&api_client, "test-id", false).await;
+    let result = delete_project(&api_client, "test-id", false, false).await;
 
     assert!(result.is_err(), "Should require --force flag");
     let error_msg = result.unwrap_err().to_string();
@@ -332,6 +414,7 @@ async fn test_project_display_formats_and_filters() {
             "INFRA-2026".to_string(),
             "github/acme/infra#123".to_string(),
         ]),
+        repo_ids: None,
     };
     let create1 = create_project(&api_client, project1).await.unwrap();
     let project1_id = create1
@@ -349,6 +432,7 @@ async fn test_project_display_formats_and_filters() {
             "android".to_string(),
         ]),
         external_refs: Some(vec!["MOBILE-456".to_string()]),
+        repo_ids: None,
     };
     create_project(&api_client, project2).await.unwrap();
 
@@ -357,6 +441,7 @@ async fn test_project_display_formats_and_filters() {
         description: None,   // Test None description display
         tags: None,          // Test None tags display
         external_refs: None, // Test None external_refs display
+        repo_ids: None,
     };
     create_project(&api_client, project3).await.unwrap();
 
@@ -425,7 +510,7 @@ async fn test_project_display_formats_and_filters() {
     .await;
     assert!(query_result.is_ok());
     let parsed: serde_json::Value = serde_json::from_str(&query_result.unwrap()).unwrap();
-    let projects = parsed.as_array().unwrap();
+    let projects = parsed["items"].as_array().unwrap();
     assert_eq!(projects.len(), 1, "Should find 1 project matching 'Mobile'");
     assert!(projects[0]["title"].as_str().unwrap().contains("Mobile"));
 
@@ -440,7 +525,7 @@ async fn test_project_display_formats_and_filters() {
     .await;
     assert!(tags_result.is_ok());
     let parsed_tags: serde_json::Value = serde_json::from_str(&tags_result.unwrap()).unwrap();
-    let projects_tags = parsed_tags.as_array().unwrap();
+    let projects_tags = parsed_tags["items"].as_array().unwrap();
     assert_eq!(
         projects_tags.len(),
         1,
@@ -458,7 +543,7 @@ async fn test_project_display_formats_and_filters() {
     .await
     .unwrap();
     let project3_parsed: serde_json::Value = serde_json::from_str(&project3_list).unwrap();
-    let project3_id = project3_parsed[0]["id"].as_str().unwrap();
+    let project3_id = project3_parsed["items"][0]["id"].as_str().unwrap();
 
     let detail3_result = get_project(&api_client, project3_id, "table").await;
     assert!(detail3_result.is_ok());
@@ -484,3 +569,125 @@ async fn test_project_display_formats_and_filters() {
         "Detail should always show Title"
     );
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_project_tree_outline_includes_list_stats() {
+    let (url, _handle) = spawn_test_server().await;
+    let api_client = ApiClient::new(Some(url));
+
+    let output = create_project(
+        &api_client,
+        CreateProjectRequest {
+            title: "Platform Rewrite".to_string(),
+            description: None,
+            tags: None,
+            external_refs: None,
+            repo_ids: None,
+        },
+    )
+    .await
+    .unwrap();
+    let project_id = output
+        .split('(')
+        .nth(1)
+        .and_then(|s| s.split(')').next())
+        .expect("Failed to extract project ID")
+        .to_string();
+
+    let post = |path: String, body: serde_json::Value| {
+        let api_client = &api_client;
+        async move {
+            let response = api_client.post(&path).json(&body).send().await.unwrap();
+            let value: serde_json::Value = ApiClient::handle_response(response).await.unwrap();
+            value["id"].as_str().unwrap().to_string()
+        }
+    };
+
+    let api_list = post(
+        "/api/v1/task-lists".to_string(),
+        json!({"title": "API Migration", "project_id": project_id}),
+    )
+    .await;
+    let ui_list = post(
+        "/api/v1/task-lists".to_string(),
+        json!({"title": "UI Refresh", "project_id": project_id}),
+    )
+    .await;
+
+    post(
+        format!("/api/v1/task-lists/{}/tasks", api_list),
+        json!({"title": "Port endpoints"}),
+    )
+    .await;
+    let finished = post(
+        format!("/api/v1/task-lists/{}/tasks", api_list),
+        json!({"title": "Write migration plan"}),
+    )
+    .await;
+    api_client
+        .patch(&format!("/api/v1/tasks/{}/complete", finished))
+        .send()
+        .await
+        .unwrap();
+
+    let parent_note = post(
+        "/api/v1/notes".to_string(),
+        json!({"title": "Architecture", "content": "Overview", "project_ids": [project_id]}),
+    )
+    .await;
+    post(
+        "/api/v1/notes".to_string(),
+        json!({
+            "title": "Data Model",
+            "content": "Tables",
+            "parent_id": parent_note,
+            "project_ids": [project_id]
+        }),
+    )
+    .await;
+
+    let outline = project_tree(&api_client, &project_id, 2, "table")
+        .await
+        .unwrap();
+
+    assert!(outline.starts_with(&format!("Platform Rewrite ({})", project_id)));
+    assert!(outline.contains(&format!("    API Migration ({}) [active]", api_list)));
+    assert!(outline.contains(
+        "      2 tasks: 0 backlog, 1 todo, 0 in progress, 0 review, 1 done, 0 cancelled"
+    ));
+    assert!(outline.contains(&format!("    UI Refresh ({}) [active]", ui_list)));
+    assert!(outline.contains(
+        "      0 tasks: 0 backlog, 0 todo, 0 in progress, 0 review, 0 done, 0 cancelled"
+    ));
+    assert!(outline.contains(&format!("    Architecture ({})", parent_note)));
+    assert!(outline.contains("      Data Model ("));
+
+    // Depth 1 stops at lists and notes
+    let shallow = project_tree(&api_client, &project_id, 1, "table")
+        .await
+        .unwrap();
+    assert!(shallow.contains("API Migration"));
+    assert!(!shallow.contains("tasks:"));
+    assert!(!shallow.contains("Data Model"));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_list_projects_json_empty_result() {
+    let (url, _handle) = spawn_test_server().await;
+    let api_client = ApiClient::new(Some(url));
+
+    let json = list_projects(
+        &api_client,
+        None,
+        Some("no-such-tag"),
+        PageParams::default(),
+        "json",
+    )
+    .await
+    .unwrap();
+    let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed["items"], serde_json::json!([]));
+    assert_eq!(parsed["total"], 0);
+    let page: Paginated<Project> = serde_json::from_str(&json).unwrap();
+    assert!(page.items.is_empty());
+}
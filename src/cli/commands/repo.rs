@@ -1,18 +1,13 @@
 use crate::cli::api_client::ApiClient;
-use crate::cli::commands::PageParams;
-use crate::cli::error::CliResult;
-use crate::cli::utils::{apply_table_style, format_tags, truncate_with_ellipsis};
+use crate::cli::commands::format::{CsvRecord, join_list, to_csv};
+use crate::cli::commands::{PageParams, Paginated, with_truncation_hint};
+use crate::cli::error::{CliError, CliResult};
+use crate::cli::utils::{apply_table_style, format_tags, truncate_display};
+use crate::sync::{GitOps, RetryPolicy, get_repo_clone_dir};
 use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
 use tabled::{Table, Tabled};
 
-#[derive(Debug, Serialize, Deserialize)]
-struct ListReposResponse {
-    items: Vec<Repo>,
-    total: usize,
-    limit: usize,
-    offset: usize,
-}
-
 #[derive(Debug, Serialize)]
 pub struct CreateRepoRequest {
     pub remote: String,
@@ -46,6 +41,22 @@ pub struct Repo {
     pub created_at: String,
 }
 
+impl CsvRecord for Repo {
+    const HEADER: &'static [&'static str] =
+        &["id", "remote", "path", "tags", "project_ids", "created_at"];
+
+    fn record(&self) -> Vec<String> {
+        vec![
+            self.id.clone(),
+            self.remote.clone(),
+            self.path.clone().unwrap_or_default(),
+            join_list(&self.tags),
+            join_list(&self.project_ids),
+            self.created_at.clone(),
+        ]
+    }
+}
+
 #[derive(Tabled)]
 struct RepoDisplay {
     #[tabled(rename = "ID")]
@@ -62,11 +73,11 @@ impl From<&Repo> for RepoDisplay {
     fn from(repo: &Repo) -> Self {
         Self {
             id: repo.id.clone(),
-            remote: truncate_with_ellipsis(&repo.remote, 50),
+            remote: truncate_display(&repo.remote, 50),
             path: repo
                 .path
                 .as_ref()
-                .map(|p| truncate_with_ellipsis(p, 30))
+                .map(|p| truncate_display(p, 30))
                 .unwrap_or_else(|| "-".to_string()),
             tags: format_tags(Some(&repo.tags)),
         }
@@ -129,6 +140,8 @@ pub async fn list_repos(
     page: PageParams<'_>,
     format: &str,
 ) -> CliResult<String> {
+    page.validate(&["remote", "path", "created_at"])?;
+
     let mut request = api_client.get("/api/v1/repos");
 
     if let Some(q) = query {
@@ -140,7 +153,7 @@ pub async fn list_repos(
     if let Some(t) = tags {
         request = request.query(&[("tags", t)]);
     }
-    if let Some(l) = page.limit {
+    if let Some(l) = page.effective_limit() {
         request = request.query(&[("limit", l.to_string())]);
     }
     if let Some(o) = page.offset {
@@ -153,12 +166,15 @@ pub async fn list_repos(
         request = request.query(&[("order", ord)]);
     }
 
-    let response: ListReposResponse = request.send().await?.json().await?;
+    let response: Paginated<Repo> = request.send().await?.json().await?;
 
-    match format {
-        "json" => Ok(serde_json::to_string_pretty(&response.items)?),
-        _ => Ok(format_table(&response.items)),
-    }
+    let hint = page.truncation_hint(response.items.len(), response.total);
+    let output = match format {
+        "json" => serde_json::to_string_pretty(&response)?,
+        "csv" => to_csv(&response.items),
+        _ => format_table(&response.items),
+    };
+    Ok(with_truncation_hint(output, format, hint))
 }
 
 fn format_table(repos: &[Repo]) -> String {
@@ -248,18 +264,99 @@ pub async fn update_repo(
     ))
 }
 
+/// Whether `path` is missing or an empty directory, i.e. safe to clone into
+fn is_clone_target(path: &Path) -> bool {
+    match std::fs::read_dir(path) {
+        Ok(mut entries) => entries.next().is_none(),
+        Err(_) => !path.exists(),
+    }
+}
+
+/// Check out a repo's remote locally
+///
+/// Clones into the repo's `path`, or into the data directory when it has
+/// none and then records that location on the repo. A path that already
+/// holds a git checkout is fast-forwarded instead; any other non-empty path
+/// is left alone.
+pub async fn clone_repo<G: GitOps>(api_client: &ApiClient, git: &G, id: &str) -> CliResult<String> {
+    let response = api_client
+        .get(&format!("/api/v1/repos/{}", id))
+        .send()
+        .await?;
+    let repo: Repo = ApiClient::handle_response(response).await?;
+
+    let (dest, assign_path) = match repo.path.as_deref().map(str::trim) {
+        Some(path) if !path.is_empty() => (PathBuf::from(path), false),
+        _ => (get_repo_clone_dir(&repo.id), true),
+    };
+    let retry = RetryPolicy::from_env();
+
+    let action = if dest.join(".git").exists() {
+        retry.run("pull", || git.pull_ff_only(&dest))?;
+        "Updated"
+    } else if is_clone_target(&dest) {
+        if let Some(parent) = dest.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| CliError::InvalidArgument {
+                message: format!("Cannot create {}: {}", parent.display(), e),
+            })?;
+        }
+        retry.run("clone", || git.clone_repo(&repo.remote, &dest))?;
+        "Cloned"
+    } else {
+        return Err(CliError::InvalidArgument {
+            message: format!("{} is not empty and is not a git checkout", dest.display()),
+        });
+    };
+
+    if assign_path {
+        update_repo(
+            api_client,
+            &repo.id,
+            UpdateRepoRequest {
+                remote: None,
+                path: Some(dest.to_string_lossy().into_owned()),
+                tags: None,
+                project_ids: None,
+            },
+        )
+        .await?;
+    }
+
+    Ok(format!(
+        "✓ {} repository: {} ({})\n  Path: {}",
+        action,
+        repo.remote,
+        repo.id,
+        dest.display()
+    ))
+}
+
 /// Delete a repo (requires --force flag for safety)
-pub async fn delete_repo(api_client: &ApiClient, id: &str, force: bool) -> CliResult<String> {
+///
+/// With `unlink` the repo is unlinked from any projects, task lists and notes
+/// instead of the delete being refused. With `purge` the row is removed for
+/// good instead of being marked deleted.
+pub async fn delete_repo(
+    api_client: &ApiClient,
+    id: &str,
+    force: bool,
+    unlink: bool,
+    purge: bool,
+) -> CliResult<String> {
     if !force {
         return Err(crate::cli::error::CliError::InvalidResponse {
             message: "Delete operation requires --force flag. This action is destructive and cannot be undone.".to_string(),
         });
     }
 
-    let response = api_client
-        .delete(&format!("/api/v1/repos/{}", id))
-        .send()
-        .await?;
+    let mut request = api_client.delete(&format!("/api/v1/repos/{}", id));
+    if unlink {
+        request = request.query(&[("force", "true")]);
+    }
+    if purge {
+        request = request.query(&[("purge", "true")]);
+    }
+    let response = request.send().await?;
 
     if response.status().is_success() {
         Ok(format!("✓ Deleted repository: {}", id))
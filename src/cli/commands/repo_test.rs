@@ -1,10 +1,11 @@
 use crate::a6s::store::surrealdb;
 use crate::api::{AppState, routes};
 use crate::cli::api_client::ApiClient;
-use crate::cli::commands::PageParams;
 use crate::cli::commands::repo::*;
+use crate::cli::commands::{PageParams, Paginated};
 use crate::db::{Database, SqliteDatabase};
 use crate::sync::MockGitOps;
+use serial_test::serial;
 use std::sync::Arc;
 use tempfile::TempDir;
 use tokio::net::TcpListener;
@@ -117,12 +118,12 @@ async fn test_repo_crud_operations() {
     assert_eq!(updated_repo.tags, vec!["backend", "api", "v2"]);
 
     // DELETE: Requires force flag
-    let delete_no_force = delete_repo(&api_client, repo_id, false).await;
+    let delete_no_force = delete_repo(&api_client, repo_id, false, false, false).await;
     assert!(delete_no_force.is_err(), "Should require --force flag");
     assert!(delete_no_force.unwrap_err().to_string().contains("--force"));
 
     // DELETE: Successful with force
-    let delete_result = delete_repo(&api_client, repo_id, true).await;
+    let delete_result = delete_repo(&api_client, repo_id, true, false, false).await;
     assert!(delete_result.is_ok(), "Should delete with --force");
 
     // Verify deletion
@@ -166,7 +167,7 @@ async fn test_repo_list_with_comprehensive_filters() {
     .await;
     assert!(result.is_ok());
     let parsed: serde_json::Value = serde_json::from_str(&result.unwrap()).unwrap();
-    let repos_found = parsed.as_array().unwrap();
+    let repos_found = parsed["items"].as_array().unwrap();
     assert_eq!(repos_found.len(), 1, "Should find 1 repo matching 'rust'");
     assert!(repos_found[0]["remote"].as_str().unwrap().contains("rust"));
 
@@ -176,11 +177,12 @@ async fn test_repo_list_with_comprehensive_filters() {
         offset: None,
         sort: Some("remote"),
         order: Some("asc"),
+        page_size: None,
     };
     let result_asc = list_repos(&api_client, None, None, None, page_asc, "json").await;
     assert!(result_asc.is_ok());
     let parsed_asc: serde_json::Value = serde_json::from_str(&result_asc.unwrap()).unwrap();
-    let repos_asc = parsed_asc.as_array().unwrap();
+    let repos_asc = parsed_asc["items"].as_array().unwrap();
     assert!(repos_asc[0]["remote"].as_str().unwrap().contains("alpha"));
     assert!(
         repos_asc[repos_asc.len() - 1]["remote"]
@@ -195,11 +197,12 @@ async fn test_repo_list_with_comprehensive_filters() {
         offset: None,
         sort: Some("remote"),
         order: Some("desc"),
+        page_size: None,
     };
     let result_desc = list_repos(&api_client, None, None, None, page_desc, "json").await;
     assert!(result_desc.is_ok());
     let parsed_desc: serde_json::Value = serde_json::from_str(&result_desc.unwrap()).unwrap();
-    let repos_desc = parsed_desc.as_array().unwrap();
+    let repos_desc = parsed_desc["items"].as_array().unwrap();
     assert!(repos_desc[0]["remote"].as_str().unwrap().contains("rust"));
     assert!(
         repos_desc[repos_desc.len() - 1]["remote"]
@@ -214,12 +217,13 @@ async fn test_repo_list_with_comprehensive_filters() {
         offset: Some(1),
         sort: Some("remote"),
         order: Some("asc"),
+        page_size: None,
     };
     let result_offset = list_repos(&api_client, None, None, None, page_offset, "json").await;
     assert!(result_offset.is_ok());
     let parsed_offset: serde_json::Value = serde_json::from_str(&result_offset.unwrap()).unwrap();
     assert_eq!(
-        parsed_offset.as_array().unwrap().len(),
+        parsed_offset["items"].as_array().unwrap().len(),
         2,
         "Should return 2 repos after skipping 1"
     );
@@ -355,7 +359,7 @@ async fn test_repo_project_linking() {
     .await;
     assert!(result.is_ok());
     let parsed: serde_json::Value = serde_json::from_str(&result.unwrap()).unwrap();
-    let repos = parsed.as_array().unwrap();
+    let repos = parsed["items"].as_array().unwrap();
     // Should find repos 1, 2, and 3 (all linked to project1)
     assert!(
         repos.len() >= 3,
@@ -395,7 +399,7 @@ async fn test_repo_error_handling() {
     );
 
     // DELETE: Non-existent repo (with force)
-    let delete_result = delete_repo(&api_client, "nonexist", true).await;
+    let delete_result = delete_repo(&api_client, "nonexist", true, false, false).await;
     assert!(
         delete_result.is_err(),
         "Should return error for non-existent repo"
@@ -408,11 +412,64 @@ async fn test_repo_error_handling() {
     );
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_delete_linked_repo_requires_unlink() {
+    use crate::cli::commands::project::{CreateProjectRequest, create_project};
+
+    let (url, _handle) = spawn_test_server().await;
+    let api_client = ApiClient::new(Some(url));
+
+    let output = create_project(
+        &api_client,
+        CreateProjectRequest {
+            title: "Linked".to_string(),
+            description: None,
+            tags: None,
+            external_refs: None,
+            repo_ids: None,
+        },
+    )
+    .await
+    .unwrap();
+    let project_id = output
+        .split('(')
+        .nth(1)
+        .and_then(|s| s.split(')').next())
+        .unwrap()
+        .to_string();
+
+    let output = create_repo(
+        &api_client,
+        CreateRepoRequest {
+            remote: "https://github.com/acme/linked".to_string(),
+            path: None,
+            tags: vec![],
+            project_ids: vec![project_id],
+        },
+    )
+    .await
+    .unwrap();
+    let repo_id = output
+        .split('(')
+        .nth(1)
+        .and_then(|s| s.split(')').next())
+        .unwrap();
+
+    // --force alone only confirms the delete; the linked repo is kept
+    let refused = delete_repo(&api_client, repo_id, true, false, false).await;
+    assert!(refused.is_err(), "Linked repo should need --unlink");
+    assert!(get_repo(&api_client, repo_id, "json").await.is_ok());
+
+    let deleted = delete_repo(&api_client, repo_id, true, true, false).await;
+    assert!(deleted.is_ok(), "Should delete with --unlink");
+    assert!(get_repo(&api_client, repo_id, "json").await.is_err());
+}
+
 #[tokio::test]
 async fn test_delete_repo_force_flag_validation() {
     // Test the --force flag validation (pure logic, no HTTP needed)
     let api_client = ApiClient::new(None);
-    let result = delete_repo(&api_client, "test-id", false).await;
+    let result = delete_repo(&api_client, "test-id", false, false, false).await;
 
     assert!(result.is_err(), "Should require --force flag");
     let error_msg = result.unwrap_err().to_string();
@@ -566,7 +623,7 @@ async fn test_repo_display_formats_and_filters() {
     .await;
     assert!(query_result.is_ok());
     let parsed: serde_json::Value = serde_json::from_str(&query_result.unwrap()).unwrap();
-    let repos = parsed.as_array().unwrap();
+    let repos = parsed["items"].as_array().unwrap();
     assert_eq!(repos.len(), 1, "Should find 1 repo matching 'prometheus'");
     assert!(repos[0]["remote"].as_str().unwrap().contains("prometheus"));
 
@@ -583,7 +640,7 @@ async fn test_repo_display_formats_and_filters() {
     assert!(project_filter_result.is_ok());
     let parsed_proj: serde_json::Value =
         serde_json::from_str(&project_filter_result.unwrap()).unwrap();
-    let repos_proj = parsed_proj.as_array().unwrap();
+    let repos_proj = parsed_proj["items"].as_array().unwrap();
     assert_eq!(repos_proj.len(), 2, "Should find 2 repos linked to project");
 
     // Test 6: Tags filter
@@ -598,7 +655,7 @@ async fn test_repo_display_formats_and_filters() {
     .await;
     assert!(tags_result.is_ok());
     let parsed_tags: serde_json::Value = serde_json::from_str(&tags_result.unwrap()).unwrap();
-    let repos_tags = parsed_tags.as_array().unwrap();
+    let repos_tags = parsed_tags["items"].as_array().unwrap();
     assert_eq!(
         repos_tags.len(),
         2,
@@ -617,7 +674,7 @@ async fn test_repo_display_formats_and_filters() {
     .await
     .unwrap();
     let repo3_parsed: serde_json::Value = serde_json::from_str(&repo3_list).unwrap();
-    let repo3_id = repo3_parsed[0]["id"].as_str().unwrap();
+    let repo3_id = repo3_parsed["items"][0]["id"].as_str().unwrap();
 
     let detail3_result = get_repo(&api_client, repo3_id, "table").await;
     assert!(detail3_result.is_ok());
@@ -635,3 +692,178 @@ async fn test_repo_display_formats_and_filters() {
         "Detail should show Tags when present"
     );
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_list_repos_json_empty_result() {
+    let (url, _handle) = spawn_test_server().await;
+    let api_client = ApiClient::new(Some(url));
+
+    let json = list_repos(
+        &api_client,
+        None,
+        None,
+        Some("no-such-tag"),
+        PageParams::default(),
+        "json",
+    )
+    .await
+    .unwrap();
+    let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed["items"], serde_json::json!([]));
+    assert_eq!(parsed["total"], 0);
+    let page: Paginated<Repo> = serde_json::from_str(&json).unwrap();
+    assert!(page.items.is_empty());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_clone_repo_clones_then_fast_forwards() {
+    use mockall::predicate::eq;
+    use std::os::unix::process::ExitStatusExt;
+    use std::process::{ExitStatus, Output};
+
+    let (url, _handle) = spawn_test_server().await;
+    let api_client = ApiClient::new(Some(url));
+    let checkouts = TempDir::new().unwrap();
+    let dest = checkouts.path().join("nested").join("widgets");
+
+    let output = create_repo(
+        &api_client,
+        CreateRepoRequest {
+            remote: "https://github.com/acme/widgets".to_string(),
+            path: Some(dest.to_string_lossy().into_owned()),
+            tags: vec![],
+            project_ids: vec![],
+        },
+    )
+    .await
+    .unwrap();
+    let repo_id = output
+        .split('(')
+        .nth(1)
+        .and_then(|s| s.split(')').next())
+        .expect("Failed to extract repo ID");
+
+    let ok = || Output {
+        status: ExitStatus::from_raw(0),
+        stdout: vec![],
+        stderr: vec![],
+    };
+
+    // Missing path: clone into it
+    let mut git = MockGitOps::new();
+    git.expect_clone_repo()
+        .with(eq("https://github.com/acme/widgets"), eq(dest.clone()))
+        .times(1)
+        .returning(move |_, _| Ok(ok()));
+    git.expect_pull_ff_only().never();
+    let output = clone_repo(&api_client, &git, repo_id).await.unwrap();
+    assert!(output.contains("Cloned repository"));
+    assert!(output.contains(&dest.display().to_string()));
+    assert!(dest.parent().unwrap().is_dir(), "Parent should be created");
+
+    // Existing checkout: fast-forward instead of cloning
+    std::fs::create_dir_all(dest.join(".git")).unwrap();
+    let mut git = MockGitOps::new();
+    git.expect_clone_repo().never();
+    git.expect_pull_ff_only()
+        .with(eq(dest.clone()))
+        .times(1)
+        .returning(move |_| Ok(ok()));
+    let output = clone_repo(&api_client, &git, repo_id).await.unwrap();
+    assert!(output.contains("Updated repository"));
+
+    // Path stays as configured
+    let fetched: Repo =
+        serde_json::from_str(&get_repo(&api_client, repo_id, "json").await.unwrap()).unwrap();
+    assert_eq!(fetched.path, Some(dest.to_string_lossy().into_owned()));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+#[serial]
+async fn test_clone_repo_without_path_uses_data_dir() {
+    use crate::sync::{clear_base_path, get_repo_clone_dir, set_base_path};
+    use std::os::unix::process::ExitStatusExt;
+    use std::path::PathBuf;
+    use std::process::{ExitStatus, Output};
+    use std::sync::Mutex;
+
+    let data_dir = TempDir::new().unwrap();
+    set_base_path(data_dir.path().to_path_buf());
+
+    let (url, _handle) = spawn_test_server().await;
+    let api_client = ApiClient::new(Some(url));
+
+    let output = create_repo(
+        &api_client,
+        CreateRepoRequest {
+            remote: "https://github.com/acme/gadgets".to_string(),
+            path: None,
+            tags: vec![],
+            project_ids: vec![],
+        },
+    )
+    .await
+    .unwrap();
+    let repo_id = output
+        .split('(')
+        .nth(1)
+        .and_then(|s| s.split(')').next())
+        .expect("Failed to extract repo ID");
+
+    let cloned_into = Arc::new(Mutex::new(None::<PathBuf>));
+    let mut git = MockGitOps::new();
+    let recorded = cloned_into.clone();
+    git.expect_clone_repo().times(1).returning(move |_, dest| {
+        *recorded.lock().unwrap() = Some(dest.to_path_buf());
+        Ok(Output {
+            status: ExitStatus::from_raw(0),
+            stdout: vec![],
+            stderr: vec![],
+        })
+    });
+    git.expect_pull_ff_only().never();
+    let output = clone_repo(&api_client, &git, repo_id).await.unwrap();
+    assert!(output.contains("Cloned repository"));
+
+    let expected = get_repo_clone_dir(repo_id);
+    assert!(expected.starts_with(data_dir.path()));
+    assert_eq!(cloned_into.lock().unwrap().as_ref(), Some(&expected));
+
+    // The clone location is saved as the repo's path
+    let fetched: Repo =
+        serde_json::from_str(&get_repo(&api_client, repo_id, "json").await.unwrap()).unwrap();
+    assert_eq!(fetched.path, Some(expected.to_string_lossy().into_owned()));
+
+    clear_base_path();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_clone_repo_refuses_non_empty_directory() {
+    let (url, _handle) = spawn_test_server().await;
+    let api_client = ApiClient::new(Some(url));
+    let dest = TempDir::new().unwrap();
+    std::fs::write(dest.path().join("README.md"), "not a checkout").unwrap();
+
+    let output = create_repo(
+        &api_client,
+        CreateRepoRequest {
+            remote: "https://github.com/acme/widgets".to_string(),
+            path: Some(dest.path().to_string_lossy().into_owned()),
+            tags: vec![],
+            project_ids: vec![],
+        },
+    )
+    .await
+    .unwrap();
+    let repo_id = output
+        .split('(')
+        .nth(1)
+        .and_then(|s| s.split(')').next())
+        .expect("Failed to extract repo ID");
+
+    let mut git = MockGitOps::new();
+    git.expect_clone_repo().never();
+    git.expect_pull_ff_only().never();
+    let err = clone_repo(&api_client, &git, repo_id).await.unwrap_err();
+    assert!(err.to_string().contains("not empty"));
+}
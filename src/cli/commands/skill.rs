@@ -1,7 +1,8 @@
 use crate::cli::api_client::ApiClient;
-use crate::cli::commands::PageParams;
+use crate::cli::commands::format::{CsvRecord, join_list, to_csv};
+use crate::cli::commands::{PageParams, Paginated, with_truncation_hint};
 use crate::cli::error::{CliError, CliResult};
-use crate::cli::utils::{apply_table_style, format_tags, truncate_with_ellipsis};
+use crate::cli::utils::{apply_table_style, format_tags, truncate_display};
 use serde::{Deserialize, Serialize};
 use tabled::{Table, Tabled};
 
@@ -20,13 +21,29 @@ pub struct Skill {
     pub updated_at: String,
 }
 
-#[derive(Debug, Deserialize)]
-#[allow(dead_code)] // total, limit, offset are part of API contract but not used in CLI
-struct SkillListResponse {
-    items: Vec<Skill>,
-    total: usize,
-    limit: usize,
-    offset: usize,
+/// Content is left out of CSV rows; use `--format json` for full skills.
+impl CsvRecord for Skill {
+    const HEADER: &'static [&'static str] = &[
+        "id",
+        "name",
+        "description",
+        "tags",
+        "project_ids",
+        "created_at",
+        "updated_at",
+    ];
+
+    fn record(&self) -> Vec<String> {
+        vec![
+            self.id.clone(),
+            self.name.clone(),
+            self.description.clone(),
+            join_list(&self.tags),
+            join_list(&self.project_ids),
+            self.created_at.clone(),
+            self.updated_at.clone(),
+        ]
+    }
 }
 
 #[derive(Tabled)]
@@ -37,14 +54,17 @@ pub(crate) struct SkillDisplay {
     pub(crate) name: String,
     #[tabled(rename = "Tags")]
     pub(crate) tags: String,
+    #[tabled(rename = "Attachments")]
+    pub(crate) attachments: usize,
 }
 
 impl From<&Skill> for SkillDisplay {
     fn from(skill: &Skill) -> Self {
         Self {
             id: skill.id.clone(),
-            name: truncate_with_ellipsis(&skill.name, 50),
+            name: truncate_display(&skill.name, 50),
             tags: format_tags(Some(&skill.tags)),
+            attachments: skill.scripts.len() + skill.references.len() + skill.assets.len(),
         }
     }
 }
@@ -74,7 +94,7 @@ pub async fn list_skills(
     if let Some(t) = filter.tags {
         request = request.query(&[("tags", t)]);
     }
-    if let Some(l) = filter.page.limit {
+    if let Some(l) = filter.page.effective_limit() {
         request = request.query(&[("limit", l.to_string().as_str())]);
     }
     if let Some(o) = filter.page.offset {
@@ -87,7 +107,7 @@ pub async fn list_skills(
         request = request.query(&[("order", ord)]);
     }
 
-    let response: SkillListResponse = request
+    let response: Paginated<Skill> = request
         .send()
         .await?
         .error_for_status()?
@@ -97,14 +117,21 @@ pub async fn list_skills(
             message: format!("Failed to parse response: {}", e),
         })?;
 
-    if format == "json" {
-        Ok(serde_json::to_string_pretty(&response.items)?)
-    } else {
-        let display: Vec<SkillDisplay> = response.items.iter().map(SkillDisplay::from).collect();
-        let mut table = Table::new(display);
-        apply_table_style(&mut table);
-        Ok(format!("{}", table))
-    }
+    let hint = filter
+        .page
+        .truncation_hint(response.items.len(), response.total);
+    let output = match format {
+        "json" => serde_json::to_string_pretty(&response)?,
+        "csv" => to_csv(&response.items),
+        _ => {
+            let display: Vec<SkillDisplay> =
+                response.items.iter().map(SkillDisplay::from).collect();
+            let mut table = Table::new(display);
+            apply_table_style(&mut table);
+            format!("{}", table)
+        }
+    };
+    Ok(with_truncation_hint(output, format, hint))
 }
 
 /// Get a skill by ID
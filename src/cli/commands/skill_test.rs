@@ -1,8 +1,8 @@
 use crate::a6s::store::surrealdb;
 use crate::api::{AppState, routes};
 use crate::cli::api_client::ApiClient;
-use crate::cli::commands::PageParams;
 use crate::cli::commands::skill::*;
+use crate::cli::commands::{PageParams, Paginated};
 use crate::db::{Database, SqliteDatabase};
 use crate::sync::MockGitOps;
 use serde_json::json;
@@ -171,6 +171,7 @@ async fn test_list_skills_empty() {
             offset: None,
             sort: None,
             order: None,
+            page_size: None,
         },
     };
 
@@ -178,7 +179,9 @@ async fn test_list_skills_empty() {
         .await
         .expect("Should list empty skills");
 
-    let skills: Vec<Skill> = serde_json::from_str(&result).unwrap();
+    let skills = serde_json::from_str::<Paginated<Skill>>(&result)
+        .unwrap()
+        .items;
     assert_eq!(skills.len(), 0, "Should have no skills");
 }
 
@@ -231,12 +234,15 @@ async fn test_list_skills_with_filters() {
             offset: None,
             sort: None,
             order: None,
+            page_size: None,
         },
     };
     let result = list_skills(&api_client, filter, "json")
         .await
         .expect("Should list all skills");
-    let all_skills: Vec<Skill> = serde_json::from_str(&result).unwrap();
+    let all_skills = serde_json::from_str::<Paginated<Skill>>(&result)
+        .unwrap()
+        .items;
     assert_eq!(all_skills.len(), 3, "Should have 3 skills");
 
     // Filter by project_id
@@ -249,12 +255,15 @@ async fn test_list_skills_with_filters() {
             offset: None,
             sort: None,
             order: None,
+            page_size: None,
         },
     };
     let result = list_skills(&api_client, filter, "json")
         .await
         .expect("Should list filtered skills");
-    let filtered: Vec<Skill> = serde_json::from_str(&result).unwrap();
+    let filtered = serde_json::from_str::<Paginated<Skill>>(&result)
+        .unwrap()
+        .items;
     assert_eq!(filtered.len(), 2, "Should have 2 skills in project");
 
     // Filter by tags
@@ -267,12 +276,15 @@ async fn test_list_skills_with_filters() {
             offset: None,
             sort: None,
             order: None,
+            page_size: None,
         },
     };
     let result = list_skills(&api_client, filter, "json")
         .await
         .expect("Should list filtered skills");
-    let tagged: Vec<Skill> = serde_json::from_str(&result).unwrap();
+    let tagged = serde_json::from_str::<Paginated<Skill>>(&result)
+        .unwrap()
+        .items;
     assert_eq!(tagged.len(), 1, "Should have 1 skill with rust tag");
     assert_eq!(tagged[0].name, "rust");
 }
@@ -307,12 +319,15 @@ async fn test_list_skills_pagination_and_sorting() {
             offset: None,
             sort: None,
             order: None,
+            page_size: None,
         },
     };
     let result = list_skills(&api_client, filter, "json")
         .await
         .expect("Should list with limit");
-    let limited: Vec<Skill> = serde_json::from_str(&result).unwrap();
+    let limited = serde_json::from_str::<Paginated<Skill>>(&result)
+        .unwrap()
+        .items;
     assert_eq!(limited.len(), 2, "Should limit to 2 skills");
 
     // Test offset
@@ -325,12 +340,15 @@ async fn test_list_skills_pagination_and_sorting() {
             offset: Some(2),
             sort: None,
             order: None,
+            page_size: None,
         },
     };
     let result = list_skills(&api_client, filter, "json")
         .await
         .expect("Should list with offset");
-    let offset_skills: Vec<Skill> = serde_json::from_str(&result).unwrap();
+    let offset_skills = serde_json::from_str::<Paginated<Skill>>(&result)
+        .unwrap()
+        .items;
     assert_eq!(offset_skills.len(), 2, "Should have 2 skills after offset");
 
     // Test sorting by name
@@ -343,12 +361,15 @@ async fn test_list_skills_pagination_and_sorting() {
             offset: None,
             sort: Some("name"),
             order: Some("asc"),
+            page_size: None,
         },
     };
     let result = list_skills(&api_client, filter, "json")
         .await
         .expect("Should list sorted");
-    let sorted: Vec<Skill> = serde_json::from_str(&result).unwrap();
+    let sorted = serde_json::from_str::<Paginated<Skill>>(&result)
+        .unwrap()
+        .items;
     assert_eq!(sorted[0].name, "alpha", "Should be sorted alphabetically");
     assert_eq!(sorted[3].name, "gamma", "Should be sorted alphabetically");
 }
@@ -389,6 +410,7 @@ async fn test_skill_table_output() {
             offset: None,
             sort: None,
             order: None,
+            page_size: None,
         },
     };
     let result = list_skills(&api_client, filter, "table")
@@ -401,6 +423,84 @@ async fn test_skill_table_output() {
     assert!(result.contains("Tags"), "Table should have Tags header");
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_list_skills_project_filter_with_attachment_count() {
+    let (url, project_id, _handle) = spawn_test_server().await;
+    let api_client = ApiClient::new(Some(url.clone()));
+
+    // Skill with two attachments, linked to the project
+    let skill_dir = TempDir::new().unwrap();
+    std::fs::write(
+        skill_dir.path().join("SKILL.md"),
+        "---\nname: deployer\ndescription: Deploys things\n---\n\nRun the script",
+    )
+    .unwrap();
+    std::fs::create_dir_all(skill_dir.path().join("scripts")).unwrap();
+    std::fs::write(skill_dir.path().join("scripts/deploy.sh"), "#!/bin/sh\n").unwrap();
+    std::fs::write(skill_dir.path().join("guide.md"), "# Guide").unwrap();
+    import_skill(
+        &api_client,
+        skill_dir.path().to_str().unwrap(),
+        None,
+        Some(vec![project_id.clone()]),
+        None,
+        false,
+    )
+    .await
+    .expect("Failed to import skill with attachments");
+
+    // Skill outside the project
+    import_skill(
+        &api_client,
+        "tests/fixtures/skills/docker",
+        None,
+        None,
+        None,
+        false,
+    )
+    .await
+    .expect("Failed to import skill");
+
+    let filter = ListSkillsFilter {
+        query: None,
+        project_id: Some(&project_id),
+        tags: None,
+        page: PageParams::default(),
+    };
+    let table = list_skills(&api_client, filter, "table")
+        .await
+        .expect("Should list in table format");
+    assert!(
+        table.contains("Attachments"),
+        "Table should have Attachments header"
+    );
+    assert!(table.contains("deployer"));
+    assert!(
+        !table.contains("docker"),
+        "Skill outside project should be excluded"
+    );
+    let row = table.lines().find(|l| l.contains("deployer")).unwrap();
+    assert!(
+        row.contains(" 2 "),
+        "Row should show two attachments: {}",
+        row
+    );
+
+    let filter = ListSkillsFilter {
+        query: None,
+        project_id: Some(&project_id),
+        tags: None,
+        page: PageParams::default(),
+    };
+    let json = list_skills(&api_client, filter, "json").await.unwrap();
+    let skills = serde_json::from_str::<Paginated<Skill>>(&json)
+        .unwrap()
+        .items;
+    assert_eq!(skills.len(), 1);
+    let attachments = skills[0].scripts.len() + skills[0].references.len() + skills[0].assets.len();
+    assert_eq!(attachments, 2);
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn test_update_skill_metadata() {
     let (url, project_id, _handle) = spawn_test_server().await;
@@ -528,12 +628,15 @@ async fn test_list_skills_with_query_search() {
             offset: None,
             sort: None,
             order: None,
+            page_size: None,
         },
     };
     let result = list_skills(&api_client, filter, "json")
         .await
         .expect("Should search skills");
-    let searched: Vec<Skill> = serde_json::from_str(&result).unwrap();
+    let searched = serde_json::from_str::<Paginated<Skill>>(&result)
+        .unwrap()
+        .items;
     assert_eq!(searched.len(), 1, "Should find 1 skill matching 'systems'");
     assert_eq!(searched[0].name, "rust");
 
@@ -547,12 +650,15 @@ async fn test_list_skills_with_query_search() {
             offset: None,
             sort: None,
             order: None,
+            page_size: None,
         },
     };
     let result = list_skills(&api_client, filter, "json")
         .await
         .expect("Should search skills");
-    let searched: Vec<Skill> = serde_json::from_str(&result).unwrap();
+    let searched = serde_json::from_str::<Paginated<Skill>>(&result)
+        .unwrap()
+        .items;
     assert_eq!(
         searched.len(),
         1,
@@ -570,12 +676,15 @@ async fn test_list_skills_with_query_search() {
             offset: None,
             sort: None,
             order: None,
+            page_size: None,
         },
     };
     let result = list_skills(&api_client, filter, "json")
         .await
         .expect("Should search with filters");
-    let searched: Vec<Skill> = serde_json::from_str(&result).unwrap();
+    let searched = serde_json::from_str::<Paginated<Skill>>(&result)
+        .unwrap()
+        .items;
     // Should only find rust (has "systems programming" + rust tag)
     assert!(
         searched.len() <= 1,
@@ -779,3 +888,22 @@ async fn test_import_auto_enables_skill() {
 
     // TempDir will be automatically cleaned up when it goes out of scope
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_list_skills_json_empty_result() {
+    let (url, _project_id, _handle) = spawn_test_server().await;
+    let api_client = ApiClient::new(Some(url));
+
+    let filter = ListSkillsFilter {
+        query: None,
+        project_id: None,
+        tags: Some("no-such-tag"),
+        page: PageParams::default(),
+    };
+    let json = list_skills(&api_client, filter, "json").await.unwrap();
+    let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed["items"], serde_json::json!([]));
+    assert_eq!(parsed["total"], 0);
+    let page: Paginated<Skill> = serde_json::from_str(&json).unwrap();
+    assert!(page.items.is_empty());
+}
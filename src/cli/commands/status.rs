@@ -0,0 +1,189 @@
+//! Status dashboard command.
+//!
+//! Aggregates entity counts and sync state into a single overview.
+
+use serde::{Deserialize, Serialize};
+use tabled::builder::Builder;
+
+use crate::cli::api_client::ApiClient;
+use crate::cli::error::CliResult;
+
+/// Page size used when walking task lists to count open tasks
+const TASK_LIST_PAGE_SIZE: usize = 100;
+
+/// Only the total of a paginated list response is needed for counting
+#[derive(Debug, Deserialize)]
+struct ListTotal {
+    total: usize,
+}
+
+#[derive(Debug, Deserialize)]
+struct TaskListPage {
+    items: Vec<TaskListId>,
+    total: usize,
+}
+
+#[derive(Debug, Deserialize)]
+struct TaskListId {
+    id: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct TaskListStats {
+    total: usize,
+    done: usize,
+    cancelled: usize,
+}
+
+#[derive(Debug, Deserialize)]
+struct SyncStatusResponse {
+    data: Option<serde_json::Value>,
+}
+
+/// Sync state shown on the dashboard
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SyncSummary {
+    pub initialized: bool,
+    pub remote_url: Option<String>,
+    /// Whether the sync working tree is clean (None when not initialized)
+    pub clean: Option<bool>,
+}
+
+/// Aggregated counts shown by `c5t status`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct StatusSummary {
+    pub projects: usize,
+    pub repos: usize,
+    pub task_lists_active: usize,
+    pub task_lists_archived: usize,
+    /// Tasks that are neither done nor cancelled, across active task lists
+    pub open_tasks: usize,
+    pub notes: usize,
+    pub sync: SyncSummary,
+}
+
+/// Count items of a list endpoint without fetching them
+async fn count(api_client: &ApiClient, path: &str, params: &[(&str, &str)]) -> CliResult<usize> {
+    let response = api_client
+        .get(path)
+        .query(&[("limit", "1")])
+        .query(params)
+        .send()
+        .await?;
+    let page: ListTotal = ApiClient::handle_response(response).await?;
+    Ok(page.total)
+}
+
+/// Sum open tasks over all active task lists using their stats
+async fn count_open_tasks(api_client: &ApiClient) -> CliResult<usize> {
+    let mut open = 0;
+    let mut offset = 0;
+
+    loop {
+        let response = api_client
+            .get("/api/v1/task-lists")
+            .query(&[("status", "active")])
+            .query(&[("limit", TASK_LIST_PAGE_SIZE), ("offset", offset)])
+            .send()
+            .await?;
+        let page: TaskListPage = ApiClient::handle_response(response).await?;
+
+        for list in &page.items {
+            let response = api_client
+                .get(&format!("/api/v1/task-lists/{}/stats", list.id))
+                .send()
+                .await?;
+            let stats: TaskListStats = ApiClient::handle_response(response).await?;
+            open += stats.total - stats.done - stats.cancelled;
+        }
+
+        offset += page.items.len();
+        if page.items.is_empty() || offset >= page.total {
+            break;
+        }
+    }
+
+    Ok(open)
+}
+
+async fn sync_summary(api_client: &ApiClient) -> CliResult<SyncSummary> {
+    let response = api_client.get("/api/v1/sync/status").send().await?;
+    let status: SyncStatusResponse = ApiClient::handle_response(response).await?;
+    let data = status.data.unwrap_or_default();
+
+    Ok(SyncSummary {
+        initialized: data
+            .get("initialized")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false),
+        remote_url: data
+            .get("remote_url")
+            .and_then(|v| v.as_str())
+            .map(str::to_string),
+        clean: data
+            .get("git")
+            .and_then(|git| git.get("clean"))
+            .and_then(|v| v.as_bool()),
+    })
+}
+
+/// Fetch all dashboard figures from the API
+pub async fn fetch_summary(api_client: &ApiClient) -> CliResult<StatusSummary> {
+    Ok(StatusSummary {
+        projects: count(api_client, "/api/v1/projects", &[]).await?,
+        repos: count(api_client, "/api/v1/repos", &[]).await?,
+        task_lists_active: count(api_client, "/api/v1/task-lists", &[("status", "active")]).await?,
+        task_lists_archived: count(api_client, "/api/v1/task-lists", &[("status", "archived")])
+            .await?,
+        open_tasks: count_open_tasks(api_client).await?,
+        notes: count(api_client, "/api/v1/notes", &[]).await?,
+        sync: sync_summary(api_client).await?,
+    })
+}
+
+/// Render the dashboard as a compact table
+pub fn format_summary(summary: &StatusSummary) -> String {
+    let sync = if !summary.sync.initialized {
+        "not initialized".to_string()
+    } else {
+        let state = match summary.sync.clean {
+            Some(true) => "clean",
+            Some(false) => "uncommitted changes",
+            None => "unknown",
+        };
+        match &summary.sync.remote_url {
+            Some(remote) => format!("{} ({})", state, remote),
+            None => state.to_string(),
+        }
+    };
+
+    let mut builder = Builder::default();
+    builder.push_record(["Item", "Count"]);
+    builder.push_record(["Projects", &summary.projects.to_string()]);
+    builder.push_record(["Repos", &summary.repos.to_string()]);
+    builder.push_record([
+        "Task lists",
+        &format!(
+            "{} active, {} archived",
+            summary.task_lists_active, summary.task_lists_archived
+        ),
+    ]);
+    builder.push_record(["Open tasks", &summary.open_tasks.to_string()]);
+    builder.push_record(["Notes", &summary.notes.to_string()]);
+    builder.push_record(["Sync", &sync]);
+
+    let mut table = builder.build();
+    crate::cli::utils::apply_table_style(&mut table);
+    table.to_string()
+}
+
+/// Show the status dashboard
+pub async fn status(api_client: &ApiClient, json: bool) -> CliResult<String> {
+    let summary = fetch_summary(api_client).await?;
+
+    if json {
+        Ok(serde_json::to_string_pretty(&summary)?)
+    } else {
+        Ok(format_summary(&summary))
+    }
+}
@@ -0,0 +1,187 @@
+use crate::a6s::store::surrealdb;
+use crate::api::{AppState, routes};
+use crate::cli::api_client::ApiClient;
+use crate::cli::commands::status::*;
+use crate::db::{
+    Database, Note, NoteRepository, Project, ProjectRepository, SqliteDatabase, Task, TaskList,
+    TaskListRepository, TaskListStatus, TaskRepository, TaskStatus,
+};
+use crate::sync::MockGitOps;
+use std::sync::Arc;
+use tempfile::TempDir;
+use tokio::net::TcpListener;
+
+fn project(id: &str) -> Project {
+    Project {
+        id: id.to_string(),
+        title: format!("Project {}", id),
+        description: None,
+        tags: vec![],
+        external_refs: vec![],
+        repo_ids: vec![],
+        task_list_ids: vec![],
+        note_ids: vec![],
+        created_at: None,
+        updated_at: None,
+    }
+}
+
+fn task_list(id: &str, status: TaskListStatus) -> TaskList {
+    TaskList {
+        id: id.to_string(),
+        title: format!("List {}", id),
+        description: None,
+        notes: None,
+        tags: vec![],
+        external_refs: vec![],
+        status,
+        repo_ids: vec![],
+        project_id: "proj0001".to_string(),
+        created_at: None,
+        updated_at: None,
+        archived_at: None,
+    }
+}
+
+fn task(list_id: &str, title: &str) -> Task {
+    Task {
+        id: String::new(),
+        list_id: list_id.to_string(),
+        parent_id: None,
+        title: title.to_string(),
+        description: None,
+        status: TaskStatus::Backlog,
+        priority: None,
+        tags: vec![],
+        external_refs: vec![],
+        created_at: None,
+        updated_at: None,
+    }
+}
+
+/// Spawn a test server seeded with a known set of entities
+async fn spawn_seeded_server() -> (String, TempDir, tokio::task::JoinHandle<()>) {
+    let db = SqliteDatabase::in_memory()
+        .await
+        .expect("Failed to create test database");
+    db.migrate().expect("Failed to run migrations");
+
+    db.projects().create(&project("proj0001")).await.unwrap();
+    db.projects().create(&project("proj0002")).await.unwrap();
+
+    db.task_lists()
+        .create(&task_list("list0001", TaskListStatus::Active))
+        .await
+        .unwrap();
+    db.task_lists()
+        .create(&task_list("list0002", TaskListStatus::Archived))
+        .await
+        .unwrap();
+
+    db.tasks()
+        .create(&task("list0001", "Open one"))
+        .await
+        .unwrap();
+    db.tasks()
+        .create(&task("list0001", "Open two"))
+        .await
+        .unwrap();
+    let mut finished = db
+        .tasks()
+        .create(&task("list0001", "Finished"))
+        .await
+        .unwrap();
+    finished.status = TaskStatus::Done;
+    db.tasks().update(&finished).await.unwrap();
+    // Tasks in archived lists are not counted as open
+    db.tasks()
+        .create(&task("list0002", "Archived work"))
+        .await
+        .unwrap();
+
+    db.notes()
+        .create(&Note {
+            id: String::new(),
+            title: "A note".to_string(),
+            content: "Content".to_string(),
+            tags: vec![],
+            parent_id: None,
+            idx: None,
+            repo_ids: vec![],
+            project_ids: vec![],
+            subnote_count: None,
+            created_at: None,
+            updated_at: None,
+        })
+        .await
+        .unwrap();
+
+    let temp_dir = TempDir::new().unwrap();
+    let analysis_db = Arc::new(surrealdb::init_db(None).await.unwrap());
+    let state = AppState::new(
+        db,
+        crate::sync::SyncManager::with_sync_dir(MockGitOps::new(), temp_dir.path().join("sync")),
+        crate::api::notifier::ChangeNotifier::new(),
+        temp_dir.path().join("skills"),
+        analysis_db,
+        crate::a6s::tracker::AnalysisTracker::new(crate::api::notifier::ChangeNotifier::new()),
+    );
+    let app = routes::create_router(state, false);
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let handle = tokio::spawn(async move {
+        axum::serve(listener, app).await.unwrap();
+    });
+
+    tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+
+    (url, temp_dir, handle)
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_status_aggregates_counts() {
+    let (url, _temp_dir, _handle) = spawn_seeded_server().await;
+    let api_client = ApiClient::new(Some(url));
+
+    let summary = fetch_summary(&api_client)
+        .await
+        .expect("status should load");
+
+    assert_eq!(
+        summary,
+        StatusSummary {
+            projects: 2,
+            repos: 0,
+            task_lists_active: 1,
+            task_lists_archived: 1,
+            open_tasks: 2,
+            notes: 1,
+            sync: SyncSummary {
+                initialized: false,
+                remote_url: None,
+                clean: None,
+            },
+        }
+    );
+
+    let table = status(&api_client, false).await.unwrap();
+    assert!(table.contains("1 active, 1 archived"));
+    assert!(table.contains("not initialized"));
+
+    let json: serde_json::Value =
+        serde_json::from_str(&status(&api_client, true).await.unwrap()).unwrap();
+    assert_eq!(json["open_tasks"], 2);
+    assert_eq!(json["sync"]["initialized"], false);
+}
+
+#[tokio::test]
+async fn test_status_connection_error() {
+    let api_client = ApiClient::new(Some("http://localhost:9999".to_string()));
+
+    let result = status(&api_client, false).await;
+    assert!(
+        result.is_err(),
+        "Should return error when API is unavailable"
+    );
+}
@@ -2,13 +2,17 @@
 
 use crate::cli::api_client::ApiClient;
 use crate::cli::error::{CliError, CliResult};
+use crate::cli::utils::truncate_display;
+use crate::sync::{ExportSummary, ImportSummary};
 use serde::{Deserialize, Serialize};
+use std::io::IsTerminal;
 use tabled::{Table, Tabled, settings::Style};
 
 /// Request to initialize sync
 #[derive(Debug, Serialize)]
 struct InitSyncRequest {
     remote_url: Option<String>,
+    project: Option<String>,
 }
 
 /// Request to export sync
@@ -16,12 +20,26 @@ struct InitSyncRequest {
 struct ExportSyncRequest {
     message: Option<String>,
     remote: bool,
+    only: Option<String>,
+    incremental: bool,
+    project: Option<String>,
 }
 
 /// Request to import sync
 #[derive(Debug, Serialize)]
 struct ImportSyncRequest {
     remote: bool,
+    only: Option<String>,
+    preview: bool,
+    project: Option<String>,
+    strategy: Option<String>,
+}
+
+/// Request to diff sync files against the database
+#[derive(Debug, Serialize)]
+struct DiffSyncRequest {
+    remote: bool,
+    project: Option<String>,
 }
 
 /// Response from sync operations
@@ -31,9 +49,16 @@ struct SyncResponse {
     data: Option<serde_json::Value>,
 }
 
-/// Initialize sync repository
-pub async fn init(api_client: &ApiClient, remote_url: Option<String>) -> CliResult<String> {
-    let req = InitSyncRequest { remote_url };
+/// Initialize sync repository, or a project's own sync workspace
+pub async fn init(
+    api_client: &ApiClient,
+    remote_url: Option<String>,
+    project: Option<String>,
+) -> CliResult<String> {
+    let req = InitSyncRequest {
+        remote_url,
+        project,
+    };
 
     let response = api_client
         .post("/api/v1/sync/init")
@@ -66,6 +91,9 @@ pub async fn init(api_client: &ApiClient, remote_url: Option<String>) -> CliResu
     output.push_str(&format!("{} {}\n\n", icon, sync_response.message));
 
     if let Some(data) = &sync_response.data {
+        if let Some(project) = data.get("project").and_then(|v| v.as_str()) {
+            output.push_str(&format!("Project:        {}\n", project));
+        }
         if let Some(sync_dir) = data.get("sync_dir").and_then(|v| v.as_str()) {
             output.push_str(&format!("Sync directory: {}\n", sync_dir));
         }
@@ -85,13 +113,23 @@ struct SyncCountRow {
     count: String,
 }
 
-/// Export database to sync
+/// Export database to sync, optionally limited to one entity type or one project
 pub async fn export(
     api_client: &ApiClient,
     message: Option<String>,
     remote: bool,
+    only: Option<String>,
+    incremental: bool,
+    project: Option<String>,
+    json: bool,
 ) -> CliResult<String> {
-    let req = ExportSyncRequest { message, remote };
+    let req = ExportSyncRequest {
+        message,
+        remote,
+        only,
+        incremental,
+        project,
+    };
 
     let response = api_client
         .post("/api/v1/sync/export")
@@ -117,6 +155,10 @@ pub async fn export(
                 message: e.to_string(),
             })?;
 
+    if json {
+        return export_summary_json(sync_response.data.as_ref());
+    }
+
     let mut output = String::new();
     output.push_str(&format!("✓ {}\n\n", sync_response.message));
 
@@ -198,9 +240,26 @@ pub async fn export(
     Ok(output)
 }
 
-/// Import from sync to database
-pub async fn import(api_client: &ApiClient, remote: bool) -> CliResult<String> {
-    let req = ImportSyncRequest { remote };
+/// Import from sync to database, or preview the field-level changes an import would make.
+///
+/// `strategy` decides what happens to records changed on both sides since the
+/// last sync (`skip`, `last-write-wins` or `force`; the server defaults to `skip`).
+pub async fn import(
+    api_client: &ApiClient,
+    remote: bool,
+    only: Option<String>,
+    preview: bool,
+    project: Option<String>,
+    strategy: Option<String>,
+    json: bool,
+) -> CliResult<String> {
+    let req = ImportSyncRequest {
+        remote,
+        only,
+        preview,
+        project,
+        strategy,
+    };
 
     let response = api_client
         .post("/api/v1/sync/import")
@@ -226,6 +285,17 @@ pub async fn import(api_client: &ApiClient, remote: bool) -> CliResult<String> {
                 message: e.to_string(),
             })?;
 
+    if let Some(preview) = sync_response.data.as_ref().and_then(|d| d.get("preview")) {
+        if json {
+            return Ok(serde_json::to_string_pretty(preview)?);
+        }
+        return Ok(format_import_preview(preview));
+    }
+
+    if json {
+        return import_summary_json(sync_response.data.as_ref());
+    }
+
     let mut output = String::new();
     output.push_str(&format!("✓ {}\n\n", sync_response.message));
 
@@ -304,9 +374,263 @@ pub async fn import(api_client: &ApiClient, remote: bool) -> CliResult<String> {
         output.push_str(&table.to_string());
     }
 
+    let warnings = sync_response
+        .data
+        .as_ref()
+        .and_then(|d| d.get("warnings"))
+        .and_then(|w| w.as_array())
+        .filter(|w| !w.is_empty());
+    if let Some(warnings) = warnings {
+        output.push_str("\n\nWarnings:\n");
+        for warning in warnings.iter().filter_map(|w| w.as_str()) {
+            output.push_str(&format!("  ⚠ {}\n", warning));
+        }
+    }
+
+    output.push_str(&format_conflicts(sync_response.data.as_ref()));
+
     Ok(output)
 }
 
+/// List the records an import skipped because both sides changed them, or
+/// nothing when there were none.
+pub(crate) fn format_conflicts(data: Option<&serde_json::Value>) -> String {
+    let conflicts = data
+        .and_then(|d| d.get("conflicts"))
+        .and_then(|c| c.as_array())
+        .filter(|c| !c.is_empty());
+    let Some(conflicts) = conflicts else {
+        return String::new();
+    };
+
+    let field = |v: &serde_json::Value, key: &str| {
+        v.get(key)
+            .and_then(|f| f.as_str())
+            .unwrap_or_default()
+            .to_string()
+    };
+    let mut output = format!(
+        "\n\nConflicts ({} skipped, changed here and in the sync files since the last sync):\n",
+        conflicts.len()
+    );
+    for conflict in conflicts {
+        output.push_str(&format!(
+            "  ✗ {} {}: local {}, incoming {}\n",
+            field(conflict, "entity_type"),
+            field(conflict, "id"),
+            field(conflict, "local"),
+            field(conflict, "incoming")
+        ));
+    }
+    output.push_str("Re-run with --force to take the incoming records, or --strategy last-write-wins to keep the newer side.\n");
+    output
+}
+
+/// Export response data as an [`ExportSummary`] JSON document
+pub(crate) fn export_summary_json(data: Option<&serde_json::Value>) -> CliResult<String> {
+    let summary: ExportSummary = match data.and_then(|d| d.get("exported")) {
+        Some(exported) => serde_json::from_value(exported.clone())?,
+        None => ExportSummary::default(),
+    };
+    Ok(serde_json::to_string_pretty(&summary)?)
+}
+
+/// Import response data as an [`ImportSummary`] JSON document, warnings and conflicts included
+pub(crate) fn import_summary_json(data: Option<&serde_json::Value>) -> CliResult<String> {
+    let mut summary: ImportSummary = match data.and_then(|d| d.get("imported")) {
+        Some(imported) => serde_json::from_value(imported.clone())?,
+        None => ImportSummary::default(),
+    };
+    if let Some(warnings) = data.and_then(|d| d.get("warnings")) {
+        summary.warnings = serde_json::from_value(warnings.clone())?;
+    }
+    if let Some(conflicts) = data.and_then(|d| d.get("conflicts")) {
+        summary.conflicts = serde_json::from_value(conflicts.clone())?;
+    }
+    Ok(serde_json::to_string_pretty(&summary)?)
+}
+
+/// Render an import preview: one line per new entity, and per updated entity
+/// one line for each changed field with its old and new value.
+fn format_import_preview(preview: &serde_json::Value) -> String {
+    let entries = |key: &str| {
+        preview
+            .get(key)
+            .and_then(|v| v.as_array())
+            .cloned()
+            .unwrap_or_default()
+    };
+    let field = |v: &serde_json::Value, key: &str| {
+        v.get(key)
+            .and_then(|f| f.as_str())
+            .unwrap_or_default()
+            .to_string()
+    };
+    let created = entries("created");
+    let updated = entries("updated");
+    let unchanged = preview
+        .get("unchanged")
+        .and_then(|v| v.as_u64())
+        .unwrap_or(0);
+
+    let mut output = format!(
+        "Import preview: {} to create, {} to update, {} unchanged (nothing imported)\n",
+        created.len(),
+        updated.len(),
+        unchanged
+    );
+
+    for entity in &created {
+        output.push_str(&format!(
+            "\n+ {} {}",
+            field(entity, "entity_type"),
+            field(entity, "id")
+        ));
+    }
+
+    for entity in &updated {
+        output.push_str(&format!(
+            "\n~ {} {}",
+            field(entity, "entity_type"),
+            field(entity, "id")
+        ));
+        for change in entity
+            .get("changes")
+            .and_then(|c| c.as_array())
+            .into_iter()
+            .flatten()
+        {
+            let value = |key: &str| {
+                truncate_display(
+                    &change.get(key).cloned().unwrap_or_default().to_string(),
+                    60,
+                )
+            };
+            output.push_str(&format!(
+                "\n    {}: {} -> {}",
+                field(change, "field"),
+                value("old"),
+                value("new")
+            ));
+        }
+    }
+
+    output.push('\n');
+    output
+}
+
+/// Show which records differ between the sync files and the database
+pub async fn diff(
+    api_client: &ApiClient,
+    remote: bool,
+    project: Option<String>,
+    json: bool,
+) -> CliResult<String> {
+    let req = DiffSyncRequest { remote, project };
+
+    let response = api_client
+        .post("/api/v1/sync/diff")
+        .json(&req)
+        .send()
+        .await
+        .map_err(|e| CliError::ConnectionFailed { source: e })?;
+
+    let status_code = response.status().as_u16();
+    if !response.status().is_success() {
+        let error_text = response.text().await.unwrap_or_default();
+        return Err(CliError::ApiError {
+            status: status_code,
+            message: format!("Failed to diff: {}", error_text),
+        });
+    }
+
+    let sync_response: SyncResponse =
+        response
+            .json()
+            .await
+            .map_err(|e| CliError::InvalidResponse {
+                message: e.to_string(),
+            })?;
+
+    if json {
+        return Ok(serde_json::to_string_pretty(
+            &sync_response.data.unwrap_or_default(),
+        )?);
+    }
+
+    let color = std::io::stdout().is_terminal() && std::env::var_os("NO_COLOR").is_none();
+    Ok(format_sync_diff(sync_response.data.as_ref(), color))
+}
+
+/// Render a sync diff like `git status`: per entity type, added records in
+/// green, modified in yellow and deleted in red.
+pub(crate) fn format_sync_diff(data: Option<&serde_json::Value>, color: bool) -> String {
+    let paint = |text: String, code: &str| {
+        if color {
+            format!("\x1b[{}m{}\x1b[0m", code, text)
+        } else {
+            text
+        }
+    };
+    let count = |key: &str| {
+        data.and_then(|d| d.get(key))
+            .and_then(|v| v.as_u64())
+            .unwrap_or(0)
+    };
+    let (added, modified, deleted) = (count("added"), count("modified"), count("deleted"));
+    if added + modified + deleted == 0 {
+        return "Database matches the sync files\n".to_string();
+    }
+
+    let mut output = format!(
+        "Sync diff: {} added, {} modified, {} deleted (nothing imported)\n",
+        added, modified, deleted
+    );
+    let entities = data
+        .and_then(|d| d.get("entities"))
+        .and_then(|e| e.as_array())
+        .into_iter()
+        .flatten();
+    for entity in entities {
+        let ids = |key: &str| {
+            entity
+                .get(key)
+                .and_then(|v| v.as_array())
+                .into_iter()
+                .flatten()
+                .filter_map(|id| id.as_str())
+                .map(str::to_string)
+                .collect::<Vec<_>>()
+        };
+        let changes = [
+            ("added:   ", ids("added"), "32"),
+            ("modified:", ids("modified"), "33"),
+            ("deleted: ", ids("deleted"), "31"),
+        ];
+        if changes.iter().all(|(_, ids, _)| ids.is_empty()) {
+            continue;
+        }
+
+        output.push_str(&format!(
+            "\n{}:\n",
+            entity
+                .get("entity_type")
+                .and_then(|v| v.as_str())
+                .unwrap_or_default()
+        ));
+        for (label, ids, code) in changes {
+            for id in ids {
+                output.push_str(&format!("\t{}\n", paint(format!("{} {}", label, id), code)));
+            }
+        }
+    }
+
+    output.push_str(
+        "\nAdded and modified records are applied by c5t sync import; deleted ones exist only in the database and are kept.\n",
+    );
+    output
+}
+
 /// Get sync status
 pub async fn status(api_client: &ApiClient) -> CliResult<String> {
     let response = api_client
@@ -332,7 +656,7 @@ pub async fn status(api_client: &ApiClient) -> CliResult<String> {
                 message: e.to_string(),
             })?;
 
-    Ok(format_sync_status(&sync_response))
+    Ok(format_sync_status(sync_response.data.as_ref()))
 }
 
 #[derive(Tabled)]
@@ -345,11 +669,11 @@ struct SyncStatusRow {
     sync_files: String,
 }
 
-fn format_sync_status(response: &SyncResponse) -> String {
+pub(crate) fn format_sync_status(data: Option<&serde_json::Value>) -> String {
     let mut output = String::new();
 
     // Parse the data field
-    if let Some(data) = &response.data {
+    if let Some(data) = data {
         let initialized = data
             .get("initialized")
             .and_then(|v| v.as_bool())
@@ -368,13 +692,23 @@ fn format_sync_status(response: &SyncResponse) -> String {
             && let Some(clean) = git.get("clean").and_then(|v| v.as_bool())
         {
             output.push_str(&format!(
-                "Status: {}\n\n",
+                "Status: {}\n",
                 if clean {
                     "✓ Clean"
                 } else {
                     "✗ Uncommitted changes"
                 }
             ));
+
+            let count = |key: &str| git.get(key).and_then(|v| v.as_u64()).unwrap_or(0);
+            let (ahead, behind) = (count("ahead"), count("behind"));
+            if ahead > 0 || behind > 0 {
+                output.push_str(&format!("Upstream: {} ahead, {} behind\n", ahead, behind));
+            }
+            if behind > 0 {
+                output.push_str("Run: c5t sync import --remote before exporting\n");
+            }
+            output.push('\n');
         }
 
         // Build table data
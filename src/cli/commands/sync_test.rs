@@ -23,7 +23,7 @@ async fn test_init_connection_error() {
     // Test error handling when API server is not available
     let api_client = ApiClient::new(Some("http://localhost:9999".to_string()));
 
-    let result = init(&api_client, None).await;
+    let result = init(&api_client, None, None).await;
     assert!(
         result.is_err(),
         "Should return error when API is unavailable"
@@ -41,7 +41,16 @@ async fn test_export_connection_error() {
     // Test error handling when API server is not available
     let api_client = ApiClient::new(Some("http://localhost:9999".to_string()));
 
-    let result = export(&api_client, Some("test message".to_string()), false).await;
+    let result = export(
+        &api_client,
+        Some("test message".to_string()),
+        false,
+        None,
+        false,
+        None,
+        false,
+    )
+    .await;
     assert!(
         result.is_err(),
         "Should return error when API is unavailable"
@@ -59,7 +68,7 @@ async fn test_import_connection_error() {
     // Test error handling when API server is not available
     let api_client = ApiClient::new(Some("http://localhost:9999".to_string()));
 
-    let result = import(&api_client, true).await;
+    let result = import(&api_client, true, None, false, None, None, false).await;
     assert!(
         result.is_err(),
         "Should return error when API is unavailable"
@@ -199,6 +208,133 @@ fn test_export_response_with_counts() {
     assert_eq!(total, Some(27));
 }
 
+#[test]
+fn test_export_json_round_trips_to_summary() {
+    use crate::sync::ExportSummary;
+
+    let data = serde_json::json!({
+        "exported": {
+            "repos": 5,
+            "projects": 3,
+            "task_lists": 2,
+            "tasks": 10,
+            "transitions": 4,
+            "notes": 7,
+            "skills": 1,
+            "attachments": 2,
+            "total": 34
+        }
+    });
+
+    let output = export_summary_json(Some(&data)).unwrap();
+    let summary: ExportSummary = serde_json::from_str(&output).unwrap();
+    assert_eq!(
+        summary,
+        ExportSummary {
+            repos: 5,
+            projects: 3,
+            task_lists: 2,
+            tasks: 10,
+            transitions: 4,
+            notes: 7,
+            skills: 1,
+            attachments: 2,
+        }
+    );
+    assert_eq!(summary.total(), 34);
+}
+
+#[test]
+fn test_import_json_round_trips_to_summary_with_warnings() {
+    use crate::sync::ImportSummary;
+
+    let data = serde_json::json!({
+        "imported": {
+            "repos": 1,
+            "projects": 2,
+            "task_lists": 3,
+            "tasks": 4,
+            "transitions": 0,
+            "notes": 5,
+            "skills": 0,
+            "attachments": 0,
+            "total": 15
+        },
+        "warnings": ["task abc12345: priority 9 clamped to 5"]
+    });
+
+    let output = import_summary_json(Some(&data)).unwrap();
+    let summary: ImportSummary = serde_json::from_str(&output).unwrap();
+    assert_eq!(summary.projects, 2);
+    assert_eq!(summary.tasks, 4);
+    assert_eq!(summary.notes, 5);
+    assert_eq!(summary.total(), 15);
+    assert_eq!(summary.warnings, ["task abc12345: priority 9 clamped to 5"]);
+
+    // Missing data yields an empty summary rather than an error
+    let empty: ImportSummary = serde_json::from_str(&import_summary_json(None).unwrap()).unwrap();
+    assert_eq!(empty, ImportSummary::default());
+}
+
+#[test]
+fn test_format_conflicts_lists_skipped_records() {
+    let data = serde_json::json!({
+        "conflicts": [{
+            "entity_type": "notes",
+            "id": "abc12345",
+            "local": "2025-02-01 00:00:00",
+            "incoming": "2025-03-01 00:00:00"
+        }]
+    });
+
+    let output = format_conflicts(Some(&data));
+    assert!(output.contains("Conflicts (1 skipped"));
+    assert!(
+        output.contains("notes abc12345: local 2025-02-01 00:00:00, incoming 2025-03-01 00:00:00")
+    );
+    assert!(output.contains("--force"));
+
+    assert!(format_conflicts(Some(&serde_json::json!({ "conflicts": [] }))).is_empty());
+}
+
+#[test]
+fn test_format_sync_diff_groups_changes_by_entity() {
+    let data = serde_json::json!({
+        "added": 1,
+        "modified": 1,
+        "deleted": 1,
+        "entities": [
+            { "entity_type": "projects", "added": [], "modified": [], "deleted": [] },
+            {
+                "entity_type": "notes",
+                "added": ["note0004"],
+                "modified": ["note0002"],
+                "deleted": ["note0003"]
+            }
+        ]
+    });
+
+    let output = format_sync_diff(Some(&data), false);
+    assert!(output.starts_with("Sync diff: 1 added, 1 modified, 1 deleted"));
+    assert!(
+        output.contains(
+            "\nnotes:\n\tadded:    note0004\n\tmodified: note0002\n\tdeleted:  note0003\n"
+        )
+    );
+    assert!(!output.contains("projects:"));
+    assert!(!output.contains('\x1b'));
+
+    let colored = format_sync_diff(Some(&data), true);
+    assert!(colored.contains("\x1b[32madded:    note0004\x1b[0m"));
+    assert!(colored.contains("\x1b[31mdeleted:  note0003\x1b[0m"));
+
+    let clean = serde_json::json!({ "added": 0, "modified": 0, "deleted": 0, "entities": [] });
+    assert_eq!(
+        format_sync_diff(Some(&clean), false),
+        "Database matches the sync files\n"
+    );
+}
+
 #[test]
 fn test_status_response_not_initialized() {
     // Test status response when not initialized
@@ -259,3 +395,34 @@ fn test_status_response_initialized() {
         .and_then(|v| v.as_bool());
     assert_eq!(git_clean, Some(true));
 }
+
+#[test]
+fn test_format_sync_status_shows_ahead_behind() {
+    let data = serde_json::json!({
+        "initialized": true,
+        "remote_url": "git@github.com:test/repo.git",
+        "git": {
+            "clean": true,
+            "ahead": 2,
+            "behind": 5
+        }
+    });
+
+    let output = format_sync_status(Some(&data));
+
+    assert!(output.contains("Upstream: 2 ahead, 5 behind"));
+    assert!(output.contains("c5t sync import --remote"));
+}
+
+#[test]
+fn test_format_sync_status_omits_ahead_behind_when_in_sync() {
+    let data = serde_json::json!({
+        "initialized": true,
+        "git": { "clean": true, "ahead": 0, "behind": 0 }
+    });
+
+    let output = format_sync_status(Some(&data));
+
+    assert!(!output.contains("Upstream"));
+    assert!(!output.contains("sync import"));
+}
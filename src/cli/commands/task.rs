@@ -1,6 +1,8 @@
 use crate::cli::api_client::ApiClient;
+use crate::cli::commands::format::{CsvRecord, join_list, to_csv};
+use crate::cli::commands::{Paginated, truncation_hint, with_truncation_hint};
 use crate::cli::error::{CliError, CliResult};
-use crate::cli::utils::{apply_table_style, format_tags, truncate_with_ellipsis};
+use crate::cli::utils::{apply_table_style, format_tags, truncate_display};
 use serde::{Deserialize, Serialize};
 use tabled::{Table, Tabled};
 
@@ -16,6 +18,39 @@ pub struct Task {
     pub tags: Option<Vec<String>>,
     pub external_refs: Vec<String>,
     pub created_at: String,
+    /// Nested subtasks, only present in tree listings
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subtasks: Option<Vec<Task>>,
+}
+
+impl CsvRecord for Task {
+    const HEADER: &'static [&'static str] = &[
+        "id",
+        "list_id",
+        "parent_id",
+        "title",
+        "description",
+        "status",
+        "priority",
+        "tags",
+        "external_refs",
+        "created_at",
+    ];
+
+    fn record(&self) -> Vec<String> {
+        vec![
+            self.id.clone(),
+            self.list_id.clone(),
+            self.parent_id.clone().unwrap_or_default(),
+            self.title.clone(),
+            self.description.clone().unwrap_or_default(),
+            self.status.clone(),
+            self.priority.map(|p| p.to_string()).unwrap_or_default(),
+            join_list(self.tags.as_deref().unwrap_or_default()),
+            join_list(&self.external_refs),
+            self.created_at.clone(),
+        ]
+    }
 }
 
 #[derive(Debug, Serialize)]
@@ -69,7 +104,7 @@ impl From<&Task> for TaskDisplay {
     fn from(task: &Task) -> Self {
         Self {
             id: task.id.clone(),
-            title: truncate_with_ellipsis(&task.title, 50),
+            title: truncate_display(&task.title, 50),
             status: task.status.clone(),
             priority: task
                 .priority
@@ -79,14 +114,6 @@ impl From<&Task> for TaskDisplay {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-struct TaskListResponse {
-    items: Vec<Task>,
-    total: usize,
-    limit: usize,
-    offset: usize,
-}
-
 /// Filter parameters for listing tasks
 pub struct ListTasksFilter<'a> {
     pub query: Option<&'a str>,
@@ -98,6 +125,10 @@ pub struct ListTasksFilter<'a> {
     pub offset: Option<u32>,
     pub sort: Option<&'a str>,
     pub order: Option<&'a str>,
+    /// Configured default limit, used when `limit` is not given
+    pub page_size: Option<u32>,
+    /// Nest subtasks under their top-level task
+    pub tree: bool,
 }
 
 /// List tasks from a task list with optional filtering
@@ -107,6 +138,12 @@ pub async fn list_tasks(
     filter: ListTasksFilter<'_>,
     format: &str,
 ) -> CliResult<String> {
+    if filter.tree && format == "csv" {
+        return Err(CliError::InvalidArgument {
+            message: "--tree cannot be combined with --format csv".to_string(),
+        });
+    }
+
     let mut request = api_client.get(&format!("/api/v1/task-lists/{}/tasks", list_id));
 
     if let Some(q) = filter.query {
@@ -124,7 +161,7 @@ pub async fn list_tasks(
     if let Some(typ) = filter.r#type {
         request = request.query(&[("type", typ)]);
     }
-    if let Some(l) = filter.limit {
+    if let Some(l) = filter.limit.or(filter.page_size) {
         request = request.query(&[("limit", l.to_string())]);
     }
     if let Some(o) = filter.offset {
@@ -136,13 +173,57 @@ pub async fn list_tasks(
     if let Some(ord) = filter.order {
         request = request.query(&[("order", ord)]);
     }
+    if filter.tree {
+        request = request.query(&[("tree", "true")]);
+    }
 
-    let response: TaskListResponse = request.send().await?.json().await?;
+    let response: Paginated<Task> = request.send().await?.json().await?;
+
+    let hint = truncation_hint(
+        filter.limit,
+        filter.page_size,
+        filter.offset,
+        response.items.len(),
+        response.total,
+    );
+    let output = match format {
+        "json" => serde_json::to_string_pretty(&response)?,
+        "csv" => to_csv(&response.items),
+        _ if filter.tree => format_tree(&response.items),
+        _ => format_table(&response.items),
+    };
+    Ok(with_truncation_hint(output, format, hint))
+}
 
-    match format {
-        "json" => Ok(serde_json::to_string_pretty(&response.items)?),
-        _ => Ok(format_table(&response.items)),
+/// Render tasks as an indented outline, subtasks below their parent
+fn format_tree(tasks: &[Task]) -> String {
+    if tasks.is_empty() {
+        return "No tasks found.".to_string();
     }
+
+    fn push_lines(lines: &mut Vec<String>, tasks: &[Task], depth: usize) {
+        for task in tasks {
+            let priority = task
+                .priority
+                .map(|p| format!(" P{}", p))
+                .unwrap_or_default();
+            lines.push(format!(
+                "{}{} ({}) [{}]{}",
+                "  ".repeat(depth),
+                task.title,
+                task.id,
+                task.status,
+                priority
+            ));
+            if let Some(subtasks) = &task.subtasks {
+                push_lines(lines, subtasks, depth + 1);
+            }
+        }
+    }
+
+    let mut lines = Vec::new();
+    push_lines(&mut lines, tasks, 0);
+    lines.join("\n")
 }
 
 pub(crate) fn format_table(tasks: &[Task]) -> String {
@@ -179,6 +260,7 @@ pub async fn transition_task(
                 external_refs: None,
                 list_id: None,
             },
+            false,
         )
         .await?;
 
@@ -188,6 +270,27 @@ pub async fn transition_task(
     Ok(results.join("\n"))
 }
 
+/// Mark a task done, optionally cascading to all open subtasks
+pub async fn complete_task(
+    api_client: &ApiClient,
+    task_id: &str,
+    with_subtasks: bool,
+) -> CliResult<String> {
+    let response = api_client
+        .patch(&format!("/api/v1/tasks/{}/complete", task_id))
+        .query(&[("cascade", with_subtasks.to_string())])
+        .send()
+        .await?;
+
+    let task: Task = ApiClient::handle_response(response).await?;
+
+    if with_subtasks {
+        Ok(format!("✓ Task {} and its subtasks completed", task.id))
+    } else {
+        Ok(format!("✓ Task {} completed", task.id))
+    }
+}
+
 /// Get a single task by ID
 pub async fn get_task(api_client: &ApiClient, id: &str, format: &str) -> CliResult<String> {
     let response = api_client
@@ -240,7 +343,10 @@ pub async fn create_task(
     api_client: &ApiClient,
     list_id: &str,
     request: CreateTaskRequest,
+    json: bool,
 ) -> CliResult<String> {
+    validate_priority(request.priority)?;
+
     let response = api_client
         .post(&format!("/api/v1/task-lists/{}/tasks", list_id))
         .json(&request)
@@ -248,15 +354,65 @@ pub async fn create_task(
         .await?;
 
     let task: Task = ApiClient::handle_response(response).await?;
+    if json {
+        return Ok(serde_json::to_string_pretty(&task)?);
+    }
     Ok(format!("✓ Created task: {} ({})", task.title, task.id))
 }
 
+/// Create every task listed in a JSON file in one batch
+///
+/// The file holds an array of task objects with the same fields as
+/// `task create`. Either all of them are created or none are.
+pub async fn create_tasks_from_file(
+    api_client: &ApiClient,
+    list_id: &str,
+    file: &str,
+    json: bool,
+) -> CliResult<String> {
+    let text = std::fs::read_to_string(file).map_err(|e| CliError::InvalidArgument {
+        message: format!("failed to read tasks from '{}': {}", file, e),
+    })?;
+    let requests = parse_task_batch(&text).map_err(|e| CliError::InvalidArgument {
+        message: format!("invalid tasks file '{}': {}", file, e),
+    })?;
+
+    let response = api_client
+        .post(&format!("/api/v1/task-lists/{}/tasks/batch", list_id))
+        .json(&requests)
+        .send()
+        .await?;
+
+    let tasks: Vec<Task> = ApiClient::handle_response(response).await?;
+    if json {
+        return Ok(serde_json::to_string_pretty(&tasks)?);
+    }
+    let mut output = format!("✓ Created {} tasks", tasks.len());
+    for task in &tasks {
+        output.push_str(&format!("\n  {} ({})", task.title, task.id));
+    }
+    Ok(output)
+}
+
+/// Parse a tasks file: a JSON array of task objects.
+pub(crate) fn parse_task_batch(text: &str) -> Result<Vec<serde_json::Value>, String> {
+    let requests: Vec<serde_json::Value> =
+        serde_json::from_str(text).map_err(|e| format!("expected a JSON array of tasks: {}", e))?;
+    if let Some(index) = requests.iter().position(|r| !r.is_object()) {
+        return Err(format!("task at index {} is not an object", index));
+    }
+    Ok(requests)
+}
+
 /// Update a task
 pub async fn update_task(
     api_client: &ApiClient,
     id: &str,
     request: UpdateTaskRequest,
+    json: bool,
 ) -> CliResult<String> {
+    validate_priority(request.priority)?;
+
     let response = api_client
         .patch(&format!("/api/v1/tasks/{}", id))
         .json(&request)
@@ -264,9 +420,72 @@ pub async fn update_task(
         .await?;
 
     let task: Task = ApiClient::handle_response(response).await?;
+    if json {
+        return Ok(serde_json::to_string_pretty(&task)?);
+    }
     Ok(format!("✓ Updated task: {} ({})", task.title, task.id))
 }
 
+/// Move a task under another parent, to the top level, or to another list
+pub async fn move_task(
+    api_client: &ApiClient,
+    id: &str,
+    new_parent: Option<String>,
+    new_list: Option<String>,
+    top_level: bool,
+    json: bool,
+) -> CliResult<String> {
+    if new_parent.is_none() && new_list.is_none() && !top_level {
+        return Err(CliError::InvalidArgument {
+            message: "nothing to move: pass --new-parent, --new-list or --top-level".to_string(),
+        });
+    }
+
+    let request = UpdateTaskRequest {
+        title: None,
+        description: None,
+        status: None,
+        priority: None,
+        parent_id: if top_level {
+            Some(None)
+        } else {
+            new_parent.map(Some)
+        },
+        tags: None,
+        external_refs: None,
+        list_id: new_list,
+    };
+
+    let response = api_client
+        .patch(&format!("/api/v1/tasks/{}", id))
+        .json(&request)
+        .send()
+        .await?;
+
+    let task: Task = ApiClient::handle_response(response).await?;
+    if json {
+        return Ok(serde_json::to_string_pretty(&task)?);
+    }
+    let position = match &task.parent_id {
+        Some(parent_id) => format!("under {}", parent_id),
+        None => "top level".to_string(),
+    };
+    Ok(format!(
+        "✓ Moved task: {} ({}) to list {}, {}",
+        task.title, task.id, task.list_id, position
+    ))
+}
+
+/// Reject priorities outside 1-5 before making a request
+fn validate_priority(priority: Option<i32>) -> CliResult<()> {
+    match priority {
+        Some(p) if !(1..=5).contains(&p) => Err(CliError::InvalidArgument {
+            message: format!("priority must be between 1 and 5, got {}", p),
+        }),
+        _ => Ok(()),
+    }
+}
+
 /// Transition log entry for task state transitions
 #[derive(Debug, Serialize, Deserialize)]
 pub struct TransitionLog {
@@ -276,15 +495,6 @@ pub struct TransitionLog {
     pub transitioned_at: String,
 }
 
-/// Response from task transitions API endpoint
-#[derive(Debug, Serialize, Deserialize)]
-struct TransitionLogListResponse {
-    items: Vec<TransitionLog>,
-    total: usize,
-    limit: Option<usize>,
-    offset: usize,
-}
-
 /// Table display for transition logs
 #[derive(Tabled)]
 struct TransitionDisplay {
@@ -314,7 +524,7 @@ pub async fn get_task_transitions(
         .send()
         .await?;
 
-    let transitions: TransitionLogListResponse = ApiClient::handle_response(response).await?;
+    let transitions: Paginated<TransitionLog> = ApiClient::handle_response(response).await?;
 
     if json {
         Ok(
@@ -338,8 +548,75 @@ pub async fn get_task_transitions(
     }
 }
 
+/// Per-status task counts for a task list
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TaskStats {
+    pub list_id: String,
+    pub total: usize,
+    pub backlog: usize,
+    pub todo: usize,
+    pub in_progress: usize,
+    pub review: usize,
+    pub done: usize,
+    pub cancelled: usize,
+}
+
+impl TaskStats {
+    /// Share of tasks that are done, rounded down (0 for an empty list)
+    fn percent_done(&self) -> usize {
+        (self.done * 100).checked_div(self.total).unwrap_or(0)
+    }
+}
+
+/// Get task counts by status for a task list
+pub async fn get_stats(api_client: &ApiClient, list_id: &str, json: bool) -> CliResult<String> {
+    let response = api_client
+        .get(&format!("/api/v1/task-lists/{}/stats", list_id))
+        .send()
+        .await?;
+
+    let stats: TaskStats = ApiClient::handle_response(response).await?;
+
+    if json {
+        return Ok(serde_json::to_string_pretty(&stats)?);
+    }
+
+    use tabled::builder::Builder;
+
+    let mut builder = Builder::default();
+    builder.push_record(["Status", "Count"]);
+    for (status, count) in [
+        ("backlog", stats.backlog),
+        ("todo", stats.todo),
+        ("in_progress", stats.in_progress),
+        ("review", stats.review),
+        ("done", stats.done),
+        ("cancelled", stats.cancelled),
+    ] {
+        builder.push_record([status.to_string(), count.to_string()]);
+    }
+    builder.push_record(["total".to_string(), stats.total.to_string()]);
+
+    let mut table = builder.build();
+    apply_table_style(&mut table);
+    Ok(format!(
+        "{}\n{}% done ({}/{})",
+        table,
+        stats.percent_done(),
+        stats.done,
+        stats.total
+    ))
+}
+
 /// Delete a task (requires --force flag for safety)
-pub async fn delete_task(api_client: &ApiClient, id: &str, force: bool) -> CliResult<String> {
+///
+/// With `purge` the row is removed for good instead of being marked deleted.
+pub async fn delete_task(
+    api_client: &ApiClient,
+    id: &str,
+    force: bool,
+    purge: bool,
+) -> CliResult<String> {
     // Safety check: require --force flag
     if !force {
         return Err(CliError::InvalidResponse {
@@ -347,10 +624,11 @@ pub async fn delete_task(api_client: &ApiClient, id: &str, force: bool) -> CliRe
         });
     }
 
-    let response = api_client
-        .delete(&format!("/api/v1/tasks/{}", id))
-        .send()
-        .await?;
+    let mut request = api_client.delete(&format!("/api/v1/tasks/{}", id));
+    if purge {
+        request = request.query(&[("purge", "true")]);
+    }
+    let response = request.send().await?;
 
     // For delete, we expect no body on success, so we don't use handle_response
     if response.status().is_success() {
@@ -3,20 +3,13 @@
 //! This module provides CLI commands for managing task lists via the REST API.
 
 use crate::cli::api_client::ApiClient;
-use crate::cli::commands::PageParams;
+use crate::cli::commands::format::{CsvRecord, join_list, to_csv};
+use crate::cli::commands::{PageParams, Paginated, with_truncation_hint};
 use crate::cli::error::CliResult;
-use crate::cli::utils::{apply_table_style, format_tags, truncate_with_ellipsis};
+use crate::cli::utils::{apply_table_style, format_tags, truncate_display};
 use serde::{Deserialize, Serialize};
 use tabled::{Table, Tabled};
 
-#[derive(Debug, Serialize, Deserialize)]
-struct ListTaskListsResponse {
-    items: Vec<TaskList>,
-    total: usize,
-    limit: usize,
-    offset: usize,
-}
-
 #[derive(Debug, Serialize)]
 pub struct CreateTaskListRequest {
     pub title: String,
@@ -27,6 +20,8 @@ pub struct CreateTaskListRequest {
     pub tags: Option<Vec<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub repo_ids: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_refs: Option<Vec<String>>,
 }
 
 #[derive(Debug, Serialize)]
@@ -42,6 +37,8 @@ pub struct UpdateTaskListRequest {
     pub project_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub repo_ids: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_refs: Option<Vec<String>>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -60,6 +57,38 @@ pub struct TaskList {
     pub archived_at: Option<String>,
 }
 
+impl CsvRecord for TaskList {
+    const HEADER: &'static [&'static str] = &[
+        "id",
+        "title",
+        "description",
+        "status",
+        "project_id",
+        "tags",
+        "repo_ids",
+        "external_refs",
+        "created_at",
+        "updated_at",
+        "archived_at",
+    ];
+
+    fn record(&self) -> Vec<String> {
+        vec![
+            self.id.clone(),
+            self.title.clone(),
+            self.description.clone().unwrap_or_default(),
+            self.status.clone(),
+            self.project_id.clone(),
+            join_list(self.tags.as_deref().unwrap_or_default()),
+            join_list(self.repo_ids.as_deref().unwrap_or_default()),
+            join_list(&self.external_refs),
+            self.created_at.clone(),
+            self.updated_at.clone(),
+            self.archived_at.clone().unwrap_or_default(),
+        ]
+    }
+}
+
 #[derive(Tabled)]
 pub(crate) struct TaskListDisplay {
     #[tabled(rename = "ID")]
@@ -78,7 +107,7 @@ impl From<&TaskList> for TaskListDisplay {
     fn from(task_list: &TaskList) -> Self {
         Self {
             id: task_list.id.clone(),
-            title: truncate_with_ellipsis(&task_list.title, 40),
+            title: truncate_display(&task_list.title, 40),
             project_id: task_list.project_id.clone(),
             status: task_list.status.clone(),
             tags: format_tags(task_list.tags.as_ref()),
@@ -122,7 +151,7 @@ pub async fn list_task_lists(
     if let Some(t) = tags {
         request = request.query(&[("tags", t)]);
     }
-    if let Some(l) = page.limit {
+    if let Some(l) = page.effective_limit() {
         request = request.query(&[("limit", l.to_string())]);
     }
     if let Some(o) = page.offset {
@@ -135,12 +164,15 @@ pub async fn list_task_lists(
         request = request.query(&[("order", ord)]);
     }
 
-    let response: ListTaskListsResponse = request.send().await?.json().await?;
+    let response: Paginated<TaskList> = request.send().await?.json().await?;
 
-    match format {
-        "json" => Ok(serde_json::to_string_pretty(&response.items)?),
-        _ => Ok(format_table(&response.items)),
-    }
+    let hint = page.truncation_hint(response.items.len(), response.total);
+    let output = match format {
+        "json" => serde_json::to_string_pretty(&response)?,
+        "csv" => to_csv(&response.items),
+        _ => format_table(&response.items),
+    };
+    Ok(with_truncation_hint(output, format, hint))
 }
 
 /// Get a single task list by ID
@@ -231,8 +263,36 @@ pub async fn update_task_list(
     ))
 }
 
+/// Archive a task list
+///
+/// With `complete_open` the list's open tasks are cancelled as well.
+pub async fn archive_task_list(
+    api_client: &ApiClient,
+    id: &str,
+    complete_open: bool,
+) -> CliResult<String> {
+    let mut request = api_client.post(&format!("/api/v1/task-lists/{}/archive", id));
+    if complete_open {
+        request = request.query(&[("complete_open", "true")]);
+    }
+    let response = request.send().await?;
+
+    let task_list: TaskList = ApiClient::handle_response(response).await?;
+    Ok(format!(
+        "✓ Archived task list: {} ({})",
+        task_list.title, task_list.id
+    ))
+}
+
 /// Delete a task list (requires --force flag for safety)
-pub async fn delete_task_list(api_client: &ApiClient, id: &str, force: bool) -> CliResult<String> {
+///
+/// With `purge` the row is removed for good instead of being marked deleted.
+pub async fn delete_task_list(
+    api_client: &ApiClient,
+    id: &str,
+    force: bool,
+    purge: bool,
+) -> CliResult<String> {
     // Safety check: require --force flag
     if !force {
         return Err(crate::cli::error::CliError::InvalidResponse {
@@ -240,10 +300,11 @@ pub async fn delete_task_list(api_client: &ApiClient, id: &str, force: bool) ->
         });
     }
 
-    let response = api_client
-        .delete(&format!("/api/v1/task-lists/{}", id))
-        .send()
-        .await?;
+    let mut request = api_client.delete(&format!("/api/v1/task-lists/{}", id));
+    if purge {
+        request = request.query(&[("purge", "true")]);
+    }
+    let response = request.send().await?;
 
     if response.status().is_success() {
         Ok(format!("✓ Deleted task list: {}", id))
@@ -1,8 +1,8 @@
 use crate::a6s::store::surrealdb;
 use crate::api::{AppState, routes};
 use crate::cli::api_client::ApiClient;
-use crate::cli::commands::PageParams;
 use crate::cli::commands::task_list::*;
+use crate::cli::commands::{PageParams, Paginated};
 use crate::db::{Database, SqliteDatabase};
 use crate::sync::MockGitOps;
 use serde_json::json;
@@ -87,6 +87,7 @@ async fn test_task_list_crud_operations() {
             "q1-2026".to_string(),
         ]),
         repo_ids: Some(vec![repo_id.to_string()]),
+        external_refs: None,
     };
     let create_result = create_task_list(&api_client, create_request).await;
     assert!(
@@ -134,6 +135,7 @@ async fn test_task_list_crud_operations() {
         ]),
         project_id: None,
         repo_ids: None,
+        external_refs: None,
     };
     let update_result = update_task_list(&api_client, list_id, update_request).await;
     assert!(update_result.is_ok(), "Should update task list");
@@ -171,12 +173,12 @@ async fn test_task_list_crud_operations() {
     );
 
     // DELETE: Test requires force flag
-    let delete_no_force = delete_task_list(&api_client, list_id, false).await;
+    let delete_no_force = delete_task_list(&api_client, list_id, false, false).await;
     assert!(delete_no_force.is_err(), "Should require --force flag");
     assert!(delete_no_force.unwrap_err().to_string().contains("force"));
 
     // DELETE: Successful deletion with force flag
-    let delete_result = delete_task_list(&api_client, list_id, true).await;
+    let delete_result = delete_task_list(&api_client, list_id, true, false).await;
     assert!(delete_result.is_ok(), "Should delete with --force flag");
     assert!(delete_result.unwrap().contains("Deleted"));
 
@@ -222,6 +224,7 @@ async fn test_task_list_list_with_comprehensive_filters() {
             description: Some(desc.to_string()),
             tags: Some(tags.iter().map(|s| s.to_string()).collect()),
             repo_ids: None,
+            external_refs: None,
         };
         create_task_list(&api_client, request)
             .await
@@ -234,6 +237,7 @@ async fn test_task_list_list_with_comprehensive_filters() {
         offset: None,
         sort: Some("title"),
         order: Some("asc"),
+        page_size: None,
     };
     let result = list_task_lists(
         &api_client,
@@ -249,7 +253,7 @@ async fn test_task_list_list_with_comprehensive_filters() {
 
     let output = result.unwrap();
     let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
-    let items = parsed.as_array().unwrap();
+    let items = parsed["items"].as_array().unwrap();
 
     // Should find "Alpha Backend Tasks" matching all filters
     assert!(!items.is_empty(), "Should find backend tasks");
@@ -268,6 +272,7 @@ async fn test_task_list_list_with_comprehensive_filters() {
         offset: Some(1),
         sort: Some("title"),
         order: Some("asc"),
+        page_size: None,
     };
     let result_offset = list_task_lists(
         &api_client,
@@ -282,7 +287,7 @@ async fn test_task_list_list_with_comprehensive_filters() {
     assert!(result_offset.is_ok(), "List with offset should succeed");
     let parsed_offset: serde_json::Value = serde_json::from_str(&result_offset.unwrap()).unwrap();
     assert_eq!(
-        parsed_offset.as_array().unwrap().len(),
+        parsed_offset["items"].as_array().unwrap().len(),
         1,
         "Should return 1 item after skipping 1"
     );
@@ -293,11 +298,12 @@ async fn test_task_list_list_with_comprehensive_filters() {
         offset: None,
         sort: Some("title"),
         order: Some("desc"),
+        page_size: None,
     };
     let result_desc = list_task_lists(&api_client, None, None, None, None, page_desc, "json").await;
     assert!(result_desc.is_ok());
     let parsed_desc: serde_json::Value = serde_json::from_str(&result_desc.unwrap()).unwrap();
-    let items_desc = parsed_desc.as_array().unwrap();
+    let items_desc = parsed_desc["items"].as_array().unwrap();
     assert_eq!(items_desc[0]["title"], "Zebra DevOps Tasks");
     assert_eq!(
         items_desc[items_desc.len() - 1]["title"],
@@ -317,6 +323,7 @@ async fn test_task_list_stats_with_various_task_states() {
         description: Some("Feature development for Q1 release".to_string()),
         tags: Some(vec!["sprint-12".to_string(), "development".to_string()]),
         repo_ids: None,
+        external_refs: None,
     };
     let create_result = create_task_list(&api_client, request)
         .await
@@ -359,7 +366,7 @@ async fn test_task_list_stats_with_various_task_states() {
             tags: Some(tags.iter().map(|s| s.to_string()).collect()),
             external_refs: Some(vec![format!("SPRINT-{}", priority * 100)]),
         };
-        crate::cli::commands::task::create_task(&api_client, list_id, req)
+        crate::cli::commands::task::create_task(&api_client, list_id, req, false)
             .await
             .expect("Failed to create task");
     }
@@ -370,7 +377,7 @@ async fn test_task_list_stats_with_various_task_states() {
 
     let stats: serde_json::Value = serde_json::from_str(&stats_result.unwrap()).unwrap();
     assert_eq!(stats["total"], 3, "Should have 3 total tasks");
-    assert_eq!(stats["backlog"], 3, "All tasks start in backlog");
+    assert_eq!(stats["todo"], 3, "All tasks start in todo");
 
     // Get stats (table format) - tests table display code path
     let stats_table = get_task_list_stats(&api_client, list_id, "table").await;
@@ -417,6 +424,7 @@ async fn test_task_list_repo_linking() {
         description: Some("Implement user profile page across all tiers".to_string()),
         tags: Some(vec!["fullstack".to_string(), "feature".to_string()]),
         repo_ids: Some(vec![repo_ids[0].clone(), repo_ids[1].clone()]),
+        external_refs: None,
     };
     let create_result = create_task_list(&api_client, request)
         .await
@@ -448,6 +456,7 @@ async fn test_task_list_repo_linking() {
             repo_ids[1].clone(),
             repo_ids[2].clone(),
         ]),
+        external_refs: None,
     };
     let update_result = update_task_list(&api_client, list_id, update_request).await;
     assert!(update_result.is_ok(), "Should update repo_ids");
@@ -578,6 +587,7 @@ async fn test_task_list_error_handling() {
         tags: None,
         project_id: None,
         repo_ids: None,
+        external_refs: None,
     };
     let update_result = update_task_list(&api_client, "nonexist", update_request).await;
     assert!(
@@ -592,7 +602,7 @@ async fn test_task_list_error_handling() {
     );
 
     // DELETE: Non-existent task list
-    let delete_result = delete_task_list(&api_client, "nonexist", true).await;
+    let delete_result = delete_task_list(&api_client, "nonexist", true, false).await;
     assert!(
         delete_result.is_err(),
         "Should return error for non-existent task list"
@@ -611,6 +621,7 @@ async fn test_task_list_error_handling() {
         description: Some("Test description".to_string()),
         tags: Some(vec!["test".to_string()]),
         repo_ids: None,
+        external_refs: None,
     };
     let create_result = create_task_list(&api_client, create_request).await;
     assert!(
@@ -639,6 +650,7 @@ async fn test_update_task_list_with_empty_title() {
         description: Some("Original description".to_string()),
         tags: Some(vec!["original".to_string()]),
         repo_ids: None,
+        external_refs: None,
     };
     let create_result = create_task_list(&api_client, request)
         .await
@@ -658,6 +670,7 @@ async fn test_update_task_list_with_empty_title() {
         tags: Some(vec!["updated".to_string()]),
         project_id: None,
         repo_ids: None,
+        external_refs: None,
     };
     let result = update_task_list(&api_client, list_id, update_request).await;
     assert!(result.is_ok(), "Should handle empty title gracefully");
@@ -674,3 +687,72 @@ async fn test_update_task_list_with_empty_title() {
     assert_eq!(updated_list["description"], "Updated description");
     assert_eq!(updated_list["tags"], json!(["updated"]));
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_list_task_lists_json_empty_result() {
+    let (url, _project_id, _handle) = spawn_test_server().await;
+    let api_client = ApiClient::new(Some(url));
+
+    let json = list_task_lists(
+        &api_client,
+        None,
+        None,
+        None,
+        Some("no-such-tag"),
+        PageParams::default(),
+        "json",
+    )
+    .await
+    .unwrap();
+    let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed["items"], serde_json::json!([]));
+    assert_eq!(parsed["total"], 0);
+    let page: Paginated<TaskList> = serde_json::from_str(&json).unwrap();
+    assert!(page.items.is_empty());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_task_list_multiple_external_refs() {
+    let (url, project_id, _handle) = spawn_test_server().await;
+    let api_client = ApiClient::new(Some(url));
+
+    let request = CreateTaskListRequest {
+        title: "Tracked".to_string(),
+        project_id,
+        description: None,
+        tags: None,
+        repo_ids: None,
+        external_refs: Some(vec!["PROJ-456".to_string(), "owner/repo#123".to_string()]),
+    };
+    let create_result = create_task_list(&api_client, request).await.unwrap();
+    let list_id = create_result
+        .split('(')
+        .nth(1)
+        .and_then(|s| s.split(')').next())
+        .unwrap();
+
+    let fetched: serde_json::Value =
+        serde_json::from_str(&get_task_list(&api_client, list_id, "json").await.unwrap()).unwrap();
+    assert_eq!(
+        fetched["external_refs"],
+        json!(["PROJ-456", "owner/repo#123"])
+    );
+
+    // Updating replaces the whole set
+    let update_request = UpdateTaskListRequest {
+        title: "".to_string(),
+        description: None,
+        status: None,
+        tags: None,
+        project_id: None,
+        repo_ids: None,
+        external_refs: Some(vec!["PROJ-789".to_string()]),
+    };
+    update_task_list(&api_client, list_id, update_request)
+        .await
+        .unwrap();
+
+    let fetched: serde_json::Value =
+        serde_json::from_str(&get_task_list(&api_client, list_id, "json").await.unwrap()).unwrap();
+    assert_eq!(fetched["external_refs"], json!(["PROJ-789"]));
+}
@@ -1,7 +1,9 @@
 use crate::a6s::store::surrealdb;
 use crate::cli::api_client::ApiClient;
+use crate::cli::commands::Paginated;
 use crate::cli::commands::task::*;
 use crate::cli::commands::task_list::{CreateTaskListRequest, create_task_list};
+use crate::cli::error::CliError;
 use crate::db::{Database, SqliteDatabase};
 use crate::sync::MockGitOps;
 use serde_json::json;
@@ -110,6 +112,7 @@ async fn create_test_task_list(api_url: &str, project_id: &str) -> String {
         description: Some("Task list for testing".to_string()),
         tags: Some(vec!["test".to_string()]),
         repo_ids: None,
+        external_refs: None,
     };
     let result = create_task_list(&api_client, request)
         .await
@@ -144,7 +147,7 @@ async fn test_task_crud_operations() {
         ]),
         parent_id: None,
     };
-    let create_result = create_task(&api_client, &list_id, create_req).await;
+    let create_result = create_task(&api_client, &list_id, create_req, false).await;
     assert!(create_result.is_ok());
     let task_id = create_result
         .unwrap()
@@ -170,7 +173,7 @@ async fn test_task_crud_operations() {
         task["external_refs"],
         json!(["AUTH-456", "github.com/org/repo#123"])
     );
-    assert_eq!(task["status"], "backlog");
+    assert_eq!(task["status"], "todo");
 
     // GET: Table format
     let get_table = get_task(&api_client, &task_id, "table")
@@ -195,7 +198,7 @@ async fn test_task_crud_operations() {
         external_refs: None,
         list_id: None,
     };
-    update_task(&api_client, &task_id, update_req)
+    update_task(&api_client, &task_id, update_req, false)
         .await
         .expect("Failed to update");
     let updated = serde_json::from_str::<serde_json::Value>(
@@ -207,8 +210,16 @@ async fn test_task_crud_operations() {
     assert_eq!(updated["tags"], json!(["security", "auth", "enterprise"]));
 
     // DELETE: Requires --force flag
-    assert!(delete_task(&api_client, &task_id, false).await.is_err());
-    assert!(delete_task(&api_client, &task_id, true).await.is_ok());
+    assert!(
+        delete_task(&api_client, &task_id, false, false)
+            .await
+            .is_err()
+    );
+    assert!(
+        delete_task(&api_client, &task_id, true, false)
+            .await
+            .is_ok()
+    );
     assert!(get_task(&api_client, &task_id, "json").await.is_err());
 }
 
@@ -238,6 +249,7 @@ async fn test_task_list_with_comprehensive_filters() {
                 external_refs: Some(vec![format!("TASK-{}", i)]),
                 parent_id: None,
             },
+            false,
         )
         .await
         .expect("Create failed");
@@ -256,6 +268,7 @@ async fn test_task_list_with_comprehensive_filters() {
                 external_refs: None,
                 parent_id: None,
             },
+            false,
         )
         .await
         .expect("Create failed");
@@ -267,7 +280,7 @@ async fn test_task_list_with_comprehensive_filters() {
         &list_id,
         ListTasksFilter {
             query: Some("Backend"),
-            status: Some("backlog"),
+            status: Some("todo"),
             tags: Some("backend"),
             r#type: Some("task"), // Only top-level tasks
             limit: Some(10),
@@ -275,6 +288,8 @@ async fn test_task_list_with_comprehensive_filters() {
             sort: Some("priority"),
             order: Some("asc"),
             parent_id: None,
+            page_size: None,
+            tree: false,
         },
         "json",
     )
@@ -282,7 +297,7 @@ async fn test_task_list_with_comprehensive_filters() {
     .expect("List failed");
 
     let tasks: serde_json::Value = serde_json::from_str(&result).unwrap();
-    let tasks_arr = tasks.as_array().unwrap();
+    let tasks_arr = tasks["items"].as_array().unwrap();
     assert!(tasks_arr.len() <= 5, "Should only get backend tasks");
     assert!(
         tasks_arr
@@ -304,6 +319,8 @@ async fn test_task_list_with_comprehensive_filters() {
             sort: None,
             order: None,
             parent_id: None,
+            page_size: None,
+            tree: false,
         },
         "table",
     )
@@ -329,6 +346,8 @@ async fn test_task_list_with_comprehensive_filters() {
             sort: None,
             order: None,
             parent_id: None,
+            page_size: None,
+            tree: false,
         },
         "table",
     )
@@ -359,6 +378,7 @@ async fn test_task_status_transitions() {
             external_refs: Some(vec!["BUG-789".to_string()]),
             parent_id: None,
         },
+        false,
     )
     .await
     .unwrap();
@@ -368,10 +388,7 @@ async fn test_task_status_transitions() {
         .and_then(|s| s.split(')').next())
         .unwrap();
 
-    // Transition: backlog -> todo
-    transition_task(&api_client, &[task_id.to_string()], "todo")
-        .await
-        .expect("Transition failed");
+    // New tasks start in todo (the server default)
     let task = serde_json::from_str::<serde_json::Value>(
         &get_task(&api_client, task_id, "json").await.unwrap(),
     )
@@ -414,6 +431,7 @@ async fn test_subtasks_with_full_data() {
             external_refs: Some(vec!["EPIC-100".to_string()]),
             parent_id: None,
         },
+        false,
     )
     .await
     .unwrap();
@@ -436,6 +454,7 @@ async fn test_subtasks_with_full_data() {
             external_refs: Some(vec!["TASK-101".to_string()]),
             parent_id: Some(parent_id.clone()),
         },
+        false,
     )
     .await
     .unwrap();
@@ -474,14 +493,16 @@ async fn test_subtasks_with_full_data() {
             offset: None,
             sort: None,
             order: None,
+            page_size: None,
+            tree: false,
         },
         "json",
     )
     .await
     .unwrap();
     let subtasks = serde_json::from_str::<serde_json::Value>(&subtasks_result).unwrap();
-    assert_eq!(subtasks.as_array().unwrap().len(), 1);
-    assert_eq!(subtasks[0]["parent_id"], parent_id);
+    assert_eq!(subtasks["items"].as_array().unwrap().len(), 1);
+    assert_eq!(subtasks["items"][0]["parent_id"], parent_id);
 
     // Remove parent (convert subtask to task)
     update_task(
@@ -497,6 +518,7 @@ async fn test_subtasks_with_full_data() {
             external_refs: None,
             list_id: None,
         },
+        false,
     )
     .await
     .expect("Update failed");
@@ -530,12 +552,13 @@ async fn test_error_handling() {
             external_refs: None,
             list_id: None,
         },
+        false,
     )
     .await;
     assert!(update_result.is_err());
 
     // Delete non-existent task
-    let delete_result = delete_task(&api_client, "nonexistent", true).await;
+    let delete_result = delete_task(&api_client, "nonexistent", true, false).await;
     assert!(delete_result.is_err());
 }
 
@@ -554,6 +577,7 @@ async fn test_get_task_transitions() {
             description: None,
             tags: None,
             repo_ids: None,
+            external_refs: None,
         },
     )
     .await
@@ -577,6 +601,7 @@ async fn test_get_task_transitions() {
             tags: Some(vec!["test".to_string()]),
             external_refs: None,
         },
+        false,
     )
     .await
     .expect("Failed to create task");
@@ -601,6 +626,7 @@ async fn test_get_task_transitions() {
             external_refs: None,
             list_id: None,
         },
+        false,
     )
     .await
     .expect("Failed to transition task");
@@ -610,8 +636,8 @@ async fn test_get_task_transitions() {
         .await
         .expect("Failed to get transitions");
 
-    // Should show both backlog and in_progress transitions
-    assert!(transitions_table.contains("backlog"));
+    // Should show both todo and in_progress transitions
+    assert!(transitions_table.contains("todo"));
     assert!(transitions_table.contains("in_progress"));
 
     // Get transitions (JSON format)
@@ -630,6 +656,552 @@ async fn test_get_task_transitions() {
         .iter()
         .map(|item| item["status"].as_str().unwrap())
         .collect();
-    assert!(statuses.contains(&"backlog"));
+    assert!(statuses.contains(&"todo"));
     assert!(statuses.contains(&"in_progress"));
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_complete_task_with_and_without_subtasks() {
+    let (url, project_id, _handle) = spawn_test_server().await;
+    let list_id = create_test_task_list(&url, &project_id).await;
+    let api_client = ApiClient::new(Some(url));
+
+    let extract_id = |output: String| {
+        output
+            .split('(')
+            .nth(1)
+            .and_then(|s| s.split(')').next())
+            .unwrap()
+            .to_string()
+    };
+
+    // Build a parent with two todo subtasks; returns (parent, [subtasks])
+    let create_family = |title: &'static str| {
+        let api_client = &api_client;
+        let list_id = &list_id;
+        async move {
+            let parent = extract_id(
+                create_task(
+                    api_client,
+                    list_id,
+                    CreateTaskRequest {
+                        title: title.to_string(),
+                        description: None,
+                        parent_id: None,
+                        priority: None,
+                        tags: None,
+                        external_refs: None,
+                    },
+                    false,
+                )
+                .await
+                .unwrap(),
+            );
+            let mut subtasks = vec![];
+            for n in 1..=2 {
+                let subtask = extract_id(
+                    create_task(
+                        api_client,
+                        list_id,
+                        CreateTaskRequest {
+                            title: format!("{} subtask {}", title, n),
+                            description: None,
+                            parent_id: Some(parent.clone()),
+                            priority: None,
+                            tags: None,
+                            external_refs: None,
+                        },
+                        false,
+                    )
+                    .await
+                    .unwrap(),
+                );
+                transition_task(api_client, std::slice::from_ref(&subtask), "todo")
+                    .await
+                    .unwrap();
+                subtasks.push(subtask);
+            }
+            (parent, subtasks)
+        }
+    };
+
+    let status_of = |id: String| {
+        let api_client = &api_client;
+        async move {
+            let json = get_task(api_client, &id, "json").await.unwrap();
+            serde_json::from_str::<serde_json::Value>(&json).unwrap()["status"]
+                .as_str()
+                .unwrap()
+                .to_string()
+        }
+    };
+
+    // Without the flag: only the parent is completed
+    let (parent, subtasks) = create_family("Plain").await;
+    let output = complete_task(&api_client, &parent, false).await.unwrap();
+    assert!(output.contains(&parent));
+    assert_eq!(status_of(parent.clone()).await, "done");
+    for subtask in &subtasks {
+        assert_eq!(status_of(subtask.clone()).await, "todo");
+    }
+
+    // With --with-subtasks: every subtask is completed too
+    let (parent, subtasks) = create_family("Cascade").await;
+    complete_task(&api_client, &parent, true).await.unwrap();
+    assert_eq!(status_of(parent.clone()).await, "done");
+    for subtask in &subtasks {
+        assert_eq!(status_of(subtask.clone()).await, "done");
+    }
+
+    // Each cascaded completion is recorded in the transition log
+    let history = get_task_transitions(&api_client, &subtasks[0], true)
+        .await
+        .unwrap();
+    let history: serde_json::Value = serde_json::from_str(&history).unwrap();
+    assert!(
+        history["items"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|t| t["status"] == "done")
+    );
+
+    // Unknown task
+    assert!(complete_task(&api_client, "nonexist", true).await.is_err());
+}
+
+#[tokio::test]
+async fn test_create_and_update_task_reject_invalid_priority() {
+    // Validation happens before any request is made
+    let api_client = ApiClient::new(Some("http://localhost:9999".to_string()));
+
+    let err = create_task(
+        &api_client,
+        "anylist1",
+        CreateTaskRequest {
+            title: "Too urgent".to_string(),
+            description: None,
+            parent_id: None,
+            priority: Some(0),
+            tags: None,
+            external_refs: None,
+        },
+        false,
+    )
+    .await
+    .unwrap_err();
+    assert!(matches!(err, CliError::InvalidArgument { .. }));
+    assert!(err.to_string().contains("between 1 and 5"));
+
+    let err = update_task(
+        &api_client,
+        "anytask1",
+        UpdateTaskRequest {
+            title: None,
+            description: None,
+            status: None,
+            priority: Some(6),
+            parent_id: None,
+            tags: None,
+            external_refs: None,
+            list_id: None,
+        },
+        false,
+    )
+    .await
+    .unwrap_err();
+    assert!(matches!(err, CliError::InvalidArgument { .. }));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_create_and_update_task_json_output() {
+    let (url, project_id, _handle) = spawn_test_server().await;
+    let list_id = create_test_task_list(&url, &project_id).await;
+    let api_client = ApiClient::new(Some(url));
+
+    let created = create_task(
+        &api_client,
+        &list_id,
+        CreateTaskRequest {
+            title: "JSON task".to_string(),
+            description: None,
+            parent_id: None,
+            priority: Some(3),
+            tags: Some(vec!["cli".to_string()]),
+            external_refs: None,
+        },
+        true,
+    )
+    .await
+    .expect("Create failed");
+    let created: serde_json::Value = serde_json::from_str(&created).unwrap();
+    assert_eq!(created["title"], "JSON task");
+    assert_eq!(created["priority"], 3);
+    let task_id = created["id"].as_str().unwrap().to_string();
+
+    let updated = update_task(
+        &api_client,
+        &task_id,
+        UpdateTaskRequest {
+            title: None,
+            description: None,
+            status: Some("todo".to_string()),
+            priority: Some(1),
+            parent_id: None,
+            tags: None,
+            external_refs: None,
+            list_id: None,
+        },
+        true,
+    )
+    .await
+    .expect("Update failed");
+    let updated: serde_json::Value = serde_json::from_str(&updated).unwrap();
+    assert_eq!(updated["id"], task_id.as_str());
+    assert_eq!(updated["status"], "todo");
+    assert_eq!(updated["priority"], 1);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_create_task_reports_missing_list_and_parent() {
+    let (url, project_id, _handle) = spawn_test_server().await;
+    let list_id = create_test_task_list(&url, &project_id).await;
+    let api_client = ApiClient::new(Some(url));
+
+    let request = |parent_id: Option<&str>| CreateTaskRequest {
+        title: "Orphan".to_string(),
+        description: None,
+        parent_id: parent_id.map(str::to_string),
+        priority: None,
+        tags: None,
+        external_refs: None,
+    };
+
+    let err = create_task(&api_client, "nolist01", request(None), false)
+        .await
+        .unwrap_err();
+    match err {
+        CliError::ApiError { status, message } => {
+            assert_eq!(status, 404);
+            assert!(message.contains("Task list 'nolist01' not found"));
+        }
+        other => panic!("Expected ApiError, got {:?}", other),
+    }
+
+    let err = create_task(&api_client, &list_id, request(Some("notask01")), false)
+        .await
+        .unwrap_err();
+    match err {
+        CliError::ApiError { status, message } => {
+            assert_eq!(status, 404);
+            assert!(message.contains("Parent task 'notask01' not found"));
+        }
+        other => panic!("Expected ApiError, got {:?}", other),
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_move_task_between_parents_and_lists() {
+    let (url, project_id, _handle) = spawn_test_server().await;
+    let list_id = create_test_task_list(&url, &project_id).await;
+    let other_list_id = create_test_task_list(&url, &project_id).await;
+    let api_client = ApiClient::new(Some(url));
+
+    let create = |title: &str, parent_id: Option<String>| CreateTaskRequest {
+        title: title.to_string(),
+        description: None,
+        parent_id,
+        priority: None,
+        tags: None,
+        external_refs: None,
+    };
+    let id_of = |json: String| {
+        serde_json::from_str::<serde_json::Value>(&json).unwrap()["id"]
+            .as_str()
+            .unwrap()
+            .to_string()
+    };
+
+    let parent_id = id_of(
+        create_task(&api_client, &list_id, create("Parent", None), true)
+            .await
+            .unwrap(),
+    );
+    let task_id = id_of(
+        create_task(&api_client, &list_id, create("Loose task", None), true)
+            .await
+            .unwrap(),
+    );
+
+    // Nothing to do
+    let err = move_task(&api_client, &task_id, None, None, false, false)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, CliError::InvalidArgument { .. }));
+
+    // Under a parent
+    let moved = move_task(
+        &api_client,
+        &task_id,
+        Some(parent_id.clone()),
+        None,
+        false,
+        false,
+    )
+    .await
+    .expect("Move under parent failed");
+    assert!(moved.contains(&format!("under {}", parent_id)), "{moved}");
+
+    // A task can't move under its own subtask
+    let err = move_task(
+        &api_client,
+        &parent_id,
+        Some(task_id.clone()),
+        None,
+        false,
+        false,
+    )
+    .await
+    .unwrap_err();
+    match err {
+        CliError::ApiError { status, message } => {
+            assert_eq!(status, 400);
+            assert!(message.contains("own ancestor"), "{message}");
+        }
+        other => panic!("Expected ApiError, got {:?}", other),
+    }
+
+    // Back to the top level of another list
+    let moved = move_task(
+        &api_client,
+        &task_id,
+        None,
+        Some(other_list_id.clone()),
+        true,
+        true,
+    )
+    .await
+    .expect("Move to other list failed");
+    let moved: serde_json::Value = serde_json::from_str(&moved).unwrap();
+    assert!(moved["parent_id"].is_null());
+    assert_eq!(moved["list_id"], other_list_id.as_str());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_get_stats_breaks_down_by_status() {
+    let (url, project_id, _handle) = spawn_test_server().await;
+    let list_id = create_test_task_list(&url, &project_id).await;
+    let empty_list_id = create_test_task_list(&url, &project_id).await;
+    let api_client = ApiClient::new(Some(url));
+
+    let mut ids = Vec::new();
+    for title in ["One", "Two", "Three", "Four"] {
+        let request = CreateTaskRequest {
+            title: title.to_string(),
+            description: None,
+            parent_id: None,
+            priority: None,
+            tags: None,
+            external_refs: None,
+        };
+        let created = create_task(&api_client, &list_id, request, true)
+            .await
+            .unwrap();
+        let created: serde_json::Value = serde_json::from_str(&created).unwrap();
+        ids.push(created["id"].as_str().unwrap().to_string());
+    }
+    complete_task(&api_client, &ids[0], false).await.unwrap();
+
+    let table = get_stats(&api_client, &list_id, false).await.unwrap();
+    assert!(table.contains("in_progress"));
+    assert!(table.contains("25% done (1/4)"), "{table}");
+
+    let output = get_stats(&api_client, &list_id, true).await.unwrap();
+    let stats: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(stats["total"], 4);
+    assert_eq!(stats["todo"], 3);
+    assert_eq!(stats["done"], 1);
+
+    // A list without tasks reports 0% rather than dividing by zero
+    let table = get_stats(&api_client, &empty_list_id, false).await.unwrap();
+    assert!(table.contains("0% done (0/0)"), "{table}");
+}
+
+#[test]
+fn test_parse_task_batch() {
+    let tasks = parse_task_batch(r#"[{"title": "A"}, {"title": "B", "priority": 1}]"#).unwrap();
+    assert_eq!(tasks.len(), 2);
+
+    assert!(
+        parse_task_batch(r#"{"title": "A"}"#)
+            .unwrap_err()
+            .contains("JSON array")
+    );
+    assert!(
+        parse_task_batch(r#"[{"title": "A"}, "B"]"#)
+            .unwrap_err()
+            .contains("index 1")
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_create_tasks_from_file() {
+    let (url, project_id, _handle) = spawn_test_server().await;
+    let list_id = create_test_task_list(&url, &project_id).await;
+    let api_client = ApiClient::new(Some(url));
+    let dir = TempDir::new().unwrap();
+
+    let file = dir.path().join("tasks.json");
+    std::fs::write(
+        &file,
+        json!([
+            {"title": "Design", "priority": 1},
+            {"title": "Build", "tags": ["backend"]},
+            {"title": "Ship", "status": "backlog"}
+        ])
+        .to_string(),
+    )
+    .unwrap();
+
+    let output = create_tasks_from_file(&api_client, &list_id, file.to_str().unwrap(), true)
+        .await
+        .unwrap();
+    let created: serde_json::Value = serde_json::from_str(&output).unwrap();
+    let created = created.as_array().unwrap();
+    assert_eq!(created.len(), 3);
+    assert_eq!(created[0]["title"], "Design");
+    assert_eq!(created[0]["priority"], 1);
+    assert_eq!(created[2]["status"], "backlog");
+
+    // A bad row rejects the whole batch and names its index
+    let bad = dir.path().join("bad.json");
+    std::fs::write(
+        &bad,
+        json!([{"title": "Fine"}, {"title": "Bad", "priority": 9}]).to_string(),
+    )
+    .unwrap();
+    let err = create_tasks_from_file(&api_client, &list_id, bad.to_str().unwrap(), false)
+        .await
+        .unwrap_err();
+    match err {
+        CliError::ApiError { status, message } => {
+            assert_eq!(status, 400);
+            assert!(message.contains("index 1"), "{}", message);
+        }
+        other => panic!("Expected ApiError, got {:?}", other),
+    }
+
+    let response = api_client
+        .get(&format!("/api/v1/task-lists/{}/tasks", list_id))
+        .send()
+        .await
+        .unwrap();
+    let body: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(body["total"], 3);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_list_tasks_json_empty_result() {
+    let (url, project_id, _handle) = spawn_test_server().await;
+    let list_id = create_test_task_list(&url, &project_id).await;
+    let api_client = ApiClient::new(Some(url));
+
+    let filter = ListTasksFilter {
+        query: None,
+        status: None,
+        parent_id: None,
+        tags: None,
+        r#type: None,
+        limit: None,
+        offset: None,
+        sort: None,
+        order: None,
+        page_size: None,
+        tree: false,
+    };
+    let json = list_tasks(&api_client, &list_id, filter, "json")
+        .await
+        .unwrap();
+    let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed["items"], serde_json::json!([]));
+    assert_eq!(parsed["total"], 0);
+    let page: Paginated<Task> = serde_json::from_str(&json).unwrap();
+    assert!(page.items.is_empty());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_list_tasks_tree() {
+    let (url, project_id, _handle) = spawn_test_server().await;
+    let list_id = create_test_task_list(&url, &project_id).await;
+    let api_client = ApiClient::new(Some(url));
+
+    let create = |title: &str, priority: i32, parent_id: Option<String>| CreateTaskRequest {
+        title: title.to_string(),
+        description: None,
+        parent_id,
+        priority: Some(priority),
+        tags: None,
+        external_refs: None,
+    };
+    let parent = create_task(&api_client, &list_id, create("Ship release", 1, None), true)
+        .await
+        .unwrap();
+    let parent_id = serde_json::from_str::<serde_json::Value>(&parent).unwrap()["id"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    create_task(
+        &api_client,
+        &list_id,
+        create("Write changelog", 3, Some(parent_id.clone())),
+        true,
+    )
+    .await
+    .unwrap();
+
+    let tree_filter = || ListTasksFilter {
+        query: None,
+        status: None,
+        parent_id: None,
+        tags: None,
+        r#type: None,
+        limit: None,
+        offset: None,
+        sort: None,
+        order: None,
+        page_size: None,
+        tree: true,
+    };
+
+    // JSON nests subtasks under their parent, with status and priority at each node
+    let json = list_tasks(&api_client, &list_id, tree_filter(), "json")
+        .await
+        .unwrap();
+    let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+    let items = parsed["items"].as_array().unwrap();
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0]["id"], parent_id);
+    assert_eq!(items[0]["status"], "backlog");
+    assert_eq!(items[0]["priority"], 1);
+    let subtasks = items[0]["subtasks"].as_array().unwrap();
+    assert_eq!(subtasks.len(), 1);
+    assert_eq!(subtasks[0]["title"], "Write changelog");
+    assert_eq!(subtasks[0]["status"], "backlog");
+    assert_eq!(subtasks[0]["priority"], 3);
+
+    // Without JSON the tree renders as an indented outline
+    let table = list_tasks(&api_client, &list_id, tree_filter(), "table")
+        .await
+        .unwrap();
+    let lines: Vec<&str> = table.lines().collect();
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].starts_with("Ship release ("));
+    assert!(lines[0].ends_with("[backlog] P1"));
+    assert!(lines[1].starts_with("  Write changelog ("));
+
+    // A tree has no CSV form
+    let err = list_tasks(&api_client, &list_id, tree_filter(), "csv")
+        .await
+        .unwrap_err();
+    assert!(matches!(err, CliError::InvalidArgument { .. }));
+}
@@ -29,6 +29,22 @@ pub enum CliError {
     #[error("API error ({status}): {message}")]
     #[diagnostic(code(context::cli::api_error))]
     ApiError { status: u16, message: String },
+
+    #[error("Invalid argument: {message}")]
+    #[diagnostic(code(context::cli::invalid_argument))]
+    InvalidArgument { message: String },
+
+    #[error("Git operation failed: {message}")]
+    #[diagnostic(code(context::cli::git_failed))]
+    GitFailed { message: String },
+}
+
+impl From<crate::sync::GitError> for CliError {
+    fn from(e: crate::sync::GitError) -> Self {
+        CliError::GitFailed {
+            message: e.to_string(),
+        }
+    }
 }
 
 impl From<reqwest::Error> for CliError {
@@ -4,6 +4,7 @@ pub mod error;
 pub mod utils;
 
 use commands::PageParams;
+use commands::format::OutputFormat;
 
 #[cfg(test)]
 #[path = "utils_test.rs"]
@@ -13,7 +14,11 @@ mod utils_test;
 #[path = "api_client_test.rs"]
 mod api_client_test;
 
-use clap::{Parser, Subcommand};
+#[cfg(test)]
+#[path = "mod_test.rs"]
+mod mod_test;
+
+use clap::{CommandFactory, Parser, Subcommand};
 use miette::Result;
 
 #[cfg(debug_assertions)]
@@ -30,6 +35,10 @@ pub struct Cli {
     #[arg(long, global = true)]
     pub api_url: Option<String>,
 
+    /// Default --limit for list commands (default: C5T_PAGE_SIZE env, otherwise the server default)
+    #[arg(long, global = true)]
+    pub page_size: Option<u32>,
+
     #[command(subcommand)]
     command: Option<Commands>,
 }
@@ -54,6 +63,42 @@ enum Commands {
         #[arg(long)]
         skills_dir: Option<std::path::PathBuf>,
 
+        /// Project ID to use when a task list is created without one (defaults to C5T_DEFAULT_PROJECT env)
+        #[arg(long)]
+        default_project: Option<String>,
+
+        /// Largest note content in bytes (defaults to C5T_MAX_NOTE_SIZE env or 100000, the maximum)
+        #[arg(long)]
+        max_note_size: Option<usize>,
+
+        /// Complete a parent task when all its subtasks are done or cancelled (defaults to C5T_AUTO_COMPLETE_PARENTS env)
+        #[arg(long)]
+        auto_complete_parents: bool,
+
+        /// Status for tasks created over REST without one (defaults to C5T_DEFAULT_TASK_STATUS env or todo)
+        #[arg(long, value_parser = parse_task_status)]
+        default_task_status: Option<crate::db::TaskStatus>,
+
+        /// Store tags in lowercase so "Rust" and "rust" are the same tag (defaults to C5T_LOWERCASE_TAGS env)
+        #[arg(long)]
+        lowercase_tags: bool,
+
+        /// Write sync files gzip-compressed as .jsonl.gz (defaults to C5T_SYNC_COMPRESS env)
+        #[arg(long)]
+        compress_sync: bool,
+
+        /// Origin allowed to call the API cross-origin, e.g. a separately hosted frontend (repeatable; defaults to C5T_ALLOWED_ORIGINS env)
+        #[arg(long = "allowed-origin", value_name = "ORIGIN")]
+        allowed_origins: Vec<String>,
+
+        /// Allow cross-origin requests from any origin when no --allowed-origin is given (defaults to C5T_CORS_PERMISSIVE env)
+        #[arg(long)]
+        cors_permissive: bool,
+
+        /// Hex characters in newly generated IDs: 8, 12 or 16 (defaults to C5T_ID_LENGTH env or 8)
+        #[arg(long)]
+        id_length: Option<crate::db::utils::IdLength>,
+
         /// Increase logging verbosity (-v = info, -vv = debug, -vvv = trace)
         #[arg(short, long, action = clap::ArgAction::Count)]
         verbose: u8,
@@ -61,6 +106,10 @@ enum Commands {
         /// Enable OpenAPI documentation endpoint at /docs
         #[arg(long)]
         docs: bool,
+
+        /// Serve Prometheus-style request and database pool metrics at /metrics
+        #[arg(long)]
+        metrics: bool,
     },
     /// Project management
     Project {
@@ -97,6 +146,23 @@ enum Commands {
         #[command(subcommand)]
         command: SyncCommands,
     },
+    /// Database maintenance
+    Maintenance {
+        #[command(subcommand)]
+        command: MaintenanceCommands,
+    },
+    /// Show an overview of projects, tasks, notes and sync state
+    Status {
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Print a shell completion script to stdout
+    #[command(hide = true)]
+    Completions {
+        /// Shell to generate completions for
+        shell: clap_complete::Shell,
+    },
 }
 
 #[derive(Subcommand)]
@@ -105,9 +171,9 @@ enum TaskCommands {
     List {
         /// Task list ID
         list_id: String,
-        /// Output as JSON
-        #[arg(long)]
-        json: bool,
+        /// Output format (table, json, csv)
+        #[arg(long, value_enum, default_value_t)]
+        format: OutputFormat,
         /// Search query (FTS5 full-text search)
         #[arg(long, short = 'q')]
         query: Option<String>,
@@ -135,6 +201,12 @@ enum TaskCommands {
         /// Sort order (asc, desc)
         #[arg(long)]
         order: Option<String>,
+        /// Nest subtasks under their parent task (paging applies to top-level tasks)
+        #[arg(long, conflicts_with_all = ["query", "parent_id", "type"])]
+        tree: bool,
+        /// Output as JSON (shorthand for --format json)
+        #[arg(long, conflicts_with = "format")]
+        json: bool,
     },
     /// Get a task by ID
     Get {
@@ -150,8 +222,8 @@ enum TaskCommands {
         #[arg(long)]
         list_id: String,
         /// Task title (short summary)
-        #[arg(long)]
-        title: String,
+        #[arg(long, required_unless_present = "from_file")]
+        title: Option<String>,
         /// Task description (optional, detailed information)
         #[arg(long)]
         description: Option<String>,
@@ -164,9 +236,15 @@ enum TaskCommands {
         /// Tags (comma-separated)
         #[arg(long)]
         tags: Option<String>,
-        /// External reference (e.g., 'owner/repo#123' for GitHub, 'PROJ-456' for Jira)
+        /// External references (comma-separated, e.g. 'owner/repo#123,PROJ-456')
         #[arg(long)]
         external_ref: Option<String>,
+        /// Create every task in a JSON file (an array of task objects) in one batch
+        #[arg(long, conflicts_with_all = ["title", "description", "parent_id", "priority", "tags", "external_ref"])]
+        from_file: Option<String>,
+        /// Output the created task as JSON
+        #[arg(long)]
+        json: bool,
     },
     /// Update a task
     Update {
@@ -187,7 +265,7 @@ enum TaskCommands {
         /// New tags (comma-separated)
         #[arg(long)]
         tags: Option<String>,
-        /// External reference (e.g., 'owner/repo#123' for GitHub, 'PROJ-456' for Jira)
+        /// External references (comma-separated, e.g. 'owner/repo#123,PROJ-456')
         #[arg(long)]
         external_ref: Option<String>,
         /// Parent task ID (for converting to/from subtask). Use empty string to remove parent.
@@ -196,6 +274,26 @@ enum TaskCommands {
         /// Move task to different list (task list ID)
         #[arg(long)]
         list_id: Option<String>,
+        /// Output the updated task as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Move a task under another parent, to the top level, or to another list
+    Move {
+        /// Task ID
+        id: String,
+        /// New parent task ID (the task becomes its subtask)
+        #[arg(long, conflicts_with = "top_level")]
+        new_parent: Option<String>,
+        /// New task list ID
+        #[arg(long)]
+        new_list: Option<String>,
+        /// Detach a subtask from its parent
+        #[arg(long)]
+        top_level: bool,
+        /// Output the moved task as JSON
+        #[arg(long)]
+        json: bool,
     },
     /// Delete a task
     Delete {
@@ -204,6 +302,9 @@ enum TaskCommands {
         /// Force deletion without confirmation
         #[arg(long)]
         force: bool,
+        /// Remove it for good instead of marking it deleted
+        #[arg(long)]
+        purge: bool,
     },
     /// Transition one or more tasks to a new status
     Transition {
@@ -213,6 +314,14 @@ enum TaskCommands {
         /// Target status (backlog, todo, in_progress, review, done, cancelled)
         status: String,
     },
+    /// Mark a task as done
+    Complete {
+        /// Task ID
+        id: String,
+        /// Also mark all open subtasks (recursively) as done
+        #[arg(long)]
+        with_subtasks: bool,
+    },
     /// View task state transition history
     Transitions {
         /// Task ID
@@ -221,6 +330,14 @@ enum TaskCommands {
         #[arg(long)]
         json: bool,
     },
+    /// Show task counts by status for a task list
+    Stats {
+        /// Task list ID
+        list_id: String,
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
 }
 
 #[derive(Subcommand)]
@@ -230,8 +347,8 @@ enum NoteCommands {
         /// Search query (FTS5 full-text search)
         #[arg(long, short = 'q')]
         query: Option<String>,
-        /// Filter by project ID
-        #[arg(long)]
+        /// Filter by project ID (combines with --tags)
+        #[arg(long, visible_alias = "project")]
         project_id: Option<String>,
         /// Filter by tags (comma-separated)
         #[arg(long)]
@@ -254,9 +371,27 @@ enum NoteCommands {
         /// Sort order (asc, desc)
         #[arg(long)]
         order: Option<String>,
-        /// Output as JSON
+        /// Output format (table, json, csv)
+        #[arg(long, value_enum, default_value_t)]
+        format: OutputFormat,
+    },
+    /// Full-text search notes, showing the matched fragment
+    Search {
+        /// Search query (FTS5 full-text search)
+        #[arg(long, short = 'q')]
+        query: String,
+        /// Filter by project ID
+        #[arg(long, visible_alias = "project")]
+        project_id: Option<String>,
+        /// Filter by tags (comma-separated)
         #[arg(long)]
-        json: bool,
+        tags: Option<String>,
+        /// Maximum number of notes to return
+        #[arg(long)]
+        limit: Option<u32>,
+        /// Output format (table, json, csv)
+        #[arg(long, value_enum, default_value_t)]
+        format: OutputFormat,
     },
     /// Get a note by ID
     Get {
@@ -272,8 +407,18 @@ enum NoteCommands {
         #[arg(long)]
         title: String,
         /// Note content (Markdown supported)
+        #[arg(
+            long,
+            required_unless_present_any = ["file", "edit"],
+            conflicts_with_all = ["file", "edit"]
+        )]
+        content: Option<String>,
+        /// Read content from a file instead (`-` reads stdin)
+        #[arg(long, conflicts_with = "edit")]
+        file: Option<String>,
+        /// Write the content in $EDITOR (falls back to vi)
         #[arg(long)]
-        content: String,
+        edit: bool,
         /// Tags (comma-separated)
         #[arg(long)]
         tags: Option<String>,
@@ -289,6 +434,9 @@ enum NoteCommands {
         /// Repository IDs to link (comma-separated)
         #[arg(long)]
         repo_ids: Option<String>,
+        /// Reuse an existing note with identical content instead of creating a duplicate
+        #[arg(long)]
+        dedup: bool,
     },
     /// Update a note
     Update {
@@ -298,8 +446,14 @@ enum NoteCommands {
         #[arg(long)]
         title: Option<String>,
         /// New content
-        #[arg(long)]
+        #[arg(long, conflicts_with_all = ["file", "edit"])]
         content: Option<String>,
+        /// Read new content from a file instead (`-` reads stdin)
+        #[arg(long, conflicts_with = "edit")]
+        file: Option<String>,
+        /// Edit the current content in $EDITOR (falls back to vi)
+        #[arg(long)]
+        edit: bool,
         /// New tags (comma-separated)
         #[arg(long)]
         tags: Option<String>,
@@ -323,6 +477,9 @@ enum NoteCommands {
         /// Force deletion without confirmation
         #[arg(long)]
         force: bool,
+        /// Remove it for good instead of marking it deleted
+        #[arg(long)]
+        purge: bool,
     },
 }
 
@@ -333,8 +490,8 @@ enum SkillCommands {
         /// Search query (FTS5 full-text search)
         #[arg(long, short = 'q')]
         query: Option<String>,
-        /// Filter by project ID
-        #[arg(long)]
+        /// Filter by project ID (combines with --tags)
+        #[arg(long, visible_alias = "project")]
         project_id: Option<String>,
         /// Filter by tags (comma-separated)
         #[arg(long)]
@@ -351,9 +508,9 @@ enum SkillCommands {
         /// Sort order (asc, desc)
         #[arg(long)]
         order: Option<String>,
-        /// Output as JSON
-        #[arg(long)]
-        json: bool,
+        /// Output format (table, json, csv)
+        #[arg(long, value_enum, default_value_t)]
+        format: OutputFormat,
     },
     /// Get a skill by ID
     Get {
@@ -414,6 +571,9 @@ enum SyncCommands {
     Init {
         /// Git remote URL (e.g., git@github.com:user/c5t-sync.git)
         remote_url: Option<String>,
+        /// Initialize this project's own sync workspace instead of the default one
+        #[arg(long)]
+        project: Option<String>,
     },
     /// Export database to sync
     Export {
@@ -423,17 +583,72 @@ enum SyncCommands {
         /// Push to remote after export
         #[arg(long)]
         remote: bool,
+        /// Export only one entity type (repos, projects, task_lists, tasks, notes, skills)
+        #[arg(long)]
+        only: Option<String>,
+        /// Only rewrite records changed since the last export
+        #[arg(long, conflicts_with = "only")]
+        incremental: bool,
+        /// Export only this project's data, to its own sync workspace
+        #[arg(long)]
+        project: Option<String>,
+        /// Output the export summary as JSON
+        #[arg(long)]
+        json: bool,
     },
     /// Import from sync to database
     Import {
         /// Pull from remote before import
         #[arg(long)]
         remote: bool,
+        /// Import only one entity type (repos, projects, task_lists, tasks, notes, skills)
+        #[arg(long)]
+        only: Option<String>,
+        /// Show field-level changes without importing
+        #[arg(long, conflicts_with = "remote")]
+        preview: bool,
+        /// Import from this project's sync workspace instead of the default one
+        #[arg(long)]
+        project: Option<String>,
+        /// Output the import summary (or preview) as JSON
+        #[arg(long)]
+        json: bool,
+        /// Overwrite records changed both here and in the sync files since the last sync
+        #[arg(long)]
+        force: bool,
+        /// How to resolve records changed on both sides (skip, last-write-wins); default skip
+        #[arg(long, conflicts_with = "force", value_parser = ["skip", "last-write-wins"])]
+        strategy: Option<String>,
+    },
+    /// Show which records differ between the sync files and the database
+    Diff {
+        /// Pull from remote before comparing
+        #[arg(long)]
+        remote: bool,
+        /// Compare this project's sync workspace instead of the default one
+        #[arg(long)]
+        project: Option<String>,
+        /// Output the diff as JSON
+        #[arg(long)]
+        json: bool,
     },
     /// Show sync status
     Status,
 }
 
+#[derive(Subcommand)]
+enum MaintenanceCommands {
+    /// Report columns missing from the database or declared with the wrong type
+    MigrateCheck {
+        /// Override data home directory (same default as `c5t api --home`)
+        #[arg(long)]
+        home: Option<std::path::PathBuf>,
+        /// Output the mismatches as JSON
+        #[arg(long)]
+        json: bool,
+    },
+}
+
 #[derive(Subcommand)]
 enum ProjectCommands {
     /// List all projects
@@ -456,14 +671,25 @@ enum ProjectCommands {
         /// Sort order (asc, desc)
         #[arg(long)]
         order: Option<String>,
+        /// Output format (table, json, csv)
+        #[arg(long, value_enum, default_value_t)]
+        format: OutputFormat,
+    },
+    /// Get a project by ID
+    Get {
+        /// Project ID
+        id: String,
         /// Output as JSON
         #[arg(long)]
         json: bool,
     },
-    /// Get a project by ID
-    Get {
+    /// Show a project with its task lists and notes as an outline
+    Tree {
         /// Project ID
         id: String,
+        /// Outline depth (1 = lists and notes, 2 = also task stats and subnotes)
+        #[arg(long, default_value_t = 2)]
+        depth: u32,
         /// Output as JSON
         #[arg(long)]
         json: bool,
@@ -479,9 +705,12 @@ enum ProjectCommands {
         /// Tags (comma-separated)
         #[arg(long)]
         tags: Option<String>,
-        /// External reference (e.g., 'owner/repo#123' for GitHub, 'PROJ-456' for Jira)
+        /// External references (comma-separated, e.g. 'owner/repo#123,PROJ-456')
         #[arg(long)]
         external_ref: Option<String>,
+        /// Repo ID to link to the new project (repeatable)
+        #[arg(long = "link-repo", value_name = "REPO_ID")]
+        link_repo: Vec<String>,
     },
     /// Update a project
     Update {
@@ -496,7 +725,7 @@ enum ProjectCommands {
         /// New tags (comma-separated)
         #[arg(long)]
         tags: Option<String>,
-        /// External reference (e.g., 'owner/repo#123' for GitHub, 'PROJ-456' for Jira)
+        /// External references (comma-separated, e.g. 'owner/repo#123,PROJ-456')
         #[arg(long)]
         external_ref: Option<String>,
     },
@@ -507,6 +736,9 @@ enum ProjectCommands {
         /// Force deletion without confirmation
         #[arg(long)]
         force: bool,
+        /// Remove it for good instead of marking it deleted
+        #[arg(long)]
+        purge: bool,
     },
 }
 
@@ -514,8 +746,8 @@ enum ProjectCommands {
 enum RepoCommands {
     /// List all repositories
     List {
-        /// Search query (FTS5 full-text search)
-        #[arg(long, short = 'q')]
+        /// Search query: substring of remote or path, or FTS5 query over remote, path and tags
+        #[arg(long, short = 'q', visible_alias = "search")]
         query: Option<String>,
         /// Filter by project ID
         #[arg(long)]
@@ -535,9 +767,9 @@ enum RepoCommands {
         /// Sort order (asc, desc)
         #[arg(long)]
         order: Option<String>,
-        /// Output as JSON
-        #[arg(long)]
-        json: bool,
+        /// Output format (table, json, csv)
+        #[arg(long, value_enum, default_value_t)]
+        format: OutputFormat,
     },
     /// Get a repository by ID
     Get {
@@ -586,6 +818,12 @@ enum RepoCommands {
         /// Force deletion without confirmation
         #[arg(long)]
         force: bool,
+        /// Unlink it from any projects, task lists and notes instead of refusing the delete
+        #[arg(long)]
+        unlink: bool,
+        /// Remove it for good instead of marking it deleted
+        #[arg(long)]
+        purge: bool,
     },
     /// Trigger code analysis for a repository
     Analyze {
@@ -595,6 +833,11 @@ enum RepoCommands {
         #[arg(long)]
         status: bool,
     },
+    /// Clone a repository's remote locally, or fast-forward an existing checkout
+    Clone {
+        /// Repository ID
+        id: String,
+    },
 }
 
 #[derive(Subcommand)]
@@ -625,9 +868,9 @@ enum TaskListCommands {
         /// Sort order (asc, desc)
         #[arg(long)]
         order: Option<String>,
-        /// Output as JSON
-        #[arg(long)]
-        json: bool,
+        /// Output format (table, json, csv)
+        #[arg(long, value_enum, default_value_t)]
+        format: OutputFormat,
     },
     /// Get a task list by ID
     Get {
@@ -654,6 +897,9 @@ enum TaskListCommands {
         /// Repository IDs to link (comma-separated)
         #[arg(long)]
         repo_ids: Option<String>,
+        /// External references (comma-separated, e.g. 'owner/repo#123,PROJ-456')
+        #[arg(long)]
+        external_ref: Option<String>,
     },
     /// Update a task list
     Update {
@@ -677,6 +923,17 @@ enum TaskListCommands {
         /// Update linked repositories (comma-separated IDs)
         #[arg(long)]
         repo_ids: Option<String>,
+        /// Replace external references (comma-separated, e.g. 'owner/repo#123,PROJ-456')
+        #[arg(long)]
+        external_ref: Option<String>,
+    },
+    /// Archive a task list
+    Archive {
+        /// Task list ID
+        id: String,
+        /// Cancel tasks that are still open (backlog, todo, in_progress, review)
+        #[arg(long)]
+        complete_open: bool,
     },
     /// Delete a task list
     Delete {
@@ -685,6 +942,9 @@ enum TaskListCommands {
         /// Force deletion without confirmation
         #[arg(long)]
         force: bool,
+        /// Remove it for good instead of marking it deleted
+        #[arg(long)]
+        purge: bool,
     },
     /// Get task statistics for a task list
     Stats {
@@ -696,9 +956,31 @@ enum TaskListCommands {
     },
 }
 
+/// Render the top-level usage text, as shown for bare `c5t`.
+fn parse_task_status(s: &str) -> std::result::Result<crate::db::TaskStatus, String> {
+    s.parse()
+}
+
+/// The `clap` definition of the CLI, for help rendering and completions.
+pub fn command() -> clap::Command {
+    Cli::command()
+}
+
+fn render_help() -> String {
+    command().render_help().to_string()
+}
+
+/// Write the completion script for `shell` to `out`.
+fn write_completions(shell: clap_complete::Shell, out: &mut dyn std::io::Write) {
+    let mut cmd = command();
+    let name = cmd.get_name().to_string();
+    clap_complete::generate(shell, &mut cmd, name, out);
+}
+
 pub async fn run() -> Result<()> {
     let cli = Cli::parse();
     let api_client = api_client::ApiClient::new(cli.api_url);
+    let page_size = cli.page_size.or_else(commands::page_size_from_env);
 
     match cli.command {
         Some(Commands::Api {
@@ -706,10 +988,38 @@ pub async fn run() -> Result<()> {
             port,
             home,
             skills_dir,
+            default_project,
+            max_note_size,
+            auto_complete_parents,
+            default_task_status,
+            lowercase_tags,
+            compress_sync,
+            allowed_origins,
+            cors_permissive,
+            id_length,
             verbose,
             docs,
+            metrics,
         }) => {
-            commands::api::run(host, port, home, skills_dir, verbose, docs).await?;
+            commands::api::run(commands::api::ApiArgs {
+                host,
+                port,
+                home,
+                skills_dir,
+                default_project,
+                max_note_size,
+                auto_complete_parents,
+                default_task_status,
+                lowercase_tags,
+                compress_sync,
+                allowed_origins,
+                cors_permissive,
+                id_length,
+                verbosity: verbose,
+                enable_docs: docs,
+                enable_metrics: metrics,
+            })
+            .await?;
         }
         Some(Commands::Project { command }) => match command {
             ProjectCommands::List {
@@ -719,20 +1029,21 @@ pub async fn run() -> Result<()> {
                 offset,
                 sort,
                 order,
-                json,
+                format,
             } => {
                 let page = commands::PageParams {
                     limit,
                     offset,
                     sort: sort.as_deref(),
                     order: order.as_deref(),
+                    page_size,
                 };
                 let output = commands::project::list_projects(
                     &api_client,
                     query.as_deref(),
                     tags.as_deref(),
                     page,
-                    if json { "json" } else { "table" },
+                    format.as_str(),
                 )
                 .await?;
                 println!("{}", output);
@@ -746,17 +1057,29 @@ pub async fn run() -> Result<()> {
                 .await?;
                 println!("{}", output);
             }
+            ProjectCommands::Tree { id, depth, json } => {
+                let output = commands::project::project_tree(
+                    &api_client,
+                    &id,
+                    depth,
+                    if json { "json" } else { "table" },
+                )
+                .await?;
+                println!("{}", output);
+            }
             ProjectCommands::Create {
                 title,
                 description,
                 tags,
                 external_ref,
+                link_repo,
             } => {
                 let request = commands::project::CreateProjectRequest {
                     title,
                     description,
                     tags: utils::parse_tags(tags.as_deref()),
-                    external_refs: external_ref.map(|s| vec![s]),
+                    external_refs: utils::parse_tags(external_ref.as_deref()),
+                    repo_ids: (!link_repo.is_empty()).then_some(link_repo),
                 };
                 let output = commands::project::create_project(&api_client, request).await?;
                 println!("{}", output);
@@ -772,13 +1095,14 @@ pub async fn run() -> Result<()> {
                     title,
                     description,
                     tags: utils::parse_tags(tags.as_deref()),
-                    external_refs: external_ref.map(|s| vec![s]),
+                    external_refs: utils::parse_tags(external_ref.as_deref()),
                 };
                 let output = commands::project::update_project(&api_client, &id, request).await?;
                 println!("{}", output);
             }
-            ProjectCommands::Delete { id, force } => {
-                let output = commands::project::delete_project(&api_client, &id, force).await?;
+            ProjectCommands::Delete { id, force, purge } => {
+                let output =
+                    commands::project::delete_project(&api_client, &id, force, purge).await?;
                 println!("{}", output);
             }
         },
@@ -791,13 +1115,14 @@ pub async fn run() -> Result<()> {
                 offset,
                 sort,
                 order,
-                json,
+                format,
             } => {
                 let page = PageParams {
                     limit,
                     offset,
                     sort: sort.as_deref(),
                     order: order.as_deref(),
+                    page_size,
                 };
                 let output = commands::repo::list_repos(
                     &api_client,
@@ -805,7 +1130,7 @@ pub async fn run() -> Result<()> {
                     project_id.as_deref(),
                     tags.as_deref(),
                     page,
-                    if json { "json" } else { "table" },
+                    format.as_str(),
                 )
                 .await?;
                 println!("{}", output);
@@ -847,8 +1172,14 @@ pub async fn run() -> Result<()> {
                 let output = commands::repo::update_repo(&api_client, &id, request).await?;
                 println!("{}", output);
             }
-            RepoCommands::Delete { id, force } => {
-                let output = commands::repo::delete_repo(&api_client, &id, force).await?;
+            RepoCommands::Delete {
+                id,
+                force,
+                unlink,
+                purge,
+            } => {
+                let output =
+                    commands::repo::delete_repo(&api_client, &id, force, unlink, purge).await?;
                 println!("{}", output);
             }
             RepoCommands::Analyze { id, status } => {
@@ -860,6 +1191,12 @@ pub async fn run() -> Result<()> {
                     println!("{}", output);
                 }
             }
+            RepoCommands::Clone { id } => {
+                let output =
+                    commands::repo::clone_repo(&api_client, &crate::sync::RealGit::new(), &id)
+                        .await?;
+                println!("{}", output);
+            }
         },
         Some(Commands::TaskList { command }) => match command {
             TaskListCommands::List {
@@ -871,13 +1208,14 @@ pub async fn run() -> Result<()> {
                 offset,
                 sort,
                 order,
-                json,
+                format,
             } => {
                 let page = PageParams {
                     limit,
                     offset,
                     sort: sort.as_deref(),
                     order: order.as_deref(),
+                    page_size,
                 };
                 let output = commands::task_list::list_task_lists(
                     &api_client,
@@ -886,7 +1224,7 @@ pub async fn run() -> Result<()> {
                     status.as_deref(),
                     tags.as_deref(),
                     page,
-                    if json { "json" } else { "table" },
+                    format.as_str(),
                 )
                 .await?;
                 println!("{}", output);
@@ -906,6 +1244,7 @@ pub async fn run() -> Result<()> {
                 description,
                 tags,
                 repo_ids,
+                external_ref,
             } => {
                 let request = commands::task_list::CreateTaskListRequest {
                     title,
@@ -917,6 +1256,7 @@ pub async fn run() -> Result<()> {
                             .map(|s| s.trim().to_string())
                             .collect::<Vec<_>>()
                     }),
+                    external_refs: utils::parse_tags(external_ref.as_deref()),
                 };
                 let output = commands::task_list::create_task_list(&api_client, request).await?;
                 println!("{}", output);
@@ -929,6 +1269,7 @@ pub async fn run() -> Result<()> {
                 tags,
                 project_id,
                 repo_ids,
+                external_ref,
             } => {
                 let request = commands::task_list::UpdateTaskListRequest {
                     title: title.unwrap_or_default(), // Empty string triggers fetch of current title
@@ -937,13 +1278,20 @@ pub async fn run() -> Result<()> {
                     tags: utils::parse_tags(tags.as_deref()),
                     project_id,
                     repo_ids: utils::parse_tags(repo_ids.as_deref()),
+                    external_refs: utils::parse_tags(external_ref.as_deref()),
                 };
                 let output =
                     commands::task_list::update_task_list(&api_client, &id, request).await?;
                 println!("{}", output);
             }
-            TaskListCommands::Delete { id, force } => {
-                let output = commands::task_list::delete_task_list(&api_client, &id, force).await?;
+            TaskListCommands::Archive { id, complete_open } => {
+                let output =
+                    commands::task_list::archive_task_list(&api_client, &id, complete_open).await?;
+                println!("{}", output);
+            }
+            TaskListCommands::Delete { id, force, purge } => {
+                let output =
+                    commands::task_list::delete_task_list(&api_client, &id, force, purge).await?;
                 println!("{}", output);
             }
             TaskListCommands::Stats { id, json } => {
@@ -959,7 +1307,7 @@ pub async fn run() -> Result<()> {
         Some(Commands::Task { command }) => match command {
             TaskCommands::List {
                 list_id,
-                json,
+                format,
                 query,
                 parent_id,
                 status,
@@ -969,7 +1317,10 @@ pub async fn run() -> Result<()> {
                 offset,
                 sort,
                 order,
+                tree,
+                json,
             } => {
+                let format = if json { OutputFormat::Json } else { format };
                 let filter = commands::task::ListTasksFilter {
                     query: query.as_deref(),
                     status: status.as_deref(),
@@ -980,14 +1331,12 @@ pub async fn run() -> Result<()> {
                     offset,
                     sort: sort.as_deref(),
                     order: order.as_deref(),
+                    page_size,
+                    tree,
                 };
-                let output = commands::task::list_tasks(
-                    &api_client,
-                    &list_id,
-                    filter,
-                    if json { "json" } else { "table" },
-                )
-                .await?;
+                let output =
+                    commands::task::list_tasks(&api_client, &list_id, filter, format.as_str())
+                        .await?;
                 println!("{}", output);
             }
             TaskCommands::Get { id, json } => {
@@ -1004,16 +1353,23 @@ pub async fn run() -> Result<()> {
                 priority,
                 tags,
                 external_ref,
+                from_file,
+                json,
             } => {
-                let request = commands::task::CreateTaskRequest {
-                    title,
-                    description,
-                    parent_id,
-                    priority,
-                    tags: utils::parse_tags(tags.as_deref()),
-                    external_refs: utils::parse_tags(external_ref.as_deref()),
+                let output = if let Some(file) = from_file {
+                    commands::task::create_tasks_from_file(&api_client, &list_id, &file, json)
+                        .await?
+                } else {
+                    let request = commands::task::CreateTaskRequest {
+                        title: title.unwrap_or_default(),
+                        description,
+                        parent_id,
+                        priority,
+                        tags: utils::parse_tags(tags.as_deref()),
+                        external_refs: utils::parse_tags(external_ref.as_deref()),
+                    };
+                    commands::task::create_task(&api_client, &list_id, request, json).await?
                 };
-                let output = commands::task::create_task(&api_client, &list_id, request).await?;
                 println!("{}", output);
             }
             TaskCommands::Update {
@@ -1026,6 +1382,7 @@ pub async fn run() -> Result<()> {
                 external_ref,
                 parent_id,
                 list_id,
+                json,
             } => {
                 let request = commands::task::UpdateTaskRequest {
                     title,
@@ -1043,21 +1400,47 @@ pub async fn run() -> Result<()> {
                     external_refs: utils::parse_tags(external_ref.as_deref()),
                     list_id,
                 };
-                let output = commands::task::update_task(&api_client, &id, request).await?;
+                let output = commands::task::update_task(&api_client, &id, request, json).await?;
                 println!("{}", output);
             }
-            TaskCommands::Delete { id, force } => {
-                let output = commands::task::delete_task(&api_client, &id, force).await?;
+            TaskCommands::Move {
+                id,
+                new_parent,
+                new_list,
+                top_level,
+                json,
+            } => {
+                let output = commands::task::move_task(
+                    &api_client,
+                    &id,
+                    new_parent,
+                    new_list,
+                    top_level,
+                    json,
+                )
+                .await?;
+                println!("{}", output);
+            }
+            TaskCommands::Delete { id, force, purge } => {
+                let output = commands::task::delete_task(&api_client, &id, force, purge).await?;
                 println!("{}", output);
             }
             TaskCommands::Transition { ids, status } => {
                 let output = commands::task::transition_task(&api_client, &ids, &status).await?;
                 println!("{}", output);
             }
+            TaskCommands::Complete { id, with_subtasks } => {
+                let output = commands::task::complete_task(&api_client, &id, with_subtasks).await?;
+                println!("{}", output);
+            }
             TaskCommands::Transitions { id, json } => {
                 let output = commands::task::get_task_transitions(&api_client, &id, json).await?;
                 println!("{}", output);
             }
+            TaskCommands::Stats { list_id, json } => {
+                let output = commands::task::get_stats(&api_client, &list_id, json).await?;
+                println!("{}", output);
+            }
         },
         Some(Commands::Note { command }) => match command {
             NoteCommands::List {
@@ -1070,13 +1453,14 @@ pub async fn run() -> Result<()> {
                 offset,
                 sort,
                 order,
-                json,
+                format,
             } => {
                 let page = commands::PageParams {
                     limit,
                     offset,
                     sort: sort.as_deref(),
                     order: order.as_deref(),
+                    page_size,
                 };
                 let output = commands::note::list_notes(
                     &api_client,
@@ -1086,7 +1470,25 @@ pub async fn run() -> Result<()> {
                     parent_id.as_deref(),
                     note_type.as_deref(),
                     page,
-                    if json { "json" } else { "table" },
+                    format.as_str(),
+                )
+                .await?;
+                println!("{}", output);
+            }
+            NoteCommands::Search {
+                query,
+                project_id,
+                tags,
+                limit,
+                format,
+            } => {
+                let output = commands::note::search_notes(
+                    &api_client,
+                    &query,
+                    project_id.as_deref(),
+                    tags.as_deref(),
+                    limit,
+                    format.as_str(),
                 )
                 .await?;
                 println!("{}", output);
@@ -1100,12 +1502,20 @@ pub async fn run() -> Result<()> {
             NoteCommands::Create {
                 title,
                 content,
+                file,
+                edit,
                 tags,
                 parent_id,
                 idx,
                 project_ids,
                 repo_ids,
+                dedup,
             } => {
+                let content = if edit {
+                    commands::note::edit_content("")?
+                } else {
+                    commands::note::resolve_content(content, file.as_deref())?.unwrap_or_default()
+                };
                 let request = commands::note::CreateNoteRequest {
                     title,
                     content,
@@ -1115,19 +1525,27 @@ pub async fn run() -> Result<()> {
                     project_ids: utils::parse_tags(project_ids.as_deref()),
                     repo_ids: utils::parse_tags(repo_ids.as_deref()),
                 };
-                let output = commands::note::create_note(&api_client, request).await?;
+                let output = commands::note::create_note(&api_client, request, dedup).await?;
                 println!("{}", output);
             }
             NoteCommands::Update {
                 id,
                 title,
                 content,
+                file,
+                edit,
                 tags,
                 parent_id,
                 idx,
                 project_ids,
                 repo_ids,
             } => {
+                let content = if edit {
+                    let current = commands::note::note_content(&api_client, &id).await?;
+                    Some(commands::note::edit_content(&current)?)
+                } else {
+                    commands::note::resolve_content(content, file.as_deref())?
+                };
                 let request = commands::note::UpdateNoteRequest {
                     title,
                     content,
@@ -1146,8 +1564,8 @@ pub async fn run() -> Result<()> {
                 let output = commands::note::update_note(&api_client, &id, request).await?;
                 println!("{}", output);
             }
-            NoteCommands::Delete { id, force } => {
-                let output = commands::note::delete_note(&api_client, &id, force).await?;
+            NoteCommands::Delete { id, force, purge } => {
+                let output = commands::note::delete_note(&api_client, &id, force, purge).await?;
                 println!("{}", output);
             }
         },
@@ -1160,13 +1578,14 @@ pub async fn run() -> Result<()> {
                 offset,
                 sort,
                 order,
-                json,
+                format,
             } => {
                 let page = commands::PageParams {
                     limit,
                     offset,
                     sort: sort.as_deref(),
                     order: order.as_deref(),
+                    page_size,
                 };
                 let filter = commands::skill::ListSkillsFilter {
                     query: query.as_deref(),
@@ -1174,12 +1593,8 @@ pub async fn run() -> Result<()> {
                     tags: tags.as_deref(),
                     page,
                 };
-                let output = commands::skill::list_skills(
-                    &api_client,
-                    filter,
-                    if json { "json" } else { "table" },
-                )
-                .await?;
+                let output =
+                    commands::skill::list_skills(&api_client, filter, format.as_str()).await?;
                 println!("{}", output);
             }
             SkillCommands::Get { id, json } => {
@@ -1239,16 +1654,65 @@ pub async fn run() -> Result<()> {
             }
         },
         Some(Commands::Sync { command }) => match command {
-            SyncCommands::Init { remote_url } => {
-                let output = commands::sync::init(&api_client, remote_url).await?;
+            SyncCommands::Init {
+                remote_url,
+                project,
+            } => {
+                let output = commands::sync::init(&api_client, remote_url, project).await?;
+                println!("{}", output);
+            }
+            SyncCommands::Export {
+                message,
+                remote,
+                only,
+                incremental,
+                project,
+                json,
+            } => {
+                let output = commands::sync::export(
+                    &api_client,
+                    message,
+                    remote,
+                    only,
+                    incremental,
+                    project,
+                    json,
+                )
+                .await?;
                 println!("{}", output);
             }
-            SyncCommands::Export { message, remote } => {
-                let output = commands::sync::export(&api_client, message, remote).await?;
+            SyncCommands::Import {
+                remote,
+                only,
+                preview,
+                project,
+                json,
+                force,
+                strategy,
+            } => {
+                let strategy = if force {
+                    Some("force".to_string())
+                } else {
+                    strategy
+                };
+                let output = commands::sync::import(
+                    &api_client,
+                    remote,
+                    only,
+                    preview,
+                    project,
+                    strategy,
+                    json,
+                )
+                .await?;
                 println!("{}", output);
             }
-            SyncCommands::Import { remote } => {
-                let output = commands::sync::import(&api_client, remote).await?;
+            SyncCommands::Diff {
+                remote,
+                project,
+                json,
+            } => {
+                let output = commands::sync::diff(&api_client, remote, project, json).await?;
                 println!("{}", output);
             }
             SyncCommands::Status => {
@@ -1256,9 +1720,21 @@ pub async fn run() -> Result<()> {
                 println!("{}", output);
             }
         },
+        Some(Commands::Maintenance { command }) => match command {
+            MaintenanceCommands::MigrateCheck { home, json } => {
+                commands::maintenance::migrate_check(home, json).await?;
+            }
+        },
+        Some(Commands::Status { json }) => {
+            let output = commands::status::status(&api_client, json).await?;
+            println!("{}", output);
+        }
+        Some(Commands::Completions { shell }) => {
+            write_completions(shell, &mut std::io::stdout());
+        }
         None => {
             // Show help when no command provided
-            let _ = Cli::parse_from(["c5t", "--help"]);
+            print!("{}", render_help());
         }
     }
 
@@ -0,0 +1,60 @@
+use super::{Cli, Commands, NoteCommands, render_help, write_completions};
+use clap::Parser;
+
+#[test]
+fn test_bare_invocation_has_no_command() {
+    let cli = Cli::try_parse_from(["c5t"]).expect("bare c5t should parse");
+    assert!(cli.command.is_none());
+}
+
+#[test]
+fn test_render_help_lists_subcommands() {
+    let help = render_help();
+
+    assert!(help.contains("Usage:"), "help should include usage line");
+    for subcommand in ["api", "project", "task-list", "task", "note", "sync"] {
+        assert!(
+            help.contains(subcommand),
+            "help should list the '{}' subcommand",
+            subcommand
+        );
+    }
+}
+
+#[test]
+fn test_completions_is_hidden_from_help() {
+    let help = render_help();
+    assert!(!help.contains("completions"));
+
+    let cli = Cli::try_parse_from(["c5t", "completions", "zsh"]).expect("completions should parse");
+    assert!(matches!(cli.command, Some(Commands::Completions { .. })));
+}
+
+#[test]
+fn test_zsh_completions_cover_subcommands_and_global_flags() {
+    let mut out = Vec::new();
+    write_completions(clap_complete::Shell::Zsh, &mut out);
+    let script = String::from_utf8(out).expect("completion script should be UTF-8");
+
+    assert!(script.starts_with("#compdef c5t"));
+    for word in ["project", "task-list", "note", "sync", "--api-url"] {
+        assert!(
+            script.contains(word),
+            "zsh completions should mention '{}'",
+            word
+        );
+    }
+}
+
+#[test]
+fn test_note_list_accepts_project_alias() {
+    let cli = Cli::try_parse_from(["c5t", "note", "list", "--project", "proj0001"])
+        .expect("--project should parse");
+
+    match cli.command {
+        Some(Commands::Note {
+            command: NoteCommands::List { project_id, .. },
+        }) => assert_eq!(project_id.as_deref(), Some("proj0001")),
+        _ => panic!("expected note list"),
+    }
+}
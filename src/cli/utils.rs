@@ -1,16 +1,31 @@
 //! Shared utilities for CLI commands
 
 use tabled::{Table, settings::Style};
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
-/// Truncate a string with ellipsis if it exceeds max length
-pub fn truncate_with_ellipsis(s: &str, max: usize) -> String {
-    let char_count = s.chars().count();
-    if char_count <= max {
-        s.to_string()
-    } else {
-        let truncated: String = s.chars().take(max - 3).collect();
-        format!("{}...", truncated)
+/// Truncate a string to fit `max_width` terminal columns, ending in `…` when cut.
+///
+/// Cuts on character boundaries and counts wide characters (CJK, most emoji)
+/// as two columns, so truncated table cells line up.
+pub fn truncate_display(s: &str, max_width: usize) -> String {
+    if s.width() <= max_width {
+        return s.to_string();
     }
+
+    // Leave one column for the ellipsis
+    let budget = max_width.saturating_sub(1);
+    let mut width = 0;
+    let mut truncated = String::new();
+    for c in s.chars() {
+        let w = c.width().unwrap_or(0);
+        if width + w > budget {
+            break;
+        }
+        width += w;
+        truncated.push(c);
+    }
+    truncated.push('…');
+    truncated
 }
 
 /// Format optional tags vector for display
@@ -1,31 +1,47 @@
 use crate::cli::utils::*;
 
 #[test]
-fn test_truncate_with_ellipsis_short_string() {
-    let result = truncate_with_ellipsis("hello", 10);
+fn test_truncate_display_short_string() {
+    let result = truncate_display("hello", 10);
     assert_eq!(result, "hello");
 }
 
 #[test]
-fn test_truncate_with_ellipsis_exact_length() {
-    let result = truncate_with_ellipsis("hello", 5);
+fn test_truncate_display_exact_length() {
+    let result = truncate_display("hello", 5);
     assert_eq!(result, "hello");
 }
 
 #[test]
-fn test_truncate_with_ellipsis_long_string() {
-    let result = truncate_with_ellipsis("hello world this is a long string", 10);
-    assert_eq!(result, "hello w...");
+fn test_truncate_display_long_string() {
+    let result = truncate_display("hello world this is a long string", 10);
+    assert_eq!(result, "hello wor…");
 }
 
 #[test]
-fn test_truncate_with_ellipsis_unicode() {
-    let result = truncate_with_ellipsis("hello 世界", 8);
-    assert_eq!(result, "hello 世界"); // 8 chars exactly, no truncation
+fn test_truncate_display_multibyte() {
+    // Accented characters are multibyte but one column wide
+    let result = truncate_display("café crème brûlée", 8);
+    assert_eq!(result, "café cr…");
+    assert_eq!(result.chars().count(), 8);
+}
 
-    // Test truncation of unicode - max=7 means result is 7 chars: "hell..."
-    let result2 = truncate_with_ellipsis("hello 世界", 7);
-    assert_eq!(result2, "hell...");
+#[test]
+fn test_truncate_display_wide_characters() {
+    // "hello " is 6 columns, each CJK character is 2
+    assert_eq!(truncate_display("hello 世界", 10), "hello 世界");
+    assert_eq!(truncate_display("hello 世界", 9), "hello 世…");
+
+    // A wide character that would overflow the budget is dropped, not split
+    assert_eq!(truncate_display("世界世界", 6), "世界…");
+    assert_eq!(truncate_display("世界世界", 5), "世界…");
+    assert_eq!(truncate_display("🚀🚀🚀", 4), "🚀…");
+}
+
+#[test]
+fn test_truncate_display_tiny_width() {
+    assert_eq!(truncate_display("hello", 1), "…");
+    assert_eq!(truncate_display("hello", 0), "…");
 }
 
 #[test]
@@ -0,0 +1,72 @@
+//! Time source for timestamps stamped outside the database.
+//!
+//! Handlers take the current time from a [`Clock`] instead of calling
+//! `Utc::now()` directly, so tests can pin it with a [`MockClock`].
+
+use std::sync::Mutex;
+
+use sqlx::types::chrono::{DateTime, Utc};
+
+/// Source of the current time.
+pub trait Clock: Send + Sync {
+    /// Current time in UTC.
+    fn now(&self) -> DateTime<Utc>;
+
+    /// Current time in the `YYYY-MM-DD HH:MM:SS` format stored in the database.
+    fn timestamp(&self) -> String {
+        self.now().format("%Y-%m-%d %H:%M:%S").to_string()
+    }
+}
+
+/// The system wall clock.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// A clock that stays at a fixed time until moved with [`MockClock::set`].
+#[derive(Debug)]
+pub struct MockClock {
+    now: Mutex<DateTime<Utc>>,
+}
+
+impl MockClock {
+    pub fn new(now: DateTime<Utc>) -> Self {
+        Self {
+            now: Mutex::new(now),
+        }
+    }
+
+    /// Move the clock to `now`.
+    pub fn set(&self, now: DateTime<Utc>) {
+        *self.now.lock().unwrap() = now;
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> DateTime<Utc> {
+        *self.now.lock().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mock_clock_returns_the_pinned_time() {
+        let at = DateTime::parse_from_rfc3339("2025-03-04T05:06:07Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let clock = MockClock::new(at);
+        assert_eq!(clock.timestamp(), "2025-03-04 05:06:07");
+
+        let later = at + chrono::Duration::hours(1);
+        clock.set(later);
+        assert_eq!(clock.now(), later);
+    }
+}
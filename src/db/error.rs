@@ -41,6 +41,14 @@ pub enum DbError {
     #[error("Constraint violation: {message}")]
     #[diagnostic(code(context::db::constraint))]
     Constraint { message: String },
+
+    #[error("Item {index} of the batch failed: {source}")]
+    #[diagnostic(code(context::db::batch_item))]
+    BatchItem {
+        index: usize,
+        #[source]
+        source: Box<DbError>,
+    },
 }
 
 /// Result type for database operations.
@@ -0,0 +1,164 @@
+//! Common list parameters parsed from a query string.
+//!
+//! Every list endpoint accepts `limit`, `offset`, `cursor`, `sort`, `order`
+//! and `tags`. [`ListQuery::from_params`] parses them once so handlers only deal
+//! with their entity-specific filters.
+
+use std::collections::HashMap;
+
+use super::pagination::{LimitPolicy, decode_cursor};
+use super::{DbError, DbResult, SortOrder};
+
+/// Parsed `limit`/`offset`/`cursor`/`sort`/`order`/`tags` list parameters.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ListQuery {
+    /// Page size, already resolved against the [`LimitPolicy`]
+    pub limit: Option<usize>,
+    pub offset: Option<usize>,
+    /// Keyset cursor from a previous page; preferred over `offset`
+    pub after: Option<String>,
+    pub sort: Option<String>,
+    pub order: Option<SortOrder>,
+    /// Comma-separated `tags`, trimmed with empty entries dropped
+    pub tags: Option<Vec<String>>,
+}
+
+impl ListQuery {
+    /// Parse list parameters from raw query-string pairs.
+    ///
+    /// Empty values count as absent. The limit is clamped by `limits`;
+    /// a non-numeric `limit`/`offset`, a malformed `cursor` or an `order`
+    /// other than `asc`/`desc` is a validation error.
+    pub fn from_params(params: &HashMap<String, String>, limits: LimitPolicy) -> DbResult<Self> {
+        let get = |key: &str| {
+            params
+                .get(key)
+                .map(|value| value.trim())
+                .filter(|value| !value.is_empty())
+        };
+
+        let limit = get("limit").map(|v| parse_count("limit", v)).transpose()?;
+        let offset = get("offset")
+            .map(|v| parse_count("offset", v))
+            .transpose()?;
+
+        let after = match get("cursor") {
+            Some(cursor) => {
+                decode_cursor(cursor)?;
+                Some(cursor.to_string())
+            }
+            None => None,
+        };
+
+        let order = get("order")
+            .map(|order| match order {
+                "asc" => Ok(SortOrder::Asc),
+                "desc" => Ok(SortOrder::Desc),
+                other => Err(DbError::Validation {
+                    message: format!("Invalid order '{}'. Expected asc or desc", other),
+                }),
+            })
+            .transpose()?;
+
+        let tags = get("tags").map(|tags| {
+            tags.split(',')
+                .map(str::trim)
+                .filter(|tag| !tag.is_empty())
+                .map(str::to_string)
+                .collect()
+        });
+
+        Ok(Self {
+            limit: limits.apply(limit),
+            offset,
+            after,
+            sort: get("sort").map(str::to_string),
+            order,
+            tags,
+        })
+    }
+}
+
+fn parse_count(name: &str, value: &str) -> DbResult<usize> {
+    value.parse().map_err(|_| DbError::Validation {
+        message: format!(
+            "Invalid {} '{}'. Expected a non-negative integer",
+            name, value
+        ),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::pagination::{LIST_LIMITS, MAX_PAGE_SIZE, encode_cursor};
+
+    fn params(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn parses_valid_params() {
+        let query = ListQuery::from_params(
+            &params(&[
+                ("limit", "20"),
+                ("offset", "40"),
+                ("sort", "title"),
+                ("order", "asc"),
+                ("tags", "rust, api,,"),
+            ]),
+            LIST_LIMITS,
+        )
+        .unwrap();
+
+        assert_eq!(
+            query,
+            ListQuery {
+                limit: Some(20),
+                offset: Some(40),
+                after: None,
+                sort: Some("title".to_string()),
+                order: Some(SortOrder::Asc),
+                tags: Some(vec!["rust".to_string(), "api".to_string()]),
+            }
+        );
+    }
+
+    #[test]
+    fn empty_params_use_policy_default() {
+        let query = ListQuery::from_params(&params(&[("order", "")]), LIST_LIMITS).unwrap();
+        assert_eq!(query, ListQuery::default());
+    }
+
+    #[test]
+    fn rejects_invalid_order() {
+        let err =
+            ListQuery::from_params(&params(&[("order", "sideways")]), LIST_LIMITS).unwrap_err();
+        assert!(matches!(err, DbError::Validation { ref message } if message.contains("sideways")));
+    }
+
+    #[test]
+    fn keeps_valid_cursor_and_rejects_garbage() {
+        let cursor = encode_cursor("created_at", "2025-01-01T00:00:00Z", "a1b2c3d4");
+        let query = ListQuery::from_params(&params(&[("cursor", &cursor)]), LIST_LIMITS).unwrap();
+        assert_eq!(query.after, Some(cursor));
+
+        let err = ListQuery::from_params(&params(&[("cursor", "%%%")]), LIST_LIMITS).unwrap_err();
+        assert!(matches!(err, DbError::Validation { .. }));
+    }
+
+    #[test]
+    fn rejects_non_numeric_limit() {
+        let err = ListQuery::from_params(&params(&[("limit", "ten")]), LIST_LIMITS).unwrap_err();
+        assert!(matches!(err, DbError::Validation { .. }));
+    }
+
+    #[test]
+    fn clamps_over_large_limit() {
+        let query = ListQuery::from_params(&params(&[("limit", "100000")]), LIST_LIMITS).unwrap();
+        assert_eq!(query.limit, Some(MAX_PAGE_SIZE));
+    }
+}
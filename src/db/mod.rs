@@ -6,18 +6,25 @@
 //!
 //! # Architecture
 //!
+//! - `clock`: Injectable time source for timestamps
 //! - `error`: Storage-agnostic error types
+//! - `list_query`: Shared parsing of list query parameters
 //! - `models`: Domain entities (Project, Repo, TaskList, Task, Note)
+//! - `pagination`: Shared list limit defaults and caps
 //! - `repository`: Trait definitions for data access
 //! - `utils`: Database utility functions
 
+pub mod clock;
 mod error;
+pub mod list_query;
 mod models;
+pub mod pagination;
 mod repository;
 pub mod sqlite;
 pub mod utils;
 
 pub use error::{DbError, DbResult};
+pub use list_query::ListQuery;
 pub use models::*;
 pub use repository::*;
 pub use sqlite::SqliteDatabase;
@@ -3,6 +3,7 @@
 //! These models are storage-agnostic and represent the core entities
 //! used throughout the application.
 
+use rmcp::schemars::{self, JsonSchema};
 use serde::{Deserialize, Serialize};
 
 // =============================================================================
@@ -55,6 +56,8 @@ pub struct PageSort {
     pub sort_by: Option<String>,
     /// Sort order (ascending or descending).
     pub sort_order: Option<SortOrder>,
+    /// Cursor from a previous page's `next_cursor`; replaces `offset` when set.
+    pub after: Option<String>,
 }
 
 /// Query for Projects - pagination + tags filter.
@@ -142,13 +145,16 @@ pub struct ListResult<T> {
     pub limit: Option<usize>,
     /// Offset that was applied.
     pub offset: usize,
+    /// Cursor for the next page, when the listing supports keyset paging
+    /// and more rows remain.
+    pub next_cursor: Option<String>,
 }
 
 /// 8-character hex ID type used for all entities.
 pub type Id = String;
 
 /// A project groups related repositories, task lists, and notes.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub struct Project {
     pub id: Id,
     pub title: String,
@@ -170,7 +176,7 @@ pub struct Project {
 }
 
 /// A git repository tracked by the system.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub struct Repo {
     pub id: Id,
     pub remote: String,
@@ -180,10 +186,38 @@ pub struct Repo {
     #[serde(default)]
     pub project_ids: Vec<Id>,
     pub created_at: Option<String>,
+    /// Bumped by every update; sync compares it with a tombstone's `deleted_at`
+    #[serde(default)]
+    pub updated_at: Option<String>,
+}
+
+/// Live projects, task lists and notes linked to a repo.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct RepoDependents {
+    pub project_ids: Vec<Id>,
+    pub task_list_ids: Vec<Id>,
+    pub note_ids: Vec<Id>,
+}
+
+impl RepoDependents {
+    pub fn is_empty(&self) -> bool {
+        self.project_ids.is_empty() && self.task_list_ids.is_empty() && self.note_ids.is_empty()
+    }
+}
+
+/// Snapshot of the storage connection pool.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PoolStats {
+    /// Open connections, idle or in use
+    pub size: u32,
+    /// Open connections not currently in use
+    pub idle: usize,
+    /// Most connections the pool will open
+    pub max_size: u32,
 }
 
 /// A collection of tasks.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub struct TaskList {
     pub id: Id,
     pub title: String,
@@ -204,7 +238,7 @@ pub struct TaskList {
 }
 
 /// Status of a task list.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default, JsonSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum TaskListStatus {
     #[default]
@@ -234,7 +268,7 @@ impl std::str::FromStr for TaskListStatus {
 }
 
 /// An individual work item within a task list.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub struct Task {
     pub id: Id,
     pub list_id: Id,
@@ -242,6 +276,8 @@ pub struct Task {
     pub title: String,
     pub description: Option<String>,
     pub status: TaskStatus,
+    /// 1 (highest) to 5 (lowest)
+    #[schemars(range(min = 1, max = 5))]
     pub priority: Option<i32>,
     pub tags: Vec<String>,
     #[serde(default)]
@@ -251,7 +287,7 @@ pub struct Task {
 }
 
 /// Status of a task.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum TaskStatus {
     #[default]
@@ -293,7 +329,7 @@ impl std::str::FromStr for TaskStatus {
 }
 
 /// Statistics for tasks in a task list, grouped by status.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct TaskStats {
     pub list_id: Id,
     pub total: usize,
@@ -305,6 +341,21 @@ pub struct TaskStats {
     pub cancelled: usize,
 }
 
+/// Task statistics rolled up across all task lists of a project.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProjectTaskStats {
+    pub project_id: Id,
+    /// Number of task lists in the project (active and archived)
+    pub task_lists: usize,
+    pub total: usize,
+    pub backlog: usize,
+    pub todo: usize,
+    pub in_progress: usize,
+    pub review: usize,
+    pub done: usize,
+    pub cancelled: usize,
+}
+
 /// A log entry recording a task state transition.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct TransitionLog {
@@ -315,7 +366,7 @@ pub struct TransitionLog {
 }
 
 /// A persistent markdown note.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub struct Note {
     pub id: Id,
     pub title: String,
@@ -338,12 +389,21 @@ pub struct Note {
     pub updated_at: Option<String>,
 }
 
+/// A note matched by full-text search, with the fragment that matched.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NoteSearchResult {
+    pub note: Note,
+    /// Matched fragment with hits wrapped in `<mark>` tags (note text is not
+    /// HTML-escaped)
+    pub snippet: String,
+}
+
 /// A skill entity following Agent Skills specification (<https://agentskills.io/specification>).
 /// Skills store reusable instructions, scripts, and resources for AI agents.
 ///
 /// The `content` field stores the complete SKILL.md file (YAML frontmatter + Markdown body).
 /// LLMs parse the frontmatter themselves - we only extract name/description for DB indexing.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub struct Skill {
     pub id: Id,
     pub name: String,
@@ -387,6 +447,9 @@ pub struct SkillAttachment {
     pub content_hash: String,
     /// MIME type (e.g., "text/x-shellscript", "image/png")
     pub mime_type: Option<String>,
+    /// Whether the file had an executable bit when ingested
+    #[serde(default)]
+    pub is_executable: bool,
     pub created_at: Option<String>,
     pub updated_at: Option<String>,
 }
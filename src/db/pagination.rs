@@ -0,0 +1,184 @@
+//! Shared list pagination limits.
+//!
+//! REST handlers and MCP tools resolve a caller's `limit` through the same
+//! [`LimitPolicy`] so defaults and caps are defined in one place rather than
+//! per handler. [`paginate_all`] walks every page of a listing for library
+//! consumers that need the full result set. Keyset listings hand out opaque
+//! cursors built by [`encode_cursor`].
+
+use std::future::Future;
+
+use base64::Engine as _;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+
+use super::{DbError, DbResult, ListResult, PageSort};
+
+/// Page size used when the caller does not give a limit.
+pub const DEFAULT_PAGE_SIZE: usize = 20;
+
+/// Largest page any list surface will return in one request.
+pub const MAX_PAGE_SIZE: usize = 100;
+
+/// Default and maximum page size for a list surface.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LimitPolicy {
+    /// Limit used when the caller does not specify one (`None` = unbounded)
+    pub default: Option<usize>,
+    /// Hard cap applied to explicit limits
+    pub max: usize,
+}
+
+impl LimitPolicy {
+    /// Resolve a requested limit against this policy.
+    ///
+    /// An unspecified limit falls back to the default; explicit limits are
+    /// capped at `max`.
+    pub fn apply(&self, requested: Option<usize>) -> Option<usize> {
+        match requested {
+            Some(limit) => Some(limit.min(self.max)),
+            None => self.default,
+        }
+    }
+}
+
+/// Limits shared by the REST list endpoints and the MCP list tools.
+///
+/// Both surfaces page the same way, so an agent and the web UI asking for
+/// the same listing get the same page. Callers that need everything (e.g.
+/// the UI's subtask lists) ask for [`MAX_PAGE_SIZE`] or walk the pages.
+pub const LIST_LIMITS: LimitPolicy = LimitPolicy {
+    default: Some(DEFAULT_PAGE_SIZE),
+    max: MAX_PAGE_SIZE,
+};
+
+/// A keyset position: the sort field, the row's value for it, and its id.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cursor {
+    pub sort_by: String,
+    /// Sort key of the last row on the page (empty for NULL)
+    pub value: String,
+    pub id: String,
+}
+
+/// Encode a `(sort_by, value, id)` keyset position as an opaque cursor.
+///
+/// The sort field travels with the cursor so a page request with a
+/// different sort can be rejected instead of silently skipping rows.
+pub fn encode_cursor(sort_by: &str, value: &str, id: &str) -> String {
+    URL_SAFE_NO_PAD.encode(format!("{}|{}|{}", sort_by, value, id))
+}
+
+/// Decode a cursor produced by [`encode_cursor`].
+pub fn decode_cursor(cursor: &str) -> DbResult<Cursor> {
+    let invalid = || DbError::Validation {
+        message: format!("Invalid cursor '{}'", cursor),
+    };
+
+    let bytes = URL_SAFE_NO_PAD.decode(cursor).map_err(|_| invalid())?;
+    let decoded = String::from_utf8(bytes).map_err(|_| invalid())?;
+    // Field names and ids never contain '|'; the value in between may
+    let (sort_by, rest) = decoded.split_once('|').ok_or_else(invalid)?;
+    let (value, id) = rest.rsplit_once('|').ok_or_else(invalid)?;
+    if sort_by.is_empty() || id.is_empty() {
+        return Err(invalid());
+    }
+
+    Ok(Cursor {
+        sort_by: sort_by.to_string(),
+        value: value.to_string(),
+        id: id.to_string(),
+    })
+}
+
+/// Collect every item of a paginated listing, `page_size` items at a time.
+///
+/// `fetch` receives a [`PageSort`] with `limit` and `offset` filled in; set
+/// `sort_by`/`sort_order` on it before building the entity query. Keep the
+/// sort stable between pages, otherwise offsets can skip or repeat rows.
+/// Stops once `total` items have been collected or a page comes back empty.
+///
+/// ```ignore
+/// let projects = paginate_all(100, |page| {
+///     let repo = &repo;
+///     async move {
+///         repo.list(Some(&ProjectQuery { page, ..Default::default() }))
+///             .await
+///     }
+/// })
+/// .await?;
+/// ```
+pub async fn paginate_all<T, F, Fut>(page_size: usize, mut fetch: F) -> DbResult<Vec<T>>
+where
+    F: FnMut(PageSort) -> Fut,
+    Fut: Future<Output = DbResult<ListResult<T>>>,
+{
+    if page_size == 0 {
+        return Err(DbError::Validation {
+            message: "page_size must be greater than zero".to_string(),
+        });
+    }
+
+    let mut items = Vec::new();
+    loop {
+        let page = fetch(PageSort {
+            limit: Some(page_size),
+            offset: Some(items.len()),
+            ..Default::default()
+        })
+        .await?;
+
+        let fetched = page.items.len();
+        items.extend(page.items);
+
+        if fetched == 0 || items.len() >= page.total {
+            return Ok(items);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_uses_default_when_unspecified() {
+        assert_eq!(LIST_LIMITS.apply(None), Some(DEFAULT_PAGE_SIZE));
+    }
+
+    #[test]
+    fn test_apply_caps_explicit_limits() {
+        assert_eq!(LIST_LIMITS.apply(Some(5)), Some(5));
+        assert_eq!(LIST_LIMITS.apply(Some(100_000)), Some(MAX_PAGE_SIZE));
+    }
+
+    #[test]
+    fn test_cursor_round_trip() {
+        let cursor = encode_cursor("created_at", "2025-01-01T00:00:00Z", "a1b2c3d4");
+        assert_eq!(
+            decode_cursor(&cursor).unwrap(),
+            Cursor {
+                sort_by: "created_at".to_string(),
+                value: "2025-01-01T00:00:00Z".to_string(),
+                id: "a1b2c3d4".to_string(),
+            }
+        );
+
+        // Values may contain the separator or be empty (NULL)
+        let cursor = encode_cursor("title", "a|b", "a1b2c3d4");
+        assert_eq!(decode_cursor(&cursor).unwrap().value, "a|b");
+        let cursor = encode_cursor("priority", "", "a1b2c3d4");
+        assert_eq!(decode_cursor(&cursor).unwrap().value, "");
+    }
+
+    #[test]
+    fn test_decode_rejects_garbage() {
+        assert!(matches!(
+            decode_cursor("not a cursor!"),
+            Err(DbError::Validation { .. })
+        ));
+        assert!(matches!(
+            decode_cursor(&URL_SAFE_NO_PAD.encode("no-separator")),
+            Err(DbError::Validation { .. })
+        ));
+    }
+}
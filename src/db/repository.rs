@@ -9,23 +9,35 @@
 use std::future::Future;
 use std::path::Path;
 
+use crate::db::utils::IdLength;
 use crate::db::{
     DbResult, ListResult, NoteQuery, ProjectQuery, RepoQuery, TaskListQuery, TaskQuery,
-    models::{Note, Project, Repo, Task, TaskList, TaskStats, TaskStatus, TransitionLog},
+    models::{
+        Note, NoteSearchResult, PoolStats, Project, ProjectTaskStats, Repo, RepoDependents, Task,
+        TaskList, TaskStats, TaskStatus, TransitionLog,
+    },
 };
-use crate::sync::{ExportSummary, ImportSummary};
+use crate::sync::{ExportSummary, ImportOptions, ImportSummary, SyncEntity};
 
 /// Repository for Project operations.
 pub trait ProjectRepository: Send + Sync {
     fn create(&self, project: &Project) -> impl Future<Output = DbResult<Project>> + Send;
     fn get(&self, id: &str) -> impl Future<Output = DbResult<Project>> + Send;
+    /// Look up the oldest project whose `external_refs` contain `external_ref`.
+    fn get_by_external_ref(
+        &self,
+        external_ref: &str,
+    ) -> impl Future<Output = DbResult<Project>> + Send;
     fn list(
         &self,
         query: Option<&ProjectQuery>,
     ) -> impl Future<Output = DbResult<ListResult<Project>>> + Send;
     fn count(&self) -> impl Future<Output = DbResult<usize>> + Send;
     fn update(&self, project: &Project) -> impl Future<Output = DbResult<()>> + Send;
+    /// Soft-delete: stamp `deleted_at` so reads no longer see the entity.
     fn delete(&self, id: &str) -> impl Future<Output = DbResult<()>> + Send;
+    /// Remove the row for good, whether or not it was soft-deleted.
+    fn purge(&self, id: &str) -> impl Future<Output = DbResult<()>> + Send;
     fn search(
         &self,
         query: &str,
@@ -37,19 +49,44 @@ pub trait ProjectRepository: Send + Sync {
 pub trait RepoRepository: Send + Sync {
     fn create(&self, repo: &Repo) -> impl Future<Output = DbResult<Repo>> + Send;
     fn get(&self, id: &str) -> impl Future<Output = DbResult<Repo>> + Send;
+    /// Look up a repo by remote, matching any equivalent spelling of it
+    /// (see [`crate::db::utils::normalize_remote`]).
+    fn get_by_remote(&self, remote: &str) -> impl Future<Output = DbResult<Repo>> + Send;
     fn list(
         &self,
         query: Option<&RepoQuery>,
     ) -> impl Future<Output = DbResult<ListResult<Repo>>> + Send;
     fn count(&self) -> impl Future<Output = DbResult<usize>> + Send;
     fn update(&self, repo: &Repo) -> impl Future<Output = DbResult<()>> + Send;
+    /// Projects, task lists and notes (not deleted) that still link to the repo.
+    fn dependents(&self, id: &str) -> impl Future<Output = DbResult<RepoDependents>> + Send;
+    /// Soft-delete: stamp `deleted_at` so reads no longer see the entity.
+    ///
+    /// Unlinks the repo from every project, task list and note in the same
+    /// transaction.
     fn delete(&self, id: &str) -> impl Future<Output = DbResult<()>> + Send;
+    /// Remove the row for good, whether or not it was soft-deleted, along
+    /// with its project, task list and note links.
+    fn purge(&self, id: &str) -> impl Future<Output = DbResult<()>> + Send;
+    /// Find repos whose remote or path contains `search_term` (case-insensitive).
+    ///
+    /// The other filters of `query` still apply.
+    fn search(
+        &self,
+        search_term: &str,
+        query: Option<&RepoQuery>,
+    ) -> impl Future<Output = DbResult<ListResult<Repo>>> + Send;
 }
 
 /// Repository for TaskList operations.
 pub trait TaskListRepository: Send + Sync {
     fn create(&self, task_list: &TaskList) -> impl Future<Output = DbResult<TaskList>> + Send;
     fn get(&self, id: &str) -> impl Future<Output = DbResult<TaskList>> + Send;
+    /// Look up the oldest task list whose `external_refs` contain `external_ref`.
+    fn get_by_external_ref(
+        &self,
+        external_ref: &str,
+    ) -> impl Future<Output = DbResult<TaskList>> + Send;
     fn list(
         &self,
         query: Option<&TaskListQuery>,
@@ -61,12 +98,31 @@ pub trait TaskListRepository: Send + Sync {
         query: Option<&TaskListQuery>,
     ) -> impl Future<Output = DbResult<ListResult<TaskList>>> + Send;
     fn update(&self, task_list: &TaskList) -> impl Future<Output = DbResult<()>> + Send;
+    /// Archive a task list, keeping an existing `archived_at`.
+    ///
+    /// With `complete_open`, every task still in `backlog`, `todo`,
+    /// `in_progress` or `review` is cancelled in the same transaction.
+    /// Returns the IDs of the cancelled tasks.
+    fn archive(
+        &self,
+        id: &str,
+        archived_at: &str,
+        complete_open: bool,
+    ) -> impl Future<Output = DbResult<Vec<String>>> + Send;
+    /// Soft-delete: stamp `deleted_at` so reads no longer see the entity.
     fn delete(&self, id: &str) -> impl Future<Output = DbResult<()>> + Send;
+    /// Remove the row for good, whether or not it was soft-deleted.
+    fn purge(&self, id: &str) -> impl Future<Output = DbResult<()>> + Send;
 }
 
 /// Repository for Task operations.
 pub trait TaskRepository: Send + Sync {
     fn create(&self, task: &Task) -> impl Future<Output = DbResult<Task>> + Send;
+    /// Create several tasks in one transaction, returning them in order.
+    ///
+    /// If any task fails, nothing is created and the error is a
+    /// [`DbError::BatchItem`](crate::db::DbError::BatchItem) naming its index.
+    fn create_many(&self, tasks: &[Task]) -> impl Future<Output = DbResult<Vec<Task>>> + Send;
     fn get(&self, id: &str) -> impl Future<Output = DbResult<Task>> + Send;
     fn list(
         &self,
@@ -78,15 +134,68 @@ pub trait TaskRepository: Send + Sync {
         search_term: &str,
         query: Option<&TaskQuery>,
     ) -> impl Future<Output = DbResult<ListResult<Task>>> + Send;
+    /// Every subtask below the given tasks, at any depth, oldest first.
+    fn list_descendants(
+        &self,
+        parent_ids: &[String],
+    ) -> impl Future<Output = DbResult<Vec<Task>>> + Send;
     fn update(&self, task: &Task) -> impl Future<Output = DbResult<()>> + Send;
+    /// Soft-delete: stamp `deleted_at` so reads no longer see the entity.
     fn delete(&self, id: &str) -> impl Future<Output = DbResult<()>> + Send;
+    /// Remove the row for good, whether or not it was soft-deleted.
+    fn purge(&self, id: &str) -> impl Future<Output = DbResult<()>> + Send;
     fn get_stats_for_list(&self, list_id: &str)
     -> impl Future<Output = DbResult<TaskStats>> + Send;
+    fn get_stats_for_project(
+        &self,
+        project_id: &str,
+    ) -> impl Future<Output = DbResult<ProjectTaskStats>> + Send;
+    /// Per-list task stats for every task list in a project, in one query.
+    ///
+    /// Lists without tasks are included with zero counts.
+    fn get_stats_for_project_lists(
+        &self,
+        project_id: &str,
+    ) -> impl Future<Output = DbResult<Vec<TaskStats>>> + Send;
+    /// Project-level task stats for several projects, in one grouped query.
+    ///
+    /// Unknown or deleted project IDs are left out of the result.
+    fn get_stats_for_projects(
+        &self,
+        project_ids: &[String],
+    ) -> impl Future<Output = DbResult<Vec<ProjectTaskStats>>> + Send;
     fn transition_tasks(
         &self,
         task_ids: &[String],
         target_status: TaskStatus,
     ) -> impl Future<Output = DbResult<Vec<Task>>> + Send;
+    /// Move several tasks under `new_parent` (or to the top level) atomically.
+    ///
+    /// The parent must be a top-level task in the same list as every moved
+    /// task, and none of the moved tasks may be the parent itself or carry
+    /// subtasks of their own. Returns the updated tasks in request order.
+    fn reparent_tasks(
+        &self,
+        task_ids: &[String],
+        new_parent: Option<&str>,
+    ) -> impl Future<Output = DbResult<Vec<Task>>> + Send;
+    /// Complete the ancestors of `task_id` whose subtasks are all finished.
+    ///
+    /// Walks up from the task's parent, marking each ancestor `done` once all of
+    /// its subtasks are `done` or `cancelled`, and stops at the first one that
+    /// still has open work. Returns the ancestors that were completed.
+    fn complete_finished_parents(
+        &self,
+        task_id: &str,
+    ) -> impl Future<Output = DbResult<Vec<Task>>> + Send;
+    /// Mark a task done; with `cascade`, also every open descendant.
+    ///
+    /// Returns the completed task first, followed by any cascaded subtasks.
+    fn complete_task(
+        &self,
+        task_id: &str,
+        cascade: bool,
+    ) -> impl Future<Output = DbResult<Vec<Task>>> + Send;
     fn get_transitions(
         &self,
         task_id: &str,
@@ -100,6 +209,9 @@ pub trait NoteRepository: Send + Sync {
     fn create(&self, note: &Note) -> impl Future<Output = DbResult<Note>> + Send;
     fn get(&self, id: &str) -> impl Future<Output = DbResult<Note>> + Send;
     fn get_metadata_only(&self, id: &str) -> impl Future<Output = DbResult<Note>> + Send;
+    /// Oldest live note whose content is exactly `content`, matched by hash.
+    fn find_by_content(&self, content: &str)
+    -> impl Future<Output = DbResult<Option<Note>>> + Send;
     fn list(
         &self,
         query: Option<&NoteQuery>,
@@ -110,12 +222,25 @@ pub trait NoteRepository: Send + Sync {
         query: Option<&NoteQuery>,
     ) -> impl Future<Output = DbResult<ListResult<Note>>> + Send;
     fn update(&self, note: &Note) -> impl Future<Output = DbResult<()>> + Send;
+    /// Soft-delete: stamp `deleted_at` so reads no longer see the entity.
     fn delete(&self, id: &str) -> impl Future<Output = DbResult<()>> + Send;
+    /// Remove the row for good, whether or not it was soft-deleted.
+    fn purge(&self, id: &str) -> impl Future<Output = DbResult<()>> + Send;
     fn search(
         &self,
         search_term: &str,
         query: Option<&NoteQuery>,
     ) -> impl Future<Output = DbResult<ListResult<Note>>> + Send;
+    /// Full-text search returning a highlighted snippet alongside each note.
+    fn search_snippets(
+        &self,
+        search_term: &str,
+        query: Option<&NoteQuery>,
+    ) -> impl Future<Output = DbResult<ListResult<NoteSearchResult>>> + Send;
+    /// Rebuild the full-text index from the stored notes and merge its segments.
+    ///
+    /// Searches running at the same time wait for it rather than failing.
+    fn rebuild_search_index(&self) -> impl Future<Output = DbResult<()>> + Send;
     /// Get specific line ranges from a note.
     /// Ranges are tuples of (start_line, end_line) where lines are 1-indexed.
     /// Ranges will be sorted and validated for overlap before processing.
@@ -177,10 +302,60 @@ pub trait SkillRepository: Send + Sync {
 
 /// Repository for sync operations (import/export).
 pub trait SyncRepository: Send + Sync {
+    /// Export gzip-compressed `.jsonl.gz` files instead of plain `.jsonl`.
+    ///
+    /// Imports read either format regardless, preferring `.jsonl.gz` when both exist.
+    fn with_compression(self, compress: bool) -> Self
+    where
+        Self: Sized;
+
     fn import_all(&self, input_dir: &Path) -> impl Future<Output = DbResult<ImportSummary>> + Send;
 
     fn export_all(&self, output_dir: &Path)
     -> impl Future<Output = DbResult<ExportSummary>> + Send;
+
+    /// Import only the given entity types; files of other types are not read.
+    ///
+    /// Records that changed on both sides since `options.base` are resolved
+    /// by `options.strategy`; skipped ones are reported in the summary.
+    fn import_entities(
+        &self,
+        input_dir: &Path,
+        entities: &[SyncEntity],
+        options: &ImportOptions,
+    ) -> impl Future<Output = DbResult<ImportSummary>> + Send;
+
+    /// Export only the given entity types; files of other types are left untouched.
+    fn export_entities(
+        &self,
+        output_dir: &Path,
+        entities: &[SyncEntity],
+    ) -> impl Future<Output = DbResult<ExportSummary>> + Send;
+
+    /// Like [`export_entities`](Self::export_entities), but only rewrites records
+    /// updated at or after `since` (or missing from the existing files).
+    ///
+    /// Other records keep their existing JSONL lines; records deleted from the
+    /// database are dropped from the files.
+    fn export_entities_since(
+        &self,
+        output_dir: &Path,
+        entities: &[SyncEntity],
+        since: &str,
+    ) -> impl Future<Output = DbResult<ExportSummary>> + Send;
+
+    /// Export the given entity types, limited to one project's data.
+    ///
+    /// Writes the project itself, its task lists with their tasks and
+    /// transitions, and the repos, notes and skills linked to it. With
+    /// `since`, behaves like [`export_entities_since`](Self::export_entities_since).
+    fn export_project_entities(
+        &self,
+        output_dir: &Path,
+        entities: &[SyncEntity],
+        project_id: &str,
+        since: Option<&str>,
+    ) -> impl Future<Output = DbResult<ExportSummary>> + Send;
 }
 
 /// Combined database interface.
@@ -223,10 +398,29 @@ pub trait Database: Send + Sync {
     type TransitionLogs<'a>
     where
         Self: 'a;
+    /// Backend connection handle handed to [`Database::with_connection`].
+    type Connection: Clone + Send + Sync + 'static;
 
     /// Run pending migrations.
     fn migrate(&self) -> DbResult<()>;
 
+    /// Run arbitrary queries against the underlying storage.
+    ///
+    /// Intended for tests and tooling that need raw access (e.g. seeding rows
+    /// with fixed timestamps). The closure receives the same pooled handle the
+    /// repositories use, so it shares their connection limits and locking.
+    fn with_connection<F, Fut, T>(&self, f: F) -> impl Future<Output = DbResult<T>> + Send
+    where
+        F: FnOnce(Self::Connection) -> Fut + Send,
+        Fut: Future<Output = DbResult<T>> + Send,
+        T: Send;
+
+    /// Current connection pool usage, for metrics.
+    fn pool_stats(&self) -> PoolStats;
+
+    /// Length of the IDs repositories generate for new entities.
+    fn id_length(&self) -> IdLength;
+
     /// Get the project repository.
     fn projects(&self) -> Self::Projects<'_>;
 
@@ -1,19 +1,37 @@
 //! SQLite database connection and migration management.
 
+use sqlx::sqlite::{SqliteConnectOptions, SqliteJournalMode};
 use sqlx::{SqlitePool, migrate::MigrateDatabase};
+use std::future::Future;
 use std::path::Path;
+use std::str::FromStr;
+
+use super::helpers::BUSY_TIMEOUT;
+use super::schema::{SchemaMismatch, verify_schema};
 
 use super::{
     SqliteNoteRepository, SqliteProjectRepository, SqliteRepoRepository, SqliteSyncRepository,
     SqliteTaskListRepository, SqliteTaskRepository, SqliteTransitionLogRepository,
 };
-use crate::db::{Database, DbError, DbResult};
+use crate::db::utils::{IdLength, content_hash, normalize_remote};
+use crate::db::{Database, DbError, DbResult, PoolStats};
+
+/// A repo whose normalized remote matches a repo that already holds the key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct RemoteKeyCollision {
+    /// The repo left without a remote key.
+    pub repo_id: String,
+    /// The repo that already holds the key.
+    pub existing_id: String,
+    pub remote_key: String,
+}
 
 /// SQLite database implementation using SQLx.
 ///
 /// Provides async access to repositories via associated types, avoiding dynamic dispatch.
 pub struct SqliteDatabase {
     pool: SqlitePool,
+    id_length: IdLength,
 }
 
 impl SqliteDatabase {
@@ -35,13 +53,25 @@ impl SqliteDatabase {
                 })?;
         }
 
-        let pool = SqlitePool::connect(&database_url)
+        // WAL lets searches read while a write (such as an index rebuild) is
+        // in progress; writers queue on the busy timeout instead of failing
+        let options = SqliteConnectOptions::from_str(&database_url)
+            .map_err(|e| DbError::Connection {
+                message: e.to_string(),
+            })?
+            .journal_mode(SqliteJournalMode::Wal)
+            .busy_timeout(BUSY_TIMEOUT);
+
+        let pool = SqlitePool::connect_with(options)
             .await
             .map_err(|e| DbError::Connection {
                 message: e.to_string(),
             })?;
 
-        Ok(Self { pool })
+        Ok(Self {
+            pool,
+            id_length: IdLength::default(),
+        })
     }
 
     /// Create an in-memory database (useful for testing).
@@ -52,7 +82,18 @@ impl SqliteDatabase {
                 .map_err(|e| DbError::Connection {
                     message: e.to_string(),
                 })?;
-        Ok(Self { pool })
+        Ok(Self {
+            pool,
+            id_length: IdLength::default(),
+        })
+    }
+
+    /// Generate IDs of `id_length` hex characters for new entities.
+    ///
+    /// Existing IDs of any length still resolve; only new IDs are affected.
+    pub fn with_id_length(mut self, id_length: IdLength) -> Self {
+        self.id_length = id_length;
+        self
     }
 
     /// Get a reference to the connection pool.
@@ -67,6 +108,9 @@ impl SqliteDatabase {
     ///
     /// This is the async version of migrate() for use when async context is available.
     pub async fn migrate_async(&self) -> DbResult<()> {
+        require_fts5(fts5_available(&self.pool).await?)?;
+        tracing::info!(search = "fts5", "SQLite full-text search available");
+
         sqlx::migrate!("data/sql/sqlite/migrations")
             .run(&self.pool)
             .await
@@ -74,10 +118,143 @@ impl SqliteDatabase {
                 message: e.to_string(),
             })?;
 
+        self.backfill_remote_keys().await?;
+        self.backfill_note_content_hashes().await
+    }
+
+    /// Compare the live schema against the columns the models expect.
+    ///
+    /// Returns an empty list when the schema matches. Does not migrate, so it
+    /// can be pointed at a database before deciding whether to upgrade it.
+    pub async fn verify_schema(&self) -> DbResult<Vec<SchemaMismatch>> {
+        verify_schema(&self.pool).await
+    }
+
+    /// Fill `repo.remote_key` for rows written before the column existed.
+    ///
+    /// Rows whose key collides with an existing repo are left without a key
+    /// rather than failing startup; each collision is logged and returned so
+    /// the pair can be merged manually.
+    pub(crate) async fn backfill_remote_keys(&self) -> DbResult<Vec<RemoteKeyCollision>> {
+        let rows: Vec<(String, String)> =
+            sqlx::query_as("SELECT id, remote FROM repo WHERE remote_key IS NULL")
+                .fetch_all(&self.pool)
+                .await
+                .map_err(|e| DbError::Migration {
+                    message: e.to_string(),
+                })?;
+
+        let mut collisions = Vec::new();
+        for (id, remote) in rows {
+            let remote_key = normalize_remote(&remote);
+            let result = sqlx::query("UPDATE OR IGNORE repo SET remote_key = ? WHERE id = ?")
+                .bind(&remote_key)
+                .bind(&id)
+                .execute(&self.pool)
+                .await
+                .map_err(|e| DbError::Migration {
+                    message: e.to_string(),
+                })?;
+            if result.rows_affected() > 0 {
+                continue;
+            }
+
+            let existing_id: String =
+                sqlx::query_scalar("SELECT id FROM repo WHERE remote_key = ?")
+                    .bind(&remote_key)
+                    .fetch_one(&self.pool)
+                    .await
+                    .map_err(|e| DbError::Migration {
+                        message: e.to_string(),
+                    })?;
+            tracing::warn!(
+                repo_id = %id,
+                existing_id = %existing_id,
+                remote_key = %remote_key,
+                "Repo remote collides with an existing repo; left without a remote key"
+            );
+            collisions.push(RemoteKeyCollision {
+                repo_id: id,
+                existing_id,
+                remote_key,
+            });
+        }
+
+        Ok(collisions)
+    }
+
+    /// Fill `note.content_hash` for rows written before the column existed.
+    async fn backfill_note_content_hashes(&self) -> DbResult<()> {
+        let rows: Vec<(String, String)> =
+            sqlx::query_as("SELECT id, content FROM note WHERE content_hash IS NULL")
+                .fetch_all(&self.pool)
+                .await
+                .map_err(|e| DbError::Migration {
+                    message: e.to_string(),
+                })?;
+
+        for (id, content) in rows {
+            sqlx::query("UPDATE note SET content_hash = ? WHERE id = ?")
+                .bind(content_hash(&content))
+                .bind(&id)
+                .execute(&self.pool)
+                .await
+                .map_err(|e| DbError::Migration {
+                    message: e.to_string(),
+                })?;
+        }
+
         Ok(())
     }
 }
 
+/// Probe whether the linked SQLite provides the FTS5 module.
+///
+/// Uses a throwaway temp table rather than `sqlite_compileoption_used` so an
+/// FTS5 loaded as an extension is detected too.
+pub(super) async fn fts5_available(pool: &SqlitePool) -> DbResult<bool> {
+    let mut conn = pool.acquire().await.map_err(|e| DbError::Connection {
+        message: e.to_string(),
+    })?;
+
+    match sqlx::query("CREATE VIRTUAL TABLE temp.c5t_fts5_probe USING fts5(content)")
+        .execute(&mut *conn)
+        .await
+    {
+        Ok(_) => {
+            sqlx::query("DROP TABLE temp.c5t_fts5_probe")
+                .execute(&mut *conn)
+                .await
+                .map_err(|e| DbError::Database {
+                    message: e.to_string(),
+                })?;
+            Ok(true)
+        }
+        Err(e) if e.to_string().contains("no such module") => Ok(false),
+        Err(e) => Err(DbError::Database {
+            message: e.to_string(),
+        }),
+    }
+}
+
+/// Fail fast when FTS5 is missing.
+///
+/// Every search index is an FTS5 virtual table created by the migrations, so
+/// without it the schema cannot be built; say so instead of surfacing an
+/// obscure migration error.
+pub(super) fn require_fts5(available: bool) -> DbResult<()> {
+    if available {
+        return Ok(());
+    }
+
+    tracing::error!(search = "unavailable", "SQLite was built without FTS5");
+    Err(DbError::Migration {
+        message: "SQLite FTS5 is not available. c5t's search indexes require it; \
+                  use a build linked against SQLite with FTS5 enabled (the bundled SQLite includes it)"
+            .to_string(),
+    })
+}
+
 impl Database for SqliteDatabase {
     type Projects<'a> = SqliteProjectRepository<'a>;
     type Repos<'a> = SqliteRepoRepository<'a>;
@@ -87,6 +264,7 @@ impl Database for SqliteDatabase {
     type Sync<'a> = SqliteSyncRepository<'a>;
     type Skills<'a> = super::SqliteSkillRepository<'a>;
     type TransitionLogs<'a> = SqliteTransitionLogRepository<'a>;
+    type Connection = SqlitePool;
 
     fn migrate(&self) -> DbResult<()> {
         // Use tokio::task::block_in_place for sync interface compatibility
@@ -95,35 +273,81 @@ impl Database for SqliteDatabase {
         })
     }
 
+    async fn with_connection<F, Fut, T>(&self, f: F) -> DbResult<T>
+    where
+        F: FnOnce(Self::Connection) -> Fut + Send,
+        Fut: Future<Output = DbResult<T>> + Send,
+        T: Send,
+    {
+        // SqlitePool is a cheap handle onto the shared pool
+        f(self.pool.clone()).await
+    }
+
+    fn pool_stats(&self) -> PoolStats {
+        PoolStats {
+            size: self.pool.size(),
+            idle: self.pool.num_idle(),
+            max_size: self.pool.options().get_max_connections(),
+        }
+    }
+
+    fn id_length(&self) -> IdLength {
+        self.id_length
+    }
+
     fn projects(&self) -> Self::Projects<'_> {
-        SqliteProjectRepository { pool: &self.pool }
+        SqliteProjectRepository {
+            pool: &self.pool,
+            id_length: self.id_length,
+        }
     }
 
     fn repos(&self) -> Self::Repos<'_> {
-        SqliteRepoRepository { pool: &self.pool }
+        SqliteRepoRepository {
+            pool: &self.pool,
+            id_length: self.id_length,
+        }
     }
 
     fn task_lists(&self) -> Self::TaskLists<'_> {
-        SqliteTaskListRepository { pool: &self.pool }
+        SqliteTaskListRepository {
+            pool: &self.pool,
+            id_length: self.id_length,
+        }
     }
 
     fn tasks(&self) -> Self::Tasks<'_> {
-        SqliteTaskRepository { pool: &self.pool }
+        SqliteTaskRepository {
+            pool: &self.pool,
+            id_length: self.id_length,
+        }
     }
 
     fn notes(&self) -> Self::Notes<'_> {
-        SqliteNoteRepository { pool: &self.pool }
+        SqliteNoteRepository {
+            pool: &self.pool,
+            id_length: self.id_length,
+        }
     }
 
     fn sync(&self) -> Self::Sync<'_> {
-        SqliteSyncRepository { pool: &self.pool }
+        SqliteSyncRepository {
+            pool: &self.pool,
+            compress: false,
+        }
     }
 
     fn skills(&self) -> Self::Skills<'_> {
-        super::SqliteSkillRepository { pool: &self.pool }
+        super::SqliteSkillRepository {
+            pool: &self.pool,
+            id_length: self.id_length,
+        }
     }
 
     fn transition_logs(&self) -> Self::TransitionLogs<'_> {
-        SqliteTransitionLogRepository { pool: &self.pool }
+        SqliteTransitionLogRepository {
+            pool: &self.pool,
+            id_length: self.id_length,
+        }
     }
 }
@@ -1,6 +1,7 @@
 //! Tests for SQLite database connection and migrations.
 
-use crate::db::{Database, SqliteDatabase};
+use super::connection::{fts5_available, require_fts5};
+use crate::db::{Database, DbError, SqliteDatabase};
 
 #[tokio::test(flavor = "multi_thread")]
 async fn migrate_creates_all_tables() {
@@ -108,3 +109,310 @@ async fn migrate_creates_fts_table() {
 
     assert!(fts_exists, "note_fts FTS table should exist");
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn with_connection_runs_raw_queries_on_shared_pool() {
+    use crate::db::{DbError, ProjectRepository};
+
+    let db = SqliteDatabase::in_memory()
+        .await
+        .expect("Failed to create in-memory database");
+    db.migrate().expect("Migration should succeed");
+
+    db.with_connection(|pool| async move {
+        sqlx::query("INSERT INTO project (id, title, created_at) VALUES (?, ?, ?)")
+            .bind("rawproj1")
+            .bind("Raw project")
+            .bind("2024-01-01 00:00:00")
+            .execute(&pool)
+            .await
+            .map_err(|e| DbError::Database {
+                message: e.to_string(),
+            })?;
+        Ok(())
+    })
+    .await
+    .expect("Insert should succeed");
+
+    let title: String = db
+        .with_connection(|pool| async move {
+            sqlx::query_scalar("SELECT title FROM project WHERE id = ?")
+                .bind("rawproj1")
+                .fetch_one(&pool)
+                .await
+                .map_err(|e| DbError::Database {
+                    message: e.to_string(),
+                })
+        })
+        .await
+        .expect("Select should succeed");
+    assert_eq!(title, "Raw project");
+
+    // Row is visible through the repositories as well
+    let project = db
+        .projects()
+        .get("rawproj1")
+        .await
+        .expect("Project should exist");
+    assert_eq!(project.created_at.as_deref(), Some("2024-01-01 00:00:00"));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn fts5_probe_detects_bundled_sqlite() {
+    let db = SqliteDatabase::in_memory()
+        .await
+        .expect("Failed to create in-memory database");
+
+    assert!(fts5_available(db.pool()).await.unwrap());
+    // The probe cleans up after itself, so it can run again
+    assert!(fts5_available(db.pool()).await.unwrap());
+}
+
+#[test]
+fn missing_fts5_fails_fast_with_clear_error() {
+    assert!(require_fts5(true).is_ok());
+
+    match require_fts5(false) {
+        Err(DbError::Migration { message }) => assert!(message.contains("FTS5")),
+        other => panic!("expected a migration error, got {:?}", other),
+    }
+}
+
+fn project_titled(id: &str, title: &str) -> crate::db::Project {
+    crate::db::Project {
+        id: id.to_string(),
+        title: title.to_string(),
+        description: None,
+        tags: vec![],
+        external_refs: vec![],
+        repo_ids: vec![],
+        task_list_ids: vec![],
+        note_ids: vec![],
+        created_at: None,
+        updated_at: None,
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn configured_id_length_applies_to_new_entities() {
+    use crate::db::utils::IdLength;
+    use crate::db::{Note, NoteRepository, ProjectRepository};
+
+    let db = SqliteDatabase::in_memory()
+        .await
+        .expect("Failed to create in-memory database")
+        .with_id_length(IdLength::new(16).unwrap());
+    db.migrate().expect("Migration should succeed");
+    assert_eq!(db.id_length().get(), 16);
+
+    let project = db
+        .projects()
+        .create(&project_titled("", "Long IDs"))
+        .await
+        .expect("Create should succeed");
+    assert_eq!(project.id.len(), 16);
+
+    let note = db
+        .notes()
+        .create(&Note {
+            id: String::new(),
+            title: "Long ID note".to_string(),
+            content: "content".to_string(),
+            tags: vec![],
+            parent_id: None,
+            idx: None,
+            repo_ids: vec![],
+            project_ids: vec![],
+            subnote_count: None,
+            created_at: None,
+            updated_at: None,
+        })
+        .await
+        .expect("Create should succeed");
+    assert_eq!(note.id.len(), 16);
+
+    // IDs minted before the length was raised still resolve
+    db.projects()
+        .create(&project_titled("abcd1234", "Legacy"))
+        .await
+        .expect("Create with a legacy ID should succeed");
+    let legacy = db
+        .projects()
+        .get("abcd1234")
+        .await
+        .expect("Legacy ID should resolve");
+    assert_eq!(legacy.title, "Legacy");
+
+    let fetched = db.projects().get(&project.id).await.unwrap();
+    assert_eq!(fetched.title, "Long IDs");
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn backfill_reports_remote_key_collisions() {
+    use super::connection::RemoteKeyCollision;
+
+    let db = SqliteDatabase::in_memory()
+        .await
+        .expect("Failed to create in-memory database");
+    db.migrate().expect("Migration should succeed");
+
+    // Two spellings of one remote, stored before remote_key was filled
+    for (id, remote) in [
+        ("repoaaaa", "https://github.com/user/repo"),
+        ("repobbbb", "git@github.com:user/repo.git"),
+    ] {
+        sqlx::query("INSERT INTO repo (id, remote, tags, created_at) VALUES (?, ?, '[]', ?)")
+            .bind(id)
+            .bind(remote)
+            .bind("2024-01-01 00:00:00")
+            .execute(db.pool())
+            .await
+            .expect("Insert should succeed");
+    }
+
+    let collisions = db
+        .backfill_remote_keys()
+        .await
+        .expect("Backfill should succeed");
+    assert_eq!(
+        collisions,
+        vec![RemoteKeyCollision {
+            repo_id: "repobbbb".to_string(),
+            existing_id: "repoaaaa".to_string(),
+            remote_key: "github.com/user/repo".to_string(),
+        }]
+    );
+
+    let keyless: Vec<String> = sqlx::query_scalar("SELECT id FROM repo WHERE remote_key IS NULL")
+        .fetch_all(db.pool())
+        .await
+        .expect("Query should succeed");
+    assert_eq!(keyless, vec!["repobbbb".to_string()]);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn relax_id_length_migration_keeps_existing_data() {
+    use crate::db::NoteRepository;
+    use sqlx::migrate::Migrator;
+
+    // The migration that rebuilds every table to relax its ID checks
+    const RELAX_ID_LENGTH: i64 = 20260901000000;
+    const TABLES: [&str; 10] = [
+        "project",
+        "repo",
+        "task_list",
+        "task",
+        "task_transition_log",
+        "note",
+        "project_repo",
+        "project_note",
+        "task_list_repo",
+        "note_repo",
+    ];
+
+    let db = SqliteDatabase::in_memory()
+        .await
+        .expect("Failed to create in-memory database");
+
+    // Bring the database to the schema just before the rebuild
+    let all = sqlx::migrate!("data/sql/sqlite/migrations");
+    let previous = Migrator {
+        migrations: all
+            .iter()
+            .filter(|migration| migration.version < RELAX_ID_LENGTH)
+            .cloned()
+            .collect::<Vec<_>>()
+            .into(),
+        ..Migrator::DEFAULT
+    };
+    previous
+        .run(db.pool())
+        .await
+        .expect("Earlier migrations should succeed");
+
+    sqlx::raw_sql(
+        "INSERT INTO project (id, title, created_at, updated_at) VALUES
+            ('proj0001', 'Kept', '2025-01-01 00:00:00', '2025-01-01 00:00:00'),
+            ('proj0002', 'Gone', '2025-01-01 00:00:00', '2025-01-01 00:00:00');
+        UPDATE project SET deleted_at = '2025-02-01 00:00:00' WHERE id = 'proj0002';
+        INSERT INTO repo (id, remote, tags, created_at, deleted_at) VALUES
+            ('repo0001', 'github:user/kept', '[]', '2025-01-01 00:00:00', NULL),
+            ('repo0002', 'github:user/gone', '[]', '2025-01-01 00:00:00', '2025-02-01 00:00:00');
+        INSERT INTO task_list (id, title, project_id, created_at, updated_at) VALUES
+            ('list0001', 'Work', 'proj0001', '2025-01-01 00:00:00', '2025-01-01 00:00:00');
+        INSERT INTO task (id, list_id, parent_id, title, status, created_at, updated_at, deleted_at) VALUES
+            ('task0001', 'list0001', NULL, 'Parent', 'todo', '2025-01-01 00:00:00', '2025-01-01 00:00:00', NULL),
+            ('task0002', 'list0001', 'task0001', 'Child', 'done', '2025-01-01 00:00:00', '2025-01-01 00:00:00', NULL),
+            ('task0003', 'list0001', NULL, 'Dropped', 'todo', '2025-01-01 00:00:00', '2025-01-01 00:00:00', '2025-02-01 00:00:00');
+        INSERT INTO task_transition_log (id, task_id, status, transitioned_at) VALUES
+            ('tran0001', 'task0001', 'todo', '2025-01-01 00:00:00'),
+            ('tran0002', 'task0002', 'done', '2025-01-01 00:00:00');
+        INSERT INTO note (id, title, content, tags, created_at, updated_at) VALUES
+            ('note0001', 'Zebra facts', 'Stripes are unique', '[]', '2025-01-01 00:00:00', '2025-01-01 00:00:00'),
+            ('note0002', 'Scratch', 'Removed before the upgrade', '[]', '2025-01-01 00:00:00', '2025-01-01 00:00:00'),
+            ('note0003', 'Old zebra', 'Deleted zebra note', '[]', '2025-01-01 00:00:00', '2025-01-01 00:00:00');
+        INSERT INTO note (id, title, content, tags, parent_id, idx, created_at, updated_at) VALUES
+            ('note0004', 'Zebra habitat', 'Savanna', '[]', 'note0001', 0, '2025-01-01 00:00:00', '2025-01-01 00:00:00');
+        DELETE FROM note WHERE id = 'note0002';
+        UPDATE note SET deleted_at = '2025-02-01 00:00:00' WHERE id = 'note0003';
+        INSERT INTO project_repo (project_id, repo_id) VALUES ('proj0001', 'repo0001'), ('proj0002', 'repo0002');
+        INSERT INTO project_note (project_id, note_id) VALUES ('proj0001', 'note0001'), ('proj0001', 'note0003');
+        INSERT INTO task_list_repo (task_list_id, repo_id) VALUES ('list0001', 'repo0001');
+        INSERT INTO note_repo (note_id, repo_id) VALUES ('note0001', 'repo0001'), ('note0003', 'repo0002');",
+    )
+    .execute(db.pool())
+    .await
+    .expect("Seeding the previous schema should succeed");
+
+    let counts = |db: &SqliteDatabase| {
+        let pool = db.pool().clone();
+        async move {
+            let mut counts = Vec::new();
+            for table in TABLES {
+                let count: i64 = sqlx::query_scalar(&format!("SELECT COUNT(*) FROM {}", table))
+                    .fetch_one(&pool)
+                    .await
+                    .unwrap();
+                counts.push((table, count));
+            }
+            counts
+        }
+    };
+    let before = counts(&db).await;
+
+    db.migrate_async().await.expect("Upgrade should succeed");
+
+    // Every row survives, tombstones included
+    assert_eq!(counts(&db).await, before);
+    let tombstones: i64 = sqlx::query_scalar(
+        "SELECT (SELECT COUNT(*) FROM project WHERE deleted_at IS NOT NULL)
+              + (SELECT COUNT(*) FROM repo WHERE deleted_at IS NOT NULL)
+              + (SELECT COUNT(*) FROM task WHERE deleted_at IS NOT NULL)
+              + (SELECT COUNT(*) FROM note WHERE deleted_at IS NOT NULL)",
+    )
+    .fetch_one(db.pool())
+    .await
+    .unwrap();
+    assert_eq!(tombstones, 4);
+
+    // The drops with foreign keys off left no dangling references
+    let violations = sqlx::query("PRAGMA foreign_key_check")
+        .fetch_all(db.pool())
+        .await
+        .unwrap();
+    assert!(
+        violations.is_empty(),
+        "{} foreign key violations",
+        violations.len()
+    );
+
+    // The search index still lines up with the notes' rowids
+    sqlx::query("INSERT INTO note_fts(note_fts) VALUES ('integrity-check')")
+        .execute(db.pool())
+        .await
+        .expect("FTS index should match the note table");
+    let hits = db.notes().search("zebra", None).await.unwrap();
+    let mut ids: Vec<String> = hits.items.into_iter().map(|note| note.id).collect();
+    ids.sort();
+    assert_eq!(ids, ["note0001", "note0004"]);
+}
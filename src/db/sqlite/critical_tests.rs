@@ -1,7 +1,8 @@
 //! Critical integration tests for relationship handling.
 
 use crate::db::{
-    Database, SqliteDatabase, TaskList, TaskListQuery, TaskListRepository, TaskListStatus,
+    Database, DbError, DbResult, Note, NoteRepository, Project, ProjectRepository, Repo,
+    RepoRepository, SqliteDatabase, TaskList, TaskListQuery, TaskListRepository, TaskListStatus,
 };
 
 async fn setup_db() -> SqliteDatabase {
@@ -177,6 +178,77 @@ async fn task_list_update_replaces_relationships() {
     assert_eq!(retrieved.project_id, "proj0002");
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn task_list_update_rolls_back_on_relationship_failure() {
+    let db = setup_db().await;
+    let task_lists = db.task_lists();
+
+    sqlx::query("INSERT INTO repo (id, remote, path, tags, created_at) VALUES (?, ?, ?, ?, ?)")
+        .bind("repo0001")
+        .bind("https://github.com/test/repo1")
+        .bind(None::<String>)
+        .bind("[]")
+        .bind("2025-01-01 00:00:00")
+        .execute(db.pool())
+        .await
+        .expect("Insert repo1 should succeed");
+
+    sqlx::query("INSERT INTO project (id, title, description, tags, created_at, updated_at) VALUES (?, ?, ?, ?, ?, ?)")
+        .bind("proj0002")
+        .bind("Project 2")
+        .bind(None::<String>)
+        .bind("[]")
+        .bind("2025-01-01 00:00:00")
+        .bind("2025-01-01 00:00:00")
+        .execute(db.pool())
+        .await
+        .expect("Insert project2 should succeed");
+
+    let original = task_lists
+        .create(&TaskList {
+            id: "listrbk1".to_string(),
+            title: "Original".to_string(),
+            description: None,
+            notes: None,
+            tags: vec![],
+            external_refs: vec![],
+            status: TaskListStatus::Active,
+            repo_ids: vec!["repo0001".to_string()],
+            project_id: "test0000".to_string(),
+            created_at: Some("2025-01-01 00:00:00".to_string()),
+            updated_at: Some("2025-01-01 00:00:00".to_string()),
+            archived_at: None,
+        })
+        .await
+        .expect("Create should succeed");
+
+    // Valid project change and valid title change, but one repo link is bogus
+    let result = task_lists
+        .update(&TaskList {
+            title: "Changed".to_string(),
+            repo_ids: vec!["repo0001".to_string(), "nonexist".to_string()],
+            project_id: "proj0002".to_string(),
+            updated_at: Some("2025-01-01 00:00:01".to_string()),
+            ..original.clone()
+        })
+        .await;
+
+    assert!(
+        matches!(result, Err(DbError::NotFound { ref entity_type, .. }) if entity_type == "Repo"),
+        "Update with missing repo should fail, got {:?}",
+        result
+    );
+
+    let retrieved = task_lists
+        .get("listrbk1")
+        .await
+        .expect("Get should succeed");
+    assert_eq!(retrieved.title, "Original");
+    assert_eq!(retrieved.project_id, "test0000");
+    assert_eq!(retrieved.repo_ids, vec!["repo0001".to_string()]);
+    assert_eq!(retrieved.updated_at, original.updated_at);
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn task_list_create_validates_repo_ids() {
     let db = setup_db().await;
@@ -575,3 +647,90 @@ async fn task_list_can_change_project() {
         .expect("Get should succeed");
     assert_eq!(retrieved.project_id, "proj0002");
 }
+
+fn assert_not_found<T: std::fmt::Debug>(result: DbResult<T>, entity_type: &str, op: &str) {
+    match result {
+        Err(DbError::NotFound { entity_type: e, id }) => {
+            assert_eq!(e, entity_type, "{} reported wrong entity type", op);
+            assert_eq!(id, "missing1", "{} reported wrong id", op);
+        }
+        other => panic!("{} {} should be NotFound, got {:?}", entity_type, op, other),
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn missing_rows_map_to_not_found_variant() {
+    let db = setup_db().await;
+
+    let project = Project {
+        id: "missing1".to_string(),
+        title: "Ghost".to_string(),
+        description: None,
+        tags: vec![],
+        external_refs: vec![],
+        repo_ids: vec![],
+        task_list_ids: vec![],
+        note_ids: vec![],
+        created_at: None,
+        updated_at: None,
+    };
+    assert_not_found(db.projects().get("missing1").await, "Project", "get");
+    assert_not_found(db.projects().update(&project).await, "Project", "update");
+    assert_not_found(db.projects().delete("missing1").await, "Project", "delete");
+
+    let repo = Repo {
+        id: "missing1".to_string(),
+        remote: "https://github.com/ghost/repo".to_string(),
+        path: None,
+        tags: vec![],
+        project_ids: vec![],
+        created_at: None,
+        updated_at: None,
+    };
+    assert_not_found(db.repos().get("missing1").await, "Repo", "get");
+    assert_not_found(db.repos().update(&repo).await, "Repo", "update");
+    assert_not_found(db.repos().delete("missing1").await, "Repo", "delete");
+
+    let note = Note {
+        id: "missing1".to_string(),
+        title: "Ghost".to_string(),
+        content: "Nothing here".to_string(),
+        tags: vec![],
+        parent_id: None,
+        idx: None,
+        repo_ids: vec![],
+        project_ids: vec![],
+        subnote_count: None,
+        created_at: None,
+        updated_at: None,
+    };
+    assert_not_found(db.notes().get("missing1").await, "Note", "get");
+    assert_not_found(db.notes().update(&note).await, "Note", "update");
+    assert_not_found(db.notes().delete("missing1").await, "Note", "delete");
+
+    let task_list = TaskList {
+        id: "missing1".to_string(),
+        title: "Ghost".to_string(),
+        description: None,
+        notes: None,
+        tags: vec![],
+        external_refs: vec![],
+        status: TaskListStatus::Active,
+        repo_ids: vec![],
+        project_id: "test0000".to_string(),
+        created_at: None,
+        updated_at: None,
+        archived_at: None,
+    };
+    assert_not_found(db.task_lists().get("missing1").await, "TaskList", "get");
+    assert_not_found(
+        db.task_lists().update(&task_list).await,
+        "TaskList",
+        "update",
+    );
+    assert_not_found(
+        db.task_lists().delete("missing1").await,
+        "TaskList",
+        "delete",
+    );
+}
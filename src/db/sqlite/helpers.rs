@@ -1,6 +1,51 @@
 //! Shared helper functions for SQLite repositories.
 
-use crate::db::{PageSort, SortOrder};
+use sqlx::sqlite::{SqliteQueryResult, SqliteRow};
+use sqlx::{Row, SqliteConnection, SqlitePool};
+use std::future::Future;
+use std::time::Duration;
+use tokio::time::Instant;
+
+use crate::db::{DbError, DbResult, PageSort, SortOrder};
+
+/// How long a statement waits on a locked database before SQLite gives up
+/// with `SQLITE_BUSY`. Also bounds [`retry_busy`].
+pub const BUSY_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Whether `error` is SQLite reporting the database busy (`SQLITE_BUSY` or
+/// one of its extended codes).
+pub fn is_busy(error: &sqlx::Error) -> bool {
+    error
+        .as_database_error()
+        .and_then(|e| e.code())
+        .and_then(|code| code.parse::<i32>().ok())
+        .is_some_and(|code| code & 0xff == 5)
+}
+
+/// Run `op`, retrying while SQLite reports the database busy, for up to
+/// [`BUSY_TIMEOUT`] in total.
+///
+/// The busy timeout already covers waiting on locks, but some conflicts
+/// return `SQLITE_BUSY` straight away, e.g. a read snapshot that a concurrent
+/// FTS rebuild or WAL checkpoint has invalidated.
+pub async fn retry_busy<T, F, Fut>(mut op: F) -> Result<T, sqlx::Error>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, sqlx::Error>>,
+{
+    let deadline = Instant::now() + BUSY_TIMEOUT;
+    let mut delay = Duration::from_millis(5);
+    loop {
+        match op().await {
+            Err(e) if is_busy(&e) && Instant::now() + delay < deadline => {
+                tracing::debug!(?delay, "Database busy, retrying");
+                tokio::time::sleep(delay).await;
+                delay = (delay * 2).min(Duration::from_millis(200));
+            }
+            result => return result,
+        }
+    }
+}
 
 /// Validate and map a sort field to the actual column name.
 /// Returns None for invalid fields (falls back to default).
@@ -42,6 +87,15 @@ pub fn build_order_clause(page: &PageSort, allowed_fields: &[&str], default_fiel
     format!("ORDER BY {} {}", sort_field, order)
 }
 
+/// Append `id` as a final sort key so rows with equal sort values (e.g. the
+/// same `created_at`) come back in a repeatable order.
+///
+/// `id_column` must be qualified the same way as the rest of the clause when
+/// the query joins other tables.
+pub fn with_id_tiebreak(order_clause: &str, id_column: &str) -> String {
+    format!("{}, {} ASC", order_clause, id_column)
+}
+
 /// Build LIMIT/OFFSET clause from PageSort parameters.
 /// Note: SQL requires LIMIT when using OFFSET. If offset is provided without limit,
 /// we use LIMIT -1 (SQLite's "no limit" value).
@@ -65,12 +119,126 @@ pub fn build_limit_offset_clause(page: &PageSort) -> String {
     clause
 }
 
+/// Wrap a list query so every returned row also carries the unpaginated total.
+///
+/// The window count runs in the outer query, after any DISTINCT in `inner_sql`,
+/// so join-based filters that de-duplicate rows are counted correctly. The
+/// order clause must therefore use unqualified column names.
+pub fn with_window_total(inner_sql: &str, order_clause: &str, limit_clause: &str) -> String {
+    format!(
+        "SELECT *, COUNT(*) OVER() AS total_count FROM ({}) {} {}",
+        inner_sql, order_clause, limit_clause
+    )
+}
+
+/// Read the total from rows produced by [`with_window_total`].
+///
+/// Returns None when the page is empty because of the offset, in which case
+/// the caller has to fall back to a separate COUNT query.
+pub fn window_total(rows: &[SqliteRow], page: &PageSort) -> Option<usize> {
+    match rows.first() {
+        Some(row) => Some(row.get::<i64, _>("total_count") as usize),
+        None if page.offset.unwrap_or(0) == 0 => Some(0),
+        None => None,
+    }
+}
+
+/// Run a COUNT query with the given bind values.
+pub async fn count_rows(
+    pool: &SqlitePool,
+    count_sql: &str,
+    bind_values: &[String],
+) -> DbResult<usize> {
+    let mut count_query = sqlx::query_scalar(count_sql);
+    for value in bind_values {
+        count_query = count_query.bind(value);
+    }
+
+    let total: i64 = count_query
+        .fetch_one(pool)
+        .await
+        .map_err(|e| DbError::Database {
+            message: e.to_string(),
+        })?;
+    Ok(total as usize)
+}
+
+/// `ON CONFLICT` clause that lets an insert take over a soft-deleted row's ID.
+///
+/// The tombstone is overwritten with `columns` from the insert rather than
+/// removed, so soft-deleted children keep their parent and sync still sees
+/// the row. A live row with the ID is left alone; [`check_inserted`] reports
+/// that as `AlreadyExists`. `table` must be a trusted table name.
+pub fn revive_deleted(table: &str, columns: &[&str]) -> String {
+    let assignments: Vec<String> = columns
+        .iter()
+        .map(|column| format!("{column} = excluded.{column}"))
+        .collect();
+    format!(
+        "ON CONFLICT(id) DO UPDATE SET {}, deleted_at = NULL WHERE {}.deleted_at IS NOT NULL",
+        assignments.join(", "),
+        table
+    )
+}
+
+/// Map an insert using [`revive_deleted`] that wrote nothing to `AlreadyExists`.
+pub fn check_inserted(result: &SqliteQueryResult, entity_type: &str, id: &str) -> DbResult<()> {
+    if result.rows_affected() == 0 {
+        return Err(DbError::AlreadyExists {
+            entity_type: entity_type.to_string(),
+            id: id.to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Drop the links and history of a soft-deleted row about to be revived, so
+/// the new record starts with only its own. Live rows are left alone.
+pub async fn clear_deleted_links(
+    conn: &mut SqliteConnection,
+    table: &str,
+    id: &str,
+) -> DbResult<()> {
+    let links: &[(&str, &str)] = match table {
+        "project" => &[
+            ("project_repo", "project_id"),
+            ("project_note", "project_id"),
+            ("project_skill", "project_id"),
+        ],
+        "repo" => &[
+            ("project_repo", "repo_id"),
+            ("task_list_repo", "repo_id"),
+            ("note_repo", "repo_id"),
+        ],
+        "task_list" => &[("task_list_repo", "task_list_id")],
+        "task" => &[("task_transition_log", "task_id")],
+        "note" => &[("note_repo", "note_id"), ("project_note", "note_id")],
+        _ => &[],
+    };
+
+    for (link_table, column) in links {
+        sqlx::query(&format!(
+            "DELETE FROM {} WHERE {} = ? AND EXISTS (SELECT 1 FROM {} WHERE id = ? AND deleted_at IS NOT NULL)",
+            link_table, column, table
+        ))
+        .bind(id)
+        .bind(id)
+        .execute(&mut *conn)
+        .await
+        .map_err(|e| DbError::Database {
+            message: e.to_string(),
+        })?;
+    }
+    Ok(())
+}
+
 /// Sanitize and transform an FTS5 search query to prevent syntax errors.
 ///
 /// This function:
 /// 1. Strips FTS5-dangerous special characters (except quotes, underscore, whitespace)
 /// 2. Handles unbalanced quotes by removing them
-/// 3. Returns None for empty/whitespace-only queries
+/// 3. Returns None for queries with nothing to match (empty, whitespace, only
+///    quotes, or only Boolean operators)
 /// 4. Detects advanced search features (Boolean operators, phrases)
 /// 5. Adds prefix matching (*) for simple queries
 ///
@@ -102,8 +270,13 @@ pub fn sanitize_fts5_query(search_term: &str) -> Option<String> {
         cleaned.replace('"', "")
     };
 
-    // Return None for empty/whitespace-only queries
-    if cleaned.trim().is_empty() {
+    // Return None when no searchable term remains: `""` or a bare `AND` is an
+    // FTS5 syntax error rather than an empty match
+    let has_terms = cleaned
+        .replace('"', " ")
+        .split_whitespace()
+        .any(|term| !matches!(term, "AND" | "OR" | "NOT"));
+    if !has_terms {
         return None;
     }
 
@@ -170,6 +343,15 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_revive_deleted_only_overwrites_tombstones() {
+        assert_eq!(
+            revive_deleted("task", &["title", "status"]),
+            "ON CONFLICT(id) DO UPDATE SET title = excluded.title, status = excluded.status, \
+             deleted_at = NULL WHERE task.deleted_at IS NOT NULL"
+        );
+    }
+
     #[test]
     fn test_sanitize_fts5_query_empty() {
         assert_eq!(sanitize_fts5_query(""), None);
@@ -177,6 +359,16 @@ mod tests {
         assert_eq!(sanitize_fts5_query("\t\n"), None);
     }
 
+    #[test]
+    fn test_sanitize_fts5_query_no_terms() {
+        assert_eq!(sanitize_fts5_query("\"\""), None);
+        assert_eq!(sanitize_fts5_query("\"  \" \"\""), None);
+        assert_eq!(sanitize_fts5_query("AND"), None);
+        assert_eq!(sanitize_fts5_query("NOT OR"), None);
+        // Lowercase words are ordinary terms
+        assert_eq!(sanitize_fts5_query("and"), Some("and*".to_string()));
+    }
+
     #[test]
     fn test_sanitize_fts5_query_special_chars() {
         // Special FTS5 chars should be stripped
@@ -8,6 +8,7 @@ mod helpers;
 mod note;
 mod project;
 mod repo;
+mod schema;
 mod skill;
 mod sync;
 mod task;
@@ -25,6 +26,8 @@ mod project_test;
 #[cfg(test)]
 mod repo_test;
 #[cfg(test)]
+mod schema_test;
+#[cfg(test)]
 mod skill_test;
 #[cfg(test)]
 mod sync_test;
@@ -39,7 +42,9 @@ pub use connection::SqliteDatabase;
 pub use note::SqliteNoteRepository;
 pub use project::SqliteProjectRepository;
 pub use repo::SqliteRepoRepository;
+pub use schema::SchemaMismatch;
 pub use skill::SqliteSkillRepository;
+pub(crate) use skill::validate_skill;
 pub use sync::SqliteSyncRepository;
 pub use task::SqliteTaskRepository;
 pub use task_list::SqliteTaskListRepository;
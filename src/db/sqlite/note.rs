@@ -1,15 +1,24 @@
 //! SQLite NoteRepository implementation.
 
-use sqlx::{Row, SqlitePool};
+use sqlx::sqlite::SqliteRow;
+use sqlx::{Row, SqliteConnection, SqlitePool};
 
-use super::helpers::build_limit_offset_clause;
+use super::helpers::{
+    build_limit_offset_clause, check_inserted, clear_deleted_links, count_rows, retry_busy,
+    revive_deleted, window_total, with_id_tiebreak, with_window_total,
+};
 use crate::db::models::{NOTE_HARD_MAX, NOTE_SOFT_MAX, NOTE_WARN_SIZE};
-use crate::db::utils::{current_timestamp, generate_entity_id};
-use crate::db::{DbError, DbResult, ListResult, Note, NoteQuery, NoteRepository};
+use crate::db::utils::{
+    IdLength, SNIPPET_ELLIPSIS, SNIPPET_HIT_END, SNIPPET_HIT_START, SNIPPET_TOKENS, content_hash,
+    current_timestamp, generate_entity_id_with_length, highlight_snippet,
+};
+use crate::db::{DbError, DbResult, ListResult, Note, NoteQuery, NoteRepository, NoteSearchResult};
 
 /// SQLx-backed note repository.
 pub struct SqliteNoteRepository<'a> {
     pub(crate) pool: &'a SqlitePool,
+    /// Length of generated IDs
+    pub(crate) id_length: IdLength,
 }
 
 /// Validates note content size.
@@ -94,6 +103,323 @@ fn validate_and_sort_ranges(ranges: &[(usize, usize)]) -> DbResult<Vec<(usize, u
     Ok(sorted)
 }
 
+/// Validates a note's links before they are written.
+///
+/// Rejects a note that names itself as parent and returns `NotFound` for any
+/// linked repo or project that doesn't exist, instead of letting the insert
+/// fail on a foreign key.
+async fn validate_note_links(conn: &mut SqliteConnection, id: &str, note: &Note) -> DbResult<()> {
+    if note.parent_id.as_deref() == Some(id) {
+        return Err(DbError::Validation {
+            message: format!("Note '{}' cannot be its own parent", id),
+        });
+    }
+
+    for (entity_type, table, ids) in [
+        ("Repo", "repo", &note.repo_ids),
+        ("Project", "project", &note.project_ids),
+    ] {
+        for linked_id in ids {
+            let exists: bool = sqlx::query_scalar(&format!(
+                "SELECT EXISTS(SELECT 1 FROM {} WHERE id = ? AND deleted_at IS NULL)",
+                table
+            ))
+            .bind(linked_id)
+            .fetch_one(&mut *conn)
+            .await
+            .map_err(|e| DbError::Database {
+                message: e.to_string(),
+            })?;
+
+            if !exists {
+                return Err(DbError::NotFound {
+                    entity_type: entity_type.to_string(),
+                    id: linked_id.clone(),
+                });
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Map a row from [`SqliteNoteRepository::fts_search`] to a note.
+fn search_row_to_note(row: &SqliteRow) -> Note {
+    let tags_json: String = row.get("tags");
+    let tags: Vec<String> = serde_json::from_str(&tags_json).unwrap_or_default();
+
+    Note {
+        id: row.get("id"),
+        title: row.get("title"),
+        content: row.get("content"),
+        tags,
+        parent_id: row.get("parent_id"),
+        idx: row.get("idx"),
+        repo_ids: vec![],    // Empty by default - relationships managed separately
+        project_ids: vec![], // Empty by default - relationships managed separately
+        // Try to get subnote_count if it exists in the result set
+        subnote_count: row.try_get::<i32, _>("subnote_count").ok(),
+        created_at: row.get("created_at"),
+        updated_at: row.get("updated_at"),
+    }
+}
+
+impl<'a> SqliteNoteRepository<'a> {
+    /// Load a note by ID; with `include_deleted`, soft-deleted rows too.
+    ///
+    /// Sync export uses this to write tombstones for deleted rows.
+    pub(crate) async fn fetch(&self, id: &str, include_deleted: bool) -> DbResult<Note> {
+        let row = sqlx::query(
+            "SELECT id, title, content, tags, parent_id, idx, created_at, updated_at FROM note WHERE id = ? AND (deleted_at IS NULL OR ?)",
+        )
+        .bind(id)
+        .bind(include_deleted)
+        .fetch_optional(self.pool)
+        .await
+        .map_err(|e| DbError::Database {
+            message: e.to_string(),
+        })?;
+
+        if let Some(row) = row {
+            let tags_json: String = row.get("tags");
+            let tags: Vec<String> =
+                serde_json::from_str(&tags_json).map_err(|e| DbError::Database {
+                    message: format!("Failed to parse tags JSON: {}", e),
+                })?;
+
+            // Get repo relationships
+            let repo_ids: Vec<String> = sqlx::query_scalar(
+                "SELECT nr.repo_id FROM note_repo nr JOIN repo r ON r.id = nr.repo_id
+                 WHERE nr.note_id = ? AND r.deleted_at IS NULL",
+            )
+            .bind(id)
+            .fetch_all(self.pool)
+            .await
+            .map_err(|e| DbError::Database {
+                message: e.to_string(),
+            })?;
+
+            // Get project relationships
+            let project_ids: Vec<String> = sqlx::query_scalar(
+                "SELECT pn.project_id FROM project_note pn JOIN project p ON p.id = pn.project_id
+                 WHERE pn.note_id = ? AND p.deleted_at IS NULL",
+            )
+            .bind(id)
+            .fetch_all(self.pool)
+            .await
+            .map_err(|e| DbError::Database {
+                message: e.to_string(),
+            })?;
+
+            Ok(Note {
+                id: row.get("id"),
+                title: row.get("title"),
+                content: row.get("content"),
+                tags,
+                parent_id: row.get("parent_id"),
+                idx: row.get("idx"),
+                repo_ids,
+                project_ids,
+                subnote_count: None, // Not computed for single note get
+                created_at: row.get("created_at"),
+                updated_at: row.get("updated_at"),
+            })
+        } else {
+            Err(DbError::NotFound {
+                entity_type: "Note".to_string(),
+                id: id.to_string(),
+            })
+        }
+    }
+
+    /// FTS5 query shared by `search` and `search_snippets`.
+    ///
+    /// Returns the page of rows plus the total match count, or `None` when the
+    /// search term has nothing to match. With `with_snippet` each row also
+    /// carries a `snippet` column.
+    async fn fts_search(
+        &self,
+        search_term: &str,
+        query: &NoteQuery,
+        with_snippet: bool,
+    ) -> DbResult<Option<(Vec<SqliteRow>, usize)>> {
+        let allowed_fields = ["title", "created_at", "updated_at", "last_activity_at"];
+
+        // Determine which JOINs are needed
+        let needs_json_each = query.tags.as_ref().is_some_and(|t| !t.is_empty());
+        let needs_project_join = query.project_id.is_some();
+
+        // Check if we need last_activity_at computed column for parent notes
+        let needs_activity_column = query.note_type.as_deref() == Some("note");
+
+        // Sanitize FTS5 search query to prevent syntax errors
+        // FTS5 has strict bareword requirements - only allows: A-Z, a-z, 0-9, _, non-ASCII
+        // Strategy: Strip dangerous chars, balance quotes, preserve Boolean ops, add prefix matching
+        // Sanitize FTS5 search query to prevent syntax errors
+        let Some(fts_query) = super::helpers::sanitize_fts5_query(search_term) else {
+            return Ok(None);
+        };
+
+        let mut bind_values: Vec<String> = vec![fts_query];
+        let mut where_conditions: Vec<String> = Vec::new();
+
+        // Use FTS5 for search - join note_fts to note table
+        let (select_cols, from_clause, order_field_prefix) = if needs_json_each
+            || needs_project_join
+        {
+            // Need aliases when doing JOINs
+            let mut from =
+                "FROM note n INNER JOIN note_fts ON n.rowid = note_fts.rowid".to_string();
+
+            if needs_project_join {
+                from.push_str("\nINNER JOIN project_note pn ON n.id = pn.note_id");
+                where_conditions.push("pn.project_id = ?".to_string());
+                bind_values.push(query.project_id.as_ref().unwrap().clone());
+            }
+
+            if needs_json_each {
+                from.push_str(", json_each(n.tags)");
+                let tags = query.tags.as_ref().unwrap();
+                let placeholders: Vec<&str> = tags.iter().map(|_| "?").collect();
+                where_conditions.push(format!("json_each.value IN ({})", placeholders.join(", ")));
+                bind_values.extend(tags.clone());
+            }
+
+            let select = if needs_activity_column {
+                "DISTINCT n.id, n.title, n.content, n.tags, n.parent_id, n.idx, n.created_at, n.updated_at, \
+                 COALESCE((SELECT MAX(updated_at) FROM note WHERE parent_id = n.id AND deleted_at IS NULL), n.updated_at) AS last_activity_at"
+            } else {
+                "DISTINCT n.id, n.title, n.content, n.tags, n.parent_id, n.idx, n.created_at, n.updated_at"
+            };
+
+            (select, from, "n.")
+        } else {
+            // No filters, simple FTS5 join - use explicit table prefix
+            let select = if needs_activity_column {
+                "note.id, note.title, note.content, note.tags, note.parent_id, note.idx, note.created_at, note.updated_at, \
+                 COALESCE((SELECT MAX(updated_at) FROM note AS child WHERE child.parent_id = note.id AND child.deleted_at IS NULL), note.updated_at) AS last_activity_at"
+            } else {
+                "note.id, note.title, note.content, note.tags, note.parent_id, note.idx, note.created_at, note.updated_at"
+            };
+
+            (
+                select,
+                "FROM note INNER JOIN note_fts ON note.rowid = note_fts.rowid".to_string(),
+                "note.",
+            )
+        };
+
+        // Add parent_id filter if specified (after we know the table prefix)
+        if let Some(parent_id) = &query.parent_id {
+            where_conditions.push(format!("{}parent_id = ?", order_field_prefix));
+            bind_values.push(parent_id.clone());
+        }
+
+        // Filter by note type: "note" (parent_id IS NULL) or "subnote" (parent_id IS NOT NULL)
+        if let Some(note_type) = &query.note_type {
+            match note_type.as_str() {
+                "note" => where_conditions.push(format!("{}parent_id IS NULL", order_field_prefix)),
+                "subnote" => {
+                    where_conditions.push(format!("{}parent_id IS NOT NULL", order_field_prefix))
+                }
+                _ => {} // Ignore invalid values
+            }
+        }
+        where_conditions.push(format!("{}deleted_at IS NULL", order_field_prefix));
+
+        // FTS5 MATCH condition - searches across title, content, and tags
+        where_conditions.insert(0, "note_fts MATCH ?".to_string());
+        let where_clause = format!("WHERE {}", where_conditions.join(" AND "));
+
+        // Build ORDER BY; unqualified since it applies to the wrapped query
+        let order_clause = if needs_activity_column && query.page.sort_by.is_none() {
+            // Default order for parent notes: most recently active first
+            "ORDER BY last_activity_at DESC".to_string()
+        } else {
+            let sort_field = query
+                .page
+                .sort_by
+                .as_deref()
+                .filter(|f| allowed_fields.contains(f))
+                .unwrap_or("created_at");
+            let sort_order = match query.page.sort_order.unwrap_or(crate::db::SortOrder::Asc) {
+                crate::db::SortOrder::Asc => "ASC",
+                crate::db::SortOrder::Desc => "DESC",
+            };
+
+            format!("ORDER BY {} {}", sort_field, sort_order)
+        };
+        let order_clause = with_id_tiebreak(&order_clause, "id");
+
+        let limit_clause = build_limit_offset_clause(&query.page);
+
+        // Best-matching fragment of any column. Hits are wrapped in sentinel
+        // characters, not tags, so the text can be escaped before the
+        // <mark> tags go in
+        let snippet_col = if with_snippet {
+            format!(
+                ", snippet(note_fts, -1, '{}', '{}', '{}', {}) AS snippet",
+                SNIPPET_HIT_START, SNIPPET_HIT_END, SNIPPET_ELLIPSIS, SNIPPET_TOKENS
+            )
+        } else {
+            String::new()
+        };
+
+        // Build final SQL; the total comes back with every row
+        let sql = with_window_total(
+            &format!(
+                "SELECT {}{} {} {}",
+                select_cols, snippet_col, from_clause, where_clause
+            ),
+            &order_clause,
+            &limit_clause,
+        );
+
+        let count_sql = if needs_json_each || needs_project_join {
+            format!(
+                "SELECT COUNT(DISTINCT n.id) {} {}",
+                from_clause, where_clause
+            )
+        } else {
+            format!("SELECT COUNT(*) {} {}", from_clause, where_clause)
+        };
+
+        // Get paginated results; a concurrent index rebuild can report busy
+        let rows = retry_busy(|| {
+            let mut query_builder = sqlx::query(&sql);
+            for value in &bind_values {
+                query_builder = query_builder.bind(value);
+            }
+            query_builder.fetch_all(self.pool)
+        })
+        .await
+        .map_err(|e| DbError::Database {
+            message: e.to_string(),
+        })?;
+
+        let total = match window_total(&rows, &query.page) {
+            Some(total) => total,
+            // Offset past the end: no rows to read the total from
+            None => {
+                let total: i64 = retry_busy(|| {
+                    let mut count_query = sqlx::query_scalar(&count_sql);
+                    for value in &bind_values {
+                        count_query = count_query.bind(value);
+                    }
+                    count_query.fetch_one(self.pool)
+                })
+                .await
+                .map_err(|e| DbError::Database {
+                    message: e.to_string(),
+                })?;
+                total as usize
+            }
+        };
+
+        Ok(Some((rows, total)))
+    }
+}
+
 impl<'a> NoteRepository for SqliteNoteRepository<'a> {
     async fn create(&self, note: &Note) -> DbResult<Note> {
         // Validate content size
@@ -101,7 +427,7 @@ impl<'a> NoteRepository for SqliteNoteRepository<'a> {
 
         // Use provided ID if not empty, otherwise generate one
         let id = if note.id.is_empty() {
-            generate_entity_id()
+            generate_entity_id_with_length(self.id_length)
         } else {
             note.id.clone()
         };
@@ -127,15 +453,35 @@ impl<'a> NoteRepository for SqliteNoteRepository<'a> {
             message: e.to_string(),
         })?;
 
-        sqlx::query(
+        validate_note_links(&mut tx, &id, note).await?;
+
+        // A deleted note keeps its row; reusing the ID revives it in place
+        clear_deleted_links(&mut tx, "note", &id).await?;
+
+        let result = sqlx::query(&format!(
             r#"
-            INSERT INTO note (id, title, content, tags, parent_id, idx, created_at, updated_at)
-            VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+            INSERT INTO note (id, title, content, content_hash, tags, parent_id, idx, created_at, updated_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+            {}
             "#,
-        )
+            revive_deleted(
+                "note",
+                &[
+                    "title",
+                    "content",
+                    "content_hash",
+                    "tags",
+                    "parent_id",
+                    "idx",
+                    "created_at",
+                    "updated_at",
+                ],
+            )
+        ))
         .bind(&id)
         .bind(&note.title)
         .bind(&note.content)
+        .bind(content_hash(&note.content))
         .bind(tags_json)
         .bind(&note.parent_id)
         .bind(note.idx)
@@ -146,10 +492,11 @@ impl<'a> NoteRepository for SqliteNoteRepository<'a> {
         .map_err(|e| DbError::Database {
             message: e.to_string(),
         })?;
+        check_inserted(&result, "Note", &id)?;
 
         // Insert repo relationships
         for repo_id in &note.repo_ids {
-            sqlx::query("INSERT INTO note_repo (note_id, repo_id) VALUES (?, ?)")
+            sqlx::query("INSERT OR IGNORE INTO note_repo (note_id, repo_id) VALUES (?, ?)")
                 .bind(&id)
                 .bind(repo_id)
                 .execute(&mut *tx)
@@ -161,7 +508,7 @@ impl<'a> NoteRepository for SqliteNoteRepository<'a> {
 
         // Insert project relationships
         for project_id in &note.project_ids {
-            sqlx::query("INSERT INTO project_note (project_id, note_id) VALUES (?, ?)")
+            sqlx::query("INSERT OR IGNORE INTO project_note (project_id, note_id) VALUES (?, ?)")
                 .bind(project_id)
                 .bind(&id)
                 .execute(&mut *tx)
@@ -192,67 +539,30 @@ impl<'a> NoteRepository for SqliteNoteRepository<'a> {
     }
 
     async fn get(&self, id: &str) -> DbResult<Note> {
-        let row = sqlx::query(
-            "SELECT id, title, content, tags, parent_id, idx, created_at, updated_at FROM note WHERE id = ?",
+        self.fetch(id, false).await
+    }
+
+    async fn find_by_content(&self, content: &str) -> DbResult<Option<Note>> {
+        let id: Option<String> = sqlx::query_scalar(
+            "SELECT id FROM note WHERE content_hash = ? AND deleted_at IS NULL
+             ORDER BY created_at ASC, id ASC LIMIT 1",
         )
-        .bind(id)
+        .bind(content_hash(content))
         .fetch_optional(self.pool)
         .await
         .map_err(|e| DbError::Database {
             message: e.to_string(),
         })?;
 
-        if let Some(row) = row {
-            let tags_json: String = row.get("tags");
-            let tags: Vec<String> =
-                serde_json::from_str(&tags_json).map_err(|e| DbError::Database {
-                    message: format!("Failed to parse tags JSON: {}", e),
-                })?;
-
-            // Get repo relationships
-            let repo_ids: Vec<String> =
-                sqlx::query_scalar("SELECT repo_id FROM note_repo WHERE note_id = ?")
-                    .bind(id)
-                    .fetch_all(self.pool)
-                    .await
-                    .map_err(|e| DbError::Database {
-                        message: e.to_string(),
-                    })?;
-
-            // Get project relationships
-            let project_ids: Vec<String> =
-                sqlx::query_scalar("SELECT project_id FROM project_note WHERE note_id = ?")
-                    .bind(id)
-                    .fetch_all(self.pool)
-                    .await
-                    .map_err(|e| DbError::Database {
-                        message: e.to_string(),
-                    })?;
-
-            Ok(Note {
-                id: row.get("id"),
-                title: row.get("title"),
-                content: row.get("content"),
-                tags,
-                parent_id: row.get("parent_id"),
-                idx: row.get("idx"),
-                repo_ids,
-                project_ids,
-                subnote_count: None, // Not computed for single note get
-                created_at: row.get("created_at"),
-                updated_at: row.get("updated_at"),
-            })
-        } else {
-            Err(DbError::NotFound {
-                entity_type: "Note".to_string(),
-                id: id.to_string(),
-            })
+        match id {
+            Some(id) => self.get(&id).await.map(Some),
+            None => Ok(None),
         }
     }
 
     async fn get_metadata_only(&self, id: &str) -> DbResult<Note> {
         let row = sqlx::query(
-            "SELECT id, title, tags, parent_id, idx, created_at, updated_at FROM note WHERE id = ?",
+            "SELECT id, title, tags, parent_id, idx, created_at, updated_at FROM note WHERE id = ? AND deleted_at IS NULL",
         )
         .bind(id)
         .fetch_optional(self.pool)
@@ -269,24 +579,28 @@ impl<'a> NoteRepository for SqliteNoteRepository<'a> {
                 })?;
 
             // Get repo relationships
-            let repo_ids: Vec<String> =
-                sqlx::query_scalar("SELECT repo_id FROM note_repo WHERE note_id = ?")
-                    .bind(id)
-                    .fetch_all(self.pool)
-                    .await
-                    .map_err(|e| DbError::Database {
-                        message: e.to_string(),
-                    })?;
+            let repo_ids: Vec<String> = sqlx::query_scalar(
+                "SELECT nr.repo_id FROM note_repo nr JOIN repo r ON r.id = nr.repo_id
+                 WHERE nr.note_id = ? AND r.deleted_at IS NULL",
+            )
+            .bind(id)
+            .fetch_all(self.pool)
+            .await
+            .map_err(|e| DbError::Database {
+                message: e.to_string(),
+            })?;
 
             // Get project relationships
-            let project_ids: Vec<String> =
-                sqlx::query_scalar("SELECT project_id FROM project_note WHERE note_id = ?")
-                    .bind(id)
-                    .fetch_all(self.pool)
-                    .await
-                    .map_err(|e| DbError::Database {
-                        message: e.to_string(),
-                    })?;
+            let project_ids: Vec<String> = sqlx::query_scalar(
+                "SELECT pn.project_id FROM project_note pn JOIN project p ON p.id = pn.project_id
+                 WHERE pn.note_id = ? AND p.deleted_at IS NULL",
+            )
+            .bind(id)
+            .fetch_all(self.pool)
+            .await
+            .map_err(|e| DbError::Database {
+                message: e.to_string(),
+            })?;
 
             Ok(Note {
                 id: row.get("id"),
@@ -354,8 +668,8 @@ impl<'a> NoteRepository for SqliteNoteRepository<'a> {
 
             let select = if needs_activity_column {
                 "DISTINCT n.id, n.title, n.content, n.tags, n.parent_id, n.idx, n.created_at, n.updated_at, \
-                 COALESCE((SELECT MAX(updated_at) FROM note WHERE parent_id = n.id), n.updated_at) AS last_activity_at, \
-                 (SELECT COUNT(*) FROM note WHERE parent_id = n.id) AS subnote_count"
+                 COALESCE((SELECT MAX(updated_at) FROM note WHERE parent_id = n.id AND deleted_at IS NULL), n.updated_at) AS last_activity_at, \
+                 (SELECT COUNT(*) FROM note WHERE parent_id = n.id AND deleted_at IS NULL) AS subnote_count"
             } else {
                 "DISTINCT n.id, n.title, n.content, n.tags, n.parent_id, n.idx, n.created_at, n.updated_at"
             };
@@ -366,8 +680,8 @@ impl<'a> NoteRepository for SqliteNoteRepository<'a> {
             let select = if needs_activity_column {
                 // Explicitly reference outer table in subquery using table name
                 "note.id, note.title, note.content, note.tags, note.parent_id, note.idx, note.created_at, note.updated_at, \
-                 COALESCE((SELECT MAX(updated_at) FROM note AS child WHERE child.parent_id = note.id), note.updated_at) AS last_activity_at, \
-                 (SELECT COUNT(*) FROM note AS child WHERE child.parent_id = note.id) AS subnote_count"
+                 COALESCE((SELECT MAX(updated_at) FROM note AS child WHERE child.parent_id = note.id AND child.deleted_at IS NULL), note.updated_at) AS last_activity_at, \
+                 (SELECT COUNT(*) FROM note AS child WHERE child.parent_id = note.id AND child.deleted_at IS NULL) AS subnote_count"
             } else {
                 "id, title, content, tags, parent_id, idx, created_at, updated_at"
             };
@@ -395,6 +709,7 @@ impl<'a> NoteRepository for SqliteNoteRepository<'a> {
                 _ => {} // Ignore invalid values
             }
         }
+        where_conditions.push(format!("{}deleted_at IS NULL", order_field_prefix));
 
         // Build WHERE clause
         let where_clause = if !where_conditions.is_empty() {
@@ -403,14 +718,11 @@ impl<'a> NoteRepository for SqliteNoteRepository<'a> {
             String::new()
         };
 
-        // Build ORDER BY with proper prefixes
+        // Build ORDER BY; unqualified since it applies to the wrapped query
         // Special handling: when querying by parent_id, default to ordering by idx
         let order_clause = if query.parent_id.is_some() && query.page.sort_by.is_none() {
             // Default order for subnotes: idx ASC (lowest first), then updated_at DESC (latest first)
-            format!(
-                "ORDER BY {}idx ASC, {}updated_at DESC",
-                order_field_prefix, order_field_prefix
-            )
+            "ORDER BY idx ASC, updated_at DESC".to_string()
         } else if needs_activity_column && query.page.sort_by.is_none() {
             // Default order for parent notes: most recently active first
             "ORDER BY last_activity_at DESC".to_string()
@@ -426,23 +738,17 @@ impl<'a> NoteRepository for SqliteNoteRepository<'a> {
                 crate::db::SortOrder::Desc => "DESC",
             };
 
-            // Handle last_activity_at sort field
-            if sort_field == "last_activity_at" {
-                format!("ORDER BY last_activity_at {}", sort_order)
-            } else {
-                format!(
-                    "ORDER BY {}{} {}",
-                    order_field_prefix, sort_field, sort_order
-                )
-            }
+            format!("ORDER BY {} {}", sort_field, sort_order)
         };
+        let order_clause = with_id_tiebreak(&order_clause, "id");
 
         let limit_clause = build_limit_offset_clause(&query.page);
 
-        // Build final SQL
-        let sql = format!(
-            "SELECT {} {} {} {} {}",
-            select_cols, from_clause, where_clause, order_clause, limit_clause
+        // Build final SQL; the total comes back with every row
+        let sql = with_window_total(
+            &format!("SELECT {} {} {}", select_cols, from_clause, where_clause),
+            &order_clause,
+            &limit_clause,
         );
 
         let count_sql = if needs_json_each || needs_project_join {
@@ -467,6 +773,12 @@ impl<'a> NoteRepository for SqliteNoteRepository<'a> {
                 message: e.to_string(),
             })?;
 
+        let total = match window_total(&rows, &query.page) {
+            Some(total) => total,
+            // Offset past the end: no rows to read the total from
+            None => count_rows(self.pool, &count_sql, &bind_values).await?,
+        };
+
         let items: Vec<Note> = rows
             .into_iter()
             .map(|row| {
@@ -492,24 +804,12 @@ impl<'a> NoteRepository for SqliteNoteRepository<'a> {
             })
             .collect();
 
-        // Get total count
-        let mut count_query = sqlx::query_scalar(&count_sql);
-        for value in &bind_values {
-            count_query = count_query.bind(value);
-        }
-
-        let total: i64 = count_query
-            .fetch_one(self.pool)
-            .await
-            .map_err(|e| DbError::Database {
-                message: e.to_string(),
-            })?;
-
         Ok(ListResult {
             items,
-            total: total as usize,
+            total,
             limit: query.page.limit,
             offset: query.page.offset.unwrap_or(0),
+            next_cursor: None,
         })
     }
 
@@ -578,6 +878,7 @@ impl<'a> NoteRepository for SqliteNoteRepository<'a> {
                 _ => {} // Ignore invalid values
             }
         }
+        where_conditions.push(format!("{}deleted_at IS NULL", order_field_prefix));
 
         let where_clause = if !where_conditions.is_empty() {
             format!("WHERE {}", where_conditions.join(" AND "))
@@ -585,13 +886,10 @@ impl<'a> NoteRepository for SqliteNoteRepository<'a> {
             String::new()
         };
 
-        // Build ORDER BY - special handling for different query types
+        // Build ORDER BY; unqualified since it applies to the wrapped query
         let order_clause = if query.parent_id.is_some() && query.page.sort_by.is_none() {
             // Default order for subnotes: idx ASC (lowest first), then updated_at DESC (latest first)
-            format!(
-                "ORDER BY {}idx ASC, {}updated_at DESC",
-                order_field_prefix, order_field_prefix
-            )
+            "ORDER BY idx ASC, updated_at DESC".to_string()
         } else if needs_activity_column && query.page.sort_by.is_none() {
             // Default order for parent notes: most recently active first
             "ORDER BY last_activity_at DESC".to_string()
@@ -607,33 +905,23 @@ impl<'a> NoteRepository for SqliteNoteRepository<'a> {
                 crate::db::SortOrder::Desc => "DESC",
             };
 
-            // Handle last_activity_at sort field
-            if sort_field == "last_activity_at" {
-                format!("ORDER BY last_activity_at {}", sort_order)
-            } else {
-                format!(
-                    "ORDER BY {}{} {}",
-                    order_field_prefix, sort_field, sort_order
-                )
-            }
+            format!("ORDER BY {} {}", sort_field, sort_order)
         };
+        let order_clause = with_id_tiebreak(&order_clause, "id");
 
         let limit_clause = build_limit_offset_clause(&query.page);
 
-        let (sql, count_sql) = if needs_json_each || needs_project_join {
+        let (inner_sql, count_sql) = if needs_json_each || needs_project_join {
             let select_cols = if needs_activity_column {
                 "DISTINCT n.id, n.title, n.tags, n.parent_id, n.idx, n.created_at, n.updated_at, \
-                 (SELECT COUNT(*) FROM note WHERE parent_id = n.id) AS subnote_count, \
-                 COALESCE((SELECT MAX(updated_at) FROM note WHERE parent_id = n.id), n.updated_at) AS last_activity_at"
+                 (SELECT COUNT(*) FROM note WHERE parent_id = n.id AND deleted_at IS NULL) AS subnote_count, \
+                 COALESCE((SELECT MAX(updated_at) FROM note WHERE parent_id = n.id AND deleted_at IS NULL), n.updated_at) AS last_activity_at"
             } else {
                 "DISTINCT n.id, n.title, n.tags, n.parent_id, n.idx, n.created_at, n.updated_at"
             };
 
             (
-                format!(
-                    "SELECT {} {} {} {} {}",
-                    select_cols, from_clause, where_clause, order_clause, limit_clause
-                ),
+                format!("SELECT {} {} {}", select_cols, from_clause, where_clause),
                 format!(
                     "SELECT COUNT(DISTINCT n.id) {} {}",
                     from_clause, where_clause
@@ -643,10 +931,10 @@ impl<'a> NoteRepository for SqliteNoteRepository<'a> {
             (
                 format!(
                     "SELECT note.id, note.title, note.tags, note.parent_id, note.idx, note.created_at, note.updated_at, \
-                     (SELECT COUNT(*) FROM note AS child WHERE child.parent_id = note.id) AS subnote_count, \
-                     COALESCE((SELECT MAX(updated_at) FROM note AS child WHERE child.parent_id = note.id), note.updated_at) AS last_activity_at
-                     FROM note {} {} {}",
-                    where_clause, order_clause, limit_clause
+                     (SELECT COUNT(*) FROM note AS child WHERE child.parent_id = note.id AND child.deleted_at IS NULL) AS subnote_count, \
+                     COALESCE((SELECT MAX(updated_at) FROM note AS child WHERE child.parent_id = note.id AND child.deleted_at IS NULL), note.updated_at) AS last_activity_at
+                     FROM note {}",
+                    where_clause
                 ),
                 format!("SELECT COUNT(*) FROM note {}", where_clause),
             )
@@ -654,12 +942,14 @@ impl<'a> NoteRepository for SqliteNoteRepository<'a> {
             (
                 format!(
                     "SELECT id, title, tags, parent_id, idx, created_at, updated_at
-                     FROM note {} {} {}",
-                    where_clause, order_clause, limit_clause
+                     FROM note {}",
+                    where_clause
                 ),
                 format!("SELECT COUNT(*) FROM note {}", where_clause),
             )
         };
+        // The total comes back with every row
+        let sql = with_window_total(&inner_sql, &order_clause, &limit_clause);
 
         // Get paginated results
         let mut query_builder = sqlx::query(&sql);
@@ -674,6 +964,12 @@ impl<'a> NoteRepository for SqliteNoteRepository<'a> {
                 message: e.to_string(),
             })?;
 
+        let total = match window_total(&rows, &query.page) {
+            Some(total) => total,
+            // Offset past the end: no rows to read the total from
+            None => count_rows(self.pool, &count_sql, &bind_values).await?,
+        };
+
         let items: Vec<Note> = rows
             .into_iter()
             .map(|row| {
@@ -699,29 +995,17 @@ impl<'a> NoteRepository for SqliteNoteRepository<'a> {
             })
             .collect();
 
-        // Get total count
-        let mut count_query = sqlx::query_scalar(&count_sql);
-        for value in &bind_values {
-            count_query = count_query.bind(value);
-        }
-
-        let total: i64 = count_query
-            .fetch_one(self.pool)
-            .await
-            .map_err(|e| DbError::Database {
-                message: e.to_string(),
-            })?;
-
         Ok(ListResult {
             items,
-            total: total as usize,
+            total,
             limit: query.page.limit,
             offset: query.page.offset.unwrap_or(0),
+            next_cursor: None,
         })
     }
 
     async fn count(&self) -> DbResult<usize> {
-        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM note")
+        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM note WHERE deleted_at IS NULL")
             .fetch_one(self.pool)
             .await
             .map_err(|e| DbError::Database {
@@ -739,6 +1023,8 @@ impl<'a> NoteRepository for SqliteNoteRepository<'a> {
             message: e.to_string(),
         })?;
 
+        validate_note_links(&mut tx, &note.id, note).await?;
+
         let tags_json = serde_json::to_string(&note.tags).map_err(|e| DbError::Database {
             message: format!("Failed to serialize tags: {}", e),
         })?;
@@ -749,12 +1035,13 @@ impl<'a> NoteRepository for SqliteNoteRepository<'a> {
         let result = sqlx::query(
             r#"
             UPDATE note
-            SET title = ?, content = ?, tags = ?, parent_id = ?, idx = ?, updated_at = ?
-            WHERE id = ?
+            SET title = ?, content = ?, content_hash = ?, tags = ?, parent_id = ?, idx = ?, updated_at = ?
+            WHERE id = ? AND deleted_at IS NULL
             "#,
         )
         .bind(&note.title)
         .bind(&note.content)
+        .bind(content_hash(&note.content))
         .bind(tags_json)
         .bind(&note.parent_id)
         .bind(note.idx)
@@ -783,7 +1070,7 @@ impl<'a> NoteRepository for SqliteNoteRepository<'a> {
             })?;
 
         for repo_id in &note.repo_ids {
-            sqlx::query("INSERT INTO note_repo (note_id, repo_id) VALUES (?, ?)")
+            sqlx::query("INSERT OR IGNORE INTO note_repo (note_id, repo_id) VALUES (?, ?)")
                 .bind(&note.id)
                 .bind(repo_id)
                 .execute(&mut *tx)
@@ -803,7 +1090,7 @@ impl<'a> NoteRepository for SqliteNoteRepository<'a> {
             })?;
 
         for project_id in &note.project_ids {
-            sqlx::query("INSERT INTO project_note (project_id, note_id) VALUES (?, ?)")
+            sqlx::query("INSERT OR IGNORE INTO project_note (project_id, note_id) VALUES (?, ?)")
                 .bind(project_id)
                 .bind(&note.id)
                 .execute(&mut *tx)
@@ -821,6 +1108,27 @@ impl<'a> NoteRepository for SqliteNoteRepository<'a> {
     }
 
     async fn delete(&self, id: &str) -> DbResult<()> {
+        let result =
+            sqlx::query("UPDATE note SET deleted_at = ? WHERE id = ? AND deleted_at IS NULL")
+                .bind(current_timestamp())
+                .bind(id)
+                .execute(self.pool)
+                .await
+                .map_err(|e| DbError::Database {
+                    message: e.to_string(),
+                })?;
+
+        if result.rows_affected() == 0 {
+            return Err(DbError::NotFound {
+                entity_type: "Note".to_string(),
+                id: id.to_string(),
+            });
+        }
+
+        Ok(())
+    }
+
+    async fn purge(&self, id: &str) -> DbResult<()> {
         let result = sqlx::query("DELETE FROM note WHERE id = ?")
             .bind(id)
             .execute(self.pool)
@@ -846,203 +1154,63 @@ impl<'a> NoteRepository for SqliteNoteRepository<'a> {
     ) -> DbResult<ListResult<Note>> {
         let default_query = NoteQuery::default();
         let query = query.unwrap_or(&default_query);
-        let allowed_fields = ["title", "created_at", "updated_at", "last_activity_at"];
-
-        // Determine which JOINs are needed
-        let needs_json_each = query.tags.as_ref().is_some_and(|t| !t.is_empty());
-        let needs_project_join = query.project_id.is_some();
-
-        // Check if we need last_activity_at computed column for parent notes
-        let needs_activity_column = query.note_type.as_deref() == Some("note");
-
-        // Sanitize FTS5 search query to prevent syntax errors
-        // FTS5 has strict bareword requirements - only allows: A-Z, a-z, 0-9, _, non-ASCII
-        // Strategy: Strip dangerous chars, balance quotes, preserve Boolean ops, add prefix matching
-        // Sanitize FTS5 search query to prevent syntax errors
-        let fts_query = match super::helpers::sanitize_fts5_query(search_term) {
-            Some(q) => q,
-            None => {
-                // Empty query returns empty results
-                return Ok(ListResult {
-                    items: vec![],
-                    total: 0,
-                    limit: query.page.limit,
-                    offset: query.page.offset.unwrap_or(0),
-                });
-            }
-        };
-
-        let mut bind_values: Vec<String> = vec![fts_query];
-        let mut where_conditions: Vec<String> = Vec::new();
-
-        // Use FTS5 for search - join note_fts to note table
-        let (select_cols, from_clause, order_field_prefix) = if needs_json_each
-            || needs_project_join
-        {
-            // Need aliases when doing JOINs
-            let mut from =
-                "FROM note n INNER JOIN note_fts ON n.rowid = note_fts.rowid".to_string();
-
-            if needs_project_join {
-                from.push_str("\nINNER JOIN project_note pn ON n.id = pn.note_id");
-                where_conditions.push("pn.project_id = ?".to_string());
-                bind_values.push(query.project_id.as_ref().unwrap().clone());
-            }
-
-            if needs_json_each {
-                from.push_str(", json_each(n.tags)");
-                let tags = query.tags.as_ref().unwrap();
-                let placeholders: Vec<&str> = tags.iter().map(|_| "?").collect();
-                where_conditions.push(format!("json_each.value IN ({})", placeholders.join(", ")));
-                bind_values.extend(tags.clone());
-            }
-
-            let select = if needs_activity_column {
-                "DISTINCT n.id, n.title, n.content, n.tags, n.parent_id, n.idx, n.created_at, n.updated_at, \
-                 COALESCE((SELECT MAX(updated_at) FROM note WHERE parent_id = n.id), n.updated_at) AS last_activity_at"
-            } else {
-                "DISTINCT n.id, n.title, n.content, n.tags, n.parent_id, n.idx, n.created_at, n.updated_at"
-            };
-
-            (select, from, "n.")
-        } else {
-            // No filters, simple FTS5 join - use explicit table prefix
-            let select = if needs_activity_column {
-                "note.id, note.title, note.content, note.tags, note.parent_id, note.idx, note.created_at, note.updated_at, \
-                 COALESCE((SELECT MAX(updated_at) FROM note AS child WHERE child.parent_id = note.id), note.updated_at) AS last_activity_at"
-            } else {
-                "note.id, note.title, note.content, note.tags, note.parent_id, note.idx, note.created_at, note.updated_at"
-            };
-
-            (
-                select,
-                "FROM note INNER JOIN note_fts ON note.rowid = note_fts.rowid".to_string(),
-                "note.",
-            )
-        };
-
-        // Add parent_id filter if specified (after we know the table prefix)
-        if let Some(parent_id) = &query.parent_id {
-            where_conditions.push(format!("{}parent_id = ?", order_field_prefix));
-            bind_values.push(parent_id.clone());
-        }
-
-        // Filter by note type: "note" (parent_id IS NULL) or "subnote" (parent_id IS NOT NULL)
-        if let Some(note_type) = &query.note_type {
-            match note_type.as_str() {
-                "note" => where_conditions.push(format!("{}parent_id IS NULL", order_field_prefix)),
-                "subnote" => {
-                    where_conditions.push(format!("{}parent_id IS NOT NULL", order_field_prefix))
-                }
-                _ => {} // Ignore invalid values
-            }
-        }
-
-        // FTS5 MATCH condition - searches across title, content, and tags
-        where_conditions.insert(0, "note_fts MATCH ?".to_string());
-        let where_clause = format!("WHERE {}", where_conditions.join(" AND "));
 
-        // Build ORDER BY with proper prefixes
-        let order_clause = if needs_activity_column && query.page.sort_by.is_none() {
-            // Default order for parent notes: most recently active first
-            "ORDER BY last_activity_at DESC".to_string()
-        } else {
-            let sort_field = query
-                .page
-                .sort_by
-                .as_deref()
-                .filter(|f| allowed_fields.contains(f))
-                .unwrap_or("created_at");
-            let sort_order = match query.page.sort_order.unwrap_or(crate::db::SortOrder::Asc) {
-                crate::db::SortOrder::Asc => "ASC",
-                crate::db::SortOrder::Desc => "DESC",
-            };
+        // An empty query returns empty results
+        let (rows, total) = self
+            .fts_search(search_term, query, false)
+            .await?
+            .unwrap_or_default();
 
-            // Handle last_activity_at sort field
-            if sort_field == "last_activity_at" {
-                format!("ORDER BY last_activity_at {}", sort_order)
-            } else {
-                format!(
-                    "ORDER BY {}{} {}",
-                    order_field_prefix, sort_field, sort_order
-                )
-            }
-        };
-
-        let limit_clause = build_limit_offset_clause(&query.page);
-
-        // Build final SQL
-        let sql = format!(
-            "SELECT {} {} {} {} {}",
-            select_cols, from_clause, where_clause, order_clause, limit_clause
-        );
-
-        let count_sql = if needs_json_each || needs_project_join {
-            format!(
-                "SELECT COUNT(DISTINCT n.id) {} {}",
-                from_clause, where_clause
-            )
-        } else {
-            format!("SELECT COUNT(*) {} {}", from_clause, where_clause)
-        };
-
-        // Get paginated results
-        let mut query_builder = sqlx::query(&sql);
-        for value in &bind_values {
-            query_builder = query_builder.bind(value);
-        }
+        Ok(ListResult {
+            items: rows.iter().map(search_row_to_note).collect(),
+            total,
+            limit: query.page.limit,
+            offset: query.page.offset.unwrap_or(0),
+            next_cursor: None,
+        })
+    }
 
-        let rows = query_builder
-            .fetch_all(self.pool)
-            .await
-            .map_err(|e| DbError::Database {
-                message: e.to_string(),
-            })?;
+    async fn search_snippets(
+        &self,
+        search_term: &str,
+        query: Option<&NoteQuery>,
+    ) -> DbResult<ListResult<NoteSearchResult>> {
+        let default_query = NoteQuery::default();
+        let query = query.unwrap_or(&default_query);
 
-        let items: Vec<Note> = rows
-            .into_iter()
-            .map(|row| {
-                let tags_json: String = row.get("tags");
-                let tags: Vec<String> = serde_json::from_str(&tags_json).unwrap_or_default();
+        let (rows, total) = self
+            .fts_search(search_term, query, true)
+            .await?
+            .unwrap_or_default();
 
-                // Try to get subnote_count if it exists in the result set
-                let subnote_count = row.try_get::<i32, _>("subnote_count").ok();
+        Ok(ListResult {
+            items: rows
+                .iter()
+                .map(|row| NoteSearchResult {
+                    note: search_row_to_note(row),
+                    snippet: highlight_snippet(row.get::<&str, _>("snippet")),
+                })
+                .collect(),
+            total,
+            limit: query.page.limit,
+            offset: query.page.offset.unwrap_or(0),
+            next_cursor: None,
+        })
+    }
 
-                Note {
-                    id: row.get("id"),
-                    title: row.get("title"),
-                    content: row.get("content"),
-                    tags,
-                    parent_id: row.get("parent_id"),
-                    idx: row.get("idx"),
-                    repo_ids: vec![], // Empty by default - relationships managed separately
-                    project_ids: vec![], // Empty by default - relationships managed separately
-                    subnote_count,
-                    created_at: row.get("created_at"),
-                    updated_at: row.get("updated_at"),
-                }
+    async fn rebuild_search_index(&self) -> DbResult<()> {
+        for command in ["rebuild", "optimize"] {
+            retry_busy(|| {
+                sqlx::query("INSERT INTO note_fts(note_fts) VALUES (?)")
+                    .bind(command)
+                    .execute(self.pool)
             })
-            .collect();
-
-        // Get total count
-        let mut count_query = sqlx::query_scalar(&count_sql);
-        for value in &bind_values {
-            count_query = count_query.bind(value);
-        }
-
-        let total: i64 = count_query
-            .fetch_one(self.pool)
             .await
             .map_err(|e| DbError::Database {
                 message: e.to_string(),
             })?;
-
-        Ok(ListResult {
-            items,
-            total: total as usize,
-            limit: query.page.limit,
-            offset: query.page.offset.unwrap_or(0),
-        })
+        }
+        Ok(())
     }
 
     async fn get_line_ranges(&self, id: &str, ranges: &[(usize, usize)]) -> DbResult<Vec<String>> {
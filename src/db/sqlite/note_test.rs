@@ -81,6 +81,69 @@ async fn fts5_search_finds_notes_by_tag_content() {
     assert_eq!(results.items[0].id, "fts00002");
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn fts5_search_snippets_mark_hits() {
+    let db = setup_db().await;
+    let notes = db.notes();
+
+    let mut note = make_note(
+        "fts00010",
+        "Runtime notes",
+        "The server uses tokio for async IO and axum for routing.",
+    );
+    note.tags = vec!["backend".to_string()];
+    notes.create(&note).await.unwrap();
+    notes
+        .create(&make_note("fts00011", "Unrelated", "Nothing to see"))
+        .await
+        .unwrap();
+
+    let results = notes
+        .search_snippets("tokio", None)
+        .await
+        .expect("Snippet search should succeed");
+    assert_eq!(results.total, 1);
+    assert_eq!(results.items[0].note.id, "fts00010");
+    assert!(
+        results.items[0].snippet.contains("<mark>tokio</mark>"),
+        "Snippet should highlight the hit: {}",
+        results.items[0].snippet
+    );
+
+    // Filters still apply, and an empty query matches nothing
+    let query = NoteQuery {
+        tags: Some(vec!["frontend".to_string()]),
+        ..Default::default()
+    };
+    let results = notes.search_snippets("tokio", Some(&query)).await.unwrap();
+    assert_eq!(results.total, 0);
+
+    let results = notes.search_snippets("   ", None).await.unwrap();
+    assert!(results.items.is_empty());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn fts5_search_snippets_escape_note_text() {
+    let db = setup_db().await;
+    let notes = db.notes();
+    notes
+        .create(&make_note(
+            "fts00012",
+            "Markup",
+            "Embed <script>alert(1)</script> & <mark>fake</mark> near widget",
+        ))
+        .await
+        .unwrap();
+
+    let results = notes.search_snippets("widget", None).await.unwrap();
+    let snippet = &results.items[0].snippet;
+    assert!(snippet.contains("&lt;script&gt;"), "{}", snippet);
+    assert!(snippet.contains("&amp; &lt;mark&gt;fake"), "{}", snippet);
+    assert!(!snippet.contains("<script>"), "{}", snippet);
+    assert_eq!(snippet.matches("<mark>").count(), 1, "{}", snippet);
+    assert!(snippet.contains("<mark>widget</mark>"), "{}", snippet);
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn fts5_search_combines_tag_and_content_results() {
     let db = setup_db().await;
@@ -522,6 +585,30 @@ async fn fts5_search_handles_whitespace_only() {
     );
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn fts5_search_handles_queries_without_terms() {
+    let db = setup_db().await;
+    let notes = db.notes();
+
+    notes
+        .create(&make_note("nt000001", "Searchable", "Some content"))
+        .await
+        .unwrap();
+
+    for term in ["", "\"\"", "AND", "  NOT  "] {
+        let result = notes.search(term, None).await;
+        assert!(result.is_ok(), "Search for {:?} should not error", term);
+
+        let result = result.unwrap();
+        assert!(
+            result.items.is_empty(),
+            "Search for {:?} should be empty",
+            term
+        );
+        assert_eq!(result.total, 0);
+    }
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn note_create_and_get() {
     let db = setup_db().await;
@@ -2499,3 +2586,97 @@ async fn list_metadata_only_filters_by_project_id() {
         "non-existent project should return 0 notes"
     );
 }
+
+// =============================================================================
+// Concurrent Index Maintenance
+// =============================================================================
+
+#[tokio::test(flavor = "multi_thread")]
+async fn search_tolerates_concurrent_index_rebuild() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let db = SqliteDatabase::open(temp_dir.path().join("context.db"))
+        .await
+        .unwrap();
+    db.migrate().unwrap();
+
+    let mode: String = sqlx::query_scalar("PRAGMA journal_mode")
+        .fetch_one(db.pool())
+        .await
+        .unwrap();
+    assert_eq!(mode, "wal");
+
+    for i in 0..50 {
+        let content = if i % 5 == 0 {
+            "kubernetes rollout notes"
+        } else {
+            "unrelated content"
+        };
+        db.notes()
+            .create(&make_note(&generate_id(), &format!("Note {}", i), content))
+            .await
+            .unwrap();
+    }
+
+    let rebuild = async {
+        for _ in 0..10 {
+            db.notes().rebuild_search_index().await.unwrap();
+        }
+    };
+    let search = async {
+        for _ in 0..20 {
+            let result = db.notes().search("kubernetes", None).await.unwrap();
+            assert_eq!(result.total, 10);
+            assert_eq!(result.items.len(), 10);
+        }
+    };
+    tokio::join!(rebuild, search);
+
+    // The rebuilt index still matches the stored notes
+    let result = db.notes().search("rollout", None).await.unwrap();
+    assert_eq!(result.total, 10);
+}
+
+// =============================================================================
+// Content Hash Tests
+// =============================================================================
+
+#[tokio::test(flavor = "multi_thread")]
+async fn find_by_content_matches_identical_content_only() {
+    let db = setup_db().await;
+    let notes = db.notes();
+
+    let note = make_note(&generate_id(), "Original", "Deploy checklist");
+    notes.create(&note).await.expect("Create should succeed");
+
+    let found = notes
+        .find_by_content("Deploy checklist")
+        .await
+        .expect("Lookup should succeed");
+    assert_eq!(found.map(|n| n.id), Some(note.id.clone()));
+
+    let missing = notes
+        .find_by_content("Deploy checklist ")
+        .await
+        .expect("Lookup should succeed");
+    assert!(missing.is_none());
+
+    // Updating the content moves the note to the new hash
+    let mut updated = notes.get(&note.id).await.unwrap();
+    updated.content = "Release checklist".to_string();
+    notes.update(&updated).await.expect("Update should succeed");
+
+    assert!(
+        notes
+            .find_by_content("Deploy checklist")
+            .await
+            .unwrap()
+            .is_none()
+    );
+    assert!(
+        notes
+            .find_by_content("Release checklist")
+            .await
+            .unwrap()
+            .is_some()
+    );
+}
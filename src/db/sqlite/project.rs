@@ -1,9 +1,12 @@
 //! SQLite ProjectRepository implementation.
 
-use sqlx::{Row, SqlitePool};
+use sqlx::{Row, SqliteConnection, SqlitePool};
 
-use super::helpers::{build_limit_offset_clause, build_order_clause};
-use crate::db::utils::{current_timestamp, generate_entity_id};
+use super::helpers::{
+    build_limit_offset_clause, build_order_clause, check_inserted, clear_deleted_links, count_rows,
+    revive_deleted, window_total, with_id_tiebreak, with_window_total,
+};
+use crate::db::utils::{IdLength, current_timestamp, generate_entity_id_with_length};
 use crate::db::{
     DbError, DbResult, ListResult, Project, ProjectQuery, ProjectRepository, SortOrder,
 };
@@ -11,6 +14,8 @@ use crate::db::{
 /// SQLx-backed project repository.
 pub struct SqliteProjectRepository<'a> {
     pub(crate) pool: &'a SqlitePool,
+    /// Length of generated IDs
+    pub(crate) id_length: IdLength,
 }
 
 fn validate_project(project: &Project) -> DbResult<()> {
@@ -30,6 +35,107 @@ fn validate_project(project: &Project) -> DbResult<()> {
     }
 }
 
+/// Check that every repo a new project links to exists.
+async fn check_repos_exist(conn: &mut SqliteConnection, repo_ids: &[String]) -> DbResult<()> {
+    for repo_id in repo_ids {
+        let exists: bool = sqlx::query_scalar(
+            "SELECT EXISTS(SELECT 1 FROM repo WHERE id = ? AND deleted_at IS NULL)",
+        )
+        .bind(repo_id)
+        .fetch_one(&mut *conn)
+        .await
+        .map_err(|e| DbError::Database {
+            message: e.to_string(),
+        })?;
+
+        if !exists {
+            return Err(DbError::NotFound {
+                entity_type: "Repo".to_string(),
+                id: repo_id.clone(),
+            });
+        }
+    }
+    Ok(())
+}
+
+impl SqliteProjectRepository<'_> {
+    /// Load a project by ID; with `include_deleted`, soft-deleted rows too.
+    ///
+    /// Sync export uses this to write tombstones for deleted rows.
+    pub(crate) async fn fetch(&self, id: &str, include_deleted: bool) -> DbResult<Project> {
+        let row = sqlx::query(
+            "SELECT id, title, description, tags, external_refs, created_at, updated_at FROM project WHERE id = ? AND (deleted_at IS NULL OR ?)",
+        )
+        .bind(id)
+        .bind(include_deleted)
+        .fetch_optional(self.pool)
+        .await
+        .map_err(|e| DbError::Database {
+            message: e.to_string(),
+        })?;
+
+        let row = row.ok_or(DbError::NotFound {
+            entity_type: "Project".to_string(),
+            id: id.to_string(),
+        })?;
+
+        let tags_json: String = row.get("tags");
+        let tags: Vec<String> = serde_json::from_str(&tags_json).unwrap_or_default();
+
+        let external_refs_json: String = row.get("external_refs");
+        let external_refs: Vec<String> =
+            serde_json::from_str(&external_refs_json).unwrap_or_default();
+
+        // Get repo relationships
+        let repo_ids: Vec<String> = sqlx::query_scalar(
+            "SELECT pr.repo_id FROM project_repo pr JOIN repo r ON r.id = pr.repo_id
+             WHERE pr.project_id = ? AND r.deleted_at IS NULL",
+        )
+        .bind(id)
+        .fetch_all(self.pool)
+        .await
+        .map_err(|e| DbError::Database {
+            message: e.to_string(),
+        })?;
+
+        // Get task list relationships (now 1:N from task_list.project_id)
+        let task_list_ids: Vec<String> = sqlx::query_scalar(
+            "SELECT id FROM task_list WHERE project_id = ? AND deleted_at IS NULL",
+        )
+        .bind(id)
+        .fetch_all(self.pool)
+        .await
+        .map_err(|e| DbError::Database {
+            message: e.to_string(),
+        })?;
+
+        // Get note relationships
+        let note_ids: Vec<String> = sqlx::query_scalar(
+            "SELECT pn.note_id FROM project_note pn JOIN note n ON n.id = pn.note_id
+             WHERE pn.project_id = ? AND n.deleted_at IS NULL",
+        )
+        .bind(id)
+        .fetch_all(self.pool)
+        .await
+        .map_err(|e| DbError::Database {
+            message: e.to_string(),
+        })?;
+
+        Ok(Project {
+            id: row.get("id"),
+            title: row.get("title"),
+            description: row.get("description"),
+            tags,
+            external_refs,
+            repo_ids,
+            task_list_ids,
+            note_ids,
+            created_at: Some(row.get("created_at")),
+            updated_at: Some(row.get("updated_at")),
+        })
+    }
+}
+
 impl<'a> ProjectRepository for SqliteProjectRepository<'a> {
     async fn create(&self, project: &Project) -> DbResult<Project> {
         // Validate project
@@ -37,7 +143,7 @@ impl<'a> ProjectRepository for SqliteProjectRepository<'a> {
 
         // Use provided ID if not empty, otherwise generate one
         let id = if project.id.is_empty() {
-            generate_entity_id()
+            generate_entity_id_with_length(self.id_length)
         } else {
             project.id.clone()
         };
@@ -63,19 +169,52 @@ impl<'a> ProjectRepository for SqliteProjectRepository<'a> {
                 message: format!("Failed to serialize external_refs: {}", e),
             })?;
 
-        sqlx::query("INSERT INTO project (id, title, description, tags, external_refs, created_at, updated_at) VALUES (?, ?, ?, ?, ?, ?, ?)")
-            .bind(&id)
-            .bind(&project.title)
-            .bind(&project.description)
-            .bind(&tags_json)
-            .bind(&external_refs_json)
-            .bind(&created_at)
-            .bind(&updated_at)
-            .execute(self.pool)
-            .await
-            .map_err(|e| DbError::Database {
-                message: e.to_string(),
-            })?;
+        // Begin transaction so the project and its repo links land together
+        let mut tx = self.pool.begin().await.map_err(|e| DbError::Database {
+            message: e.to_string(),
+        })?;
+
+        check_repos_exist(&mut tx, &project.repo_ids).await?;
+
+        // A deleted project keeps its row; reusing the ID revives it in place
+        clear_deleted_links(&mut tx, "project", &id).await?;
+
+        let result = sqlx::query(&format!(
+            "INSERT INTO project (id, title, description, tags, external_refs, created_at, updated_at) VALUES (?, ?, ?, ?, ?, ?, ?) {}",
+            revive_deleted(
+                "project",
+                &["title", "description", "tags", "external_refs", "created_at", "updated_at"],
+            )
+        ))
+        .bind(&id)
+        .bind(&project.title)
+        .bind(&project.description)
+        .bind(&tags_json)
+        .bind(&external_refs_json)
+        .bind(&created_at)
+        .bind(&updated_at)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DbError::Database {
+            message: e.to_string(),
+        })?;
+        check_inserted(&result, "Project", &id)?;
+
+        // Insert repo relationships
+        for repo_id in &project.repo_ids {
+            sqlx::query("INSERT OR IGNORE INTO project_repo (project_id, repo_id) VALUES (?, ?)")
+                .bind(&id)
+                .bind(repo_id)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| DbError::Database {
+                    message: e.to_string(),
+                })?;
+        }
+
+        tx.commit().await.map_err(|e| DbError::Database {
+            message: e.to_string(),
+        })?;
 
         Ok(Project {
             id,
@@ -83,7 +222,7 @@ impl<'a> ProjectRepository for SqliteProjectRepository<'a> {
             description: project.description.clone(),
             tags: project.tags.clone(),
             external_refs: project.external_refs.clone(),
-            repo_ids: vec![],
+            repo_ids: project.repo_ids.clone(),
             task_list_ids: vec![],
             note_ids: vec![],
             created_at: Some(created_at),
@@ -92,70 +231,31 @@ impl<'a> ProjectRepository for SqliteProjectRepository<'a> {
     }
 
     async fn get(&self, id: &str) -> DbResult<Project> {
-        let row = sqlx::query(
-            "SELECT id, title, description, tags, external_refs, created_at, updated_at FROM project WHERE id = ?",
+        self.fetch(id, false).await
+    }
+
+    async fn get_by_external_ref(&self, external_ref: &str) -> DbResult<Project> {
+        // Matches any element of the JSON array, not just the first
+        let id: Option<String> = sqlx::query_scalar(
+            "SELECT id FROM project \
+             WHERE deleted_at IS NULL \
+               AND EXISTS (SELECT 1 FROM json_each(project.external_refs) WHERE json_each.value = ?) \
+             ORDER BY created_at, id LIMIT 1",
         )
-        .bind(id)
+        .bind(external_ref)
         .fetch_optional(self.pool)
         .await
         .map_err(|e| DbError::Database {
             message: e.to_string(),
         })?;
 
-        let row = row.ok_or(DbError::NotFound {
-            entity_type: "Project".to_string(),
-            id: id.to_string(),
-        })?;
-
-        let tags_json: String = row.get("tags");
-        let tags: Vec<String> = serde_json::from_str(&tags_json).unwrap_or_default();
-
-        let external_refs_json: String = row.get("external_refs");
-        let external_refs: Vec<String> =
-            serde_json::from_str(&external_refs_json).unwrap_or_default();
-
-        // Get repo relationships
-        let repo_ids: Vec<String> =
-            sqlx::query_scalar("SELECT repo_id FROM project_repo WHERE project_id = ?")
-                .bind(id)
-                .fetch_all(self.pool)
-                .await
-                .map_err(|e| DbError::Database {
-                    message: e.to_string(),
-                })?;
-
-        // Get task list relationships (now 1:N from task_list.project_id)
-        let task_list_ids: Vec<String> =
-            sqlx::query_scalar("SELECT id FROM task_list WHERE project_id = ?")
-                .bind(id)
-                .fetch_all(self.pool)
-                .await
-                .map_err(|e| DbError::Database {
-                    message: e.to_string(),
-                })?;
-
-        // Get note relationships
-        let note_ids: Vec<String> =
-            sqlx::query_scalar("SELECT note_id FROM project_note WHERE project_id = ?")
-                .bind(id)
-                .fetch_all(self.pool)
-                .await
-                .map_err(|e| DbError::Database {
-                    message: e.to_string(),
-                })?;
-
-        Ok(Project {
-            id: row.get("id"),
-            title: row.get("title"),
-            description: row.get("description"),
-            tags,
-            external_refs,
-            repo_ids,
-            task_list_ids,
-            note_ids,
-            created_at: Some(row.get("created_at")),
-            updated_at: Some(row.get("updated_at")),
-        })
+        match id {
+            Some(id) => self.get(&id).await,
+            None => Err(DbError::NotFound {
+                entity_type: "Project".to_string(),
+                id: external_ref.to_string(),
+            }),
+        }
     }
 
     async fn list(&self, query: Option<&ProjectQuery>) -> DbResult<ListResult<Project>> {
@@ -163,11 +263,14 @@ impl<'a> ProjectRepository for SqliteProjectRepository<'a> {
         let query = query.unwrap_or(&default_query);
         let allowed_fields = ["title", "created_at", "updated_at"];
 
-        let order_clause = build_order_clause(&query.page, &allowed_fields, "created_at");
+        let order_clause = with_id_tiebreak(
+            &build_order_clause(&query.page, &allowed_fields, "created_at"),
+            "id",
+        );
         let limit_clause = build_limit_offset_clause(&query.page);
 
         // Build conditions and bind values
-        let mut conditions: Vec<String> = vec![];
+        let mut conditions: Vec<String> = vec!["deleted_at IS NULL".to_string()];
         let mut bind_values: Vec<String> = vec![];
 
         // Tag filtering requires json_each join
@@ -181,19 +284,15 @@ impl<'a> ProjectRepository for SqliteProjectRepository<'a> {
             bind_values.extend(tags.clone());
         }
 
-        let where_clause = if conditions.is_empty() {
-            String::new()
-        } else {
-            format!("WHERE {}", conditions.join(" AND "))
-        };
+        let where_clause = format!("WHERE {}", conditions.join(" AND "));
 
         // Build SQL based on whether we need json_each
-        let (sql, count_sql) = if needs_json_each {
+        let (inner_sql, count_sql) = if needs_json_each {
             (
                 format!(
                     "SELECT DISTINCT p.id, p.title, p.description, p.tags, p.external_refs, p.created_at, p.updated_at \
-                     FROM project p, json_each(p.tags) {} {} {}",
-                    where_clause, order_clause, limit_clause
+                     FROM project p, json_each(p.tags) {}",
+                    where_clause
                 ),
                 format!(
                     "SELECT COUNT(DISTINCT p.id) FROM project p, json_each(p.tags) {}",
@@ -203,14 +302,15 @@ impl<'a> ProjectRepository for SqliteProjectRepository<'a> {
         } else {
             (
                 format!(
-                    "SELECT id, title, description, tags, external_refs, created_at, updated_at FROM project {} {}",
-                    order_clause, limit_clause
+                    "SELECT id, title, description, tags, external_refs, created_at, updated_at FROM project {}",
+                    where_clause
                 ),
-                "SELECT COUNT(*) FROM project".to_string(),
+                format!("SELECT COUNT(*) FROM project {}", where_clause),
             )
         };
+        let sql = with_window_total(&inner_sql, &order_clause, &limit_clause);
 
-        // Execute main query
+        // Execute main query; the total comes back with every row
         let mut sql_query = sqlx::query(&sql);
         for value in &bind_values {
             sql_query = sql_query.bind(value);
@@ -223,6 +323,12 @@ impl<'a> ProjectRepository for SqliteProjectRepository<'a> {
                 message: e.to_string(),
             })?;
 
+        let total = match window_total(&rows, &query.page) {
+            Some(total) => total,
+            // Offset past the end: no rows to read the total from
+            None => count_rows(self.pool, &count_sql, &bind_values).await?,
+        };
+
         let items: Vec<Project> = rows
             .into_iter()
             .map(|row| {
@@ -246,34 +352,23 @@ impl<'a> ProjectRepository for SqliteProjectRepository<'a> {
             })
             .collect();
 
-        // Execute count query
-        let mut count_query = sqlx::query_scalar(&count_sql);
-        for value in &bind_values {
-            count_query = count_query.bind(value);
-        }
-
-        let total: i64 = count_query
-            .fetch_one(self.pool)
-            .await
-            .map_err(|e| DbError::Database {
-                message: e.to_string(),
-            })?;
-
         Ok(ListResult {
             items,
-            total: total as usize,
+            total,
             limit: query.page.limit,
             offset: query.page.offset.unwrap_or(0),
+            next_cursor: None,
         })
     }
 
     async fn count(&self) -> DbResult<usize> {
-        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM project")
-            .fetch_one(self.pool)
-            .await
-            .map_err(|e| DbError::Database {
-                message: e.to_string(),
-            })?;
+        let count: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM project WHERE deleted_at IS NULL")
+                .fetch_one(self.pool)
+                .await
+                .map_err(|e| DbError::Database {
+                    message: e.to_string(),
+                })?;
         Ok(count as usize)
     }
 
@@ -293,7 +388,7 @@ impl<'a> ProjectRepository for SqliteProjectRepository<'a> {
         let updated_at = project.updated_at.clone().unwrap_or_else(current_timestamp);
 
         let result = sqlx::query(
-            "UPDATE project SET title = ?, description = ?, tags = ?, external_refs = ?, updated_at = ? WHERE id = ?",
+            "UPDATE project SET title = ?, description = ?, tags = ?, external_refs = ?, updated_at = ? WHERE id = ? AND deleted_at IS NULL",
         )
         .bind(&project.title)
         .bind(&project.description)
@@ -318,6 +413,61 @@ impl<'a> ProjectRepository for SqliteProjectRepository<'a> {
     }
 
     async fn delete(&self, id: &str) -> DbResult<()> {
+        let deleted_at = current_timestamp();
+        let mut tx = self.pool.begin().await.map_err(|e| DbError::Database {
+            message: e.to_string(),
+        })?;
+
+        let result =
+            sqlx::query("UPDATE project SET deleted_at = ? WHERE id = ? AND deleted_at IS NULL")
+                .bind(&deleted_at)
+                .bind(id)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| DbError::Database {
+                    message: e.to_string(),
+                })?;
+
+        if result.rows_affected() == 0 {
+            return Err(DbError::NotFound {
+                entity_type: "Project".to_string(),
+                id: id.to_string(),
+            });
+        }
+
+        // Task lists and their tasks go with the project, as a purge would
+        // cascade to them
+        sqlx::query(
+            "UPDATE task SET deleted_at = ?
+             WHERE deleted_at IS NULL
+               AND list_id IN (SELECT id FROM task_list WHERE project_id = ?)",
+        )
+        .bind(&deleted_at)
+        .bind(id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DbError::Database {
+            message: e.to_string(),
+        })?;
+        sqlx::query(
+            "UPDATE task_list SET deleted_at = ? WHERE project_id = ? AND deleted_at IS NULL",
+        )
+        .bind(&deleted_at)
+        .bind(id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DbError::Database {
+            message: e.to_string(),
+        })?;
+
+        tx.commit().await.map_err(|e| DbError::Database {
+            message: e.to_string(),
+        })?;
+
+        Ok(())
+    }
+
+    async fn purge(&self, id: &str) -> DbResult<()> {
         let result = sqlx::query("DELETE FROM project WHERE id = ?")
             .bind(id)
             .execute(self.pool)
@@ -354,12 +504,16 @@ impl<'a> ProjectRepository for SqliteProjectRepository<'a> {
                     total: 0,
                     limit: query.page.limit,
                     offset: query.page.offset.unwrap_or(0),
+                    next_cursor: None,
                 });
             }
         };
 
         let mut bind_values: Vec<String> = vec![fts_query];
-        let mut where_conditions: Vec<String> = vec!["project_fts MATCH ?".to_string()];
+        let mut where_conditions: Vec<String> = vec![
+            "project_fts MATCH ?".to_string(),
+            "p.deleted_at IS NULL".to_string(),
+        ];
 
         // Check if we need JOINs for tag filtering
         let needs_json_each = query.tags.as_ref().is_some_and(|t| !t.is_empty());
@@ -389,7 +543,7 @@ impl<'a> ProjectRepository for SqliteProjectRepository<'a> {
                 SortOrder::Desc => "DESC",
             };
 
-            format!("ORDER BY p.{} {}", sort_field, order)
+            format!("ORDER BY p.{} {}, p.id ASC", sort_field, order)
         };
 
         // Build FROM clause with necessary JOINs
@@ -471,6 +625,7 @@ impl<'a> ProjectRepository for SqliteProjectRepository<'a> {
             total,
             limit: query.page.limit,
             offset: query.page.offset.unwrap_or(0),
+            next_cursor: None,
         })
     }
 }
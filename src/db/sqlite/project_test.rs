@@ -1,6 +1,9 @@
 //! Tests for SqliteProjectRepository.
 
-use crate::db::{Database, Project, ProjectQuery, ProjectRepository, SqliteDatabase};
+use crate::db::{
+    Database, DbError, Project, ProjectQuery, ProjectRepository, Repo, RepoQuery, RepoRepository,
+    SqliteDatabase, TaskList, TaskListRepository, TaskListStatus,
+};
 
 async fn setup_db() -> SqliteDatabase {
     let db = SqliteDatabase::in_memory()
@@ -132,6 +135,91 @@ async fn delete_project() {
     assert!(result.is_err());
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn deleted_project_hides_its_task_lists_and_frees_its_id() {
+    let db = setup_db().await;
+    let repo = db.projects();
+
+    let project = Project {
+        id: "softdel1".to_string(),
+        title: "Soft Deleted".to_string(),
+        description: None,
+        tags: vec![],
+        external_refs: vec![],
+        repo_ids: vec![],
+        task_list_ids: vec![],
+        note_ids: vec![],
+        created_at: None,
+        updated_at: None,
+    };
+    repo.create(&project).await.expect("Create should succeed");
+    db.task_lists()
+        .create(&TaskList {
+            id: "softlst1".to_string(),
+            title: "Owned".to_string(),
+            description: None,
+            notes: None,
+            tags: vec![],
+            external_refs: vec![],
+            status: TaskListStatus::Active,
+            repo_ids: vec![],
+            project_id: "softdel1".to_string(),
+            created_at: None,
+            updated_at: None,
+            archived_at: None,
+        })
+        .await
+        .expect("Create task list should succeed");
+
+    repo.delete("softdel1")
+        .await
+        .expect("Delete should succeed");
+
+    assert!(matches!(
+        repo.get("softdel1").await,
+        Err(DbError::NotFound { .. })
+    ));
+    assert!(matches!(
+        db.task_lists().get("softlst1").await,
+        Err(DbError::NotFound { .. })
+    ));
+    let listed = repo.list(None).await.expect("List should succeed");
+    assert!(listed.items.iter().all(|p| p.id != "softdel1"));
+
+    // Creating with the same ID revives the deleted project; its deleted
+    // task list stays behind as a tombstone
+    let recreated = repo
+        .create(&Project {
+            title: "Back Again".to_string(),
+            ..project.clone()
+        })
+        .await
+        .expect("Create should reuse a deleted ID");
+    assert_eq!(recreated.title, "Back Again");
+    assert!(db.task_lists().get("softlst1").await.is_err());
+    let list_deleted: bool =
+        sqlx::query_scalar("SELECT deleted_at IS NOT NULL FROM task_list WHERE id = ?")
+            .bind("softlst1")
+            .fetch_one(db.pool())
+            .await
+            .unwrap();
+    assert!(list_deleted);
+
+    // A live project's ID is still taken
+    assert!(matches!(
+        repo.create(&project).await,
+        Err(DbError::AlreadyExists { .. })
+    ));
+
+    repo.purge("softdel1").await.expect("Purge should succeed");
+    let stored: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM project WHERE id = ?")
+        .bind("softdel1")
+        .fetch_one(db.pool())
+        .await
+        .unwrap();
+    assert_eq!(stored, 0);
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn project_create_with_tags() {
     let db = setup_db().await;
@@ -727,6 +815,7 @@ async fn list_projects_with_offset_without_limit() {
             offset: Some(1),
             sort_by: None,
             sort_order: None,
+            after: None,
         },
         tags: None,
     };
@@ -745,6 +834,91 @@ async fn list_projects_with_offset_without_limit() {
     assert_eq!(result.items[1].title, "Project 3");
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn list_projects_single_query_total_matches_pages() {
+    let db = setup_db().await;
+    let repo = db.projects();
+
+    for i in 1..=5 {
+        // Two matching tags per even project so the tag join yields duplicate rows
+        let tags = if i % 2 == 0 {
+            vec!["rust".to_string(), "cli".to_string()]
+        } else {
+            vec!["python".to_string()]
+        };
+        repo.create(&Project {
+            id: format!("window{:02}", i),
+            title: format!("Project {}", i),
+            description: None,
+            tags,
+            external_refs: vec![],
+            repo_ids: vec![],
+            task_list_ids: vec![],
+            note_ids: vec![],
+            created_at: Some(format!("2025-01-01 00:00:{:02}", i)),
+            updated_at: Some(format!("2025-01-01 00:00:{:02}", i)),
+        })
+        .await
+        .unwrap();
+    }
+
+    let filters = [None, Some(vec!["rust".to_string(), "cli".to_string()])];
+    let pages = [
+        (None, None),
+        (Some(2), None),
+        (Some(2), Some(1)),
+        (Some(2), Some(4)),
+        (None, Some(1)),
+        (Some(10), Some(10)),
+    ];
+
+    for tags in filters {
+        let all = repo
+            .list(Some(&ProjectQuery {
+                tags: tags.clone(),
+                ..Default::default()
+            }))
+            .await
+            .unwrap();
+        assert_eq!(all.total, all.items.len());
+
+        for (limit, offset) in pages {
+            let result = repo
+                .list(Some(&ProjectQuery {
+                    page: crate::db::PageSort {
+                        limit,
+                        offset,
+                        sort_by: None,
+                        sort_order: None,
+                        after: None,
+                    },
+                    tags: tags.clone(),
+                }))
+                .await
+                .unwrap();
+
+            let start = offset.unwrap_or(0).min(all.items.len());
+            let end = limit.map_or(all.items.len(), |l| (start + l).min(all.items.len()));
+            let expected: Vec<&str> = all.items[start..end]
+                .iter()
+                .map(|p| p.id.as_str())
+                .collect();
+            let actual: Vec<&str> = result.items.iter().map(|p| p.id.as_str()).collect();
+
+            assert_eq!(
+                actual, expected,
+                "items for limit={:?} offset={:?}",
+                limit, offset
+            );
+            assert_eq!(
+                result.total, all.total,
+                "total for limit={:?} offset={:?}",
+                limit, offset
+            );
+        }
+    }
+}
+
 // =============================================================================
 // Validation Tests
 // =============================================================================
@@ -834,3 +1008,206 @@ async fn update_project_with_empty_title_should_fail() {
     let result = projects.update(&updated).await;
     assert!(result.is_err(), "Update should fail with empty title");
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn paginate_all_collects_every_page() {
+    use crate::db::SortOrder;
+    use crate::db::pagination::paginate_all;
+    use std::collections::HashSet;
+
+    let db = setup_db().await;
+    let repo = db.projects();
+
+    for i in 0..25 {
+        let project = Project {
+            id: format!("{:08x}", i + 1),
+            title: format!("Project {:02}", i),
+            description: None,
+            tags: vec![],
+            external_refs: vec![],
+            repo_ids: vec![],
+            task_list_ids: vec![],
+            note_ids: vec![],
+            created_at: None,
+            updated_at: None,
+        };
+        repo.create(&project).await.expect("Create should succeed");
+    }
+
+    let mut calls = 0;
+    let projects = paginate_all(10, |mut page| {
+        calls += 1;
+        page.sort_by = Some("title".to_string());
+        page.sort_order = Some(SortOrder::Asc);
+        let repo = &repo;
+        async move {
+            repo.list(Some(&ProjectQuery {
+                page,
+                ..Default::default()
+            }))
+            .await
+        }
+    })
+    .await
+    .expect("paginate_all should succeed");
+
+    assert_eq!(projects.len(), 25);
+    let ids: HashSet<_> = projects.iter().map(|p| p.id.as_str()).collect();
+    assert_eq!(ids.len(), 25, "no project should be returned twice");
+    assert_eq!(calls, 3, "should stop at total without an extra empty page");
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn list_projects_breaks_created_at_ties_by_id() {
+    let db = setup_db().await;
+    let repo = db.projects();
+
+    // Insert out of id order, all sharing one timestamp
+    for id in ["tiebrk03", "tiebrk01", "tiebrk04", "tiebrk02"] {
+        repo.create(&Project {
+            id: id.to_string(),
+            title: format!("Project {}", id),
+            description: None,
+            tags: vec!["same".to_string()],
+            external_refs: vec![],
+            repo_ids: vec![],
+            task_list_ids: vec![],
+            note_ids: vec![],
+            created_at: Some("2025-01-01 00:00:00".to_string()),
+            updated_at: Some("2025-01-01 00:00:00".to_string()),
+        })
+        .await
+        .unwrap();
+    }
+
+    let expected = vec!["tiebrk01", "tiebrk02", "tiebrk03", "tiebrk04"];
+    let queries = [
+        ProjectQuery::default(),
+        ProjectQuery {
+            tags: Some(vec!["same".to_string()]),
+            ..Default::default()
+        },
+    ];
+
+    for query in &queries {
+        for _ in 0..3 {
+            let result = repo.list(Some(query)).await.expect("List should succeed");
+            let ids: Vec<&str> = result.items.iter().map(|p| p.id.as_str()).collect();
+            assert_eq!(ids, expected);
+        }
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn create_project_links_repos_atomically() {
+    let db = setup_db().await;
+
+    for (id, remote) in [
+        ("linkrp01", "github:acme/api"),
+        ("linkrp02", "github:acme/web"),
+    ] {
+        db.repos()
+            .create(&Repo {
+                id: id.to_string(),
+                remote: remote.to_string(),
+                path: None,
+                tags: vec![],
+                project_ids: vec![],
+                created_at: None,
+                updated_at: None,
+            })
+            .await
+            .expect("Repo create should succeed");
+    }
+
+    let project = |id: &str, repo_ids: &[&str]| Project {
+        id: id.to_string(),
+        title: format!("Project {}", id),
+        description: None,
+        tags: vec![],
+        external_refs: vec![],
+        repo_ids: repo_ids.iter().map(|r| r.to_string()).collect(),
+        task_list_ids: vec![],
+        note_ids: vec![],
+        created_at: None,
+        updated_at: None,
+    };
+
+    let created = db
+        .projects()
+        .create(&project("linked01", &["linkrp01", "linkrp02"]))
+        .await
+        .expect("Create should succeed");
+    assert_eq!(created.repo_ids, vec!["linkrp01", "linkrp02"]);
+
+    let mut repo_ids = db.projects().get("linked01").await.unwrap().repo_ids;
+    repo_ids.sort();
+    assert_eq!(repo_ids, vec!["linkrp01", "linkrp02"]);
+
+    let linked = db
+        .repos()
+        .list(Some(&RepoQuery {
+            project_id: Some("linked01".to_string()),
+            ..Default::default()
+        }))
+        .await
+        .unwrap();
+    assert_eq!(linked.total, 2);
+
+    // One missing repo rolls the whole create back
+    let err = db
+        .projects()
+        .create(&project("linked02", &["linkrp01", "missing1"]))
+        .await
+        .expect_err("Create with a missing repo should fail");
+    assert!(matches!(err, DbError::NotFound { ref id, .. } if id == "missing1"));
+    assert!(matches!(
+        db.projects().get("linked02").await,
+        Err(DbError::NotFound { .. })
+    ));
+    let still_linked = db.repos().get("linkrp01").await.unwrap().project_ids;
+    assert_eq!(still_linked, vec!["linked01"]);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn get_by_external_ref_matches_any_ref() {
+    let db = setup_db().await;
+    let repo = db.projects();
+
+    let project = Project {
+        id: "extref01".to_string(),
+        title: "Tracked Project".to_string(),
+        description: None,
+        tags: vec![],
+        external_refs: vec!["PROJ-123".to_string(), "owner/repo#45".to_string()],
+        repo_ids: vec![],
+        task_list_ids: vec![],
+        note_ids: vec![],
+        created_at: None,
+        updated_at: None,
+    };
+    repo.create(&project).await.expect("Create should succeed");
+
+    // Every ref is stored and read back in order
+    let retrieved = repo.get("extref01").await.unwrap();
+    assert_eq!(retrieved.external_refs, project.external_refs);
+
+    for external_ref in ["PROJ-123", "owner/repo#45"] {
+        let found = repo
+            .get_by_external_ref(external_ref)
+            .await
+            .expect("Lookup should succeed");
+        assert_eq!(found.id, "extref01");
+    }
+
+    // Exact matches only, and deleted projects are not found
+    assert!(matches!(
+        repo.get_by_external_ref("PROJ-12").await,
+        Err(DbError::NotFound { .. })
+    ));
+    repo.delete("extref01").await.unwrap();
+    assert!(matches!(
+        repo.get_by_external_ref("PROJ-123").await,
+        Err(DbError::NotFound { .. })
+    ));
+}
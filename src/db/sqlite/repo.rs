@@ -1,14 +1,47 @@
 //! SQLite RepoRepository implementation.
 
-use sqlx::{Row, SqlitePool};
+use sqlx::{Row, SqliteConnection, SqlitePool};
 
-use super::helpers::build_limit_offset_clause;
-use crate::db::utils::{current_timestamp, generate_entity_id};
-use crate::db::{DbError, DbResult, ListResult, Repo, RepoQuery, RepoRepository};
+use super::helpers::{
+    build_limit_offset_clause, check_inserted, clear_deleted_links, count_rows, revive_deleted,
+    window_total, with_window_total,
+};
+use crate::db::utils::{
+    IdLength, current_timestamp, generate_entity_id_with_length, normalize_remote,
+};
+use crate::db::{DbError, DbResult, ListResult, Repo, RepoDependents, RepoQuery, RepoRepository};
 
 /// SQLx-backed repo repository.
 pub struct SqliteRepoRepository<'a> {
     pub(crate) pool: &'a SqlitePool,
+    /// Length of generated IDs
+    pub(crate) id_length: IdLength,
+}
+
+/// Escape `%`, `_` and `\` so a search term matches literally in a LIKE pattern.
+fn escape_like(term: &str) -> String {
+    let mut escaped = String::with_capacity(term.len());
+    for c in term.chars() {
+        if matches!(c, '%' | '_' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Remove every project, task list and note link to the repo.
+async fn unlink_repo(tx: &mut SqliteConnection, id: &str) -> DbResult<()> {
+    for table in ["project_repo", "task_list_repo", "note_repo"] {
+        sqlx::query(&format!("DELETE FROM {} WHERE repo_id = ?", table))
+            .bind(id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| DbError::Database {
+                message: e.to_string(),
+            })?;
+    }
+    Ok(())
 }
 
 fn validate_repo(repo: &Repo) -> DbResult<()> {
@@ -28,76 +61,29 @@ fn validate_repo(repo: &Repo) -> DbResult<()> {
     }
 }
 
-impl<'a> RepoRepository for SqliteRepoRepository<'a> {
-    async fn create(&self, repo: &Repo) -> DbResult<Repo> {
-        // Validate repo
-        validate_repo(repo)?;
-
-        // Use provided ID if not empty, otherwise generate one
-        let id = if repo.id.is_empty() {
-            generate_entity_id()
-        } else {
-            repo.id.clone()
-        };
-
-        // Respect input timestamp or generate if None/empty (see utils.rs for policy)
-        let created_at = repo
-            .created_at
-            .clone()
-            .filter(|s| !s.is_empty())
-            .or_else(|| Some(current_timestamp()));
-
-        let tags_json = serde_json::to_string(&repo.tags).map_err(|e| DbError::Database {
-            message: format!("Failed to serialize tags: {}", e),
-        })?;
-
-        // Begin transaction for atomicity
-        let mut tx = self.pool.begin().await.map_err(|e| DbError::Database {
-            message: e.to_string(),
-        })?;
-
-        sqlx::query("INSERT INTO repo (id, remote, path, tags, created_at) VALUES (?, ?, ?, ?, ?)")
-            .bind(&id)
-            .bind(&repo.remote)
-            .bind(&repo.path)
-            .bind(&tags_json)
-            .bind(&created_at)
-            .execute(&mut *tx)
-            .await
-            .map_err(|e| DbError::Database {
-                message: e.to_string(),
-            })?;
-
-        // Insert project relationships
-        for project_id in &repo.project_ids {
-            sqlx::query("INSERT INTO project_repo (project_id, repo_id) VALUES (?, ?)")
-                .bind(project_id)
-                .bind(&id)
-                .execute(&mut *tx)
-                .await
-                .map_err(|e| DbError::Database {
-                    message: e.to_string(),
-                })?;
-        }
-
-        // Commit transaction
-        tx.commit().await.map_err(|e| DbError::Database {
+/// Map a unique violation on the remote columns to `AlreadyExists`.
+fn remote_conflict(e: sqlx::Error, remote: &str) -> DbError {
+    match e.as_database_error() {
+        Some(db_err) if db_err.is_unique_violation() => DbError::AlreadyExists {
+            entity_type: "Repo".to_string(),
+            id: remote.to_string(),
+        },
+        _ => DbError::Database {
             message: e.to_string(),
-        })?;
-
-        Ok(Repo {
-            id,
-            remote: repo.remote.clone(),
-            path: repo.path.clone(),
-            tags: repo.tags.clone(),
-            project_ids: repo.project_ids.clone(),
-            created_at,
-        })
+        },
     }
+}
 
-    async fn get(&self, id: &str) -> DbResult<Repo> {
-        let row = sqlx::query("SELECT id, remote, path, tags, created_at FROM repo WHERE id = ?")
+impl SqliteRepoRepository<'_> {
+    /// Load a repo by ID; with `include_deleted`, soft-deleted rows too.
+    ///
+    /// Sync export uses this to write tombstones for deleted rows.
+    pub(crate) async fn fetch(&self, id: &str, include_deleted: bool) -> DbResult<Repo> {
+        let row = sqlx::query(
+            "SELECT id, remote, path, tags, created_at, updated_at FROM repo WHERE id = ? AND (deleted_at IS NULL OR ?)",
+        )
             .bind(id)
+            .bind(include_deleted)
             .fetch_optional(self.pool)
             .await
             .map_err(|e| DbError::Database {
@@ -113,14 +99,16 @@ impl<'a> RepoRepository for SqliteRepoRepository<'a> {
         let tags: Vec<String> = serde_json::from_str(&tags_json).unwrap_or_default();
 
         // Get project relationships
-        let project_ids: Vec<String> =
-            sqlx::query_scalar("SELECT project_id FROM project_repo WHERE repo_id = ?")
-                .bind(id)
-                .fetch_all(self.pool)
-                .await
-                .map_err(|e| DbError::Database {
-                    message: e.to_string(),
-                })?;
+        let project_ids: Vec<String> = sqlx::query_scalar(
+            "SELECT pr.project_id FROM project_repo pr JOIN project p ON p.id = pr.project_id
+             WHERE pr.repo_id = ? AND p.deleted_at IS NULL",
+        )
+        .bind(id)
+        .fetch_all(self.pool)
+        .await
+        .map_err(|e| DbError::Database {
+            message: e.to_string(),
+        })?;
 
         Ok(Repo {
             id: row.get("id"),
@@ -129,10 +117,20 @@ impl<'a> RepoRepository for SqliteRepoRepository<'a> {
             tags,
             project_ids,
             created_at: row.get("created_at"),
+            updated_at: row.get("updated_at"),
         })
     }
 
-    async fn list(&self, query: Option<&RepoQuery>) -> DbResult<ListResult<Repo>> {
+    /// List repos, optionally limited to those matching `search_term`.
+    ///
+    /// A search term matches a case-insensitive substring of the remote or
+    /// path, or the FTS5 index (remote, path and tags). Without one,
+    /// `query.search_query` filters through FTS5 alone.
+    async fn list_matching(
+        &self,
+        query: Option<&RepoQuery>,
+        search_term: Option<&str>,
+    ) -> DbResult<ListResult<Repo>> {
         let default_query = RepoQuery::default();
         let query = query.unwrap_or(&default_query);
         let allowed_fields = ["remote", "path", "created_at"];
@@ -146,7 +144,8 @@ impl<'a> RepoRepository for SqliteRepoRepository<'a> {
             .is_some_and(|q| !q.trim().is_empty());
 
         let mut bind_values: Vec<String> = Vec::new();
-        let mut where_conditions: Vec<String> = Vec::new();
+        // Unqualified: none of the joined tables has a deleted_at column
+        let mut where_conditions: Vec<String> = vec!["deleted_at IS NULL".to_string()];
 
         // Sanitize and prepare FTS5 query if search is requested
         let fts_query = if has_search {
@@ -156,60 +155,81 @@ impl<'a> RepoRepository for SqliteRepoRepository<'a> {
             None
         };
 
-        // Decide on table alias usage
-        let (select_cols, from_clause, order_field_prefix) =
-            if needs_json_each || needs_project_join || fts_query.is_some() {
-                // Need aliases when doing JOINs or FTS5
-                let mut from = "FROM repo r".to_string();
-
-                // Add FTS5 join if searching
-                if fts_query.is_some() {
-                    from.push_str("\nINNER JOIN repo_fts ON r.id = repo_fts.id");
-                }
-
-                if needs_project_join {
-                    from.push_str("\nINNER JOIN project_repo pr ON r.id = pr.repo_id");
-                    where_conditions.push("pr.project_id = ?".to_string());
-                    bind_values.push(query.project_id.as_ref().unwrap().clone());
-                }
-
-                if needs_json_each {
-                    from.push_str(", json_each(r.tags)");
-                    let tags = query.tags.as_ref().unwrap();
-                    let placeholders: Vec<&str> = tags.iter().map(|_| "?").collect();
-                    where_conditions
-                        .push(format!("json_each.value IN ({})", placeholders.join(", ")));
-                    bind_values.extend(tags.clone());
-                }
+        // A search term matches substrings OR the FTS5 index, so it can't use the join
+        let substring = search_term.map(|term| {
+            (
+                format!("%{}%", escape_like(term.trim())),
+                super::helpers::sanitize_fts5_query(term),
+            )
+        });
+        let fts_query = if substring.is_some() { None } else { fts_query };
 
-                // Add FTS5 search condition
-                if let Some(ref query_str) = fts_query {
-                    where_conditions.push("repo_fts MATCH ?".to_string());
-                    bind_values.push(query_str.clone());
+        // Decide on table alias usage
+        let (select_cols, from_clause) = if needs_json_each
+            || needs_project_join
+            || fts_query.is_some()
+            || substring.is_some()
+        {
+            // Need aliases when doing JOINs or FTS5
+            let mut from = "FROM repo r".to_string();
+
+            // Add FTS5 join if searching
+            if fts_query.is_some() {
+                from.push_str("\nINNER JOIN repo_fts ON r.id = repo_fts.id");
+            }
+
+            if needs_project_join {
+                from.push_str("\nINNER JOIN project_repo pr ON r.id = pr.repo_id");
+                where_conditions.push("pr.project_id = ?".to_string());
+                bind_values.push(query.project_id.as_ref().unwrap().clone());
+            }
+
+            if needs_json_each {
+                from.push_str(", json_each(r.tags)");
+                let tags = query.tags.as_ref().unwrap();
+                let placeholders: Vec<&str> = tags.iter().map(|_| "?").collect();
+                where_conditions.push(format!("json_each.value IN ({})", placeholders.join(", ")));
+                bind_values.extend(tags.clone());
+            }
+
+            // Add FTS5 search condition
+            if let Some(ref query_str) = fts_query {
+                where_conditions.push("repo_fts MATCH ?".to_string());
+                bind_values.push(query_str.clone());
+            }
+
+            // Add substring search condition (LIKE is case-insensitive for ASCII)
+            if let Some((pattern, fts)) = &substring {
+                let mut matches = vec![
+                    "r.remote LIKE ? ESCAPE '\\'".to_string(),
+                    "r.path LIKE ? ESCAPE '\\'".to_string(),
+                ];
+                bind_values.push(pattern.clone());
+                bind_values.push(pattern.clone());
+                if let Some(fts) = fts {
+                    matches.push(
+                        "r.id IN (SELECT id FROM repo_fts WHERE repo_fts MATCH ?)".to_string(),
+                    );
+                    bind_values.push(fts.clone());
                 }
+                where_conditions.push(format!("({})", matches.join(" OR ")));
+            }
 
-                (
-                    "DISTINCT r.id, r.remote, r.path, r.tags, r.created_at",
-                    from,
-                    "r.",
-                )
-            } else {
-                // No joins, simple query (no search)
-                (
-                    "id, remote, path, tags, created_at",
-                    "FROM repo".to_string(),
-                    "",
-                )
-            };
-
-        // Build WHERE clause
-        let where_clause = if !where_conditions.is_empty() {
-            format!("WHERE {}", where_conditions.join(" AND "))
+            (
+                "DISTINCT r.id, r.remote, r.path, r.tags, r.created_at, r.updated_at",
+                from,
+            )
         } else {
-            String::new()
+            // No joins, simple query (no search)
+            (
+                "id, remote, path, tags, created_at, updated_at",
+                "FROM repo".to_string(),
+            )
         };
 
-        // Build ORDER BY with proper prefixes
+        let where_clause = format!("WHERE {}", where_conditions.join(" AND "));
+
+        // Build ORDER BY; unqualified since it applies to the wrapped query
         let sort_field = query
             .page
             .sort_by
@@ -220,29 +240,28 @@ impl<'a> RepoRepository for SqliteRepoRepository<'a> {
             crate::db::SortOrder::Asc => "ASC",
             crate::db::SortOrder::Desc => "DESC",
         };
-        let order_clause = format!(
-            "ORDER BY {}{} {}",
-            order_field_prefix, sort_field, sort_order
-        );
+        let order_clause = format!("ORDER BY {} {}, id ASC", sort_field, sort_order);
 
         let limit_clause = build_limit_offset_clause(&query.page);
 
-        // Build final SQL
-        let sql = format!(
-            "SELECT {} {} {} {} {}",
-            select_cols, from_clause, where_clause, order_clause, limit_clause
+        // Build final SQL; the total comes back with every row
+        let sql = with_window_total(
+            &format!("SELECT {} {} {}", select_cols, from_clause, where_clause),
+            &order_clause,
+            &limit_clause,
         );
 
-        let count_sql = if needs_json_each || needs_project_join || fts_query.is_some() {
+        let count_sql = if needs_json_each
+            || needs_project_join
+            || fts_query.is_some()
+            || substring.is_some()
+        {
             format!(
                 "SELECT COUNT(DISTINCT r.id) {} {}",
                 from_clause, where_clause
             )
-        } else if !where_clause.is_empty() {
-            // Simple query but with WHERE clause (no joins)
-            format!("SELECT COUNT(*) FROM repo {}", where_clause)
         } else {
-            "SELECT COUNT(*) FROM repo".to_string()
+            format!("SELECT COUNT(*) FROM repo {}", where_clause)
         };
 
         // Execute main query
@@ -258,6 +277,12 @@ impl<'a> RepoRepository for SqliteRepoRepository<'a> {
                 message: e.to_string(),
             })?;
 
+        let total = match window_total(&rows, &query.page) {
+            Some(total) => total,
+            // Offset past the end: no rows to read the total from
+            None => count_rows(self.pool, &count_sql, &bind_values).await?,
+        };
+
         let items: Vec<Repo> = rows
             .into_iter()
             .map(|row| {
@@ -270,33 +295,174 @@ impl<'a> RepoRepository for SqliteRepoRepository<'a> {
                     tags,
                     project_ids: vec![], // Empty by default - relationships managed separately
                     created_at: row.get("created_at"),
+                    updated_at: row.get("updated_at"),
                 }
             })
             .collect();
 
-        // Execute count query
-        let mut count_query = sqlx::query_scalar(&count_sql);
-        for value in &bind_values {
-            count_query = count_query.bind(value);
-        }
-
-        let total: i64 = count_query
-            .fetch_one(self.pool)
-            .await
-            .map_err(|e| DbError::Database {
-                message: e.to_string(),
-            })?;
-
         Ok(ListResult {
             items,
-            total: total as usize,
+            total,
             limit: query.page.limit,
             offset: query.page.offset.unwrap_or(0),
+            next_cursor: None,
         })
     }
+}
+
+impl<'a> RepoRepository for SqliteRepoRepository<'a> {
+    async fn create(&self, repo: &Repo) -> DbResult<Repo> {
+        // Validate repo
+        validate_repo(repo)?;
+
+        // Begin transaction for atomicity
+        let mut tx = self.pool.begin().await.map_err(|e| DbError::Database {
+            message: e.to_string(),
+        })?;
+
+        // A deleted repo still holds its remote. Re-adding the remote revives
+        // that row under its own ID, so the tombstone other machines synced
+        // is superseded rather than lost
+        let remote_key = normalize_remote(&repo.remote);
+        let deleted_id: Option<String> = sqlx::query_scalar(
+            "SELECT id FROM repo WHERE deleted_at IS NOT NULL AND (remote = ? OR remote_key = ?)",
+        )
+        .bind(&repo.remote)
+        .bind(&remote_key)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| DbError::Database {
+            message: e.to_string(),
+        })?;
+
+        // Otherwise use provided ID if not empty, or generate one
+        let id = match deleted_id {
+            Some(deleted_id) if repo.id.is_empty() || repo.id == deleted_id => deleted_id,
+            Some(deleted_id) => {
+                return Err(DbError::Validation {
+                    message: format!(
+                        "Remote {} belongs to deleted repo {}; create it with that ID to restore it",
+                        repo.remote, deleted_id
+                    ),
+                });
+            }
+            None if repo.id.is_empty() => generate_entity_id_with_length(self.id_length),
+            None => repo.id.clone(),
+        };
+
+        // Respect input timestamp or generate if None/empty (see utils.rs for policy)
+        let created_at = repo
+            .created_at
+            .clone()
+            .filter(|s| !s.is_empty())
+            .or_else(|| Some(current_timestamp()));
+        let updated_at = repo
+            .updated_at
+            .clone()
+            .filter(|s| !s.is_empty())
+            .or_else(|| created_at.clone());
+
+        let tags_json = serde_json::to_string(&repo.tags).map_err(|e| DbError::Database {
+            message: format!("Failed to serialize tags: {}", e),
+        })?;
+
+        clear_deleted_links(&mut tx, "repo", &id).await?;
+
+        let result = sqlx::query(&format!(
+            "INSERT INTO repo (id, remote, remote_key, path, tags, created_at, updated_at) VALUES (?, ?, ?, ?, ?, ?, ?) {}",
+            revive_deleted(
+                "repo",
+                &["remote", "remote_key", "path", "tags", "created_at", "updated_at"],
+            )
+        ))
+        .bind(&id)
+        .bind(&repo.remote)
+        .bind(&remote_key)
+        .bind(&repo.path)
+        .bind(&tags_json)
+        .bind(&created_at)
+        .bind(&updated_at)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| remote_conflict(e, &repo.remote))?;
+        check_inserted(&result, "Repo", &id)?;
+
+        // Insert project relationships
+        for project_id in &repo.project_ids {
+            sqlx::query("INSERT OR IGNORE INTO project_repo (project_id, repo_id) VALUES (?, ?)")
+                .bind(project_id)
+                .bind(&id)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| DbError::Database {
+                    message: e.to_string(),
+                })?;
+        }
+
+        // Commit transaction
+        tx.commit().await.map_err(|e| DbError::Database {
+            message: e.to_string(),
+        })?;
+
+        Ok(Repo {
+            id,
+            remote: repo.remote.clone(),
+            path: repo.path.clone(),
+            tags: repo.tags.clone(),
+            project_ids: repo.project_ids.clone(),
+            created_at,
+            updated_at,
+        })
+    }
+
+    async fn get(&self, id: &str) -> DbResult<Repo> {
+        self.fetch(id, false).await
+    }
+
+    async fn get_by_remote(&self, remote: &str) -> DbResult<Repo> {
+        let id: Option<String> =
+            sqlx::query_scalar("SELECT id FROM repo WHERE remote_key = ? AND deleted_at IS NULL")
+                .bind(normalize_remote(remote))
+                .fetch_optional(self.pool)
+                .await
+                .map_err(|e| DbError::Database {
+                    message: e.to_string(),
+                })?;
+
+        match id {
+            Some(id) => self.get(&id).await,
+            None => Err(DbError::NotFound {
+                entity_type: "Repo".to_string(),
+                id: remote.to_string(),
+            }),
+        }
+    }
+    async fn list(&self, query: Option<&RepoQuery>) -> DbResult<ListResult<Repo>> {
+        self.list_matching(query, None).await
+    }
+
+    async fn search(
+        &self,
+        search_term: &str,
+        query: Option<&RepoQuery>,
+    ) -> DbResult<ListResult<Repo>> {
+        // Empty query returns empty results, like the other searches
+        if search_term.trim().is_empty() {
+            let default_query = RepoQuery::default();
+            let query = query.unwrap_or(&default_query);
+            return Ok(ListResult {
+                items: vec![],
+                total: 0,
+                limit: query.page.limit,
+                offset: query.page.offset.unwrap_or(0),
+                next_cursor: None,
+            });
+        }
+        self.list_matching(query, Some(search_term)).await
+    }
 
     async fn count(&self) -> DbResult<usize> {
-        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM repo")
+        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM repo WHERE deleted_at IS NULL")
             .fetch_one(self.pool)
             .await
             .map_err(|e| DbError::Database {
@@ -318,16 +484,19 @@ impl<'a> RepoRepository for SqliteRepoRepository<'a> {
             message: format!("Failed to serialize tags: {}", e),
         })?;
 
-        let result = sqlx::query("UPDATE repo SET remote = ?, path = ?, tags = ? WHERE id = ?")
-            .bind(&repo.remote)
-            .bind(&repo.path)
-            .bind(&tags_json)
-            .bind(&repo.id)
-            .execute(&mut *tx)
-            .await
-            .map_err(|e| DbError::Database {
-                message: e.to_string(),
-            })?;
+        // Callers pass back the repo they read, so the stamp is always fresh
+        let result = sqlx::query(
+            "UPDATE repo SET remote = ?, remote_key = ?, path = ?, tags = ?, updated_at = ? WHERE id = ? AND deleted_at IS NULL",
+        )
+        .bind(&repo.remote)
+        .bind(normalize_remote(&repo.remote))
+        .bind(&repo.path)
+        .bind(&tags_json)
+        .bind(current_timestamp())
+        .bind(&repo.id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| remote_conflict(e, &repo.remote))?;
 
         if result.rows_affected() == 0 {
             return Err(DbError::NotFound {
@@ -348,7 +517,7 @@ impl<'a> RepoRepository for SqliteRepoRepository<'a> {
 
         // Insert new relationships
         for project_id in &repo.project_ids {
-            sqlx::query("INSERT INTO project_repo (project_id, repo_id) VALUES (?, ?)")
+            sqlx::query("INSERT OR IGNORE INTO project_repo (project_id, repo_id) VALUES (?, ?)")
                 .bind(project_id)
                 .bind(&repo.id)
                 .execute(&mut *tx)
@@ -365,10 +534,83 @@ impl<'a> RepoRepository for SqliteRepoRepository<'a> {
         Ok(())
     }
 
+    async fn dependents(&self, id: &str) -> DbResult<RepoDependents> {
+        self.get(id).await?;
+
+        let linked = |sql: &'static str| {
+            sqlx::query_scalar::<_, String>(sql)
+                .bind(id)
+                .fetch_all(self.pool)
+        };
+        let map_err = |e: sqlx::Error| DbError::Database {
+            message: e.to_string(),
+        };
+
+        Ok(RepoDependents {
+            project_ids: linked(
+                "SELECT p.id FROM project_repo pr JOIN project p ON p.id = pr.project_id
+                 WHERE pr.repo_id = ? AND p.deleted_at IS NULL ORDER BY p.id",
+            )
+            .await
+            .map_err(map_err)?,
+            task_list_ids: linked(
+                "SELECT tl.id FROM task_list_repo tlr JOIN task_list tl ON tl.id = tlr.task_list_id
+                 WHERE tlr.repo_id = ? AND tl.deleted_at IS NULL ORDER BY tl.id",
+            )
+            .await
+            .map_err(map_err)?,
+            note_ids: linked(
+                "SELECT n.id FROM note_repo nr JOIN note n ON n.id = nr.note_id
+                 WHERE nr.repo_id = ? AND n.deleted_at IS NULL ORDER BY n.id",
+            )
+            .await
+            .map_err(map_err)?,
+        })
+    }
+
     async fn delete(&self, id: &str) -> DbResult<()> {
+        let mut tx = self.pool.begin().await.map_err(|e| DbError::Database {
+            message: e.to_string(),
+        })?;
+
+        let result =
+            sqlx::query("UPDATE repo SET deleted_at = ? WHERE id = ? AND deleted_at IS NULL")
+                .bind(current_timestamp())
+                .bind(id)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| DbError::Database {
+                    message: e.to_string(),
+                })?;
+
+        if result.rows_affected() == 0 {
+            return Err(DbError::NotFound {
+                entity_type: "Repo".to_string(),
+                id: id.to_string(),
+            });
+        }
+
+        unlink_repo(&mut tx, id).await?;
+
+        tx.commit().await.map_err(|e| DbError::Database {
+            message: e.to_string(),
+        })?;
+
+        Ok(())
+    }
+
+    async fn purge(&self, id: &str) -> DbResult<()> {
+        let mut tx = self.pool.begin().await.map_err(|e| DbError::Database {
+            message: e.to_string(),
+        })?;
+
+        // Explicit rather than relying on ON DELETE CASCADE, which only
+        // applies when foreign keys are enforced on the connection
+        unlink_repo(&mut tx, id).await?;
+
         let result = sqlx::query("DELETE FROM repo WHERE id = ?")
             .bind(id)
-            .execute(self.pool)
+            .execute(&mut *tx)
             .await
             .map_err(|e| DbError::Database {
                 message: e.to_string(),
@@ -381,6 +623,10 @@ impl<'a> RepoRepository for SqliteRepoRepository<'a> {
             });
         }
 
+        tx.commit().await.map_err(|e| DbError::Database {
+            message: e.to_string(),
+        })?;
+
         Ok(())
     }
 }
@@ -1,6 +1,6 @@
 //! Tests for SqliteRepoRepository.
 
-use crate::db::{Database, Repo, RepoQuery, RepoRepository, SqliteDatabase};
+use crate::db::{Database, DbError, Repo, RepoQuery, RepoRepository, SqliteDatabase};
 
 async fn setup_db() -> SqliteDatabase {
     let db = SqliteDatabase::in_memory()
@@ -22,6 +22,7 @@ async fn repo_create_and_get() {
         tags: vec![],
         project_ids: vec![], // Empty by default - relationships managed separately
         created_at: Some("2025-01-01 00:00:00".to_string()),
+        updated_at: None,
     };
 
     repos.create(&repo).await.expect("Create should succeed");
@@ -59,6 +60,7 @@ async fn repo_list() {
             tags: vec![],
             project_ids: vec![], // Empty by default - relationships managed separately
             created_at: Some("2025-01-01 00:00:00".to_string()),
+            updated_at: None,
         })
         .await
         .unwrap();
@@ -70,6 +72,7 @@ async fn repo_list() {
             tags: vec![],
             project_ids: vec![], // Empty by default - relationships managed separately
             created_at: Some("2025-01-01 00:00:01".to_string()),
+            updated_at: None,
         })
         .await
         .unwrap();
@@ -90,6 +93,7 @@ async fn repo_update() {
         tags: vec![],
         project_ids: vec![], // Empty by default - relationships managed separately
         created_at: Some("2025-01-01 00:00:00".to_string()),
+        updated_at: None,
     };
     repos.create(&repo).await.expect("Create should succeed");
 
@@ -112,6 +116,7 @@ async fn repo_delete() {
         tags: vec![],
         project_ids: vec![], // Empty by default - relationships managed separately
         created_at: Some("2025-01-01 00:00:00".to_string()),
+        updated_at: None,
     };
     repos.create(&repo).await.expect("Create should succeed");
 
@@ -124,6 +129,74 @@ async fn repo_delete() {
     assert!(result.is_err());
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn repo_create_revives_deleted_remote() {
+    use crate::db::{Project, ProjectRepository};
+
+    let db = setup_db().await;
+    let repos = db.repos();
+    db.projects()
+        .create(&Project {
+            id: "proj0002".to_string(),
+            title: "Before".to_string(),
+            description: None,
+            tags: vec![],
+            external_refs: vec![],
+            repo_ids: vec![],
+            task_list_ids: vec![],
+            note_ids: vec![],
+            created_at: None,
+            updated_at: None,
+        })
+        .await
+        .unwrap();
+
+    let repo = Repo {
+        id: "repodel2".to_string(),
+        remote: "https://github.com/user/revived".to_string(),
+        path: None,
+        tags: vec![],
+        project_ids: vec!["proj0002".to_string()],
+        created_at: Some("2025-01-01 00:00:00".to_string()),
+        updated_at: None,
+    };
+    repos.create(&repo).await.expect("Create should succeed");
+    repos
+        .delete("repodel2")
+        .await
+        .expect("Delete should succeed");
+
+    // Re-adding the remote, spelled differently, brings back the same row
+    let revived = repos
+        .create(&Repo {
+            id: String::new(),
+            remote: "git@github.com:user/revived.git".to_string(),
+            path: Some("/src/revived".to_string()),
+            project_ids: vec![],
+            created_at: None,
+            ..repo.clone()
+        })
+        .await
+        .expect("Create should revive the deleted repo");
+    assert_eq!(revived.id, "repodel2");
+
+    let stored = repos.get("repodel2").await.expect("Revived repo is live");
+    assert_eq!(stored.remote, "git@github.com:user/revived.git");
+    assert_eq!(stored.path.as_deref(), Some("/src/revived"));
+    // Links from before the deletion don't come back
+    assert!(stored.project_ids.is_empty());
+
+    // A different explicit ID can't take over a deleted repo's remote
+    repos.delete("repodel2").await.unwrap();
+    let result = repos
+        .create(&Repo {
+            id: "repoelse".to_string(),
+            ..repo
+        })
+        .await;
+    assert!(matches!(result, Err(DbError::Validation { .. })));
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn repo_create_with_tags() {
     let db = setup_db().await;
@@ -136,6 +209,7 @@ async fn repo_create_with_tags() {
         tags: vec!["work".to_string(), "active".to_string()],
         project_ids: vec![], // Empty by default - relationships managed separately
         created_at: Some("2025-01-01 00:00:00".to_string()),
+        updated_at: None,
     };
 
     repos.create(&repo).await.expect("Create should succeed");
@@ -160,6 +234,7 @@ async fn repo_list_with_tag_filter() {
             tags: vec!["work".to_string(), "active".to_string()],
             project_ids: vec![], // Empty by default - relationships managed separately
             created_at: Some("2025-01-01 00:00:00".to_string()),
+            updated_at: None,
         })
         .await
         .unwrap();
@@ -172,6 +247,7 @@ async fn repo_list_with_tag_filter() {
             tags: vec!["work".to_string(), "archived".to_string()],
             project_ids: vec![], // Empty by default - relationships managed separately
             created_at: Some("2025-01-01 00:00:01".to_string()),
+            updated_at: None,
         })
         .await
         .unwrap();
@@ -184,6 +260,7 @@ async fn repo_list_with_tag_filter() {
             tags: vec!["personal".to_string(), "active".to_string()],
             project_ids: vec![], // Empty by default - relationships managed separately
             created_at: Some("2025-01-01 00:00:02".to_string()),
+            updated_at: None,
         })
         .await
         .unwrap();
@@ -252,6 +329,7 @@ async fn repo_get_loads_project_relationships() {
         tags: vec![],
         project_ids: vec![],
         created_at: Some("2025-01-01 00:00:00".to_string()),
+        updated_at: None,
     };
     repos.create(&repo).await.expect("Create should succeed");
 
@@ -296,6 +374,7 @@ async fn repo_list_with_search_query() {
             tags: vec!["backend".to_string(), "production".to_string()],
             project_ids: vec![],
             created_at: Some("2025-01-01 00:00:00".to_string()),
+            updated_at: None,
         })
         .await
         .unwrap();
@@ -308,6 +387,7 @@ async fn repo_list_with_search_query() {
             tags: vec!["frontend".to_string(), "production".to_string()],
             project_ids: vec![],
             created_at: Some("2025-01-01 00:00:01".to_string()),
+            updated_at: None,
         })
         .await
         .unwrap();
@@ -320,6 +400,7 @@ async fn repo_list_with_search_query() {
             tags: vec!["internal".to_string(), "backend".to_string()],
             project_ids: vec![],
             created_at: Some("2025-01-01 00:00:02".to_string()),
+            updated_at: None,
         })
         .await
         .unwrap();
@@ -361,6 +442,57 @@ async fn repo_list_with_search_query() {
     assert_eq!(result.total, 0);
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn repo_search_matches_remote_and_path_substrings() {
+    let db = setup_db().await;
+    let repos = db.repos();
+
+    for (id, remote, path) in [
+        ("like0001", "https://github.com/ck3mp3r/context", None),
+        ("like0002", "https://github.com/acme/Context-Tools", None),
+        (
+            "like0003",
+            "https://gitlab.com/other/thing",
+            Some("/home/dev/context_checkout"),
+        ),
+    ] {
+        repos
+            .create(&Repo {
+                id: id.to_string(),
+                remote: remote.to_string(),
+                path: path.map(str::to_string),
+                tags: vec![],
+                project_ids: vec![],
+                created_at: None,
+                updated_at: None,
+            })
+            .await
+            .unwrap();
+    }
+
+    // A partial remote matches only that repo
+    let result = repos.search("mp3r/con", None).await.unwrap();
+    assert_eq!(result.total, 1);
+    assert_eq!(result.items[0].id, "like0001");
+
+    // Case-insensitive, over both remote and path
+    let result = repos.search("CONTEXT", None).await.unwrap();
+    let mut ids: Vec<&str> = result.items.iter().map(|r| r.id.as_str()).collect();
+    ids.sort();
+    assert_eq!(ids, vec!["like0001", "like0002", "like0003"]);
+
+    // LIKE wildcards in the term match literally
+    let result = repos.search("context_c", None).await.unwrap();
+    assert_eq!(result.total, 1);
+    assert_eq!(result.items[0].id, "like0003");
+    let result = repos.search("m%p", None).await.unwrap();
+    assert_eq!(result.total, 0);
+
+    // Empty term returns nothing
+    let result = repos.search("  ", None).await.unwrap();
+    assert_eq!(result.total, 0);
+}
+
 // =============================================================================
 // FTS5 Search Tests
 // =============================================================================
@@ -379,6 +511,7 @@ async fn fts5_search_finds_repo_by_remote() {
             tags: vec!["language".to_string()],
             project_ids: vec![],
             created_at: Some("2025-01-01 00:00:00".to_string()),
+            updated_at: None,
         })
         .await
         .unwrap();
@@ -391,6 +524,7 @@ async fn fts5_search_finds_repo_by_remote() {
             tags: vec!["language".to_string()],
             project_ids: vec![],
             created_at: Some("2025-01-01 00:00:01".to_string()),
+            updated_at: None,
         })
         .await
         .unwrap();
@@ -419,6 +553,7 @@ async fn fts5_search_finds_repo_by_path() {
             tags: vec![],
             project_ids: vec![],
             created_at: Some("2025-01-01 00:00:00".to_string()),
+            updated_at: None,
         })
         .await
         .unwrap();
@@ -431,6 +566,7 @@ async fn fts5_search_finds_repo_by_path() {
             tags: vec![],
             project_ids: vec![],
             created_at: Some("2025-01-01 00:00:01".to_string()),
+            updated_at: None,
         })
         .await
         .unwrap();
@@ -459,6 +595,7 @@ async fn fts5_search_finds_repo_by_tags() {
             tags: vec!["microservice".to_string(), "production".to_string()],
             project_ids: vec![],
             created_at: Some("2025-01-01 00:00:00".to_string()),
+            updated_at: None,
         })
         .await
         .unwrap();
@@ -471,6 +608,7 @@ async fn fts5_search_finds_repo_by_tags() {
             tags: vec!["monolith".to_string(), "legacy".to_string()],
             project_ids: vec![],
             created_at: Some("2025-01-01 00:00:01".to_string()),
+            updated_at: None,
         })
         .await
         .unwrap();
@@ -499,6 +637,7 @@ async fn fts5_search_boolean_operators() {
             tags: vec!["api".to_string(), "production".to_string()],
             project_ids: vec![],
             created_at: Some("2025-01-01 00:00:00".to_string()),
+            updated_at: None,
         })
         .await
         .unwrap();
@@ -511,6 +650,7 @@ async fn fts5_search_boolean_operators() {
             tags: vec!["frontend".to_string(), "production".to_string()],
             project_ids: vec![],
             created_at: Some("2025-01-01 00:00:01".to_string()),
+            updated_at: None,
         })
         .await
         .unwrap();
@@ -523,6 +663,7 @@ async fn fts5_search_boolean_operators() {
             tags: vec!["api".to_string(), "staging".to_string()],
             project_ids: vec![],
             created_at: Some("2025-01-01 00:00:02".to_string()),
+            updated_at: None,
         })
         .await
         .unwrap();
@@ -551,6 +692,7 @@ async fn fts5_search_phrase_query() {
             tags: vec![],
             project_ids: vec![],
             created_at: Some("2025-01-01 00:00:00".to_string()),
+            updated_at: None,
         })
         .await
         .unwrap();
@@ -563,6 +705,7 @@ async fn fts5_search_phrase_query() {
             tags: vec![],
             project_ids: vec![],
             created_at: Some("2025-01-01 00:00:01".to_string()),
+            updated_at: None,
         })
         .await
         .unwrap();
@@ -591,6 +734,7 @@ async fn fts5_search_handles_special_characters() {
             tags: vec!["app".to_string()],
             project_ids: vec![],
             created_at: Some("2025-01-01 00:00:00".to_string()),
+            updated_at: None,
         })
         .await
         .unwrap();
@@ -618,6 +762,7 @@ async fn fts5_search_empty_results() {
             tags: vec!["test".to_string()],
             project_ids: vec![],
             created_at: Some("2025-01-01 00:00:00".to_string()),
+            updated_at: None,
         })
         .await
         .unwrap();
@@ -649,6 +794,7 @@ async fn create_repo_with_invalid_project_should_rollback() {
         tags: vec![],
         project_ids: vec!["nonexistent_project".to_string()], // Invalid project_id
         created_at: Some("2025-01-01 00:00:00".to_string()),
+        updated_at: None,
     };
 
     let repo_id = repo.id.clone();
@@ -684,6 +830,7 @@ async fn create_repo_with_empty_remote_should_fail() {
         tags: vec![],
         project_ids: vec![],
         created_at: None,
+        updated_at: None,
     };
 
     let result = repos.create(&repo).await;
@@ -713,6 +860,7 @@ async fn create_repo_with_whitespace_only_remote_should_fail() {
         tags: vec![],
         project_ids: vec![],
         created_at: None,
+        updated_at: None,
     };
 
     let result = repos.create(&repo).await;
@@ -735,6 +883,7 @@ async fn update_repo_with_empty_remote_should_fail() {
         tags: vec![],
         project_ids: vec![],
         created_at: None,
+        updated_at: None,
     };
     repos.create(&repo).await.unwrap();
 
@@ -745,3 +894,152 @@ async fn update_repo_with_empty_remote_should_fail() {
     let result = repos.update(&updated).await;
     assert!(result.is_err(), "Update should fail with empty remote");
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn repo_get_by_remote_matches_equivalent_forms() {
+    let db = setup_db().await;
+    let repos = db.repos();
+
+    repos
+        .create(&Repo {
+            id: "remote01".to_string(),
+            remote: "git@github.com:ck3mp3r/context.git".to_string(),
+            path: None,
+            tags: vec![],
+            project_ids: vec![],
+            created_at: None,
+            updated_at: None,
+        })
+        .await
+        .expect("Create should succeed");
+
+    for remote in [
+        "git@github.com:ck3mp3r/context.git",
+        "https://github.com/ck3mp3r/context",
+        "github:ck3mp3r/context",
+    ] {
+        let found = repos
+            .get_by_remote(remote)
+            .await
+            .unwrap_or_else(|e| panic!("{} should match: {}", remote, e));
+        assert_eq!(found.id, "remote01");
+        // Raw remote is preserved as entered
+        assert_eq!(found.remote, "git@github.com:ck3mp3r/context.git");
+    }
+
+    let missing = repos.get_by_remote("github:ck3mp3r/other").await;
+    assert!(matches!(missing, Err(DbError::NotFound { .. })));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn repo_create_rejects_equivalent_remote() {
+    let db = setup_db().await;
+    let repos = db.repos();
+
+    repos
+        .create(&Repo {
+            id: "remote01".to_string(),
+            remote: "https://github.com/ck3mp3r/context.git".to_string(),
+            path: None,
+            tags: vec![],
+            project_ids: vec![],
+            created_at: None,
+            updated_at: None,
+        })
+        .await
+        .expect("Create should succeed");
+
+    let result = repos
+        .create(&Repo {
+            id: "remote02".to_string(),
+            remote: "github:ck3mp3r/context".to_string(),
+            path: None,
+            tags: vec![],
+            project_ids: vec![],
+            created_at: None,
+            updated_at: None,
+        })
+        .await;
+
+    assert!(
+        matches!(result, Err(DbError::AlreadyExists { .. })),
+        "Equivalent remote should be rejected, got {:?}",
+        result
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn repo_local_paths_differing_in_case_are_distinct() {
+    let db = setup_db().await;
+    let repos = db.repos();
+
+    for (id, remote) in [("local001", "/home/me/Proj"), ("local002", "/home/me/proj")] {
+        repos
+            .create(&Repo {
+                id: id.to_string(),
+                remote: remote.to_string(),
+                path: None,
+                tags: vec![],
+                project_ids: vec![],
+                created_at: None,
+                updated_at: None,
+            })
+            .await
+            .unwrap_or_else(|e| panic!("{} should be created: {}", remote, e));
+    }
+
+    let found = repos.get_by_remote("/home/me/Proj").await.unwrap();
+    assert_eq!(found.id, "local001");
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn repo_dependents_and_delete_unlinks_them() {
+    use crate::db::{Project, ProjectRepository};
+
+    let db = setup_db().await;
+    db.projects()
+        .create(&Project {
+            id: "proj0001".to_string(),
+            title: "Linked".to_string(),
+            description: None,
+            tags: vec![],
+            external_refs: vec![],
+            repo_ids: vec![],
+            task_list_ids: vec![],
+            note_ids: vec![],
+            created_at: None,
+            updated_at: None,
+        })
+        .await
+        .unwrap();
+    db.repos()
+        .create(&Repo {
+            id: "repo0001".to_string(),
+            remote: "github:user/linked".to_string(),
+            path: None,
+            tags: vec![],
+            project_ids: vec!["proj0001".to_string()],
+            created_at: None,
+            updated_at: None,
+        })
+        .await
+        .unwrap();
+
+    let dependents = db.repos().dependents("repo0001").await.unwrap();
+    assert_eq!(dependents.project_ids, vec!["proj0001"]);
+    assert!(dependents.task_list_ids.is_empty());
+    assert!(!dependents.is_empty());
+
+    db.repos().delete("repo0001").await.unwrap();
+
+    let links: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM project_repo WHERE repo_id = ?")
+        .bind("repo0001")
+        .fetch_one(db.pool())
+        .await
+        .unwrap();
+    assert_eq!(links, 0);
+    assert!(matches!(
+        db.repos().dependents("repo0001").await,
+        Err(DbError::NotFound { .. })
+    ));
+}
@@ -0,0 +1,257 @@
+//! Schema drift detection.
+//!
+//! Compares the live SQLite schema against the columns the models read and
+//! write, so a database left behind by a partial or foreign migration is
+//! reported up front instead of failing on the first query that touches it.
+
+use std::fmt;
+
+use serde::Serialize;
+use sqlx::SqlitePool;
+
+use crate::db::{DbError, DbResult};
+
+/// Columns each model table is expected to have, with their declared types.
+const EXPECTED_SCHEMA: &[(&str, &[(&str, &str)])] = &[
+    (
+        "project",
+        &[
+            ("id", "TEXT"),
+            ("title", "TEXT"),
+            ("description", "TEXT"),
+            ("tags", "TEXT"),
+            ("external_refs", "TEXT"),
+            ("created_at", "TEXT"),
+            ("updated_at", "TEXT"),
+            ("deleted_at", "TEXT"),
+        ],
+    ),
+    (
+        "repo",
+        &[
+            ("id", "TEXT"),
+            ("remote", "TEXT"),
+            ("remote_key", "TEXT"),
+            ("path", "TEXT"),
+            ("tags", "TEXT"),
+            ("created_at", "TEXT"),
+            ("updated_at", "TEXT"),
+            ("deleted_at", "TEXT"),
+        ],
+    ),
+    (
+        "task_list",
+        &[
+            ("id", "TEXT"),
+            ("title", "TEXT"),
+            ("description", "TEXT"),
+            ("notes", "TEXT"),
+            ("tags", "TEXT"),
+            ("external_refs", "TEXT"),
+            ("status", "TEXT"),
+            ("project_id", "TEXT"),
+            ("created_at", "TEXT"),
+            ("updated_at", "TEXT"),
+            ("archived_at", "TEXT"),
+            ("deleted_at", "TEXT"),
+        ],
+    ),
+    (
+        "task",
+        &[
+            ("id", "TEXT"),
+            ("list_id", "TEXT"),
+            ("parent_id", "TEXT"),
+            ("title", "TEXT"),
+            ("description", "TEXT"),
+            ("status", "TEXT"),
+            ("priority", "INTEGER"),
+            ("tags", "TEXT"),
+            ("external_refs", "TEXT"),
+            ("created_at", "TEXT"),
+            ("updated_at", "TEXT"),
+            ("deleted_at", "TEXT"),
+        ],
+    ),
+    (
+        "note",
+        &[
+            ("id", "TEXT"),
+            ("title", "TEXT"),
+            ("content", "TEXT"),
+            ("content_hash", "TEXT"),
+            ("tags", "TEXT"),
+            ("parent_id", "TEXT"),
+            ("idx", "INTEGER"),
+            ("created_at", "TEXT"),
+            ("updated_at", "TEXT"),
+            ("deleted_at", "TEXT"),
+        ],
+    ),
+    (
+        "skill",
+        &[
+            ("id", "TEXT"),
+            ("name", "TEXT"),
+            ("description", "TEXT"),
+            ("content", "TEXT"),
+            ("tags", "TEXT"),
+            ("created_at", "TEXT"),
+            ("updated_at", "TEXT"),
+        ],
+    ),
+    (
+        "skill_attachment",
+        &[
+            ("id", "TEXT"),
+            ("skill_id", "TEXT"),
+            ("type", "TEXT"),
+            ("filename", "TEXT"),
+            ("content", "TEXT"),
+            ("content_hash", "TEXT"),
+            ("mime_type", "TEXT"),
+            ("is_executable", "INTEGER"),
+            ("created_at", "TEXT"),
+            ("updated_at", "TEXT"),
+        ],
+    ),
+    (
+        "task_transition_log",
+        &[
+            ("id", "TEXT"),
+            ("task_id", "TEXT"),
+            ("status", "TEXT"),
+            ("transitioned_at", "TEXT"),
+        ],
+    ),
+    (
+        "project_repo",
+        &[
+            ("project_id", "TEXT"),
+            ("repo_id", "TEXT"),
+            ("created_at", "TEXT"),
+        ],
+    ),
+    (
+        "project_note",
+        &[
+            ("project_id", "TEXT"),
+            ("note_id", "TEXT"),
+            ("created_at", "TEXT"),
+        ],
+    ),
+    (
+        "project_skill",
+        &[("project_id", "TEXT"), ("skill_id", "TEXT")],
+    ),
+    (
+        "task_list_repo",
+        &[
+            ("task_list_id", "TEXT"),
+            ("repo_id", "TEXT"),
+            ("created_at", "TEXT"),
+        ],
+    ),
+    (
+        "note_repo",
+        &[
+            ("note_id", "TEXT"),
+            ("repo_id", "TEXT"),
+            ("created_at", "TEXT"),
+        ],
+    ),
+];
+
+/// A difference between the live schema and what the models expect.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum SchemaMismatch {
+    MissingTable {
+        table: String,
+    },
+    MissingColumn {
+        table: String,
+        column: String,
+        expected_type: String,
+    },
+    TypeMismatch {
+        table: String,
+        column: String,
+        expected_type: String,
+        actual_type: String,
+    },
+}
+
+impl fmt::Display for SchemaMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SchemaMismatch::MissingTable { table } => write!(f, "{}: table is missing", table),
+            SchemaMismatch::MissingColumn {
+                table,
+                column,
+                expected_type,
+            } => write!(
+                f,
+                "{}.{}: column is missing (expected {})",
+                table, column, expected_type
+            ),
+            SchemaMismatch::TypeMismatch {
+                table,
+                column,
+                expected_type,
+                actual_type,
+            } => write!(
+                f,
+                "{}.{}: declared as {}, expected {}",
+                table, column, actual_type, expected_type
+            ),
+        }
+    }
+}
+
+/// Compare every expected table against `PRAGMA table_info`.
+///
+/// Extra columns are not reported; older builds may leave columns behind
+/// that the current models simply ignore.
+pub(super) async fn verify_schema(pool: &SqlitePool) -> DbResult<Vec<SchemaMismatch>> {
+    let mut mismatches = Vec::new();
+
+    for (table, columns) in EXPECTED_SCHEMA {
+        let actual: Vec<(String, String)> =
+            sqlx::query_as("SELECT name, type FROM pragma_table_info(?)")
+                .bind(table)
+                .fetch_all(pool)
+                .await
+                .map_err(|e| DbError::Database {
+                    message: e.to_string(),
+                })?;
+
+        if actual.is_empty() {
+            mismatches.push(SchemaMismatch::MissingTable {
+                table: table.to_string(),
+            });
+            continue;
+        }
+
+        for (column, expected_type) in *columns {
+            match actual.iter().find(|(name, _)| name == column) {
+                None => mismatches.push(SchemaMismatch::MissingColumn {
+                    table: table.to_string(),
+                    column: column.to_string(),
+                    expected_type: expected_type.to_string(),
+                }),
+                Some((_, actual_type)) if !actual_type.eq_ignore_ascii_case(expected_type) => {
+                    mismatches.push(SchemaMismatch::TypeMismatch {
+                        table: table.to_string(),
+                        column: column.to_string(),
+                        expected_type: expected_type.to_string(),
+                        actual_type: actual_type.clone(),
+                    })
+                }
+                Some(_) => {}
+            }
+        }
+    }
+
+    Ok(mismatches)
+}
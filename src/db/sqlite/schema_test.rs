@@ -0,0 +1,78 @@
+//! Tests for schema drift detection.
+
+use crate::db::sqlite::SchemaMismatch;
+use crate::db::{Database, SqliteDatabase};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn verify_schema_passes_after_migrations() {
+    let db = SqliteDatabase::in_memory().await.unwrap();
+    db.migrate().unwrap();
+
+    let mismatches = db.verify_schema().await.unwrap();
+    assert!(mismatches.is_empty(), "unexpected drift: {:?}", mismatches);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn verify_schema_reports_missing_columns_on_old_schema() {
+    let db = SqliteDatabase::in_memory().await.unwrap();
+
+    // A pre-external_refs project table with a singular reference column
+    // and a priority stored as text
+    sqlx::raw_sql(
+        "CREATE TABLE project (
+            id TEXT PRIMARY KEY,
+            title TEXT NOT NULL,
+            description TEXT,
+            tags TEXT DEFAULT '[]',
+            external_ref TEXT,
+            created_at TEXT NOT NULL,
+            updated_at TEXT NOT NULL
+        );
+        CREATE TABLE task (
+            id TEXT PRIMARY KEY,
+            list_id TEXT NOT NULL,
+            parent_id TEXT,
+            title TEXT NOT NULL,
+            description TEXT,
+            status TEXT NOT NULL,
+            priority TEXT,
+            tags TEXT DEFAULT '[]',
+            external_refs TEXT NOT NULL DEFAULT '[]',
+            created_at TEXT NOT NULL,
+            updated_at TEXT NOT NULL,
+            deleted_at TEXT
+        );",
+    )
+    .execute(db.pool())
+    .await
+    .unwrap();
+
+    let mismatches = db.verify_schema().await.unwrap();
+
+    assert!(mismatches.contains(&SchemaMismatch::MissingColumn {
+        table: "project".to_string(),
+        column: "external_refs".to_string(),
+        expected_type: "TEXT".to_string(),
+    }));
+    assert!(mismatches.contains(&SchemaMismatch::MissingColumn {
+        table: "project".to_string(),
+        column: "deleted_at".to_string(),
+        expected_type: "TEXT".to_string(),
+    }));
+    assert!(mismatches.contains(&SchemaMismatch::TypeMismatch {
+        table: "task".to_string(),
+        column: "priority".to_string(),
+        expected_type: "INTEGER".to_string(),
+        actual_type: "TEXT".to_string(),
+    }));
+    assert!(mismatches.contains(&SchemaMismatch::MissingTable {
+        table: "note".to_string(),
+    }));
+
+    // Columns that are present with the right type are not reported
+    assert!(!mismatches.iter().any(|m| matches!(
+        m,
+        SchemaMismatch::MissingColumn { table, column, .. }
+            if table == "project" && column == "title"
+    )));
+}
@@ -2,14 +2,16 @@
 
 use sqlx::{Row, SqlitePool};
 
-use super::helpers::build_limit_offset_clause;
+use super::helpers::{build_limit_offset_clause, count_rows, window_total, with_window_total};
 use crate::db::models::{SKILL_DESCRIPTION_MAX, Skill, SkillAttachment, SkillQuery};
-use crate::db::utils::{current_timestamp, generate_entity_id};
+use crate::db::utils::{IdLength, current_timestamp, generate_entity_id_with_length};
 use crate::db::{DbError, DbResult, ListResult, SkillRepository};
 
 /// SQLx-backed skill repository.
 pub struct SqliteSkillRepository<'a> {
     pub(crate) pool: &'a SqlitePool,
+    /// Length of generated IDs
+    pub(crate) id_length: IdLength,
 }
 
 /// Standard column list for SELECT queries (without table alias)
@@ -45,7 +47,7 @@ fn row_to_skill(row: &sqlx::sqlite::SqliteRow) -> Skill {
 /// - name: required, not empty
 /// - description: required, not empty, max 1024 chars  
 /// - content: required, not empty, must start with '---' (YAML frontmatter delimiter)
-fn validate_skill(skill: &Skill) -> DbResult<()> {
+pub(crate) fn validate_skill(skill: &Skill) -> DbResult<()> {
     let mut errors = Vec::new();
 
     // Validate name (required, not empty)
@@ -87,7 +89,7 @@ impl<'a> SkillRepository for SqliteSkillRepository<'a> {
 
         // Use provided ID if not empty, otherwise generate one
         let id = if skill.id.is_empty() {
-            generate_entity_id()
+            generate_entity_id_with_length(self.id_length)
         } else {
             skill.id.clone()
         };
@@ -135,7 +137,7 @@ impl<'a> SkillRepository for SqliteSkillRepository<'a> {
 
         // Insert project relationships
         for project_id in &skill.project_ids {
-            sqlx::query("INSERT INTO project_skill (project_id, skill_id) VALUES (?, ?)")
+            sqlx::query("INSERT OR IGNORE INTO project_skill (project_id, skill_id) VALUES (?, ?)")
                 .bind(project_id)
                 .bind(&id)
                 .execute(&mut *tx)
@@ -214,9 +216,7 @@ impl<'a> SkillRepository for SqliteSkillRepository<'a> {
         let needs_project_join = query.project_id.is_some();
         let mut bind_values: Vec<String> = Vec::new();
         let mut where_conditions: Vec<String> = Vec::new();
-        let (select_cols, from_clause, order_field_prefix) = if needs_json_each
-            || needs_project_join
-        {
+        let (select_cols, from_clause) = if needs_json_each || needs_project_join {
             let mut from = "FROM skill s".to_string();
             if needs_project_join {
                 from.push_str("\nINNER JOIN project_skill ps ON s.id = ps.skill_id");
@@ -230,9 +230,9 @@ impl<'a> SkillRepository for SqliteSkillRepository<'a> {
                 where_conditions.push(format!("json_each.value IN ({})", placeholders.join(", ")));
                 bind_values.extend(tags.clone());
             }
-            (format!("DISTINCT {}", SKILL_COLS_ALIASED), from, "s.")
+            (format!("DISTINCT {}", SKILL_COLS_ALIASED), from)
         } else {
-            (SKILL_COLS.to_string(), "FROM skill".to_string(), "")
+            (SKILL_COLS.to_string(), "FROM skill".to_string())
         };
         let where_clause = if !where_conditions.is_empty() {
             format!("WHERE {}", where_conditions.join(" AND "))
@@ -250,15 +250,14 @@ impl<'a> SkillRepository for SqliteSkillRepository<'a> {
                 crate::db::SortOrder::Asc => "ASC",
                 crate::db::SortOrder::Desc => "DESC",
             };
-            format!(
-                "ORDER BY {}{} {}",
-                order_field_prefix, sort_field, sort_order
-            )
+            // Unqualified: ordering applies to the wrapped query below
+            format!("ORDER BY {} {}, id ASC", sort_field, sort_order)
         };
         let limit_clause = build_limit_offset_clause(&query.page);
-        let sql = format!(
-            "SELECT {} {} {} {} {}",
-            select_cols, from_clause, where_clause, order_clause, limit_clause
+        let sql = with_window_total(
+            &format!("SELECT {} {} {}", select_cols, from_clause, where_clause),
+            &order_clause,
+            &limit_clause,
         );
         let count_sql = if needs_json_each || needs_project_join {
             format!(
@@ -278,22 +277,19 @@ impl<'a> SkillRepository for SqliteSkillRepository<'a> {
             .map_err(|e| DbError::Database {
                 message: e.to_string(),
             })?;
-        let items: Vec<Skill> = rows.iter().map(row_to_skill).collect();
-        let mut count_query = sqlx::query_scalar(&count_sql);
-        for value in &bind_values {
-            count_query = count_query.bind(value);
-        }
-        let total: i64 = count_query
-            .fetch_one(self.pool)
-            .await
-            .map_err(|e| DbError::Database {
-                message: e.to_string(),
-            })?;
+        let total = match window_total(&rows, &query.page) {
+            Some(total) => total,
+            // Offset past the end: no rows to read the total from
+            None => count_rows(self.pool, &count_sql, &bind_values).await?,
+        };
+        let mut items: Vec<Skill> = rows.iter().map(row_to_skill).collect();
+        self.load_attachment_names(&mut items).await?;
         Ok(ListResult {
             items,
-            total: total as usize,
+            total,
             limit: query.page.limit,
             offset: query.page.offset.unwrap_or(0),
+            next_cursor: None,
         })
     }
 
@@ -355,7 +351,7 @@ impl<'a> SkillRepository for SqliteSkillRepository<'a> {
                 message: e.to_string(),
             })?;
         for project_id in &skill.project_ids {
-            sqlx::query("INSERT INTO project_skill (project_id, skill_id) VALUES (?, ?)")
+            sqlx::query("INSERT OR IGNORE INTO project_skill (project_id, skill_id) VALUES (?, ?)")
                 .bind(project_id)
                 .bind(&skill.id)
                 .execute(&mut *tx)
@@ -406,6 +402,17 @@ impl<'a> SkillRepository for SqliteSkillRepository<'a> {
         // Simple LIKE search for now (upgradeable to FTS later)
         let default_query = SkillQuery::default();
         let query = query.unwrap_or(&default_query);
+
+        // Empty query returns empty results, like the FTS-backed searches
+        if search_term.trim().is_empty() {
+            return Ok(ListResult {
+                items: vec![],
+                total: 0,
+                limit: query.page.limit,
+                offset: query.page.offset.unwrap_or(0),
+                next_cursor: None,
+            });
+        }
         let mut bind_values: Vec<String> = Vec::new();
         let mut where_conditions: Vec<String> = Vec::new();
         where_conditions.push("(name LIKE ? OR description LIKE ? OR content LIKE ?)".to_string());
@@ -441,19 +448,21 @@ impl<'a> SkillRepository for SqliteSkillRepository<'a> {
             .map_err(|e| DbError::Database {
                 message: e.to_string(),
             })?;
-        let items: Vec<Skill> = rows.iter().map(row_to_skill).collect();
+        let mut items: Vec<Skill> = rows.iter().map(row_to_skill).collect();
+        self.load_attachment_names(&mut items).await?;
         let total = items.len();
         Ok(ListResult {
             items,
             total,
             limit: query.page.limit,
             offset: query.page.offset.unwrap_or(0),
+            next_cursor: None,
         })
     }
 
     async fn get_attachments(&self, skill_id: &str) -> DbResult<Vec<SkillAttachment>> {
         let rows = sqlx::query(
-            "SELECT id, skill_id, type, filename, content, content_hash, mime_type, created_at, updated_at FROM skill_attachment WHERE skill_id = ? ORDER BY type, filename"
+            "SELECT id, skill_id, type, filename, content, content_hash, mime_type, is_executable, created_at, updated_at FROM skill_attachment WHERE skill_id = ? ORDER BY type, filename"
         )
         .bind(skill_id)
         .fetch_all(self.pool)
@@ -472,6 +481,7 @@ impl<'a> SkillRepository for SqliteSkillRepository<'a> {
                 content: row.get("content"),
                 content_hash: row.get("content_hash"),
                 mime_type: row.get("mime_type"),
+                is_executable: row.get("is_executable"),
                 created_at: row.get("created_at"),
                 updated_at: row.get("updated_at"),
             })
@@ -492,7 +502,7 @@ impl<'a> SkillRepository for SqliteSkillRepository<'a> {
 
     async fn create_attachment(&self, attachment: &SkillAttachment) -> DbResult<SkillAttachment> {
         let id = if attachment.id.is_empty() {
-            generate_entity_id()
+            generate_entity_id_with_length(self.id_length)
         } else {
             attachment.id.clone()
         };
@@ -510,8 +520,8 @@ impl<'a> SkillRepository for SqliteSkillRepository<'a> {
             r#"
             INSERT INTO skill_attachment (
                 id, skill_id, type, filename, content, content_hash, mime_type,
-                created_at, updated_at
-            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+                is_executable, created_at, updated_at
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
             "#,
         )
         .bind(&id)
@@ -521,6 +531,7 @@ impl<'a> SkillRepository for SqliteSkillRepository<'a> {
         .bind(&attachment.content)
         .bind(&attachment.content_hash)
         .bind(&attachment.mime_type)
+        .bind(attachment.is_executable)
         .bind(&created_at)
         .bind(&updated_at)
         .execute(self.pool)
@@ -537,6 +548,7 @@ impl<'a> SkillRepository for SqliteSkillRepository<'a> {
             content: attachment.content.clone(),
             content_hash: attachment.content_hash.clone(),
             mime_type: attachment.mime_type.clone(),
+            is_executable: attachment.is_executable,
             created_at: Some(created_at),
             updated_at: Some(updated_at),
         })
@@ -548,13 +560,14 @@ impl<'a> SkillRepository for SqliteSkillRepository<'a> {
         sqlx::query(
             r#"
             UPDATE skill_attachment
-            SET content = ?, content_hash = ?, mime_type = ?, updated_at = ?
+            SET content = ?, content_hash = ?, mime_type = ?, is_executable = ?, updated_at = ?
             WHERE id = ?
             "#,
         )
         .bind(&attachment.content)
         .bind(&attachment.content_hash)
         .bind(&attachment.mime_type)
+        .bind(attachment.is_executable)
         .bind(&updated_at)
         .bind(&attachment.id)
         .execute(self.pool)
@@ -629,4 +642,41 @@ impl<'a> SqliteSkillRepository<'a> {
 
         Ok((scripts, references, assets))
     }
+
+    /// Fill in attachment filenames for a page of skills with a single query.
+    async fn load_attachment_names(&self, skills: &mut [Skill]) -> DbResult<()> {
+        if skills.is_empty() {
+            return Ok(());
+        }
+
+        let placeholders: Vec<&str> = skills.iter().map(|_| "?").collect();
+        let sql = format!(
+            "SELECT skill_id, type, filename FROM skill_attachment WHERE skill_id IN ({}) ORDER BY filename",
+            placeholders.join(", ")
+        );
+        let mut query_builder = sqlx::query_as::<_, (String, String, String)>(&sql);
+        for skill in skills.iter() {
+            query_builder = query_builder.bind(&skill.id);
+        }
+        let rows = query_builder
+            .fetch_all(self.pool)
+            .await
+            .map_err(|e| DbError::Database {
+                message: e.to_string(),
+            })?;
+
+        for (skill_id, type_, filename) in rows {
+            let Some(skill) = skills.iter_mut().find(|s| s.id == skill_id) else {
+                continue;
+            };
+            match type_.as_str() {
+                "script" => skill.scripts.push(filename),
+                "reference" => skill.references.push(filename),
+                "asset" => skill.assets.push(filename),
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
 }
@@ -297,6 +297,13 @@ Test instructions for frontend.
         .await
         .expect("Search should succeed");
     assert!(results.items.is_empty());
+
+    // Empty query matches nothing rather than every skill
+    let results = skills
+        .search("  ", None)
+        .await
+        .expect("Search should succeed");
+    assert!(results.items.is_empty());
 }
 
 #[tokio::test(flavor = "multi_thread")]
@@ -1,18 +1,46 @@
 //! SQLite-specific sync repository implementation.
 
+use serde::Serialize;
 use sqlx::SqlitePool;
-use std::path::Path;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
 
+use crate::db::utils::{IdLength, content_hash, normalize_remote};
 use crate::db::{DbError, DbResult, Note, Project, Repo, Skill, SyncRepository, Task, TaskList};
-use crate::sync::{ExportSummary, ImportSummary, read_jsonl};
+use crate::sync::{
+    ExportSummary, ImportOptions, ImportSummary, JsonlError, Resolution, SyncEntity, SyncRecord,
+    merge_jsonl, normalize_task_priority, read_jsonl, read_jsonl_ids, sort_skill_for_export,
+    sync_file, sync_file_for_export, write_jsonl,
+};
 
 /// SQLite-specific sync repository.
 pub struct SqliteSyncRepository<'a> {
     pub(crate) pool: &'a SqlitePool,
+    /// Export gzip-compressed `.jsonl.gz` files
+    pub(crate) compress: bool,
 }
 
 impl<'a> SyncRepository for SqliteSyncRepository<'a> {
+    fn with_compression(mut self, compress: bool) -> Self {
+        self.compress = compress;
+        self
+    }
+
     async fn import_all(&self, input_dir: &Path) -> DbResult<ImportSummary> {
+        self.import_entities(input_dir, &SyncEntity::ALL, &ImportOptions::default())
+            .await
+    }
+
+    async fn export_all(&self, output_dir: &Path) -> DbResult<ExportSummary> {
+        self.export_entities(output_dir, &SyncEntity::ALL).await
+    }
+
+    async fn import_entities(
+        &self,
+        input_dir: &Path,
+        entities: &[SyncEntity],
+        options: &ImportOptions,
+    ) -> DbResult<ImportSummary> {
         // Begin transaction
         let mut tx = self.pool.begin().await.map_err(|e| DbError::Database {
             message: format!("Failed to begin transaction: {}", e),
@@ -27,7 +55,7 @@ impl<'a> SyncRepository for SqliteSyncRepository<'a> {
             })?;
 
         // Perform import using transaction
-        let summary = import_all_with_transaction(&mut tx, input_dir)
+        let summary = import_all_with_transaction(&mut tx, input_dir, entities, options)
             .await
             .map_err(|e| DbError::Database {
                 message: format!("Import failed: {}", e),
@@ -55,23 +83,72 @@ impl<'a> SyncRepository for SqliteSyncRepository<'a> {
         Ok(summary)
     }
 
-    async fn export_all(&self, output_dir: &Path) -> DbResult<ExportSummary> {
-        export_all_from_pool(self.pool, output_dir)
+    async fn export_entities(
+        &self,
+        output_dir: &Path,
+        entities: &[SyncEntity],
+    ) -> DbResult<ExportSummary> {
+        export_all_from_pool(self.pool, output_dir, entities, None, None, self.compress)
             .await
             .map_err(|e| DbError::Database {
                 message: format!("Export failed: {}", e),
             })
     }
+
+    async fn export_entities_since(
+        &self,
+        output_dir: &Path,
+        entities: &[SyncEntity],
+        since: &str,
+    ) -> DbResult<ExportSummary> {
+        export_all_from_pool(
+            self.pool,
+            output_dir,
+            entities,
+            None,
+            Some(since),
+            self.compress,
+        )
+        .await
+        .map_err(|e| DbError::Database {
+            message: format!("Incremental export failed: {}", e),
+        })
+    }
+
+    async fn export_project_entities(
+        &self,
+        output_dir: &Path,
+        entities: &[SyncEntity],
+        project_id: &str,
+        since: Option<&str>,
+    ) -> DbResult<ExportSummary> {
+        export_all_from_pool(
+            self.pool,
+            output_dir,
+            entities,
+            Some(project_id),
+            since,
+            self.compress,
+        )
+        .await
+        .map_err(|e| DbError::Database {
+            message: format!("Project export failed: {}", e),
+        })
+    }
 }
 
-/// Import all JSONL files using a provided SQLite transaction.
+/// Import the JSONL files of the given entity types using a provided SQLite transaction.
 ///
 /// This is SQLite-specific because it uses raw SQL queries within a transaction.
 async fn import_all_with_transaction(
     tx: &mut sqlx::Transaction<'_, sqlx::Sqlite>,
     input_dir: &Path,
+    entities: &[SyncEntity],
+    options: &ImportOptions,
 ) -> Result<ImportSummary, Box<dyn std::error::Error + Send + Sync>> {
     let mut summary = ImportSummary::default();
+    // Incoming repo ids merged into a local repo with the same remote
+    let mut merged_repo_ids: HashMap<String, String> = HashMap::new();
 
     // Import order (with deferred FK, this doesn't matter, but keep logical):
     // 1. Projects (no FK dependencies)
@@ -82,19 +159,44 @@ async fn import_all_with_transaction(
     // 6. Skills (can reference projects via project_skill M:N)
 
     // ========== Import Projects ==========
-    let projects_file = input_dir.join("projects.jsonl");
-    if projects_file.exists() {
-        let projects: Vec<Project> = read_jsonl(&projects_file)?;
-        for project in projects {
+    let projects_file = sync_file(input_dir, "projects.jsonl");
+    if entities.contains(&SyncEntity::Projects) && projects_file.exists() {
+        let projects: Vec<SyncRecord<Project>> = read_jsonl(&projects_file)?;
+        for SyncRecord {
+            entity: project,
+            deleted_at,
+        } in projects
+        {
+            if !should_write(
+                tx,
+                options,
+                &mut summary,
+                SyncEntity::Projects,
+                "project",
+                &project.id,
+                project.updated_at.as_deref(),
+            )
+            .await?
+            {
+                continue;
+            }
+
             // Upsert project
             sqlx::query(
-                "INSERT INTO project (id, title, description, tags, created_at, updated_at)
-                 VALUES (?, ?, ?, ?, ?, ?)
+                "INSERT INTO project (id, title, description, tags, created_at, updated_at, deleted_at)
+                 VALUES (?, ?, ?, ?, ?, ?, ?)
                  ON CONFLICT(id) DO UPDATE SET
                    title = excluded.title,
                    description = excluded.description,
                    tags = excluded.tags,
-                   updated_at = excluded.updated_at",
+                   updated_at = excluded.updated_at,
+                   deleted_at = CASE
+                     WHEN excluded.deleted_at IS NOT NULL THEN COALESCE(deleted_at, excluded.deleted_at)
+                     WHEN deleted_at IS NOT NULL
+                       AND (excluded.updated_at IS NULL OR excluded.updated_at <= deleted_at)
+                       THEN deleted_at
+                     ELSE NULL
+                   END",
             )
             .bind(&project.id)
             .bind(&project.title)
@@ -102,6 +204,7 @@ async fn import_all_with_transaction(
             .bind(serde_json::to_string(&project.tags)?)
             .bind(&project.created_at)
             .bind(&project.updated_at)
+            .bind(&deleted_at)
             .execute(&mut **tx)
             .await?;
 
@@ -110,24 +213,88 @@ async fn import_all_with_transaction(
     }
 
     // ========== Import Repos ==========
-    let repos_file = input_dir.join("repos.jsonl");
-    if repos_file.exists() {
-        let repos: Vec<Repo> = read_jsonl(&repos_file)?;
-        for repo in repos {
-            // Upsert repo
+    let repos_file = sync_file(input_dir, "repos.jsonl");
+    if entities.contains(&SyncEntity::Repos) && repos_file.exists() {
+        let repos: Vec<SyncRecord<Repo>> = read_jsonl(&repos_file)?;
+        for SyncRecord {
+            entity: repo,
+            deleted_at,
+        } in repos
+        {
+            let remote_key = normalize_remote(&repo.remote);
+
+            // remote_key is unique, so a repo stored under another id with an
+            // equivalent remote absorbs the incoming record and its links
+            let existing_id: Option<String> =
+                sqlx::query_scalar("SELECT id FROM repo WHERE remote_key = ? AND id != ?")
+                    .bind(&remote_key)
+                    .bind(&repo.id)
+                    .fetch_optional(&mut **tx)
+                    .await?;
+            if let Some(existing_id) = existing_id {
+                let warning = format!(
+                    "Repo {} has the same remote as repo {} ({}); merged into {}",
+                    repo.id, existing_id, remote_key, existing_id
+                );
+                tracing::warn!("{}", warning);
+                summary.warnings.push(warning);
+
+                for project_id in &repo.project_ids {
+                    sqlx::query(
+                        "INSERT OR IGNORE INTO project_repo (project_id, repo_id) VALUES (?, ?)",
+                    )
+                    .bind(project_id)
+                    .bind(&existing_id)
+                    .execute(&mut **tx)
+                    .await?;
+                }
+
+                merged_repo_ids.insert(repo.id, existing_id);
+                summary.repos += 1;
+                continue;
+            }
+
+            if !should_write(
+                tx,
+                options,
+                &mut summary,
+                SyncEntity::Repos,
+                "repo",
+                &repo.id,
+                repo.updated_at.as_deref(),
+            )
+            .await?
+            {
+                continue;
+            }
+
+            // Upsert repo; records exported before repos had updated_at
+            // keep the local stamp and never undo a local deletion
             sqlx::query(
-                "INSERT INTO repo (id, remote, path, tags, created_at)
-                 VALUES (?, ?, ?, ?, ?)
+                "INSERT INTO repo (id, remote, remote_key, path, tags, created_at, updated_at, deleted_at)
+                 VALUES (?, ?, ?, ?, ?, ?, ?, ?)
                  ON CONFLICT(id) DO UPDATE SET
                    remote = excluded.remote,
+                   remote_key = excluded.remote_key,
                    path = excluded.path,
-                   tags = excluded.tags",
+                   tags = excluded.tags,
+                   updated_at = COALESCE(excluded.updated_at, updated_at),
+                   deleted_at = CASE
+                     WHEN excluded.deleted_at IS NOT NULL THEN COALESCE(deleted_at, excluded.deleted_at)
+                     WHEN deleted_at IS NOT NULL
+                       AND (excluded.updated_at IS NULL OR excluded.updated_at <= deleted_at)
+                       THEN deleted_at
+                     ELSE NULL
+                   END",
             )
             .bind(&repo.id)
             .bind(&repo.remote)
+            .bind(&remote_key)
             .bind(&repo.path)
             .bind(serde_json::to_string(&repo.tags)?)
             .bind(&repo.created_at)
+            .bind(&repo.updated_at)
+            .bind(&deleted_at)
             .execute(&mut **tx)
             .await?;
 
@@ -152,14 +319,32 @@ async fn import_all_with_transaction(
     }
 
     // ========== Import Task Lists ==========
-    let lists_file = input_dir.join("lists.jsonl");
-    if lists_file.exists() {
-        let task_lists: Vec<TaskList> = read_jsonl(&lists_file)?;
-        for task_list in task_lists {
+    let lists_file = sync_file(input_dir, "lists.jsonl");
+    if entities.contains(&SyncEntity::TaskLists) && lists_file.exists() {
+        let task_lists: Vec<SyncRecord<TaskList>> = read_jsonl(&lists_file)?;
+        for SyncRecord {
+            entity: task_list,
+            deleted_at,
+        } in task_lists
+        {
+            if !should_write(
+                tx,
+                options,
+                &mut summary,
+                SyncEntity::TaskLists,
+                "task_list",
+                &task_list.id,
+                task_list.updated_at.as_deref(),
+            )
+            .await?
+            {
+                continue;
+            }
+
             // Upsert task_list
             sqlx::query(
-                "INSERT INTO task_list (id, title, description, notes, project_id, tags, status, external_refs, created_at, updated_at, archived_at)
-                 VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+                "INSERT INTO task_list (id, title, description, notes, project_id, tags, status, external_refs, created_at, updated_at, archived_at, deleted_at)
+                 VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
                  ON CONFLICT(id) DO UPDATE SET
                    title = excluded.title,
                    description = excluded.description,
@@ -169,7 +354,14 @@ async fn import_all_with_transaction(
                    status = excluded.status,
                    external_refs = excluded.external_refs,
                    updated_at = excluded.updated_at,
-                   archived_at = excluded.archived_at",
+                   archived_at = excluded.archived_at,
+                   deleted_at = CASE
+                     WHEN excluded.deleted_at IS NOT NULL THEN COALESCE(deleted_at, excluded.deleted_at)
+                     WHEN deleted_at IS NOT NULL
+                       AND (excluded.updated_at IS NULL OR excluded.updated_at <= deleted_at)
+                       THEN deleted_at
+                     ELSE NULL
+                   END",
             )
             .bind(&task_list.id)
             .bind(&task_list.title)
@@ -182,6 +374,7 @@ async fn import_all_with_transaction(
             .bind(&task_list.created_at)
             .bind(&task_list.updated_at)
             .bind(&task_list.archived_at)
+            .bind(&deleted_at)
             .execute(&mut **tx)
             .await?;
 
@@ -192,11 +385,13 @@ async fn import_all_with_transaction(
                 .await?;
 
             for repo_id in &task_list.repo_ids {
-                sqlx::query("INSERT INTO task_list_repo (task_list_id, repo_id) VALUES (?, ?)")
-                    .bind(&task_list.id)
-                    .bind(repo_id)
-                    .execute(&mut **tx)
-                    .await?;
+                sqlx::query(
+                    "INSERT OR IGNORE INTO task_list_repo (task_list_id, repo_id) VALUES (?, ?)",
+                )
+                .bind(&task_list.id)
+                .bind(merged_repo_ids.get(repo_id).unwrap_or(repo_id))
+                .execute(&mut **tx)
+                .await?;
             }
 
             summary.task_lists += 1;
@@ -204,14 +399,36 @@ async fn import_all_with_transaction(
     }
 
     // ========== Import Tasks ==========
-    let tasks_file = input_dir.join("tasks.jsonl");
-    if tasks_file.exists() {
-        let tasks: Vec<Task> = read_jsonl(&tasks_file)?;
-        for task in tasks {
+    let tasks_file = sync_file(input_dir, "tasks.jsonl");
+    if entities.contains(&SyncEntity::Tasks) && tasks_file.exists() {
+        let tasks: Vec<SyncRecord<Task>> = read_jsonl(&tasks_file)?;
+        for SyncRecord {
+            entity: mut task,
+            deleted_at,
+        } in tasks
+        {
+            if let Some(warning) = normalize_task_priority(&mut task) {
+                tracing::warn!("{}", warning);
+                summary.warnings.push(warning);
+            }
+            if !should_write(
+                tx,
+                options,
+                &mut summary,
+                SyncEntity::Tasks,
+                "task",
+                &task.id,
+                task.updated_at.as_deref(),
+            )
+            .await?
+            {
+                continue;
+            }
+
             // Upsert task
             sqlx::query(
-                "INSERT INTO task (id, list_id, parent_id, title, description, status, priority, tags, created_at, updated_at)
-                 VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+                "INSERT INTO task (id, list_id, parent_id, title, description, status, priority, tags, created_at, updated_at, deleted_at)
+                 VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
                  ON CONFLICT(id) DO UPDATE SET
                    list_id = excluded.list_id,
                    parent_id = excluded.parent_id,
@@ -220,7 +437,14 @@ async fn import_all_with_transaction(
                    status = excluded.status,
                    priority = excluded.priority,
                    tags = excluded.tags,
-                   updated_at = excluded.updated_at",
+                   updated_at = excluded.updated_at,
+                   deleted_at = CASE
+                     WHEN excluded.deleted_at IS NOT NULL THEN COALESCE(deleted_at, excluded.deleted_at)
+                     WHEN deleted_at IS NOT NULL
+                       AND (excluded.updated_at IS NULL OR excluded.updated_at <= deleted_at)
+                       THEN deleted_at
+                     ELSE NULL
+                   END",
             )
             .bind(&task.id)
             .bind(&task.list_id)
@@ -232,6 +456,7 @@ async fn import_all_with_transaction(
             .bind(serde_json::to_string(&task.tags)?)
             .bind(&task.created_at)
             .bind(&task.updated_at)
+            .bind(&deleted_at)
             .execute(&mut **tx)
             .await?;
 
@@ -240,8 +465,8 @@ async fn import_all_with_transaction(
     }
 
     // ========== Import Task Transitions ==========
-    let transitions_file = input_dir.join("task_transition_log.jsonl");
-    if transitions_file.exists() {
+    let transitions_file = sync_file(input_dir, "task_transition_log.jsonl");
+    if entities.contains(&SyncEntity::Tasks) && transitions_file.exists() {
         use crate::db::TransitionLog;
         let transitions: Vec<TransitionLog> = read_jsonl(&transitions_file)?;
         for transition in transitions {
@@ -266,30 +491,58 @@ async fn import_all_with_transaction(
     }
 
     // ========== Import Notes ==========
-    let notes_file = input_dir.join("notes.jsonl");
-    if notes_file.exists() {
-        let notes: Vec<Note> = read_jsonl(&notes_file)?;
-        for note in notes {
+    let notes_file = sync_file(input_dir, "notes.jsonl");
+    if entities.contains(&SyncEntity::Notes) && notes_file.exists() {
+        let notes: Vec<SyncRecord<Note>> = read_jsonl(&notes_file)?;
+        for SyncRecord {
+            entity: note,
+            deleted_at,
+        } in notes
+        {
+            if !should_write(
+                tx,
+                options,
+                &mut summary,
+                SyncEntity::Notes,
+                "note",
+                &note.id,
+                note.updated_at.as_deref(),
+            )
+            .await?
+            {
+                continue;
+            }
+
             // Upsert note
             sqlx::query(
-                "INSERT INTO note (id, title, content, tags, parent_id, idx, created_at, updated_at)
-                 VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+                "INSERT INTO note (id, title, content, content_hash, tags, parent_id, idx, created_at, updated_at, deleted_at)
+                 VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
                  ON CONFLICT(id) DO UPDATE SET
                    title = excluded.title,
                    content = excluded.content,
+                   content_hash = excluded.content_hash,
                    tags = excluded.tags,
                    parent_id = excluded.parent_id,
                    idx = excluded.idx,
-                   updated_at = excluded.updated_at",
+                   updated_at = excluded.updated_at,
+                   deleted_at = CASE
+                     WHEN excluded.deleted_at IS NOT NULL THEN COALESCE(deleted_at, excluded.deleted_at)
+                     WHEN deleted_at IS NOT NULL
+                       AND (excluded.updated_at IS NULL OR excluded.updated_at <= deleted_at)
+                       THEN deleted_at
+                     ELSE NULL
+                   END",
             )
             .bind(&note.id)
             .bind(&note.title)
             .bind(&note.content)
+            .bind(content_hash(&note.content))
             .bind(serde_json::to_string(&note.tags)?)
             .bind(&note.parent_id)
             .bind(note.idx)
             .bind(&note.created_at)
             .bind(&note.updated_at)
+            .bind(&deleted_at)
             .execute(&mut **tx)
             .await?;
 
@@ -314,9 +567,9 @@ async fn import_all_with_transaction(
                 .await?;
 
             for repo_id in &note.repo_ids {
-                sqlx::query("INSERT INTO note_repo (note_id, repo_id) VALUES (?, ?)")
+                sqlx::query("INSERT OR IGNORE INTO note_repo (note_id, repo_id) VALUES (?, ?)")
                     .bind(&note.id)
-                    .bind(repo_id)
+                    .bind(merged_repo_ids.get(repo_id).unwrap_or(repo_id))
                     .execute(&mut **tx)
                     .await?;
             }
@@ -326,10 +579,24 @@ async fn import_all_with_transaction(
     }
 
     // ========== Import Skills ==========
-    let skills_file = input_dir.join("skills.jsonl");
-    if skills_file.exists() {
+    let skills_file = sync_file(input_dir, "skills.jsonl");
+    if entities.contains(&SyncEntity::Skills) && skills_file.exists() {
         let skills: Vec<Skill> = read_jsonl(&skills_file)?;
         for skill in skills {
+            if !should_write(
+                tx,
+                options,
+                &mut summary,
+                SyncEntity::Skills,
+                "skill",
+                &skill.id,
+                skill.updated_at.as_deref(),
+            )
+            .await?
+            {
+                continue;
+            }
+
             // Upsert skill
             sqlx::query(
                 "INSERT INTO skill (id, name, description, content, tags, created_at, updated_at)
@@ -372,15 +639,15 @@ async fn import_all_with_transaction(
     }
 
     // ========== Import Skill Attachments ==========
-    let attachments_file = input_dir.join("skills_attachments.jsonl");
-    if attachments_file.exists() {
+    let attachments_file = sync_file(input_dir, "skills_attachments.jsonl");
+    if entities.contains(&SyncEntity::Skills) && attachments_file.exists() {
         use crate::db::SkillAttachment;
         let attachments: Vec<SkillAttachment> = read_jsonl(&attachments_file)?;
         for attachment in attachments {
             // Upsert attachment
             sqlx::query(
-                "INSERT INTO skill_attachment (id, skill_id, type, filename, content, content_hash, mime_type, created_at, updated_at)
-                 VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+                "INSERT INTO skill_attachment (id, skill_id, type, filename, content, content_hash, mime_type, is_executable, created_at, updated_at)
+                 VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
                  ON CONFLICT(id) DO UPDATE SET
                    skill_id = excluded.skill_id,
                    type = excluded.type,
@@ -388,6 +655,7 @@ async fn import_all_with_transaction(
                    content = excluded.content,
                    content_hash = excluded.content_hash,
                    mime_type = excluded.mime_type,
+                   is_executable = excluded.is_executable,
                    updated_at = excluded.updated_at",
             )
             .bind(&attachment.id)
@@ -397,6 +665,7 @@ async fn import_all_with_transaction(
             .bind(&attachment.content)
             .bind(&attachment.content_hash)
             .bind(&attachment.mime_type)
+            .bind(attachment.is_executable)
             .bind(&attachment.created_at)
             .bind(&attachment.updated_at)
             .execute(&mut **tx)
@@ -409,107 +678,444 @@ async fn import_all_with_transaction(
     Ok(summary)
 }
 
-/// Export all database entities to JSONL files using a SQLite pool.
+/// Whether an incoming record may overwrite the stored row, per the import's
+/// conflict strategy. Skipped conflicts are recorded in `summary`.
+async fn should_write(
+    tx: &mut sqlx::Transaction<'_, sqlx::Sqlite>,
+    options: &ImportOptions,
+    summary: &mut ImportSummary,
+    entity: SyncEntity,
+    table: &str,
+    id: &str,
+    incoming: Option<&str>,
+) -> Result<bool, sqlx::Error> {
+    if !options.detects_conflicts() {
+        return Ok(true);
+    }
+
+    let local = stored_updated_at(tx, table, id).await?;
+    Ok(
+        match options.check(entity, id, local.as_deref(), incoming) {
+            Resolution::Write => true,
+            Resolution::KeepLocal => false,
+            Resolution::Conflict(conflict) => {
+                tracing::warn!(
+                    entity_type = %conflict.entity_type,
+                    id = %conflict.id,
+                    "Skipping record changed on both sides since the last sync"
+                );
+                summary.conflicts.push(conflict);
+                false
+            }
+        },
+    )
+}
+
+/// `updated_at` of a stored row, soft-deleted or not; `None` when there is no row.
+async fn stored_updated_at(
+    tx: &mut sqlx::Transaction<'_, sqlx::Sqlite>,
+    table: &str,
+    id: &str,
+) -> Result<Option<String>, sqlx::Error> {
+    let updated_at: Option<Option<String>> =
+        sqlx::query_scalar(&format!("SELECT updated_at FROM {} WHERE id = ?", table))
+            .bind(id)
+            .fetch_optional(&mut **tx)
+            .await?;
+    Ok(updated_at.flatten())
+}
+
+/// Decides which records an export rewrites.
+///
+/// A full export (`since` is `None`) rewrites every record. An incremental
+/// export rewrites records updated at or after `since`, records without an
+/// `updated_at`, and records the existing file doesn't have yet.
+struct ExportPlan<'a> {
+    path: PathBuf,
+    since: Option<&'a str>,
+    exported: HashSet<String>,
+}
+
+impl<'a> ExportPlan<'a> {
+    fn new(path: PathBuf, since: Option<&'a str>) -> Result<Self, JsonlError> {
+        let exported = match since {
+            Some(_) => read_jsonl_ids(&path)?,
+            None => HashSet::new(),
+        };
+        Ok(Self {
+            path,
+            since,
+            exported,
+        })
+    }
+
+    fn needs(&self, id: &str, updated_at: Option<&str>) -> bool {
+        match self.since {
+            None => true,
+            Some(since) => {
+                !self.exported.contains(id) || updated_at.is_none_or(|updated| updated >= since)
+            }
+        }
+    }
+
+    /// Write `changed` records; for incremental exports, merge them into the
+    /// existing file, keeping only `current_ids`.
+    fn write<T: Serialize>(
+        &self,
+        current_ids: &[&str],
+        changed: &[T],
+        id_of: impl Fn(&T) -> &str,
+    ) -> Result<(), JsonlError> {
+        match self.since {
+            None => write_jsonl(&self.path, changed),
+            Some(_) => merge_jsonl(&self.path, current_ids, changed, id_of),
+        }
+    }
+}
+
+/// Soft-deleted rows of `table` as `(id, deleted_at)`, exported as tombstones.
+///
+/// With `project`, only rows matching `scope` (which binds the project ID).
+async fn deleted_rows(
+    pool: &SqlitePool,
+    table: &str,
+    scope: &str,
+    project: Option<&str>,
+) -> Result<Vec<(String, String)>, sqlx::Error> {
+    let mut sql = format!(
+        "SELECT id, deleted_at FROM {} WHERE deleted_at IS NOT NULL",
+        table
+    );
+    if project.is_some() {
+        sql.push_str(" AND ");
+        sql.push_str(scope);
+    }
+    sql.push_str(" ORDER BY id");
+    let mut query = sqlx::query_as(&sql);
+    if let Some(project_id) = project {
+        query = query.bind(project_id);
+    }
+    query.fetch_all(pool).await
+}
+
+/// Export the given entity types to JSONL files using a SQLite pool.
 ///
 /// Uses the repository pattern through a temporary SqliteDatabase instance.
+/// Files belonging to other entity types are not written. With `since`, only
+/// records changed since then are fetched and rewritten (see [`ExportPlan`]);
+/// task transitions and skills are always written in full. Soft-deleted
+/// records are written as tombstones carrying `deleted_at`. With `project`,
+/// only that project and the records belonging or linked to it are written.
+/// With `compress`, files are written as `.jsonl.gz`.
 async fn export_all_from_pool(
     pool: &SqlitePool,
     output_dir: &Path,
+    entities: &[SyncEntity],
+    project: Option<&str>,
+    since: Option<&str>,
+    compress: bool,
 ) -> Result<ExportSummary, Box<dyn std::error::Error + Send + Sync>> {
     use crate::db::sqlite::{
         SqliteNoteRepository, SqliteProjectRepository, SqliteRepoRepository, SqliteSkillRepository,
         SqliteTaskListRepository, SqliteTaskRepository,
     };
     use crate::db::{
-        NoteRepository, ProjectRepository, RepoRepository, SkillRepository, TaskListRepository,
-        TaskRepository,
+        NoteQuery, NoteRepository, ProjectRepository, RepoQuery, RepoRepository, SkillQuery,
+        SkillRepository, TaskListQuery, TaskListRepository, TaskQuery, TaskRepository,
     };
-    use crate::sync::write_jsonl;
 
     let mut summary = ExportSummary::default();
 
+    // Export only reads, so these repositories never generate an ID
+
     // Export repos - get full entities with relationships
-    let repos_repo = SqliteRepoRepository { pool };
-    let repos_list = repos_repo.list(None).await?;
-    let mut repos = Vec::new();
-    for repo in repos_list.items {
-        let full_repo = repos_repo.get(&repo.id).await?;
-        repos.push(full_repo);
+    if entities.contains(&SyncEntity::Repos) {
+        let repos_repo = SqliteRepoRepository {
+            pool,
+            id_length: IdLength::default(),
+        };
+        let query = RepoQuery {
+            project_id: project.map(str::to_string),
+            ..Default::default()
+        };
+        let repos_list = repos_repo.list(Some(&query)).await?;
+        let plan = ExportPlan::new(
+            sync_file_for_export(output_dir, "repos.jsonl", compress),
+            since,
+        )?;
+        let mut repos = Vec::new();
+        for repo in &repos_list.items {
+            if plan.needs(&repo.id, None) {
+                repos.push(SyncRecord::live(repos_repo.get(&repo.id).await?));
+            }
+        }
+        let deleted = deleted_rows(
+            pool,
+            "repo",
+            "id IN (SELECT repo_id FROM project_repo WHERE project_id = ?)",
+            project,
+        )
+        .await?;
+        for (id, deleted_at) in &deleted {
+            if plan.needs(id, Some(deleted_at)) {
+                repos.push(SyncRecord {
+                    entity: repos_repo.fetch(id, true).await?,
+                    deleted_at: Some(deleted_at.clone()),
+                });
+            }
+        }
+        let ids: Vec<&str> = repos_list
+            .items
+            .iter()
+            .map(|r| r.id.as_str())
+            .chain(deleted.iter().map(|(id, _)| id.as_str()))
+            .collect();
+        plan.write(&ids, &repos, |r| &r.entity.id)?;
+        summary.repos = repos_list.items.len();
     }
-    write_jsonl(&output_dir.join("repos.jsonl"), &repos)?;
-    summary.repos = repos.len();
 
     // Export projects - get full entities with relationships
-    let projects_repo = SqliteProjectRepository { pool };
-    let projects_list = projects_repo.list(None).await?;
-    let mut projects = Vec::new();
-    for project in projects_list.items {
-        let full_project = projects_repo.get(&project.id).await?;
-        projects.push(full_project);
+    if entities.contains(&SyncEntity::Projects) {
+        let projects_repo = SqliteProjectRepository {
+            pool,
+            id_length: IdLength::default(),
+        };
+        let mut projects_list = projects_repo.list(None).await?;
+        if let Some(project_id) = project {
+            projects_list.items.retain(|p| p.id == project_id);
+        }
+        let plan = ExportPlan::new(
+            sync_file_for_export(output_dir, "projects.jsonl", compress),
+            since,
+        )?;
+        let mut projects = Vec::new();
+        for project in &projects_list.items {
+            if plan.needs(&project.id, project.updated_at.as_deref()) {
+                projects.push(SyncRecord::live(projects_repo.get(&project.id).await?));
+            }
+        }
+        let deleted = deleted_rows(pool, "project", "id = ?", project).await?;
+        for (id, deleted_at) in &deleted {
+            if plan.needs(id, Some(deleted_at)) {
+                projects.push(SyncRecord {
+                    entity: projects_repo.fetch(id, true).await?,
+                    deleted_at: Some(deleted_at.clone()),
+                });
+            }
+        }
+        let ids: Vec<&str> = projects_list
+            .items
+            .iter()
+            .map(|p| p.id.as_str())
+            .chain(deleted.iter().map(|(id, _)| id.as_str()))
+            .collect();
+        plan.write(&ids, &projects, |p| &p.entity.id)?;
+        summary.projects = projects_list.items.len();
     }
-    write_jsonl(&output_dir.join("projects.jsonl"), &projects)?;
-    summary.projects = projects.len();
 
     // Export task lists - get full entities with relationships
-    let task_lists_repo = SqliteTaskListRepository { pool };
-    let task_lists_list = task_lists_repo.list(None).await?;
-    let mut task_lists = Vec::new();
-    for task_list in task_lists_list.items {
-        let full_task_list = task_lists_repo.get(&task_list.id).await?;
-        task_lists.push(full_task_list);
+    if entities.contains(&SyncEntity::TaskLists) {
+        let task_lists_repo = SqliteTaskListRepository {
+            pool,
+            id_length: IdLength::default(),
+        };
+        let query = TaskListQuery {
+            project_id: project.map(str::to_string),
+            ..Default::default()
+        };
+        let task_lists_list = task_lists_repo.list(Some(&query)).await?;
+        let plan = ExportPlan::new(
+            sync_file_for_export(output_dir, "lists.jsonl", compress),
+            since,
+        )?;
+        let mut task_lists = Vec::new();
+        for task_list in &task_lists_list.items {
+            if plan.needs(&task_list.id, task_list.updated_at.as_deref()) {
+                task_lists.push(SyncRecord::live(task_lists_repo.get(&task_list.id).await?));
+            }
+        }
+        let deleted = deleted_rows(pool, "task_list", "project_id = ?", project).await?;
+        for (id, deleted_at) in &deleted {
+            if plan.needs(id, Some(deleted_at)) {
+                task_lists.push(SyncRecord {
+                    entity: task_lists_repo.fetch(id, true).await?,
+                    deleted_at: Some(deleted_at.clone()),
+                });
+            }
+        }
+        let ids: Vec<&str> = task_lists_list
+            .items
+            .iter()
+            .map(|l| l.id.as_str())
+            .chain(deleted.iter().map(|(id, _)| id.as_str()))
+            .collect();
+        plan.write(&ids, &task_lists, |l| &l.entity.id)?;
+        summary.task_lists = task_lists_list.items.len();
     }
-    write_jsonl(&output_dir.join("lists.jsonl"), &task_lists)?;
-    summary.task_lists = task_lists.len();
-
-    // Export tasks (no relationships to fetch)
-    let tasks_repo = SqliteTaskRepository { pool };
-    let tasks = tasks_repo.list(None).await?;
-    write_jsonl(&output_dir.join("tasks.jsonl"), &tasks.items)?;
-    summary.tasks = tasks.items.len();
-
-    // Export task transitions (all transitions for all tasks)
-    let mut all_transitions = Vec::new();
-    for task in &tasks.items {
-        let transitions = tasks_repo.get_transitions(&task.id, None, None).await?;
-        all_transitions.extend(transitions.items);
+
+    if entities.contains(&SyncEntity::Tasks) {
+        // Export tasks (no relationships to fetch)
+        let tasks_repo = SqliteTaskRepository {
+            pool,
+            id_length: IdLength::default(),
+        };
+        let tasks = match project {
+            None => tasks_repo.list(None).await?,
+            Some(project_id) => {
+                let list_query = TaskListQuery {
+                    project_id: Some(project_id.to_string()),
+                    ..Default::default()
+                };
+                let lists = SqliteTaskListRepository {
+                    pool,
+                    id_length: IdLength::default(),
+                }
+                .list(Some(&list_query))
+                .await?;
+                let mut items = Vec::new();
+                for list in &lists.items {
+                    let query = TaskQuery {
+                        list_id: Some(list.id.clone()),
+                        ..Default::default()
+                    };
+                    items.extend(tasks_repo.list(Some(&query)).await?.items);
+                }
+                crate::db::ListResult {
+                    total: items.len(),
+                    items,
+                    limit: None,
+                    offset: 0,
+                    next_cursor: None,
+                }
+            }
+        };
+        let plan = ExportPlan::new(
+            sync_file_for_export(output_dir, "tasks.jsonl", compress),
+            since,
+        )?;
+        let mut changed: Vec<SyncRecord<Task>> = tasks
+            .items
+            .iter()
+            .filter(|t| plan.needs(&t.id, t.updated_at.as_deref()))
+            .cloned()
+            .map(SyncRecord::live)
+            .collect();
+        let deleted = deleted_rows(
+            pool,
+            "task",
+            "list_id IN (SELECT id FROM task_list WHERE project_id = ?)",
+            project,
+        )
+        .await?;
+        for (id, deleted_at) in &deleted {
+            if plan.needs(id, Some(deleted_at)) {
+                changed.push(SyncRecord {
+                    entity: tasks_repo.fetch(id, true).await?,
+                    deleted_at: Some(deleted_at.clone()),
+                });
+            }
+        }
+        let ids: Vec<&str> = tasks
+            .items
+            .iter()
+            .map(|t| t.id.as_str())
+            .chain(deleted.iter().map(|(id, _)| id.as_str()))
+            .collect();
+        plan.write(&ids, &changed, |t| &t.entity.id)?;
+        summary.tasks = tasks.items.len();
+
+        // Export task transitions (all transitions for all tasks)
+        let mut all_transitions = Vec::new();
+        for task in &tasks.items {
+            let transitions = tasks_repo.get_transitions(&task.id, None, None).await?;
+            all_transitions.extend(transitions.items);
+        }
+        write_jsonl(
+            &sync_file_for_export(output_dir, "task_transition_log.jsonl", compress),
+            &all_transitions,
+        )?;
+        summary.transitions = all_transitions.len();
     }
-    write_jsonl(
-        &output_dir.join("task_transition_log.jsonl"),
-        &all_transitions,
-    )?;
-    summary.transitions = all_transitions.len();
 
     // Export notes - get full entities with relationships
-    let notes_repo = SqliteNoteRepository { pool };
-    let notes_list = notes_repo.list(None).await?;
-    let mut notes = Vec::new();
-    for note in notes_list.items {
-        let full_note = notes_repo.get(&note.id).await?;
-        notes.push(full_note);
+    if entities.contains(&SyncEntity::Notes) {
+        let notes_repo = SqliteNoteRepository {
+            pool,
+            id_length: IdLength::default(),
+        };
+        let query = NoteQuery {
+            project_id: project.map(str::to_string),
+            ..Default::default()
+        };
+        let notes_list = notes_repo.list(Some(&query)).await?;
+        let plan = ExportPlan::new(
+            sync_file_for_export(output_dir, "notes.jsonl", compress),
+            since,
+        )?;
+        let mut notes = Vec::new();
+        for note in &notes_list.items {
+            if plan.needs(&note.id, note.updated_at.as_deref()) {
+                notes.push(SyncRecord::live(notes_repo.get(&note.id).await?));
+            }
+        }
+        let deleted = deleted_rows(
+            pool,
+            "note",
+            "id IN (SELECT note_id FROM project_note WHERE project_id = ?)",
+            project,
+        )
+        .await?;
+        for (id, deleted_at) in &deleted {
+            if plan.needs(id, Some(deleted_at)) {
+                notes.push(SyncRecord {
+                    entity: notes_repo.fetch(id, true).await?,
+                    deleted_at: Some(deleted_at.clone()),
+                });
+            }
+        }
+        let ids: Vec<&str> = notes_list
+            .items
+            .iter()
+            .map(|n| n.id.as_str())
+            .chain(deleted.iter().map(|(id, _)| id.as_str()))
+            .collect();
+        plan.write(&ids, &notes, |n| &n.entity.id)?;
+        summary.notes = notes_list.items.len();
     }
-    write_jsonl(&output_dir.join("notes.jsonl"), &notes)?;
-    summary.notes = notes.len();
 
     // Export skills - get full entities with relationships
-    let skills_repo = SqliteSkillRepository { pool };
-    let skills_list = skills_repo.list(None).await?;
-    let mut skills = Vec::new();
-    let mut all_attachments = Vec::new();
-    for skill in skills_list.items {
-        let full_skill = skills_repo.get(&skill.id).await?;
-        let attachments = skills_repo.get_attachments(&full_skill.id).await?;
-        skills.push(full_skill);
-        all_attachments.extend(attachments);
+    if entities.contains(&SyncEntity::Skills) {
+        let skills_repo = SqliteSkillRepository {
+            pool,
+            id_length: IdLength::default(),
+        };
+        let query = SkillQuery {
+            project_id: project.map(str::to_string),
+            ..Default::default()
+        };
+        let skills_list = skills_repo.list(Some(&query)).await?;
+        let mut skills = Vec::new();
+        let mut all_attachments = Vec::new();
+        for skill in skills_list.items {
+            let mut full_skill = skills_repo.get(&skill.id).await?;
+            let mut attachments = skills_repo.get_attachments(&full_skill.id).await?;
+            sort_skill_for_export(&mut full_skill, &mut attachments);
+            skills.push(full_skill);
+            all_attachments.extend(attachments);
+        }
+        write_jsonl(
+            &sync_file_for_export(output_dir, "skills.jsonl", compress),
+            &skills,
+        )?;
+        summary.skills = skills.len();
+
+        // Export skill attachments - one attachment per line
+        write_jsonl(
+            &sync_file_for_export(output_dir, "skills_attachments.jsonl", compress),
+            &all_attachments,
+        )?;
+        summary.attachments = all_attachments.len();
     }
-    write_jsonl(&output_dir.join("skills.jsonl"), &skills)?;
-    summary.skills = skills.len();
-
-    // Export skill attachments - one attachment per line
-    write_jsonl(
-        &output_dir.join("skills_attachments.jsonl"),
-        &all_attachments,
-    )?;
-    summary.attachments = all_attachments.len();
 
     Ok(summary)
 }
@@ -27,6 +27,7 @@ mod tests {
             tags: vec![],
             project_ids: vec!["proj0001".to_string()], // FK reference
             created_at: Some("2024-01-01T00:00:00Z".to_string()),
+            updated_at: None,
         };
 
         // Create the project being referenced
@@ -63,6 +64,7 @@ mod tests {
             tags: vec![],
             project_ids: vec!["nonexistent".to_string()], // Invalid FK
             created_at: Some("2024-01-01T00:00:00Z".to_string()),
+            updated_at: None,
         };
 
         write_jsonl(&temp_dir.path().join("repos.jsonl"), &[repo]).unwrap();
@@ -96,6 +98,7 @@ mod tests {
             tags: vec![],
             project_ids: vec!["proj0001".to_string()],
             created_at: Some("2024-01-01T00:00:00Z".to_string()),
+            updated_at: None,
         };
 
         // Invalid task_list with bad project FK
@@ -320,6 +323,7 @@ mod tests {
             tags: vec!["git".to_string()],
             project_ids: vec!["proj0001".to_string()],
             created_at: Some("2024-01-01T00:00:00Z".to_string()),
+            updated_at: None,
         };
         db1.repos().create(&repo).await.unwrap();
 
@@ -380,6 +384,41 @@ mod tests {
         }
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_compressed_export_then_import_roundtrip() {
+        let db1 = setup_test_db().await;
+        let db2 = setup_test_db().await;
+        let temp_dir = TempDir::new().unwrap();
+
+        let repo = Repo {
+            id: "repo0001".to_string(),
+            remote: "https://github.com/test/repo".to_string(),
+            path: None,
+            tags: vec![],
+            project_ids: vec![],
+            created_at: Some("2024-01-01T00:00:00Z".to_string()),
+            updated_at: None,
+        };
+        db1.repos().create(&repo).await.unwrap();
+
+        // A plain file from an earlier uncompressed export is replaced
+        db1.sync().export_all(temp_dir.path()).await.unwrap();
+        db1.sync()
+            .with_compression(true)
+            .export_all(temp_dir.path())
+            .await
+            .unwrap();
+        assert!(temp_dir.path().join("repos.jsonl.gz").exists());
+        assert!(!temp_dir.path().join("repos.jsonl").exists());
+
+        let summary = db2.sync().import_all(temp_dir.path()).await.unwrap();
+        assert_eq!(summary.repos, 1);
+        assert_eq!(
+            db2.repos().get("repo0001").await.unwrap().remote,
+            "https://github.com/test/repo"
+        );
+    }
+
     #[tokio::test(flavor = "multi_thread")]
     async fn test_import_export_preserves_all_relationships() {
         use crate::db::{Note, NoteRepository, TaskList, TaskListRepository, TaskListStatus};
@@ -410,6 +449,7 @@ mod tests {
             tags: vec![],
             project_ids: vec!["proj0001".to_string()],
             created_at: Some("2024-01-01T00:00:00Z".to_string()),
+            updated_at: None,
         };
         db1.repos().create(&repo).await.unwrap();
 
@@ -715,6 +755,7 @@ Do something useful with this skill.
             content: BASE64_STANDARD.encode("#!/bin/bash\necho test"),
             content_hash: "hash123".to_string(),
             mime_type: Some("text/x-shellscript".to_string()),
+            is_executable: false,
             created_at: Some("2024-01-01T00:00:00Z".to_string()),
             updated_at: Some("2024-01-01T00:00:00Z".to_string()),
         };
@@ -728,6 +769,7 @@ Do something useful with this skill.
             content: BASE64_STANDARD.encode("# Documentation"),
             content_hash: "hash456".to_string(),
             mime_type: Some("text/markdown".to_string()),
+            is_executable: false,
             created_at: Some("2024-01-01T00:00:00Z".to_string()),
             updated_at: Some("2024-01-01T00:00:00Z".to_string()),
         };
@@ -1253,4 +1295,483 @@ Updated instructions for the skill.
             "Should have no transitions when importing old export"
         );
     }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_skill_export_is_stable_across_attachment_changes() {
+        use crate::db::SkillAttachment;
+
+        let db = setup_test_db().await;
+        let temp_dir = TempDir::new().unwrap();
+
+        let skill = Skill {
+            id: "skill001".to_string(),
+            name: "stable-skill".to_string(),
+            description: "Stable export".to_string(),
+            content: "---\nname: stable-skill\ndescription: Stable export\n---\n".to_string(),
+            tags: vec![],
+            project_ids: vec![],
+            scripts: vec![],
+            references: vec![],
+            assets: vec![],
+            created_at: Some("2024-01-01T00:00:00Z".to_string()),
+            updated_at: Some("2024-01-01T00:00:00Z".to_string()),
+        };
+        db.skills().create(&skill).await.unwrap();
+
+        let attachment = |id: &str, type_: &str, filename: &str| SkillAttachment {
+            id: id.to_string(),
+            skill_id: "skill001".to_string(),
+            type_: type_.to_string(),
+            filename: filename.to_string(),
+            content: BASE64_STANDARD.encode(filename),
+            content_hash: format!("hash-{}", id),
+            mime_type: None,
+            is_executable: false,
+            created_at: Some("2024-01-01T00:00:00Z".to_string()),
+            updated_at: Some("2024-01-01T00:00:00Z".to_string()),
+        };
+
+        // Inserted out of order on purpose
+        for a in [
+            attachment("attach03", "script", "z.sh"),
+            attachment("attach01", "reference", "b.md"),
+            attachment("attach02", "script", "a.sh"),
+            attachment("attach04", "reference", "a.md"),
+        ] {
+            db.skills().create_attachment(&a).await.unwrap();
+        }
+
+        let read_exports = |dir: &std::path::Path| {
+            (
+                std::fs::read(dir.join("skills.jsonl")).unwrap(),
+                std::fs::read(dir.join("skills_attachments.jsonl")).unwrap(),
+            )
+        };
+
+        db.sync().export_all(temp_dir.path()).await.unwrap();
+        let original = read_exports(temp_dir.path());
+
+        let attachments: Vec<SkillAttachment> =
+            crate::sync::read_jsonl(&temp_dir.path().join("skills_attachments.jsonl")).unwrap();
+        let order: Vec<_> = attachments
+            .iter()
+            .map(|a| (a.type_.as_str(), a.filename.as_str()))
+            .collect();
+        assert_eq!(
+            order,
+            vec![
+                ("reference", "a.md"),
+                ("reference", "b.md"),
+                ("script", "a.sh"),
+                ("script", "z.sh"),
+            ]
+        );
+
+        db.sync().export_all(temp_dir.path()).await.unwrap();
+        assert_eq!(read_exports(temp_dir.path()), original);
+
+        db.skills()
+            .create_attachment(&attachment("attach05", "script", "m.sh"))
+            .await
+            .unwrap();
+        db.sync().export_all(temp_dir.path()).await.unwrap();
+        assert_ne!(read_exports(temp_dir.path()), original);
+
+        db.skills().delete_attachment("attach05").await.unwrap();
+        db.sync().export_all(temp_dir.path()).await.unwrap();
+        assert_eq!(read_exports(temp_dir.path()), original);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_import_clamps_out_of_range_task_priority() {
+        use crate::db::TaskRepository;
+
+        let db = setup_test_db().await;
+        let temp_dir = TempDir::new().unwrap();
+
+        let project = Project {
+            id: "prio0001".to_string(),
+            title: "Priority Project".to_string(),
+            description: None,
+            tags: vec![],
+            external_refs: vec![],
+            repo_ids: vec![],
+            task_list_ids: vec![],
+            note_ids: vec![],
+            created_at: Some("2024-01-01 00:00:00".to_string()),
+            updated_at: Some("2024-01-01 00:00:00".to_string()),
+        };
+        let list = TaskList {
+            id: "priolst1".to_string(),
+            title: "Priority List".to_string(),
+            description: None,
+            notes: None,
+            tags: vec![],
+            external_refs: vec![],
+            status: TaskListStatus::Active,
+            repo_ids: vec![],
+            project_id: "prio0001".to_string(),
+            created_at: Some("2024-01-01 00:00:00".to_string()),
+            updated_at: Some("2024-01-01 00:00:00".to_string()),
+            archived_at: None,
+        };
+        let task = |id: &str, priority: i32| crate::db::Task {
+            id: id.to_string(),
+            list_id: "priolst1".to_string(),
+            parent_id: None,
+            title: format!("Task {}", id),
+            description: None,
+            status: crate::db::TaskStatus::Todo,
+            priority: Some(priority),
+            tags: vec![],
+            external_refs: vec![],
+            created_at: Some("2024-01-01 00:00:00".to_string()),
+            updated_at: Some("2024-01-01 00:00:00".to_string()),
+        };
+
+        write_jsonl(&temp_dir.path().join("projects.jsonl"), &[project]).unwrap();
+        write_jsonl(&temp_dir.path().join("lists.jsonl"), &[list]).unwrap();
+        // A priority of 9 predates the 1-5 CHECK constraint
+        write_jsonl(
+            &temp_dir.path().join("tasks.jsonl"),
+            &[task("priotsk1", 9), task("priotsk2", 2)],
+        )
+        .unwrap();
+
+        let summary = db
+            .sync()
+            .import_all(temp_dir.path())
+            .await
+            .expect("Out-of-range priority should not abort the import");
+
+        assert_eq!(summary.tasks, 2);
+        assert_eq!(summary.warnings.len(), 1);
+        assert!(summary.warnings[0].contains("priotsk1"));
+        assert!(summary.warnings[0].contains("priority 9"));
+
+        let clamped = db.tasks().get("priotsk1").await.unwrap();
+        assert_eq!(clamped.priority, Some(5));
+        let untouched = db.tasks().get("priotsk2").await.unwrap();
+        assert_eq!(untouched.priority, Some(2));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_import_reports_conflicts_for_records_changed_on_both_sides() {
+        use crate::sync::{ConflictStrategy, ImportOptions, SyncEntity};
+
+        let project = |id: &str, title: &str, updated_at: &str| Project {
+            id: id.to_string(),
+            title: title.to_string(),
+            description: None,
+            tags: vec![],
+            external_refs: vec![],
+            repo_ids: vec![],
+            task_list_ids: vec![],
+            note_ids: vec![],
+            created_at: Some("2025-01-01 00:00:00".to_string()),
+            updated_at: Some(updated_at.to_string()),
+        };
+
+        let db = setup_test_db().await;
+        // Edited here after the last sync
+        db.projects()
+            .create(&project("conf0001", "Local edit", "2025-02-01 00:00:00"))
+            .await
+            .unwrap();
+        // Untouched here since the last sync
+        db.projects()
+            .create(&project("conf0002", "Synced", "2024-12-01 00:00:00"))
+            .await
+            .unwrap();
+        // Edited here, untouched remotely
+        db.projects()
+            .create(&project("conf0003", "Local only", "2025-02-01 00:00:00"))
+            .await
+            .unwrap();
+
+        let temp_dir = TempDir::new().unwrap();
+        write_jsonl(
+            &temp_dir.path().join("projects.jsonl"),
+            &[
+                project("conf0001", "Remote edit", "2025-03-01 00:00:00"),
+                project("conf0002", "Remote only", "2025-03-01 00:00:00"),
+                project("conf0003", "Stale", "2024-12-01 00:00:00"),
+            ],
+        )
+        .unwrap();
+
+        let options = |strategy| ImportOptions {
+            base: Some("2025-01-01 00:00:00".to_string()),
+            strategy,
+        };
+
+        // Default: the conflicting row is skipped and reported
+        let summary = db
+            .sync()
+            .import_entities(
+                temp_dir.path(),
+                &[SyncEntity::Projects],
+                &options(ConflictStrategy::Skip),
+            )
+            .await
+            .unwrap();
+        assert_eq!(summary.projects, 1);
+        assert_eq!(summary.conflicts.len(), 1);
+        let conflict = &summary.conflicts[0];
+        assert_eq!(conflict.entity_type, "projects");
+        assert_eq!(conflict.id, "conf0001");
+        assert_eq!(conflict.local, "2025-02-01 00:00:00");
+        assert_eq!(conflict.incoming, "2025-03-01 00:00:00");
+        assert_eq!(
+            db.projects().get("conf0001").await.unwrap().title,
+            "Local edit"
+        );
+        assert_eq!(
+            db.projects().get("conf0002").await.unwrap().title,
+            "Remote only"
+        );
+
+        // Last write wins: the newer incoming record replaces the local edit
+        let summary = db
+            .sync()
+            .import_entities(
+                temp_dir.path(),
+                &[SyncEntity::Projects],
+                &options(ConflictStrategy::LastWriteWins),
+            )
+            .await
+            .unwrap();
+        assert!(summary.conflicts.is_empty());
+        assert_eq!(summary.projects, 2);
+        assert_eq!(
+            db.projects().get("conf0001").await.unwrap().title,
+            "Remote edit"
+        );
+        // A record changed only locally never counts as a conflict and is kept
+        assert_eq!(
+            db.projects().get("conf0003").await.unwrap().title,
+            "Local only"
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_import_without_baseline_overwrites() {
+        use crate::db::{Note, NoteRepository};
+
+        let note = |title: &str, updated_at: &str| Note {
+            id: "conf0003".to_string(),
+            title: title.to_string(),
+            content: "Body".to_string(),
+            tags: vec![],
+            parent_id: None,
+            idx: None,
+            repo_ids: vec![],
+            project_ids: vec![],
+            subnote_count: None,
+            created_at: Some("2025-01-01 00:00:00".to_string()),
+            updated_at: Some(updated_at.to_string()),
+        };
+
+        let db = setup_test_db().await;
+        db.notes()
+            .create(&note("Local", "2025-02-01 00:00:00"))
+            .await
+            .unwrap();
+
+        let temp_dir = TempDir::new().unwrap();
+        write_jsonl(
+            &temp_dir.path().join("notes.jsonl"),
+            &[note("Incoming", "2025-01-15 00:00:00")],
+        )
+        .unwrap();
+
+        // import_all has no baseline, so nothing counts as a conflict
+        let summary = db.sync().import_all(temp_dir.path()).await.unwrap();
+        assert!(summary.conflicts.is_empty());
+        assert_eq!(db.notes().get("conf0003").await.unwrap().title, "Incoming");
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_soft_deletes_sync_as_tombstones() {
+        use crate::db::{Note, NoteRepository};
+        use crate::sync::{SyncRecord, read_jsonl};
+
+        let note = |title: &str, updated_at: &str| Note {
+            id: "tomb0001".to_string(),
+            title: title.to_string(),
+            content: "Body".to_string(),
+            tags: vec![],
+            parent_id: None,
+            idx: None,
+            repo_ids: vec![],
+            project_ids: vec![],
+            subnote_count: None,
+            created_at: Some("2025-01-01 00:00:00".to_string()),
+            updated_at: Some(updated_at.to_string()),
+        };
+
+        let db = setup_test_db().await;
+        db.notes()
+            .create(&note("Deleted here", "2025-01-01 00:00:00"))
+            .await
+            .unwrap();
+        db.notes().delete("tomb0001").await.unwrap();
+
+        // The deletion is exported as a tombstone
+        let export_dir = TempDir::new().unwrap();
+        db.sync().export_all(export_dir.path()).await.unwrap();
+        let records: Vec<SyncRecord<Note>> =
+            read_jsonl(&export_dir.path().join("notes.jsonl")).unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].entity.id, "tomb0001");
+        assert!(records[0].deleted_at.is_some());
+
+        // ...and deletes the note on the machine importing it
+        let other = setup_test_db().await;
+        other
+            .notes()
+            .create(&note("Live there", "2025-01-01 00:00:00"))
+            .await
+            .unwrap();
+        other.sync().import_all(export_dir.path()).await.unwrap();
+        assert!(other.notes().get("tomb0001").await.is_err());
+
+        // A record older than the deletion doesn't bring the note back
+        let stale_dir = TempDir::new().unwrap();
+        write_jsonl(
+            &stale_dir.path().join("notes.jsonl"),
+            &[note("Stale edit", "2025-01-02 00:00:00")],
+        )
+        .unwrap();
+        db.sync().import_all(stale_dir.path()).await.unwrap();
+        assert!(db.notes().get("tomb0001").await.is_err());
+
+        // A record edited after the deletion does
+        let newer_dir = TempDir::new().unwrap();
+        write_jsonl(
+            &newer_dir.path().join("notes.jsonl"),
+            &[note("Edited later", "9999-01-01 00:00:00")],
+        )
+        .unwrap();
+        db.sync().import_all(newer_dir.path()).await.unwrap();
+        assert_eq!(
+            db.notes().get("tomb0001").await.unwrap().title,
+            "Edited later"
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_repo_deletion_yields_to_later_update() {
+        let repo = |updated_at: Option<&str>| Repo {
+            id: "tomb0002".to_string(),
+            remote: "github:user/tombstoned".to_string(),
+            path: None,
+            tags: vec![],
+            project_ids: vec![],
+            created_at: Some("2025-01-01 00:00:00".to_string()),
+            updated_at: updated_at.map(str::to_string),
+        };
+
+        let db = setup_test_db().await;
+        db.repos().create(&repo(None)).await.unwrap();
+        db.repos().delete("tomb0002").await.unwrap();
+
+        let import = |record: Repo| {
+            let db = &db;
+            async move {
+                let dir = TempDir::new().unwrap();
+                write_jsonl(&dir.path().join("repos.jsonl"), &[record]).unwrap();
+                db.sync().import_all(dir.path()).await.unwrap();
+            }
+        };
+
+        // Neither a record without updated_at nor an older one restores it
+        import(repo(None)).await;
+        assert!(db.repos().get("tomb0002").await.is_err());
+        import(repo(Some("2025-01-02 00:00:00"))).await;
+        assert!(db.repos().get("tomb0002").await.is_err());
+
+        // A record updated after the deletion does
+        import(repo(Some("9999-01-01 00:00:00"))).await;
+        let restored = db.repos().get("tomb0002").await.unwrap();
+        assert_eq!(restored.updated_at.as_deref(), Some("9999-01-01 00:00:00"));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_import_merges_repo_with_equivalent_remote() {
+        use crate::db::{Note, NoteRepository};
+
+        let repo = |id: &str, remote: &str, project_ids: Vec<String>| Repo {
+            id: id.to_string(),
+            remote: remote.to_string(),
+            path: None,
+            tags: vec![],
+            project_ids,
+            created_at: Some("2025-01-01 00:00:00".to_string()),
+            updated_at: None,
+        };
+
+        let db = setup_test_db().await;
+        db.repos()
+            .create(&repo("repoloc1", "https://github.com/user/shared", vec![]))
+            .await
+            .unwrap();
+
+        // The same remote, spelled differently and stored under another id
+        let temp_dir = TempDir::new().unwrap();
+        let project = Project {
+            id: "shrproj1".to_string(),
+            title: "Shared".to_string(),
+            description: None,
+            tags: vec![],
+            external_refs: vec![],
+            repo_ids: vec![],
+            task_list_ids: vec![],
+            note_ids: vec![],
+            created_at: Some("2025-01-01 00:00:00".to_string()),
+            updated_at: Some("2025-01-01 00:00:00".to_string()),
+        };
+        let note = Note {
+            id: "shrnote1".to_string(),
+            title: "Shared note".to_string(),
+            content: "Body".to_string(),
+            tags: vec![],
+            parent_id: None,
+            idx: None,
+            repo_ids: vec!["repoinc1".to_string()],
+            project_ids: vec![],
+            subnote_count: None,
+            created_at: Some("2025-01-01 00:00:00".to_string()),
+            updated_at: Some("2025-01-01 00:00:00".to_string()),
+        };
+        write_jsonl(&temp_dir.path().join("projects.jsonl"), &[project]).unwrap();
+        write_jsonl(
+            &temp_dir.path().join("repos.jsonl"),
+            &[repo(
+                "repoinc1",
+                "git@github.com:user/shared.git",
+                vec!["shrproj1".to_string()],
+            )],
+        )
+        .unwrap();
+        write_jsonl(&temp_dir.path().join("notes.jsonl"), &[note]).unwrap();
+
+        let summary = db
+            .sync()
+            .import_all(temp_dir.path())
+            .await
+            .expect("An equivalent remote should not abort the import");
+
+        assert_eq!(summary.warnings.len(), 1);
+        assert!(summary.warnings[0].contains("repoinc1"));
+        assert!(summary.warnings[0].contains("repoloc1"));
+
+        // The incoming record and its links land on the local repo
+        assert!(db.repos().get("repoinc1").await.is_err());
+        let merged = db.repos().get("repoloc1").await.unwrap();
+        assert_eq!(merged.remote, "https://github.com/user/shared");
+        assert_eq!(merged.project_ids, vec!["shrproj1".to_string()]);
+        let note = db.notes().get("shrnote1").await.unwrap();
+        assert_eq!(note.repo_ids, vec!["repoloc1".to_string()]);
+    }
 }
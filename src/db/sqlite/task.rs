@@ -2,18 +2,25 @@
 
 use std::str::FromStr;
 
-use sqlx::{Row, SqlitePool};
+use sqlx::sqlite::SqliteRow;
+use sqlx::{Row, SqliteConnection, SqliteExecutor, SqlitePool};
 
-use super::helpers::{build_limit_offset_clause, build_order_clause};
-use crate::db::utils::{current_timestamp, generate_entity_id};
+use super::helpers::{
+    build_limit_offset_clause, check_inserted, clear_deleted_links, count_rows, revive_deleted,
+    validate_sort_field, window_total, with_id_tiebreak, with_window_total,
+};
+use crate::db::pagination::{decode_cursor, encode_cursor};
+use crate::db::utils::{IdLength, current_timestamp, generate_entity_id_with_length};
 use crate::db::{
-    DbError, DbResult, ListResult, Task, TaskQuery, TaskRepository, TaskStats, TaskStatus,
-    TransitionLog,
+    DbError, DbResult, ListResult, PageSort, ProjectTaskStats, SortOrder, Task, TaskQuery,
+    TaskRepository, TaskStats, TaskStatus, TransitionLog,
 };
 
 /// SQLx-backed task repository.
 pub struct SqliteTaskRepository<'a> {
     pub(crate) pool: &'a SqlitePool,
+    /// Length of generated IDs
+    pub(crate) id_length: IdLength,
 }
 
 /// Returns the allowed transitions from a given status.
@@ -80,15 +87,19 @@ fn validate_task(task: &Task) -> DbResult<()> {
 }
 
 /// Checks that the given parent_id refers to a top-level task (no grandparent nesting).
-async fn check_parent_depth(pool: &SqlitePool, parent_id: &str) -> DbResult<()> {
-    let grandparent: Option<String> = sqlx::query_scalar("SELECT parent_id FROM task WHERE id = ?")
-        .bind(parent_id)
-        .fetch_optional(pool)
-        .await
-        .map_err(|e| DbError::Database {
-            message: e.to_string(),
-        })?
-        .flatten();
+async fn check_parent_depth(executor: impl SqliteExecutor<'_>, parent_id: &str) -> DbResult<()> {
+    let grandparent: Option<String> =
+        sqlx::query_scalar("SELECT parent_id FROM task WHERE id = ? AND deleted_at IS NULL")
+            .bind(parent_id)
+            .fetch_optional(executor)
+            .await
+            .map_err(|e| DbError::Database {
+                message: e.to_string(),
+            })?
+            .ok_or_else(|| DbError::NotFound {
+                entity_type: "Task".to_string(),
+                id: parent_id.to_string(),
+            })?;
 
     if grandparent.is_some() {
         return Err(DbError::Validation {
@@ -101,99 +112,240 @@ async fn check_parent_depth(pool: &SqlitePool, parent_id: &str) -> DbResult<()>
     Ok(())
 }
 
-impl<'a> TaskRepository for SqliteTaskRepository<'a> {
-    async fn create(&self, task: &Task) -> DbResult<Task> {
-        // Validate task
-        validate_task(task)?;
+/// Reject a parent that would create a cycle or push the task's own
+/// subtasks below the one-level nesting limit.
+async fn check_reparent(pool: &SqlitePool, task_id: &str, parent_id: &str) -> DbResult<()> {
+    let is_ancestor: bool = sqlx::query_scalar(
+        r#"
+        WITH RECURSIVE ancestors(id, parent_id) AS (
+            SELECT id, parent_id FROM task WHERE id = ?
+            UNION
+            SELECT t.id, t.parent_id FROM task t JOIN ancestors a ON t.id = a.parent_id
+        )
+        SELECT EXISTS(SELECT 1 FROM ancestors WHERE id = ?)
+        "#,
+    )
+    .bind(parent_id)
+    .bind(task_id)
+    .fetch_one(pool)
+    .await
+    .map_err(|e| DbError::Database {
+        message: e.to_string(),
+    })?;
+
+    if is_ancestor {
+        return Err(DbError::Validation {
+            message: format!(
+                "Cannot move task '{}' under '{}': a task cannot be its own ancestor",
+                task_id, parent_id
+            ),
+        });
+    }
 
-        // Depth guard: parent must be a top-level task
-        if let Some(parent_id) = &task.parent_id {
-            check_parent_depth(self.pool, parent_id).await?;
-        }
+    let has_subtasks: bool = sqlx::query_scalar(
+        "SELECT EXISTS(SELECT 1 FROM task WHERE parent_id = ? AND deleted_at IS NULL)",
+    )
+    .bind(task_id)
+    .fetch_one(pool)
+    .await
+    .map_err(|e| DbError::Database {
+        message: e.to_string(),
+    })?;
+
+    if has_subtasks {
+        return Err(DbError::Validation {
+            message: format!(
+                "Task '{}' has subtasks and cannot become a subtask. Nesting subtasks more than one level deep is not allowed.",
+                task_id
+            ),
+        });
+    }
+    Ok(())
+}
 
-        // Use provided ID if not empty, otherwise generate one
-        let id = if task.id.is_empty() {
-            generate_entity_id()
-        } else {
-            task.id.clone()
-        };
+/// Report a missing task list by ID instead of a bare foreign key failure.
+async fn check_list_exists(executor: impl SqliteExecutor<'_>, list_id: &str) -> DbResult<()> {
+    let exists: bool = sqlx::query_scalar(
+        "SELECT EXISTS(SELECT 1 FROM task_list WHERE id = ? AND deleted_at IS NULL)",
+    )
+    .bind(list_id)
+    .fetch_one(executor)
+    .await
+    .map_err(|e| DbError::Database {
+        message: e.to_string(),
+    })?;
+
+    if !exists {
+        return Err(DbError::NotFound {
+            entity_type: "TaskList".to_string(),
+            id: list_id.to_string(),
+        });
+    }
+    Ok(())
+}
 
-        // Use provided timestamps or generate if None/empty (see utils.rs for policy)
-        let created_at = task
-            .created_at
-            .clone()
-            .filter(|s| !s.is_empty())
-            .unwrap_or_else(current_timestamp);
-        let updated_at = task
-            .updated_at
-            .clone()
-            .filter(|s| !s.is_empty())
-            .unwrap_or_else(current_timestamp);
+/// Insert a task and its initial transition, returning the task's ID.
+///
+/// Runs on the caller's connection so batches share one transaction.
+async fn insert_task(
+    conn: &mut SqliteConnection,
+    task: &Task,
+    id_length: IdLength,
+) -> DbResult<String> {
+    // Validate task
+    validate_task(task)?;
+    check_list_exists(&mut *conn, &task.list_id).await?;
+
+    // Depth guard: parent must be a top-level task
+    if let Some(parent_id) = &task.parent_id {
+        check_parent_depth(&mut *conn, parent_id).await?;
+    }
 
-        let status_str = task.status.to_string();
-        let tags_json = serde_json::to_string(&task.tags).map_err(|e| DbError::Database {
-            message: format!("Failed to serialize tags: {}", e),
+    // Use provided ID if not empty, otherwise generate one
+    let id = if task.id.is_empty() {
+        generate_entity_id_with_length(id_length)
+    } else {
+        task.id.clone()
+    };
+    // A deleted task keeps its row; reusing the ID revives it in place
+    clear_deleted_links(&mut *conn, "task", &id).await?;
+
+    // Use provided timestamps or generate if None/empty (see utils.rs for policy)
+    let created_at = task
+        .created_at
+        .clone()
+        .filter(|s| !s.is_empty())
+        .unwrap_or_else(current_timestamp);
+    let updated_at = task
+        .updated_at
+        .clone()
+        .filter(|s| !s.is_empty())
+        .unwrap_or_else(current_timestamp);
+
+    let status_str = task.status.to_string();
+    let tags_json = serde_json::to_string(&task.tags).map_err(|e| DbError::Database {
+        message: format!("Failed to serialize tags: {}", e),
+    })?;
+
+    let external_refs_json =
+        serde_json::to_string(&task.external_refs).map_err(|e| DbError::Database {
+            message: format!("Failed to serialize external_refs: {}", e),
         })?;
 
-        let external_refs_json =
-            serde_json::to_string(&task.external_refs).map_err(|e| DbError::Database {
-                message: format!("Failed to serialize external_refs: {}", e),
-            })?;
-
-        sqlx::query(
-            r#"
-            INSERT INTO task (id, list_id, parent_id, title, description, status, priority, tags, external_refs, created_at, updated_at)
-            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
-            "#,
+    let result = sqlx::query(&format!(
+        r#"
+        INSERT INTO task (id, list_id, parent_id, title, description, status, priority, tags, external_refs, created_at, updated_at)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+        {}
+        "#,
+        revive_deleted(
+            "task",
+            &[
+                "list_id",
+                "parent_id",
+                "title",
+                "description",
+                "status",
+                "priority",
+                "tags",
+                "external_refs",
+                "created_at",
+                "updated_at",
+            ],
         )
-        .bind(&id)
-        .bind(&task.list_id)
-        .bind(&task.parent_id)
-        .bind(&task.title)
-        .bind(&task.description)
-        .bind(status_str)
-        .bind(task.priority)
-        .bind(&tags_json)
-        .bind(&external_refs_json)
-        .bind(&created_at)
-        .bind(&updated_at)
-        .execute(self.pool)
-        .await
-        .map_err(|e| DbError::Database {
-            message: e.to_string(),
-        })?;
+    ))
+    .bind(&id)
+    .bind(&task.list_id)
+    .bind(&task.parent_id)
+    .bind(&task.title)
+    .bind(&task.description)
+    .bind(status_str)
+    .bind(task.priority)
+    .bind(&tags_json)
+    .bind(&external_refs_json)
+    .bind(&created_at)
+    .bind(&updated_at)
+    .execute(&mut *conn)
+    .await
+    .map_err(|e| DbError::Database {
+        message: e.to_string(),
+    })?;
+    check_inserted(&result, "Task", &id)?;
+
+    // Log initial transition
+    let transition = TransitionLog {
+        id: generate_entity_id_with_length(id_length),
+        task_id: id.clone(),
+        status: task.status.clone(),
+        transitioned_at: created_at.clone(),
+    };
+
+    sqlx::query(
+        "INSERT INTO task_transition_log (id, task_id, status, transitioned_at)
+         VALUES (?, ?, ?, ?)",
+    )
+    .bind(&transition.id)
+    .bind(&transition.task_id)
+    .bind(transition.status.to_string())
+    .bind(&transition.transitioned_at)
+    .execute(&mut *conn)
+    .await
+    .map_err(|e| DbError::Database {
+        message: e.to_string(),
+    })?;
+
+    Ok(id)
+}
 
-        // Log initial transition
-        let transition = TransitionLog {
-            id: generate_entity_id(),
-            task_id: id.clone(),
-            status: task.status.clone(),
-            transitioned_at: created_at.clone(),
-        };
+/// Task counts per status, read from `status, count` rows of a GROUP BY query.
+#[derive(Default)]
+struct StatusCounts {
+    total: usize,
+    backlog: usize,
+    todo: usize,
+    in_progress: usize,
+    review: usize,
+    done: usize,
+    cancelled: usize,
+}
 
-        sqlx::query(
-            "INSERT INTO task_transition_log (id, task_id, status, transitioned_at)
-             VALUES (?, ?, ?, ?)",
-        )
-        .bind(&transition.id)
-        .bind(&transition.task_id)
-        .bind(transition.status.to_string())
-        .bind(&transition.transitioned_at)
-        .execute(self.pool)
-        .await
-        .map_err(|e| DbError::Database {
-            message: e.to_string(),
-        })?;
+impl StatusCounts {
+    fn from_rows(rows: &[SqliteRow]) -> Self {
+        let mut counts = Self::default();
 
-        self.get(&id).await
+        for row in rows {
+            let status: String = row.get("status");
+            let count: i64 = row.get("count");
+            let count = count as usize;
+
+            counts.total += count;
+
+            match status.as_str() {
+                "backlog" => counts.backlog = count,
+                "todo" => counts.todo = count,
+                "in_progress" => counts.in_progress = count,
+                "review" => counts.review = count,
+                "done" => counts.done = count,
+                "cancelled" => counts.cancelled = count,
+                _ => {}
+            }
+        }
+
+        counts
     }
+}
 
-    async fn get(&self, id: &str) -> DbResult<Task> {
+impl SqliteTaskRepository<'_> {
+    /// Load a task by ID; with `include_deleted`, soft-deleted rows too.
+    ///
+    /// Sync export uses this to write tombstones for deleted rows.
+    pub(crate) async fn fetch(&self, id: &str, include_deleted: bool) -> DbResult<Task> {
         let row = sqlx::query(
             "SELECT id, list_id, parent_id, title, description, status, priority, tags, external_refs, created_at, updated_at
-             FROM task WHERE id = ?",
+             FROM task WHERE id = ? AND (deleted_at IS NULL OR ?)",
         )
         .bind(id)
+        .bind(include_deleted)
         .fetch_optional(self.pool)
         .await
         .map_err(|e| DbError::Database {
@@ -207,6 +359,52 @@ impl<'a> TaskRepository for SqliteTaskRepository<'a> {
 
         Ok(row_to_task(&row))
     }
+}
+
+impl<'a> TaskRepository for SqliteTaskRepository<'a> {
+    async fn create(&self, task: &Task) -> DbResult<Task> {
+        let mut tx = self.pool.begin().await.map_err(|e| DbError::Database {
+            message: e.to_string(),
+        })?;
+        let id = insert_task(&mut tx, task, self.id_length).await?;
+        tx.commit().await.map_err(|e| DbError::Database {
+            message: e.to_string(),
+        })?;
+
+        self.get(&id).await
+    }
+
+    async fn create_many(&self, tasks: &[Task]) -> DbResult<Vec<Task>> {
+        let mut tx = self.pool.begin().await.map_err(|e| DbError::Database {
+            message: e.to_string(),
+        })?;
+
+        // Dropping the transaction on error rolls back the tasks inserted so far
+        let mut ids = Vec::with_capacity(tasks.len());
+        for (index, task) in tasks.iter().enumerate() {
+            let id = insert_task(&mut tx, task, self.id_length)
+                .await
+                .map_err(|e| DbError::BatchItem {
+                    index,
+                    source: Box::new(e),
+                })?;
+            ids.push(id);
+        }
+
+        tx.commit().await.map_err(|e| DbError::Database {
+            message: e.to_string(),
+        })?;
+
+        let mut created = Vec::with_capacity(ids.len());
+        for id in &ids {
+            created.push(self.get(id).await?);
+        }
+        Ok(created)
+    }
+
+    async fn get(&self, id: &str) -> DbResult<Task> {
+        self.fetch(id, false).await
+    }
 
     async fn list(&self, query: Option<&TaskQuery>) -> DbResult<ListResult<Task>> {
         let default_query = TaskQuery::default();
@@ -221,11 +419,66 @@ impl<'a> TaskRepository for SqliteTaskRepository<'a> {
             || (query.parent_id.is_none() && query.task_type.is_none());
         let needs_activity_column = is_sorting_by_updated && is_querying_parents;
 
-        let order_clause = build_order_clause(&query.page, &allowed_fields, "created_at");
-        let limit_clause = build_limit_offset_clause(&query.page);
+        let sort_field = query
+            .page
+            .sort_by
+            .as_deref()
+            .and_then(|field| validate_sort_field(field, &allowed_fields))
+            .unwrap_or("created_at");
+        let sort_order = query.page.sort_order.unwrap_or(SortOrder::Asc);
+
+        // Keyset paging walks `(sort key, id)`. NULLs sort as the lowest
+        // value, so the ORDER BY and the cursor comparison agree on them.
+        let key_column = if sort_field == "updated_at" && needs_activity_column {
+            "last_activity_at"
+        } else {
+            sort_field
+        };
+        let (sort_key, key_placeholder) = if sort_field == "priority" {
+            ("COALESCE(priority, 0)".to_string(), "CAST(? AS INTEGER)")
+        } else {
+            (format!("COALESCE({}, '')", key_column), "?")
+        };
+        let order_clause = with_id_tiebreak(
+            &format!(
+                "ORDER BY {} {}",
+                sort_key,
+                match sort_order {
+                    SortOrder::Asc => "ASC",
+                    SortOrder::Desc => "DESC",
+                }
+            ),
+            "id",
+        );
+
+        // A cursor replaces the offset, and one extra row is fetched to tell
+        // whether another page follows
+        let cursor = match query.page.after.as_deref() {
+            Some(cursor) => {
+                let cursor = decode_cursor(cursor)?;
+                if cursor.sort_by != sort_field {
+                    return Err(DbError::Validation {
+                        message: format!(
+                            "cursor was issued for sort={}, not sort={}",
+                            cursor.sort_by, sort_field
+                        ),
+                    });
+                }
+                Some(cursor)
+            }
+            None => None,
+        };
+        let limit_clause = if cursor.is_some() {
+            build_limit_offset_clause(&PageSort {
+                limit: query.page.limit.map(|limit| limit + 1),
+                ..Default::default()
+            })
+        } else {
+            build_limit_offset_clause(&query.page)
+        };
 
         // Build filter conditions
-        let mut conditions: Vec<String> = Vec::new();
+        let mut conditions: Vec<String> = vec!["deleted_at IS NULL".to_string()];
         let mut bind_values: Vec<String> = Vec::new();
 
         if let Some(list_id) = &query.list_id {
@@ -271,67 +524,81 @@ impl<'a> TaskRepository for SqliteTaskRepository<'a> {
             bind_values.extend(tags.clone());
         }
 
-        let where_clause = if conditions.is_empty() {
-            String::new()
-        } else {
-            format!("WHERE {}", conditions.join(" AND "))
-        };
+        let where_clause = format!("WHERE {}", conditions.join(" AND "));
 
         // Build SQL based on whether we need json_each or activity column
-        let (sql, count_sql) = if needs_json_each {
+        let (inner_sql, count_sql) = if needs_json_each {
             let select_cols = if needs_activity_column {
                 "DISTINCT t.id, t.list_id, t.parent_id, t.title, t.description, t.status, t.priority, t.tags, t.external_refs, t.created_at, t.updated_at, \
-                 COALESCE((SELECT MAX(updated_at) FROM task WHERE parent_id = t.id), t.updated_at) AS last_activity_at"
+                 COALESCE((SELECT MAX(updated_at) FROM task WHERE parent_id = t.id AND deleted_at IS NULL), t.updated_at) AS last_activity_at"
             } else {
                 "DISTINCT t.id, t.list_id, t.parent_id, t.title, t.description, t.status, t.priority, t.tags, t.external_refs, t.created_at, t.updated_at"
             };
 
-            // Replace updated_at in ORDER BY with last_activity_at if we computed it
-            let order_clause_adjusted = if needs_activity_column {
-                order_clause.replace("updated_at", "last_activity_at")
-            } else {
-                order_clause.clone()
-            };
-
-            let sql = format!(
-                "SELECT {}
-                 FROM task t, json_each(t.tags)
-                 {} {} {}",
-                select_cols, where_clause, order_clause_adjusted, limit_clause
-            );
-            let count_sql = format!(
-                "SELECT COUNT(DISTINCT t.id) FROM task t, json_each(t.tags) {}",
-                where_clause
-            );
-            (sql, count_sql)
+            (
+                format!(
+                    "SELECT {}
+                     FROM task t, json_each(t.tags)
+                     {}",
+                    select_cols, where_clause
+                ),
+                format!(
+                    "SELECT COUNT(DISTINCT t.id) FROM task t, json_each(t.tags) {}",
+                    where_clause
+                ),
+            )
         } else {
             let select_cols = if needs_activity_column {
                 "task.id, task.list_id, task.parent_id, task.title, task.description, task.status, task.priority, task.tags, task.external_refs, task.created_at, task.updated_at, \
-                 COALESCE((SELECT MAX(updated_at) FROM task AS child WHERE child.parent_id = task.id), task.updated_at) AS last_activity_at"
+                 COALESCE((SELECT MAX(updated_at) FROM task AS child WHERE child.parent_id = task.id AND child.deleted_at IS NULL), task.updated_at) AS last_activity_at"
             } else {
                 "id, list_id, parent_id, title, description, status, priority, tags, external_refs, created_at, updated_at"
             };
 
-            // Replace updated_at in ORDER BY with last_activity_at if we computed it
-            let order_clause_adjusted = if needs_activity_column {
-                order_clause.replace("updated_at", "last_activity_at")
-            } else {
-                order_clause.clone()
-            };
+            (
+                format!(
+                    "SELECT {}
+                     FROM task
+                     {}",
+                    select_cols, where_clause
+                ),
+                format!("SELECT COUNT(*) FROM task {}", where_clause),
+            )
+        };
 
-            let sql = format!(
-                "SELECT {}
-                 FROM task
-                 {} {} {}",
-                select_cols, where_clause, order_clause_adjusted, limit_clause
-            );
-            let count_sql = format!("SELECT COUNT(*) FROM task {}", where_clause);
-            (sql, count_sql)
+        // Rows after the cursor, in the same direction as the ORDER BY (ties
+        // on the sort key are always broken by ascending id)
+        let mut page_bind_values = bind_values.clone();
+        let sql = match &cursor {
+            Some(cursor) => {
+                let cmp = match sort_order {
+                    SortOrder::Asc => ">",
+                    SortOrder::Desc => "<",
+                };
+                page_bind_values.extend([
+                    cursor.value.clone(),
+                    cursor.value.clone(),
+                    cursor.id.clone(),
+                ]);
+                // A cursor page only covers the rows after the cursor, so the
+                // total still needs its own count
+                format!(
+                    "SELECT * FROM ({inner}) WHERE ({key} {cmp} {ph} OR ({key} = {ph} AND id > ?)) {order} {limit}",
+                    inner = inner_sql,
+                    key = sort_key,
+                    cmp = cmp,
+                    ph = key_placeholder,
+                    order = order_clause,
+                    limit = limit_clause
+                )
+            }
+            // Otherwise the total comes back with every row
+            None => with_window_total(&inner_sql, &order_clause, &limit_clause),
         };
 
         // Get paginated results
         let mut query_builder = sqlx::query(&sql);
-        for value in &bind_values {
+        for value in &page_bind_values {
             query_builder = query_builder.bind(value);
         }
 
@@ -342,26 +609,56 @@ impl<'a> TaskRepository for SqliteTaskRepository<'a> {
                 message: e.to_string(),
             })?;
 
-        let items: Vec<Task> = rows.iter().map(row_to_task).collect();
+        let window = if cursor.is_some() {
+            None
+        } else {
+            window_total(&rows, &query.page)
+        };
+        let total = match window {
+            Some(total) => total,
+            // Cursor page, or offset past the end: count separately
+            None => count_rows(self.pool, &count_sql, &bind_values).await?,
+        };
 
-        // Get total count
-        let mut count_query = sqlx::query_scalar(&count_sql);
-        for value in &bind_values {
-            count_query = count_query.bind(value);
-        }
+        let mut items: Vec<Task> = rows.iter().map(row_to_task).collect();
 
-        let total: i64 = count_query
-            .fetch_one(self.pool)
-            .await
-            .map_err(|e| DbError::Database {
-                message: e.to_string(),
-            })?;
+        let offset = if cursor.is_some() {
+            0
+        } else {
+            query.page.offset.unwrap_or(0)
+        };
+
+        let has_more = match query.page.limit {
+            Some(limit) if cursor.is_some() => {
+                let more = items.len() > limit;
+                items.truncate(limit);
+                more
+            }
+            Some(_) => offset + items.len() < total,
+            None => false,
+        };
+        // The last kept row carries the sort key, including a computed
+        // last_activity_at that the Task model doesn't hold
+        let next_cursor = has_more
+            .then(|| items.len().checked_sub(1).and_then(|last| rows.get(last)))
+            .flatten()
+            .map(|row| {
+                let value = if sort_field == "priority" {
+                    row.get::<Option<i64>, _>("priority")
+                        .unwrap_or(0)
+                        .to_string()
+                } else {
+                    row.get::<Option<String>, _>(key_column).unwrap_or_default()
+                };
+                encode_cursor(sort_field, &value, row.get::<&str, _>("id"))
+            });
 
         Ok(ListResult {
             items,
-            total: total as usize,
+            total,
             limit: query.page.limit,
-            offset: query.page.offset.unwrap_or(0),
+            offset,
+            next_cursor,
         })
     }
 
@@ -383,12 +680,16 @@ impl<'a> TaskRepository for SqliteTaskRepository<'a> {
                     total: 0,
                     limit: query.page.limit,
                     offset: query.page.offset.unwrap_or(0),
+                    next_cursor: None,
                 });
             }
         };
 
         let mut bind_values: Vec<String> = vec![fts_query];
-        let mut where_conditions: Vec<String> = vec!["task_fts MATCH ?".to_string()];
+        let mut where_conditions: Vec<String> = vec![
+            "task_fts MATCH ?".to_string(),
+            "t.deleted_at IS NULL".to_string(),
+        ];
 
         // Add list_id filter if specified (REQUIRED for tasks)
         if let Some(ref list_id) = query.list_id {
@@ -438,7 +739,7 @@ impl<'a> TaskRepository for SqliteTaskRepository<'a> {
 
         let where_clause = format!("WHERE {}", where_conditions.join(" AND "));
 
-        // Build ORDER BY clause
+        // Build ORDER BY; unqualified since it applies to the wrapped query
         let allowed_fields = ["title", "status", "priority", "created_at", "updated_at"];
         let order_clause = {
             let sort_field = query
@@ -453,7 +754,7 @@ impl<'a> TaskRepository for SqliteTaskRepository<'a> {
                 crate::db::SortOrder::Desc => "DESC",
             };
 
-            format!("ORDER BY t.{} {}", sort_field, order)
+            format!("ORDER BY {} {}, id ASC", sort_field, order)
         };
 
         // Build FROM clause with necessary JOINs
@@ -463,29 +764,21 @@ impl<'a> TaskRepository for SqliteTaskRepository<'a> {
             "FROM task t INNER JOIN task_fts ON t.id = task_fts.id"
         };
 
-        // Count query
         let count_sql = format!(
             "SELECT COUNT(DISTINCT t.id) {} {}",
             from_clause, where_clause
         );
 
-        let mut count_query = sqlx::query_scalar::<_, i64>(&count_sql);
-        for value in &bind_values {
-            count_query = count_query.bind(value);
-        }
-        let total = count_query
-            .fetch_one(self.pool)
-            .await
-            .map_err(|e| DbError::Database {
-                message: e.to_string(),
-            })? as usize;
-
-        // Data query with LIMIT/OFFSET
+        // Data query with LIMIT/OFFSET; the total comes back with every row
         let limit_clause = build_limit_offset_clause(&query.page);
-        let data_sql = format!(
-            "SELECT DISTINCT t.id, t.list_id, t.parent_id, t.title, t.description, t.status, t.priority, t.tags, t.external_refs, t.created_at, t.updated_at
-             {} {} {} {}",
-            from_clause, where_clause, order_clause, limit_clause
+        let data_sql = with_window_total(
+            &format!(
+                "SELECT DISTINCT t.id, t.list_id, t.parent_id, t.title, t.description, t.status, t.priority, t.tags, t.external_refs, t.created_at, t.updated_at
+                 {} {}",
+                from_clause, where_clause
+            ),
+            &order_clause,
+            &limit_clause,
         );
 
         let mut data_query = sqlx::query(&data_sql);
@@ -500,6 +793,12 @@ impl<'a> TaskRepository for SqliteTaskRepository<'a> {
                 message: e.to_string(),
             })?;
 
+        let total = match window_total(&rows, &query.page) {
+            Some(total) => total,
+            // Offset past the end: no rows to read the total from
+            None => count_rows(self.pool, &count_sql, &bind_values).await?,
+        };
+
         // Map rows to Task objects
         let items: Vec<Task> = rows.iter().map(row_to_task).collect();
 
@@ -508,11 +807,12 @@ impl<'a> TaskRepository for SqliteTaskRepository<'a> {
             total,
             limit: query.page.limit,
             offset: query.page.offset.unwrap_or(0),
+            next_cursor: None,
         })
     }
 
     async fn count(&self) -> DbResult<usize> {
-        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM task")
+        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM task WHERE deleted_at IS NULL")
             .fetch_one(self.pool)
             .await
             .map_err(|e| DbError::Database {
@@ -521,12 +821,51 @@ impl<'a> TaskRepository for SqliteTaskRepository<'a> {
         Ok(count as usize)
     }
 
+    async fn list_descendants(&self, parent_ids: &[String]) -> DbResult<Vec<Task>> {
+        if parent_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let placeholders = parent_ids.iter().map(|_| "?").collect::<Vec<_>>().join(",");
+        let sql = format!(
+            r#"
+            WITH RECURSIVE descendants(id) AS (
+                SELECT id FROM task WHERE parent_id IN ({}) AND deleted_at IS NULL
+                UNION
+                SELECT t.id FROM task t JOIN descendants d ON t.parent_id = d.id
+                WHERE t.deleted_at IS NULL
+            )
+            SELECT id, list_id, parent_id, title, description, status, priority, tags, external_refs, created_at, updated_at
+            FROM task
+            WHERE id IN (SELECT id FROM descendants)
+            ORDER BY created_at ASC, id ASC
+            "#,
+            placeholders
+        );
+
+        let mut query = sqlx::query(&sql);
+        for id in parent_ids {
+            query = query.bind(id);
+        }
+        let rows = query
+            .fetch_all(self.pool)
+            .await
+            .map_err(|e| DbError::Database {
+                message: e.to_string(),
+            })?;
+
+        Ok(rows.iter().map(row_to_task).collect())
+    }
+
     async fn update(&self, task: &Task) -> DbResult<()> {
         // Validate task
         validate_task(task)?;
+        check_list_exists(self.pool, &task.list_id).await?;
 
-        // Depth guard: parent must be a top-level task
+        // Depth guard: parent must be a top-level task, and the task must
+        // not end up under itself or carry its own subtasks a level deeper
         if let Some(parent_id) = &task.parent_id {
+            check_reparent(self.pool, &task.id, parent_id).await?;
             check_parent_depth(self.pool, parent_id).await?;
         }
 
@@ -553,7 +892,7 @@ impl<'a> TaskRepository for SqliteTaskRepository<'a> {
             r#"
             UPDATE task 
             SET list_id = ?, parent_id = ?, title = ?, description = ?, status = ?, priority = ?, tags = ?, external_refs = ?, updated_at = ?
-            WHERE id = ?
+            WHERE id = ? AND deleted_at IS NULL
             "#,
         )
         .bind(&task.list_id)
@@ -582,7 +921,7 @@ impl<'a> TaskRepository for SqliteTaskRepository<'a> {
         // Log transition if status changed
         if task.status != current.status {
             let transition = TransitionLog {
-                id: generate_entity_id(),
+                id: generate_entity_id_with_length(self.id_length),
                 task_id: task.id.clone(),
                 status: task.status.clone(),
                 transitioned_at: current_timestamp(),
@@ -607,6 +946,37 @@ impl<'a> TaskRepository for SqliteTaskRepository<'a> {
     }
 
     async fn delete(&self, id: &str) -> DbResult<()> {
+        // The task and all of its subtasks share one deletion stamp
+        let result = sqlx::query(
+            r#"
+            WITH RECURSIVE subtree(id) AS (
+                SELECT id FROM task WHERE id = ? AND deleted_at IS NULL
+                UNION
+                SELECT t.id FROM task t JOIN subtree s ON t.parent_id = s.id
+                WHERE t.deleted_at IS NULL
+            )
+            UPDATE task SET deleted_at = ? WHERE id IN (SELECT id FROM subtree)
+            "#,
+        )
+        .bind(id)
+        .bind(current_timestamp())
+        .execute(self.pool)
+        .await
+        .map_err(|e| DbError::Database {
+            message: e.to_string(),
+        })?;
+
+        if result.rows_affected() == 0 {
+            return Err(DbError::NotFound {
+                entity_type: "Task".to_string(),
+                id: id.to_string(),
+            });
+        }
+
+        Ok(())
+    }
+
+    async fn purge(&self, id: &str) -> DbResult<()> {
         let result = sqlx::query("DELETE FROM task WHERE id = ?")
             .bind(id)
             .execute(self.pool)
@@ -632,7 +1002,7 @@ impl<'a> TaskRepository for SqliteTaskRepository<'a> {
                 status,
                 COUNT(*) as count
             FROM task
-            WHERE list_id = ?
+            WHERE list_id = ? AND deleted_at IS NULL
             GROUP BY status
             "#,
         )
@@ -643,65 +1013,220 @@ impl<'a> TaskRepository for SqliteTaskRepository<'a> {
             message: e.to_string(),
         })?;
 
-        let mut backlog = 0;
-        let mut todo = 0;
-        let mut in_progress = 0;
-        let mut review = 0;
-        let mut done = 0;
-        let mut cancelled = 0;
-        let mut total = 0;
-
-        for row in rows {
-            let status: String = row.get("status");
-            let count: i64 = row.get("count");
-            let count = count as usize;
-
-            total += count;
-
-            match status.as_str() {
-                "backlog" => backlog = count,
-                "todo" => todo = count,
-                "in_progress" => in_progress = count,
-                "review" => review = count,
-                "done" => done = count,
-                "cancelled" => cancelled = count,
-                _ => {}
-            }
-        }
+        let counts = StatusCounts::from_rows(&rows);
 
         Ok(TaskStats {
             list_id: list_id.to_string(),
-            total,
-            backlog,
-            todo,
-            in_progress,
-            review,
-            done,
-            cancelled,
+            total: counts.total,
+            backlog: counts.backlog,
+            todo: counts.todo,
+            in_progress: counts.in_progress,
+            review: counts.review,
+            done: counts.done,
+            cancelled: counts.cancelled,
         })
     }
 
-    async fn transition_tasks(
-        &self,
-        task_ids: &[String],
-        target_status: TaskStatus,
-    ) -> DbResult<Vec<Task>> {
-        // Validate input
-        if task_ids.is_empty() {
-            return Err(DbError::Validation {
-                message: "task_ids cannot be empty".to_string(),
-            });
-        }
-
-        // Start transaction for atomic operation
-        let mut tx = self.pool.begin().await.map_err(|e| DbError::Database {
+    async fn get_stats_for_project(&self, project_id: &str) -> DbResult<ProjectTaskStats> {
+        let task_lists: Option<i64> = sqlx::query_scalar(
+            "SELECT (SELECT COUNT(*) FROM task_list WHERE project_id = p.id AND deleted_at IS NULL)
+             FROM project p WHERE p.id = ? AND p.deleted_at IS NULL",
+        )
+        .bind(project_id)
+        .fetch_optional(self.pool)
+        .await
+        .map_err(|e| DbError::Database {
+            message: e.to_string(),
+        })?;
+        let task_lists = task_lists.ok_or_else(|| DbError::NotFound {
+            entity_type: "Project".to_string(),
+            id: project_id.to_string(),
+        })?;
+
+        let rows = sqlx::query(
+            r#"
+            SELECT
+                t.status AS status,
+                COUNT(*) as count
+            FROM task t
+            INNER JOIN task_list tl ON t.list_id = tl.id
+            WHERE tl.project_id = ? AND tl.deleted_at IS NULL AND t.deleted_at IS NULL
+            GROUP BY t.status
+            "#,
+        )
+        .bind(project_id)
+        .fetch_all(self.pool)
+        .await
+        .map_err(|e| DbError::Database {
+            message: e.to_string(),
+        })?;
+
+        let counts = StatusCounts::from_rows(&rows);
+
+        Ok(ProjectTaskStats {
+            project_id: project_id.to_string(),
+            task_lists: task_lists as usize,
+            total: counts.total,
+            backlog: counts.backlog,
+            todo: counts.todo,
+            in_progress: counts.in_progress,
+            review: counts.review,
+            done: counts.done,
+            cancelled: counts.cancelled,
+        })
+    }
+
+    async fn get_stats_for_project_lists(&self, project_id: &str) -> DbResult<Vec<TaskStats>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT
+                tl.id AS list_id,
+                t.status AS status,
+                COUNT(t.id) as count
+            FROM task_list tl
+            LEFT JOIN task t ON t.list_id = tl.id AND t.deleted_at IS NULL
+            WHERE tl.project_id = ? AND tl.deleted_at IS NULL
+            GROUP BY tl.id, t.status
+            ORDER BY tl.id
+            "#,
+        )
+        .bind(project_id)
+        .fetch_all(self.pool)
+        .await
+        .map_err(|e| DbError::Database {
+            message: e.to_string(),
+        })?;
+
+        // Rows arrive ordered by list; a NULL status marks a list with no tasks.
+        let mut grouped: Vec<(String, Vec<SqliteRow>)> = Vec::new();
+        for row in rows {
+            let list_id: String = row.get("list_id");
+            if grouped.last().is_none_or(|(id, _)| *id != list_id) {
+                grouped.push((list_id, Vec::new()));
+            }
+            let status: Option<String> = row.get("status");
+            if status.is_some()
+                && let Some((_, list_rows)) = grouped.last_mut()
+            {
+                list_rows.push(row);
+            }
+        }
+
+        Ok(grouped
+            .into_iter()
+            .map(|(list_id, list_rows)| {
+                let counts = StatusCounts::from_rows(&list_rows);
+                TaskStats {
+                    list_id,
+                    total: counts.total,
+                    backlog: counts.backlog,
+                    todo: counts.todo,
+                    in_progress: counts.in_progress,
+                    review: counts.review,
+                    done: counts.done,
+                    cancelled: counts.cancelled,
+                }
+            })
+            .collect())
+    }
+
+    async fn get_stats_for_projects(
+        &self,
+        project_ids: &[String],
+    ) -> DbResult<Vec<ProjectTaskStats>> {
+        if project_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let placeholders = project_ids
+            .iter()
+            .map(|_| "?")
+            .collect::<Vec<_>>()
+            .join(",");
+        let sql = format!(
+            r#"
+            SELECT
+                p.id AS project_id,
+                (SELECT COUNT(*) FROM task_list
+                 WHERE project_id = p.id AND deleted_at IS NULL) AS task_lists,
+                t.status AS status,
+                COUNT(t.id) as count
+            FROM project p
+            LEFT JOIN task_list tl ON tl.project_id = p.id AND tl.deleted_at IS NULL
+            LEFT JOIN task t ON t.list_id = tl.id AND t.deleted_at IS NULL
+            WHERE p.id IN ({}) AND p.deleted_at IS NULL
+            GROUP BY p.id, t.status
+            ORDER BY p.id
+            "#,
+            placeholders
+        );
+
+        let mut query = sqlx::query(&sql);
+        for id in project_ids {
+            query = query.bind(id);
+        }
+        let rows = query
+            .fetch_all(self.pool)
+            .await
+            .map_err(|e| DbError::Database {
+                message: e.to_string(),
+            })?;
+
+        // Rows arrive ordered by project; a NULL status marks a project with no tasks.
+        let mut grouped: Vec<(String, i64, Vec<SqliteRow>)> = Vec::new();
+        for row in rows {
+            let project_id: String = row.get("project_id");
+            if grouped.last().is_none_or(|(id, _, _)| *id != project_id) {
+                grouped.push((project_id, row.get("task_lists"), Vec::new()));
+            }
+            let status: Option<String> = row.get("status");
+            if status.is_some()
+                && let Some((_, _, project_rows)) = grouped.last_mut()
+            {
+                project_rows.push(row);
+            }
+        }
+
+        Ok(grouped
+            .into_iter()
+            .map(|(project_id, task_lists, project_rows)| {
+                let counts = StatusCounts::from_rows(&project_rows);
+                ProjectTaskStats {
+                    project_id,
+                    task_lists: task_lists as usize,
+                    total: counts.total,
+                    backlog: counts.backlog,
+                    todo: counts.todo,
+                    in_progress: counts.in_progress,
+                    review: counts.review,
+                    done: counts.done,
+                    cancelled: counts.cancelled,
+                }
+            })
+            .collect())
+    }
+
+    async fn transition_tasks(
+        &self,
+        task_ids: &[String],
+        target_status: TaskStatus,
+    ) -> DbResult<Vec<Task>> {
+        // Validate input
+        if task_ids.is_empty() {
+            return Err(DbError::Validation {
+                message: "task_ids cannot be empty".to_string(),
+            });
+        }
+
+        // Start transaction for atomic operation
+        let mut tx = self.pool.begin().await.map_err(|e| DbError::Database {
             message: e.to_string(),
         })?;
 
         // Build IN clause for SQL query
         let placeholders = task_ids.iter().map(|_| "?").collect::<Vec<_>>().join(",");
         let query_str = format!(
-            "SELECT id, list_id, parent_id, title, description, status, priority, tags, external_refs, created_at, updated_at FROM task WHERE id IN ({})",
+            "SELECT id, list_id, parent_id, title, description, status, priority, tags, external_refs, created_at, updated_at FROM task WHERE id IN ({}) AND deleted_at IS NULL",
             placeholders
         );
 
@@ -771,7 +1296,7 @@ impl<'a> TaskRepository for SqliteTaskRepository<'a> {
             let in_flight_statuses = "('todo','in_progress','review')";
             for task in &tasks {
                 let blocking: Vec<String> = sqlx::query_scalar(&format!(
-                    "SELECT id FROM task WHERE parent_id = ? AND status IN {}",
+                    "SELECT id FROM task WHERE parent_id = ? AND deleted_at IS NULL AND status IN {}",
                     in_flight_statuses
                 ))
                 .bind(&task.id)
@@ -828,7 +1353,7 @@ impl<'a> TaskRepository for SqliteTaskRepository<'a> {
         let transition_timestamp = current_timestamp();
         for task_id in task_ids {
             let transition = TransitionLog {
-                id: generate_entity_id(),
+                id: generate_entity_id_with_length(self.id_length),
                 task_id: task_id.clone(),
                 status: target_status.clone(),
                 transitioned_at: transition_timestamp.clone(),
@@ -873,6 +1398,384 @@ impl<'a> TaskRepository for SqliteTaskRepository<'a> {
         Ok(updated_tasks)
     }
 
+    async fn reparent_tasks(
+        &self,
+        task_ids: &[String],
+        new_parent: Option<&str>,
+    ) -> DbResult<Vec<Task>> {
+        if task_ids.is_empty() {
+            return Err(DbError::Validation {
+                message: "task_ids cannot be empty".to_string(),
+            });
+        }
+
+        // Repeated IDs would otherwise look like missing tasks
+        let mut seen = std::collections::HashSet::new();
+        let task_ids: Vec<&String> = task_ids.iter().filter(|id| seen.insert(*id)).collect();
+
+        let mut tx = self.pool.begin().await.map_err(|e| DbError::Database {
+            message: e.to_string(),
+        })?;
+
+        let placeholders = task_ids.iter().map(|_| "?").collect::<Vec<_>>().join(",");
+        let query_str = format!(
+            "SELECT id, list_id, parent_id, title, description, status, priority, tags, external_refs, created_at, updated_at FROM task WHERE id IN ({}) AND deleted_at IS NULL",
+            placeholders
+        );
+
+        let mut query = sqlx::query(&query_str);
+        for id in &task_ids {
+            query = query.bind(*id);
+        }
+        let tasks: Vec<Task> = query
+            .fetch_all(&mut *tx)
+            .await
+            .map_err(|e| DbError::Database {
+                message: e.to_string(),
+            })?
+            .iter()
+            .map(row_to_task)
+            .collect();
+
+        if let Some(missing) = task_ids
+            .iter()
+            .find(|id| !tasks.iter().any(|task| &task.id == **id))
+        {
+            return Err(DbError::NotFound {
+                entity_type: "Task".to_string(),
+                id: missing.to_string(),
+            });
+        }
+
+        if let Some(parent_id) = new_parent {
+            let parent = sqlx::query(
+                "SELECT id, list_id, parent_id, title, description, status, priority, tags, external_refs, created_at, updated_at
+                 FROM task WHERE id = ? AND deleted_at IS NULL",
+            )
+            .bind(parent_id)
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(|e| DbError::Database {
+                message: e.to_string(),
+            })?
+            .map(|row| row_to_task(&row))
+            .ok_or_else(|| DbError::NotFound {
+                entity_type: "Task".to_string(),
+                id: parent_id.to_string(),
+            })?;
+
+            // The target and its ancestors must not be among the moved tasks
+            let cycle_query = format!(
+                r#"
+                WITH RECURSIVE ancestors(id, parent_id) AS (
+                    SELECT id, parent_id FROM task WHERE id = ?
+                    UNION
+                    SELECT t.id, t.parent_id FROM task t JOIN ancestors a ON t.id = a.parent_id
+                )
+                SELECT id FROM ancestors WHERE id IN ({}) LIMIT 1
+                "#,
+                placeholders
+            );
+            let mut query = sqlx::query_scalar::<_, String>(&cycle_query).bind(parent_id);
+            for id in &task_ids {
+                query = query.bind(*id);
+            }
+            let cycle = query
+                .fetch_optional(&mut *tx)
+                .await
+                .map_err(|e| DbError::Database {
+                    message: e.to_string(),
+                })?;
+
+            if let Some(task_id) = cycle {
+                return Err(DbError::Validation {
+                    message: format!(
+                        "Cannot move task '{}' under '{}': a task cannot be its own ancestor",
+                        task_id, parent_id
+                    ),
+                });
+            }
+
+            if parent.parent_id.is_some() {
+                return Err(DbError::Validation {
+                    message: format!(
+                        "Task '{}' is already a subtask. Nesting subtasks more than one level deep is not allowed.",
+                        parent_id
+                    ),
+                });
+            }
+
+            if let Some(task) = tasks.iter().find(|task| task.list_id != parent.list_id) {
+                return Err(DbError::Validation {
+                    message: format!(
+                        "Cannot move task '{}' under '{}': task is in list '{}' but the parent is in list '{}'",
+                        task.id, parent_id, task.list_id, parent.list_id
+                    ),
+                });
+            }
+
+            let nested_query = format!(
+                "SELECT parent_id FROM task WHERE parent_id IN ({}) AND deleted_at IS NULL LIMIT 1",
+                placeholders
+            );
+            let mut query = sqlx::query_scalar::<_, String>(&nested_query);
+            for id in &task_ids {
+                query = query.bind(*id);
+            }
+            let nested = query
+                .fetch_optional(&mut *tx)
+                .await
+                .map_err(|e| DbError::Database {
+                    message: e.to_string(),
+                })?;
+
+            if let Some(task_id) = nested {
+                return Err(DbError::Validation {
+                    message: format!(
+                        "Task '{}' has subtasks and cannot become a subtask. Nesting subtasks more than one level deep is not allowed.",
+                        task_id
+                    ),
+                });
+            }
+        }
+
+        let mut update = sqlx::query(&format!(
+            "UPDATE task SET parent_id = ?, updated_at = ? WHERE id IN ({})",
+            placeholders
+        ))
+        .bind(new_parent)
+        .bind(current_timestamp());
+        for id in &task_ids {
+            update = update.bind(*id);
+        }
+        update
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| DbError::Database {
+                message: e.to_string(),
+            })?;
+
+        let mut fetch_query = sqlx::query(&query_str);
+        for id in &task_ids {
+            fetch_query = fetch_query.bind(*id);
+        }
+        let mut updated: Vec<Task> = fetch_query
+            .fetch_all(&mut *tx)
+            .await
+            .map_err(|e| DbError::Database {
+                message: e.to_string(),
+            })?
+            .iter()
+            .map(row_to_task)
+            .collect();
+
+        tx.commit().await.map_err(|e| DbError::Database {
+            message: e.to_string(),
+        })?;
+
+        updated.sort_by_key(|task| task_ids.iter().position(|id| **id == task.id));
+        Ok(updated)
+    }
+
+    async fn complete_task(&self, task_id: &str, cascade: bool) -> DbResult<Vec<Task>> {
+        let mut tx = self.pool.begin().await.map_err(|e| DbError::Database {
+            message: e.to_string(),
+        })?;
+
+        let exists: Option<String> =
+            sqlx::query_scalar("SELECT id FROM task WHERE id = ? AND deleted_at IS NULL")
+                .bind(task_id)
+                .fetch_optional(&mut *tx)
+                .await
+                .map_err(|e| DbError::Database {
+                    message: e.to_string(),
+                })?;
+        if exists.is_none() {
+            return Err(DbError::NotFound {
+                entity_type: "Task".to_string(),
+                id: task_id.to_string(),
+            });
+        }
+
+        // The task itself, then (optionally) every open descendant
+        let mut ids = vec![task_id.to_string()];
+        if cascade {
+            let descendants: Vec<String> = sqlx::query_scalar(
+                r#"
+                WITH RECURSIVE descendants(id) AS (
+                    SELECT id FROM task WHERE parent_id = ? AND deleted_at IS NULL
+                    UNION ALL
+                    SELECT t.id FROM task t JOIN descendants d ON t.parent_id = d.id
+                    WHERE t.deleted_at IS NULL
+                )
+                SELECT id FROM task
+                WHERE id IN (SELECT id FROM descendants)
+                  AND status NOT IN ('done', 'cancelled')
+                "#,
+            )
+            .bind(task_id)
+            .fetch_all(&mut *tx)
+            .await
+            .map_err(|e| DbError::Database {
+                message: e.to_string(),
+            })?;
+            ids.extend(descendants);
+        }
+
+        let placeholders = ids.iter().map(|_| "?").collect::<Vec<_>>().join(",");
+        let updated_at = current_timestamp();
+
+        // Only tasks that actually change get a new timestamp and a log entry
+        let select_open = format!(
+            "SELECT id FROM task WHERE id IN ({}) AND status != 'done'",
+            placeholders
+        );
+        let mut query = sqlx::query_scalar(&select_open);
+        for id in &ids {
+            query = query.bind(id);
+        }
+        let changing: Vec<String> =
+            query
+                .fetch_all(&mut *tx)
+                .await
+                .map_err(|e| DbError::Database {
+                    message: e.to_string(),
+                })?;
+
+        for id in &changing {
+            sqlx::query("UPDATE task SET status = 'done', updated_at = ? WHERE id = ?")
+                .bind(&updated_at)
+                .bind(id)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| DbError::Database {
+                    message: e.to_string(),
+                })?;
+
+            sqlx::query(
+                "INSERT INTO task_transition_log (id, task_id, status, transitioned_at)
+                 VALUES (?, ?, ?, ?)",
+            )
+            .bind(generate_entity_id_with_length(self.id_length))
+            .bind(id)
+            .bind(TaskStatus::Done.to_string())
+            .bind(&updated_at)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| DbError::Database {
+                message: e.to_string(),
+            })?;
+        }
+
+        let fetch = format!(
+            "SELECT id, list_id, parent_id, title, description, status, priority, tags, external_refs, created_at, updated_at FROM task WHERE id IN ({}) AND deleted_at IS NULL",
+            placeholders
+        );
+        let mut query = sqlx::query(&fetch);
+        for id in &ids {
+            query = query.bind(id);
+        }
+        let rows = query
+            .fetch_all(&mut *tx)
+            .await
+            .map_err(|e| DbError::Database {
+                message: e.to_string(),
+            })?;
+
+        tx.commit().await.map_err(|e| DbError::Database {
+            message: e.to_string(),
+        })?;
+
+        // Completed task first, descendants after
+        let mut tasks: Vec<Task> = rows.iter().map(row_to_task).collect();
+        tasks.sort_by_key(|t| t.id != task_id);
+        Ok(tasks)
+    }
+
+    async fn complete_finished_parents(&self, task_id: &str) -> DbResult<Vec<Task>> {
+        let mut tx = self.pool.begin().await.map_err(|e| DbError::Database {
+            message: e.to_string(),
+        })?;
+
+        let mut completed = Vec::new();
+        // Guards against a corrupt parent chain looping back on itself
+        let mut visited = std::collections::HashSet::new();
+        let mut current = task_id.to_string();
+
+        while visited.insert(current.clone()) {
+            let parent_id: Option<String> =
+                sqlx::query_scalar("SELECT parent_id FROM task WHERE id = ?")
+                    .bind(&current)
+                    .fetch_optional(&mut *tx)
+                    .await
+                    .map_err(|e| DbError::Database {
+                        message: e.to_string(),
+                    })?
+                    .flatten();
+            let Some(parent_id) = parent_id else {
+                break;
+            };
+
+            let (parent_open, children_open): (bool, i64) = sqlx::query_as(
+                "SELECT
+                    (SELECT status NOT IN ('done', 'cancelled') FROM task WHERE id = ?),
+                    (SELECT COUNT(*) FROM task WHERE parent_id = ? AND deleted_at IS NULL AND status NOT IN ('done', 'cancelled'))",
+            )
+            .bind(&parent_id)
+            .bind(&parent_id)
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(|e| DbError::Database {
+                message: e.to_string(),
+            })?;
+            if !parent_open || children_open > 0 {
+                break;
+            }
+
+            let updated_at = current_timestamp();
+            sqlx::query("UPDATE task SET status = 'done', updated_at = ? WHERE id = ?")
+                .bind(&updated_at)
+                .bind(&parent_id)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| DbError::Database {
+                    message: e.to_string(),
+                })?;
+            sqlx::query(
+                "INSERT INTO task_transition_log (id, task_id, status, transitioned_at)
+                 VALUES (?, ?, ?, ?)",
+            )
+            .bind(generate_entity_id_with_length(self.id_length))
+            .bind(&parent_id)
+            .bind(TaskStatus::Done.to_string())
+            .bind(&updated_at)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| DbError::Database {
+                message: e.to_string(),
+            })?;
+
+            let row = sqlx::query(
+                "SELECT id, list_id, parent_id, title, description, status, priority, tags, external_refs, created_at, updated_at FROM task WHERE id = ?",
+            )
+            .bind(&parent_id)
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(|e| DbError::Database {
+                message: e.to_string(),
+            })?;
+            completed.push(row_to_task(&row));
+
+            current = parent_id;
+        }
+
+        tx.commit().await.map_err(|e| DbError::Database {
+            message: e.to_string(),
+        })?;
+
+        Ok(completed)
+    }
+
     async fn get_transitions(
         &self,
         task_id: &str,
@@ -928,6 +1831,7 @@ impl<'a> TaskRepository for SqliteTaskRepository<'a> {
             total: total as usize,
             limit: Some(limit),
             offset,
+            next_cursor: None,
         })
     }
 }
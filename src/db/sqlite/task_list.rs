@@ -2,24 +2,100 @@
 
 use std::str::FromStr;
 
-use sqlx::{Row, SqlitePool};
+use sqlx::{Row, SqliteConnection, SqlitePool};
 
-use super::helpers::{build_limit_offset_clause, build_order_clause};
-use crate::db::utils::{current_timestamp, generate_entity_id};
+use super::helpers::{
+    build_limit_offset_clause, build_order_clause, check_inserted, clear_deleted_links, count_rows,
+    revive_deleted, window_total, with_id_tiebreak, with_window_total,
+};
+use crate::db::utils::{IdLength, current_timestamp, generate_entity_id_with_length};
 use crate::db::{
     DbError, DbResult, ListResult, TaskList, TaskListQuery, TaskListRepository, TaskListStatus,
+    TaskStatus,
 };
 
 /// SQLx-backed task list repository.
 pub struct SqliteTaskListRepository<'a> {
     pub(crate) pool: &'a SqlitePool,
+    /// Length of generated IDs
+    pub(crate) id_length: IdLength,
+}
+
+impl SqliteTaskListRepository<'_> {
+    /// Load a task list by ID; with `include_deleted`, soft-deleted rows too.
+    ///
+    /// Sync export uses this to write tombstones for deleted rows.
+    pub(crate) async fn fetch(&self, id: &str, include_deleted: bool) -> DbResult<TaskList> {
+        // Get the main task_list record
+        let row = sqlx::query(
+            "SELECT id, title, description, notes, tags, external_refs, status, project_id, created_at, updated_at, archived_at
+             FROM task_list WHERE id = ? AND (deleted_at IS NULL OR ?)",
+        )
+        .bind(id)
+        .bind(include_deleted)
+        .fetch_optional(self.pool)
+        .await
+        .map_err(|e| DbError::Database {
+            message: e.to_string(),
+        })?;
+
+        let row = row.ok_or(DbError::NotFound {
+            entity_type: "TaskList".to_string(),
+            id: id.to_string(),
+        })?;
+
+        // Parse tags JSON
+        let tags_json: String = row.get("tags");
+        let tags: Vec<String> =
+            serde_json::from_str(&tags_json).map_err(|e| DbError::Database {
+                message: format!("Failed to parse tags JSON: {}", e),
+            })?;
+
+        // Parse external_refs JSON
+        let external_refs_json: String = row.get("external_refs");
+        let external_refs: Vec<String> =
+            serde_json::from_str(&external_refs_json).unwrap_or_default();
+
+        // Parse status
+        let status_str: String = row.get("status");
+        let status = TaskListStatus::from_str(&status_str).map_err(|_| DbError::Database {
+            message: format!("Invalid status: {}", status_str),
+        })?;
+
+        // Get repo relationships
+        let repo_ids: Vec<String> = sqlx::query_scalar(
+            "SELECT tlr.repo_id FROM task_list_repo tlr JOIN repo r ON r.id = tlr.repo_id
+             WHERE tlr.task_list_id = ? AND r.deleted_at IS NULL",
+        )
+        .bind(id)
+        .fetch_all(self.pool)
+        .await
+        .map_err(|e| DbError::Database {
+            message: e.to_string(),
+        })?;
+
+        Ok(TaskList {
+            id: row.get("id"),
+            title: row.get("title"),
+            description: row.get("description"),
+            notes: row.get("notes"),
+            tags,
+            external_refs,
+            status,
+            repo_ids,
+            project_id: row.get("project_id"),
+            created_at: Some(row.get("created_at")),
+            updated_at: Some(row.get("updated_at")),
+            archived_at: row.get("archived_at"),
+        })
+    }
 }
 
 impl<'a> TaskListRepository for SqliteTaskListRepository<'a> {
     async fn create(&self, task_list: &TaskList) -> DbResult<TaskList> {
         // Use provided ID if not empty, otherwise generate one
         let id = if task_list.id.is_empty() {
-            generate_entity_id()
+            generate_entity_id_with_length(self.id_length)
         } else {
             task_list.id.clone()
         };
@@ -39,39 +115,10 @@ impl<'a> TaskListRepository for SqliteTaskListRepository<'a> {
             message: e.to_string(),
         })?;
 
-        // Validate repo_ids exist
-        for repo_id in &task_list.repo_ids {
-            let exists: bool = sqlx::query_scalar("SELECT EXISTS(SELECT 1 FROM repo WHERE id = ?)")
-                .bind(repo_id)
-                .fetch_one(&mut *tx)
-                .await
-                .map_err(|e| DbError::Database {
-                    message: e.to_string(),
-                })?;
-
-            if !exists {
-                return Err(DbError::NotFound {
-                    entity_type: "Repo".to_string(),
-                    id: repo_id.clone(),
-                });
-            }
-        }
-
-        // Validate project_id exists (REQUIRED - not optional)
-        let exists: bool = sqlx::query_scalar("SELECT EXISTS(SELECT 1 FROM project WHERE id = ?)")
-            .bind(&task_list.project_id)
-            .fetch_one(&mut *tx)
-            .await
-            .map_err(|e| DbError::Database {
-                message: e.to_string(),
-            })?;
+        validate_relationships(&mut tx, task_list).await?;
 
-        if !exists {
-            return Err(DbError::NotFound {
-                entity_type: "Project".to_string(),
-                id: task_list.project_id.clone(),
-            });
-        }
+        // A deleted task list keeps its row; reusing the ID revives it in place
+        clear_deleted_links(&mut tx, "task_list", &id).await?;
 
         // Insert the task_list record
         let tags_json = serde_json::to_string(&task_list.tags).map_err(|e| DbError::Database {
@@ -82,10 +129,25 @@ impl<'a> TaskListRepository for SqliteTaskListRepository<'a> {
                 message: format!("Failed to serialize external_refs: {}", e),
             })?;
 
-        sqlx::query(
+        let result = sqlx::query(&format!(
             "INSERT INTO task_list (id, title, description, notes, tags, external_refs, status, project_id, created_at, updated_at, archived_at) 
-             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
-        )
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?) {}",
+            revive_deleted(
+                "task_list",
+                &[
+                    "title",
+                    "description",
+                    "notes",
+                    "tags",
+                    "external_refs",
+                    "status",
+                    "project_id",
+                    "created_at",
+                    "updated_at",
+                    "archived_at",
+                ],
+            )
+        ))
         .bind(&id)
         .bind(&task_list.title)
         .bind(&task_list.description)
@@ -102,17 +164,20 @@ impl<'a> TaskListRepository for SqliteTaskListRepository<'a> {
         .map_err(|e| DbError::Database {
             message: e.to_string(),
         })?;
+        check_inserted(&result, "TaskList", &id)?;
 
         // Insert task_list <-> repo relationships
         for repo_id in &task_list.repo_ids {
-            sqlx::query("INSERT INTO task_list_repo (task_list_id, repo_id) VALUES (?, ?)")
-                .bind(&id)
-                .bind(repo_id)
-                .execute(&mut *tx)
-                .await
-                .map_err(|e| DbError::Database {
-                    message: e.to_string(),
-                })?;
+            sqlx::query(
+                "INSERT OR IGNORE INTO task_list_repo (task_list_id, repo_id) VALUES (?, ?)",
+            )
+            .bind(&id)
+            .bind(repo_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| DbError::Database {
+                message: e.to_string(),
+            })?;
         }
 
         // Commit transaction
@@ -137,65 +202,31 @@ impl<'a> TaskListRepository for SqliteTaskListRepository<'a> {
     }
 
     async fn get(&self, id: &str) -> DbResult<TaskList> {
-        // Get the main task_list record
-        let row = sqlx::query(
-            "SELECT id, title, description, notes, tags, external_refs, status, project_id, created_at, updated_at, archived_at
-             FROM task_list WHERE id = ?",
+        self.fetch(id, false).await
+    }
+
+    async fn get_by_external_ref(&self, external_ref: &str) -> DbResult<TaskList> {
+        // Matches any element of the JSON array, not just the first
+        let id: Option<String> = sqlx::query_scalar(
+            "SELECT id FROM task_list \
+             WHERE deleted_at IS NULL \
+               AND EXISTS (SELECT 1 FROM json_each(task_list.external_refs) WHERE json_each.value = ?) \
+             ORDER BY created_at, id LIMIT 1",
         )
-        .bind(id)
+        .bind(external_ref)
         .fetch_optional(self.pool)
         .await
         .map_err(|e| DbError::Database {
             message: e.to_string(),
         })?;
 
-        let row = row.ok_or(DbError::NotFound {
-            entity_type: "TaskList".to_string(),
-            id: id.to_string(),
-        })?;
-
-        // Parse tags JSON
-        let tags_json: String = row.get("tags");
-        let tags: Vec<String> =
-            serde_json::from_str(&tags_json).map_err(|e| DbError::Database {
-                message: format!("Failed to parse tags JSON: {}", e),
-            })?;
-
-        // Parse external_refs JSON
-        let external_refs_json: String = row.get("external_refs");
-        let external_refs: Vec<String> =
-            serde_json::from_str(&external_refs_json).unwrap_or_default();
-
-        // Parse status
-        let status_str: String = row.get("status");
-        let status = TaskListStatus::from_str(&status_str).map_err(|_| DbError::Database {
-            message: format!("Invalid status: {}", status_str),
-        })?;
-
-        // Get repo relationships
-        let repo_ids: Vec<String> =
-            sqlx::query_scalar("SELECT repo_id FROM task_list_repo WHERE task_list_id = ?")
-                .bind(id)
-                .fetch_all(self.pool)
-                .await
-                .map_err(|e| DbError::Database {
-                    message: e.to_string(),
-                })?;
-
-        Ok(TaskList {
-            id: row.get("id"),
-            title: row.get("title"),
-            description: row.get("description"),
-            notes: row.get("notes"),
-            tags,
-            external_refs,
-            status,
-            repo_ids,
-            project_id: row.get("project_id"),
-            created_at: Some(row.get("created_at")),
-            updated_at: Some(row.get("updated_at")),
-            archived_at: row.get("archived_at"),
-        })
+        match id {
+            Some(id) => self.get(&id).await,
+            None => Err(DbError::NotFound {
+                entity_type: "TaskList".to_string(),
+                id: external_ref.to_string(),
+            }),
+        }
     }
 
     async fn list(&self, query: Option<&TaskListQuery>) -> DbResult<ListResult<TaskList>> {
@@ -203,11 +234,14 @@ impl<'a> TaskListRepository for SqliteTaskListRepository<'a> {
         let query = query.unwrap_or(&default_query);
         let allowed_fields = ["title", "status", "created_at", "updated_at"];
 
-        let order_clause = build_order_clause(&query.page, &allowed_fields, "created_at");
+        let order_clause = with_id_tiebreak(
+            &build_order_clause(&query.page, &allowed_fields, "created_at"),
+            "id",
+        );
         let limit_clause = build_limit_offset_clause(&query.page);
 
         // Build filter conditions
-        let mut conditions: Vec<String> = Vec::new();
+        let mut conditions: Vec<String> = vec!["tl.deleted_at IS NULL".to_string()];
         let mut bind_values: Vec<String> = Vec::new();
 
         if let Some(status) = &query.status {
@@ -231,47 +265,35 @@ impl<'a> TaskListRepository for SqliteTaskListRepository<'a> {
             bind_values.extend(tags.clone());
         }
 
-        let where_clause = if conditions.is_empty() {
-            String::new()
-        } else {
-            format!("WHERE {}", conditions.join(" AND "))
-        };
+        let where_clause = format!("WHERE {}", conditions.join(" AND "));
 
         // Build SQL based on whether we need json_each
-        let (sql, count_sql) = if needs_json_each {
+        let (inner_sql, count_sql) = if needs_json_each {
             (
                 format!(
                     "SELECT DISTINCT tl.id, tl.title, tl.description, tl.notes, tl.tags, tl.external_refs, tl.status, tl.project_id, tl.created_at, tl.updated_at, tl.archived_at 
                      FROM task_list tl, json_each(tl.tags)
-                     {} {} {}",
-                    where_clause, order_clause, limit_clause
+                     {}",
+                    where_clause
                 ),
                 format!(
                     "SELECT COUNT(DISTINCT tl.id) FROM task_list tl, json_each(tl.tags) {}",
                     where_clause
                 ),
             )
-        } else if !conditions.is_empty() {
-            (
-                format!(
-                    "SELECT id, title, description, notes, tags, external_refs, status, project_id, created_at, updated_at, archived_at 
-                     FROM task_list tl {} {} {}",
-                    where_clause, order_clause, limit_clause
-                ),
-                format!("SELECT COUNT(*) FROM task_list tl {}", where_clause),
-            )
         } else {
             (
                 format!(
                     "SELECT id, title, description, notes, tags, external_refs, status, project_id, created_at, updated_at, archived_at 
-                     FROM task_list {} {}",
-                     order_clause, limit_clause
+                     FROM task_list tl {}",
+                    where_clause
                 ),
-                "SELECT COUNT(*) FROM task_list".to_string(),
+                format!("SELECT COUNT(*) FROM task_list tl {}", where_clause),
             )
         };
+        let sql = with_window_total(&inner_sql, &order_clause, &limit_clause);
 
-        // Get paginated results
+        // Get paginated results; the total comes back with every row
         let mut query_builder = sqlx::query(&sql);
         for value in &bind_values {
             query_builder = query_builder.bind(value);
@@ -284,6 +306,12 @@ impl<'a> TaskListRepository for SqliteTaskListRepository<'a> {
                 message: e.to_string(),
             })?;
 
+        let total = match window_total(&rows, &query.page) {
+            Some(total) => total,
+            // Offset past the end: no rows to read the total from
+            None => count_rows(self.pool, &count_sql, &bind_values).await?,
+        };
+
         let items: Vec<TaskList> = rows
             .into_iter()
             .map(|row| {
@@ -314,24 +342,12 @@ impl<'a> TaskListRepository for SqliteTaskListRepository<'a> {
             })
             .collect();
 
-        // Get total count
-        let mut count_query = sqlx::query_scalar(&count_sql);
-        for value in &bind_values {
-            count_query = count_query.bind(value);
-        }
-
-        let total: i64 = count_query
-            .fetch_one(self.pool)
-            .await
-            .map_err(|e| DbError::Database {
-                message: e.to_string(),
-            })?;
-
         Ok(ListResult {
             items,
-            total: total as usize,
+            total,
             limit: query.page.limit,
             offset: query.page.offset.unwrap_or(0),
+            next_cursor: None,
         })
     }
 
@@ -353,12 +369,16 @@ impl<'a> TaskListRepository for SqliteTaskListRepository<'a> {
                     total: 0,
                     limit: query.page.limit,
                     offset: query.page.offset.unwrap_or(0),
+                    next_cursor: None,
                 });
             }
         };
 
         let mut bind_values: Vec<String> = vec![fts_query];
-        let mut where_conditions: Vec<String> = vec!["task_list_fts MATCH ?".to_string()];
+        let mut where_conditions: Vec<String> = vec![
+            "task_list_fts MATCH ?".to_string(),
+            "tl.deleted_at IS NULL".to_string(),
+        ];
 
         // Add project filter if specified
         if let Some(ref project_id) = query.project_id {
@@ -400,7 +420,7 @@ impl<'a> TaskListRepository for SqliteTaskListRepository<'a> {
                 crate::db::SortOrder::Desc => "DESC",
             };
 
-            format!("ORDER BY tl.{} {}", sort_field, order)
+            format!("ORDER BY tl.{} {}, tl.id ASC", sort_field, order)
         };
 
         // Build FROM clause with necessary JOINs
@@ -483,16 +503,18 @@ impl<'a> TaskListRepository for SqliteTaskListRepository<'a> {
             total,
             limit: query.page.limit,
             offset: query.page.offset.unwrap_or(0),
+            next_cursor: None,
         })
     }
 
     async fn count(&self) -> DbResult<usize> {
-        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM task_list")
-            .fetch_one(self.pool)
-            .await
-            .map_err(|e| DbError::Database {
-                message: e.to_string(),
-            })?;
+        let count: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM task_list WHERE deleted_at IS NULL")
+                .fetch_one(self.pool)
+                .await
+                .map_err(|e| DbError::Database {
+                    message: e.to_string(),
+                })?;
         Ok(count as usize)
     }
 
@@ -520,10 +542,14 @@ impl<'a> TaskListRepository for SqliteTaskListRepository<'a> {
             }
         }
 
+        // Core fields and links are written in one transaction so a failed
+        // relink leaves the task list exactly as it was
         let mut tx = self.pool.begin().await.map_err(|e| DbError::Database {
             message: e.to_string(),
         })?;
 
+        validate_relationships(&mut tx, &task_list).await?;
+
         // Update the main task_lists record
         let tags_json = serde_json::to_string(&task_list.tags).map_err(|e| DbError::Database {
             message: format!("Failed to serialize tags: {}", e),
@@ -546,7 +572,7 @@ impl<'a> TaskListRepository for SqliteTaskListRepository<'a> {
             UPDATE task_list 
             SET title = ?, description = ?, notes = ?, tags = ?, external_refs = ?, 
                 status = ?, project_id = ?, updated_at = ?, archived_at = ?
-            WHERE id = ?
+            WHERE id = ? AND deleted_at IS NULL
             "#,
         )
         .bind(&task_list.title)
@@ -565,34 +591,178 @@ impl<'a> TaskListRepository for SqliteTaskListRepository<'a> {
             message: e.to_string(),
         })?;
 
-        // Replace repo relationships (delete all, then insert new ones)
-        sqlx::query("DELETE FROM task_list_repo WHERE task_list_id = ?")
+        // Diff desired vs current repo links and apply only the changes
+        let current_repo_ids: Vec<String> =
+            sqlx::query_scalar("SELECT repo_id FROM task_list_repo WHERE task_list_id = ?")
+                .bind(&task_list.id)
+                .fetch_all(&mut *tx)
+                .await
+                .map_err(|e| DbError::Database {
+                    message: e.to_string(),
+                })?;
+
+        for repo_id in current_repo_ids
+            .iter()
+            .filter(|id| !task_list.repo_ids.contains(id))
+        {
+            sqlx::query("DELETE FROM task_list_repo WHERE task_list_id = ? AND repo_id = ?")
+                .bind(&task_list.id)
+                .bind(repo_id)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| DbError::Database {
+                    message: e.to_string(),
+                })?;
+        }
+
+        for repo_id in task_list
+            .repo_ids
+            .iter()
+            .filter(|id| !current_repo_ids.contains(id))
+        {
+            sqlx::query(
+                "INSERT OR IGNORE INTO task_list_repo (task_list_id, repo_id) VALUES (?, ?)",
+            )
             .bind(&task_list.id)
+            .bind(repo_id)
             .execute(&mut *tx)
             .await
             .map_err(|e| DbError::Database {
                 message: e.to_string(),
             })?;
+        }
 
-        for repo_id in &task_list.repo_ids {
-            sqlx::query("INSERT INTO task_list_repo (task_list_id, repo_id) VALUES (?, ?)")
-                .bind(&task_list.id)
-                .bind(repo_id)
+        tx.commit().await.map_err(|e| DbError::Database {
+            message: e.to_string(),
+        })?;
+
+        Ok(())
+    }
+
+    async fn archive(
+        &self,
+        id: &str,
+        archived_at: &str,
+        complete_open: bool,
+    ) -> DbResult<Vec<String>> {
+        let mut tx = self.pool.begin().await.map_err(|e| DbError::Database {
+            message: e.to_string(),
+        })?;
+
+        let result = sqlx::query(
+            r#"
+            UPDATE task_list
+            SET status = 'archived',
+                archived_at = CASE WHEN status = 'archived' THEN archived_at ELSE ? END,
+                updated_at = ?
+            WHERE id = ? AND deleted_at IS NULL
+            "#,
+        )
+        .bind(archived_at)
+        .bind(archived_at)
+        .bind(id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DbError::Database {
+            message: e.to_string(),
+        })?;
+
+        if result.rows_affected() == 0 {
+            return Err(DbError::NotFound {
+                entity_type: "TaskList".to_string(),
+                id: id.to_string(),
+            });
+        }
+
+        let mut cancelled = Vec::new();
+        if complete_open {
+            cancelled = sqlx::query_scalar(
+                "SELECT id FROM task WHERE list_id = ? AND deleted_at IS NULL
+                 AND status IN ('backlog', 'todo', 'in_progress', 'review')
+                 ORDER BY created_at, id",
+            )
+            .bind(id)
+            .fetch_all(&mut *tx)
+            .await
+            .map_err(|e| DbError::Database {
+                message: e.to_string(),
+            })?;
+
+            // The transition log entry is the task's completion stamp
+            for task_id in &cancelled {
+                sqlx::query("UPDATE task SET status = 'cancelled', updated_at = ? WHERE id = ?")
+                    .bind(archived_at)
+                    .bind(task_id)
+                    .execute(&mut *tx)
+                    .await
+                    .map_err(|e| DbError::Database {
+                        message: e.to_string(),
+                    })?;
+
+                sqlx::query(
+                    "INSERT INTO task_transition_log (id, task_id, status, transitioned_at)
+                     VALUES (?, ?, ?, ?)",
+                )
+                .bind(generate_entity_id_with_length(self.id_length))
+                .bind(task_id)
+                .bind(TaskStatus::Cancelled.to_string())
+                .bind(archived_at)
                 .execute(&mut *tx)
                 .await
                 .map_err(|e| DbError::Database {
                     message: e.to_string(),
                 })?;
+            }
         }
 
         tx.commit().await.map_err(|e| DbError::Database {
             message: e.to_string(),
         })?;
 
-        Ok(())
+        Ok(cancelled)
     }
 
     async fn delete(&self, id: &str) -> DbResult<()> {
+        let deleted_at = current_timestamp();
+        let mut tx = self.pool.begin().await.map_err(|e| DbError::Database {
+            message: e.to_string(),
+        })?;
+
+        let result =
+            sqlx::query("UPDATE task_list SET deleted_at = ? WHERE id = ? AND deleted_at IS NULL")
+                .bind(&deleted_at)
+                .bind(id)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| DbError::Database {
+                    message: e.to_string(),
+                })?;
+
+        if result.rows_affected() == 0 {
+            return Err(DbError::NotFound {
+                entity_type: "TaskList".to_string(),
+                id: id.to_string(),
+            });
+        }
+
+        // Tasks go with their list, as they would with the ON DELETE CASCADE
+        sqlx::query("UPDATE task SET deleted_at = ? WHERE list_id = ? AND deleted_at IS NULL")
+            .bind(&deleted_at)
+            .bind(id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| DbError::Database {
+                message: e.to_string(),
+            })?;
+
+        tx.commit().await.map_err(|e| DbError::Database {
+            message: e.to_string(),
+        })?;
+
+        Ok(())
+    }
+
+    async fn purge(&self, id: &str) -> DbResult<()> {
         let mut tx = self.pool.begin().await.map_err(|e| DbError::Database {
             message: e.to_string(),
         })?;
@@ -629,3 +799,49 @@ impl<'a> TaskListRepository for SqliteTaskListRepository<'a> {
         Ok(())
     }
 }
+
+/// Check that every linked repo and the owning project exist.
+///
+/// Runs inside the caller's transaction so the checks see the same snapshot
+/// as the writes that follow.
+async fn validate_relationships(conn: &mut SqliteConnection, task_list: &TaskList) -> DbResult<()> {
+    // Validate repo_ids exist
+    for repo_id in &task_list.repo_ids {
+        let exists: bool = sqlx::query_scalar(
+            "SELECT EXISTS(SELECT 1 FROM repo WHERE id = ? AND deleted_at IS NULL)",
+        )
+        .bind(repo_id)
+        .fetch_one(&mut *conn)
+        .await
+        .map_err(|e| DbError::Database {
+            message: e.to_string(),
+        })?;
+
+        if !exists {
+            return Err(DbError::NotFound {
+                entity_type: "Repo".to_string(),
+                id: repo_id.clone(),
+            });
+        }
+    }
+
+    // Validate project_id exists (REQUIRED - not optional)
+    let exists: bool = sqlx::query_scalar(
+        "SELECT EXISTS(SELECT 1 FROM project WHERE id = ? AND deleted_at IS NULL)",
+    )
+    .bind(&task_list.project_id)
+    .fetch_one(&mut *conn)
+    .await
+    .map_err(|e| DbError::Database {
+        message: e.to_string(),
+    })?;
+
+    if !exists {
+        return Err(DbError::NotFound {
+            entity_type: "Project".to_string(),
+            id: task_list.project_id.clone(),
+        });
+    }
+
+    Ok(())
+}
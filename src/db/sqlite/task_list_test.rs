@@ -1,8 +1,8 @@
-//! Tests for SqliteTaskListRepository FTS5 search.
+//! Tests for SqliteTaskListRepository FTS5 search and archiving.
 
 use crate::db::{
-    Database, Project, ProjectRepository, SqliteDatabase, TaskList, TaskListQuery,
-    TaskListRepository, TaskListStatus,
+    Database, Project, ProjectRepository, SqliteDatabase, Task, TaskList, TaskListQuery,
+    TaskListRepository, TaskListStatus, TaskQuery, TaskRepository, TaskStatus,
 };
 
 async fn setup_db() -> SqliteDatabase {
@@ -441,3 +441,190 @@ async fn fts5_search_handles_special_characters() {
     // Should match "test" after sanitization
     assert_eq!(result.items.len(), 1);
 }
+
+// =============================================================================
+// Archive
+// =============================================================================
+
+async fn create_list_with_tasks(db: &SqliteDatabase, list_id: &str) {
+    let project = create_test_project(db, &format!("p{}", &list_id[1..])).await;
+    db.task_lists()
+        .create(&TaskList {
+            id: list_id.to_string(),
+            title: "Sprint".to_string(),
+            description: None,
+            notes: None,
+            tags: vec![],
+            external_refs: vec![],
+            status: TaskListStatus::Active,
+            repo_ids: vec![],
+            project_id: project.id,
+            created_at: None,
+            updated_at: None,
+            archived_at: None,
+        })
+        .await
+        .unwrap();
+
+    for (i, status) in [
+        TaskStatus::Backlog,
+        TaskStatus::Todo,
+        TaskStatus::InProgress,
+        TaskStatus::Review,
+        TaskStatus::Done,
+        TaskStatus::Cancelled,
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        db.tasks()
+            .create(&Task {
+                id: format!("{}t{}", &list_id[..6], i),
+                list_id: list_id.to_string(),
+                parent_id: None,
+                title: format!("Task {}", i),
+                description: None,
+                status,
+                priority: None,
+                tags: vec![],
+                external_refs: vec![],
+                created_at: Some(format!("2025-01-01 00:00:0{}", i)),
+                updated_at: Some(format!("2025-01-01 00:00:0{}", i)),
+            })
+            .await
+            .unwrap();
+    }
+}
+
+async fn statuses(db: &SqliteDatabase, list_id: &str) -> Vec<TaskStatus> {
+    let query = TaskQuery {
+        list_id: Some(list_id.to_string()),
+        ..Default::default()
+    };
+    let mut tasks = db.tasks().list(Some(&query)).await.unwrap().items;
+    tasks.sort_by(|a, b| a.id.cmp(&b.id));
+    tasks.into_iter().map(|t| t.status).collect()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn archive_leaves_open_tasks_by_default() {
+    let db = setup_db().await;
+    create_list_with_tasks(&db, "list0001").await;
+
+    let cancelled = db
+        .task_lists()
+        .archive("list0001", "2025-02-01 00:00:00", false)
+        .await
+        .unwrap();
+    assert!(cancelled.is_empty());
+
+    let list = db.task_lists().get("list0001").await.unwrap();
+    assert_eq!(list.status, TaskListStatus::Archived);
+    assert_eq!(list.archived_at.as_deref(), Some("2025-02-01 00:00:00"));
+    assert_eq!(
+        statuses(&db, "list0001").await,
+        vec![
+            TaskStatus::Backlog,
+            TaskStatus::Todo,
+            TaskStatus::InProgress,
+            TaskStatus::Review,
+            TaskStatus::Done,
+            TaskStatus::Cancelled,
+        ]
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn archive_with_complete_open_cancels_open_tasks() {
+    let db = setup_db().await;
+    create_list_with_tasks(&db, "list0002").await;
+
+    let cancelled = db
+        .task_lists()
+        .archive("list0002", "2025-02-01 00:00:00", true)
+        .await
+        .unwrap();
+    assert_eq!(
+        cancelled,
+        vec!["list00t0", "list00t1", "list00t2", "list00t3"]
+    );
+
+    assert_eq!(
+        statuses(&db, "list0002").await,
+        vec![
+            TaskStatus::Cancelled,
+            TaskStatus::Cancelled,
+            TaskStatus::Cancelled,
+            TaskStatus::Cancelled,
+            TaskStatus::Done,
+            TaskStatus::Cancelled,
+        ]
+    );
+
+    // Each cancellation is stamped in the transition log
+    let transitions = db
+        .tasks()
+        .get_transitions("list00t1", None, None)
+        .await
+        .unwrap();
+    assert!(transitions.items.iter().any(|t| {
+        t.status == TaskStatus::Cancelled && t.transitioned_at == "2025-02-01 00:00:00"
+    }));
+
+    // Done tasks keep their status and get no new entry
+    let done = db
+        .tasks()
+        .get_transitions("list00t4", None, None)
+        .await
+        .unwrap();
+    assert!(done.items.iter().all(|t| t.status != TaskStatus::Cancelled));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn archive_missing_list_is_not_found() {
+    let db = setup_db().await;
+    let result = db
+        .task_lists()
+        .archive("nope0000", "2025-02-01 00:00:00", true)
+        .await;
+    assert!(matches!(result, Err(crate::db::DbError::NotFound { .. })));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn get_by_external_ref_matches_any_ref() {
+    let db = setup_db().await;
+    let project = create_test_project(&db, "proj0001").await;
+    let repo = db.task_lists();
+
+    let task_list = TaskList {
+        id: "list0001".to_string(),
+        title: "Sprint".to_string(),
+        description: None,
+        notes: None,
+        tags: vec![],
+        external_refs: vec!["JIRA-7".to_string(), "owner/repo#8".to_string()],
+        status: TaskListStatus::Active,
+        repo_ids: vec![],
+        project_id: project.id.clone(),
+        created_at: None,
+        updated_at: None,
+        archived_at: None,
+    };
+    repo.create(&task_list).await.unwrap();
+
+    let retrieved = repo.get("list0001").await.unwrap();
+    assert_eq!(retrieved.external_refs, task_list.external_refs);
+
+    assert_eq!(
+        repo.get_by_external_ref("JIRA-7").await.unwrap().id,
+        "list0001"
+    );
+    assert_eq!(
+        repo.get_by_external_ref("owner/repo#8").await.unwrap().id,
+        "list0001"
+    );
+    assert!(matches!(
+        repo.get_by_external_ref("JIRA-8").await,
+        Err(crate::db::DbError::NotFound { .. })
+    ));
+}
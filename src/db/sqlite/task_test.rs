@@ -1,8 +1,8 @@
 //! Tests for SqliteTaskRepository.
 
 use crate::db::{
-    Database, SqliteDatabase, Task, TaskList, TaskListRepository, TaskListStatus, TaskQuery,
-    TaskRepository, TaskStatus,
+    Database, DbError, SortOrder, SqliteDatabase, Task, TaskList, TaskListRepository,
+    TaskListStatus, TaskQuery, TaskRepository, TaskStatus,
 };
 
 async fn setup_db() -> SqliteDatabase {
@@ -335,6 +335,71 @@ async fn task_delete() {
     assert!(result.is_err());
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn task_soft_delete_hides_subtasks_until_purged() {
+    let db = setup_db().await;
+    db.task_lists()
+        .create(&make_task_list("listsoft", "Soft Delete"))
+        .await
+        .expect("Create list");
+
+    let tasks = db.tasks();
+    tasks
+        .create(&make_task("softpar1", "listsoft", "Parent"))
+        .await
+        .expect("Create parent");
+    let mut child = make_task("softsub1", "listsoft", "Child");
+    child.parent_id = Some("softpar1".to_string());
+    tasks.create(&child).await.expect("Create subtask");
+    tasks
+        .create(&make_task("softkeep", "listsoft", "Kept"))
+        .await
+        .expect("Create sibling");
+
+    tasks
+        .delete("softpar1")
+        .await
+        .expect("Delete should succeed");
+
+    // The subtask goes with its parent; the sibling stays
+    assert!(matches!(
+        tasks.get("softsub1").await,
+        Err(DbError::NotFound { .. })
+    ));
+    let query = TaskQuery {
+        list_id: Some("listsoft".to_string()),
+        ..Default::default()
+    };
+    let listed = tasks.list(Some(&query)).await.expect("List should succeed");
+    let ids: Vec<_> = listed.items.iter().map(|t| t.id.as_str()).collect();
+    assert_eq!(ids, vec!["softkeep"]);
+    assert_eq!(listed.total, 1);
+
+    // Deleting twice is a miss, but the rows are still there
+    assert!(matches!(
+        tasks.delete("softpar1").await,
+        Err(DbError::NotFound { .. })
+    ));
+    let stored: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM task WHERE deleted_at IS NOT NULL")
+        .fetch_one(db.pool())
+        .await
+        .unwrap();
+    assert_eq!(stored, 2);
+
+    tasks.purge("softpar1").await.expect("Purge should succeed");
+    let stored: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM task")
+        .fetch_one(db.pool())
+        .await
+        .unwrap();
+    assert_eq!(stored, 1);
+
+    // The ID is free again
+    tasks
+        .create(&make_task("softpar1", "listsoft", "Parent again"))
+        .await
+        .expect("Create should reuse a purged ID");
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn task_create_with_tags() {
     let db = setup_db().await;
@@ -909,6 +974,7 @@ async fn parent_tasks_sorted_by_activity_include_subtask_updates() {
             offset: Some(0),
             sort_by: Some("updated_at".to_string()),
             sort_order: Some(crate::db::SortOrder::Desc),
+            after: None,
         },
         list_id: Some("sort0001".to_string()),
         parent_id: None,
@@ -933,6 +999,158 @@ async fn parent_tasks_sorted_by_activity_include_subtask_updates() {
     );
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn list_pages_by_cursor_without_skipping_or_repeating() {
+    let db = setup_db().await;
+    let tasks = db.tasks();
+    db.task_lists()
+        .create(&make_task_list("curs0000", "Cursor Test"))
+        .await
+        .expect("Create task list should succeed");
+
+    // curs0002 and curs0003 share a created_at, so the id breaks the tie
+    for (id, created_at) in [
+        ("curs0001", "2026-01-01 10:00:01"),
+        ("curs0002", "2026-01-01 10:00:02"),
+        ("curs0003", "2026-01-01 10:00:02"),
+        ("curs0004", "2026-01-01 10:00:03"),
+        ("curs0005", "2026-01-01 10:00:04"),
+    ] {
+        let mut task = make_task(id, "curs0000", id);
+        task.created_at = Some(created_at.to_string());
+        tasks
+            .create(&task)
+            .await
+            .expect("Create task should succeed");
+    }
+
+    let page_query = |after: Option<String>| TaskQuery {
+        page: crate::db::PageSort {
+            limit: Some(2),
+            after,
+            ..Default::default()
+        },
+        list_id: Some("curs0000".to_string()),
+        ..Default::default()
+    };
+    let ids = |result: &crate::db::ListResult<Task>| {
+        result
+            .items
+            .iter()
+            .map(|t| t.id.clone())
+            .collect::<Vec<_>>()
+    };
+
+    let first = tasks.list(Some(&page_query(None))).await.unwrap();
+    assert_eq!(ids(&first), ["curs0001", "curs0002"]);
+    assert!(first.next_cursor.is_some());
+
+    // An older task inserted between pages would shift an offset-based page
+    let mut late = make_task("curs0006", "curs0000", "Late");
+    late.created_at = Some("2026-01-01 09:00:00".to_string());
+    tasks.create(&late).await.unwrap();
+
+    let second = tasks
+        .list(Some(&page_query(first.next_cursor)))
+        .await
+        .unwrap();
+    assert_eq!(ids(&second), ["curs0003", "curs0004"]);
+    assert_eq!(second.total, 6);
+
+    let third = tasks
+        .list(Some(&page_query(second.next_cursor)))
+        .await
+        .unwrap();
+    assert_eq!(ids(&third), ["curs0005"]);
+    assert_eq!(third.next_cursor, None);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn list_pages_by_cursor_on_other_sort_keys() {
+    let db = setup_db().await;
+    let tasks = db.tasks();
+    db.task_lists()
+        .create(&make_task_list("keys0000", "Sort Key Test"))
+        .await
+        .expect("Create task list should succeed");
+
+    for (id, priority, updated_at) in [
+        ("keys0001", 2, "2026-01-01 10:00:05"),
+        ("keys0002", 1, "2026-01-01 10:00:01"),
+        ("keys0003", 2, "2026-01-01 10:00:03"),
+        ("keys0004", 3, "2026-01-01 10:00:03"),
+        ("keys0005", 1, "2026-01-01 10:00:02"),
+    ] {
+        let mut task = make_task(id, "keys0000", id);
+        task.priority = Some(priority);
+        task.updated_at = Some(updated_at.to_string());
+        tasks
+            .create(&task)
+            .await
+            .expect("Create task should succeed");
+    }
+
+    let walk = |sort_by: &'static str, sort_order: SortOrder| {
+        let tasks = &tasks;
+        async move {
+            let mut ids = Vec::new();
+            let mut after = None;
+            loop {
+                let page = tasks
+                    .list(Some(&TaskQuery {
+                        page: crate::db::PageSort {
+                            limit: Some(2),
+                            sort_by: Some(sort_by.to_string()),
+                            sort_order: Some(sort_order),
+                            after,
+                            ..Default::default()
+                        },
+                        list_id: Some("keys0000".to_string()),
+                        ..Default::default()
+                    }))
+                    .await
+                    .unwrap();
+                ids.extend(page.items.into_iter().map(|t| t.id));
+                match page.next_cursor {
+                    Some(cursor) => after = Some(cursor),
+                    None => return ids,
+                }
+            }
+        }
+    };
+
+    // Priority ascending, ties broken by id
+    assert_eq!(
+        walk("priority", SortOrder::Asc).await,
+        ["keys0002", "keys0005", "keys0001", "keys0003", "keys0004"]
+    );
+    // Most recent activity first, as the started/closed kanban columns sort
+    assert_eq!(
+        walk("updated_at", SortOrder::Desc).await,
+        ["keys0001", "keys0003", "keys0004", "keys0005", "keys0002"]
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn list_cursor_must_match_sort() {
+    let db = setup_db().await;
+    let query = TaskQuery {
+        page: crate::db::PageSort {
+            sort_by: Some("priority".to_string()),
+            after: Some(crate::db::pagination::encode_cursor(
+                "created_at",
+                "2026-01-01 10:00:00",
+                "curs0001",
+            )),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let result = db.tasks().list(Some(&query)).await;
+    assert!(matches!(result, Err(DbError::Validation { .. })));
+}
+
 // =============================================================================
 // FTS5 Search Tests
 // =============================================================================
@@ -1968,8 +2186,8 @@ async fn test_bulk_transition_logs_all_task_transitions() {
 }
 
 #[tokio::test(flavor = "multi_thread")]
-async fn test_task_delete_cascades_to_transitions() {
-    // Integration test: Deleting a task should cascade delete its transitions
+async fn test_task_purge_cascades_to_transitions() {
+    // Integration test: Purging a task should cascade delete its transitions
     let db = setup_db().await;
 
     // Setup
@@ -2001,8 +2219,8 @@ async fn test_task_delete_cascades_to_transitions() {
         "Should have 3 transitions before delete"
     );
 
-    // Delete task
-    db.tasks().delete(&created.id).await.unwrap();
+    // Purge task
+    db.tasks().purge(&created.id).await.unwrap();
 
     // Verify task is gone
     let result = db.tasks().get(&created.id).await;
@@ -2338,3 +2556,171 @@ async fn update_task_rejects_grandparent_nesting() {
         "Re-parenting under a subtask must be rejected"
     );
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn update_task_rejects_own_ancestor() {
+    let db = setup_db().await;
+    db.task_lists()
+        .create(&make_task_list("grdlist7", "Guardrail List 7"))
+        .await
+        .unwrap();
+
+    let mut parent = make_task("grdpar06", "grdlist7", "Parent");
+    db.tasks().create(&parent).await.unwrap();
+
+    let mut subtask = make_task("grdsub06", "grdlist7", "Subtask");
+    subtask.parent_id = Some("grdpar06".to_string());
+    db.tasks().create(&subtask).await.unwrap();
+
+    // A task can't be its own parent
+    let mut looped = db.tasks().get("grdsub06").await.unwrap();
+    looped.parent_id = Some("grdsub06".to_string());
+    let err = db.tasks().update(&looped).await.unwrap_err();
+    assert!(
+        matches!(err, DbError::Validation { .. }),
+        "Self-parenting must be a validation error: {err}"
+    );
+    assert!(err.to_string().contains("own ancestor"), "{err}");
+
+    // ...nor move under its own subtask
+    parent.parent_id = Some("grdsub06".to_string());
+    let err = db.tasks().update(&parent).await.unwrap_err();
+    assert!(err.to_string().contains("own ancestor"), "{err}");
+
+    // A task with subtasks can't become a subtask itself
+    let other = make_task("grdoth01", "grdlist7", "Other");
+    db.tasks().create(&other).await.unwrap();
+    parent.parent_id = Some("grdoth01".to_string());
+    let err = db.tasks().update(&parent).await.unwrap_err();
+    assert!(err.to_string().contains("has subtasks"), "{err}");
+
+    // Nothing was changed
+    let stored = db.tasks().get("grdpar06").await.unwrap();
+    assert_eq!(stored.parent_id, None);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn reparent_tasks_moves_all_or_nothing() {
+    let db = setup_db().await;
+    db.task_lists()
+        .create(&make_task_list("rprlist1", "Reparent List"))
+        .await
+        .unwrap();
+    db.task_lists()
+        .create(&make_task_list("rprlist2", "Other List"))
+        .await
+        .unwrap();
+
+    for (id, list_id) in [
+        ("rprpar01", "rprlist1"),
+        ("rprtsk01", "rprlist1"),
+        ("rprtsk02", "rprlist1"),
+        ("rprothr1", "rprlist2"),
+    ] {
+        db.tasks()
+            .create(&make_task(id, list_id, id))
+            .await
+            .unwrap();
+    }
+
+    let ids = vec!["rprtsk02".to_string(), "rprtsk01".to_string()];
+    let moved = db
+        .tasks()
+        .reparent_tasks(&ids, Some("rprpar01"))
+        .await
+        .unwrap();
+    let moved_ids: Vec<_> = moved.iter().map(|t| t.id.as_str()).collect();
+    assert_eq!(moved_ids, vec!["rprtsk02", "rprtsk01"]);
+    assert!(
+        moved
+            .iter()
+            .all(|t| t.parent_id.as_deref() == Some("rprpar01"))
+    );
+
+    // A task from another list rejects the whole batch
+    let err = db
+        .tasks()
+        .reparent_tasks(
+            &["rprothr1".to_string(), "rprtsk01".to_string()],
+            Some("rprpar01"),
+        )
+        .await
+        .unwrap_err();
+    assert!(matches!(err, DbError::Validation { .. }), "{err}");
+    let other = db.tasks().get("rprothr1").await.unwrap();
+    assert_eq!(other.parent_id, None);
+
+    // Moving the parent under its own subtask is a cycle
+    let err = db
+        .tasks()
+        .reparent_tasks(&["rprpar01".to_string()], Some("rprtsk01"))
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("own ancestor"), "{err}");
+
+    // A null parent moves tasks back to the top level
+    let moved = db.tasks().reparent_tasks(&ids, None).await.unwrap();
+    assert!(moved.iter().all(|t| t.parent_id.is_none()));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn create_many_inserts_all_in_order() {
+    let db = setup_db().await;
+    db.task_lists()
+        .create(&make_task_list("list0001", "Batch"))
+        .await
+        .unwrap();
+
+    let tasks = vec![
+        make_task("", "list0001", "First"),
+        make_task("", "list0001", "Second"),
+        make_task("", "list0001", "Third"),
+    ];
+    let created = db.tasks().create_many(&tasks).await.unwrap();
+
+    let titles: Vec<&str> = created.iter().map(|t| t.title.as_str()).collect();
+    assert_eq!(titles, vec!["First", "Second", "Third"]);
+    assert!(created.iter().all(|t| t.id.len() == 8));
+
+    // Each task gets its initial transition
+    let transitions = db
+        .tasks()
+        .get_transitions(&created[0].id, None, None)
+        .await
+        .unwrap();
+    assert_eq!(transitions.items.len(), 1);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn create_many_rolls_back_on_failure() {
+    let db = setup_db().await;
+    db.task_lists()
+        .create(&make_task_list("list0001", "Batch"))
+        .await
+        .unwrap();
+
+    let tasks = vec![
+        make_task("task0001", "list0001", "Fine"),
+        make_task("task0002", "list0001", "Also fine"),
+        make_task("task0003", "list0001", "   "),
+    ];
+    let err = db.tasks().create_many(&tasks).await.unwrap_err();
+    match err {
+        DbError::BatchItem { index, source } => {
+            assert_eq!(index, 2);
+            assert!(matches!(*source, DbError::Validation { .. }));
+        }
+        other => panic!("Expected BatchItem, got {:?}", other),
+    }
+
+    // Nothing from the batch was kept
+    assert!(matches!(
+        db.tasks().get("task0001").await,
+        Err(DbError::NotFound { .. })
+    ));
+    let query = TaskQuery {
+        list_id: Some("list0001".to_string()),
+        ..Default::default()
+    };
+    assert_eq!(db.tasks().list(Some(&query)).await.unwrap().total, 0);
+}
@@ -4,12 +4,14 @@ use std::str::FromStr;
 
 use sqlx::{Row, SqlitePool};
 
-use crate::db::utils::{current_timestamp, generate_entity_id};
+use crate::db::utils::{IdLength, current_timestamp, generate_entity_id_with_length};
 use crate::db::{DbError, DbResult, TaskStatus, TransitionLog};
 
 /// SQLx-backed transition log repository.
 pub struct SqliteTransitionLogRepository<'a> {
     pub(crate) pool: &'a SqlitePool,
+    /// Length of generated IDs
+    pub(crate) id_length: IdLength,
 }
 
 impl<'a> SqliteTransitionLogRepository<'a> {
@@ -17,7 +19,7 @@ impl<'a> SqliteTransitionLogRepository<'a> {
     pub async fn insert(&self, log: &TransitionLog) -> DbResult<TransitionLog> {
         // Use provided ID if not empty, otherwise generate one
         let id = if log.id.is_empty() {
-            generate_entity_id()
+            generate_entity_id_with_length(self.id_length)
         } else {
             log.id.clone()
         };
@@ -0,0 +1,49 @@
+//! Bodies of entity GETs kept with their ETags.
+//!
+//! Re-fetching an entity sends the stored tag in `If-None-Match`; a 304
+//! answer means the stored body is still current, so polling views skip
+//! both the transfer and the server-side serialization.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+/// Entries kept before the cache is emptied and starts over.
+const MAX_ENTRIES: usize = 256;
+
+/// A response body and the ETag it was served with.
+struct Entry {
+    etag: String,
+    body: String,
+}
+
+thread_local! {
+    static ENTRIES: RefCell<HashMap<String, Entry>> = RefCell::new(HashMap::new());
+}
+
+/// ETag of the cached response for `url`, if any.
+pub fn etag(url: &str) -> Option<String> {
+    ENTRIES.with(|entries| entries.borrow().get(url).map(|entry| entry.etag.clone()))
+}
+
+/// Cached response body for `url`, if any.
+pub fn body(url: &str) -> Option<String> {
+    ENTRIES.with(|entries| entries.borrow().get(url).map(|entry| entry.body.clone()))
+}
+
+/// Remember the body served for `url` under `etag`.
+pub fn store(url: &str, etag: String, body: String) {
+    ENTRIES.with(|entries| {
+        let mut entries = entries.borrow_mut();
+        if entries.len() >= MAX_ENTRIES && !entries.contains_key(url) {
+            entries.clear();
+        }
+        entries.insert(url.to_string(), Entry { etag, body });
+    });
+}
+
+/// Drop the cached response for `url`.
+pub fn forget(url: &str) {
+    ENTRIES.with(|entries| {
+        entries.borrow_mut().remove(url);
+    });
+}
@@ -6,16 +6,60 @@ use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 
 use std::marker::PhantomData;
+use std::sync::OnceLock;
+use wasm_bindgen::prelude::*;
 
-use crate::models::{ApiError, Note, Paginated, Project, Repo, Skill, Task, TaskList, TaskStats};
+mod etag_cache;
+mod scope;
+
+pub use scope::RequestScope;
+use scope::scoped;
+
+use crate::models::{
+    ApiError, Note, NoteType, Paginated, Project, Repo, SearchResults, Skill, Task, TaskList,
+    TaskStats,
+};
 
 // Development: Trunk proxy strips /dev prefix, forwards /api/v1/* to backend
 #[cfg(debug_assertions)]
-const API_BASE: &str = "/dev/api/v1";
+const DEFAULT_API_BASE: &str = "/dev/api/v1";
 
 // Production: Direct calls to backend at /api/v1/*
 #[cfg(not(debug_assertions))]
-const API_BASE: &str = "/api/v1";
+const DEFAULT_API_BASE: &str = "/api/v1";
+
+#[wasm_bindgen(inline_js = "export function runtime_api_base() { \
+    const config = window.__C5T_CONFIG__; \
+    return config && typeof config.apiBase === 'string' ? config.apiBase : undefined; \
+}")]
+extern "C" {
+    fn runtime_api_base() -> Option<String>;
+}
+
+/// Pick the API base: runtime config, then build-time value, then the default.
+///
+/// Blank values are skipped and a trailing slash is dropped, so paths can
+/// always be appended as `{base}/projects`.
+fn resolve_api_base(runtime: Option<String>, build: Option<&str>) -> String {
+    [runtime.as_deref(), build]
+        .into_iter()
+        .flatten()
+        .map(|base| base.trim().trim_end_matches('/'))
+        .find(|base| !base.is_empty())
+        .unwrap_or(DEFAULT_API_BASE)
+        .to_string()
+}
+
+/// Base URL of the REST API, e.g. `/api/v1` or `http://localhost:3737/api/v1`.
+///
+/// Set `window.__C5T_CONFIG__ = { apiBase: "..." }` before the app loads, or
+/// build with `C5T_API_BASE`, to target an API mounted elsewhere. A
+/// cross-origin base also needs the server to allow this origin
+/// (`--allowed-origin`).
+pub fn api_base() -> &'static str {
+    static API_BASE: OnceLock<String> = OnceLock::new();
+    API_BASE.get_or_init(|| resolve_api_base(runtime_api_base(), option_env!("C5T_API_BASE")))
+}
 
 /// Trait for types that have a list endpoint
 pub trait ListEndpoint: DeserializeOwned {
@@ -106,8 +150,17 @@ impl<T: ListEndpoint> QueryBuilder<T> {
         self
     }
 
+    /// Filter by tags (sent comma-separated, omitted when empty)
+    pub fn tags(self, tags: &[String]) -> Self {
+        if tags.is_empty() {
+            self
+        } else {
+            self.param("tags", tags.join(","))
+        }
+    }
+
     pub async fn fetch(self) -> Result<Paginated<T>> {
-        let mut url = format!("{}/{}", API_BASE, T::endpoint());
+        let mut url = format!("{}/{}", api_base(), T::endpoint());
         let mut query_params = vec![];
 
         // Add custom params first
@@ -151,6 +204,16 @@ impl<T: ListEndpoint> QueryBuilder<T> {
     }
 }
 
+impl QueryBuilder<Note> {
+    /// Filter by note type (omitted means both notes and subnotes)
+    pub fn note_type(self, note_type: Option<NoteType>) -> Self {
+        match note_type {
+            Some(t) => self.param("type", t.as_str()),
+            None => self,
+        }
+    }
+}
+
 impl<T: ListEndpoint> Default for QueryBuilder<T> {
     fn default() -> Self {
         Self::new()
@@ -181,11 +244,70 @@ type Result<T> = std::result::Result<T, ApiClientError>;
 async fn handle_response<T: DeserializeOwned>(
     request: gloo_net::http::RequestBuilder,
 ) -> Result<T> {
-    let response = request
+    let response = scoped(request)
+        .send()
+        .await
+        .map_err(|e| ApiClientError::Network(e.to_string()))?;
+
+    parse_response(response).await
+}
+
+/// GET a single entity, revalidating a cached copy with its ETag
+///
+/// A 304 Not Modified means the cached body is unchanged and is reused; a
+/// fresh body is cached when the server tags it with an ETag.
+async fn get_cached<T: DeserializeOwned>(url: &str) -> Result<T> {
+    let request = match etag_cache::etag(url) {
+        Some(etag) => Request::get(url).header("If-None-Match", &etag),
+        None => Request::get(url),
+    };
+    let response = scoped(request)
+        .send()
+        .await
+        .map_err(|e| ApiClientError::Network(e.to_string()))?;
+
+    let body = match response.status() {
+        304 => etag_cache::body(url).ok_or_else(|| {
+            ApiClientError::Deserialization("304 Not Modified without a cached body".to_string())
+        })?,
+        200..300 => {
+            let etag = response.headers().get("etag");
+            let body = response
+                .text()
+                .await
+                .map_err(|e| ApiClientError::Deserialization(e.to_string()))?;
+            match etag {
+                Some(etag) => etag_cache::store(url, etag, body.clone()),
+                None => etag_cache::forget(url),
+            }
+            body
+        }
+        _ => {
+            etag_cache::forget(url);
+            return parse_response(response).await;
+        }
+    };
+
+    serde_json::from_str(&body).map_err(|e| ApiClientError::Deserialization(e.to_string()))
+}
+
+/// Helper function to send a request with a JSON body and handle the response
+async fn send_json<T: DeserializeOwned, B: Serialize>(
+    request: gloo_net::http::RequestBuilder,
+    body: &B,
+) -> Result<T> {
+    let response = scoped(request)
+        .json(body)
+        .map_err(|e| ApiClientError::Deserialization(e.to_string()))?
         .send()
         .await
         .map_err(|e| ApiClientError::Network(e.to_string()))?;
 
+    parse_response(response).await
+}
+
+/// Deserialize a successful response body or the API error payload
+async fn parse_response<T: DeserializeOwned>(response: gloo_net::http::Response) -> Result<T> {
     let status = response.status();
 
     if (200..300).contains(&status) {
@@ -202,6 +324,22 @@ async fn handle_response<T: DeserializeOwned>(
     }
 }
 
+/// Search notes, tasks and projects in one request.
+///
+/// `limit` caps the number of results per group (backend default 5).
+pub async fn search(query: &str, limit: Option<usize>) -> Result<SearchResults> {
+    let encoded = query
+        .replace(' ', "+")
+        .replace('&', "%26")
+        .replace('=', "%3D")
+        .replace('#', "%23");
+    let mut url = format!("{}/search?q={}", api_base(), encoded);
+    if let Some(lim) = limit {
+        url = format!("{}&limit={}", url, lim);
+    }
+    handle_response(Request::get(&url)).await
+}
+
 /// Graph API
 pub mod graph {
     use super::*;
@@ -220,7 +358,7 @@ pub mod graph {
         depth: Option<u32>,
         visible_ids: Option<&str>,
     ) -> Result<Option<String>> {
-        let mut url = format!("{}/repos/{}/graph", API_BASE, repo_id);
+        let mut url = format!("{}/repos/{}/graph", api_base(), repo_id);
         let mut query_params = vec![];
 
         // Add optional query parameters
@@ -238,7 +376,7 @@ pub mod graph {
             url = format!("{}?{}", url, query_params.join("&"));
         }
 
-        let response = Request::get(&url)
+        let response = scoped(Request::get(&url))
             .send()
             .await
             .map_err(|e| ApiClientError::Network(e.to_string()))?;
@@ -268,13 +406,44 @@ pub mod projects {
     use super::*;
 
     pub async fn get(id: &str) -> Result<Project> {
-        let url = format!("{}/projects/{}", API_BASE, id);
-        handle_response(Request::get(&url)).await
+        let url = format!("{}/projects/{}", api_base(), id);
+        get_cached(&url).await
+    }
+
+    /// Request body for creating a project
+    #[derive(Debug, Clone, Default, Serialize)]
+    pub struct CreateProject {
+        pub title: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub description: Option<String>,
+        pub tags: Vec<String>,
+        pub external_refs: Vec<String>,
+        /// Repos to link in the same transaction
+        pub repo_ids: Vec<String>,
+    }
+
+    /// Request body for replacing a project's fields (PUT)
+    #[derive(Debug, Clone, Default, Serialize)]
+    pub struct UpdateProject {
+        pub title: String,
+        pub description: Option<String>,
+        pub tags: Vec<String>,
+        pub external_refs: Vec<String>,
+    }
+
+    pub async fn create(project: &CreateProject) -> Result<Project> {
+        let url = format!("{}/projects", api_base());
+        send_json(Request::post(&url), project).await
+    }
+
+    pub async fn update(id: &str, project: &UpdateProject) -> Result<Project> {
+        let url = format!("{}/projects/{}", api_base(), id);
+        send_json(Request::put(&url), project).await
     }
 
     pub async fn delete(id: &str) -> Result<()> {
-        let url = format!("{}/projects/{}", API_BASE, id);
-        let response = Request::delete(&url)
+        let url = format!("{}/projects/{}", api_base(), id);
+        let response = scoped(Request::delete(&url))
             .send()
             .await
             .map_err(|e| ApiClientError::Network(e.to_string()))?;
@@ -296,13 +465,42 @@ pub mod repos {
     use super::*;
 
     pub async fn get(id: &str) -> Result<Repo> {
-        let url = format!("{}/repos/{}", API_BASE, id);
-        handle_response(Request::get(&url)).await
+        let url = format!("{}/repos/{}", api_base(), id);
+        get_cached(&url).await
+    }
+
+    /// Request body for creating a repo
+    #[derive(Debug, Clone, Default, Serialize)]
+    pub struct CreateRepo {
+        pub remote: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub path: Option<String>,
+        pub tags: Vec<String>,
+        pub project_ids: Vec<String>,
+    }
+
+    /// Request body for replacing a repo's fields (PUT)
+    #[derive(Debug, Clone, Default, Serialize)]
+    pub struct UpdateRepo {
+        pub remote: String,
+        pub path: Option<String>,
+        pub tags: Vec<String>,
+        pub project_ids: Vec<String>,
+    }
+
+    pub async fn create(repo: &CreateRepo) -> Result<Repo> {
+        let url = format!("{}/repos", api_base());
+        send_json(Request::post(&url), repo).await
+    }
+
+    pub async fn update(id: &str, repo: &UpdateRepo) -> Result<Repo> {
+        let url = format!("{}/repos/{}", api_base(), id);
+        send_json(Request::put(&url), repo).await
     }
 
     pub async fn delete(id: &str) -> Result<()> {
-        let url = format!("{}/repos/{}", API_BASE, id);
-        let response = Request::delete(&url)
+        let url = format!("{}/repos/{}", api_base(), id);
+        let response = scoped(Request::delete(&url))
             .send()
             .await
             .map_err(|e| ApiClientError::Network(e.to_string()))?;
@@ -324,14 +522,55 @@ pub mod task_lists {
     use super::*;
 
     pub async fn get(id: &str) -> Result<TaskList> {
-        let url = format!("{}/task-lists/{}", API_BASE, id);
-        handle_response(Request::get(&url)).await
+        let url = format!("{}/task-lists/{}", api_base(), id);
+        get_cached(&url).await
     }
 
     pub async fn get_stats(id: &str) -> Result<TaskStats> {
-        let url = format!("{}/task-lists/{}/stats", API_BASE, id);
+        let url = format!("{}/task-lists/{}/stats", api_base(), id);
         handle_response(Request::get(&url)).await
     }
+
+    /// Request body for creating a task list
+    #[derive(Debug, Clone, Default, Serialize)]
+    pub struct CreateTaskList {
+        pub title: String,
+        pub project_id: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub description: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub notes: Option<String>,
+        pub tags: Vec<String>,
+        pub external_refs: Vec<String>,
+        pub repo_ids: Vec<String>,
+    }
+
+    /// Request body for replacing a task list's fields (PUT)
+    #[derive(Debug, Clone, Default, Serialize)]
+    pub struct UpdateTaskList {
+        pub title: String,
+        pub description: Option<String>,
+        pub notes: Option<String>,
+        pub tags: Vec<String>,
+        pub external_refs: Vec<String>,
+        /// active or archived; the current status is kept when omitted
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub status: Option<String>,
+        pub repo_ids: Vec<String>,
+        /// Move the list to another project; kept when omitted
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub project_id: Option<String>,
+    }
+
+    pub async fn create(task_list: &CreateTaskList) -> Result<TaskList> {
+        let url = format!("{}/task-lists", api_base());
+        send_json(Request::post(&url), task_list).await
+    }
+
+    pub async fn update(id: &str, task_list: &UpdateTaskList) -> Result<TaskList> {
+        let url = format!("{}/task-lists/{}", api_base(), id);
+        send_json(Request::put(&url), task_list).await
+    }
 }
 
 /// Tasks API
@@ -343,13 +582,14 @@ pub mod tasks {
         list_id: &str,
         limit: Option<usize>,
         offset: Option<usize>,
+        cursor: Option<&str>,
         status: Option<&str>,
         sort: Option<&str>,
         order: Option<&str>,
         parent_id: Option<&str>,
         task_type: Option<&str>,
     ) -> Result<Paginated<Task>> {
-        let mut url = format!("{}/task-lists/{}/tasks", API_BASE, list_id);
+        let mut url = format!("{}/task-lists/{}/tasks", api_base(), list_id);
         let mut query_params = vec![];
 
         if let Some(lim) = limit {
@@ -358,6 +598,9 @@ pub mod tasks {
         if let Some(off) = offset {
             query_params.push(format!("offset={}", off));
         }
+        if let Some(c) = cursor {
+            query_params.push(format!("cursor={}", c));
+        }
         if let Some(s) = status {
             query_params.push(format!("status={}", s));
         }
@@ -382,9 +625,58 @@ pub mod tasks {
     }
 
     pub async fn get(id: &str) -> Result<Task> {
-        let url = format!("{}/tasks/{}", API_BASE, id);
+        let url = format!("{}/tasks/{}", api_base(), id);
         handle_response(Request::get(&url)).await
     }
+
+    /// Request body for creating a task
+    #[derive(Debug, Clone, Default, Serialize)]
+    pub struct CreateTask {
+        pub title: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub description: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub parent_id: Option<String>,
+        /// Initial status; the server's default applies when omitted
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub status: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub priority: Option<i32>,
+        pub tags: Vec<String>,
+    }
+
+    pub async fn create(list_id: &str, task: &CreateTask) -> Result<Task> {
+        let url = format!("{}/task-lists/{}/tasks", api_base(), list_id);
+        send_json(Request::post(&url), task).await
+    }
+
+    /// Request body for replacing a task's fields (PUT)
+    #[derive(Debug, Clone, Default, Serialize)]
+    pub struct UpdateTask {
+        pub title: String,
+        pub description: Option<String>,
+        /// The current status is kept when omitted
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub status: Option<String>,
+        pub priority: Option<i32>,
+        pub tags: Vec<String>,
+        pub external_refs: Vec<String>,
+    }
+
+    pub async fn update(id: &str, task: &UpdateTask) -> Result<Task> {
+        let url = format!("{}/tasks/{}", api_base(), id);
+        send_json(Request::put(&url), task).await
+    }
+
+    /// Move a task to another status (PATCH with only the status field)
+    pub async fn set_status(id: &str, status: &str) -> Result<Task> {
+        let url = format!("{}/tasks/{}", api_base(), id);
+        send_json(
+            Request::patch(&url),
+            &serde_json::json!({ "status": status }),
+        )
+        .await
+    }
 }
 
 /// Notes API
@@ -392,13 +684,49 @@ pub mod notes {
     use super::*;
 
     pub async fn get(id: &str) -> Result<Note> {
-        let url = format!("{}/notes/{}", API_BASE, id);
-        handle_response(Request::get(&url)).await
+        let url = format!("{}/notes/{}", api_base(), id);
+        get_cached(&url).await
+    }
+
+    /// Request body for creating a note
+    #[derive(Debug, Clone, Default, Serialize)]
+    pub struct CreateNote {
+        pub title: String,
+        pub content: String,
+        pub tags: Vec<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub parent_id: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub idx: Option<i32>,
+        pub repo_ids: Vec<String>,
+        pub project_ids: Vec<String>,
+    }
+
+    /// Request body for replacing a note's fields (PUT)
+    #[derive(Debug, Clone, Default, Serialize)]
+    pub struct UpdateNote {
+        pub title: String,
+        pub content: String,
+        pub tags: Vec<String>,
+        pub parent_id: Option<String>,
+        pub idx: Option<i32>,
+        pub repo_ids: Vec<String>,
+        pub project_ids: Vec<String>,
+    }
+
+    pub async fn create(note: &CreateNote) -> Result<Note> {
+        let url = format!("{}/notes", api_base());
+        send_json(Request::post(&url), note).await
+    }
+
+    pub async fn update(id: &str, note: &UpdateNote) -> Result<Note> {
+        let url = format!("{}/notes/{}", api_base(), id);
+        send_json(Request::put(&url), note).await
     }
 
     pub async fn delete(id: &str) -> Result<()> {
-        let url = format!("{}/notes/{}", API_BASE, id);
-        let response = Request::delete(&url)
+        let url = format!("{}/notes/{}", api_base(), id);
+        let response = scoped(Request::delete(&url))
             .send()
             .await
             .map_err(|e| ApiClientError::Network(e.to_string()))?;
@@ -420,13 +748,13 @@ pub mod skills {
     use super::*;
 
     pub async fn get(id: &str) -> Result<Skill> {
-        let url = format!("{}/skills/{}", API_BASE, id);
+        let url = format!("{}/skills/{}", api_base(), id);
         handle_response(Request::get(&url)).await
     }
 
     pub async fn delete(id: &str) -> Result<()> {
-        let url = format!("{}/skills/{}", API_BASE, id);
-        let response = Request::delete(&url)
+        let url = format!("{}/skills/{}", api_base(), id);
+        let response = scoped(Request::delete(&url))
             .send()
             .await
             .map_err(|e| ApiClientError::Network(e.to_string()))?;
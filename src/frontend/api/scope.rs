@@ -0,0 +1,137 @@
+//! Cancellation of in-flight requests when a component unmounts.
+//!
+//! A [`RequestScope`] owns an `AbortController`. Requests sent while a future
+//! runs through [`RequestScope::run`] carry its abort signal, so cleaning up
+//! the component aborts the fetch, and `run` returns `None` instead of a
+//! stale result that would update signals of a component that is gone.
+
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use gloo_net::http::RequestBuilder;
+use leptos::prelude::on_cleanup;
+use web_sys::{AbortController, AbortSignal};
+
+thread_local! {
+    /// Controllers of live scopes; a scope is cancelled once it is removed.
+    static CONTROLLERS: RefCell<HashMap<u64, AbortController>> = RefCell::new(HashMap::new());
+    static NEXT_ID: Cell<u64> = const { Cell::new(0) };
+    /// Abort signal of the scope whose future is currently being polled.
+    static CURRENT_SIGNAL: RefCell<Option<AbortSignal>> = const { RefCell::new(None) };
+}
+
+/// Handle to a set of requests cancelled together.
+///
+/// The handle is a plain id (the controller lives in a thread-local map), so
+/// it is `Copy` and can move into `Send` closures such as `on_cleanup`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RequestScope(u64);
+
+impl RequestScope {
+    /// Create a scope that is cancelled when the current reactive owner is
+    /// cleaned up, i.e. when the component calling this unmounts.
+    pub fn new() -> Self {
+        let scope = Self::detached();
+        on_cleanup(move || scope.cancel());
+        scope
+    }
+
+    /// Create a scope that is only cancelled by [`RequestScope::cancel`].
+    pub fn detached() -> Self {
+        let id = NEXT_ID.with(|next| {
+            let id = next.get();
+            next.set(id.wrapping_add(1));
+            id
+        });
+        match AbortController::new() {
+            Ok(controller) => CONTROLLERS.with(|c| {
+                c.borrow_mut().insert(id, controller);
+            }),
+            // Without a controller requests can't be aborted, but results
+            // are still dropped after cancel() because the id is never live
+            Err(_) => web_sys::console::warn_1(&"AbortController unavailable".into()),
+        }
+        Self(id)
+    }
+
+    /// Abort this scope's pending requests; later results are discarded.
+    pub fn cancel(self) {
+        if let Some(controller) = CONTROLLERS.with(|c| c.borrow_mut().remove(&self.0)) {
+            controller.abort();
+        }
+    }
+
+    /// Whether [`RequestScope::cancel`] has run (or the scope never got a controller).
+    pub fn is_cancelled(self) -> bool {
+        CONTROLLERS.with(|c| !c.borrow().contains_key(&self.0))
+    }
+
+    fn signal(self) -> Option<AbortSignal> {
+        CONTROLLERS.with(|c| c.borrow().get(&self.0).map(|c| c.signal()))
+    }
+
+    /// Run `future` with this scope's abort signal attached to the requests it sends.
+    ///
+    /// Returns `None` if the scope is cancelled before or while the future
+    /// runs, so callers only set signals on `Some`:
+    ///
+    /// ```ignore
+    /// let scope = RequestScope::new();
+    /// spawn_local(async move {
+    ///     if let Some(Ok(task)) = scope.run(tasks::get(&id)).await {
+    ///         set_task.set(Some(task));
+    ///     }
+    /// });
+    /// ```
+    pub async fn run<T>(self, future: impl Future<Output = T>) -> Option<T> {
+        if self.is_cancelled() {
+            return None;
+        }
+        let output = Scoped {
+            scope: self,
+            inner: Box::pin(future),
+        }
+        .await;
+        if self.is_cancelled() {
+            // The owner is gone; its signals must not be touched
+            #[cfg(debug_assertions)]
+            web_sys::console::debug_1(&"Dropped response of a cancelled request scope".into());
+            return None;
+        }
+        Some(output)
+    }
+}
+
+impl Default for RequestScope {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Attach the abort signal of the scope being polled, if any, to a request.
+pub(super) fn scoped(request: RequestBuilder) -> RequestBuilder {
+    CURRENT_SIGNAL.with(|current| match current.borrow().as_ref() {
+        Some(signal) => request.abort_signal(Some(signal)),
+        None => request,
+    })
+}
+
+/// Future that makes its scope's signal current while the inner future is polled.
+struct Scoped<F: Future> {
+    scope: RequestScope,
+    inner: Pin<Box<F>>,
+}
+
+impl<F: Future> Future for Scoped<F> {
+    type Output = F::Output;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let previous = CURRENT_SIGNAL.with(|current| current.replace(self.scope.signal()));
+        let poll = self.inner.as_mut().poll(cx);
+        CURRENT_SIGNAL.with(|current| current.replace(previous));
+        poll
+    }
+}
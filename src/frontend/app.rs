@@ -8,7 +8,8 @@ use leptos_use::core::ConnectionReadyState;
 use thaw::*;
 
 use crate::breadcrumb_state::BreadcrumbPageState;
-use crate::components::ThemeSwitcher;
+use crate::components::{CountBadge, GlobalSearch, NavCounts, ThemeSwitcher};
+use crate::hooks::use_hotkeys;
 use crate::pages::{
     NoteDetail, Notes, ProjectDetail, Projects, RepoDetail, Repos, Skills, TaskListDetail,
 };
@@ -69,6 +70,8 @@ fn NavAndContent(catppuccin_theme: RwSignal<CatppuccinTheme>) -> impl IntoView {
     // WebSocket connection status (from context)
     let ws_state = use_websocket_connection();
     let location = use_location();
+    let counts = NavCounts::new();
+    use_hotkeys();
 
     // Determine active tab based on current path
     let is_active = move |path: &str| {
@@ -108,6 +111,7 @@ fn NavAndContent(catppuccin_theme: RwSignal<CatppuccinTheme>) -> impl IntoView {
                         </span>
                     </a>
                     <div class="flex gap-4 items-center">
+                        <GlobalSearch/>
                         <div class="flex gap-2 items-center">
                             <a href="/"
                                 class="px-4 py-2 rounded-lg font-medium transition-colors"
@@ -117,6 +121,7 @@ fn NavAndContent(catppuccin_theme: RwSignal<CatppuccinTheme>) -> impl IntoView {
                                 class:hover:bg-ctp-surface1=move || !is_active("/")
                                 class:hover:text-ctp-text=move || !is_active("/")>
                                 "Projects"
+                                <CountBadge count=counts.projects/>
                             </a>
                             <a href="/notes"
                                 class="px-4 py-2 rounded-lg font-medium transition-colors"
@@ -126,6 +131,7 @@ fn NavAndContent(catppuccin_theme: RwSignal<CatppuccinTheme>) -> impl IntoView {
                                 class:hover:bg-ctp-surface1=move || !is_active("/notes")
                                 class:hover:text-ctp-text=move || !is_active("/notes")>
                                 "Notes"
+                                <CountBadge count=counts.notes/>
                             </a>
                             <a href="/skills"
                                 class="px-4 py-2 rounded-lg font-medium transition-colors"
@@ -135,6 +141,7 @@ fn NavAndContent(catppuccin_theme: RwSignal<CatppuccinTheme>) -> impl IntoView {
                                 class:hover:bg-ctp-surface1=move || !is_active("/skills")
                                 class:hover:text-ctp-text=move || !is_active("/skills")>
                                 "Skills"
+                                <CountBadge count=counts.skills/>
                             </a>
                             <a href="/repos"
                                 class="px-4 py-2 rounded-lg font-medium transition-colors"
@@ -144,6 +151,7 @@ fn NavAndContent(catppuccin_theme: RwSignal<CatppuccinTheme>) -> impl IntoView {
                                 class:hover:bg-ctp-surface1=move || !is_active("/repos")
                                 class:hover:text-ctp-text=move || !is_active("/repos")>
                                 "Repos"
+                                <CountBadge count=counts.repos/>
                             </a>
                         </div>
                         <ThemeSwitcher theme=catppuccin_theme/>
@@ -0,0 +1,248 @@
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+use leptos_router::hooks::use_navigate;
+use wasm_bindgen::JsCast;
+use wasm_bindgen::prelude::*;
+
+use crate::api;
+use crate::hooks::GLOBAL_SEARCH_ID;
+use crate::models::SearchResults;
+
+/// Number of results requested per entity group
+const RESULTS_PER_GROUP: usize = 5;
+
+/// Where a search hit navigates to when selected
+#[derive(Debug, Clone, PartialEq)]
+enum SearchTarget {
+    /// Direct route
+    Path(String),
+    /// Task hit - the route needs the owning task list's project, resolved on selection
+    TaskList(String),
+}
+
+/// A single row in the results dropdown
+#[derive(Debug, Clone, PartialEq)]
+struct SearchHit {
+    group: &'static str,
+    id: String,
+    title: String,
+    target: SearchTarget,
+}
+
+fn flatten_results(results: SearchResults) -> Vec<SearchHit> {
+    let projects = results.projects.into_iter().map(|p| SearchHit {
+        group: "Projects",
+        target: SearchTarget::Path(format!("/projects/{}", p.id)),
+        id: p.id,
+        title: p.title,
+    });
+    let notes = results.notes.into_iter().map(|n| SearchHit {
+        group: "Notes",
+        target: SearchTarget::Path(format!("/notes/{}", n.id)),
+        id: n.id,
+        title: n.title,
+    });
+    let tasks = results.tasks.into_iter().map(|t| SearchHit {
+        group: "Tasks",
+        target: SearchTarget::TaskList(t.list_id),
+        id: t.id,
+        title: t.title,
+    });
+    projects.chain(notes).chain(tasks).collect()
+}
+
+/// Header search box querying notes, tasks and projects at once.
+///
+/// Input is debounced, results are shown in a grouped dropdown that supports
+/// arrow key navigation, Enter to open and Escape to close.
+#[component]
+pub fn GlobalSearch(
+    /// Debounce delay in milliseconds
+    #[prop(optional, default = 300)]
+    debounce_ms: u32,
+) -> impl IntoView {
+    let navigate = use_navigate();
+
+    let input = RwSignal::new(String::new());
+    let hits = RwSignal::new(Vec::<SearchHit>::new());
+    let open = RwSignal::new(false);
+    let active = RwSignal::new(None::<usize>);
+    let debounce_timeout = RwSignal::new(None::<i32>);
+    // Incremented per request so stale responses are dropped
+    let request_seq = RwSignal::new(0u32);
+
+    let run_search = move |value: String| {
+        if value.trim().is_empty() {
+            hits.set(vec![]);
+            open.set(false);
+            return;
+        }
+        let seq = request_seq.get_untracked().wrapping_add(1);
+        request_seq.set(seq);
+        spawn_local(async move {
+            let result = api::search(&value, Some(RESULTS_PER_GROUP)).await;
+            if request_seq.get_untracked() != seq {
+                return;
+            }
+            match result {
+                Ok(results) => {
+                    hits.set(flatten_results(results));
+                    active.set(None);
+                    open.set(true);
+                }
+                Err(e) => {
+                    web_sys::console::log_1(&format!("Search failed: {}", e).into());
+                    hits.set(vec![]);
+                }
+            }
+        });
+    };
+
+    let on_input = move |ev: web_sys::Event| {
+        let value = event_target_value(&ev);
+        input.set(value.clone());
+
+        if let Some(timeout_id) = debounce_timeout.get_untracked() {
+            web_sys::window()
+                .unwrap()
+                .clear_timeout_with_handle(timeout_id);
+        }
+
+        let callback = Closure::once(move || {
+            run_search(value);
+            debounce_timeout.set(None);
+        });
+
+        let timeout_id = web_sys::window()
+            .unwrap()
+            .set_timeout_with_callback_and_timeout_and_arguments_0(
+                callback.as_ref().unchecked_ref(),
+                debounce_ms as i32,
+            )
+            .unwrap();
+
+        debounce_timeout.set(Some(timeout_id));
+        callback.forget();
+    };
+
+    let close = move || {
+        open.set(false);
+        active.set(None);
+    };
+
+    let select = Callback::new(move |hit: SearchHit| {
+        close();
+        input.set(String::new());
+        hits.set(vec![]);
+        let navigate = navigate.clone();
+        match hit.target {
+            SearchTarget::Path(path) => navigate(&path, Default::default()),
+            SearchTarget::TaskList(list_id) => {
+                spawn_local(async move {
+                    match api::task_lists::get(&list_id).await {
+                        Ok(list) => navigate(
+                            &format!("/projects/{}/task-lists/{}", list.project_id, list.id),
+                            Default::default(),
+                        ),
+                        Err(e) => web_sys::console::log_1(
+                            &format!("Failed to resolve task list: {}", e).into(),
+                        ),
+                    }
+                });
+            }
+        }
+    });
+
+    let on_keydown = move |ev: leptos::ev::KeyboardEvent| {
+        let count = hits.with_untracked(|h| h.len());
+        match ev.key().as_str() {
+            "Escape" => close(),
+            "ArrowDown" if count > 0 => {
+                ev.prevent_default();
+                open.set(true);
+                active.update(|a| *a = Some(a.map_or(0, |i| (i + 1) % count)));
+            }
+            "ArrowUp" if count > 0 => {
+                ev.prevent_default();
+                open.set(true);
+                active.update(|a| *a = Some(a.map_or(count - 1, |i| (i + count - 1) % count)));
+            }
+            "Enter" => {
+                if let Some(hit) = active
+                    .get_untracked()
+                    .and_then(|i| hits.with_untracked(|h| h.get(i).cloned()))
+                {
+                    ev.prevent_default();
+                    select.run(hit);
+                }
+            }
+            _ => {}
+        }
+    };
+
+    view! {
+        <div class="relative w-64">
+            <input
+                id=GLOBAL_SEARCH_ID
+                type="search"
+                placeholder="Search everything... (/)"
+                aria-label="Search notes, tasks and projects"
+                prop:value=move || input.get()
+                on:input=on_input
+                on:keydown=on_keydown
+                on:focus=move |_| {
+                    if !hits.with_untracked(|h| h.is_empty()) {
+                        open.set(true);
+                    }
+                }
+                class="w-full rounded-lg border-ctp-surface1 bg-ctp-base px-3 py-1.5 text-sm text-ctp-text placeholder-ctp-subtext0 focus:border-ctp-blue focus:ring-2 focus:ring-ctp-blue focus:outline-none"
+            />
+            <Show when=move || open.get()>
+                // Backdrop closes the dropdown on outside click
+                <div class="fixed inset-0 z-40" on:click=move |_| close()></div>
+                <div class="absolute right-0 mt-2 w-96 max-h-96 overflow-y-auto z-50 bg-ctp-surface0 border border-ctp-surface1 rounded-lg shadow-lg">
+                    {move || {
+                        let items = hits.get();
+                        if items.is_empty() {
+                            return view! {
+                                <p class="px-4 py-3 text-sm text-ctp-subtext0">"No results"</p>
+                            }
+                                .into_any();
+                        }
+                        let mut last_group = "";
+                        items
+                            .into_iter()
+                            .enumerate()
+                            .map(|(idx, hit)| {
+                                let header = (hit.group != last_group).then(|| {
+                                    view! {
+                                        <div class="px-4 pt-3 pb-1 text-xs font-semibold uppercase text-ctp-overlay1">
+                                            {hit.group}
+                                        </div>
+                                    }
+                                });
+                                last_group = hit.group;
+                                let id = hit.id.clone();
+                                let title = hit.title.clone();
+                                view! {
+                                    {header}
+                                    <button
+                                        type="button"
+                                        class="w-full text-left px-4 py-2 flex items-center gap-2 text-sm text-ctp-text hover:bg-ctp-surface1"
+                                        class:bg-ctp-surface1=move || active.get() == Some(idx)
+                                        on:mouseenter=move |_| active.set(Some(idx))
+                                        on:click=move |_| select.run(hit.clone())
+                                    >
+                                        <span class="font-mono text-xs text-ctp-overlay0">{id}</span>
+                                        <span class="truncate">{title}</span>
+                                    </button>
+                                }
+                            })
+                            .collect::<Vec<_>>()
+                            .into_any()
+                    }}
+                </div>
+            </Show>
+        </div>
+    }
+}
@@ -1,17 +1,25 @@
+pub mod global_search;
+pub mod nav_counts;
 pub mod note_components;
 pub mod repo_components;
 pub mod search_input;
 pub mod skill_components;
 pub mod sort_controls;
+pub mod tag_filter;
 pub mod task_components;
 pub mod theme_switcher;
 pub mod ui_components;
 
-pub use note_components::{MarkdownContent, NoteCard, NoteStackSidebar};
+pub use global_search::GlobalSearch;
+pub use nav_counts::{CountBadge, NavCounts};
+pub use note_components::{
+    MarkdownContent, NoteBody, NoteCard, NoteStackSidebar, NoteTypeBadge, NoteTypeFilter,
+};
 pub use repo_components::RepoCard;
 pub use search_input::SearchInput;
 pub use skill_components::{SkillCard, SkillDetailModal};
 pub use sort_controls::SortControls;
+pub use tag_filter::TagFilter;
 pub use task_components::{ExternalRefLink, TaskListCard, TaskListContent, TaskListDetailModal};
 pub use theme_switcher::ThemeSwitcher;
 pub use ui_components::{Breadcrumb, BreadcrumbItem, CopyableId, Pagination};
@@ -0,0 +1,106 @@
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+
+use crate::api::{ListEndpoint, QueryBuilder, RequestScope};
+use crate::models::{Note, NoteType, Project, Repo, Skill, UpdateMessage};
+use crate::websocket::use_websocket_updates;
+
+/// Entity counts shown next to the nav items.
+///
+/// `None` means the count isn't known (still loading or the request failed),
+/// in which case the badge is hidden.
+#[derive(Clone, Copy)]
+pub struct NavCounts {
+    pub projects: RwSignal<Option<usize>>,
+    pub notes: RwSignal<Option<usize>>,
+    pub skills: RwSignal<Option<usize>>,
+    pub repos: RwSignal<Option<usize>>,
+}
+
+impl NavCounts {
+    /// Load the counts and keep them current from WebSocket updates.
+    ///
+    /// Creates and deletes adjust the count right away; notes are refetched
+    /// instead because the update doesn't say whether it was a subnote, which
+    /// the Notes page doesn't list.
+    pub fn new() -> Self {
+        let counts = Self {
+            projects: RwSignal::new(None),
+            notes: RwSignal::new(None),
+            skills: RwSignal::new(None),
+            repos: RwSignal::new(None),
+        };
+        let requests = RequestScope::new();
+
+        load(requests, counts.projects, QueryBuilder::<Project>::new());
+        load(requests, counts.notes, notes_query());
+        load(requests, counts.skills, QueryBuilder::<Skill>::new());
+        load(requests, counts.repos, QueryBuilder::<Repo>::new());
+
+        let ws_updates = use_websocket_updates();
+        Effect::new(move || {
+            let Some(update) = ws_updates.get() else {
+                return;
+            };
+            match update {
+                UpdateMessage::ProjectCreated { .. } => adjust(counts.projects, 1),
+                UpdateMessage::ProjectDeleted { .. } => adjust(counts.projects, -1),
+                UpdateMessage::SkillCreated { .. } => adjust(counts.skills, 1),
+                UpdateMessage::SkillDeleted { .. } => adjust(counts.skills, -1),
+                UpdateMessage::RepoCreated { .. } => adjust(counts.repos, 1),
+                UpdateMessage::RepoDeleted { .. } => adjust(counts.repos, -1),
+                UpdateMessage::NoteCreated { .. }
+                | UpdateMessage::NoteUpdated { .. }
+                | UpdateMessage::NoteDeleted { .. } => load(requests, counts.notes, notes_query()),
+                _ => {}
+            }
+        });
+
+        counts
+    }
+}
+
+impl Default for NavCounts {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Top-level notes, matching what the Notes page lists by default.
+fn notes_query() -> QueryBuilder<Note> {
+    QueryBuilder::<Note>::new().note_type(Some(NoteType::Note))
+}
+
+/// Fetch a single-item page and keep only its total.
+fn load<T: ListEndpoint + 'static>(
+    requests: RequestScope,
+    count: RwSignal<Option<usize>>,
+    query: QueryBuilder<T>,
+) {
+    spawn_local(async move {
+        if let Some(result) = requests.run(query.limit(1).fetch()).await {
+            count.set(result.ok().map(|page| page.total));
+        }
+    });
+}
+
+fn adjust(count: RwSignal<Option<usize>>, delta: isize) {
+    count.update(|c| {
+        if let Some(n) = c {
+            *n = n.saturating_add_signed(delta);
+        }
+    });
+}
+
+#[component]
+pub fn CountBadge(count: RwSignal<Option<usize>>) -> impl IntoView {
+    move || {
+        count.get().map(|n| {
+            view! {
+                <span class="ml-2 px-1.5 py-0.5 text-xs font-mono rounded-full bg-ctp-surface1 text-ctp-subtext0">
+                    {n}
+                </span>
+            }
+        })
+    }
+}
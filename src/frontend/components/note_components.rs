@@ -1,9 +1,10 @@
 use crate::api::QueryBuilder;
 use crate::components::CopyableId;
-use crate::models::{Note, UpdateMessage};
+use crate::markdown::render_markdown;
+use crate::models::{Note, NoteType, UpdateMessage};
+use crate::utils::truncate_chars;
 use crate::websocket::use_websocket_updates;
 use leptos::prelude::*;
-use pulldown_cmark::{Options, Parser, html};
 use std::sync::atomic::{AtomicU16, Ordering};
 
 #[component]
@@ -15,21 +16,10 @@ pub fn NoteCard(
     #[prop(optional)] breadcrumb_name: Option<String>,
 ) -> impl IntoView {
     // Create a preview of the content (first 300 chars for markdown, UTF-8 safe)
-    let preview_content = if note.content.chars().count() > 300 {
-        let truncated: String = note.content.chars().take(300).collect();
-        format!("{}...", truncated)
-    } else {
-        note.content.clone()
-    };
+    let preview_content = truncate_chars(&note.content, 300);
 
     // Parse markdown to HTML for preview
-    let mut options = Options::empty();
-    options.insert(Options::ENABLE_STRIKETHROUGH);
-    options.insert(Options::ENABLE_TABLES);
-
-    let parser = Parser::new_ext(&preview_content, options);
-    let mut html_output = String::new();
-    html::push_html(&mut html_output, parser);
+    let html_output = render_markdown(&preview_content);
 
     // Check if note has subnotes for stacked effect
     let has_subnotes = note.subnote_count.unwrap_or(0) > 0;
@@ -84,6 +74,7 @@ pub fn NoteCard(
                         <CopyableId id=note.id.clone()/>
                     </div>
                     <h3 class="flex-1 min-w-0 break-words text-xl font-semibold text-ctp-text">{note.title.clone()}</h3>
+                    <NoteTypeBadge note_type=note.note_type()/>
                 </div>
 
             <div class="relative flex-grow mb-4">
@@ -124,20 +115,69 @@ pub fn NoteCard(
     }
 }
 
+/// Small badge showing whether a note is a top-level note or a subnote
+#[component]
+pub fn NoteTypeBadge(note_type: NoteType) -> impl IntoView {
+    let class = match note_type {
+        NoteType::Note => "flex-shrink-0 bg-ctp-blue/20 text-ctp-blue text-xs px-2 py-0.5 rounded",
+        NoteType::Subnote => {
+            "flex-shrink-0 bg-ctp-mauve/20 text-ctp-mauve text-xs px-2 py-0.5 rounded"
+        }
+    };
+
+    view! { <span class=class>{note_type.label()}</span> }
+}
+
+/// Toggle group for filtering the notes list by type.
+///
+/// `None` means no filter (both notes and subnotes).
+#[component]
+pub fn NoteTypeFilter(
+    /// Currently selected note type
+    note_type: ReadSignal<Option<NoteType>>,
+    /// Callback when the selection changes
+    on_change: Callback<Option<NoteType>>,
+) -> impl IntoView {
+    let options = [
+        (Some(NoteType::Note), "Notes"),
+        (Some(NoteType::Subnote), "Subnotes"),
+        (None, "All"),
+    ];
+
+    view! {
+        <div class="flex items-center gap-3 text-sm">
+            <span class="text-ctp-subtext0">"Type:"</span>
+            <div class="flex gap-1 bg-ctp-surface0 p-1 rounded-lg border border-ctp-surface1">
+                {options
+                    .into_iter()
+                    .map(|(value, label)| {
+                        view! {
+                            <button
+                                on:click=move |_| on_change.run(value)
+                                class=move || {
+                                    if note_type.get() == value {
+                                        "px-3 py-1.5 rounded bg-ctp-blue text-ctp-base font-medium transition-colors"
+                                    } else {
+                                        "px-3 py-1.5 rounded text-ctp-text hover:bg-ctp-surface1 transition-colors"
+                                    }
+                                }
+                            >
+                                {label}
+                            </button>
+                        }
+                    })
+                    .collect::<Vec<_>>()}
+            </div>
+        </div>
+    }
+}
+
 /// Atomic counter for unique MarkdownContent DOM IDs
 static MD_COUNTER: AtomicU16 = AtomicU16::new(0);
 
 #[component]
 pub fn MarkdownContent(content: String) -> impl IntoView {
-    let mut options = Options::empty();
-    options.insert(Options::ENABLE_STRIKETHROUGH);
-    options.insert(Options::ENABLE_TABLES);
-    options.insert(Options::ENABLE_FOOTNOTES);
-    options.insert(Options::ENABLE_TASKLISTS);
-
-    let parser = Parser::new_ext(&content, options);
-    let mut html_output = String::new();
-    html::push_html(&mut html_output, parser);
+    let html_output = render_markdown(&content);
 
     let id = MD_COUNTER.fetch_add(1, Ordering::Relaxed);
     let sel = format!("#md-{}", id);
@@ -152,6 +192,40 @@ pub fn MarkdownContent(content: String) -> impl IntoView {
         <div id=format!("md-{}", id) inner_html=html_output></div>
     }
 }
+
+/// Note content rendered as markdown, with a toggle to show the raw source
+#[component]
+pub fn NoteBody(content: String) -> impl IntoView {
+    let (show_source, set_show_source) = signal(false);
+
+    view! {
+        <div class="flex justify-end mb-2">
+            <button
+                class="text-xs px-2 py-1 rounded text-ctp-subtext0 hover:text-ctp-text hover:bg-ctp-surface1 transition-colors"
+                on:click=move |_| set_show_source.update(|s| *s = !*s)
+            >
+                {move || if show_source.get() { "View rendered" } else { "View source" }}
+            </button>
+        </div>
+        {move || {
+            if show_source.get() {
+                view! {
+                    <pre class="whitespace-pre-wrap break-words text-sm text-ctp-text bg-ctp-mantle border border-ctp-surface1 rounded p-4">
+                        {content.clone()}
+                    </pre>
+                }
+                    .into_any()
+            } else {
+                view! {
+                    <div class="prose prose-invert max-w-none">
+                        <MarkdownContent content=content.clone()/>
+                    </div>
+                }
+                    .into_any()
+            }
+        }}
+    }
+}
 #[component]
 pub fn NoteStackSidebar(parent_note: Note, on_note_select: Callback<String>) -> impl IntoView {
     use leptos::task::spawn_local;
@@ -174,7 +248,7 @@ pub fn NoteStackSidebar(parent_note: Note, on_note_select: Callback<String>) ->
     // Watch for WebSocket note updates - refetch if any note in this stack changes
     Effect::new(move || {
         if let Some(
-            UpdateMessage::NoteUpdated { note_id: _ }
+            UpdateMessage::NoteUpdated { .. }
             | UpdateMessage::NoteDeleted { note_id: _ }
             | UpdateMessage::NoteCreated { note_id: _ },
         ) = ws_updates.get()
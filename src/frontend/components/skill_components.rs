@@ -1,10 +1,12 @@
 use leptos::prelude::*;
-use pulldown_cmark::{Options, Parser, html};
 use thaw::*;
 
 use crate::api::skills;
 use crate::components::CopyableId;
+use crate::hooks::use_close_on_escape;
+use crate::markdown::render_markdown;
 use crate::models::{Skill, UpdateMessage};
+use crate::utils::truncate_chars;
 use crate::websocket::use_websocket_updates;
 
 #[component]
@@ -13,12 +15,7 @@ pub fn SkillCard(
     #[prop(optional)] on_click: Option<Callback<String>>,
 ) -> impl IntoView {
     // Create a preview of the description (first 200 chars)
-    let preview_content = if skill.description.chars().count() > 200 {
-        let truncated: String = skill.description.chars().take(200).collect();
-        format!("{}...", truncated)
-    } else {
-        skill.description.clone()
-    };
+    let preview_content = truncate_chars(&skill.description, 200);
 
     let skill_id = skill.id.clone();
     let href = if on_click.is_some() {
@@ -91,6 +88,8 @@ pub fn SkillCard(
 pub fn SkillDetailModal(skill_id: ReadSignal<String>, open: RwSignal<bool>) -> impl IntoView {
     use leptos::task::spawn_local;
 
+    use_close_on_escape(open);
+
     let (skill_data, set_skill_data) = signal(None::<Skill>);
     let (loading, set_loading) = signal(true);
     let (error, set_error) = signal(None::<String>);
@@ -295,15 +294,7 @@ pub fn SkillDetailModal(skill_id: ReadSignal<String>, open: RwSignal<bool>) -> i
                                             .join("\n");
 
                                         // Render markdown body
-                                        let mut options = Options::empty();
-                                        options.insert(Options::ENABLE_STRIKETHROUGH);
-                                        options.insert(Options::ENABLE_TABLES);
-                                        options.insert(Options::ENABLE_FOOTNOTES);
-                                        options.insert(Options::ENABLE_TASKLISTS);
-
-                                        let parser = Parser::new_ext(markdown_without_links.trim(), options);
-                                        let mut html_output = String::new();
-                                        html::push_html(&mut html_output, parser);
+                                        let html_output = render_markdown(markdown_without_links.trim());
 
                                         view! {
                                             <div class="mb-6">
@@ -0,0 +1,66 @@
+use leptos::prelude::*;
+
+/// Tag filter control: shows active tags as removable chips plus an input
+/// to add another tag (press Enter to add).
+///
+/// # Props
+/// - `tags`: ReadSignal<Vec<String>> - The active tag filter
+/// - `on_add`: Callback<String> - Called with a tag to add
+/// - `on_remove`: Callback<String> - Called with a tag to remove
+#[component]
+pub fn TagFilter(
+    /// Active tags
+    tags: ReadSignal<Vec<String>>,
+    /// Callback when a tag is added
+    on_add: Callback<String>,
+    /// Callback when a tag is removed
+    on_remove: Callback<String>,
+) -> impl IntoView {
+    let input = RwSignal::new(String::new());
+
+    let on_keydown = move |ev: leptos::ev::KeyboardEvent| {
+        if ev.key() == "Enter" {
+            ev.prevent_default();
+            let value = input.get_untracked();
+            if !value.trim().is_empty() {
+                on_add.run(value);
+                input.set(String::new());
+            }
+        }
+    };
+
+    view! {
+        <div class="flex flex-wrap items-center gap-2 text-sm">
+            <span class="text-ctp-subtext0">"Tags:"</span>
+            {move || {
+                tags.get()
+                    .into_iter()
+                    .map(|tag| {
+                        let tag_for_remove = tag.clone();
+                        view! {
+                            <span class="flex items-center gap-1 bg-ctp-surface1 text-ctp-subtext1 text-xs px-2 py-1 rounded">
+                                {tag}
+                                <button
+                                    type="button"
+                                    class="text-ctp-overlay0 hover:text-ctp-red"
+                                    title="Remove tag filter"
+                                    on:click=move |_| on_remove.run(tag_for_remove.clone())
+                                >
+                                    "×"
+                                </button>
+                            </span>
+                        }
+                    })
+                    .collect::<Vec<_>>()
+            }}
+            <input
+                type="text"
+                placeholder="Filter by tag..."
+                prop:value=move || input.get()
+                on:input=move |ev| input.set(event_target_value(&ev))
+                on:keydown=on_keydown
+                class="w-32 rounded border-ctp-surface1 bg-ctp-surface0 px-2 py-1 text-xs text-ctp-text placeholder-ctp-subtext0 focus:border-ctp-blue focus:outline-none"
+            />
+        </div>
+    }
+}
@@ -3,9 +3,14 @@ use leptos::prelude::*;
 use leptos::task::spawn_local;
 use thaw::*;
 
-use crate::api::{ApiClientError, task_lists, tasks};
+use crate::api::{ApiClientError, RequestScope, task_lists, tasks};
 use crate::components::CopyableId;
+use crate::hooks::use_close_on_escape;
 use crate::models::{Task, TaskList, TaskStats};
+use crate::utils::truncate_chars;
+
+/// Subtasks are listed in full, up to the largest page the server returns
+const SUBTASK_LIMIT: usize = 100;
 
 // Helper functions for badge colors and labels (DRY)
 fn priority_border_color(priority: Option<i32>) -> &'static str {
@@ -67,6 +72,64 @@ fn status_bg_color(status: &str) -> &'static str {
     }
 }
 
+/// A card moved between kanban columns: the source column drops it and
+/// the target column adds it
+#[derive(Clone)]
+struct TaskMove {
+    task: Task,
+    from: &'static str,
+    to: &'static str,
+}
+
+/// Drag-and-drop state shared by the columns of one kanban board
+#[derive(Clone, Copy)]
+struct KanbanBoard {
+    /// Card being dragged and the column it was picked up from
+    dragging: RwSignal<Option<(Task, &'static str)>>,
+    moved: RwSignal<Option<TaskMove>>,
+    /// Failed move notice (auto-dismisses)
+    move_error: RwSignal<Option<String>>,
+    stats_refetch: WriteSignal<u32>,
+}
+
+impl KanbanBoard {
+    /// Move `task` to the `to` column, then PATCH its status. The card
+    /// goes back to its original column if the request fails.
+    fn move_task(self, task: Task, from: &'static str, to: &'static str) {
+        // Dropping a card back onto its own column changes nothing
+        if from == to {
+            return;
+        }
+        let moved_task = Task {
+            status: to.to_string(),
+            ..task.clone()
+        };
+        self.moved.set(Some(TaskMove {
+            task: moved_task,
+            from,
+            to,
+        }));
+
+        spawn_local(async move {
+            if let Err(e) = tasks::set_status(&task.id, to).await {
+                self.moved.set(Some(TaskMove {
+                    task: task.clone(),
+                    from: to,
+                    to: from,
+                }));
+                self.move_error
+                    .set(Some(format!("Failed to move \"{}\": {}", task.title, e)));
+                let move_error = self.move_error;
+                set_timeout(
+                    move || move_error.set(None),
+                    std::time::Duration::from_secs(4),
+                );
+            }
+            self.stats_refetch.update(|n| *n = n.wrapping_add(1));
+        });
+    }
+}
+
 #[component]
 pub fn KanbanColumn(
     status: &'static str,
@@ -75,9 +138,24 @@ pub fn KanbanColumn(
     total_count: usize,
 ) -> impl IntoView {
     let (tasks, set_tasks) = signal(Vec::<Task>::new());
-    let (offset, set_offset) = signal(0);
+    // Keyset cursor for the next page; offsets would skip or repeat tasks
+    // when others are created while the column is scrolled
+    let (next_cursor, set_next_cursor) = signal(None::<String>);
     let (loading, set_loading) = signal(false);
 
+    // Column count, adjusted optimistically while tasks are being created
+    let (count, set_count) = signal(total_count);
+
+    // Optimistic task creation: each pending add gets its own key so
+    // concurrent adds can resolve independently
+    let new_task_title = RwSignal::new(String::new());
+    let pending_tasks = RwSignal::new(Vec::<(u32, String)>::new());
+    let next_pending_key = StoredValue::new(0u32);
+    let create_error = RwSignal::new(None::<String>);
+
+    // Column fetches are aborted when the column unmounts (e.g. navigating away)
+    let requests = RequestScope::new();
+
     // Task detail dialog state - store task ID only, not the whole object
     let (selected_task_id, set_selected_task_id) = signal(String::new());
     let dialog_open = RwSignal::new(false);
@@ -93,6 +171,50 @@ pub fn KanbanColumn(
     // Store list_id in a signal so it can be shared across closures
     let list_id_signal = StoredValue::new(list_id.clone());
 
+    // Drag-and-drop between columns
+    let board = expect_context::<KanbanBoard>();
+    let (drop_hover, set_drop_hover) = signal(false);
+
+    // Apply card moves to this column. Not run on mount, so a column
+    // recreated after a stats refetch doesn't replay the last move.
+    Effect::watch(
+        move || board.moved.get(),
+        move |task_move, _, _| {
+            let Some(TaskMove { task, from, to }) = task_move.clone() else {
+                return;
+            };
+            if from == status {
+                set_tasks.update(|t| t.retain(|existing| existing.id != task.id));
+                set_count.update(|c| *c = c.saturating_sub(1));
+            } else if to == status {
+                set_tasks.update(|t| {
+                    if !t.iter().any(|existing| existing.id == task.id) {
+                        t.insert(0, task);
+                    }
+                });
+                set_count.update(|c| *c += 1);
+            }
+        },
+        false,
+    );
+
+    let on_dragover = move |ev: ev::DragEvent| {
+        if board.dragging.get_untracked().is_some() {
+            // Accept the drop
+            ev.prevent_default();
+            set_drop_hover.set(true);
+        }
+    };
+
+    let on_drop = move |ev: ev::DragEvent| {
+        ev.prevent_default();
+        set_drop_hover.set(false);
+        if let Some((task, from)) = board.dragging.get_untracked() {
+            board.dragging.set(None);
+            board.move_task(task, from, status);
+        }
+    };
+
     // WebSocket updates - refetch trigger
     let (refetch_trigger, set_refetch_trigger) = signal(0u32);
     let ws_updates = crate::websocket::use_websocket_updates();
@@ -119,64 +241,112 @@ pub fn KanbanColumn(
         }
     });
 
-    // Determine sort order based on status
-    let (sort_field, sort_order) = match status {
-        "backlog" | "todo" => ("priority", "asc"), // Priority 1-5, nulls last
-        "in_progress" | "review" => ("updated_at", "desc"), // Most recently updated first
-        "done" => ("updated_at", "desc"),          // Most recently updated first
-        "cancelled" => ("updated_at", "desc"),     // Most recently updated first
-        _ => ("updated_at", "desc"),               // Fallback
-    };
-
     // Initial fetch + refetch on WebSocket updates
     Effect::new(move |_| {
         let _ = refetch_trigger.get(); // Track WebSocket refetch trigger
         let list_id = list_id_signal.get_value();
-        set_offset.set(0); // Reset offset on refetch
+        set_next_cursor.set(None); // Restart from the first page on refetch
         spawn_local(async move {
-            let result = tasks::list_for_task_list(
-                &list_id,
-                Some(25),
-                Some(0),
-                Some(status),
-                Some(sort_field),
-                Some(sort_order),
-                None,
-                None, // Fetch all tasks - filter orphaned subtasks in UI (lines 136-160)
-            )
-            .await;
-            if let Ok(paginated) = result {
+            let result = requests
+                .run(tasks::list_for_task_list(
+                    &list_id,
+                    Some(25),
+                    None,
+                    None,
+                    Some(status),
+                    None, // The column's default sort; cursors resume it
+                    None,
+                    None,
+                    None, // Fetch all tasks - filter orphaned subtasks in UI (lines 136-160)
+                ))
+                .await;
+            if let Some(Ok(paginated)) = result {
                 set_tasks.set(paginated.items);
+                set_next_cursor.set(paginated.next_cursor);
             }
         });
     });
 
     let load_more = move |_| {
+        let Some(cursor) = next_cursor.get() else {
+            return;
+        };
         set_loading.set(true);
         let list_id = list_id_signal.get_value();
-        let current_offset = offset.get();
-        let new_offset = current_offset + 25;
 
         spawn_local(async move {
-            let result = tasks::list_for_task_list(
-                &list_id,
-                Some(25),
-                Some(new_offset),
-                Some(status),
-                Some(sort_field),
-                Some(sort_order),
-                None,
-                None, // Fetch all tasks - filter orphaned subtasks in UI (lines 136-160)
-            )
-            .await;
+            let Some(result) = requests
+                .run(tasks::list_for_task_list(
+                    &list_id,
+                    Some(25),
+                    None,
+                    Some(&cursor),
+                    Some(status),
+                    None,
+                    None,
+                    None,
+                    None, // Fetch all tasks - filter orphaned subtasks in UI (lines 136-160)
+                ))
+                .await
+            else {
+                return;
+            };
             if let Ok(paginated) = result {
                 set_tasks.update(|t| t.extend(paginated.items));
-                set_offset.set(new_offset);
+                set_next_cursor.set(paginated.next_cursor);
             }
             set_loading.set(false);
         });
     };
 
+    let create_task = move |title: String| {
+        let key = next_pending_key.get_value();
+        next_pending_key.set_value(key.wrapping_add(1));
+        pending_tasks.update(|p| p.insert(0, (key, title.clone())));
+        set_count.update(|c| *c += 1);
+
+        let list_id = list_id_signal.get_value();
+        spawn_local(async move {
+            // Create the task directly in this column's status
+            let request = tasks::CreateTask {
+                title,
+                status: Some(status.to_string()),
+                ..Default::default()
+            };
+            let result = tasks::create(&list_id, &request).await;
+
+            pending_tasks.update(|p| p.retain(|(k, _)| *k != key));
+            match result {
+                Ok(task) => {
+                    set_tasks.update(|t| {
+                        if !t.iter().any(|existing| existing.id == task.id) {
+                            t.insert(0, task);
+                        }
+                    });
+                }
+                Err(e) => {
+                    set_count.update(|c| *c = c.saturating_sub(1));
+                    create_error.set(Some(format!("Failed to create task: {}", e)));
+                    set_timeout(
+                        move || create_error.set(None),
+                        std::time::Duration::from_secs(4),
+                    );
+                }
+            }
+        });
+    };
+
+    let on_new_task_keydown = move |ev: ev::KeyboardEvent| {
+        if ev.key() == "Enter" {
+            ev.prevent_default();
+            let title = new_task_title.get_untracked().trim().to_string();
+            if !title.is_empty() {
+                new_task_title.set(String::new());
+                create_task(title);
+            }
+        }
+    };
+
     let bg_color = match status {
         "backlog" => "bg-ctp-surface0",
         "todo" => "bg-ctp-blue/10",
@@ -202,25 +372,60 @@ pub fn KanbanColumn(
 
             // Load more when within 200px of bottom
             let displayed = tasks.get().len();
-            if scroll_top + client_height >= scroll_height - 200.0 && displayed < total_count {
+            if scroll_top + client_height >= scroll_height - 200.0 && displayed < count.get() {
                 load_more(());
             }
         }
     };
 
     view! {
-        <div class=format!("{} rounded-lg p-4 flex flex-col h-full overflow-hidden", bg_color)>
+        <div
+            class=move || {
+                format!(
+                    "{} relative rounded-lg p-4 flex flex-col h-full overflow-hidden {}",
+                    bg_color,
+                    if drop_hover.get() { "ring-2 ring-ctp-blue" } else { "" },
+                )
+            }
+            on:dragover=on_dragover
+            on:dragleave=move |_| set_drop_hover.set(false)
+            on:drop=on_drop
+        >
             <h3 class="font-semibold text-ctp-text mb-4 flex justify-between items-center flex-shrink-0">
                 <span>{label}</span>
                 <span class="text-xs bg-ctp-surface1 px-2 py-1 rounded">
-                    {total_count}
+                    {move || count.get()}
                 </span>
             </h3>
+            // Failed create notice (auto-dismisses)
+            {move || {
+                create_error.get().map(|message| {
+                    view! {
+                        <div class="absolute bottom-16 left-4 right-4 z-10 bg-ctp-red/90 text-ctp-base text-xs rounded px-3 py-2 shadow-lg">
+                            {message}
+                        </div>
+                    }
+                })
+            }}
             <div
                 node_ref=scroll_ref
                 on:scroll=on_scroll
                 class="space-y-2 overflow-y-auto flex-1 min-h-0"
             >
+                // Placeholder cards for tasks being created
+                {move || {
+                    pending_tasks
+                        .get()
+                        .into_iter()
+                        .map(|(_, title)| {
+                            view! {
+                                <div class="p-3 bg-ctp-surface0 rounded-lg border-l-4 border-ctp-surface1 opacity-60 animate-pulse">
+                                    <span class="text-sm text-ctp-text">{title}</span>
+                                </div>
+                            }
+                        })
+                        .collect::<Vec<_>>()
+                }}
                 {move || {
                     let all_tasks = tasks.get();
 
@@ -272,8 +477,22 @@ pub fn KanbanColumn(
                                     }
                                 }
 
+                                let task_for_drag = task.clone();
+                                let on_dragstart = move |ev: ev::DragEvent| {
+                                    if let Some(transfer) = ev.data_transfer() {
+                                        // Firefox only starts a drag that carries data
+                                        let _ = transfer.set_data("text/plain", &task_for_drag.id);
+                                        transfer.set_effect_allowed("move");
+                                    }
+                                    board.dragging.set(Some((task_for_drag.clone(), status)));
+                                };
+
                                 view! {
-                                    <div>
+                                    <div
+                                        draggable="true"
+                                        on:dragstart=on_dragstart
+                                        on:dragend=move |_| board.dragging.set(None)
+                                    >
                                         {move || {
                                             parent_task.get().map(|parent| {
                                                 view! {
@@ -329,6 +548,15 @@ pub fn KanbanColumn(
                     })
                 }}
             </div>
+            // Quick add, pinned below the scrolling task list
+            <input
+                type="text"
+                placeholder="+ Add task"
+                prop:value=move || new_task_title.get()
+                on:input=move |ev| new_task_title.set(event_target_value(&ev))
+                on:keydown=on_new_task_keydown
+                class="mt-3 flex-shrink-0 w-full rounded border-ctp-surface1 bg-ctp-surface0 px-3 py-1.5 text-sm text-ctp-text placeholder-ctp-subtext0 focus:border-ctp-blue focus:outline-none"
+            />
 
             // Task detail dialog
             <TaskDetailDialog
@@ -375,12 +603,10 @@ pub fn SubtaskStackItem(
                     </div>
                     <div class="flex-1 min-w-0 text-sm text-ctp-text break-words font-medium">
                         {move || {
-                            let title = &subtask.title;
-                            let truncated = !is_expanded() && title.len() > 60;
-                            if truncated {
-                                format!("{}...", &title[..60])
+                            if is_expanded() {
+                                subtask.title.clone()
                             } else {
-                                title.clone()
+                                truncate_chars(&subtask.title, 60)
                             }
                         }}
                     </div>
@@ -481,24 +707,30 @@ pub fn TaskCard(
     let (subtask_count, set_subtask_count) = signal(0usize);
     let task_id = task.id.clone();
     let list_id = task.list_id.clone();
+    let requests = RequestScope::new();
 
     Effect::new(move || {
         let task_id = task_id.clone();
         let list_id = list_id.clone();
         spawn_local(async move {
             // Fetch tasks where parent_id == this task's id
-            match tasks::list_for_task_list(
-                &list_id,
-                Some(1),        // limit 1 - we only need the count
-                None,           // offset
-                None,           // status
-                None,           // sort
-                None,           // order
-                Some(&task_id), // parent_id filter
-                None,           // task_type - we want subtasks here
-            )
-            .await
-            {
+            let Some(result) = requests
+                .run(tasks::list_for_task_list(
+                    &list_id,
+                    Some(1),        // limit 1 - we only need the count
+                    None,           // offset
+                    None,           // cursor
+                    None,           // status
+                    None,           // sort
+                    None,           // order
+                    Some(&task_id), // parent_id filter
+                    None,           // task_type - we want subtasks here
+                ))
+                .await
+            else {
+                return;
+            };
+            match result {
                 Ok(paginated) => {
                     set_subtask_count.set(paginated.total);
                 }
@@ -554,22 +786,10 @@ pub fn TaskCard(
                     </div>
                     {task.description.as_ref().map(|desc| {
                         // Truncate markdown before rendering to HTML
-                        let preview_content = if desc.chars().count() > 100 {
-                            let truncated: String = desc.chars().take(100).collect();
-                            format!("{}...", truncated)
-                        } else {
-                            desc.clone()
-                        };
+                        let preview_content = truncate_chars(desc, 100);
 
                         // Parse markdown to HTML for preview
-                        use pulldown_cmark::{Options, Parser, html};
-                        let mut options = Options::empty();
-                        options.insert(Options::ENABLE_STRIKETHROUGH);
-                        options.insert(Options::ENABLE_TABLES);
-
-                        let parser = Parser::new_ext(&preview_content, options);
-                        let mut html_output = String::new();
-                        html::push_html(&mut html_output, parser);
+                        let html_output = crate::markdown::render_markdown(&preview_content);
 
                         view! { <div class="text-ctp-subtext0 text-xs mt-1" inner_html=html_output></div> }
                     })}
@@ -667,6 +887,7 @@ pub fn SubtaskList(
     let task_id_for_fetch = task_id.clone();
     let list_id_for_fetch = list_id.clone();
     let parent_status_for_fetch = parent_status.clone();
+    let requests = RequestScope::new();
 
     // Fetch subtasks on mount - only those matching parent's status
     Effect::new(move || {
@@ -674,18 +895,23 @@ pub fn SubtaskList(
         let list_id = list_id_for_fetch.clone();
         let parent_status = parent_status_for_fetch.clone();
         spawn_local(async move {
-            match tasks::list_for_task_list(
-                &list_id,
-                None,                 // limit - get all matching subtasks
-                None,                 // offset
-                Some(&parent_status), // status - filter by parent's status
-                Some("priority"),     // sort by priority
-                Some("asc"),          // ascending
-                Some(&task_id),       // parent_id filter
-                None,                 // task_type - we want subtasks here
-            )
-            .await
-            {
+            let Some(result) = requests
+                .run(tasks::list_for_task_list(
+                    &list_id,
+                    Some(SUBTASK_LIMIT),  // limit - get all matching subtasks
+                    None,                 // offset
+                    None,                 // cursor
+                    Some(&parent_status), // status - filter by parent's status
+                    Some("priority"),     // sort by priority
+                    Some("asc"),          // ascending
+                    Some(&task_id),       // parent_id filter
+                    None,                 // task_type - we want subtasks here
+                ))
+                .await
+            else {
+                return;
+            };
+            match result {
                 Ok(paginated) => {
                     set_subtasks.set(paginated.items);
                     set_loading.set(false);
@@ -752,11 +978,7 @@ pub fn MiniParentCard(
         }
     };
 
-    let truncated_title = if parent_task.title.len() > 50 {
-        format!("{}...", &parent_task.title[..50])
-    } else {
-        parent_task.title.clone()
-    };
+    let truncated_title = truncate_chars(&parent_task.title, 50);
 
     let bg_color = status_bg_color(&parent_task.status.to_string());
     let priority_color = priority_border_color(parent_task.priority);
@@ -788,23 +1010,29 @@ pub fn TaskDetailContent(
     let (subtasks, set_subtasks) = signal(Vec::<Task>::new());
     let task_id_for_fetch = task.id.clone();
     let list_id_for_fetch = task.list_id.clone();
+    let requests = RequestScope::new();
 
     Effect::new(move || {
         let task_id = task_id_for_fetch.clone();
         let list_id = list_id_for_fetch.clone();
         spawn_local(async move {
-            match tasks::list_for_task_list(
-                &list_id,
-                None,
-                None,
-                None,
-                Some("priority"),
-                Some("asc"),
-                Some(&task_id),
-                None, // task_type - we want subtasks here
-            )
-            .await
-            {
+            let Some(result) = requests
+                .run(tasks::list_for_task_list(
+                    &list_id,
+                    Some(SUBTASK_LIMIT),
+                    None,
+                    None,
+                    None,
+                    Some("priority"),
+                    Some("asc"),
+                    Some(&task_id),
+                    None, // task_type - we want subtasks here
+                ))
+                .await
+            else {
+                return;
+            };
+            match result {
                 Ok(paginated) => {
                     set_subtasks.set(paginated.items);
                 }
@@ -982,6 +1210,8 @@ pub fn TaskDetailDialog(
         Option<String>,
     >,
 ) -> impl IntoView {
+    use_close_on_escape(open);
+
     let task_resource = LocalResource::new(move || {
         let id = task_id.get();
         async move {
@@ -1211,6 +1441,7 @@ pub fn TaskListContent(task_list: Signal<TaskList>) -> impl IntoView {
     // WebSocket updates - refetch trigger for stats
     let (stats_refetch_trigger, set_stats_refetch_trigger) = signal(0u32);
     let ws_updates = crate::websocket::use_websocket_updates();
+    let requests = RequestScope::new();
 
     // Watch for WebSocket task updates and trigger stats refetch
     Effect::new(move || {
@@ -1230,6 +1461,14 @@ pub fn TaskListContent(task_list: Signal<TaskList>) -> impl IntoView {
         }
     });
 
+    let board = KanbanBoard {
+        dragging: RwSignal::new(None),
+        moved: RwSignal::new(None),
+        move_error: RwSignal::new(None),
+        stats_refetch: set_stats_refetch_trigger,
+    };
+    provide_context(board);
+
     // Fetch stats when task list changes or WebSocket updates
     Effect::new(move || {
         let tl = task_list.get();
@@ -1239,8 +1478,9 @@ pub fn TaskListContent(task_list: Signal<TaskList>) -> impl IntoView {
 
         // Fetch stats only - columns will fetch their own tasks
         spawn_local(async move {
-            let result = task_lists::get_stats(&id).await;
-            set_stats_data.set(Some(result));
+            if let Some(result) = requests.run(task_lists::get_stats(&id)).await {
+                set_stats_data.set(Some(result));
+            }
         });
     });
 
@@ -1302,6 +1542,17 @@ pub fn TaskListContent(task_list: Signal<TaskList>) -> impl IntoView {
                                     }
                                 })}
 
+                                // Failed move notice (auto-dismisses)
+                                {move || {
+                                    board.move_error.get().map(|message| {
+                                        view! {
+                                            <div class="fixed bottom-4 right-4 z-50 bg-ctp-red/90 text-ctp-base text-sm rounded px-4 py-2 shadow-lg">
+                                                {message}
+                                            </div>
+                                        }
+                                    })
+                                }}
+
                                 <div class="grid grid-cols-1 md:grid-cols-2 lg:grid-cols-3 xl:grid-cols-6 gap-4 flex-1 min-h-0">
                                     {statuses
                                         .clone()
@@ -1358,6 +1609,8 @@ pub fn TaskListDetailModal(
     task_list: ReadSignal<Option<TaskList>>,
     open: RwSignal<bool>,
 ) -> impl IntoView {
+    use_close_on_escape(open);
+
     view! {
         <OverlayDrawer
             open
@@ -171,15 +171,17 @@ impl BreadcrumbItem {
 }
 
 /// Breadcrumb navigation component with consistent styling
-/// Displays a horizontal list of navigation items with separators
+/// Displays a horizontal list of navigation items with separators.
+/// Rendered as an ordered list inside `<nav aria-label="breadcrumb">`, with the
+/// last item marked as the current page.
 #[component]
 pub fn Breadcrumb(items: Vec<BreadcrumbItem>) -> impl IntoView {
     let items_len = items.len();
     let page_state = use_context::<crate::breadcrumb_state::BreadcrumbPageState>();
 
     view! {
-        <div class="bg-ctp-surface0 border-b border-ctp-surface1 py-3">
-            <div class="container mx-auto pl-[1.15rem] pr-6 flex items-center gap-3 text-base">
+        <nav aria-label="breadcrumb" class="bg-ctp-surface0 border-b border-ctp-surface1 py-3">
+            <ol class="container mx-auto pl-[1.15rem] pr-6 flex items-center gap-3 text-base">
                 {items
                     .into_iter()
                     .enumerate()
@@ -192,7 +194,7 @@ pub fn Breadcrumb(items: Vec<BreadcrumbItem>) -> impl IntoView {
                         let state = page_state.clone();
 
                         view! {
-                            <div class="flex items-center gap-3">
+                            <li class="flex items-center gap-3">
                                 {if let Some(base_href) = href {
                                     let href_with_query = move || {
                                         match (name.as_ref(), state.as_ref()) {
@@ -229,7 +231,10 @@ pub fn Breadcrumb(items: Vec<BreadcrumbItem>) -> impl IntoView {
                                         .into_any()
                                 } else {
                                     view! {
-                                        <div class="flex items-center gap-2">
+                                        <div
+                                            class="flex items-center gap-2"
+                                            aria-current=is_last.then_some("page")
+                                        >
                                             {if let Some(item_id) = id {
                                                 view! { <CopyableId id=item_id/> }.into_any()
                                             } else {
@@ -242,13 +247,13 @@ pub fn Breadcrumb(items: Vec<BreadcrumbItem>) -> impl IntoView {
                                 }}
 
                                 {(!is_last).then(|| {
-                                    view! { <span class="text-ctp-overlay0">"/"</span> }
+                                    view! { <span class="text-ctp-overlay0" aria-hidden="true">"/"</span> }
                                 })}
-                            </div>
+                            </li>
                         }
                     })
                     .collect::<Vec<_>>()}
-            </div>
-        </div>
+            </ol>
+        </nav>
     }
 }
@@ -1,11 +1,15 @@
 //! Reusable hooks for common UI patterns
 
 mod url_utils;
+mod use_hotkeys;
 mod use_pagination;
 mod use_search;
 mod use_sort;
+mod use_tags;
 
 pub use url_utils::*;
+pub use use_hotkeys::*;
 pub use use_pagination::*;
 pub use use_search::*;
 pub use use_sort::*;
+pub use use_tags::*;
@@ -0,0 +1,170 @@
+use leptos::ev;
+use leptos::prelude::*;
+use leptos_router::hooks::use_navigate;
+use wasm_bindgen::JsCast;
+
+/// DOM id of the header search box focused by `/`
+pub const GLOBAL_SEARCH_ID: &str = "global-search";
+
+/// Longest pause between the keys of a sequence such as `g p`
+const SEQUENCE_TIMEOUT_MS: f64 = 1000.0;
+
+/// Action run by a keyboard shortcut
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Command {
+    FocusSearch,
+    GoTo(&'static str),
+}
+
+/// Key sequences and the command each runs
+const BINDINGS: &[(&[&str], Command)] = &[
+    (&["/"], Command::FocusSearch),
+    (&["g", "p"], Command::GoTo("/")),
+    (&["g", "n"], Command::GoTo("/notes")),
+];
+
+/// Registry of open/closed signals for overlays that Esc should close
+#[derive(Clone, Copy)]
+pub struct Hotkeys {
+    overlays: StoredValue<Vec<(usize, RwSignal<bool>)>>,
+    next_id: StoredValue<usize>,
+}
+
+impl Hotkeys {
+    /// Close the most recently registered overlay that is open
+    fn close_top_overlay(&self) -> bool {
+        self.overlays.with_value(|overlays| {
+            overlays
+                .iter()
+                .rev()
+                .find(|(_, open)| open.get_untracked())
+                .map(|(_, open)| open.set(false))
+                .is_some()
+        })
+    }
+}
+
+/// Whether keyboard focus is in a text field, where plain keys are typing
+fn editing_text() -> bool {
+    let Some(active) = web_sys::window()
+        .and_then(|w| w.document())
+        .and_then(|d| d.active_element())
+    else {
+        return false;
+    };
+    matches!(active.tag_name().as_str(), "INPUT" | "TEXTAREA" | "SELECT")
+        || active
+            .dyn_ref::<web_sys::HtmlElement>()
+            .is_some_and(|el| el.is_content_editable())
+}
+
+fn focus_search() {
+    if let Some(input) = web_sys::window()
+        .and_then(|w| w.document())
+        .and_then(|d| d.get_element_by_id(GLOBAL_SEARCH_ID))
+        .and_then(|el| el.dyn_into::<web_sys::HtmlElement>().ok())
+    {
+        let _ = input.focus();
+    }
+}
+
+/// Command bound to exactly `keys`, or whether `keys` starts a longer binding
+fn lookup(keys: &[String]) -> (Option<Command>, bool) {
+    let matches = |binding: &[&str]| binding.iter().zip(keys).all(|(b, k)| b == k);
+    let exact = BINDINGS
+        .iter()
+        .find(|(binding, _)| binding.len() == keys.len() && matches(binding))
+        .map(|(_, command)| *command);
+    let prefix = BINDINGS
+        .iter()
+        .any(|(binding, _)| binding.len() > keys.len() && matches(binding));
+    (exact, prefix)
+}
+
+/// Hook installing the app-wide keyboard shortcuts
+///
+/// `/` focuses the header search, `g p` goes to projects, `g n` to notes
+/// and Esc closes the topmost overlay registered with `use_close_on_escape`.
+/// Plain keys are ignored while a text field has focus; Esc always works.
+/// Call once, inside the router.
+///
+/// # Example
+/// ```rust
+/// use_hotkeys();
+/// ```
+pub fn use_hotkeys() {
+    let hotkeys = Hotkeys {
+        overlays: StoredValue::new(Vec::new()),
+        next_id: StoredValue::new(0),
+    };
+    provide_context(hotkeys);
+
+    let navigate = use_navigate();
+    let pending = StoredValue::new((Vec::<String>::new(), 0.0_f64));
+
+    let handle = window_event_listener(ev::keydown, move |ev| {
+        if ev.key() == "Escape" {
+            if hotkeys.close_top_overlay() {
+                ev.prevent_default();
+            }
+            return;
+        }
+        if ev.ctrl_key() || ev.meta_key() || ev.alt_key() || editing_text() {
+            return;
+        }
+
+        let key = ev.key();
+        let now = ev.time_stamp();
+        let mut keys = pending.with_value(|(keys, last)| {
+            if now - last > SEQUENCE_TIMEOUT_MS {
+                Vec::new()
+            } else {
+                keys.clone()
+            }
+        });
+        keys.push(key.clone());
+
+        let (mut command, mut waiting) = lookup(&keys);
+        if command.is_none() && !waiting {
+            // Not part of the sequence in progress; try the key on its own
+            keys = vec![key];
+            (command, waiting) = lookup(&keys);
+        }
+
+        if let Some(command) = command {
+            ev.prevent_default();
+            pending.set_value((Vec::new(), now));
+            match command {
+                Command::FocusSearch => focus_search(),
+                Command::GoTo(path) => navigate(path, Default::default()),
+            }
+        } else if waiting {
+            pending.set_value((keys, now));
+        } else {
+            pending.set_value((Vec::new(), now));
+        }
+    });
+    on_cleanup(move || handle.remove());
+}
+
+/// Let Esc close an overlay by setting `open` to false
+///
+/// Overlays mounted later (e.g. a dialog inside a drawer) close first.
+/// Does nothing outside `use_hotkeys`.
+pub fn use_close_on_escape(open: RwSignal<bool>) {
+    let Some(hotkeys) = use_context::<Hotkeys>() else {
+        return;
+    };
+
+    let id = hotkeys.next_id.get_value();
+    hotkeys.next_id.set_value(id + 1);
+    hotkeys
+        .overlays
+        .update_value(|overlays| overlays.push((id, open)));
+
+    on_cleanup(move || {
+        hotkeys
+            .overlays
+            .update_value(|overlays| overlays.retain(|(other, _)| *other != id));
+    });
+}
@@ -0,0 +1,89 @@
+use leptos::prelude::*;
+use leptos_router::hooks::{use_location, use_navigate, use_query_map};
+
+use super::build_url_with_params;
+
+/// Return type for use_tags hook
+pub struct UseTagsReturn {
+    pub tags: ReadSignal<Vec<String>>,
+    pub on_add: Callback<String>,
+    pub on_remove: Callback<String>,
+}
+
+/// Parse the comma-separated `tags` query parameter
+fn parse_tags(value: Option<String>) -> Vec<String> {
+    value
+        .map(|v| {
+            v.split(',')
+                .map(|t| t.trim().to_string())
+                .filter(|t| !t.is_empty())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Hook for managing a tag filter with URL persistence
+///
+/// Reads initial tags from URL query parameter (?tags=a,b) and updates
+/// the URL when tags are added or removed. Also resets pagination to page 0
+/// when the filter changes.
+///
+/// # Example
+/// ```rust
+/// let tag_filter = use_tags();
+///
+/// view! {
+///     <TagFilter
+///         tags=tag_filter.tags
+///         on_add=tag_filter.on_add
+///         on_remove=tag_filter.on_remove
+///     />
+/// }
+/// ```
+pub fn use_tags() -> UseTagsReturn {
+    let query = use_query_map();
+    let navigate = use_navigate();
+    let location = use_location();
+
+    let (tags, set_tags) = signal(parse_tags(query.read().get("tags")));
+
+    // Watch for URL changes (e.g., back/forward navigation)
+    Effect::new(move |_| {
+        set_tags.set(parse_tags(query.get().get("tags")));
+    });
+
+    let update_url = move |new_tags: Vec<String>| {
+        set_tags.set(new_tags.clone());
+
+        let value = (!new_tags.is_empty()).then(|| new_tags.join(","));
+        let url = build_url_with_params(
+            location.pathname.get(),
+            query.read().clone(),
+            [("tags".to_string(), value), ("page".to_string(), None)].into(),
+        );
+        navigate(&url, Default::default());
+    };
+    let update_url_for_remove = update_url.clone();
+
+    let on_add = Callback::new(move |tag: String| {
+        let tag = tag.trim().to_string();
+        let mut current = tags.get_untracked();
+        if tag.is_empty() || current.contains(&tag) {
+            return;
+        }
+        current.push(tag);
+        update_url(current);
+    });
+
+    let on_remove = Callback::new(move |tag: String| {
+        let mut current = tags.get_untracked();
+        current.retain(|t| *t != tag);
+        update_url_for_remove(current);
+    });
+
+    UseTagsReturn {
+        tags,
+        on_add,
+        on_remove,
+    }
+}
@@ -7,6 +7,7 @@ mod app;
 mod breadcrumb_state;
 mod components;
 mod hooks;
+mod markdown;
 mod mermaid;
 mod models;
 mod pages;
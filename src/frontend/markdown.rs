@@ -0,0 +1,64 @@
+//! Markdown to HTML rendering for note, task and skill content.
+//!
+//! Output is injected with `inner_html`, so raw HTML in the source is shown
+//! as text instead of being passed through, and links cannot run script.
+
+use pulldown_cmark::{CowStr, Event, Options, Parser, Tag, TagEnd, html};
+
+/// URL schemes that execute or embed content when followed
+const UNSAFE_SCHEMES: &[&str] = &["javascript:", "vbscript:", "data:"];
+
+/// Escape a value for use inside a double-quoted HTML attribute
+fn escape_attr(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+fn is_safe_href(dest: &str) -> bool {
+    // Browsers ignore whitespace and control characters inside a scheme
+    let dest: String = dest
+        .chars()
+        .filter(|c| !c.is_ascii_whitespace() && !c.is_ascii_control())
+        .collect::<String>()
+        .to_ascii_lowercase();
+    !UNSAFE_SCHEMES.iter().any(|scheme| dest.starts_with(scheme))
+}
+
+/// Opening `<a>` tag that opens in a new tab without exposing `window.opener`
+fn link_open_tag(dest: &str, title: &str) -> String {
+    let mut tag = String::from("<a");
+    if is_safe_href(dest) {
+        tag.push_str(&format!(" href=\"{}\"", escape_attr(dest)));
+    }
+    if !title.is_empty() {
+        tag.push_str(&format!(" title=\"{}\"", escape_attr(title)));
+    }
+    tag.push_str(" target=\"_blank\" rel=\"noopener noreferrer\">");
+    tag
+}
+
+/// Render markdown (GFM tables, strikethrough, footnotes, task lists) to HTML.
+pub fn render_markdown(content: &str) -> String {
+    let mut options = Options::empty();
+    options.insert(Options::ENABLE_STRIKETHROUGH);
+    options.insert(Options::ENABLE_TABLES);
+    options.insert(Options::ENABLE_FOOTNOTES);
+    options.insert(Options::ENABLE_TASKLISTS);
+
+    let events = Parser::new_ext(content, options).map(|event| match event {
+        // Raw HTML becomes text, which push_html escapes
+        Event::Html(raw) | Event::InlineHtml(raw) => Event::Text(raw),
+        Event::Start(Tag::Link {
+            dest_url, title, ..
+        }) => Event::Html(CowStr::from(link_open_tag(&dest_url, &title))),
+        Event::End(TagEnd::Link) => Event::Html(CowStr::Borrowed("</a>")),
+        event => event,
+    });
+
+    let mut html_output = String::new();
+    html::push_html(&mut html_output, events);
+    html_output
+}
@@ -68,6 +68,47 @@ pub struct TaskStats {
     pub cancelled: usize,
 }
 
+/// Note type as understood by the notes `type` query parameter.
+///
+/// The backend derives the type from `parent_id`: top-level notes are `note`,
+/// notes with a parent are `subnote`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum NoteType {
+    Note,
+    Subnote,
+}
+
+impl NoteType {
+    /// Value sent as the `type` query parameter
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NoteType::Note => "note",
+            NoteType::Subnote => "subnote",
+        }
+    }
+
+    /// Human readable label for filters and badges
+    pub fn label(&self) -> &'static str {
+        match self {
+            NoteType::Note => "Note",
+            NoteType::Subnote => "Subnote",
+        }
+    }
+}
+
+impl std::str::FromStr for NoteType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "note" => Ok(NoteType::Note),
+            "subnote" => Ok(NoteType::Subnote),
+            _ => Err(format!("Invalid note type: {}", s)),
+        }
+    }
+}
+
 /// Note response from API
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Note {
@@ -90,6 +131,17 @@ pub struct Note {
     pub updated_at: String,
 }
 
+impl Note {
+    /// Derive the note type from the parent relationship
+    pub fn note_type(&self) -> NoteType {
+        if self.parent_id.is_some() {
+            NoteType::Subnote
+        } else {
+            NoteType::Note
+        }
+    }
+}
+
 /// Skill response from API
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Skill {
@@ -113,6 +165,14 @@ pub struct Skill {
     pub updated_at: String,
 }
 
+/// Grouped results from the cross-entity search endpoint
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SearchResults {
+    pub notes: Vec<Note>,
+    pub tasks: Vec<Task>,
+    pub projects: Vec<Project>,
+}
+
 /// Paginated response wrapper
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Paginated<T> {
@@ -120,6 +180,9 @@ pub struct Paginated<T> {
     pub total: usize,
     pub limit: usize,
     pub offset: usize,
+    /// Only sent by listings that support cursor paging
+    #[serde(default)]
+    pub next_cursor: Option<String>,
 }
 
 /// API error response
@@ -134,32 +197,74 @@ pub struct ApiError {
 #[serde(tag = "type", content = "data")]
 pub enum UpdateMessage {
     // Notes
-    NoteCreated { note_id: String },
-    NoteUpdated { note_id: String },
-    NoteDeleted { note_id: String },
+    NoteCreated {
+        note_id: String,
+    },
+    /// `changed` lists the fields that changed, when the server knows them
+    NoteUpdated {
+        note_id: String,
+        #[serde(default)]
+        changed: Vec<String>,
+    },
+    NoteDeleted {
+        note_id: String,
+    },
 
     // Skills
-    SkillCreated { skill_id: String },
-    SkillUpdated { skill_id: String },
-    SkillDeleted { skill_id: String },
+    SkillCreated {
+        skill_id: String,
+    },
+    SkillUpdated {
+        skill_id: String,
+    },
+    SkillDeleted {
+        skill_id: String,
+    },
 
     // Projects
-    ProjectCreated { project_id: String },
-    ProjectUpdated { project_id: String },
-    ProjectDeleted { project_id: String },
+    ProjectCreated {
+        project_id: String,
+    },
+    ProjectUpdated {
+        project_id: String,
+    },
+    ProjectDeleted {
+        project_id: String,
+    },
 
     // Repos
-    RepoCreated { repo_id: String },
-    RepoUpdated { repo_id: String },
-    RepoDeleted { repo_id: String },
+    RepoCreated {
+        repo_id: String,
+    },
+    RepoUpdated {
+        repo_id: String,
+    },
+    RepoDeleted {
+        repo_id: String,
+    },
 
     // TaskLists
-    TaskListCreated { task_list_id: String },
-    TaskListUpdated { task_list_id: String },
-    TaskListDeleted { task_list_id: String },
+    TaskListCreated {
+        task_list_id: String,
+    },
+    TaskListUpdated {
+        task_list_id: String,
+    },
+    TaskListDeleted {
+        task_list_id: String,
+    },
 
     // Tasks
-    TaskCreated { task_id: String },
-    TaskUpdated { task_id: String },
-    TaskDeleted { task_id: String },
+    TaskCreated {
+        task_id: String,
+    },
+    /// `changed` lists the fields that changed, when the server knows them
+    TaskUpdated {
+        task_id: String,
+        #[serde(default)]
+        changed: Vec<String>,
+    },
+    TaskDeleted {
+        task_id: String,
+    },
 }
@@ -3,9 +3,7 @@ use leptos::task::spawn_local;
 use leptos_router::hooks::use_params_map;
 
 use crate::api::{ApiClientError, QueryBuilder, notes, projects};
-use crate::components::{
-    Breadcrumb, BreadcrumbItem, CopyableId, MarkdownContent, NoteStackSidebar,
-};
+use crate::components::{Breadcrumb, BreadcrumbItem, CopyableId, NoteBody, NoteStackSidebar};
 use crate::models::{Note, Project, UpdateMessage};
 use crate::websocket::use_websocket_updates;
 
@@ -30,6 +28,33 @@ pub fn NoteDetail() -> impl IntoView {
         }
     });
 
+    // Fetch the parent note when viewing a subnote directly, for the breadcrumb trail
+    let (parent_note_data, set_parent_note_data) = signal(None::<Note>);
+    let parent_note_id = Memo::new(move |_| {
+        note_data.with(|data| match data {
+            Some(Ok(note)) => note.parent_id.clone(),
+            _ => None,
+        })
+    });
+
+    Effect::new(move || match parent_note_id.get() {
+        Some(parent_id) => {
+            spawn_local(async move {
+                set_parent_note_data.set(notes::get(&parent_id).await.ok());
+            });
+        }
+        None => set_parent_note_data.set(None),
+    });
+
+    // Breadcrumb segment for the parent note (if any)
+    let parent_crumb = move |base: &str| {
+        parent_note_data.get().map(|parent| {
+            BreadcrumbItem::new(parent.title.clone())
+                .with_id(parent.id.clone())
+                .with_href(format!("{}/{}", base, parent.id))
+        })
+    };
+
     // Fetch subnotes to determine if we should show sidebar
     let (subnotes_data, set_subnotes_data) = signal(None::<Result<Vec<Note>, ApiClientError>>);
     let (subnotes_refetch_trigger, _set_subnotes_refetch_trigger) = signal(0u32);
@@ -45,6 +70,7 @@ pub fn NoteDetail() -> impl IntoView {
                 match update {
                     UpdateMessage::NoteUpdated {
                         note_id: updated_id,
+                        ..
                     } if updated_id == current_note_id => {
                         web_sys::console::log_1(
                             &format!("Note {} updated via WebSocket, refetching...", updated_id)
@@ -149,6 +175,7 @@ pub fn NoteDetail() -> impl IntoView {
                 match update {
                     UpdateMessage::NoteUpdated {
                         note_id: updated_id,
+                        ..
                     } if updated_id == current_selected => {
                         web_sys::console::log_1(
                             &format!(
@@ -221,7 +248,7 @@ pub fn NoteDetail() -> impl IntoView {
                                 (note.title.clone(), note.id.clone())
                             };
 
-                            let items = vec![
+                            let mut items = vec![
                                 BreadcrumbItem::new("Projects")
                                     .with_href("/")
                                     .with_name("projects"),
@@ -229,9 +256,9 @@ pub fn NoteDetail() -> impl IntoView {
                                     .with_id(project.id.clone())
                                     .with_href(format!("/projects/{}/notes", project.id))
                                     .with_name(project.id.clone()),
-                                BreadcrumbItem::new(title)
-                                    .with_id(id),
                             ];
+                            items.extend(parent_crumb(&format!("/projects/{}/notes", project.id)));
+                            items.push(BreadcrumbItem::new(title).with_id(id));
                             Some(view! { <Breadcrumb items=items/> })
                         }
                         _ => None
@@ -256,13 +283,13 @@ pub fn NoteDetail() -> impl IntoView {
                     };
 
                     breadcrumb_data.map(|(title, id)| {
-                        let items = vec![
+                        let mut items = vec![
                             BreadcrumbItem::new("Notes")
                                 .with_href("/notes")
                                 .with_name("notes"),
-                            BreadcrumbItem::new(title)
-                                .with_id(id),
                         ];
+                        items.extend(parent_crumb("/notes"));
+                        items.push(BreadcrumbItem::new(title).with_id(id));
                         view! { <Breadcrumb items=items/> }
                     })
                 }
@@ -343,9 +370,7 @@ pub fn NoteDetail() -> impl IntoView {
 
                                                                                 // Scrollable content
                                                                                 <div class="flex-1 overflow-y-auto min-h-0 pt-6">
-                                                                                    <div class="prose prose-invert max-w-none">
-                                                                                        <MarkdownContent content=selected_note.content.clone()/>
-                                                                                    </div>
+                                                                                    <NoteBody content=selected_note.content.clone()/>
                                                                                 </div>
                                                                             </div>
                                                                         }.into_any()
@@ -402,9 +427,7 @@ pub fn NoteDetail() -> impl IntoView {
 
                                                 // Scrollable content
                                                 <div class="flex-1 overflow-y-auto min-h-0 pt-6">
-                                                    <div class="prose prose-invert max-w-none">
-                                                        <MarkdownContent content=note.content.clone()/>
-                                                    </div>
+                                                    <NoteBody content=note.content.clone()/>
                                                 </div>
                                             </div>
                                         }.into_any()
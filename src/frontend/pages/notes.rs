@@ -1,13 +1,14 @@
 use leptos::prelude::*;
 use leptos::task::spawn_local;
-use leptos_router::hooks::use_location;
+use leptos_router::hooks::{use_location, use_navigate, use_query_map};
 
 use crate::api::{ApiClientError, QueryBuilder};
 use crate::components::{
-    Breadcrumb, BreadcrumbItem, NoteCard, Pagination, SearchInput, SortControls,
+    Breadcrumb, BreadcrumbItem, NoteCard, NoteTypeFilter, Pagination, SearchInput, SortControls,
+    TagFilter,
 };
-use crate::hooks::{use_pagination, use_search, use_sort};
-use crate::models::{Note, Paginated, UpdateMessage};
+use crate::hooks::{build_url_with_params, use_pagination, use_search, use_sort, use_tags};
+use crate::models::{Note, NoteType, Paginated, UpdateMessage};
 use crate::websocket::use_websocket_updates;
 
 #[component]
@@ -22,11 +23,43 @@ fn NotesList() -> impl IntoView {
     const PAGE_SIZE: usize = 12;
 
     let location = use_location();
+    let navigate = use_navigate();
+    let query = use_query_map();
 
     // Hooks for search, sort, and pagination
     let pagination = use_pagination();
     let search = use_search();
     let sort = use_sort("last_activity_at", "desc");
+    let tag_filter = use_tags();
+
+    // Note type filter - read from URL (?type=note|subnote|all), defaults to top-level notes
+    let parse_note_type = |value: Option<String>| match value.as_deref() {
+        Some("all") => None,
+        Some(v) => v.parse::<NoteType>().ok().or(Some(NoteType::Note)),
+        None => Some(NoteType::Note),
+    };
+    let note_type = RwSignal::new(parse_note_type(query.read().get("type")));
+
+    // Watch for URL changes to sync the type filter
+    Effect::new(move |_| {
+        note_type.set(parse_note_type(query.get().get("type")));
+    });
+
+    let location_for_type = location.clone();
+    let on_note_type_change = Callback::new(move |new_type: Option<NoteType>| {
+        note_type.set(new_type);
+        let value = match new_type {
+            Some(NoteType::Note) => None,
+            Some(t) => Some(t.as_str().to_string()),
+            None => Some("all".to_string()),
+        };
+        let url = build_url_with_params(
+            location_for_type.pathname.get(),
+            query.read().clone(),
+            [("type".to_string(), value), ("page".to_string(), None)].into(),
+        );
+        navigate(&url, Default::default());
+    });
 
     let (notes_data, set_notes_data) = signal(None::<Result<Paginated<Note>, ApiClientError>>);
 
@@ -53,6 +86,8 @@ fn NotesList() -> impl IntoView {
         let current_query = search.search_query.get();
         let current_sort = sort.sort_field.get();
         let current_order = sort.sort_order.get();
+        let current_type = note_type.get();
+        let current_tags = tag_filter.tags.get();
         let trigger = refetch_trigger.get();
 
         // Log for debugging with all dependency values
@@ -79,7 +114,8 @@ fn NotesList() -> impl IntoView {
                 .offset(offset)
                 .sort(current_sort)
                 .order(current_order)
-                .param("type", "note");
+                .note_type(current_type)
+                .tags(&current_tags);
 
             if !current_query.trim().is_empty() {
                 builder = builder.search(current_query);
@@ -113,6 +149,10 @@ fn NotesList() -> impl IntoView {
                         placeholder="Search notes..."
                     />
                 </div>
+                <NoteTypeFilter
+                    note_type=note_type.read_only()
+                    on_change=on_note_type_change
+                />
                 <SortControls
                     sort_field=sort.sort_field
                     sort_order=sort.sort_order
@@ -125,6 +165,13 @@ fn NotesList() -> impl IntoView {
                     ]
                 />
             </div>
+            <div class="mb-6">
+                <TagFilter
+                    tags=tag_filter.tags
+                    on_add=tag_filter.on_add
+                    on_remove=tag_filter.on_remove
+                />
+            </div>
 
             {move || {
                 match notes_data.get() {
@@ -6,9 +6,9 @@ use thaw::*;
 use crate::api::{ApiClientError, QueryBuilder, projects};
 use crate::components::{
     Breadcrumb, BreadcrumbItem, ExternalRefLink, NoteCard, Pagination, RepoCard, SearchInput,
-    SkillCard, SkillDetailModal, SortControls, TaskListCard, TaskListDetailModal,
+    SkillCard, SkillDetailModal, SortControls, TagFilter, TaskListCard, TaskListDetailModal,
 };
-use crate::hooks::{build_url_with_params, use_pagination, use_search, use_sort};
+use crate::hooks::{build_url_with_params, use_pagination, use_search, use_sort, use_tags};
 use crate::models::{Note, Paginated, Project, Repo, Skill, TaskList, UpdateMessage};
 use crate::websocket::use_websocket_updates;
 
@@ -53,6 +53,9 @@ pub fn ProjectDetail() -> impl IntoView {
     let note_search = use_search();
     let note_sort = use_sort("last_activity_at", "desc");
 
+    // Tag filter shared by the task lists and notes tabs (?tags=a,b)
+    let tag_filter = use_tags();
+
     // Hooks for repos tab
     let repo_pagination = use_pagination();
     let repo_search = use_search();
@@ -214,6 +217,7 @@ pub fn ProjectDetail() -> impl IntoView {
         let current_page = task_list_pagination.page.get();
         let current_sort = task_list_sort.sort_field.get();
         let current_order = task_list_sort.sort_order.get();
+        let current_tags = tag_filter.tags.get();
         let _ = task_list_refetch_trigger.get();
         if !id.is_empty() {
             spawn_local(async move {
@@ -229,6 +233,7 @@ pub fn ProjectDetail() -> impl IntoView {
                     .offset(offset)
                     .sort(current_sort)
                     .order(current_order)
+                    .tags(&current_tags)
                     .param("project_id", id);
 
                 if let Some(stat) = status {
@@ -252,6 +257,7 @@ pub fn ProjectDetail() -> impl IntoView {
         let current_page = note_pagination.page.get();
         let current_sort = note_sort.sort_field.get();
         let current_order = note_sort.sort_order.get();
+        let current_tags = tag_filter.tags.get();
         let _ = note_refetch_trigger.get();
         if !id.is_empty() {
             spawn_local(async move {
@@ -267,6 +273,7 @@ pub fn ProjectDetail() -> impl IntoView {
                     .offset(offset)
                     .sort(current_sort)
                     .order(current_order)
+                    .tags(&current_tags)
                     .param("project_id", id)
                     .param("type", "note");
 
@@ -508,6 +515,13 @@ pub fn ProjectDetail() -> impl IntoView {
                                                             ]
                                                         />
                                                     </div>
+                                                    <div class="mb-6">
+                                                        <TagFilter
+                                                            tags=tag_filter.tags
+                                                            on_add=tag_filter.on_add
+                                                            on_remove=tag_filter.on_remove
+                                                        />
+                                                    </div>
 
                                                     {move || match task_lists_data.get() {
                                                         None => {
@@ -604,6 +618,13 @@ pub fn ProjectDetail() -> impl IntoView {
                                                             ]
                                                         />
                                                     </div>
+                                                    <div class="mb-6">
+                                                        <TagFilter
+                                                            tags=tag_filter.tags
+                                                            on_add=tag_filter.on_add
+                                                            on_remove=tag_filter.on_remove
+                                                        />
+                                                    </div>
 
                                                     {move || match notes_data.get() {
                                                         None => {
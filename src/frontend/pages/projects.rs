@@ -5,8 +5,9 @@ use leptos_router::hooks::use_location;
 use crate::api::{ApiClientError, QueryBuilder};
 use crate::components::{
     Breadcrumb, BreadcrumbItem, CopyableId, ExternalRefLink, Pagination, SearchInput, SortControls,
+    TagFilter,
 };
-use crate::hooks::{use_pagination, use_search, use_sort};
+use crate::hooks::{use_pagination, use_search, use_sort, use_tags};
 use crate::models::{Paginated, Project, UpdateMessage};
 use crate::websocket::use_websocket_updates;
 
@@ -16,10 +17,11 @@ pub fn Projects() -> impl IntoView {
 
     let location = use_location();
 
-    // Hooks for search, sort, and pagination
+    // Hooks for search, sort, tag filter, and pagination
     let pagination = use_pagination();
     let search = use_search();
     let sort = use_sort("updated_at", "desc");
+    let tag_filter = use_tags();
 
     // Get page state for breadcrumb navigation
     let page_state = use_context::<crate::breadcrumb_state::BreadcrumbPageState>()
@@ -60,6 +62,7 @@ pub fn Projects() -> impl IntoView {
         let current_query = search.search_query.get();
         let current_sort = sort.sort_field.get();
         let current_order = sort.sort_order.get();
+        let current_tags = tag_filter.tags.get();
         let _ = refetch_trigger.get();
 
         set_projects_data.set(None);
@@ -71,7 +74,8 @@ pub fn Projects() -> impl IntoView {
                 .limit(PAGE_SIZE)
                 .offset(offset)
                 .sort(current_sort)
-                .order(current_order);
+                .order(current_order)
+                .tags(&current_tags);
 
             if !current_query.trim().is_empty() {
                 builder = builder.search(current_query);
@@ -109,6 +113,13 @@ pub fn Projects() -> impl IntoView {
                     ]
                 />
             </div>
+            <div class="mb-6">
+                <TagFilter
+                    tags=tag_filter.tags
+                    on_add=tag_filter.on_add
+                    on_remove=tag_filter.on_remove
+                />
+            </div>
 
             {move || match projects_data.get() {
                 None => {
@@ -125,10 +136,12 @@ pub fn Projects() -> impl IntoView {
                     if paginated.items.is_empty() {
                         view! {
                             <p class="text-ctp-subtext0">
-                                {if search.search_query.get().trim().is_empty() {
+                                {if search.search_query.get().trim().is_empty()
+                                    && tag_filter.tags.get().is_empty()
+                                {
                                     "No projects found. Create one to get started!"
                                 } else {
-                                    "No projects found matching your search."
+                                    "No projects found matching your filters."
                                 }}
                             </p>
                         }
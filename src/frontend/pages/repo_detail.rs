@@ -4,18 +4,12 @@ use leptos_router::hooks::use_params_map;
 use thaw::Tooltip;
 use wasm_bindgen::prelude::*;
 
-use crate::api::{ApiClientError, graph, projects, repos};
+use crate::api::{ApiClientError, api_base, graph, projects, repos};
 use crate::components::{Breadcrumb, BreadcrumbItem, CopyableId};
 use crate::models::{Project, Repo, UpdateMessage};
 use crate::utils::extract_repo_name;
 use crate::websocket::use_websocket_updates;
 
-// Import API_BASE constant for graph initialization
-#[cfg(debug_assertions)]
-const API_BASE: &str = "/dev/api/v1";
-#[cfg(not(debug_assertions))]
-const API_BASE: &str = "/api/v1";
-
 #[wasm_bindgen(
     inline_js = "export function copy_to_clipboard(text) { navigator.clipboard.writeText(text); }"
 )]
@@ -350,7 +344,7 @@ fn GraphViewer(repo_id: String) -> impl IntoView {
             let rid = repo_id_for_init.clone();
             set_timeout(
                 move || {
-                    if init_graph(container_id, &data, &rid, API_BASE) {
+                    if init_graph(container_id, &data, &rid, api_base()) {
                         let kinds_json = get_kinds(container_id);
                         if let Ok(kinds) = serde_json::from_str::<Vec<KindInfo>>(&kinds_json) {
                             set_legend_kinds.set(kinds);
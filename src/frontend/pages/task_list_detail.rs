@@ -2,7 +2,7 @@ use leptos::prelude::*;
 use leptos::task::spawn_local;
 use leptos_router::hooks::use_params_map;
 
-use crate::api::{ApiClientError, projects, task_lists};
+use crate::api::{ApiClientError, RequestScope, projects, task_lists};
 use crate::components::{Breadcrumb, BreadcrumbItem, TaskListContent};
 use crate::models::{Project, TaskList, UpdateMessage};
 use crate::websocket::use_websocket_updates;
@@ -31,30 +31,40 @@ pub fn TaskListDetail() -> impl IntoView {
         }
     });
 
-    // Fetch project and task list when params change or refetch trigger fires
+    // Pending fetches are aborted when navigating away from the page
+    let requests = RequestScope::new();
+
+    // Fetch task list when params change or refetch trigger fires
     Effect::new(move || {
         let params = params.get();
         let _ = refetch_trigger.get(); // Track refetch trigger
 
-        if let (Some(project_id), Some(task_list_id)) =
-            (params.get("project_id"), params.get("task_list_id"))
-        {
-            let project_id = project_id.to_string();
+        if let Some(task_list_id) = params.get("task_list_id") {
             let task_list_id = task_list_id.to_string();
 
-            // Fetch project
-            spawn_local({
-                let project_id = project_id.clone();
-                async move {
-                    let result = projects::get(&project_id).await;
-                    set_project_data.set(Some(result));
+            spawn_local(async move {
+                if let Some(result) = requests.run(task_lists::get(&task_list_id)).await {
+                    set_task_list_data.set(Some(result));
                 }
             });
+        }
+    });
 
-            // Fetch task list
+    // Fetch the owning project from the loaded task list, so the breadcrumb
+    // follows the actual parent relationship rather than the URL
+    let owning_project_id = Memo::new(move |_| {
+        task_list_data.with(|data| match data {
+            Some(Ok(task_list)) => Some(task_list.project_id.clone()),
+            _ => None,
+        })
+    });
+
+    Effect::new(move || {
+        if let Some(project_id) = owning_project_id.get() {
             spawn_local(async move {
-                let result = task_lists::get(&task_list_id).await;
-                set_task_list_data.set(Some(result));
+                if let Some(result) = requests.run(projects::get(&project_id)).await {
+                    set_project_data.set(Some(result));
+                }
             });
         }
     });
@@ -272,11 +272,28 @@ impl ThemeColors {
 
 const THEME_STORAGE_KEY: &str = "catppuccin-theme";
 
-/// Load theme from localStorage
+/// Load theme from localStorage, falling back to the system color scheme
 pub fn load_theme_from_storage() -> CatppuccinTheme {
     use gloo_storage::{LocalStorage, Storage};
 
-    LocalStorage::get(THEME_STORAGE_KEY).unwrap_or_default()
+    LocalStorage::get(THEME_STORAGE_KEY).unwrap_or_else(|_| system_theme())
+}
+
+/// Pick Latte or Mocha based on the `prefers-color-scheme` media query
+fn system_theme() -> CatppuccinTheme {
+    let prefers_light = web_sys::window()
+        .and_then(|w| {
+            w.match_media("(prefers-color-scheme: light)")
+                .ok()
+                .flatten()
+        })
+        .is_some_and(|mql| mql.matches());
+
+    if prefers_light {
+        CatppuccinTheme::Latte
+    } else {
+        CatppuccinTheme::Mocha
+    }
 }
 
 /// Save theme to localStorage
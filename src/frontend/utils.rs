@@ -39,3 +39,14 @@ pub fn extract_repo_name(remote: &str) -> String {
     // Fallback: return original
     remote.to_string()
 }
+
+/// Shorten `s` to at most `max_chars` characters, appending `...` when cut.
+///
+/// Counts `char`s rather than bytes, so multibyte text (emoji, CJK) is never
+/// split mid-character.
+pub fn truncate_chars(s: &str, max_chars: usize) -> String {
+    match s.char_indices().nth(max_chars) {
+        Some((end, _)) => format!("{}...", &s[..end]),
+        None => s.to_string(),
+    }
+}
@@ -20,9 +20,9 @@ use crate::db::Database;
 use crate::sync::RealGit;
 
 use super::tools::{
-    CodeAnalysisTools, CodeQueryTools, NoteTools, ProjectTools, RepoTools, SkillTools, SyncTools,
-    TaskListTools, TaskTools, notes::*, projects::*, repos::*, skills::*, sync::*, task_lists::*,
-    tasks::*,
+    CodeAnalysisTools, CodeQueryTools, EntityTools, LinkTools, NoteTools, ProjectTools, RepoTools,
+    SkillTools, SyncTools, TaskListTools, TaskTools, entities::*, links::*, notes::*, projects::*,
+    repos::*, skills::*, sync::*, task_lists::*, tasks::*,
 };
 
 /// Main MCP server coordinator
@@ -44,6 +44,8 @@ use super::tools::{
 /// - TaskTools: Task operations
 /// - NoteTools: Note operations
 /// - SkillTools: Skill operations
+/// - LinkTools: Relationships between entities
+/// - EntityTools: Entity schemas
 /// - CodeAnalysisTools: Code analysis operations
 pub struct McpServer<D: Database> {
     project_tools: ProjectTools<D>,
@@ -52,6 +54,8 @@ pub struct McpServer<D: Database> {
     task_tools: TaskTools<D>,
     note_tools: NoteTools<D>,
     skill_tools: SkillTools<D>,
+    link_tools: LinkTools<D>,
+    entity_tools: EntityTools,
     sync_tools: SyncTools<D, RealGit>,
     code_analysis_tools: CodeAnalysisTools<D>,
     code_query_tools: CodeQueryTools,
@@ -87,6 +91,8 @@ impl<D: Database + 'static> McpServer<D> {
             task_tools: TaskTools::new(Arc::clone(&db), notifier.clone()),
             note_tools: NoteTools::new(Arc::clone(&db), notifier.clone()),
             skill_tools: SkillTools::new(Arc::clone(&db), notifier.clone(), skills_dir),
+            link_tools: LinkTools::new(Arc::clone(&db), notifier.clone()),
+            entity_tools: EntityTools::new(),
             sync_tools: SyncTools::with_real_git(Arc::clone(&db)),
             code_analysis_tools: CodeAnalysisTools::new(
                 Arc::clone(&db),
@@ -98,11 +104,17 @@ impl<D: Database + 'static> McpServer<D> {
         }
     }
 
+    /// Complete a parent task once all of its subtasks are finished
+    pub fn with_auto_complete_parents(mut self, enabled: bool) -> Self {
+        self.task_tools = self.task_tools.with_auto_complete_parents(enabled);
+        self
+    }
+
     // =========================================================================
     // Project Tools
     // =========================================================================
 
-    #[tool(description = "List projects with pagination (default: 10, max: 20)")]
+    #[tool(description = "List projects with pagination (default: 20, max: 100)")]
     pub async fn list_projects(
         &self,
         params: Parameters<ListProjectsParams>,
@@ -118,6 +130,16 @@ impl<D: Database + 'static> McpServer<D> {
         self.project_tools.get_project(params).await
     }
 
+    #[tool(
+        description = "Get a project with its linked repos, task lists (with task stats) and most recent notes in one call"
+    )]
+    pub async fn get_project_context(
+        &self,
+        params: Parameters<GetProjectContextParams>,
+    ) -> Result<CallToolResult, McpError> {
+        self.project_tools.get_project_context(params).await
+    }
+
     #[tool(description = "Create a new project")]
     pub async fn create_project(
         &self,
@@ -146,7 +168,7 @@ impl<D: Database + 'static> McpServer<D> {
     // Repository Tools
     // =========================================================================
 
-    #[tool(description = "List repositories with pagination (default: 10, max: 20)")]
+    #[tool(description = "List repositories with pagination (default: 20, max: 100)")]
     pub async fn list_repos(
         &self,
         params: Parameters<ListReposParams>,
@@ -238,6 +260,14 @@ impl<D: Database + 'static> McpServer<D> {
         self.task_list_tools.get_task_list_stats(params).await
     }
 
+    #[tool(description = "Get task statistics rolled up across a project's task lists")]
+    pub async fn get_project_task_stats(
+        &self,
+        params: Parameters<GetProjectTaskStatsParams>,
+    ) -> Result<CallToolResult, McpError> {
+        self.task_list_tools.get_project_task_stats(params).await
+    }
+
     // =========================================================================
     // Task Tools
     // =========================================================================
@@ -304,6 +334,14 @@ impl<D: Database + 'static> McpServer<D> {
         self.note_tools.list_notes(params).await
     }
 
+    #[tool(description = "Full-text search notes, returning IDs, titles and matched fragments")]
+    pub async fn search_notes(
+        &self,
+        params: Parameters<SearchNotesParams>,
+    ) -> Result<CallToolResult, McpError> {
+        self.note_tools.search_notes(params).await
+    }
+
     #[tool(description = "Create a new note")]
     pub async fn create_note(
         &self,
@@ -330,6 +368,16 @@ impl<D: Database + 'static> McpServer<D> {
         self.note_tools.read_note(params).await
     }
 
+    #[tool(
+        description = "Get a note's content in pages (max_chars/offset, follow next_offset), or a summary with title, tags and an excerpt."
+    )]
+    pub async fn get_note(
+        &self,
+        params: Parameters<GetNoteParams>,
+    ) -> Result<CallToolResult, McpError> {
+        self.note_tools.get_note(params).await
+    }
+
     #[tool(
         description = "Edit a note - update metadata (title, tags, relationships) and/or apply line-range patches to content. All fields optional. Patches are automatically sorted, validated, and applied in reverse order to maintain accurate line numbers."
     )]
@@ -368,6 +416,38 @@ impl<D: Database + 'static> McpServer<D> {
         self.skill_tools.update_skill(params).await
     }
 
+    // =========================================================================
+    // Relationship Tools
+    // =========================================================================
+
+    #[tool(
+        description = "Link two entities (project→repo/note/skill, repo→note/task_list). Idempotent."
+    )]
+    pub async fn link(&self, params: Parameters<LinkParams>) -> Result<CallToolResult, McpError> {
+        self.link_tools.link(params).await
+    }
+
+    #[tool(
+        description = "Unlink two entities (project→repo/note/skill, repo→note/task_list). Idempotent."
+    )]
+    pub async fn unlink(&self, params: Parameters<LinkParams>) -> Result<CallToolResult, McpError> {
+        self.link_tools.unlink(params).await
+    }
+
+    // =========================================================================
+    // Entity Tools
+    // =========================================================================
+
+    #[tool(
+        description = "Get the JSON schema of an entity type (project, repo, task_list, task, note, skill): fields, types, required fields and allowed values"
+    )]
+    pub async fn describe_entity(
+        &self,
+        params: Parameters<DescribeEntityParams>,
+    ) -> Result<CallToolResult, McpError> {
+        self.entity_tools.describe_entity(params).await
+    }
+
     // =========================================================================
     // Sync Tools
     // =========================================================================
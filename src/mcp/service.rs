@@ -25,6 +25,8 @@ use super::server::McpServer;
 /// * `notifier` - Change notifier for WebSocket broadcasts
 /// * `skills_dir` - Directory where skill attachments are extracted
 /// * `analysis_db` - Shared SurrealDB connection for code analysis
+/// * `tracker` - Analysis progress tracker
+/// * `auto_complete_parents` - Complete parent tasks once all subtasks are finished
 /// * `cancellation_token` - Token for graceful shutdown
 ///
 /// # Returns
@@ -46,7 +48,8 @@ use super::server::McpServer;
 /// let temp_dir = TempDir::new().unwrap();
 /// let skills_dir = temp_dir.path().join("skills");
 /// let analysis_db = Arc::new(context::a6s::surrealdb::init_shared_db().await?);
-/// let mcp_service = create_mcp_service(db, notifier, skills_dir, analysis_db, ct);
+/// let tracker = context::a6s::tracker::AnalysisTracker::new(notifier.clone());
+/// let mcp_service = create_mcp_service(db, notifier, skills_dir, analysis_db, tracker, false, ct);
 /// }
 /// ```
 pub fn create_mcp_service<D: Database + 'static>(
@@ -55,6 +58,7 @@ pub fn create_mcp_service<D: Database + 'static>(
     skills_dir: std::path::PathBuf,
     analysis_db: Arc<surrealdb::SurrealDbConnection>,
     tracker: AnalysisTracker,
+    auto_complete_parents: bool,
     cancellation_token: CancellationToken,
 ) -> StreamableHttpService<McpServer<D>, LocalSessionManager> {
     let db = db.into();
@@ -68,7 +72,8 @@ pub fn create_mcp_service<D: Database + 'static>(
             skills_dir.clone(),
             Arc::clone(&analysis_db),
             tracker.clone(),
-        );
+        )
+        .with_auto_complete_parents(auto_complete_parents);
         Ok(server)
     };
 
@@ -51,6 +51,7 @@ async fn test_create_mcp_service() {
         temp_dir.path().join("skills"),
         analysis_db,
         AnalysisTracker::new(ChangeNotifier::new()),
+        false,
         ct,
     );
 
@@ -80,6 +81,7 @@ async fn test_mcp_service_with_router() {
         temp_dir.path().join("skills"),
         analysis_db,
         AnalysisTracker::new(ChangeNotifier::new()),
+        false,
         ct,
     );
 
@@ -121,6 +123,7 @@ async fn test_mcp_session_management_configured() {
         temp_dir.path().join("skills"),
         analysis_db,
         AnalysisTracker::new(ChangeNotifier::new()),
+        false,
         ct,
     );
     let app = Router::new().nest_service("/mcp", service);
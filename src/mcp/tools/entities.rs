@@ -0,0 +1,110 @@
+//! Entity schema tool implementations
+//!
+//! Describes the shape of stored entities so agents can build valid
+//! create/update calls without guessing.
+//! Follows Single Responsibility Principle (SRP).
+
+use crate::db::{Note, Project, Repo, Skill, Task, TaskList};
+use rmcp::{
+    ErrorData as McpError,
+    handler::server::{router::tool::ToolRouter, wrapper::Parameters},
+    model::*,
+    schemars,
+    schemars::{JsonSchema, generate::SchemaSettings},
+    tool, tool_router,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct DescribeEntityParams {
+    #[schemars(description = "Entity type: project, repo, task_list, task, note or skill")]
+    pub entity_type: String,
+}
+
+/// Entity types accepted by `describe_entity`
+const ENTITY_TYPES: &[&str] = &["project", "repo", "task_list", "task", "note", "skill"];
+
+/// JSON schema for `T` with nested types (such as status enums) inlined,
+/// so each field carries its allowed values directly.
+fn schema_of<T: JsonSchema>() -> serde_json::Value {
+    let generator = SchemaSettings::draft2020_12()
+        .with(|settings| settings.inline_subschemas = true)
+        .into_generator();
+    serde_json::to_value(generator.into_root_schema_for::<T>()).unwrap_or_default()
+}
+
+/// Entity schema tools
+///
+/// Stateless: the schemas are derived from the model types.
+///
+/// # SOLID Principles
+/// - **Single Responsibility**: Only describes entity shapes
+#[derive(Clone)]
+pub struct EntityTools {
+    tool_router: ToolRouter<Self>,
+}
+
+impl Default for EntityTools {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[tool_router]
+impl EntityTools {
+    /// Create new EntityTools
+    pub fn new() -> Self {
+        Self {
+            tool_router: Self::tool_router(),
+        }
+    }
+
+    /// Get the tool router for this handler
+    pub fn router(&self) -> &ToolRouter<Self> {
+        &self.tool_router
+    }
+
+    /// Describe the fields of an entity type
+    #[tool(
+        description = "Get the JSON schema of an entity type (fields, types, required fields, allowed enum values such as task statuses, priority range). Use before creating or updating an entity."
+    )]
+    pub async fn describe_entity(
+        &self,
+        params: Parameters<DescribeEntityParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let entity_type = params.0.entity_type.as_str();
+        let schema = match entity_type {
+            "project" => schema_of::<Project>(),
+            "repo" => schema_of::<Repo>(),
+            "task_list" => schema_of::<TaskList>(),
+            "task" => schema_of::<Task>(),
+            "note" => schema_of::<Note>(),
+            "skill" => schema_of::<Skill>(),
+            _ => {
+                return Err(McpError::invalid_params(
+                    "unknown_entity_type",
+                    Some(json!({
+                        "message": format!("Unknown entity type '{}'", entity_type),
+                        "supported": ENTITY_TYPES
+                    })),
+                ));
+            }
+        };
+
+        let content = serde_json::to_string_pretty(&json!({
+            "entity_type": entity_type,
+            "schema": schema
+        }))
+        .map_err(|e| {
+            McpError::internal_error(
+                "serialization_error",
+                Some(json!({
+                    "message": e.to_string()
+                })),
+            )
+        })?;
+
+        Ok(CallToolResult::success(vec![ContentBlock::text(content)]))
+    }
+}
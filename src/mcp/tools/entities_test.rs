@@ -0,0 +1,72 @@
+//! Tests for entity schema MCP tools
+
+use crate::mcp::tools::entities::*;
+use rmcp::handler::server::wrapper::Parameters;
+use rmcp::model::{CallToolResult, ContentBlock};
+
+fn parse(result: CallToolResult) -> serde_json::Value {
+    match &result.content[0] {
+        ContentBlock::Text(text) => serde_json::from_str(&text.text).unwrap(),
+        _ => panic!("Expected text content"),
+    }
+}
+
+fn params(entity_type: &str) -> Parameters<DescribeEntityParams> {
+    Parameters(DescribeEntityParams {
+        entity_type: entity_type.to_string(),
+    })
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_describe_task_lists_statuses_and_priority_range() {
+    let tools = EntityTools::new();
+
+    let described = parse(
+        tools
+            .describe_entity(params("task"))
+            .await
+            .expect("describe_entity should succeed"),
+    );
+    assert_eq!(described["entity_type"], "task");
+
+    let schema = &described["schema"];
+    assert_eq!(
+        schema["properties"]["status"]["enum"],
+        serde_json::json!([
+            "backlog",
+            "todo",
+            "in_progress",
+            "review",
+            "done",
+            "cancelled"
+        ])
+    );
+
+    let priority = &schema["properties"]["priority"];
+    assert_eq!(priority["type"], serde_json::json!(["integer", "null"]));
+    assert_eq!(priority["minimum"], 1);
+    assert_eq!(priority["maximum"], 5);
+
+    let required = schema["required"].as_array().unwrap();
+    assert!(required.contains(&serde_json::json!("title")));
+    assert!(required.contains(&serde_json::json!("list_id")));
+    assert!(!required.contains(&serde_json::json!("priority")));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_describe_unknown_entity_lists_supported_types() {
+    let tools = EntityTools::new();
+
+    let err = tools
+        .describe_entity(params("widget"))
+        .await
+        .expect_err("unknown entity type should fail");
+    assert_eq!(err.message, "unknown_entity_type");
+    let supported = &err.data.unwrap()["supported"];
+    assert!(
+        supported
+            .as_array()
+            .unwrap()
+            .contains(&serde_json::json!("task_list"))
+    );
+}
@@ -0,0 +1,274 @@
+//! Relationship tool implementations
+//!
+//! Handles MCP tools for linking and unlinking entities.
+//! Follows Single Responsibility Principle (SRP).
+
+use crate::api::notifier::{ChangeNotifier, UpdateMessage};
+use crate::db::{
+    Database, NoteRepository, ProjectRepository, RepoRepository, SkillRepository,
+    TaskListRepository,
+};
+use crate::mcp::tools::map_db_error;
+use rmcp::{
+    ErrorData as McpError,
+    handler::server::{router::tool::ToolRouter, wrapper::Parameters},
+    model::*,
+    schemars,
+    schemars::JsonSchema,
+    tool, tool_router,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::sync::Arc;
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct LinkParams {
+    #[schemars(description = "Parent entity type: project or repo")]
+    pub parent_type: String,
+    #[schemars(description = "Parent entity ID")]
+    pub parent_id: String,
+    #[schemars(
+        description = "Child entity type. Supported pairs: project→repo, project→note, project→skill, repo→note, repo→task_list"
+    )]
+    pub child_type: String,
+    #[schemars(description = "Child entity ID")]
+    pub child_id: String,
+}
+
+/// Supported parent→child relationships
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Relation {
+    ProjectRepo,
+    ProjectNote,
+    ProjectSkill,
+    RepoNote,
+    RepoTaskList,
+}
+
+/// Human-readable list of supported pairs for error messages
+const SUPPORTED_PAIRS: &[&str] = &[
+    "project→repo",
+    "project→note",
+    "project→skill",
+    "repo→note",
+    "repo→task_list",
+];
+
+impl Relation {
+    fn resolve(parent_type: &str, child_type: &str) -> Option<Self> {
+        match (parent_type, child_type) {
+            ("project", "repo") => Some(Self::ProjectRepo),
+            ("project", "note") => Some(Self::ProjectNote),
+            ("project", "skill") => Some(Self::ProjectSkill),
+            ("repo", "note") => Some(Self::RepoNote),
+            ("repo", "task_list") => Some(Self::RepoTaskList),
+            _ => None,
+        }
+    }
+}
+
+/// Add or remove `id` from `ids`, returning whether anything changed
+fn toggle(ids: &mut Vec<String>, id: &str, link: bool) -> bool {
+    let present = ids.iter().any(|existing| existing == id);
+    match (link, present) {
+        (true, false) => {
+            ids.push(id.to_string());
+            true
+        }
+        (false, true) => {
+            ids.retain(|existing| existing != id);
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Relationship management tools
+///
+/// Generic over `D: Database` for zero-cost abstraction.
+///
+/// # SOLID Principles
+/// - **Single Responsibility**: Only handles relationships between entities
+/// - **Dependency Inversion**: Depends on Database trait
+#[derive(Clone)]
+pub struct LinkTools<D: Database> {
+    db: Arc<D>,
+    notifier: ChangeNotifier,
+    tool_router: ToolRouter<Self>,
+}
+
+#[tool_router]
+impl<D: Database + 'static> LinkTools<D> {
+    /// Create new LinkTools with database
+    pub fn new(db: Arc<D>, notifier: ChangeNotifier) -> Self {
+        Self {
+            db,
+            notifier,
+            tool_router: Self::tool_router(),
+        }
+    }
+
+    /// Get the tool router for this handler
+    pub fn router(&self) -> &ToolRouter<Self> {
+        &self.tool_router
+    }
+
+    /// Link a child entity to a parent (no-op if already linked)
+    #[tool(
+        description = "Link two entities (e.g. a note to a project). Idempotent. Returns the child's parent IDs of that type."
+    )]
+    pub async fn link(&self, params: Parameters<LinkParams>) -> Result<CallToolResult, McpError> {
+        self.apply(params.0, true).await
+    }
+
+    /// Unlink a child entity from a parent (no-op if not linked)
+    #[tool(
+        description = "Unlink two entities (e.g. a note from a project). Idempotent. Returns the child's remaining parent IDs of that type."
+    )]
+    pub async fn unlink(&self, params: Parameters<LinkParams>) -> Result<CallToolResult, McpError> {
+        self.apply(params.0, false).await
+    }
+}
+
+impl<D: Database + 'static> LinkTools<D> {
+    async fn apply(&self, params: LinkParams, link: bool) -> Result<CallToolResult, McpError> {
+        let relation =
+            Relation::resolve(&params.parent_type, &params.child_type).ok_or_else(|| {
+                McpError::invalid_params(
+                    "unsupported_relationship",
+                    Some(json!({
+                        "message": format!(
+                            "Cannot link {} to {}",
+                            params.child_type, params.parent_type
+                        ),
+                        "supported": SUPPORTED_PAIRS
+                    })),
+                )
+            })?;
+
+        // Make sure the parent exists so a bad ID is reported by name
+        match relation {
+            Relation::ProjectRepo | Relation::ProjectNote | Relation::ProjectSkill => {
+                self.db
+                    .projects()
+                    .get(&params.parent_id)
+                    .await
+                    .map_err(map_db_error)?;
+            }
+            Relation::RepoNote | Relation::RepoTaskList => {
+                self.db
+                    .repos()
+                    .get(&params.parent_id)
+                    .await
+                    .map_err(map_db_error)?;
+            }
+        }
+
+        let (changed, parent_ids) = match relation {
+            Relation::ProjectRepo => {
+                let mut repo = self
+                    .db
+                    .repos()
+                    .get(&params.child_id)
+                    .await
+                    .map_err(map_db_error)?;
+                let changed = toggle(&mut repo.project_ids, &params.parent_id, link);
+                if changed {
+                    self.db.repos().update(&repo).await.map_err(map_db_error)?;
+                    self.notifier.notify(UpdateMessage::RepoUpdated {
+                        repo_id: repo.id.clone(),
+                    });
+                }
+                (changed, repo.project_ids)
+            }
+            Relation::ProjectNote | Relation::RepoNote => {
+                let mut note = self
+                    .db
+                    .notes()
+                    .get(&params.child_id)
+                    .await
+                    .map_err(map_db_error)?;
+                let ids = if relation == Relation::ProjectNote {
+                    &mut note.project_ids
+                } else {
+                    &mut note.repo_ids
+                };
+                let changed = toggle(ids, &params.parent_id, link);
+                if changed {
+                    self.db.notes().update(&note).await.map_err(map_db_error)?;
+                    let field = if relation == Relation::ProjectNote {
+                        "project_ids"
+                    } else {
+                        "repo_ids"
+                    };
+                    self.notifier.notify(UpdateMessage::NoteUpdated {
+                        note_id: note.id.clone(),
+                        changed: vec![field.to_string()],
+                    });
+                }
+                let ids = if relation == Relation::ProjectNote {
+                    note.project_ids
+                } else {
+                    note.repo_ids
+                };
+                (changed, ids)
+            }
+            Relation::ProjectSkill => {
+                let mut skill = self
+                    .db
+                    .skills()
+                    .get(&params.child_id)
+                    .await
+                    .map_err(map_db_error)?;
+                let changed = toggle(&mut skill.project_ids, &params.parent_id, link);
+                if changed {
+                    self.db
+                        .skills()
+                        .update(&skill)
+                        .await
+                        .map_err(map_db_error)?;
+                    self.notifier.notify(UpdateMessage::SkillUpdated {
+                        skill_id: skill.id.clone(),
+                    });
+                }
+                (changed, skill.project_ids)
+            }
+            Relation::RepoTaskList => {
+                let mut task_list = self
+                    .db
+                    .task_lists()
+                    .get(&params.child_id)
+                    .await
+                    .map_err(map_db_error)?;
+                let changed = toggle(&mut task_list.repo_ids, &params.parent_id, link);
+                if changed {
+                    self.db
+                        .task_lists()
+                        .update(&task_list)
+                        .await
+                        .map_err(map_db_error)?;
+                    self.notifier.notify(UpdateMessage::TaskListUpdated {
+                        task_list_id: task_list.id.clone(),
+                    });
+                }
+                (changed, task_list.repo_ids)
+            }
+        };
+
+        let response = json!({
+            "parent_type": params.parent_type,
+            "parent_id": params.parent_id,
+            "child_type": params.child_type,
+            "child_id": params.child_id,
+            "linked": link,
+            "changed": changed,
+            "parent_ids": parent_ids,
+        });
+
+        let content = serde_json::to_string_pretty(&response).map_err(|e| {
+            McpError::internal_error("serialization_error", Some(json!({"error": e.to_string()})))
+        })?;
+
+        Ok(CallToolResult::success(vec![ContentBlock::text(content)]))
+    }
+}
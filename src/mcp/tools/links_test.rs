@@ -0,0 +1,137 @@
+//! Tests for relationship MCP tools
+
+use crate::api::notifier::ChangeNotifier;
+use crate::db::{Database, Note, NoteRepository, Project, ProjectRepository, SqliteDatabase};
+use crate::mcp::tools::links::*;
+use rmcp::handler::server::wrapper::Parameters;
+use rmcp::model::{CallToolResult, ContentBlock};
+use std::sync::Arc;
+
+async fn setup() -> (Arc<SqliteDatabase>, String, String) {
+    let db = SqliteDatabase::in_memory().await.unwrap();
+    db.migrate().unwrap();
+
+    let project = db
+        .projects()
+        .create(&Project {
+            id: String::new(),
+            title: "Linked Project".to_string(),
+            description: None,
+            tags: vec![],
+            external_refs: vec![],
+            repo_ids: vec![],
+            task_list_ids: vec![],
+            note_ids: vec![],
+            created_at: None,
+            updated_at: None,
+        })
+        .await
+        .unwrap();
+
+    let note = db
+        .notes()
+        .create(&Note {
+            id: String::new(),
+            title: "Loose note".to_string(),
+            content: "Not attached to anything yet".to_string(),
+            tags: vec![],
+            parent_id: None,
+            idx: None,
+            repo_ids: vec![],
+            project_ids: vec![],
+            subnote_count: None,
+            created_at: None,
+            updated_at: None,
+        })
+        .await
+        .unwrap();
+
+    (Arc::new(db), project.id, note.id)
+}
+
+fn params(parent_type: &str, parent_id: &str, child_type: &str, child_id: &str) -> LinkParams {
+    LinkParams {
+        parent_type: parent_type.to_string(),
+        parent_id: parent_id.to_string(),
+        child_type: child_type.to_string(),
+        child_id: child_id.to_string(),
+    }
+}
+
+fn parse(result: CallToolResult) -> serde_json::Value {
+    match &result.content[0] {
+        ContentBlock::Text(text) => serde_json::from_str(&text.text).unwrap(),
+        _ => panic!("Expected text content"),
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_link_note_to_project_is_idempotent() {
+    let (db, project_id, note_id) = setup().await;
+    let tools = LinkTools::new(db.clone(), ChangeNotifier::new());
+
+    let first = parse(
+        tools
+            .link(Parameters(params("project", &project_id, "note", &note_id)))
+            .await
+            .expect("link should succeed"),
+    );
+    assert_eq!(first["changed"], true);
+    assert_eq!(first["parent_ids"], serde_json::json!([project_id]));
+
+    let second = parse(
+        tools
+            .link(Parameters(params("project", &project_id, "note", &note_id)))
+            .await
+            .expect("repeated link should succeed"),
+    );
+    assert_eq!(second["changed"], false);
+    assert_eq!(second["parent_ids"], serde_json::json!([project_id]));
+
+    let note = db.notes().get(&note_id).await.unwrap();
+    assert_eq!(note.project_ids, vec![project_id.clone()]);
+
+    let unlinked = parse(
+        tools
+            .unlink(Parameters(params("project", &project_id, "note", &note_id)))
+            .await
+            .expect("unlink should succeed"),
+    );
+    assert_eq!(unlinked["changed"], true);
+    assert_eq!(unlinked["parent_ids"], serde_json::json!([]));
+    assert!(
+        db.notes()
+            .get(&note_id)
+            .await
+            .unwrap()
+            .project_ids
+            .is_empty()
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_link_rejects_unsupported_pair() {
+    let (db, project_id, note_id) = setup().await;
+    let tools = LinkTools::new(db, ChangeNotifier::new());
+
+    // Notes cannot be parents of projects
+    let err = tools
+        .link(Parameters(params("note", &note_id, "project", &project_id)))
+        .await
+        .unwrap_err();
+    assert_eq!(err.message, "unsupported_relationship");
+    assert!(err.data.unwrap()["supported"].is_array());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_link_reports_missing_parent() {
+    let (db, _project_id, note_id) = setup().await;
+    let tools = LinkTools::new(db, ChangeNotifier::new());
+
+    let err = tools
+        .link(Parameters(params("project", "noproj01", "note", &note_id)))
+        .await
+        .unwrap_err();
+    assert_eq!(err.message, "not_found");
+    assert_eq!(err.data.unwrap()["entity_type"], "Project");
+}
@@ -3,32 +3,18 @@
 //! This module contains tool handlers organized by entity type.
 //! Each module follows Single Responsibility Principle (SRP).
 
-// Pagination limits to prevent context overflow
-/// Default limit for list operations (when not specified by user)
-pub(crate) const DEFAULT_LIMIT: usize = 10;
-
-/// Maximum limit for list operations (hard cap to prevent context overflow)
-/// CRITICAL: Keep this small! Large responses break the agent's context window.
-pub(crate) const MAX_LIMIT: usize = 20;
-
-/// Apply limit with default and max cap
-///
-/// Returns the capped limit value, ensuring it's between DEFAULT_LIMIT and MAX_LIMIT.
-/// If user_limit is None, returns DEFAULT_LIMIT.
-/// If user_limit exceeds MAX_LIMIT, returns MAX_LIMIT.
-pub(crate) fn apply_limit(user_limit: Option<usize>) -> usize {
-    match user_limit {
-        Some(limit) => limit.min(MAX_LIMIT),
-        None => DEFAULT_LIMIT,
-    }
-}
-
 pub mod code_analysis;
 #[cfg(test)]
 mod code_analysis_test;
 pub mod code_query;
 #[cfg(test)]
 mod code_query_test;
+pub mod entities;
+#[cfg(test)]
+mod entities_test;
+pub mod links;
+#[cfg(test)]
+mod links_test;
 pub mod notes;
 #[cfg(test)]
 mod notes_test;
@@ -53,6 +39,8 @@ mod tasks_test;
 
 pub use code_analysis::CodeAnalysisTools;
 pub use code_query::CodeQueryTools;
+pub use entities::EntityTools;
+pub use links::LinkTools;
 pub use notes::NoteTools;
 pub use projects::ProjectTools;
 pub use repos::RepoTools;
@@ -115,7 +103,8 @@ pub(crate) fn map_db_error(err: DbError) -> McpError {
                     "foreign_key_violation",
                     Some(serde_json::json!({
                         "message": "Referenced entity does not exist",
-                        "details": message
+                        "details": message,
+                        "help": "Check that referenced IDs (list_id, project_id, parent_id, repo_ids) exist"
                     })),
                 )
             } else if message.contains("UNIQUE constraint failed") {
@@ -138,6 +127,15 @@ pub(crate) fn map_db_error(err: DbError) -> McpError {
                         "field": field
                     })),
                 )
+            } else if message.contains("fts5: syntax error") {
+                McpError::invalid_params(
+                    "invalid_search_query",
+                    Some(serde_json::json!({
+                        "message": "Search query could not be parsed",
+                        "details": message,
+                        "help": "Balance quotes and parentheses, or search for plain words"
+                    })),
+                )
             } else if message.contains("CHECK constraint failed") {
                 McpError::invalid_params(
                     "validation_failed",
@@ -173,5 +171,12 @@ pub(crate) fn map_db_error(err: DbError) -> McpError {
                 "message": message
             })),
         ),
+        DbError::BatchItem { index, source } => McpError::invalid_params(
+            "batch_item_failed",
+            Some(serde_json::json!({
+                "index": index,
+                "message": source.to_string()
+            })),
+        ),
     }
 }
@@ -13,7 +13,9 @@ use serde_json::json;
 use sha2::Digest;
 use std::sync::Arc;
 
-use crate::api::notifier::{ChangeNotifier, UpdateMessage};
+use crate::api::notifier::{ChangeNotifier, UpdateMessage, changed_fields};
+use crate::db::pagination::LIST_LIMITS;
+use crate::db::utils::excerpt;
 use crate::db::{Database, Note, NoteQuery, NoteRepository, PageSort};
 use crate::mcp::tools::map_db_error;
 
@@ -34,6 +36,23 @@ fn compute_etag(updated_at: &str) -> String {
     })
 }
 
+// =============================================================================
+// Content Paging
+// =============================================================================
+
+/// Characters of content `get_note` returns per call by default, and at most.
+const NOTE_PAGE_CHARS: usize = 10_000;
+
+/// Slice `content` to at most `max_chars` characters starting at character
+/// `offset`. Returns the page and the offset of the next page, if any.
+/// Counts characters rather than bytes so pages never split a UTF-8 sequence.
+fn page_content(content: &str, offset: usize, max_chars: usize) -> (String, Option<usize>) {
+    let page: String = content.chars().skip(offset).take(max_chars).collect();
+    let end = offset + page.chars().count();
+    let next_offset = (end < content.chars().count()).then_some(end);
+    (page, next_offset)
+}
+
 // =============================================================================
 // TOON Formatting Helper
 // =============================================================================
@@ -124,7 +143,7 @@ pub struct ListNotesParams {
         description = "Filter by note type: 'note' (parent notes only) or 'subnote' (subnotes only). Omit to return both parent notes and subnotes (default)."
     )]
     pub note_type: Option<String>,
-    #[schemars(description = "Maximum number of items to return (default: 10, max: 20)")]
+    #[schemars(description = "Maximum number of items to return (default: 20, max: 100)")]
     pub limit: Option<usize>,
     #[schemars(description = "Number of items to skip")]
     pub offset: Option<usize>,
@@ -140,6 +159,16 @@ pub struct ListNotesParams {
     pub order: Option<String>,
 }
 
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct SearchNotesParams {
+    #[schemars(
+        description = "FTS5 search query over title, content and tags. Examples: 'rust AND async' (Boolean), '\"exact phrase\"' (phrase match), 'term*' (prefix)"
+    )]
+    pub query: String,
+    #[schemars(description = "Maximum number of items to return (default: 20, max: 100)")]
+    pub limit: Option<usize>,
+}
+
 #[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct ReadNoteParams {
     #[schemars(description = "Note ID")]
@@ -154,6 +183,20 @@ pub struct ReadNoteParams {
     pub format: Option<String>,
 }
 
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct GetNoteParams {
+    #[schemars(description = "Note ID")]
+    pub note_id: String,
+    #[schemars(
+        description = "Return only title, tags and a short content excerpt (default: false)"
+    )]
+    pub summary: Option<bool>,
+    #[schemars(description = "Character offset into content to start from (default: 0)")]
+    pub offset: Option<usize>,
+    #[schemars(description = "Maximum characters of content to return (default/max: 10000)")]
+    pub max_chars: Option<usize>,
+}
+
 #[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct CreateNoteParams {
     #[schemars(description = "Note title")]
@@ -236,7 +279,7 @@ impl<D: Database + 'static> NoteTools<D> {
     }
 
     #[tool(
-        description = "List notes. Query for FTS search. Default: metadata only (use include_content=true for full). Filter by tags/project_id/parent_id/note_type. Limit: 10 (max 20)."
+        description = "List notes. Query for FTS search. Default: metadata only (use include_content=true for full). Filter by tags/project_id/parent_id/note_type. Limit: 20 (max 100)."
     )]
     pub async fn list_notes(
         &self,
@@ -248,7 +291,7 @@ impl<D: Database + 'static> NoteTools<D> {
         // Build query
         let query = NoteQuery {
             page: PageSort {
-                limit: params.0.limit,
+                limit: LIST_LIMITS.apply(params.0.limit),
                 offset: params.0.offset,
                 sort_by: params.0.sort.clone(),
                 sort_order: match params.0.order.as_deref() {
@@ -256,6 +299,7 @@ impl<D: Database + 'static> NoteTools<D> {
                     Some("asc") => Some(crate::db::SortOrder::Asc),
                     _ => None,
                 },
+                after: None,
             },
             tags: params.0.tags.clone(),
             project_id: params.0.project_id.clone(),
@@ -285,6 +329,54 @@ impl<D: Database + 'static> NoteTools<D> {
         )]))
     }
 
+    #[tool(
+        description = "Full-text search notes. Returns ID, title, tags and the matched fragment (hits wrapped in <mark>) per note; use read_note for content. Limit: 20 (max 100)."
+    )]
+    pub async fn search_notes(
+        &self,
+        params: Parameters<SearchNotesParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let query = NoteQuery {
+            page: PageSort {
+                limit: LIST_LIMITS.apply(params.0.limit),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let result = self
+            .db
+            .notes()
+            .search_snippets(&params.0.query, Some(&query))
+            .await
+            .map_err(map_db_error)?;
+
+        let items: Vec<_> = result
+            .items
+            .iter()
+            .map(|hit| {
+                json!({
+                    "id": hit.note.id,
+                    "title": hit.note.title,
+                    "tags": hit.note.tags,
+                    "parent_id": hit.note.parent_id,
+                    "snippet": hit.snippet,
+                })
+            })
+            .collect();
+
+        let response = json!({
+            "items": items,
+            "total": result.total,
+            "limit": result.limit,
+            "offset": result.offset,
+        });
+
+        Ok(CallToolResult::success(vec![ContentBlock::text(
+            serde_json::to_string_pretty(&response).unwrap(),
+        )]))
+    }
+
     #[tool(
         description = "Create note (Markdown). Keep under 10k chars. For larger: split & link with parent:NOTE_ID tag."
     )]
@@ -451,6 +543,63 @@ impl<D: Database + 'static> NoteTools<D> {
         }
     }
 
+    #[tool(
+        description = "Get note content in pages of max_chars characters (default/max 10000); follow next_offset until null. summary=true returns only title, tags and an excerpt."
+    )]
+    pub async fn get_note(
+        &self,
+        params: Parameters<GetNoteParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let note = self.db.notes().get(&params.0.note_id).await.map_err(|e| {
+            McpError::resource_not_found(
+                "note_not_found",
+                Some(serde_json::json!({"error": e.to_string()})),
+            )
+        })?;
+
+        let etag = compute_etag(note.updated_at.as_ref().unwrap_or(&String::new()));
+        let total_chars = note.content.chars().count();
+
+        let response = if params.0.summary.unwrap_or(false) {
+            json!({
+                "id": note.id,
+                "title": note.title,
+                "tags": note.tags,
+                "excerpt": excerpt(&note.content),
+                "total_chars": total_chars,
+                "etag": etag,
+            })
+        } else {
+            let offset = params.0.offset.unwrap_or(0);
+            let max_chars = params
+                .0
+                .max_chars
+                .unwrap_or(NOTE_PAGE_CHARS)
+                .clamp(1, NOTE_PAGE_CHARS);
+            let (content, next_offset) = page_content(&note.content, offset, max_chars);
+            json!({
+                "id": note.id,
+                "title": note.title,
+                "tags": note.tags,
+                "parent_id": note.parent_id,
+                "idx": note.idx,
+                "project_ids": note.project_ids,
+                "repo_ids": note.repo_ids,
+                "created_at": note.created_at,
+                "updated_at": note.updated_at,
+                "etag": etag,
+                "content": content,
+                "offset": offset,
+                "next_offset": next_offset,
+                "total_chars": total_chars,
+            })
+        };
+
+        Ok(CallToolResult::success(vec![ContentBlock::text(
+            serde_json::to_string_pretty(&response).unwrap(),
+        )]))
+    }
+
     #[tool(
         description = "Edit note. REQUIRES etag from read_note. Updates metadata and/or applies line patches. If etag fails, re-read first."
     )]
@@ -482,6 +631,7 @@ impl<D: Database + 'static> NoteTools<D> {
         }
 
         // Get existing note for editing
+        let before = current_note.clone();
         let mut note = current_note;
 
         // Update metadata fields (same as update_note)
@@ -547,6 +697,7 @@ impl<D: Database + 'static> NoteTools<D> {
         // Broadcast NoteUpdated notification
         self.notifier.notify(UpdateMessage::NoteUpdated {
             note_id: params.0.note_id.clone(),
+            changed: changed_fields(&before, &updated),
         });
 
         Ok(CallToolResult::success(vec![ContentBlock::text(
@@ -1,9 +1,11 @@
 //! Tests for Note MCP tools
 
 use crate::api::notifier::ChangeNotifier;
-use crate::db::{Database, Note, NoteRepository, SqliteDatabase};
+use crate::db::{Database, DbError, Note, NoteRepository, SqliteDatabase};
+use crate::mcp::tools::map_db_error;
 use crate::mcp::tools::notes::{
-    CreateNoteParams, DeleteNoteParams, EditNoteParams, ListNotesParams, NoteTools, ReadNoteParams,
+    CreateNoteParams, DeleteNoteParams, EditNoteParams, GetNoteParams, ListNotesParams, NoteTools,
+    ReadNoteParams, SearchNotesParams,
 };
 use rmcp::handler::server::wrapper::Parameters;
 use rmcp::model::ContentBlock;
@@ -420,6 +422,68 @@ async fn test_search_notes() {
     assert_eq!(items[0]["title"], "Rust Programming");
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn search_notes_tool_returns_ids_titles_and_snippets() {
+    let db = SqliteDatabase::in_memory().await.unwrap();
+    db.migrate().unwrap();
+    let db = Arc::new(db);
+
+    let mut ids = Vec::new();
+    for (title, content) in [
+        ("Async Rust", "Pinning futures in async Rust"),
+        ("Rust Lifetimes", "Borrowing rules in Rust"),
+        ("Go Channels", "Select over buffered channels"),
+    ] {
+        let note = Note {
+            id: String::new(),
+            title: title.to_string(),
+            content: content.to_string(),
+            tags: vec!["lang".to_string()],
+            parent_id: None,
+            idx: None,
+            repo_ids: vec![],
+            project_ids: vec![],
+            subnote_count: None,
+            created_at: None,
+            updated_at: None,
+        };
+        ids.push(db.notes().create(&note).await.unwrap().id);
+    }
+
+    let tools = NoteTools::new(db.clone(), ChangeNotifier::new());
+    let result = tools
+        .search_notes(Parameters(SearchNotesParams {
+            query: "rust".to_string(),
+            limit: Some(1),
+        }))
+        .await
+        .expect("search should succeed");
+
+    let content_text = match &result.content[0] {
+        ContentBlock::Text(text) => text.text.as_str(),
+        _ => panic!("Expected text content"),
+    };
+    let json: serde_json::Value = serde_json::from_str(content_text).unwrap();
+
+    assert_eq!(json["total"], 2);
+    let items = json["items"].as_array().unwrap();
+    assert_eq!(items.len(), 1);
+    let id = items[0]["id"].as_str().unwrap();
+    assert!(ids[..2].iter().any(|rust_id| rust_id == id));
+    assert!(items[0]["title"].as_str().unwrap().contains("Rust"));
+    assert!(items[0]["snippet"].as_str().unwrap().contains("<mark>"));
+    assert!(items[0].get("content").is_none());
+}
+
+#[test]
+fn fts_syntax_errors_map_to_invalid_params() {
+    let err = map_db_error(DbError::Database {
+        message: "error returned from database: (code: 1) fts5: syntax error near \"\"\"".into(),
+    });
+    assert_eq!(err.code, rmcp::model::ErrorCode::INVALID_PARAMS);
+    assert_eq!(err.message, "invalid_search_query");
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn test_search_notes_with_tag_filter() {
     let db = SqliteDatabase::in_memory().await.unwrap();
@@ -1200,3 +1264,142 @@ async fn test_list_notes_with_project_filter() {
         "non-existent project should return 0 notes"
     );
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_list_notes_applies_shared_limit_policy() {
+    use crate::db::pagination::{DEFAULT_PAGE_SIZE, MAX_PAGE_SIZE};
+
+    let db = SqliteDatabase::in_memory().await.unwrap();
+    db.migrate().unwrap();
+    let db = Arc::new(db);
+    let tools = NoteTools::new(db.clone(), ChangeNotifier::new());
+
+    // One more note than the default page holds
+    for i in 0..=DEFAULT_PAGE_SIZE {
+        let note = Note {
+            id: String::new(),
+            title: format!("Note {}", i),
+            content: "Body".to_string(),
+            tags: vec![],
+            parent_id: None,
+            idx: None,
+            repo_ids: vec![],
+            project_ids: vec![],
+            subnote_count: None,
+            created_at: None,
+            updated_at: None,
+        };
+        db.notes().create(&note).await.unwrap();
+    }
+
+    // Same default and cap as the REST list endpoints
+    for (requested, limit, shown) in [
+        (None, DEFAULT_PAGE_SIZE, DEFAULT_PAGE_SIZE),
+        (Some(100_000), MAX_PAGE_SIZE, DEFAULT_PAGE_SIZE + 1),
+    ] {
+        let params = ListNotesParams {
+            query: None,
+            tags: None,
+            project_id: None,
+            parent_id: None,
+            note_type: None,
+            limit: requested,
+            offset: None,
+            include_content: None,
+            sort: None,
+            order: None,
+        };
+        let result = tools
+            .list_notes(Parameters(params))
+            .await
+            .expect("list_notes should succeed");
+        let content_text = match &result.content[0] {
+            ContentBlock::Text(text) => text.text.as_str(),
+            _ => panic!("Expected text content"),
+        };
+        let json: serde_json::Value = serde_json::from_str(content_text).unwrap();
+
+        assert_eq!(json["total"], DEFAULT_PAGE_SIZE + 1);
+        assert_eq!(json["limit"], limit);
+        assert_eq!(json["items"].as_array().unwrap().len(), shown);
+    }
+}
+
+fn parse_text(result: &rmcp::model::CallToolResult) -> serde_json::Value {
+    match &result.content[0] {
+        ContentBlock::Text(text) => serde_json::from_str(&text.text).unwrap(),
+        _ => panic!("Expected text content"),
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn get_note_summary_and_paging() {
+    let db = SqliteDatabase::in_memory().await.unwrap();
+    db.migrate().unwrap();
+    let db = Arc::new(db);
+
+    let content = (1..=200)
+        .map(|i| format!("Zeile {i}: größer als ✓"))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let note = Note {
+        id: String::new(),
+        title: "Long Note".to_string(),
+        content: content.clone(),
+        tags: vec!["big".to_string()],
+        parent_id: None,
+        idx: None,
+        repo_ids: vec![],
+        project_ids: vec![],
+        subnote_count: None,
+        created_at: None,
+        updated_at: None,
+    };
+    let created = db.notes().create(&note).await.unwrap();
+    let tools = NoteTools::new(db.clone(), ChangeNotifier::new());
+
+    let summary = parse_text(
+        &tools
+            .get_note(Parameters(GetNoteParams {
+                note_id: created.id.clone(),
+                summary: Some(true),
+                offset: None,
+                max_chars: None,
+            }))
+            .await
+            .unwrap(),
+    );
+    assert_eq!(summary["title"], "Long Note");
+    assert_eq!(summary["tags"][0], "big");
+    assert!(summary.get("content").is_none());
+    let excerpt = summary["excerpt"].as_str().unwrap();
+    assert!(excerpt.starts_with("Zeile 1: größer"));
+    assert!(excerpt.ends_with('…'));
+    assert!(excerpt.len() < content.len());
+    assert_eq!(summary["total_chars"], content.chars().count());
+
+    let mut rebuilt = String::new();
+    let mut offset = Some(0);
+    let mut pages = 0;
+    while let Some(at) = offset {
+        let page = parse_text(
+            &tools
+                .get_note(Parameters(GetNoteParams {
+                    note_id: created.id.clone(),
+                    summary: None,
+                    offset: Some(at),
+                    max_chars: Some(777),
+                }))
+                .await
+                .unwrap(),
+        );
+        assert_eq!(page["offset"], at);
+        let chunk = page["content"].as_str().unwrap();
+        assert!(chunk.chars().count() <= 777);
+        rebuilt.push_str(chunk);
+        offset = page["next_offset"].as_u64().map(|n| n as usize);
+        pages += 1;
+    }
+    assert!(pages > 1);
+    assert_eq!(rebuilt, content);
+}
@@ -4,8 +4,13 @@
 //! Follows Single Responsibility Principle (SRP).
 
 use crate::api::notifier::{ChangeNotifier, UpdateMessage};
-use crate::db::{Database, PageSort, Project, ProjectQuery, ProjectRepository};
-use crate::mcp::tools::{apply_limit, map_db_error};
+use crate::db::pagination::LIST_LIMITS;
+use crate::db::{
+    Database, NoteQuery, NoteRepository, PageSort, Project, ProjectQuery, ProjectRepository,
+    RepoQuery, RepoRepository, SortOrder, TaskListQuery, TaskListRepository, TaskRepository,
+    TaskStats,
+};
+use crate::mcp::tools::map_db_error;
 use rmcp::{
     ErrorData as McpError,
     handler::server::{router::tool::ToolRouter, wrapper::Parameters},
@@ -26,7 +31,7 @@ pub struct ListProjectsParams {
     )]
     pub query: Option<String>,
     #[schemars(
-        description = "Maximum number of projects to return (default: 10, max: 20). IMPORTANT: Keep this small to prevent context overflow."
+        description = "Maximum number of projects to return (default: 20, max: 100). IMPORTANT: Keep this small to prevent context overflow."
     )]
     pub limit: Option<usize>,
     #[schemars(description = "Number of items to skip")]
@@ -45,6 +50,16 @@ pub struct GetProjectParams {
     pub id: String,
 }
 
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct GetProjectContextParams {
+    #[schemars(description = "Project ID (8-character hex)")]
+    pub id: String,
+    #[schemars(
+        description = "Maximum number of most recently updated notes to include (default: 20, max: 100)"
+    )]
+    pub note_limit: Option<usize>,
+}
+
 #[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct CreateProjectParams {
     #[schemars(description = "Project title")]
@@ -107,19 +122,17 @@ impl<D: Database + 'static> ProjectTools<D> {
         &self.tool_router
     }
 
-    /// List projects with pagination and sorting (default: 10, max: 20)
+    /// List projects with pagination and sorting (default: 20, max: 100)
     #[tool(
-        description = "List projects with pagination and sorting. Sort by title, created_at, or updated_at. Default limit: 10, max: 20 to prevent context overflow."
+        description = "List projects with pagination and sorting. Sort by title, created_at, or updated_at. Default limit: 20, max: 100 to prevent context overflow."
     )]
     pub async fn list_projects(
         &self,
         params: Parameters<ListProjectsParams>,
     ) -> Result<CallToolResult, McpError> {
-        let limit = apply_limit(params.0.limit);
-
         let query = ProjectQuery {
             page: PageSort {
-                limit: Some(limit),
+                limit: LIST_LIMITS.apply(params.0.limit),
                 offset: params.0.offset,
                 sort_by: params.0.sort.clone(),
                 sort_order: match params.0.order.as_deref() {
@@ -127,6 +140,7 @@ impl<D: Database + 'static> ProjectTools<D> {
                     Some("asc") => Some(crate::db::SortOrder::Asc),
                     _ => None,
                 },
+                after: None,
             },
             tags: None,
         };
@@ -179,6 +193,125 @@ impl<D: Database + 'static> ProjectTools<D> {
         Ok(CallToolResult::success(vec![ContentBlock::text(content)]))
     }
 
+    /// Get a project with everything linked to it
+    #[tool(
+        description = "Get a project's full context in one call: the project, its linked repos, its task lists with task counts by status, and its most recently updated notes (titles only, up to note_limit: default 20, max 100). Use at the start of a session."
+    )]
+    pub async fn get_project_context(
+        &self,
+        params: Parameters<GetProjectContextParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let id = &params.0.id;
+
+        let project = self.db.projects().get(id).await.map_err(map_db_error)?;
+
+        let repos = self
+            .db
+            .repos()
+            .list(Some(&RepoQuery {
+                project_id: Some(id.clone()),
+                ..Default::default()
+            }))
+            .await
+            .map_err(map_db_error)?;
+
+        let task_lists = self
+            .db
+            .task_lists()
+            .list(Some(&TaskListQuery {
+                project_id: Some(id.clone()),
+                ..Default::default()
+            }))
+            .await
+            .map_err(map_db_error)?;
+
+        let mut stats = self
+            .db
+            .tasks()
+            .get_stats_for_project_lists(id)
+            .await
+            .map_err(map_db_error)?;
+
+        let notes = self
+            .db
+            .notes()
+            .list(Some(&NoteQuery {
+                page: PageSort {
+                    limit: LIST_LIMITS.apply(params.0.note_limit),
+                    sort_by: Some("updated_at".to_string()),
+                    sort_order: Some(SortOrder::Desc),
+                    ..Default::default()
+                },
+                project_id: Some(id.clone()),
+                ..Default::default()
+            }))
+            .await
+            .map_err(map_db_error)?;
+
+        let task_lists: Vec<_> = task_lists
+            .items
+            .into_iter()
+            .map(|list| {
+                let list_stats = stats
+                    .iter()
+                    .position(|s| s.list_id == list.id)
+                    .map(|i| stats.swap_remove(i))
+                    .unwrap_or_else(|| TaskStats {
+                        list_id: list.id.clone(),
+                        ..Default::default()
+                    });
+                json!({
+                    "id": list.id,
+                    "title": list.title,
+                    "status": list.status,
+                    "tags": list.tags,
+                    "stats": {
+                        "total": list_stats.total,
+                        "backlog": list_stats.backlog,
+                        "todo": list_stats.todo,
+                        "in_progress": list_stats.in_progress,
+                        "review": list_stats.review,
+                        "done": list_stats.done,
+                        "cancelled": list_stats.cancelled,
+                    },
+                })
+            })
+            .collect();
+
+        let recent_notes: Vec<_> = notes
+            .items
+            .into_iter()
+            .map(|note| {
+                json!({
+                    "id": note.id,
+                    "title": note.title,
+                    "tags": note.tags,
+                    "parent_id": note.parent_id,
+                    "updated_at": note.updated_at,
+                })
+            })
+            .collect();
+
+        let response = json!({
+            "project": project,
+            "repos": repos.items,
+            "task_lists": task_lists,
+            "notes": {
+                "items": recent_notes,
+                "total": notes.total,
+            },
+        });
+
+        let content = serde_json::to_string_pretty(&response).map_err(|e| {
+            McpError::internal_error(
+                "serialization_error",
+                Some(serde_json::json!({"error": e.to_string()})),
+            )
+        })?;
+
+        Ok(CallToolResult::success(vec![ContentBlock::text(content)]))
+    }
+
     /// Create a new project
     #[tool(description = "Create a new project")]
     pub async fn create_project(
@@ -297,8 +297,8 @@ async fn test_list_projects_respects_limit() {
     db.migrate().unwrap();
     let db = Arc::new(db);
 
-    // Create 25 test projects (plus 1 Default from migration = 26 total)
-    for i in 0..25 {
+    // Create 120 test projects (plus 1 Default from migration = 121 total)
+    for i in 0..120 {
         let project = Project {
             id: format!("proj{:04}", i),
             title: format!("Project {}", i),
@@ -319,7 +319,7 @@ async fn test_list_projects_respects_limit() {
     use crate::mcp::tools::projects::ListProjectsParams;
     use rmcp::handler::server::wrapper::Parameters;
 
-    // Test 1: Without limit parameter, should return DEFAULT_LIMIT (10)
+    // Test 1: Without limit parameter, should return DEFAULT_PAGE_SIZE (20)
     let result = tools
         .list_projects(Parameters(ListProjectsParams {
             query: None,
@@ -337,7 +337,11 @@ async fn test_list_projects_respects_limit() {
     };
     let response: serde_json::Value = serde_json::from_str(content_text).unwrap();
     let projects = response["items"].as_array().unwrap();
-    assert_eq!(projects.len(), 10, "Should return DEFAULT_LIMIT (10) items");
+    assert_eq!(
+        projects.len(),
+        20,
+        "Should return DEFAULT_PAGE_SIZE (20) items"
+    );
 
     // Test 2: With limit=5, should return 5
     let result = tools
@@ -359,11 +363,11 @@ async fn test_list_projects_respects_limit() {
     let projects = response["items"].as_array().unwrap();
     assert_eq!(projects.len(), 5, "Should return requested 5 items");
 
-    // Test 3: With limit=50 (exceeds MAX_LIMIT), should cap at MAX_LIMIT (20)
+    // Test 3: With limit=500 (exceeds MAX_PAGE_SIZE), should cap at MAX_PAGE_SIZE (100)
     let result = tools
         .list_projects(Parameters(ListProjectsParams {
             query: None,
-            limit: Some(50),
+            limit: Some(500),
             offset: None,
             sort: None,
             order: None,
@@ -379,8 +383,8 @@ async fn test_list_projects_respects_limit() {
     let projects = response["items"].as_array().unwrap();
     assert_eq!(
         projects.len(),
-        20,
-        "Should cap at MAX_LIMIT (20) even though 50 requested"
+        100,
+        "Should cap at MAX_PAGE_SIZE (100) even though 500 requested"
     );
 }
 
@@ -1014,3 +1018,171 @@ async fn test_search_projects_empty_results() {
     assert_eq!(items.len(), 0);
     assert_eq!(response["total"], 0);
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn get_project_context_gathers_linked_entities() {
+    use crate::db::{
+        Note, NoteRepository, Repo, RepoRepository, Task, TaskList, TaskListRepository,
+        TaskListStatus, TaskRepository, TaskStatus,
+    };
+    use crate::mcp::tools::projects::GetProjectContextParams;
+    use rmcp::handler::server::wrapper::Parameters;
+
+    let db = SqliteDatabase::in_memory().await.unwrap();
+    db.migrate().unwrap();
+    let db = Arc::new(db);
+
+    let project = Project {
+        id: "c0ffee00".to_string(),
+        title: "Context".to_string(),
+        description: None,
+        tags: vec![],
+        external_refs: vec![],
+        repo_ids: vec![],
+        task_list_ids: vec![],
+        note_ids: vec![],
+        created_at: None,
+        updated_at: None,
+    };
+    db.projects().create(&project).await.unwrap();
+
+    db.repos()
+        .create(&Repo {
+            id: "aaaa0001".to_string(),
+            remote: "github:ck3mp3r/context".to_string(),
+            path: None,
+            tags: vec![],
+            project_ids: vec![project.id.clone()],
+            created_at: None,
+            updated_at: None,
+        })
+        .await
+        .unwrap();
+    db.repos()
+        .create(&Repo {
+            id: "aaaa0002".to_string(),
+            remote: "github:ck3mp3r/unrelated".to_string(),
+            path: None,
+            tags: vec![],
+            project_ids: vec![],
+            created_at: None,
+            updated_at: None,
+        })
+        .await
+        .unwrap();
+
+    let list = db
+        .task_lists()
+        .create(&TaskList {
+            id: String::new(),
+            title: "Sprint".to_string(),
+            description: None,
+            notes: None,
+            tags: vec![],
+            external_refs: vec![],
+            status: TaskListStatus::Active,
+            repo_ids: vec![],
+            project_id: project.id.clone(),
+            created_at: None,
+            updated_at: None,
+            archived_at: None,
+        })
+        .await
+        .unwrap();
+    for (title, status) in [("One", TaskStatus::Todo), ("Two", TaskStatus::Done)] {
+        db.tasks()
+            .create(&Task {
+                id: String::new(),
+                list_id: list.id.clone(),
+                parent_id: None,
+                title: title.to_string(),
+                description: None,
+                status,
+                priority: None,
+                tags: vec![],
+                external_refs: vec![],
+                created_at: None,
+                updated_at: None,
+            })
+            .await
+            .unwrap();
+    }
+
+    for (title, updated_at) in [
+        ("Oldest", "2025-01-01 00:00:00"),
+        ("Newest", "2025-03-01 00:00:00"),
+        ("Middle", "2025-02-01 00:00:00"),
+    ] {
+        db.notes()
+            .create(&Note {
+                id: String::new(),
+                title: title.to_string(),
+                content: "Body".to_string(),
+                tags: vec![],
+                parent_id: None,
+                idx: None,
+                repo_ids: vec![],
+                project_ids: vec![project.id.clone()],
+                subnote_count: None,
+                created_at: Some(updated_at.to_string()),
+                updated_at: Some(updated_at.to_string()),
+            })
+            .await
+            .unwrap();
+    }
+
+    let tools = ProjectTools::new(db, ChangeNotifier::new());
+    let result = tools
+        .get_project_context(Parameters(GetProjectContextParams {
+            id: project.id.clone(),
+            note_limit: Some(2),
+        }))
+        .await
+        .unwrap();
+    let content_text = match &result.content[0] {
+        ContentBlock::Text(text) => text.text.as_str(),
+        _ => panic!("Expected text content"),
+    };
+    let context: serde_json::Value = serde_json::from_str(content_text).unwrap();
+
+    assert_eq!(context["project"]["title"], "Context");
+
+    let repos = context["repos"].as_array().unwrap();
+    assert_eq!(repos.len(), 1);
+    assert_eq!(repos[0]["id"], "aaaa0001");
+
+    let lists = context["task_lists"].as_array().unwrap();
+    assert_eq!(lists.len(), 1);
+    assert_eq!(lists[0]["status"], "active");
+    assert_eq!(lists[0]["stats"]["total"], 2);
+    assert_eq!(lists[0]["stats"]["todo"], 1);
+    assert_eq!(lists[0]["stats"]["done"], 1);
+
+    assert_eq!(context["notes"]["total"], 3);
+    let titles: Vec<_> = context["notes"]["items"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|n| n["title"].as_str().unwrap())
+        .collect();
+    assert_eq!(titles, ["Newest", "Middle"]);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn get_project_context_unknown_project_is_not_found() {
+    use crate::mcp::tools::projects::GetProjectContextParams;
+    use rmcp::handler::server::wrapper::Parameters;
+
+    let db = SqliteDatabase::in_memory().await.unwrap();
+    db.migrate().unwrap();
+    let tools = ProjectTools::new(Arc::new(db), ChangeNotifier::new());
+
+    let err = tools
+        .get_project_context(Parameters(GetProjectContextParams {
+            id: "deadbeef".to_string(),
+            note_limit: None,
+        }))
+        .await
+        .unwrap_err();
+    assert_eq!(err.message, "not_found");
+}
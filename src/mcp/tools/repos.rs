@@ -4,8 +4,9 @@
 //! Follows Single Responsibility Principle (SRP).
 
 use crate::api::notifier::{ChangeNotifier, UpdateMessage};
+use crate::db::pagination::LIST_LIMITS;
 use crate::db::{Database, PageSort, Repo, RepoQuery, RepoRepository};
-use crate::mcp::tools::{apply_limit, map_db_error};
+use crate::mcp::tools::map_db_error;
 use rmcp::{
     ErrorData as McpError,
     handler::server::{router::tool::ToolRouter, wrapper::Parameters},
@@ -28,7 +29,7 @@ pub struct ListReposParams {
     #[schemars(description = "Filter by project ID")]
     pub project_id: Option<String>,
     #[schemars(
-        description = "Maximum number of repos to return (default: 10, max: 20). IMPORTANT: Keep small to prevent context overflow."
+        description = "Maximum number of repos to return (default: 20, max: 100). IMPORTANT: Keep small to prevent context overflow."
     )]
     pub limit: Option<usize>,
     #[schemars(description = "Number of items to skip (for pagination)")]
@@ -107,19 +108,17 @@ impl<D: Database + 'static> RepoTools<D> {
         &self.tool_router
     }
 
-    /// List repositories with pagination and sorting (default: 10, max: 20)
+    /// List repositories with pagination and sorting (default: 20, max: 100)
     #[tool(
-        description = "List repositories with pagination and sorting. Sort by remote, path, or created_at. Default limit: 10, max: 20 to prevent context overflow."
+        description = "List repositories with pagination and sorting. Sort by remote, path, or created_at. Default limit: 20, max: 100 to prevent context overflow."
     )]
     pub async fn list_repos(
         &self,
         params: Parameters<ListReposParams>,
     ) -> Result<CallToolResult, McpError> {
-        let limit = apply_limit(params.0.limit);
-
         let query = RepoQuery {
             page: PageSort {
-                limit: Some(limit),
+                limit: LIST_LIMITS.apply(params.0.limit),
                 offset: params.0.offset,
                 sort_by: params.0.sort.clone(),
                 sort_order: match params.0.order.as_deref() {
@@ -127,6 +126,7 @@ impl<D: Database + 'static> RepoTools<D> {
                     Some("asc") => Some(crate::db::SortOrder::Asc),
                     _ => None,
                 },
+                after: None,
             },
             tags: None,
             project_id: params.0.project_id,
@@ -193,6 +193,7 @@ impl<D: Database + 'static> RepoTools<D> {
             tags: params.0.tags.unwrap_or_default(),
             project_ids: params.0.project_ids.unwrap_or_default(),
             created_at: None, // Repository generates this
+            updated_at: None, // Repository generates this
         };
 
         let created = self.db.repos().create(&repo).await.map_err(map_db_error)?;
@@ -55,6 +55,7 @@ async fn test_get_repo() {
         tags: vec![],
         project_ids: vec![],
         created_at: Some("2025-01-01 00:00:00".to_string()),
+        updated_at: None,
     };
     db.repos().create(&repo).await.unwrap();
 
@@ -172,6 +173,7 @@ async fn test_update_repo() {
         tags: vec![],
         project_ids: vec![],
         created_at: Some("2025-01-01 00:00:00".to_string()),
+        updated_at: None,
     };
     db.repos().create(&repo).await.unwrap();
 
@@ -214,6 +216,7 @@ async fn test_delete_repo() {
         tags: vec![],
         project_ids: vec![],
         created_at: Some("2025-01-01 00:00:00".to_string()),
+        updated_at: None,
     };
     db.repos().create(&repo).await.unwrap();
 
@@ -263,6 +266,7 @@ async fn test_update_repo_with_project_ids() {
         tags: vec![],
         project_ids: vec![],
         created_at: Some("2025-01-01 00:00:00".to_string()),
+        updated_at: None,
     };
     db.repos().create(&repo).await.unwrap();
 
@@ -326,6 +330,7 @@ async fn test_update_repo_transaction_safety() {
         tags: vec![],
         project_ids: vec!["proj1111".to_string()],
         created_at: Some("2025-01-01 00:00:00".to_string()),
+        updated_at: None,
     };
     db.repos().create(&repo).await.unwrap();
 
@@ -353,8 +358,8 @@ async fn test_list_repos_respects_limit() {
     db.migrate().unwrap();
     let db = Arc::new(db);
 
-    // Create 25 test repos
-    for i in 0..25 {
+    // Create 120 test repos
+    for i in 0..120 {
         let repo = Repo {
             id: format!("repo{:04}", i),
             remote: format!("git@github.com:user/repo{}.git", i),
@@ -362,13 +367,14 @@ async fn test_list_repos_respects_limit() {
             tags: vec![],
             project_ids: vec![],
             created_at: Some("2025-01-01 00:00:00".to_string()),
+            updated_at: None,
         };
         db.repos().create(&repo).await.unwrap();
     }
 
     let tools = RepoTools::new(Arc::clone(&db), ChangeNotifier::new());
 
-    // Test 1: Without limit parameter, should return DEFAULT_LIMIT (10)
+    // Test 1: Without limit parameter, should return DEFAULT_PAGE_SIZE (20)
     let result = tools
         .list_repos(Parameters(ListReposParams {
             query: None,
@@ -387,7 +393,11 @@ async fn test_list_repos_respects_limit() {
     };
     let response: serde_json::Value = serde_json::from_str(content_text).unwrap();
     let repos = response["items"].as_array().unwrap();
-    assert_eq!(repos.len(), 10, "Should return DEFAULT_LIMIT (10) items");
+    assert_eq!(
+        repos.len(),
+        20,
+        "Should return DEFAULT_PAGE_SIZE (20) items"
+    );
 
     // Test 2: With limit=5, should return 5
     let result = tools
@@ -410,12 +420,12 @@ async fn test_list_repos_respects_limit() {
     let repos = response["items"].as_array().unwrap();
     assert_eq!(repos.len(), 5, "Should return requested 5 items");
 
-    // Test 3: With limit=50 (exceeds MAX_LIMIT), should cap at MAX_LIMIT (20)
+    // Test 3: With limit=500 (exceeds MAX_PAGE_SIZE), should cap at MAX_PAGE_SIZE (100)
     let result = tools
         .list_repos(Parameters(ListReposParams {
             query: None,
             project_id: None,
-            limit: Some(50),
+            limit: Some(500),
             offset: None,
             sort: None,
             order: None,
@@ -431,8 +441,8 @@ async fn test_list_repos_respects_limit() {
     let repos = response["items"].as_array().unwrap();
     assert_eq!(
         repos.len(),
-        20,
-        "Should cap at MAX_LIMIT (20) even though 50 requested"
+        100,
+        "Should cap at MAX_PAGE_SIZE (100) even though 500 requested"
     );
 }
 
@@ -450,6 +460,7 @@ async fn test_list_repos_with_sort_and_order() {
         tags: vec![],
         project_ids: vec![],
         created_at: Some("2025-01-01 10:00:00".to_string()),
+        updated_at: None,
     };
 
     let repo2 = Repo {
@@ -459,6 +470,7 @@ async fn test_list_repos_with_sort_and_order() {
         tags: vec![],
         project_ids: vec![],
         created_at: Some("2025-01-03 10:00:00".to_string()),
+        updated_at: None,
     };
 
     let repo3 = Repo {
@@ -468,6 +480,7 @@ async fn test_list_repos_with_sort_and_order() {
         tags: vec![],
         project_ids: vec![],
         created_at: Some("2025-01-02 10:00:00".to_string()),
+        updated_at: None,
     };
 
     db.repos().create(&repo1).await.unwrap();
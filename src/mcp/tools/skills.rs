@@ -15,6 +15,7 @@ use std::sync::Arc;
 
 use crate::api::notifier::ChangeNotifier;
 use crate::db::SkillRepository;
+use crate::db::pagination::LIST_LIMITS;
 
 #[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct ListSkillsParams {
@@ -26,7 +27,7 @@ pub struct ListSkillsParams {
     pub tags: Option<Vec<String>>,
     #[schemars(description = "Filter by project ID")]
     pub project_id: Option<String>,
-    #[schemars(description = "Maximum number of items to return (default: 10, max: 20)")]
+    #[schemars(description = "Maximum number of items to return (default: 20, max: 100)")]
     pub limit: Option<usize>,
     #[schemars(description = "Number of items to skip")]
     pub offset: Option<usize>,
@@ -81,7 +82,7 @@ impl<D: crate::db::Database + 'static> SkillTools<D> {
     // -- Tools to implement (following notes pattern)
 
     #[tool(
-        description = "List skills with optional full-text search. Provide 'query' parameter to search, omit to list all. Supports filtering, sorting, and pagination."
+        description = "List skills with optional full-text search. Provide 'query' parameter to search, omit to list all. Supports filtering, sorting, and pagination. Default limit: 20, max: 100."
     )]
     pub async fn list_skills(
         &self,
@@ -89,7 +90,7 @@ impl<D: crate::db::Database + 'static> SkillTools<D> {
     ) -> Result<CallToolResult, McpError> {
         let db_query = crate::db::SkillQuery {
             page: crate::db::PageSort {
-                limit: params.0.limit,
+                limit: LIST_LIMITS.apply(params.0.limit),
                 offset: params.0.offset,
                 sort_by: params.0.sort.clone(),
                 sort_order: match params.0.order.as_deref() {
@@ -97,6 +98,7 @@ impl<D: crate::db::Database + 'static> SkillTools<D> {
                     Some("asc") => Some(crate::db::SortOrder::Asc),
                     _ => None,
                 },
+                after: None,
             },
             tags: params.0.tags.clone(),
             project_id: params.0.project_id.clone(),
@@ -10,7 +10,7 @@
 
 use crate::db::Database;
 use crate::mcp::tools::map_db_error;
-use crate::sync::{GitOps, RealGit, SyncError, SyncManager};
+use crate::sync::{GitOps, InitResult, RealGit, SyncError, SyncManager};
 use rmcp::{
     ErrorData as McpError,
     handler::server::{router::tool::ToolRouter, wrapper::Parameters},
@@ -206,14 +206,22 @@ impl<D: Database + 'static, G: GitOps + Send + Sync + 'static> SyncTools<D, G> {
 
         let content = match operation {
             SyncOperation::Init => {
-                manager
+                let result = manager
                     .init(params.remote_url)
                     .await
                     .map_err(map_sync_error)?;
 
+                let message = match result {
+                    InitResult::Created => "Sync initialized successfully",
+                    InitResult::AlreadyInitialized => "Sync already initialized",
+                    InitResult::RemoteUpdated => {
+                        "Sync already initialized, remote 'origin' updated"
+                    }
+                };
+
                 serde_json::json!({
                     "status": "success",
-                    "message": "Sync initialized successfully",
+                    "message": message,
                     "sync_dir": crate::sync::get_sync_dir().display().to_string(),
                 })
             }
@@ -256,7 +264,8 @@ impl<D: Database + 'static, G: GitOps + Send + Sync + 'static> SyncTools<D, G> {
                         "tasks": summary.tasks,
                         "notes": summary.notes,
                         "total": summary.total(),
-                    }
+                    },
+                    "warnings": summary.warnings,
                 })
             }
 
@@ -278,6 +287,8 @@ impl<D: Database + 'static, G: GitOps + Send + Sync + 'static> SyncTools<D, G> {
                                 "git": {
                                     "clean": git_status.clean,
                                     "status": if git_status.clean { "No changes" } else { &git_status.status_output },
+                                    "ahead": git_status.ahead,
+                                    "behind": git_status.behind,
                                 },
                                 "database": {
                                     "repos": db_counts.repos,
@@ -360,5 +371,11 @@ fn map_sync_error(err: SyncError) -> McpError {
                 "error": io_err.to_string(),
             })),
         ),
+        err @ SyncError::InvalidRemoteUrl { .. } => McpError::invalid_params(
+            "invalid_remote_url",
+            Some(serde_json::json!({
+                "error": err.to_string(),
+            })),
+        ),
     }
 }
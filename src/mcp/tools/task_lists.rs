@@ -13,11 +13,12 @@ use serde_json::json;
 use std::sync::Arc;
 
 use crate::api::notifier::{ChangeNotifier, UpdateMessage};
+use crate::db::pagination::LIST_LIMITS;
 use crate::db::{
     Database, PageSort, SortOrder, TaskList, TaskListQuery, TaskListRepository, TaskListStatus,
     TaskRepository,
 };
-use crate::mcp::tools::{apply_limit, map_db_error};
+use crate::mcp::tools::map_db_error;
 
 // =============================================================================
 // Parameter Structs
@@ -35,7 +36,7 @@ pub struct ListTaskListsParams {
     pub status: Option<String>,
     #[schemars(description = "Filter by project ID")]
     pub project_id: Option<String>,
-    #[schemars(description = "Maximum number of items to return (default: 10, max: 20)")]
+    #[schemars(description = "Maximum number of items to return (default: 20, max: 100)")]
     pub limit: Option<usize>,
     #[schemars(description = "Number of items to skip")]
     pub offset: Option<usize>,
@@ -117,6 +118,12 @@ pub struct GetTaskListStatsParams {
     pub id: String,
 }
 
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct GetProjectTaskStatsParams {
+    #[schemars(description = "Project ID")]
+    pub project_id: String,
+}
+
 // =============================================================================
 // TaskList Tools
 // =============================================================================
@@ -144,7 +151,7 @@ impl<D: Database + 'static> TaskListTools<D> {
     }
 
     #[tool(
-        description = "List task lists with filtering by project, status, or tags. Use this to find existing lists before creating new ones."
+        description = "List task lists with filtering by project, status, or tags. Use this to find existing lists before creating new ones. Default limit: 20, max: 100."
     )]
     pub async fn list_task_lists(
         &self,
@@ -161,7 +168,7 @@ impl<D: Database + 'static> TaskListTools<D> {
         // Build query
         let query = TaskListQuery {
             page: PageSort {
-                limit: Some(apply_limit(params.0.limit)),
+                limit: LIST_LIMITS.apply(params.0.limit),
                 offset: params.0.offset,
                 sort_by: params.0.sort.clone(),
                 sort_order: match params.0.order.as_deref() {
@@ -169,6 +176,7 @@ impl<D: Database + 'static> TaskListTools<D> {
                     Some("asc") => Some(SortOrder::Asc),
                     _ => None,
                 },
+                after: None,
             },
             status: params.0.status.clone(),
             tags,
@@ -186,7 +194,7 @@ impl<D: Database + 'static> TaskListTools<D> {
         let response = json!({
             "items": result.items,
             "total": result.total,
-            "limit": result.limit.unwrap_or(50),
+            "limit": result.limit,
             "offset": result.offset,
         });
 
@@ -397,4 +405,26 @@ impl<D: Database + 'static> TaskListTools<D> {
         })?;
         Ok(CallToolResult::success(vec![ContentBlock::text(content)]))
     }
+
+    #[tool(
+        description = "Get task statistics rolled up across all task lists of a project (task list count plus counts by status). Use to report overall project progress."
+    )]
+    pub async fn get_project_task_stats(
+        &self,
+        params: Parameters<GetProjectTaskStatsParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let stats = self
+            .db
+            .tasks()
+            .get_stats_for_project(&params.0.project_id)
+            .await
+            .map_err(map_db_error)?;
+        let content = serde_json::to_string_pretty(&stats).map_err(|e| {
+            McpError::internal_error(
+                "serialization_error",
+                Some(serde_json::json!({"error": e.to_string()})),
+            )
+        })?;
+        Ok(CallToolResult::success(vec![ContentBlock::text(content)]))
+    }
 }
@@ -6,8 +6,8 @@ use crate::db::{
     TaskListStatus, TaskRepository, TaskStatus,
 };
 use crate::mcp::tools::task_lists::{
-    CreateTaskListParams, DeleteTaskListParams, GetTaskListParams, GetTaskListStatsParams,
-    ListTaskListsParams, TaskListTools, UpdateTaskListParams,
+    CreateTaskListParams, DeleteTaskListParams, GetProjectTaskStatsParams, GetTaskListParams,
+    GetTaskListStatsParams, ListTaskListsParams, TaskListTools, UpdateTaskListParams,
 };
 use rmcp::handler::server::wrapper::Parameters;
 use rmcp::model::{CallToolResult, ContentBlock};
@@ -122,6 +122,33 @@ async fn test_create_and_get_task_list() {
     assert_eq!(fetched.title, "Sprint 1");
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_create_task_list_with_missing_project_names_the_project() {
+    let db = SqliteDatabase::in_memory().await.unwrap();
+    db.migrate().unwrap();
+    let db = Arc::new(db);
+    let tools = TaskListTools::new(db.clone(), ChangeNotifier::new());
+
+    let params = CreateTaskListParams {
+        title: "Orphan list".to_string(),
+        description: None,
+        notes: None,
+        tags: None,
+        external_refs: None,
+        repo_ids: None,
+        project_id: "noproj01".to_string(),
+    };
+
+    let err = tools
+        .create_task_list(Parameters(params))
+        .await
+        .unwrap_err();
+    assert_eq!(err.message, "not_found");
+    let data = err.data.unwrap();
+    assert_eq!(data["entity_type"], "Project");
+    assert_eq!(data["id"], "noproj01");
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn test_update_task_list() {
     let db = SqliteDatabase::in_memory().await.unwrap();
@@ -470,6 +497,120 @@ async fn test_get_task_list_stats() {
     assert_eq!(json["cancelled"], 0);
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_get_project_task_stats() {
+    let db = SqliteDatabase::in_memory().await.unwrap();
+    db.migrate().unwrap();
+    let db = Arc::new(db);
+    let tools = TaskListTools::new(db.clone(), ChangeNotifier::new());
+
+    let mut project_ids = vec![];
+    for title in ["Tracked", "Other"] {
+        let project = Project {
+            id: String::new(),
+            title: title.to_string(),
+            description: None,
+            tags: vec![],
+            external_refs: vec![],
+            repo_ids: vec![],
+            task_list_ids: vec![],
+            note_ids: vec![],
+            created_at: None,
+            updated_at: None,
+        };
+        project_ids.push(db.projects().create(&project).await.unwrap().id);
+    }
+
+    // Two lists in the tracked project (one archived), one list elsewhere
+    let seeds = [
+        (
+            &project_ids[0],
+            TaskListStatus::Active,
+            vec![
+                TaskStatus::Backlog,
+                TaskStatus::Todo,
+                TaskStatus::InProgress,
+            ],
+        ),
+        (
+            &project_ids[0],
+            TaskListStatus::Archived,
+            vec![TaskStatus::Done, TaskStatus::Done, TaskStatus::Cancelled],
+        ),
+        (
+            &project_ids[1],
+            TaskListStatus::Active,
+            vec![TaskStatus::Review],
+        ),
+    ];
+    for (project_id, list_status, statuses) in seeds {
+        let list = TaskList {
+            id: String::new(),
+            title: "Rollup".to_string(),
+            description: None,
+            notes: None,
+            tags: vec![],
+            external_refs: vec![],
+            status: list_status,
+            repo_ids: vec![],
+            project_id: project_id.clone(),
+            created_at: None,
+            updated_at: None,
+            archived_at: None,
+        };
+        let created_list = db.task_lists().create(&list).await.unwrap();
+
+        for status in statuses {
+            let task = Task {
+                id: String::new(),
+                list_id: created_list.id.clone(),
+                parent_id: None,
+                title: format!("Task with status {:?}", status),
+                description: None,
+                status,
+                priority: None,
+                tags: vec![],
+                external_refs: vec![],
+                created_at: None,
+                updated_at: None,
+            };
+            db.tasks().create(&task).await.unwrap();
+        }
+    }
+
+    let result = tools
+        .get_project_task_stats(Parameters(GetProjectTaskStatsParams {
+            project_id: project_ids[0].clone(),
+        }))
+        .await
+        .expect("get_project_task_stats should succeed");
+
+    let content_text = match &result.content[0] {
+        ContentBlock::Text(text) => text.text.as_str(),
+        _ => panic!("Expected text content"),
+    };
+    let json: serde_json::Value = serde_json::from_str(content_text).unwrap();
+
+    assert_eq!(json["project_id"], project_ids[0]);
+    assert_eq!(json["task_lists"], 2);
+    assert_eq!(json["total"], 6);
+    assert_eq!(json["backlog"], 1);
+    assert_eq!(json["todo"], 1);
+    assert_eq!(json["in_progress"], 1);
+    assert_eq!(json["review"], 0);
+    assert_eq!(json["done"], 2);
+    assert_eq!(json["cancelled"], 1);
+
+    // Unknown projects are reported by name
+    let err = tools
+        .get_project_task_stats(Parameters(GetProjectTaskStatsParams {
+            project_id: "noproj01".to_string(),
+        }))
+        .await
+        .unwrap_err();
+    assert_eq!(err.message, "not_found");
+}
+
 // =============================================================================
 // FTS5 Search Tests
 // =============================================================================
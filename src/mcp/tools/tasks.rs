@@ -12,9 +12,10 @@ use serde::{Deserialize, Serialize};
 use serde_json::json;
 use std::sync::Arc;
 
-use crate::api::notifier::{ChangeNotifier, UpdateMessage};
+use crate::api::notifier::{ChangeNotifier, UpdateMessage, changed_fields};
+use crate::db::pagination::LIST_LIMITS;
 use crate::db::{Database, PageSort, SortOrder, Task, TaskQuery, TaskRepository, TaskStatus};
-use crate::mcp::tools::{apply_limit, map_db_error};
+use crate::mcp::tools::map_db_error;
 
 // =============================================================================
 // Validation Helpers
@@ -57,7 +58,7 @@ pub struct ListTasksParams {
     )]
     #[serde(rename = "type")]
     pub task_type: Option<String>,
-    #[schemars(description = "Maximum number of tasks to return (default: 10, max: 20)")]
+    #[schemars(description = "Maximum number of tasks to return (default: 20, max: 100)")]
     pub limit: Option<usize>,
     #[schemars(description = "Number of items to skip (for pagination)")]
     pub offset: Option<usize>,
@@ -157,6 +158,7 @@ pub struct DeleteTaskParams {
 pub struct TaskTools<D: Database> {
     db: Arc<D>,
     notifier: ChangeNotifier,
+    auto_complete_parents: bool,
     tool_router: ToolRouter<Self>,
 }
 
@@ -166,10 +168,17 @@ impl<D: Database + 'static> TaskTools<D> {
         Self {
             db,
             notifier,
+            auto_complete_parents: false,
             tool_router: Self::tool_router(),
         }
     }
 
+    /// Complete a parent task once all of its subtasks are finished
+    pub fn with_auto_complete_parents(mut self, enabled: bool) -> Self {
+        self.auto_complete_parents = enabled;
+        self
+    }
+
     /// Get the tool router for this handler
     pub fn router(&self) -> &ToolRouter<Self> {
         &self.tool_router
@@ -188,7 +197,7 @@ impl<D: Database + 'static> TaskTools<D> {
         // Build query
         let query = TaskQuery {
             page: PageSort {
-                limit: Some(apply_limit(params.0.limit)),
+                limit: LIST_LIMITS.apply(params.0.limit),
                 offset: params.0.offset,
                 sort_by: params.0.sort.clone(),
                 sort_order: match params.0.order.as_deref() {
@@ -196,6 +205,7 @@ impl<D: Database + 'static> TaskTools<D> {
                     Some("asc") => Some(SortOrder::Asc),
                     _ => None,
                 },
+                after: None,
             },
             list_id: Some(params.0.list_id.clone()),
             status: status_str,
@@ -307,6 +317,7 @@ impl<D: Database + 'static> TaskTools<D> {
         for task_id in &params.0.task_ids {
             self.notifier.notify(UpdateMessage::TaskUpdated {
                 task_id: task_id.clone(),
+                changed: vec!["status".to_string()],
             });
         }
 
@@ -321,6 +332,34 @@ impl<D: Database + 'static> TaskTools<D> {
             )
         };
 
+        // Roll finished subtasks up into their parents when the server opts in
+        if self.auto_complete_parents
+            && matches!(target_status, TaskStatus::Done | TaskStatus::Cancelled)
+        {
+            let mut completed_parents = Vec::new();
+            for task in transitioned.iter().filter(|t| t.parent_id.is_some()) {
+                let completed = self
+                    .db
+                    .tasks()
+                    .complete_finished_parents(&task.id)
+                    .await
+                    .map_err(map_db_error)?;
+                for parent in completed {
+                    self.notifier.notify(UpdateMessage::TaskUpdated {
+                        task_id: parent.id.clone(),
+                        changed: vec!["status".to_string()],
+                    });
+                    completed_parents.push(parent.id);
+                }
+            }
+            if !completed_parents.is_empty() {
+                message.push_str(&format!(
+                    " All subtasks finished, so parent task(s) {} were auto-completed.",
+                    completed_parents.join(", ")
+                ));
+            }
+        }
+
         // Reminder: if subtasks moved to in_progress/review, check if parent needs attention
         if matches!(target_status, TaskStatus::InProgress | TaskStatus::Review) {
             let parent_ids: Vec<String> = transitioned
@@ -377,6 +416,7 @@ impl<D: Database + 'static> TaskTools<D> {
                 Some(serde_json::json!({"error": e.to_string()})),
             )
         })?;
+        let before = task.clone();
 
         // Update fields (content only - use transition_task for status changes)
         if let Some(title) = &params.0.title {
@@ -415,6 +455,7 @@ impl<D: Database + 'static> TaskTools<D> {
 
         self.notifier.notify(UpdateMessage::TaskUpdated {
             task_id: params.0.task_id.clone(),
+            changed: changed_fields(&before, &updated),
         });
 
         Ok(CallToolResult::success(vec![ContentBlock::text(
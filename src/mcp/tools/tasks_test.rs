@@ -251,6 +251,30 @@ async fn test_get_task_not_found() {
     assert!(result.is_err());
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_create_task_with_missing_list_names_the_list() {
+    let db = SqliteDatabase::in_memory().await.unwrap();
+    db.migrate().unwrap();
+    let db = Arc::new(db);
+    let tools = TaskTools::new(db.clone(), ChangeNotifier::new());
+
+    let params = CreateTaskParams {
+        list_id: "nolist01".to_string(),
+        title: "Orphan task".to_string(),
+        description: None,
+        priority: None,
+        parent_id: None,
+        tags: None,
+        external_refs: None,
+    };
+
+    let err = tools.create_task(Parameters(params)).await.unwrap_err();
+    assert_eq!(err.message, "not_found");
+    let data = err.data.unwrap();
+    assert_eq!(data["entity_type"], "TaskList");
+    assert_eq!(data["id"], "nolist01");
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn test_list_tasks_filtered_by_status() {
     let db = SqliteDatabase::in_memory().await.unwrap();
@@ -2338,3 +2362,92 @@ async fn test_transition_any_status_to_cancelled() {
     let task = db.tasks().get(&task.id).await.unwrap();
     assert_eq!(task.status, TaskStatus::Cancelled);
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_last_subtask_done_auto_completes_parent_when_enabled() {
+    for enabled in [true, false] {
+        let db = SqliteDatabase::in_memory().await.unwrap();
+        db.migrate().unwrap();
+        let db = Arc::new(db);
+        let tools =
+            TaskTools::new(db.clone(), ChangeNotifier::new()).with_auto_complete_parents(enabled);
+
+        let project_id = create_test_project(&db).await;
+        let list = db
+            .task_lists()
+            .create(&TaskList {
+                id: String::new(),
+                title: "Rollup".to_string(),
+                description: None,
+                notes: None,
+                tags: vec![],
+                status: crate::db::TaskListStatus::Active,
+                external_refs: vec![],
+                project_id,
+                repo_ids: vec![],
+                created_at: None,
+                updated_at: None,
+                archived_at: None,
+            })
+            .await
+            .unwrap();
+
+        let new_task = |title: &str, parent_id: Option<String>, status: TaskStatus| Task {
+            id: String::new(),
+            list_id: list.id.clone(),
+            parent_id,
+            title: title.to_string(),
+            description: None,
+            status,
+            priority: None,
+            tags: vec![],
+            external_refs: vec![],
+            created_at: None,
+            updated_at: None,
+        };
+
+        let parent = db
+            .tasks()
+            .create(&new_task("Parent", None, TaskStatus::InProgress))
+            .await
+            .unwrap();
+        db.tasks()
+            .create(&new_task(
+                "Already done",
+                Some(parent.id.clone()),
+                TaskStatus::Done,
+            ))
+            .await
+            .unwrap();
+        let last = db
+            .tasks()
+            .create(&new_task(
+                "Last one",
+                Some(parent.id.clone()),
+                TaskStatus::InProgress,
+            ))
+            .await
+            .unwrap();
+
+        let result = tools
+            .transition_task(Parameters(TransitionTaskParams {
+                task_ids: vec![last.id.clone()],
+                status: "done".to_string(),
+            }))
+            .await
+            .expect("transition should succeed");
+
+        let parent = db.tasks().get(&parent.id).await.unwrap();
+        let message = match &result.content[0] {
+            ContentBlock::Text(text) => text.text.clone(),
+            _ => panic!("Expected text content"),
+        };
+        if enabled {
+            assert_eq!(parent.status, TaskStatus::Done);
+            assert!(message.contains("auto-completed"));
+        } else {
+            assert_eq!(parent.status, TaskStatus::InProgress);
+            assert!(!message.contains("auto-completed"));
+        }
+    }
+}
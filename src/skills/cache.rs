@@ -123,9 +123,13 @@ pub fn extract_attachments(
             message: format!("Failed to write file {}: {}", file_path.display(), e),
         })?;
 
-        // Set executable permissions for shell scripts
+        // Restore the executable bit; shell scripts ingested before modes were
+        // recorded are still treated as executable
         #[cfg(unix)]
-        if attachment.filename.ends_with(".sh") || attachment.filename.ends_with(".bash") {
+        if attachment.is_executable
+            || attachment.filename.ends_with(".sh")
+            || attachment.filename.ends_with(".bash")
+        {
             use std::os::unix::fs::PermissionsExt;
             let mut perms = fs::metadata(&file_path)
                 .map_err(|e| DbError::Database {
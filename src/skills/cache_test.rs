@@ -100,6 +100,7 @@ This is a test skill for cache validation."#,
         content: BASE64.encode(script_content),
         content_hash: "abc123".to_string(),
         mime_type: Some("text/x-shellscript".to_string()),
+        is_executable: false,
         created_at: None,
         updated_at: None,
     }];
@@ -185,6 +186,7 @@ Test content"#,
             content: BASE64.encode(script_content),
             content_hash: "abc123".to_string(),
             mime_type: Some("text/x-shellscript".to_string()),
+            is_executable: false,
             created_at: None,
             updated_at: None,
         },
@@ -196,6 +198,7 @@ Test content"#,
             content: BASE64.encode(reference_content),
             content_hash: "def456".to_string(),
             mime_type: Some("text/markdown".to_string()),
+            is_executable: false,
             created_at: None,
             updated_at: None,
         },
@@ -207,6 +210,7 @@ Test content"#,
             content: BASE64.encode(nested_content),
             content_hash: "ghi789".to_string(),
             mime_type: Some("application/xml".to_string()),
+            is_executable: false,
             created_at: None,
             updated_at: None,
         },
@@ -309,6 +313,7 @@ Test content"#,
         content: BASE64.encode("test content"),
         content_hash: "hash123".to_string(),
         mime_type: Some("text/markdown".to_string()),
+        is_executable: false,
         created_at: None,
         updated_at: None,
     }];
@@ -8,7 +8,13 @@
 //! 5. Insert into database
 //! 6. Cleanup temp files
 
+use super::parser::SkillMd;
+use super::scanner::AttachmentData;
+use crate::db::sqlite::validate_skill;
+use crate::db::utils::IdLength;
 use crate::db::{Database, Skill, SkillAttachment, SkillRepository};
+use serde::Serialize;
+use std::path::{Path, PathBuf};
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -85,45 +91,15 @@ pub async fn import_skill<D: Database>(
     tags: Option<Vec<String>>,
     upsert: bool,
 ) -> Result<Skill, ImportError> {
-    // Parse source URL to determine type (git+https, git+ssh, local path)
-    let source_type = super::source::parse_source(source)?;
-
-    // Fetch source to a directory (clone for git, validate for local)
-    let source_path = super::source::fetch_source(source_type)?;
-
-    // Determine temp directory to clean up (only for Git clones)
-    // Git clones create: /tmp/c5t-skill-import-{pid}
-    // If skill_dir is inside this, we need to clean up the parent temp dir
-    let temp_dir = std::env::temp_dir().join(format!("c5t-skill-import-{}", std::process::id()));
-    let should_cleanup = source_path.starts_with(&temp_dir);
-
-    // Navigate to subpath if specified
-    let skill_dir = if let Some(path) = subpath {
-        source_path.join(path)
-    } else {
-        source_path
-    };
+    let fetched = FetchedSource::fetch(source, subpath)?;
 
     // Import logic wrapped to ensure cleanup on success or failure
     let result = async {
-        // Check for SKILL.md existence
-        let skill_md_path = skill_dir.join("SKILL.md");
-        if !skill_md_path.exists() {
-            return Err(ImportError::SkillMdNotFound);
-        }
-
-        // Parse SKILL.md (extract name + description, store full content)
-        let parsed = super::parser::parse_skill_md(&skill_md_path)?;
-
-        // Scan attachments
-        let attachments = super::scanner::scan_attachments(&skill_dir)?;
+        let (parsed, attachments) = load_skill_dir(&fetched.skill_dir)?;
 
-        // Generate deterministic skill ID from name (8-char hex checksum)
-        // This ensures same skill name = same ID, preventing duplicates
-        let skill_id = super::generate_skill_id(&parsed.name);
-
-        // Check if skill already exists (before creating the new skill struct)
-        let existing = db.skills().get(&skill_id).await.ok();
+        // Deterministic skill ID from name (hex checksum of the configured
+        // length), so the same skill name maps to the same ID
+        let (skill_id, existing) = find_existing_skill(db, &parsed.name).await;
 
         // Determine tags and project_ids: preserve existing if not provided, otherwise use new
         let final_tags = match (&tags, &existing) {
@@ -140,29 +116,9 @@ pub async fn import_skill<D: Database>(
 
         // Create skill
         let skill = Skill {
-            id: skill_id.clone(),
-            name: parsed.name,
-            description: parsed.description,
-            content: parsed.content,
             tags: final_tags,
             project_ids: final_project_ids,
-            scripts: attachments
-                .iter()
-                .filter(|a| a.type_ == "script")
-                .map(|a| a.filename.clone())
-                .collect(),
-            references: attachments
-                .iter()
-                .filter(|a| a.type_ == "reference")
-                .map(|a| a.filename.clone())
-                .collect(),
-            assets: attachments
-                .iter()
-                .filter(|a| a.type_ == "asset")
-                .map(|a| a.filename.clone())
-                .collect(),
-            created_at: None,
-            updated_at: None,
+            ..build_skill(skill_id.clone(), parsed, &attachments)
         };
 
         // Check if we need to update (skill exists and upsert is true)
@@ -204,6 +160,7 @@ pub async fn import_skill<D: Database>(
                 content: attachment_data.content_base64,
                 content_hash: attachment_data.content_hash,
                 mime_type: attachment_data.mime_type,
+                is_executable: attachment_data.is_executable,
                 created_at: None,
                 updated_at: None,
             };
@@ -217,14 +174,188 @@ pub async fn import_skill<D: Database>(
     }
     .await;
 
-    // Cleanup temp directory if this was a Git clone
-    if should_cleanup && temp_dir.exists() {
-        std::fs::remove_dir_all(&temp_dir).ok(); // Ignore cleanup errors
+    fetched.cleanup();
+
+    result
+}
+
+/// Resolve the ID a skill named `name` is stored under, with the stored skill.
+///
+/// Skills imported before the ID length was raised keep their 8-char ID,
+/// which is a prefix of the longer one; that legacy ID is reused when it
+/// exists so re-importing updates the skill instead of duplicating it.
+async fn find_existing_skill<D: Database>(db: &D, name: &str) -> (String, Option<Skill>) {
+    let skill_id = super::generate_skill_id(name, db.id_length());
+    if let Ok(skill) = db.skills().get(&skill_id).await {
+        return (skill_id, Some(skill));
+    }
+
+    let legacy_id = super::generate_skill_id(name, IdLength::default());
+    if legacy_id != skill_id
+        && let Ok(skill) = db.skills().get(&legacy_id).await
+    {
+        return (legacy_id, Some(skill));
     }
 
+    (skill_id, None)
+}
+
+/// Attachment entry in an import preview (content omitted)
+#[derive(Debug, Clone, Serialize)]
+pub struct AttachmentManifestEntry {
+    /// Attachment type: "script", "reference", or "asset"
+    #[serde(rename = "type")]
+    pub type_: String,
+    /// Relative path from skill directory
+    pub filename: String,
+    /// SHA256 hash of file content
+    pub content_hash: String,
+    /// MIME type (if detectable)
+    pub mime_type: Option<String>,
+}
+
+/// Result of previewing a skill import without writing to the database
+#[derive(Debug, Clone, Serialize)]
+pub struct SkillPreview {
+    /// Deterministic ID the skill would be stored under
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    /// Whether a skill with this ID already exists (import would need `upsert`)
+    pub exists: bool,
+    pub attachments: Vec<AttachmentManifestEntry>,
+}
+
+/// Preview a skill import without touching the database
+///
+/// Fetches and parses the source exactly like [`import_skill`] and runs the
+/// same validation the repository applies on insert, but returns the parsed
+/// metadata and attachment manifest instead of storing anything.
+pub async fn preview_skill<D: Database>(
+    db: &D,
+    source: &str,
+    subpath: Option<&str>,
+) -> Result<SkillPreview, ImportError> {
+    let fetched = FetchedSource::fetch(source, subpath)?;
+
+    let result = async {
+        let (parsed, attachments) = load_skill_dir(&fetched.skill_dir)?;
+        let (skill_id, existing) = find_existing_skill(db, &parsed.name).await;
+        let skill = build_skill(skill_id, parsed, &attachments);
+
+        validate_skill(&skill).map_err(|e| ImportError::ValidationError(e.to_string()))?;
+
+        let exists = existing.is_some();
+
+        Ok(SkillPreview {
+            id: skill.id,
+            name: skill.name,
+            description: skill.description,
+            exists,
+            attachments: attachments
+                .into_iter()
+                .map(|a| AttachmentManifestEntry {
+                    type_: a.type_,
+                    filename: a.filename,
+                    content_hash: a.content_hash,
+                    mime_type: a.mime_type,
+                })
+                .collect(),
+        })
+    }
+    .await;
+
+    fetched.cleanup();
+
     result
 }
 
+/// A skill source fetched to the local filesystem
+struct FetchedSource {
+    /// Directory containing SKILL.md (source root joined with subpath)
+    skill_dir: PathBuf,
+    /// Temp directory to remove afterwards (only for Git clones)
+    temp_dir: Option<PathBuf>,
+}
+
+impl FetchedSource {
+    fn fetch(source: &str, subpath: Option<&str>) -> Result<Self, ImportError> {
+        // Parse source URL to determine type (git+https, git+ssh, local path)
+        let source_type = super::source::parse_source(source)?;
+
+        // Fetch source to a directory (clone for git, validate for local)
+        let source_path = super::source::fetch_source(source_type)?;
+
+        // Git clones create: /tmp/c5t-skill-import-{pid}
+        // If skill_dir is inside this, we need to clean up the parent temp dir
+        let temp_dir =
+            std::env::temp_dir().join(format!("c5t-skill-import-{}", std::process::id()));
+        let temp_dir = source_path.starts_with(&temp_dir).then_some(temp_dir);
+
+        // Navigate to subpath if specified
+        let skill_dir = if let Some(path) = subpath {
+            source_path.join(path)
+        } else {
+            source_path
+        };
+
+        Ok(Self {
+            skill_dir,
+            temp_dir,
+        })
+    }
+
+    fn cleanup(self) {
+        if let Some(temp_dir) = self.temp_dir
+            && temp_dir.exists()
+        {
+            std::fs::remove_dir_all(&temp_dir).ok(); // Ignore cleanup errors
+        }
+    }
+}
+
+/// Parse SKILL.md and scan attachments in a skill directory
+fn load_skill_dir(skill_dir: &Path) -> Result<(SkillMd, Vec<AttachmentData>), ImportError> {
+    // Check for SKILL.md existence
+    let skill_md_path = skill_dir.join("SKILL.md");
+    if !skill_md_path.exists() {
+        return Err(ImportError::SkillMdNotFound);
+    }
+
+    // Parse SKILL.md (extract name + description, store full content)
+    let parsed = super::parser::parse_skill_md(&skill_md_path)?;
+
+    // Scan attachments
+    let attachments = super::scanner::scan_attachments(skill_dir)?;
+
+    Ok((parsed, attachments))
+}
+
+/// Build an unlinked, untagged skill from parsed SKILL.md and its attachments
+fn build_skill(id: String, parsed: SkillMd, attachments: &[AttachmentData]) -> Skill {
+    let filenames = |type_: &str| {
+        attachments
+            .iter()
+            .filter(|a| a.type_ == type_)
+            .map(|a| a.filename.clone())
+            .collect()
+    };
+
+    Skill {
+        id,
+        name: parsed.name,
+        description: parsed.description,
+        content: parsed.content,
+        tags: vec![],
+        project_ids: vec![],
+        scripts: filenames("script"),
+        references: filenames("reference"),
+        assets: filenames("asset"),
+        created_at: None,
+        updated_at: None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -671,4 +802,117 @@ description: Test adding
 
         std::fs::remove_dir_all(&temp_dir).ok();
     }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_preview_returns_manifest_without_storing() {
+        use crate::db::utils::generate_entity_id;
+
+        let db = SqliteDatabase::in_memory()
+            .await
+            .expect("Failed to create in-memory database");
+        db.migrate().expect("Migration should succeed");
+
+        let temp_dir =
+            std::env::temp_dir().join(format!("test-skill-preview-{}", generate_entity_id()));
+        std::fs::create_dir_all(temp_dir.join("scripts")).unwrap();
+        std::fs::write(
+            temp_dir.join("SKILL.md"),
+            r#"---
+name: Preview Skill
+description: Parsed but not stored
+---
+
+# Preview Skill
+"#,
+        )
+        .unwrap();
+        std::fs::write(temp_dir.join("scripts/run.sh"), "#!/bin/bash\necho run").unwrap();
+        std::fs::write(temp_dir.join("notes.md"), "# Notes").unwrap();
+
+        let result = preview_skill(&db, temp_dir.to_str().unwrap(), None).await;
+
+        std::fs::remove_dir_all(&temp_dir).ok();
+
+        let preview = result.expect("Preview should succeed");
+        assert_eq!(
+            preview.id,
+            crate::skills::generate_skill_id("Preview Skill", IdLength::default())
+        );
+        assert_eq!(preview.name, "Preview Skill");
+        assert_eq!(preview.description, "Parsed but not stored");
+        assert!(!preview.exists);
+
+        let mut filenames: Vec<_> = preview
+            .attachments
+            .iter()
+            .map(|a| (a.type_.as_str(), a.filename.as_str()))
+            .collect();
+        filenames.sort();
+        assert_eq!(
+            filenames,
+            vec![("reference", "notes.md"), ("script", "scripts/run.sh")]
+        );
+
+        // Nothing was written
+        assert!(
+            db.skills().get(&preview.id).await.is_err(),
+            "Preview must not create a skill row"
+        );
+    }
+
+    #[cfg(unix)]
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_executable_bit_survives_sync_round_trip() {
+        use crate::db::SyncRepository;
+        use crate::skills::extract_attachments;
+        use std::os::unix::fs::PermissionsExt;
+
+        let source = SqliteDatabase::in_memory().await.unwrap();
+        source.migrate().unwrap();
+        let target = SqliteDatabase::in_memory().await.unwrap();
+        target.migrate().unwrap();
+
+        let skill_dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(
+            skill_dir.path().join("SKILL.md"),
+            "---\nname: exec-skill\ndescription: Has an executable script\n---\n\n# Exec\n",
+        )
+        .unwrap();
+        // .py is not covered by the .sh/.bash fallback, so only the stored mode
+        // can make it executable again
+        let script = skill_dir.path().join("run.py");
+        std::fs::write(&script, "#!/usr/bin/env python3\nprint('run')\n").unwrap();
+        std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755)).unwrap();
+        std::fs::write(skill_dir.path().join("helper.py"), "VALUE = 1\n").unwrap();
+
+        let skill = import_skill(
+            &source,
+            skill_dir.path().to_str().unwrap(),
+            None,
+            None,
+            None,
+            false,
+        )
+        .await
+        .unwrap();
+
+        let sync_dir = tempfile::TempDir::new().unwrap();
+        source.sync().export_all(sync_dir.path()).await.unwrap();
+        target.sync().import_all(sync_dir.path()).await.unwrap();
+
+        let attachments = target.skills().get_attachments(&skill.id).await.unwrap();
+        let cache_base = tempfile::TempDir::new().unwrap();
+        let cache_dir =
+            extract_attachments(cache_base.path(), &skill.name, &skill.content, &attachments)
+                .unwrap();
+
+        let mode = |name: &str| {
+            std::fs::metadata(cache_dir.join(name))
+                .unwrap()
+                .permissions()
+                .mode()
+        };
+        assert_ne!(mode("run.py") & 0o111, 0, "run.py should be executable");
+        assert_eq!(mode("helper.py") & 0o111, 0, "helper.py should not be");
+    }
 }
@@ -14,6 +14,8 @@ mod parser;
 mod scanner;
 mod source;
 
+use crate::db::utils::IdLength;
+
 // Re-export cache functions
 pub use cache::{
     clear_all_caches, extract_attachments, get_skill_cache_dir, get_skills_cache_dir,
@@ -21,20 +23,20 @@ pub use cache::{
 };
 
 // Re-export import functions
-pub use import::{ImportError, import_skill};
+pub use import::{AttachmentManifestEntry, ImportError, SkillPreview, import_skill, preview_skill};
 
 /// Generate deterministic skill ID from skill name.
-/// Uses SHA256 hash of name, truncated to 8-char hex (first 4 bytes).
-/// Same name = same ID.
-pub fn generate_skill_id(name: &str) -> String {
+/// Uses SHA256 hash of name, truncated to `length` hex chars.
+/// Same name = same ID; a longer ID starts with the shorter one.
+pub fn generate_skill_id(name: &str, length: IdLength) -> String {
     use sha2::{Digest, Sha256};
     let mut hasher = Sha256::new();
     hasher.update(name.as_bytes());
     let hash = hasher.finalize();
-    format!(
-        "{:08x}",
-        &hash[..4].iter().fold(0u32, |acc, &b| (acc << 8) | b as u32)
-    )
+    hash[..length.get() / 2]
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
 }
 
 #[cfg(test)]
@@ -58,7 +60,7 @@ mod tests {
         ];
 
         for name in test_names {
-            let id = generate_skill_id(name);
+            let id = generate_skill_id(name, IdLength::default());
             assert_eq!(
                 id.len(),
                 8,
@@ -82,8 +84,8 @@ mod tests {
     fn test_generate_skill_id_deterministic() {
         // Same name should always produce the same ID
         let name = "rust-async-patterns";
-        let id1 = generate_skill_id(name);
-        let id2 = generate_skill_id(name);
+        let id1 = generate_skill_id(name, IdLength::default());
+        let id2 = generate_skill_id(name, IdLength::default());
 
         assert_eq!(id1, id2, "Same skill name should generate the same ID");
         assert_eq!(id1.len(), 8, "ID should be 8 characters");
@@ -95,8 +97,8 @@ mod tests {
         let name1 = "skill-one";
         let name2 = "skill-two";
 
-        let id1 = generate_skill_id(name1);
-        let id2 = generate_skill_id(name2);
+        let id1 = generate_skill_id(name1, IdLength::default());
+        let id2 = generate_skill_id(name2, IdLength::default());
 
         assert_ne!(
             id1, id2,
@@ -105,4 +107,17 @@ mod tests {
         assert_eq!(id1.len(), 8, "ID1 should be 8 characters");
         assert_eq!(id2.len(), 8, "ID2 should be 8 characters");
     }
+
+    #[test]
+    fn test_generate_skill_id_uses_configured_length() {
+        let name = "rust-async-patterns";
+        let short = generate_skill_id(name, IdLength::default());
+
+        for length in IdLength::SUPPORTED {
+            let id = generate_skill_id(name, IdLength::new(length).unwrap());
+            assert_eq!(id.len(), length);
+            // Longer IDs extend the 8-char one, so legacy IDs stay derivable
+            assert!(id.starts_with(&short));
+        }
+    }
 }
@@ -41,6 +41,9 @@ pub struct AttachmentData {
 
     /// MIME type (if detectable)
     pub mime_type: Option<String>,
+
+    /// Whether the file has any executable bit set (always false off Unix)
+    pub is_executable: bool,
 }
 
 /// Scan a skill directory recursively for all files
@@ -123,12 +126,15 @@ fn scan_directory_recursive(
             // Detect MIME type
             let mime_type = detect_mime_type(&file_name);
 
+            let is_executable = is_executable(&path)?;
+
             attachments.push(AttachmentData {
                 type_,
                 filename: relative_path,
                 content_base64,
                 content_hash,
                 mime_type,
+                is_executable,
             });
         }
     }
@@ -174,6 +180,20 @@ fn classify_file(filename: &str) -> String {
     }
 }
 
+/// Check whether a file has any executable bit set
+#[cfg(unix)]
+fn is_executable(path: &Path) -> Result<bool, ScannerError> {
+    use std::os::unix::fs::PermissionsExt;
+    let metadata = std::fs::metadata(path)
+        .map_err(|e| ScannerError::ReadFileError(format!("{}: {}", path.display(), e)))?;
+    Ok(metadata.permissions().mode() & 0o111 != 0)
+}
+
+#[cfg(not(unix))]
+fn is_executable(_path: &Path) -> Result<bool, ScannerError> {
+    Ok(false)
+}
+
 /// Compute SHA256 hash of data
 fn sha256_hash(data: &[u8]) -> String {
     use sha2::{Digest, Sha256};
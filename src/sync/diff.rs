@@ -0,0 +1,185 @@
+//! Entity-by-entity comparison of the sync files with the database.
+
+use crate::db::{
+    Database, Note, NoteRepository, Project, ProjectRepository, Repo, RepoRepository, Skill,
+    SkillRepository, Task, TaskList, TaskListRepository, TaskRepository,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use super::SyncEntity;
+use super::import::ImportError;
+use super::jsonl::{JsonlError, read_live_jsonl, sync_file};
+use super::preview::IGNORED_FIELDS;
+
+/// How the records of one entity type differ between the sync files and the database.
+///
+/// IDs in each list are sorted.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EntityDiff {
+    pub entity_type: String,
+    /// In the sync files but not in the database
+    pub added: Vec<String>,
+    /// In both, with a different `updated_at` (for repos: different fields)
+    pub modified: Vec<String>,
+    /// In the database but not in the sync files
+    pub deleted: Vec<String>,
+}
+
+impl EntityDiff {
+    fn between(
+        entity: SyncEntity,
+        local: &BTreeMap<String, Value>,
+        incoming: &BTreeMap<String, Value>,
+    ) -> Self {
+        let mut diff = Self {
+            entity_type: entity.to_string(),
+            ..Self::default()
+        };
+        for (id, stamp) in incoming {
+            match local.get(id) {
+                None => diff.added.push(id.clone()),
+                Some(current) if current != stamp => diff.modified.push(id.clone()),
+                Some(_) => {}
+            }
+        }
+        diff.deleted = local
+            .keys()
+            .filter(|id| !incoming.contains_key(*id))
+            .cloned()
+            .collect();
+        diff
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.modified.is_empty() && self.deleted.is_empty()
+    }
+}
+
+/// Differences between the sync files and the database, per entity type.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SyncDiff {
+    pub entities: Vec<EntityDiff>,
+}
+
+impl SyncDiff {
+    pub fn added(&self) -> usize {
+        self.entities.iter().map(|e| e.added.len()).sum()
+    }
+
+    pub fn modified(&self) -> usize {
+        self.entities.iter().map(|e| e.modified.len()).sum()
+    }
+
+    pub fn deleted(&self) -> usize {
+        self.entities.iter().map(|e| e.deleted.len()).sum()
+    }
+
+    pub fn is_clean(&self) -> bool {
+        self.entities.iter().all(EntityDiff::is_empty)
+    }
+}
+
+/// What decides whether a record changed: its `updated_at`, or for records
+/// without one every stored field.
+fn stamp<T: Serialize>(record: &T) -> Result<Value, JsonlError> {
+    let value = serde_json::to_value(record)?;
+    if let Some(updated_at) = value.get("updated_at").filter(|v| !v.is_null()) {
+        return Ok(updated_at.clone());
+    }
+    Ok(stored_fields(value))
+}
+
+/// A record's stored fields, without bookkeeping timestamps.
+fn stored_fields(mut value: Value) -> Value {
+    if let Some(fields) = value.as_object_mut() {
+        fields.retain(|field, _| !IGNORED_FIELDS.contains(&field.as_str()));
+    }
+    value
+}
+
+fn stamps<T: Serialize>(
+    records: &[T],
+    id_of: impl Fn(&T) -> &str,
+) -> Result<BTreeMap<String, Value>, JsonlError> {
+    records
+        .iter()
+        .map(|record| Ok((id_of(record).to_string(), stamp(record)?)))
+        .collect()
+}
+
+fn field_stamps<T: Serialize>(
+    records: &[T],
+    id_of: impl Fn(&T) -> &str,
+) -> Result<BTreeMap<String, Value>, JsonlError> {
+    records
+        .iter()
+        .map(|record| {
+            Ok((
+                id_of(record).to_string(),
+                stored_fields(serde_json::to_value(record)?),
+            ))
+        })
+        .collect()
+}
+
+/// Read a sync file, treating a missing one as empty.
+fn read_records<T: for<'de> Deserialize<'de>>(path: &Path) -> Result<Vec<T>, JsonlError> {
+    if path.exists() {
+        read_live_jsonl(path)
+    } else {
+        Ok(Vec::new())
+    }
+}
+
+/// Compare the JSONL files in `sync_dir` with the database, entity by entity.
+///
+/// Records are matched by ID and compared by `updated_at`. Nothing is written.
+/// Task transitions and skill attachments are not compared.
+pub async fn diff_all<D: Database>(db: &D, sync_dir: &Path) -> Result<SyncDiff, ImportError> {
+    let mut diff = SyncDiff::default();
+
+    for entity in SyncEntity::ALL {
+        let path = sync_file(sync_dir, entity.files()[0]);
+        let (local, incoming) = match entity {
+            SyncEntity::Repos => {
+                // Compare full records (with project links): repos exported
+                // by older versions have no updated_at to go by
+                let mut repos = Vec::new();
+                for repo in db.repos().list(None).await?.items {
+                    repos.push(db.repos().get(&repo.id).await?);
+                }
+                (
+                    field_stamps(&repos, |r| &r.id)?,
+                    field_stamps(&read_records::<Repo>(&path)?, |r| &r.id)?,
+                )
+            }
+            SyncEntity::Projects => (
+                stamps(&db.projects().list(None).await?.items, |p| &p.id)?,
+                stamps(&read_records::<Project>(&path)?, |p| &p.id)?,
+            ),
+            SyncEntity::TaskLists => (
+                stamps(&db.task_lists().list(None).await?.items, |l| &l.id)?,
+                stamps(&read_records::<TaskList>(&path)?, |l| &l.id)?,
+            ),
+            SyncEntity::Tasks => (
+                stamps(&db.tasks().list(None).await?.items, |t| &t.id)?,
+                stamps(&read_records::<Task>(&path)?, |t| &t.id)?,
+            ),
+            SyncEntity::Notes => (
+                stamps(&db.notes().list(None).await?.items, |n| &n.id)?,
+                stamps(&read_records::<Note>(&path)?, |n| &n.id)?,
+            ),
+            SyncEntity::Skills => (
+                stamps(&db.skills().list(None).await?.items, |s| &s.id)?,
+                stamps(&read_records::<Skill>(&path)?, |s| &s.id)?,
+            ),
+        };
+        diff.entities
+            .push(EntityDiff::between(entity, &local, &incoming));
+    }
+
+    Ok(diff)
+}
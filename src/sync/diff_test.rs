@@ -0,0 +1,107 @@
+use crate::db::{Database, Note, NoteRepository, Repo, RepoRepository, SqliteDatabase};
+use crate::sync::diff::*;
+use crate::sync::jsonl::write_jsonl;
+use tempfile::TempDir;
+
+async fn setup_test_db() -> SqliteDatabase {
+    let db = SqliteDatabase::in_memory().await.unwrap();
+    db.migrate().unwrap();
+    db
+}
+
+fn note(id: &str, updated_at: &str) -> Note {
+    Note {
+        id: id.to_string(),
+        title: format!("Note {}", id),
+        content: "Body".to_string(),
+        tags: vec![],
+        parent_id: None,
+        idx: None,
+        repo_ids: vec![],
+        project_ids: vec![],
+        subnote_count: None,
+        created_at: Some("2025-01-01 00:00:00".to_string()),
+        updated_at: Some(updated_at.to_string()),
+    }
+}
+
+fn entity<'a>(diff: &'a SyncDiff, entity_type: &str) -> &'a EntityDiff {
+    diff.entities
+        .iter()
+        .find(|e| e.entity_type == entity_type)
+        .unwrap()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_diff_reports_added_modified_and_deleted_notes() {
+    let db = setup_test_db().await;
+    let temp_dir = TempDir::new().unwrap();
+
+    db.notes()
+        .create(&note("note0001", "2025-01-01 00:00:00"))
+        .await
+        .unwrap();
+    db.notes()
+        .create(&note("note0002", "2025-01-01 00:00:00"))
+        .await
+        .unwrap();
+    db.notes()
+        .create(&note("note0003", "2025-01-01 00:00:00"))
+        .await
+        .unwrap();
+
+    write_jsonl(
+        &temp_dir.path().join("notes.jsonl"),
+        &[
+            note("note0001", "2025-01-01 00:00:00"),
+            note("note0002", "2025-02-01 00:00:00"),
+            note("note0004", "2025-02-01 00:00:00"),
+        ],
+    )
+    .unwrap();
+
+    let diff = diff_all(&db, temp_dir.path()).await.unwrap();
+
+    let notes = entity(&diff, "notes");
+    assert_eq!(notes.added, vec!["note0004"]);
+    assert_eq!(notes.modified, vec!["note0002"]);
+    assert_eq!(notes.deleted, vec!["note0003"]);
+    assert_eq!((diff.added(), diff.modified(), diff.deleted()), (1, 1, 1));
+    assert!(!diff.is_clean());
+
+    // Every entity type is reported, even without a sync file
+    assert_eq!(diff.entities.len(), 6);
+    assert!(entity(&diff, "tasks").is_empty());
+
+    // Nothing was written
+    assert!(db.notes().get("note0004").await.is_err());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_diff_compares_repo_fields() {
+    let db = setup_test_db().await;
+    let temp_dir = TempDir::new().unwrap();
+
+    let repo = Repo {
+        id: "repo0001".to_string(),
+        remote: "github:user/project".to_string(),
+        path: None,
+        tags: vec![],
+        project_ids: vec![],
+        created_at: Some("2025-01-01 00:00:00".to_string()),
+        updated_at: None,
+    };
+    db.repos().create(&repo).await.unwrap();
+
+    write_jsonl(&temp_dir.path().join("repos.jsonl"), &[repo.clone()]).unwrap();
+    let diff = diff_all(&db, temp_dir.path()).await.unwrap();
+    assert!(diff.is_clean());
+
+    let moved = Repo {
+        path: Some("/home/user/project".to_string()),
+        ..repo
+    };
+    write_jsonl(&temp_dir.path().join("repos.jsonl"), &[moved]).unwrap();
+    let diff = diff_all(&db, temp_dir.path()).await.unwrap();
+    assert_eq!(entity(&diff, "repos").modified, vec!["repo0001"]);
+}
@@ -0,0 +1,77 @@
+//! Entity types that can be synced individually.
+
+use std::fmt;
+use std::str::FromStr;
+
+/// A syncable entity type and the JSONL files that hold it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SyncEntity {
+    Repos,
+    Projects,
+    TaskLists,
+    /// Tasks together with their transition log
+    Tasks,
+    Notes,
+    /// Skills together with their attachments
+    Skills,
+}
+
+impl SyncEntity {
+    /// Every entity type, in import order.
+    pub const ALL: [SyncEntity; 6] = [
+        SyncEntity::Projects,
+        SyncEntity::Repos,
+        SyncEntity::TaskLists,
+        SyncEntity::Tasks,
+        SyncEntity::Notes,
+        SyncEntity::Skills,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SyncEntity::Repos => "repos",
+            SyncEntity::Projects => "projects",
+            SyncEntity::TaskLists => "task_lists",
+            SyncEntity::Tasks => "tasks",
+            SyncEntity::Notes => "notes",
+            SyncEntity::Skills => "skills",
+        }
+    }
+
+    /// JSONL files written and read for this entity type.
+    pub fn files(&self) -> &'static [&'static str] {
+        match self {
+            SyncEntity::Repos => &["repos.jsonl"],
+            SyncEntity::Projects => &["projects.jsonl"],
+            SyncEntity::TaskLists => &["lists.jsonl"],
+            SyncEntity::Tasks => &["tasks.jsonl", "task_transition_log.jsonl"],
+            SyncEntity::Notes => &["notes.jsonl"],
+            SyncEntity::Skills => &["skills.jsonl", "skills_attachments.jsonl"],
+        }
+    }
+}
+
+impl fmt::Display for SyncEntity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for SyncEntity {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "repos" => Ok(SyncEntity::Repos),
+            "projects" => Ok(SyncEntity::Projects),
+            "task_lists" | "lists" => Ok(SyncEntity::TaskLists),
+            "tasks" => Ok(SyncEntity::Tasks),
+            "notes" => Ok(SyncEntity::Notes),
+            "skills" => Ok(SyncEntity::Skills),
+            _ => Err(format!(
+                "Invalid entity type: '{}'. Valid types: repos, projects, task_lists, tasks, notes, skills",
+                s
+            )),
+        }
+    }
+}
@@ -1,10 +1,11 @@
 //! Export database entities to JSONL files.
 
 use crate::db::{
-    Database, NoteRepository, ProjectRepository, RepoRepository, SkillRepository,
-    TaskListRepository, TaskRepository,
+    Database, NoteRepository, ProjectRepository, RepoRepository, Skill, SkillAttachment,
+    SkillRepository, TaskListRepository, TaskRepository,
 };
 use miette::Diagnostic;
+use serde::{Deserialize, Serialize};
 use std::path::Path;
 use thiserror::Error;
 
@@ -107,8 +108,9 @@ pub async fn export_all<D: Database>(
     let mut skills = Vec::new();
     let mut all_attachments = Vec::new();
     for skill in skills_list.items {
-        let full_skill = db.skills().get(&skill.id).await?;
-        let attachments = db.skills().get_attachments(&full_skill.id).await?;
+        let mut full_skill = db.skills().get(&skill.id).await?;
+        let mut attachments = db.skills().get_attachments(&full_skill.id).await?;
+        sort_skill_for_export(&mut full_skill, &mut attachments);
         skills.push(full_skill);
         all_attachments.extend(attachments);
     }
@@ -136,8 +138,20 @@ pub async fn export_all<D: Database>(
     Ok(summary)
 }
 
+/// Put a skill's filename lists and its attachments into a stable order.
+///
+/// Attachments are sorted by type, then filename, so re-inserting them in the
+/// database doesn't reorder `skills_attachments.jsonl` and produce noisy diffs.
+pub(crate) fn sort_skill_for_export(skill: &mut Skill, attachments: &mut [SkillAttachment]) {
+    skill.scripts.sort();
+    skill.references.sort();
+    skill.assets.sort();
+    attachments.sort_by(|a, b| (&a.type_, &a.filename).cmp(&(&b.type_, &b.filename)));
+}
+
 /// Summary of exported entities.
-#[derive(Debug, Default, PartialEq, Eq)]
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct ExportSummary {
     pub repos: usize,
     pub projects: usize,
@@ -50,6 +50,7 @@ async fn test_export_with_data() {
         tags: vec!["test".to_string()],
         project_ids: vec![],
         created_at: Some("2024-01-01T00:00:00Z".to_string()),
+        updated_at: None,
     };
     db.repos().create(&repo).await.unwrap();
 
@@ -141,6 +142,7 @@ async fn test_export_includes_relationships() {
         tags: vec!["test".to_string()],
         project_ids: vec!["proj0001".to_string()],
         created_at: Some("2024-01-01T00:00:00Z".to_string()),
+        updated_at: None,
     };
     db.repos().create(&repo).await.unwrap();
 
@@ -503,6 +505,7 @@ Run scripts/deploy.sh
         content: base64::prelude::BASE64_STANDARD.encode(b"#!/bin/bash\necho 'deploying'"),
         content_hash: "abc123".to_string(),
         mime_type: Some("text/x-shellscript".to_string()),
+        is_executable: false,
         created_at: Some("2024-01-01T00:00:00Z".to_string()),
         updated_at: Some("2024-01-01T00:00:00Z".to_string()),
     };
@@ -519,6 +522,7 @@ Run scripts/deploy.sh
         content: base64::prelude::BASE64_STANDARD.encode(b"# API Documentation"),
         content_hash: "def456".to_string(),
         mime_type: Some("text/markdown".to_string()),
+        is_executable: false,
         created_at: Some("2024-01-01T00:00:00Z".to_string()),
         updated_at: Some("2024-01-01T00:00:00Z".to_string()),
     };
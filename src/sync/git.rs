@@ -6,6 +6,7 @@
 use miette::Diagnostic;
 use std::path::Path;
 use std::process::{Command, Output};
+use std::time::Duration;
 use thiserror::Error;
 
 #[cfg(test)]
@@ -27,6 +28,112 @@ pub enum GitError {
     GitNotFound,
 }
 
+/// Output fragments of git network failures that are worth retrying.
+const TRANSIENT_MARKERS: &[&str] = &[
+    "could not resolve host",
+    "connection timed out",
+    "operation timed out",
+    "connection reset",
+    "connection refused",
+    "network is unreachable",
+    "temporary failure in name resolution",
+    "the remote end hung up unexpectedly",
+    "early eof",
+    "rpc failed",
+];
+
+/// Output fragments that rule out a retry even when a network marker matches:
+/// a retry cannot fix bad credentials or a rejected push.
+const PERMANENT_MARKERS: &[&str] = &[
+    "authentication failed",
+    "permission denied",
+    "could not read username",
+    "returned error: 401",
+    "returned error: 403",
+    "[rejected]",
+    "non-fast-forward",
+    "conflict",
+];
+
+impl GitError {
+    /// Whether this failure looks like a transient network problem.
+    pub fn is_transient(&self) -> bool {
+        let GitError::NonZeroExit { output, .. } = self else {
+            return false;
+        };
+        let output = output.to_lowercase();
+
+        !PERMANENT_MARKERS.iter().any(|m| output.contains(m))
+            && TRANSIENT_MARKERS.iter().any(|m| output.contains(m))
+    }
+}
+
+/// Default retries for git network operations (push/pull).
+pub const DEFAULT_GIT_RETRIES: u32 = 3;
+
+/// Bounded retry with exponential backoff for git network operations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Retries after the first attempt (0 disables retrying)
+    pub max_retries: u32,
+    /// Delay before the first retry; doubled for each further retry
+    pub initial_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: DEFAULT_GIT_RETRIES,
+            initial_backoff: Duration::from_millis(500),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Default policy with `max_retries` read from C5T_GIT_RETRIES when set.
+    pub fn from_env() -> Self {
+        let max_retries = std::env::var("C5T_GIT_RETRIES")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(DEFAULT_GIT_RETRIES);
+
+        Self {
+            max_retries,
+            ..Self::default()
+        }
+    }
+
+    /// Run `op`, retrying transient failures until the retry budget is spent.
+    ///
+    /// Non-transient errors (authentication, rejected pushes, conflicts) are
+    /// returned immediately.
+    pub fn run<F>(&self, name: &str, mut op: F) -> Result<Output, GitError>
+    where
+        F: FnMut() -> Result<Output, GitError>,
+    {
+        let mut backoff = self.initial_backoff;
+        let mut attempt = 0;
+
+        loop {
+            match op() {
+                Err(e) if attempt < self.max_retries && e.is_transient() => {
+                    attempt += 1;
+                    tracing::warn!(
+                        operation = name,
+                        attempt,
+                        max_retries = self.max_retries,
+                        error = %e,
+                        "Transient git failure, retrying"
+                    );
+                    std::thread::sleep(backoff);
+                    backoff = backoff.saturating_mul(2);
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
 /// Trait for git operations. Can be mocked in tests.
 #[cfg_attr(test, automock)]
 pub trait GitOps {
@@ -36,12 +143,18 @@ pub trait GitOps {
     /// Add a remote to the repository.
     fn add_remote(&self, path: &Path, name: &str, url: &str) -> Result<Output, GitError>;
 
+    /// Change the URL of an existing remote.
+    fn set_remote_url(&self, path: &Path, name: &str, url: &str) -> Result<Output, GitError>;
+
     /// Get the URL of a remote.
     fn remote_get_url(&self, path: &Path, name: &str) -> Result<Output, GitError>;
 
     /// Get repository status in porcelain format.
     fn status_porcelain(&self, path: &Path) -> Result<Output, GitError>;
 
+    /// Count the commits in a revision range (`git rev-list --count`).
+    fn rev_list_count(&self, path: &Path, range: &str) -> Result<Output, GitError>;
+
     /// Add files to the staging area.
     fn add_files(&self, path: &Path, files: &[String]) -> Result<Output, GitError>;
 
@@ -53,6 +166,12 @@ pub trait GitOps {
 
     /// Push to a remote repository.
     fn push(&self, path: &Path, remote: &str, branch: &str) -> Result<Output, GitError>;
+
+    /// Clone `url` into `dest`, which must not exist or be an empty directory.
+    fn clone_repo(&self, url: &str, dest: &Path) -> Result<Output, GitError>;
+
+    /// Fast-forward the current branch from its upstream, refusing to merge.
+    fn pull_ff_only(&self, path: &Path) -> Result<Output, GitError>;
 }
 
 /// Real implementation of GitOps using std::process::Command.
@@ -120,6 +239,11 @@ impl GitOps for RealGit {
         self.check_output(output)
     }
 
+    fn set_remote_url(&self, path: &Path, name: &str, url: &str) -> Result<Output, GitError> {
+        let output = self.run_git(path, &["remote", "set-url", name, url])?;
+        self.check_output(output)
+    }
+
     fn remote_get_url(&self, path: &Path, name: &str) -> Result<Output, GitError> {
         let output = self.run_git(path, &["remote", "get-url", name])?;
         self.check_output(output)
@@ -130,6 +254,11 @@ impl GitOps for RealGit {
         self.check_output(output)
     }
 
+    fn rev_list_count(&self, path: &Path, range: &str) -> Result<Output, GitError> {
+        let output = self.run_git(path, &["rev-list", "--count", range])?;
+        self.check_output(output)
+    }
+
     fn add_files(&self, path: &Path, files: &[String]) -> Result<Output, GitError> {
         let mut args = vec!["add"];
         let file_refs: Vec<&str> = files.iter().map(|s| s.as_str()).collect();
@@ -152,4 +281,20 @@ impl GitOps for RealGit {
         let output = self.run_git(path, &["push", remote, branch])?;
         self.check_output(output)
     }
+
+    fn clone_repo(&self, url: &str, dest: &Path) -> Result<Output, GitError> {
+        // dest may not exist yet, so git runs from the current directory;
+        // an absolute dest can't be resolved against the wrong one
+        let dest = std::path::absolute(dest).map_err(|e| GitError::CommandFailed(e.to_string()))?;
+        let output = self.run_git(
+            Path::new("."),
+            &["clone", "--", url, &dest.to_string_lossy()],
+        )?;
+        self.check_output(output)
+    }
+
+    fn pull_ff_only(&self, path: &Path) -> Result<Output, GitError> {
+        let output = self.run_git(path, &["pull", "--ff-only"])?;
+        self.check_output(output)
+    }
 }
@@ -164,3 +164,25 @@ fn test_mock_network_error() {
         panic!("Expected NonZeroExit error");
     }
 }
+
+#[test]
+fn test_clone_repo_resolves_nested_relative_dest() {
+    let git = RealGit::new();
+    let source = tempfile::tempdir().unwrap();
+    git.init(source.path()).expect("git init should succeed");
+
+    // A relative dest whose parent already exists, resolved from the
+    // current directory rather than from that parent
+    let work = tempfile::Builder::new()
+        .prefix(".clone-test")
+        .tempdir_in(".")
+        .unwrap();
+    let relative = Path::new(work.path().file_name().unwrap()).join("nested/clone");
+    std::fs::create_dir_all(relative.parent().unwrap()).unwrap();
+
+    git.clone_repo(&source.path().to_string_lossy(), &relative)
+        .expect("git clone should succeed");
+
+    assert!(work.path().join("nested/clone/.git").is_dir());
+    assert!(!work.path().join("nested").join(&relative).exists());
+}
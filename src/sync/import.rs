@@ -5,10 +5,14 @@ use crate::db::{
     SkillAttachment, SkillRepository, Task, TaskList, TaskListRepository, TaskRepository,
 };
 use miette::Diagnostic;
+use serde::{Deserialize, Serialize};
+use std::fmt;
 use std::path::Path;
+use std::str::FromStr;
 use thiserror::Error;
 
-use super::jsonl::{JsonlError, read_jsonl};
+use super::SyncEntity;
+use super::jsonl::{JsonlError, read_live_jsonl, sync_file};
 
 /// Errors that can occur during import.
 #[derive(Error, Diagnostic, Debug)]
@@ -60,16 +64,21 @@ pub async fn import_all<D: Database>(
     // 6. Skills (can reference projects)
 
     // Import projects FIRST (no dependencies)
-    let projects_file = input_dir.join("projects.jsonl");
+    let projects_file = sync_file(input_dir, "projects.jsonl");
     if projects_file.exists() {
         tracing::debug!("Importing projects");
-        let projects: Vec<Project> = read_jsonl(&projects_file)?;
+        let projects: Vec<Project> = read_live_jsonl(&projects_file)?;
         for project in projects {
             match db.projects().get(&project.id).await {
                 Ok(_existing) => {
                     db.projects().update(&project).await?;
                 }
                 Err(_) => {
+                    // Repos are imported next and carry the project links
+                    let project = Project {
+                        repo_ids: vec![],
+                        ..project
+                    };
                     db.projects().create(&project).await?;
                 }
             }
@@ -79,10 +88,10 @@ pub async fn import_all<D: Database>(
     }
 
     // Import repos SECOND (can reference projects)
-    let repos_file = input_dir.join("repos.jsonl");
+    let repos_file = sync_file(input_dir, "repos.jsonl");
     if repos_file.exists() {
         tracing::debug!("Importing repos");
-        let repos: Vec<Repo> = read_jsonl(&repos_file)?;
+        let repos: Vec<Repo> = read_live_jsonl(&repos_file)?;
         for repo in repos {
             match db.repos().get(&repo.id).await {
                 Ok(_existing) => {
@@ -98,10 +107,10 @@ pub async fn import_all<D: Database>(
     }
 
     // Import task lists
-    let lists_file = input_dir.join("lists.jsonl");
+    let lists_file = sync_file(input_dir, "lists.jsonl");
     if lists_file.exists() {
         tracing::debug!("Importing task lists");
-        let task_lists: Vec<TaskList> = read_jsonl(&lists_file)?;
+        let task_lists: Vec<TaskList> = read_live_jsonl(&lists_file)?;
         for task_list in task_lists {
             match db.task_lists().get(&task_list.id).await {
                 Ok(_existing) => {
@@ -117,11 +126,15 @@ pub async fn import_all<D: Database>(
     }
 
     // Import tasks
-    let tasks_file = input_dir.join("tasks.jsonl");
+    let tasks_file = sync_file(input_dir, "tasks.jsonl");
     if tasks_file.exists() {
         tracing::debug!("Importing tasks");
-        let tasks: Vec<Task> = read_jsonl(&tasks_file)?;
-        for task in tasks {
+        let tasks: Vec<Task> = read_live_jsonl(&tasks_file)?;
+        for mut task in tasks {
+            if let Some(warning) = normalize_task_priority(&mut task) {
+                tracing::warn!("{}", warning);
+                summary.warnings.push(warning);
+            }
             match db.tasks().get(&task.id).await {
                 Ok(_existing) => {
                     db.tasks().update(&task).await?;
@@ -136,10 +149,10 @@ pub async fn import_all<D: Database>(
     }
 
     // Import notes
-    let notes_file = input_dir.join("notes.jsonl");
+    let notes_file = sync_file(input_dir, "notes.jsonl");
     if notes_file.exists() {
         tracing::debug!("Importing notes");
-        let notes: Vec<Note> = read_jsonl(&notes_file)?;
+        let notes: Vec<Note> = read_live_jsonl(&notes_file)?;
         for note in notes {
             match db.notes().get(&note.id).await {
                 Ok(_existing) => {
@@ -155,10 +168,10 @@ pub async fn import_all<D: Database>(
     }
 
     // Import skills
-    let skills_file = input_dir.join("skills.jsonl");
+    let skills_file = sync_file(input_dir, "skills.jsonl");
     if skills_file.exists() {
         tracing::debug!("Importing skills");
-        let skills: Vec<Skill> = read_jsonl(&skills_file)?;
+        let skills: Vec<Skill> = read_live_jsonl(&skills_file)?;
         for skill in skills {
             // Upsert skill (will have filename arrays from export)
             match db.skills().get(&skill.id).await {
@@ -175,10 +188,10 @@ pub async fn import_all<D: Database>(
     }
 
     // Import skill attachments
-    let attachments_file = input_dir.join("skills_attachments.jsonl");
+    let attachments_file = sync_file(input_dir, "skills_attachments.jsonl");
     if attachments_file.exists() {
         tracing::debug!("Importing skill attachments");
-        let attachments: Vec<SkillAttachment> = read_jsonl(&attachments_file)?;
+        let attachments: Vec<SkillAttachment> = read_live_jsonl(&attachments_file)?;
 
         // Group attachments by skill_id for efficient processing
         let mut attachments_by_skill: std::collections::HashMap<String, Vec<SkillAttachment>> =
@@ -207,8 +220,11 @@ pub async fn import_all<D: Database>(
                 });
 
                 match existing {
-                    Some(existing_att) if existing_att.content_hash != attachment.content_hash => {
-                        // Content changed - update attachment
+                    Some(existing_att)
+                        if existing_att.content_hash != attachment.content_hash
+                            || existing_att.is_executable != attachment.is_executable =>
+                    {
+                        // Content or mode changed - update attachment
                         tracing::debug!(
                             skill_id = %attachment.skill_id,
                             filename = %attachment.filename,
@@ -272,8 +288,28 @@ pub async fn import_all<D: Database>(
     Ok(summary)
 }
 
+/// Clamp an imported task's priority into the 1-5 range the schema allows.
+///
+/// Exports from older schemas can carry out-of-range values that would fail
+/// the CHECK constraint and abort the whole import. Returns a warning naming
+/// the task when its priority was changed.
+pub(crate) fn normalize_task_priority(task: &mut Task) -> Option<String> {
+    let priority = task.priority?;
+    let clamped = priority.clamp(1, 5);
+    if clamped == priority {
+        return None;
+    }
+
+    task.priority = Some(clamped);
+    Some(format!(
+        "Task {} had out-of-range priority {}; clamped to {}",
+        task.id, priority, clamped
+    ))
+}
+
 /// Summary of imported entities.
-#[derive(Debug, Default, PartialEq, Eq)]
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct ImportSummary {
     pub repos: usize,
     pub projects: usize,
@@ -283,6 +319,130 @@ pub struct ImportSummary {
     pub notes: usize,
     pub skills: usize,
     pub attachments: usize,
+    /// Records that were adjusted to import cleanly (e.g. clamped priorities)
+    pub warnings: Vec<String>,
+    /// Records edited on both sides since the last sync and left unimported
+    pub conflicts: Vec<Conflict>,
+}
+
+/// A record edited both in the database and in the sync files since the last sync.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Conflict {
+    pub entity_type: String,
+    pub id: String,
+    /// `updated_at` of the stored row
+    pub local: String,
+    /// `updated_at` of the incoming record
+    pub incoming: String,
+}
+
+/// What an import does with a record that changed on both sides.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ConflictStrategy {
+    /// Keep the stored row and report the conflict.
+    #[default]
+    Skip,
+    /// Keep whichever side has the newer `updated_at`.
+    LastWriteWins,
+    /// Always overwrite with the incoming record.
+    Force,
+}
+
+impl ConflictStrategy {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ConflictStrategy::Skip => "skip",
+            ConflictStrategy::LastWriteWins => "last-write-wins",
+            ConflictStrategy::Force => "force",
+        }
+    }
+}
+
+impl fmt::Display for ConflictStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ConflictStrategy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "skip" => Ok(ConflictStrategy::Skip),
+            "last-write-wins" | "lww" => Ok(ConflictStrategy::LastWriteWins),
+            "force" => Ok(ConflictStrategy::Force),
+            _ => Err(format!(
+                "Invalid conflict strategy: '{}'. Valid strategies: skip, last-write-wins, force",
+                s
+            )),
+        }
+    }
+}
+
+/// Conflict detection settings for an import.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ImportOptions {
+    /// Time of the last sync both sides started from; `None` disables detection
+    pub base: Option<String>,
+    pub strategy: ConflictStrategy,
+}
+
+impl ImportOptions {
+    /// Whether detection needs the stored row's `updated_at` at all.
+    pub fn detects_conflicts(&self) -> bool {
+        self.base.is_some() && self.strategy != ConflictStrategy::Force
+    }
+
+    /// Decide whether an incoming record may overwrite the stored one.
+    ///
+    /// The record changed on both sides when its stored and incoming
+    /// `updated_at` both lie after the base and differ; the strategy then
+    /// picks the outcome. A record changed only locally is kept unless the
+    /// strategy is `Force`. Records without a stored row or timestamps are
+    /// always written.
+    pub fn check(
+        &self,
+        entity: SyncEntity,
+        id: &str,
+        local: Option<&str>,
+        incoming: Option<&str>,
+    ) -> Resolution {
+        let (Some(base), Some(local), Some(incoming)) = (self.base.as_deref(), local, incoming)
+        else {
+            return Resolution::Write;
+        };
+        if local <= base || local == incoming {
+            return Resolution::Write;
+        }
+        // Only the local side changed: the incoming record is older
+        if incoming <= base && self.strategy != ConflictStrategy::Force {
+            return Resolution::KeepLocal;
+        }
+
+        match self.strategy {
+            ConflictStrategy::Force => Resolution::Write,
+            ConflictStrategy::LastWriteWins if incoming > local => Resolution::Write,
+            ConflictStrategy::LastWriteWins => Resolution::KeepLocal,
+            ConflictStrategy::Skip => Resolution::Conflict(Conflict {
+                entity_type: entity.to_string(),
+                id: id.to_string(),
+                local: local.to_string(),
+                incoming: incoming.to_string(),
+            }),
+        }
+    }
+}
+
+/// Outcome of [`ImportOptions::check`] for one record.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Resolution {
+    /// Write the incoming record.
+    Write,
+    /// Keep the stored row; it is the newer side.
+    KeepLocal,
+    /// Keep the stored row and report the conflict.
+    Conflict(Conflict),
 }
 
 impl ImportSummary {
@@ -63,6 +63,7 @@ async fn test_export_then_import() {
         tags: vec!["test".to_string()],
         project_ids: vec![],
         created_at: Some("2024-01-01T00:00:00Z".to_string()),
+        updated_at: None,
     };
     db1.repos().create(&repo).await.unwrap();
 
@@ -117,6 +118,7 @@ async fn test_import_updates_existing() {
         tags: vec!["v1".to_string()],
         project_ids: vec![],
         created_at: Some("2024-01-01T00:00:00Z".to_string()),
+        updated_at: None,
     };
     db.repos().create(&repo_v1).await.unwrap();
 
@@ -128,6 +130,7 @@ async fn test_import_updates_existing() {
         tags: vec!["v2".to_string()],                        // Changed
         project_ids: vec![],
         created_at: Some("2024-01-01T00:00:00Z".to_string()),
+        updated_at: None,
     };
 
     write_jsonl(&temp_dir.path().join("repos.jsonl"), &[repo_v2]).unwrap();
@@ -180,6 +183,7 @@ async fn test_import_preserves_relationships() {
         tags: vec![],
         project_ids: vec!["proj0001".to_string()],
         created_at: Some("2024-01-01T00:00:00Z".to_string()),
+        updated_at: None,
     };
     db1.repos().create(&repo).await.unwrap();
 
@@ -1062,6 +1066,7 @@ This skill has attachments.
             content: script_b64.clone(),
             content_hash: script_hash.clone(),
             mime_type: Some("text/x-shellscript".to_string()),
+            is_executable: false,
             created_at: Some("2024-01-01T10:00:00Z".to_string()),
             updated_at: Some("2024-01-01T10:00:00Z".to_string()),
         },
@@ -1073,6 +1078,7 @@ This skill has attachments.
             content: reference_b64.clone(),
             content_hash: reference_hash.clone(),
             mime_type: Some("text/markdown".to_string()),
+            is_executable: false,
             created_at: Some("2024-01-01T10:00:00Z".to_string()),
             updated_at: Some("2024-01-01T10:00:00Z".to_string()),
         },
@@ -1134,6 +1140,7 @@ This skill has attachments.
             content: modified_b64.clone(),
             content_hash: modified_hash.clone(),
             mime_type: Some("text/x-shellscript".to_string()),
+            is_executable: false,
             created_at: Some("2024-01-01T10:00:00Z".to_string()),
             updated_at: Some("2024-01-02T10:00:00Z".to_string()),
         },
@@ -1145,6 +1152,7 @@ This skill has attachments.
             content: reference_b64.clone(),
             content_hash: reference_hash.clone(),
             mime_type: Some("text/markdown".to_string()),
+            is_executable: false,
             created_at: Some("2024-01-01T10:00:00Z".to_string()),
             updated_at: Some("2024-01-01T10:00:00Z".to_string()),
         },
@@ -1189,6 +1197,7 @@ This skill has attachments.
         content: reference_b64.clone(),
         content_hash: reference_hash.clone(),
         mime_type: Some("text/markdown".to_string()),
+        is_executable: false,
         created_at: Some("2024-01-01T10:00:00Z".to_string()),
         updated_at: Some("2024-01-01T10:00:00Z".to_string()),
     }];
@@ -1211,3 +1220,104 @@ This skill has attachments.
     assert_eq!(final_attachments[0].filename, "README.md");
     assert!(final_attachments.iter().all(|a| a.filename != "run.sh"));
 }
+
+#[test]
+fn conflict_check_follows_strategy() {
+    use crate::sync::{ConflictStrategy, ImportOptions, Resolution, SyncEntity};
+
+    let base = Some("2025-01-01 00:00:00".to_string());
+    let check = |strategy, local: &str, incoming: &str| {
+        ImportOptions {
+            base: base.clone(),
+            strategy,
+        }
+        .check(SyncEntity::Notes, "note0001", Some(local), Some(incoming))
+    };
+
+    // Only the incoming side changed since the base: written
+    assert_eq!(
+        check(
+            ConflictStrategy::Skip,
+            "2024-12-01 00:00:00",
+            "2025-02-01 00:00:00"
+        ),
+        Resolution::Write
+    );
+    // Only the local side changed: kept, whatever the strategy short of force
+    for strategy in [ConflictStrategy::Skip, ConflictStrategy::LastWriteWins] {
+        assert_eq!(
+            check(strategy, "2025-02-01 00:00:00", "2024-12-01 00:00:00"),
+            Resolution::KeepLocal
+        );
+    }
+    assert_eq!(
+        check(
+            ConflictStrategy::Force,
+            "2025-02-01 00:00:00",
+            "2024-12-01 00:00:00"
+        ),
+        Resolution::Write
+    );
+    // Both sides changed
+    let Resolution::Conflict(conflict) = check(
+        ConflictStrategy::Skip,
+        "2025-03-01 00:00:00",
+        "2025-02-01 00:00:00",
+    ) else {
+        panic!("expected a conflict");
+    };
+    assert_eq!(conflict.entity_type, "notes");
+    assert_eq!(conflict.local, "2025-03-01 00:00:00");
+    assert_eq!(
+        check(
+            ConflictStrategy::LastWriteWins,
+            "2025-03-01 00:00:00",
+            "2025-02-01 00:00:00"
+        ),
+        Resolution::KeepLocal
+    );
+    assert_eq!(
+        check(
+            ConflictStrategy::LastWriteWins,
+            "2025-02-01 00:00:00",
+            "2025-03-01 00:00:00"
+        ),
+        Resolution::Write
+    );
+    assert_eq!(
+        check(
+            ConflictStrategy::Force,
+            "2025-03-01 00:00:00",
+            "2025-02-01 00:00:00"
+        ),
+        Resolution::Write
+    );
+
+    // No baseline: nothing to compare against
+    let options = ImportOptions::default();
+    assert!(!options.detects_conflicts());
+    assert_eq!(
+        options.check(
+            SyncEntity::Notes,
+            "note0001",
+            Some("2025-03-01 00:00:00"),
+            Some("2025-02-01 00:00:00")
+        ),
+        Resolution::Write
+    );
+}
+
+#[test]
+fn conflict_strategy_parses_cli_names() {
+    use crate::sync::ConflictStrategy;
+
+    assert_eq!(
+        "last-write-wins".parse::<ConflictStrategy>(),
+        Ok(ConflictStrategy::LastWriteWins)
+    );
+    assert_eq!(
+        "force".parse::<ConflictStrategy>(),
+        Ok(ConflictStrategy::Force)
+    );
+    assert!("newest".parse::<ConflictStrategy>().is_err());
+}
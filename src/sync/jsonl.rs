@@ -2,12 +2,17 @@
 //!
 //! Provides functions to serialize and deserialize entities to/from JSONL format.
 //! JSONL format: One JSON object per line, newline-delimited.
+//!
+//! Files whose name ends in `.gz` are gzip-compressed on write and
+//! decompressed on read.
 
+use flate2::{Compression, read::GzDecoder, write::GzEncoder};
 use miette::Diagnostic;
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
 use std::fs::File;
 use std::io::{BufRead, BufReader, BufWriter, Write};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use thiserror::Error;
 
 /// Errors that can occur during JSONL operations.
@@ -26,9 +31,83 @@ pub enum JsonlError {
     InvalidLine { line: usize, error: String },
 }
 
+/// Suffix of gzip-compressed sync files (`notes.jsonl.gz`).
+pub const GZIP_SUFFIX: &str = ".gz";
+
+fn is_gzip(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext == "gz")
+}
+
+/// The same file in the other format: `x.jsonl` for `x.jsonl.gz` and vice versa.
+fn counterpart(path: &Path) -> PathBuf {
+    if is_gzip(path) {
+        path.with_extension("")
+    } else {
+        let mut name = path.as_os_str().to_owned();
+        name.push(GZIP_SUFFIX);
+        PathBuf::from(name)
+    }
+}
+
+/// Path to read sync file `name` (e.g. `notes.jsonl`) from in `dir`.
+///
+/// Prefers the compressed copy when there is one, so a plain file left over
+/// from before compression was enabled is ignored.
+pub fn sync_file(dir: &Path, name: &str) -> PathBuf {
+    let plain = dir.join(name);
+    let compressed = counterpart(&plain);
+    if compressed.exists() {
+        compressed
+    } else {
+        plain
+    }
+}
+
+/// Path to export sync file `name` to in `dir`, gzip-compressed or plain.
+pub fn sync_file_for_export(dir: &Path, name: &str, compress: bool) -> PathBuf {
+    let plain = dir.join(name);
+    if compress { counterpart(&plain) } else { plain }
+}
+
+/// Open a JSONL file for reading, decompressing `.gz` files.
+fn open_lines(path: &Path) -> Result<Box<dyn BufRead>, JsonlError> {
+    let file = File::open(path)?;
+    Ok(if is_gzip(path) {
+        Box::new(BufReader::new(GzDecoder::new(file)))
+    } else {
+        Box::new(BufReader::new(file))
+    })
+}
+
+/// Create `path` and fill it with `write`, gzip-compressing `.gz` files.
+///
+/// Any copy of the file in the other format is removed afterwards, so
+/// readers never pick up a stale one.
+fn write_file(
+    path: &Path,
+    write: impl FnOnce(&mut dyn Write) -> Result<(), JsonlError>,
+) -> Result<(), JsonlError> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    if is_gzip(path) {
+        let mut encoder = GzEncoder::new(writer, Compression::default());
+        write(&mut encoder)?;
+        encoder.finish()?.flush()?;
+    } else {
+        write(&mut writer)?;
+        writer.flush()?;
+    }
+
+    let other = counterpart(path);
+    if other.exists() {
+        std::fs::remove_file(other)?;
+    }
+    Ok(())
+}
+
 /// Write entities to a JSONL file.
 ///
-/// Each entity is serialized to JSON and written as a single line.
+/// Each entity is serialized to JSON and written as a single line. Paths
+/// ending in `.gz` are written gzip-compressed.
 ///
 /// # Arguments
 /// * `path` - Path to the output file
@@ -37,21 +116,19 @@ pub enum JsonlError {
 /// # Errors
 /// Returns error if file cannot be created/written or serialization fails.
 pub fn write_jsonl<T: Serialize>(path: &Path, entities: &[T]) -> Result<(), JsonlError> {
-    let file = File::create(path)?;
-    let mut writer = BufWriter::new(file);
-
-    for entity in entities {
-        let json = serde_json::to_string(entity)?;
-        writeln!(writer, "{}", json)?;
-    }
-
-    writer.flush()?;
-    Ok(())
+    write_file(path, |writer| {
+        for entity in entities {
+            let json = serde_json::to_string(entity)?;
+            writeln!(writer, "{}", json)?;
+        }
+        Ok(())
+    })
 }
 
 /// Read entities from a JSONL file.
 ///
-/// Each line is deserialized into an entity of type T.
+/// Each line is deserialized into an entity of type T. Paths ending in `.gz`
+/// are decompressed.
 ///
 /// # Arguments
 /// * `path` - Path to the input file
@@ -59,8 +136,7 @@ pub fn write_jsonl<T: Serialize>(path: &Path, entities: &[T]) -> Result<(), Json
 /// # Errors
 /// Returns error if file cannot be read or any line fails to deserialize.
 pub fn read_jsonl<T: for<'de> Deserialize<'de>>(path: &Path) -> Result<Vec<T>, JsonlError> {
-    let file = File::open(path)?;
-    let reader = BufReader::new(file);
+    let reader = open_lines(path)?;
     let mut entities = Vec::new();
 
     for (line_num, line_result) in reader.lines().enumerate() {
@@ -81,3 +157,118 @@ pub fn read_jsonl<T: for<'de> Deserialize<'de>>(path: &Path) -> Result<Vec<T>, J
 
     Ok(entities)
 }
+
+/// A synced entity together with its soft-delete state.
+///
+/// Live records serialize exactly like the bare entity; deleted ones carry a
+/// `deleted_at` tombstone so the deletion reaches other machines.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SyncRecord<T> {
+    #[serde(flatten)]
+    pub entity: T,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deleted_at: Option<String>,
+}
+
+impl<T> SyncRecord<T> {
+    /// Wrap a live (not deleted) entity.
+    pub fn live(entity: T) -> Self {
+        Self {
+            entity,
+            deleted_at: None,
+        }
+    }
+}
+
+/// Read a JSONL file, skipping tombstoned records.
+pub fn read_live_jsonl<T: for<'de> Deserialize<'de>>(path: &Path) -> Result<Vec<T>, JsonlError> {
+    Ok(read_jsonl::<SyncRecord<T>>(path)?
+        .into_iter()
+        .filter(|record| record.deleted_at.is_none())
+        .map(|record| record.entity)
+        .collect())
+}
+
+/// Read the `id` of every record in a JSONL file.
+///
+/// A missing file yields an empty set.
+pub fn read_jsonl_ids(path: &Path) -> Result<HashSet<String>, JsonlError> {
+    Ok(read_lines_by_id(path)?
+        .into_iter()
+        .map(|(id, _)| id)
+        .collect())
+}
+
+/// Rewrite a JSONL file in place, keeping the existing line of every record
+/// that is still present and unchanged.
+///
+/// `current_ids` lists every record that should remain, in the order new
+/// records are appended. Records in `changed` replace their existing line, or
+/// are appended when the file doesn't have them yet. Existing lines whose id
+/// is not in `current_ids` are dropped.
+pub fn merge_jsonl<T: Serialize>(
+    path: &Path,
+    current_ids: &[&str],
+    changed: &[T],
+    id_of: impl Fn(&T) -> &str,
+) -> Result<(), JsonlError> {
+    let existing = read_lines_by_id(path)?;
+    let current: HashSet<&str> = current_ids.iter().copied().collect();
+    let mut changed: HashMap<&str, String> = changed
+        .iter()
+        .map(|entity| Ok((id_of(entity), serde_json::to_string(entity)?)))
+        .collect::<Result<_, serde_json::Error>>()?;
+
+    let mut lines = Vec::with_capacity(current_ids.len());
+    let mut seen = HashSet::new();
+    for (id, line) in &existing {
+        if !current.contains(id.as_str()) {
+            continue;
+        }
+        seen.insert(id.as_str());
+        lines.push(changed.remove(id.as_str()).unwrap_or_else(|| line.clone()));
+    }
+    for id in current_ids {
+        if !seen.contains(id)
+            && let Some(line) = changed.remove(id)
+        {
+            lines.push(line);
+        }
+    }
+
+    write_file(path, |writer| {
+        for line in lines {
+            writeln!(writer, "{}", line)?;
+        }
+        Ok(())
+    })
+}
+
+/// Read the raw lines of a JSONL file paired with each record's `id`.
+fn read_lines_by_id(path: &Path) -> Result<Vec<(String, String)>, JsonlError> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+
+    #[derive(Deserialize)]
+    struct IdOnly {
+        id: String,
+    }
+
+    let reader = open_lines(path)?;
+    let mut lines = Vec::new();
+    for (line_num, line_result) in reader.lines().enumerate() {
+        let line = line_result?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let IdOnly { id } = serde_json::from_str(&line).map_err(|e| JsonlError::InvalidLine {
+            line: line_num + 1,
+            error: e.to_string(),
+        })?;
+        lines.push((id, line));
+    }
+
+    Ok(lines)
+}
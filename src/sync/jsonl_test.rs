@@ -131,3 +131,64 @@ fn test_file_not_found() {
     assert!(result.is_err());
     assert!(matches!(result.unwrap_err(), JsonlError::Io(_)));
 }
+
+#[test]
+fn test_gzip_roundtrip() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.jsonl.gz");
+
+    let entities = vec![TestEntity {
+        id: "1".to_string(),
+        name: "Alice".to_string(),
+        count: 42,
+    }];
+
+    write_jsonl(&file_path, &entities).unwrap();
+
+    // Stored compressed: gzip magic bytes, not JSON
+    let raw = std::fs::read(&file_path).unwrap();
+    assert_eq!(&raw[..2], &[0x1f, 0x8b]);
+
+    let read_entities: Vec<TestEntity> = read_jsonl(&file_path).unwrap();
+    assert_eq!(entities, read_entities);
+}
+
+#[test]
+fn test_write_removes_other_format() {
+    let temp_dir = TempDir::new().unwrap();
+    let plain = temp_dir.path().join("test.jsonl");
+    let compressed = temp_dir.path().join("test.jsonl.gz");
+
+    write_jsonl::<TestEntity>(&plain, &[]).unwrap();
+    write_jsonl::<TestEntity>(&compressed, &[]).unwrap();
+    assert!(compressed.exists());
+    assert!(!plain.exists());
+
+    write_jsonl::<TestEntity>(&plain, &[]).unwrap();
+    assert!(plain.exists());
+    assert!(!compressed.exists());
+}
+
+#[test]
+fn test_sync_file_prefers_compressed() {
+    let temp_dir = TempDir::new().unwrap();
+    let dir = temp_dir.path();
+
+    // Neither exists: the plain name
+    assert_eq!(sync_file(dir, "notes.jsonl"), dir.join("notes.jsonl"));
+
+    std::fs::write(dir.join("notes.jsonl"), "").unwrap();
+    assert_eq!(sync_file(dir, "notes.jsonl"), dir.join("notes.jsonl"));
+
+    std::fs::write(dir.join("notes.jsonl.gz"), "").unwrap();
+    assert_eq!(sync_file(dir, "notes.jsonl"), dir.join("notes.jsonl.gz"));
+
+    assert_eq!(
+        sync_file_for_export(dir, "notes.jsonl", true),
+        dir.join("notes.jsonl.gz")
+    );
+    assert_eq!(
+        sync_file_for_export(dir, "notes.jsonl", false),
+        dir.join("notes.jsonl")
+    );
+}
@@ -2,6 +2,7 @@
 //!
 //! Coordinates git operations, export, import, and status checking.
 
+use crate::db::utils::current_timestamp;
 use crate::db::{
     Database, NoteRepository, ProjectRepository, RepoRepository, SkillRepository, SyncRepository,
     TaskListRepository, TaskRepository,
@@ -12,11 +13,14 @@ use std::sync::Arc;
 use thiserror::Error;
 
 use super::{
+    SyncEntity,
+    diff::{SyncDiff, diff_all},
     export::{ExportError, ExportSummary},
-    git::{GitError, GitOps},
-    import::{ImportError, ImportSummary},
-    paths::get_sync_dir,
-    read_jsonl,
+    git::{GitError, GitOps, RetryPolicy},
+    import::{ConflictStrategy, ImportError, ImportOptions, ImportSummary},
+    paths::{get_sync_dir, get_sync_dir_for},
+    preview::{ImportPreview, preview_import},
+    read_live_jsonl, sync_file,
 };
 
 /// Result of sync initialization.
@@ -26,6 +30,8 @@ pub enum InitResult {
     Created,
     /// Sync was already initialized (idempotent operation)
     AlreadyInitialized,
+    /// Sync was already initialized and the `origin` remote was pointed at a new URL
+    RemoteUpdated,
 }
 
 /// Errors that can occur during sync operations.
@@ -54,6 +60,58 @@ pub enum SyncError {
     #[error("IO error: {0}")]
     #[diagnostic(code(c5t::sync::io))]
     Io(#[from] std::io::Error),
+
+    #[error("Invalid remote URL '{url}': {reason}")]
+    #[diagnostic(
+        code(c5t::sync::invalid_remote_url),
+        help(
+            "Use an https://, ssh://, git:// or file:// URL, an scp-style user@host:path, or an absolute path"
+        )
+    )]
+    InvalidRemoteUrl { url: String, reason: String },
+}
+
+/// URL schemes git can use for a sync remote.
+const REMOTE_SCHEMES: &[&str] = &["https", "http", "ssh", "git", "file"];
+
+/// Check that a remote URL is in a form git accepts before touching the repo.
+fn validate_remote_url(url: &str) -> Result<(), SyncError> {
+    let invalid = |reason: &str| SyncError::InvalidRemoteUrl {
+        url: url.to_string(),
+        reason: reason.to_string(),
+    };
+
+    if url.trim().is_empty() {
+        return Err(invalid("URL is empty"));
+    }
+    if url.chars().any(|c| c.is_whitespace() || c.is_control()) {
+        return Err(invalid("URL must not contain whitespace"));
+    }
+    if url.starts_with('-') {
+        return Err(invalid("URL must not start with '-'"));
+    }
+
+    if let Some((scheme, rest)) = url.split_once("://") {
+        if !REMOTE_SCHEMES.contains(&scheme.to_ascii_lowercase().as_str()) {
+            return Err(invalid(&format!("unsupported scheme '{}'", scheme)));
+        }
+        if rest.is_empty() {
+            return Err(invalid("missing host or path"));
+        }
+        return Ok(());
+    }
+
+    if url.starts_with('/') {
+        return Ok(());
+    }
+
+    // scp-style: [user@]host:path, with no '/' before the colon
+    match url.split_once(':') {
+        Some((host, path)) if !host.is_empty() && !host.contains('/') && !path.is_empty() => Ok(()),
+        _ => Err(invalid(
+            "expected a URL, user@host:path, or an absolute path",
+        )),
+    }
 }
 
 /// Sync manager handles all sync operations.
@@ -63,9 +121,23 @@ pub enum SyncError {
 /// SyncManager wraps GitOps in Arc to enable cloning without requiring G: Clone.
 /// This allows using non-Clone types like MockGitOps in tests.
 /// Clone is implemented manually (not derived) to avoid the G: Clone bound.
+///
+/// # Project Workspaces
+///
+/// By default the manager syncs all data through the shared sync directory.
+/// [`SyncManager::for_project`] derives a manager scoped to one project: it
+/// works in that project's own workspace (see [`get_sync_dir_for`]) and
+/// exports only the project's data.
 pub struct SyncManager<G: GitOps> {
     git: std::sync::Arc<G>,
     sync_dir: PathBuf,
+    retry: RetryPolicy,
+    /// Project whose data this manager exports (`None` = all data)
+    project: Option<String>,
+    /// How imports treat records changed on both sides since the last sync
+    conflict_strategy: ConflictStrategy,
+    /// Export gzip-compressed `.jsonl.gz` files
+    compress: bool,
 }
 
 // Manual Clone implementation - Arc<G> is Clone even if G is not
@@ -74,16 +146,26 @@ impl<G: GitOps> Clone for SyncManager<G> {
         Self {
             git: Arc::clone(&self.git),
             sync_dir: self.sync_dir.clone(),
+            retry: self.retry,
+            project: self.project.clone(),
+            conflict_strategy: self.conflict_strategy,
+            compress: self.compress,
         }
     }
 }
 
 impl<G: GitOps> SyncManager<G> {
     /// Create a new sync manager with the given git operations handler.
+    ///
+    /// Push/pull retries follow [`RetryPolicy::from_env`].
     pub fn new(git: G) -> Self {
         Self {
             git: std::sync::Arc::new(git),
             sync_dir: get_sync_dir(),
+            retry: RetryPolicy::from_env(),
+            project: None,
+            conflict_strategy: ConflictStrategy::default(),
+            compress: false,
         }
     }
 
@@ -92,9 +174,57 @@ impl<G: GitOps> SyncManager<G> {
         Self {
             git: std::sync::Arc::new(git),
             sync_dir,
+            retry: RetryPolicy::from_env(),
+            project: None,
+            conflict_strategy: ConflictStrategy::default(),
+            compress: false,
         }
     }
 
+    /// Derive a manager for a single project's sync workspace.
+    ///
+    /// Shares this manager's git handler and retry policy; the default
+    /// workspace is left untouched.
+    pub fn for_project(&self, project_id: &str) -> Self {
+        self.clone()
+            .with_project(project_id, get_sync_dir_for(project_id))
+    }
+
+    /// Scope this manager to one project using a custom workspace directory (for testing).
+    pub fn with_project(mut self, project_id: &str, sync_dir: PathBuf) -> Self {
+        self.project = Some(project_id.to_string());
+        self.sync_dir = sync_dir;
+        self
+    }
+
+    /// Project this manager is scoped to, if any.
+    pub fn project(&self) -> Option<&str> {
+        self.project.as_deref()
+    }
+
+    /// Directory this manager syncs through.
+    pub fn sync_dir(&self) -> &std::path::Path {
+        &self.sync_dir
+    }
+
+    /// Override the retry policy for push/pull.
+    pub fn with_retry_policy(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// Export `.jsonl.gz` files instead of plain `.jsonl` (imports read both).
+    pub fn with_compression(mut self, compress: bool) -> Self {
+        self.compress = compress;
+        self
+    }
+
+    /// Override how imports resolve records changed on both sides.
+    pub fn with_conflict_strategy(mut self, strategy: ConflictStrategy) -> Self {
+        self.conflict_strategy = strategy;
+        self
+    }
+
     /// Check if sync is initialized (git repository exists).
     pub fn is_initialized(&self) -> bool {
         self.sync_dir.join(".git").exists()
@@ -104,9 +234,15 @@ impl<G: GitOps> SyncManager<G> {
     ///
     /// Creates the sync directory, initializes git, and optionally adds a remote.
     /// Idempotent: safe to call multiple times, won't reinitialize existing repos.
+    /// The remote URL is validated before any filesystem or git changes are made.
     ///
-    /// Returns InitResult::Created if newly initialized, InitResult::AlreadyInitialized if already set up.
+    /// Returns InitResult::Created if newly initialized, InitResult::AlreadyInitialized if already
+    /// set up, and InitResult::RemoteUpdated if an existing `origin` was pointed at a new URL.
     pub async fn init(&self, remote_url: Option<String>) -> Result<InitResult, SyncError> {
+        if let Some(url) = &remote_url {
+            validate_remote_url(url)?;
+        }
+
         tracing::info!("Initializing sync repository at {:?}", self.sync_dir);
 
         let was_initialized = self.is_initialized();
@@ -125,7 +261,8 @@ impl<G: GitOps> SyncManager<G> {
             self.git.init(&self.sync_dir)?;
         }
 
-        // Add remote if provided and not already present
+        // Add remote if provided; re-point an existing origin that differs
+        let mut remote_updated = false;
         if let Some(url) = &remote_url {
             match self.git.remote_get_url(&self.sync_dir, "origin") {
                 Ok(existing_output) => {
@@ -135,11 +272,13 @@ impl<G: GitOps> SyncManager<G> {
                     if existing_url == *url {
                         tracing::info!("Remote 'origin' already set to: {}", url);
                     } else {
-                        tracing::warn!(
+                        tracing::info!(
                             existing = %existing_url,
                             new = %url,
-                            "Remote 'origin' already exists with different URL, skipping"
+                            "Updating remote 'origin' URL"
                         );
+                        self.git.set_remote_url(&self.sync_dir, "origin", url)?;
+                        remote_updated = true;
                     }
                 }
                 Err(_) => {
@@ -149,10 +288,12 @@ impl<G: GitOps> SyncManager<G> {
             }
         }
 
-        let result = if was_initialized {
-            InitResult::AlreadyInitialized
-        } else {
+        let result = if !was_initialized {
             InitResult::Created
+        } else if remote_updated {
+            InitResult::RemoteUpdated
+        } else {
+            InitResult::AlreadyInitialized
         };
 
         tracing::info!(result = ?result, "Sync initialization complete");
@@ -200,16 +341,96 @@ impl<G: GitOps> SyncManager<G> {
         message: Option<String>,
         remote: bool,
     ) -> Result<ExportSummary, SyncError> {
-        tracing::info!(remote = remote, "Starting export operation");
+        self.export_selected(db, &SyncEntity::ALL, message, remote, false)
+            .await
+    }
+
+    /// Export only what changed since the last full or incremental export.
+    ///
+    /// Records updated since the timestamp recorded by the previous export are
+    /// rewritten in place; unchanged records keep their existing JSONL lines and
+    /// deleted ones are dropped. Falls back to a full export when no previous
+    /// export is recorded. Commit and push behave as in [`SyncManager::export`].
+    pub async fn export_incremental<D: Database>(
+        &self,
+        db: &D,
+        message: Option<String>,
+        remote: bool,
+    ) -> Result<ExportSummary, SyncError> {
+        self.export_selected(db, &SyncEntity::ALL, message, remote, true)
+            .await
+    }
+
+    /// Export a single entity type to JSONL and optionally push to remote.
+    ///
+    /// Only that entity's files are rewritten and staged; the other JSONL files
+    /// keep their current contents, so [`SyncManager::status`] reports the
+    /// fresh count for this entity alongside the previous counts for the rest.
+    /// Commit and push behave as in [`SyncManager::export`].
+    pub async fn export_entity<D: Database>(
+        &self,
+        db: &D,
+        entity: SyncEntity,
+        message: Option<String>,
+        remote: bool,
+    ) -> Result<ExportSummary, SyncError> {
+        self.export_selected(db, &[entity], message, remote, false)
+            .await
+    }
+
+    async fn export_selected<D: Database>(
+        &self,
+        db: &D,
+        entities: &[SyncEntity],
+        message: Option<String>,
+        remote: bool,
+        incremental: bool,
+    ) -> Result<ExportSummary, SyncError> {
+        let partial = entities.len() < SyncEntity::ALL.len();
+        tracing::info!(
+            remote = remote,
+            incremental = incremental,
+            ?entities,
+            "Starting export operation"
+        );
 
         if !self.is_initialized() {
             tracing::error!("Sync not initialized");
             return Err(SyncError::NotInitialized);
         }
 
+        // Taken before reading so edits made during the export are picked up next time
+        let started_at = current_timestamp();
+
         // Export to JSONL using sync repository
-        tracing::info!("Exporting database to JSONL files");
-        let summary = db.sync().export_all(&self.sync_dir).await?;
+        let since = incremental.then(|| self.last_export()).flatten();
+        let summary = match (self.project.as_deref(), since) {
+            (Some(project_id), since) => {
+                tracing::info!(project_id, ?since, "Exporting project to JSONL files");
+                db.sync()
+                    .with_compression(self.compress)
+                    .export_project_entities(&self.sync_dir, entities, project_id, since.as_deref())
+                    .await?
+            }
+            (None, Some(since)) => {
+                tracing::info!(%since, "Exporting changes to JSONL files");
+                db.sync()
+                    .with_compression(self.compress)
+                    .export_entities_since(&self.sync_dir, entities, &since)
+                    .await?
+            }
+            (None, None) => {
+                tracing::info!("Exporting database to JSONL files");
+                db.sync()
+                    .with_compression(self.compress)
+                    .export_entities(&self.sync_dir, entities)
+                    .await?
+            }
+        };
+        // A partial export leaves other entities stale, so it can't serve as a baseline
+        if !partial {
+            std::fs::write(self.last_export_path(), &started_at)?;
+        }
         tracing::info!(
             repos = summary.repos,
             projects = summary.projects,
@@ -220,14 +441,29 @@ impl<G: GitOps> SyncManager<G> {
             "Export complete"
         );
 
-        // Add all JSONL files (git add .)
-        tracing::debug!("Adding all files to git");
-        self.git.add_files(&self.sync_dir, &[".".to_string()])?;
+        // Stage all JSONL files (git add .), or only the exported entities' files.
+        // The pathspec glob also stages removal of the file in the other format.
+        let files: Vec<String> = if partial {
+            entities
+                .iter()
+                .flat_map(|e| e.files())
+                .map(|f| format!("{f}*"))
+                .collect()
+        } else {
+            vec![".".to_string()]
+        };
+        tracing::debug!(?files, "Adding files to git");
+        self.git.add_files(&self.sync_dir, &files)?;
 
         // Commit with timestamp-based message if not provided
         let commit_msg = message.unwrap_or_else(|| {
-            let now = chrono::Utc::now();
-            format!("sync: export at {}", now.format("%Y-%m-%d %H:%M:%S UTC"))
+            let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S UTC");
+            if partial {
+                let names: Vec<&str> = entities.iter().map(|e| e.as_str()).collect();
+                format!("sync: export {} at {}", names.join(", "), now)
+            } else {
+                format!("sync: export at {}", now)
+            }
         });
 
         // Try to commit - if nothing to commit, that's okay (not an error)
@@ -238,7 +474,8 @@ impl<G: GitOps> SyncManager<G> {
                 // Push if requested and remote exists
                 if remote && self.has_remote()? {
                     tracing::info!("Pushing to remote");
-                    self.git.push(&self.sync_dir, "origin", "main")?;
+                    self.retry
+                        .run("push", || self.git.push(&self.sync_dir, "origin", "main"))?;
                     tracing::info!("Push complete");
                 }
             }
@@ -250,7 +487,8 @@ impl<G: GitOps> SyncManager<G> {
                 // Still push if requested and remote exists (idempotent)
                 if remote && self.has_remote()? {
                     tracing::info!("Pushing to remote (no new commits)");
-                    self.git.push(&self.sync_dir, "origin", "main")?;
+                    self.retry
+                        .run("push", || self.git.push(&self.sync_dir, "origin", "main"))?;
                     tracing::info!("Push complete");
                 }
             }
@@ -302,7 +540,29 @@ impl<G: GitOps> SyncManager<G> {
         db: &D,
         remote: bool,
     ) -> Result<ImportSummary, SyncError> {
-        tracing::info!(remote = remote, "Starting import operation");
+        self.import_selected(db, &SyncEntity::ALL, remote).await
+    }
+
+    /// Import a single entity type from JSONL, optionally pulling from remote first.
+    ///
+    /// Other entity files are not read. Entities referenced by the imported rows
+    /// (e.g. the task list of a task) must already exist in the database.
+    pub async fn import_entity<D: Database>(
+        &self,
+        db: &D,
+        entity: SyncEntity,
+        remote: bool,
+    ) -> Result<ImportSummary, SyncError> {
+        self.import_selected(db, &[entity], remote).await
+    }
+
+    async fn import_selected<D: Database>(
+        &self,
+        db: &D,
+        entities: &[SyncEntity],
+        remote: bool,
+    ) -> Result<ImportSummary, SyncError> {
+        tracing::info!(remote = remote, ?entities, "Starting import operation");
 
         if !self.is_initialized() {
             tracing::error!("Sync not initialized");
@@ -312,25 +572,82 @@ impl<G: GitOps> SyncManager<G> {
         // Pull latest changes if requested
         if remote && self.has_remote()? {
             tracing::info!("Pulling latest changes from remote");
-            self.git.pull(&self.sync_dir, "origin", "main")?;
+            self.retry
+                .run("pull", || self.git.pull(&self.sync_dir, "origin", "main"))?;
             tracing::info!("Pull complete");
         }
 
+        // Taken before importing so edits made during the import count as local changes
+        let started_at = current_timestamp();
+        let options = ImportOptions {
+            base: self.last_sync(),
+            strategy: self.conflict_strategy,
+        };
+
         // Import from JSONL using sync repository
-        tracing::info!("Importing JSONL files to database");
-        let summary = db.sync().import_all(&self.sync_dir).await?;
+        tracing::info!(strategy = %options.strategy, base = ?options.base, "Importing JSONL files to database");
+        let summary = db
+            .sync()
+            .import_entities(&self.sync_dir, entities, &options)
+            .await?;
         tracing::info!(
             repos = summary.repos,
             projects = summary.projects,
             task_lists = summary.task_lists,
             tasks = summary.tasks,
             notes = summary.notes,
+            conflicts = summary.conflicts.len(),
             "Import complete"
         );
 
+        // Skipped conflicts must stay detectable, and a partial import leaves
+        // other entities behind, so neither can move the baseline
+        if summary.conflicts.is_empty() && entities.len() == SyncEntity::ALL.len() {
+            std::fs::write(self.last_import_path(), &started_at)?;
+        }
+
         Ok(summary)
     }
 
+    /// Preview what importing the local JSONL files would change, without writing.
+    ///
+    /// Reports entities that would be created and, for updates, each changed
+    /// field with its current and incoming value. Never pulls from the remote.
+    pub async fn preview_import<D: Database>(
+        &self,
+        db: &D,
+        only: Option<SyncEntity>,
+    ) -> Result<ImportPreview, SyncError> {
+        if !self.is_initialized() {
+            return Err(SyncError::NotInitialized);
+        }
+
+        let entities = match only {
+            Some(entity) => vec![entity],
+            None => SyncEntity::ALL.to_vec(),
+        };
+        Ok(preview_import(db, &self.sync_dir, &entities).await?)
+    }
+
+    /// Compare the sync files with the database, optionally pulling from remote first.
+    ///
+    /// Lists, per entity type, the records only in the sync files (added), in
+    /// both with a different `updated_at` (modified) and only in the database
+    /// (deleted). Never writes to the database.
+    pub async fn diff<D: Database>(&self, db: &D, remote: bool) -> Result<SyncDiff, SyncError> {
+        if !self.is_initialized() {
+            return Err(SyncError::NotInitialized);
+        }
+
+        if remote && self.has_remote()? {
+            tracing::info!("Pulling latest changes from remote");
+            self.retry
+                .run("pull", || self.git.pull(&self.sync_dir, "origin", "main"))?;
+        }
+
+        Ok(diff_all(db, &self.sync_dir).await?)
+    }
+
     /// Get sync status.
     pub async fn status<D: Database>(&self, db: &D) -> Result<SyncStatus, SyncError> {
         if !self.is_initialized() {
@@ -355,6 +672,14 @@ impl<G: GitOps> SyncManager<G> {
         let git_output = self.git.status_porcelain(&self.sync_dir)?;
         let git_status_str = String::from_utf8_lossy(&git_output.stdout);
         let is_clean = git_status_str.trim().is_empty();
+        let (ahead, behind) = if remote_url.is_some() {
+            (
+                self.count_commits("origin/main..HEAD"),
+                self.count_commits("HEAD..origin/main"),
+            )
+        } else {
+            (0, 0)
+        };
 
         // Count entities in database
         let db_counts = EntityCounts {
@@ -376,12 +701,47 @@ impl<G: GitOps> SyncManager<G> {
             git_status: Some(GitStatus {
                 clean: is_clean,
                 status_output: git_status_str.to_string(),
+                ahead,
+                behind,
             }),
             db_counts: Some(db_counts),
             jsonl_counts,
         })
     }
 
+    /// Where the last full export time is kept.
+    ///
+    /// Lives inside `.git` so it's never staged by `git add .` and stays local
+    /// to this clone.
+    fn last_export_path(&self) -> PathBuf {
+        self.sync_dir.join(".git").join("c5t-last-export")
+    }
+
+    /// Timestamp of the last full (or incremental) export, if any.
+    fn last_export(&self) -> Option<String> {
+        std::fs::read_to_string(self.last_export_path())
+            .ok()
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+    }
+
+    /// Where the last full import time is kept, next to the last export time.
+    fn last_import_path(&self) -> PathBuf {
+        self.sync_dir.join(".git").join("c5t-last-import")
+    }
+
+    /// Time of the last full export or conflict-free import, whichever is later.
+    ///
+    /// Records edited after it in both the database and the sync files
+    /// are import conflicts.
+    fn last_sync(&self) -> Option<String> {
+        let last_import = std::fs::read_to_string(self.last_import_path())
+            .ok()
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty());
+        self.last_export().max(last_import)
+    }
+
     /// Check if a remote is configured.
     fn has_remote(&self) -> Result<bool, SyncError> {
         match self.git.remote_get_url(&self.sync_dir, "origin") {
@@ -390,21 +750,37 @@ impl<G: GitOps> SyncManager<G> {
         }
     }
 
+    /// Count commits in a revision range.
+    ///
+    /// Returns 0 when the range can't be resolved, e.g. before the first
+    /// push or pull has created `origin/main`.
+    fn count_commits(&self, range: &str) -> usize {
+        self.git
+            .rev_list_count(&self.sync_dir, range)
+            .ok()
+            .and_then(|output| String::from_utf8_lossy(&output.stdout).trim().parse().ok())
+            .unwrap_or(0)
+    }
+
     /// Count entities in JSONL files.
     async fn count_jsonl_entities(&self) -> Option<EntityCounts> {
         use crate::db::{Note, Project, Repo, Skill, SkillAttachment, Task, TaskList};
 
-        let repos: Vec<Repo> = read_jsonl(&self.sync_dir.join("repos.jsonl")).unwrap_or_default();
+        let repos: Vec<Repo> =
+            read_live_jsonl(&sync_file(&self.sync_dir, "repos.jsonl")).unwrap_or_default();
         let projects: Vec<Project> =
-            read_jsonl(&self.sync_dir.join("projects.jsonl")).unwrap_or_default();
+            read_live_jsonl(&sync_file(&self.sync_dir, "projects.jsonl")).unwrap_or_default();
         let task_lists: Vec<TaskList> =
-            read_jsonl(&self.sync_dir.join("lists.jsonl")).unwrap_or_default();
-        let tasks: Vec<Task> = read_jsonl(&self.sync_dir.join("tasks.jsonl")).unwrap_or_default();
-        let notes: Vec<Note> = read_jsonl(&self.sync_dir.join("notes.jsonl")).unwrap_or_default();
+            read_live_jsonl(&sync_file(&self.sync_dir, "lists.jsonl")).unwrap_or_default();
+        let tasks: Vec<Task> =
+            read_live_jsonl(&sync_file(&self.sync_dir, "tasks.jsonl")).unwrap_or_default();
+        let notes: Vec<Note> =
+            read_live_jsonl(&sync_file(&self.sync_dir, "notes.jsonl")).unwrap_or_default();
         let skills: Vec<Skill> =
-            read_jsonl(&self.sync_dir.join("skills.jsonl")).unwrap_or_default();
+            read_live_jsonl(&sync_file(&self.sync_dir, "skills.jsonl")).unwrap_or_default();
         let attachments: Vec<SkillAttachment> =
-            read_jsonl(&self.sync_dir.join("skills_attachments.jsonl")).unwrap_or_default();
+            read_live_jsonl(&sync_file(&self.sync_dir, "skills_attachments.jsonl"))
+                .unwrap_or_default();
 
         Some(EntityCounts {
             repos: repos.len(),
@@ -433,6 +809,10 @@ pub struct SyncStatus {
 pub struct GitStatus {
     pub clean: bool,
     pub status_output: String,
+    /// Local commits not yet on `origin/main`
+    pub ahead: usize,
+    /// Commits on `origin/main` (as of the last pull) not yet merged locally
+    pub behind: usize,
 }
 
 /// Entity counts.
@@ -1,9 +1,11 @@
 use crate::db::{Database, SqliteDatabase};
-use crate::sync::git::{GitError, MockGitOps};
+use crate::sync::git::{GitError, MockGitOps, RetryPolicy};
 use crate::sync::manager::*;
+use mockall::Sequence;
 use mockall::predicate::*;
 use std::os::unix::process::ExitStatusExt;
 use std::process::{ExitStatus, Output};
+use std::time::Duration;
 use tempfile::TempDir;
 
 fn mock_output(code: i32, stdout: &str, stderr: &str) -> Output {
@@ -91,6 +93,91 @@ async fn test_init_with_remote() {
         .unwrap();
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_second_init_is_a_noop() {
+    let temp_dir = TempDir::new().unwrap();
+    let sync_dir = temp_dir.path().to_path_buf();
+    std::fs::create_dir_all(sync_dir.join(".git")).unwrap();
+
+    let mut mock_git = MockGitOps::new();
+    mock_git.expect_init().never();
+    mock_git
+        .expect_remote_get_url()
+        .with(eq(sync_dir.clone()), eq("origin"))
+        .times(1)
+        .returning(|_, _| Ok(mock_output(0, "https://github.com/test/repo.git\n", "")));
+    mock_git.expect_add_remote().never();
+    mock_git.expect_set_remote_url().never();
+
+    let manager = SyncManager::with_sync_dir(mock_git, sync_dir);
+    let result = manager
+        .init(Some("https://github.com/test/repo.git".to_string()))
+        .await
+        .unwrap();
+
+    assert_eq!(result, InitResult::AlreadyInitialized);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_init_updates_changed_remote() {
+    let temp_dir = TempDir::new().unwrap();
+    let sync_dir = temp_dir.path().to_path_buf();
+    std::fs::create_dir_all(sync_dir.join(".git")).unwrap();
+
+    let mut mock_git = MockGitOps::new();
+    mock_git.expect_init().never();
+    mock_git
+        .expect_remote_get_url()
+        .returning(|_, _| Ok(mock_output(0, "https://github.com/test/old.git\n", "")));
+    mock_git
+        .expect_set_remote_url()
+        .with(
+            eq(sync_dir.clone()),
+            eq("origin"),
+            eq("git@github.com:test/new.git"),
+        )
+        .times(1)
+        .returning(|_, _, _| Ok(mock_output(0, "", "")));
+
+    let manager = SyncManager::with_sync_dir(mock_git, sync_dir);
+    let result = manager
+        .init(Some("git@github.com:test/new.git".to_string()))
+        .await
+        .unwrap();
+
+    assert_eq!(result, InitResult::RemoteUpdated);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_init_rejects_invalid_remote_url_before_git() {
+    let temp_dir = TempDir::new().unwrap();
+    let sync_dir = temp_dir.path().join("sync");
+
+    // No expectations: any git call would panic
+    let mock_git = MockGitOps::new();
+    let manager = SyncManager::with_sync_dir(mock_git, sync_dir.clone());
+
+    for url in [
+        "",
+        "not a url",
+        "ftp://example.com/repo.git",
+        "--upload-pack=evil",
+        "repo.git",
+    ] {
+        let result = manager.init(Some(url.to_string())).await;
+        assert!(
+            matches!(result, Err(SyncError::InvalidRemoteUrl { .. })),
+            "expected '{}' to be rejected",
+            url
+        );
+    }
+
+    assert!(
+        !sync_dir.exists(),
+        "invalid URL must not create the sync dir"
+    );
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn test_status_not_initialized() {
     let temp_dir = TempDir::new().unwrap();
@@ -151,6 +238,185 @@ async fn test_export_nothing_to_commit() {
     assert!(result.is_ok());
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_export_entity_only_touches_its_files() {
+    use crate::db::{Note, NoteRepository, ProjectRepository};
+    use crate::sync::SyncEntity;
+
+    let temp_dir = TempDir::new().unwrap();
+    let sync_dir = temp_dir.path().to_path_buf();
+    std::fs::create_dir_all(sync_dir.join(".git")).unwrap();
+    let db = setup_test_db().await;
+
+    // A project in the database plus a stale projects file from an earlier export
+    db.projects()
+        .create(&crate::db::Project {
+            id: String::new(),
+            title: "Unexported".to_string(),
+            description: None,
+            tags: vec![],
+            external_refs: vec![],
+            repo_ids: vec![],
+            task_list_ids: vec![],
+            note_ids: vec![],
+            created_at: None,
+            updated_at: None,
+        })
+        .await
+        .unwrap();
+    std::fs::write(sync_dir.join("projects.jsonl"), "").unwrap();
+
+    for title in ["First", "Second"] {
+        db.notes()
+            .create(&Note {
+                id: String::new(),
+                title: title.to_string(),
+                content: "content".to_string(),
+                tags: vec![],
+                parent_id: None,
+                idx: None,
+                repo_ids: vec![],
+                project_ids: vec![],
+                subnote_count: None,
+                created_at: None,
+                updated_at: None,
+            })
+            .await
+            .unwrap();
+    }
+
+    let mut mock_git = MockGitOps::new();
+    mock_git
+        .expect_add_files()
+        .withf(|_, files| files == ["notes.jsonl*".to_string()])
+        .times(1)
+        .returning(|_, _| Ok(mock_output(0, "", "")));
+    mock_git
+        .expect_commit()
+        .withf(|_, message| message.starts_with("sync: export notes at "))
+        .times(1)
+        .returning(|_, _| Ok(mock_output(0, "", "")));
+    mock_git
+        .expect_remote_get_url()
+        .returning(|_, _| Err(GitError::GitNotFound));
+    mock_git
+        .expect_status_porcelain()
+        .returning(|_| Ok(mock_output(0, "", "")));
+
+    let manager = SyncManager::with_sync_dir(mock_git, sync_dir.clone());
+    let summary = manager
+        .export_entity(&db, SyncEntity::Notes, None, false)
+        .await
+        .unwrap();
+
+    assert_eq!(summary.notes, 2);
+    assert_eq!(summary.projects, 0);
+
+    // Other entity files are untouched
+    assert_eq!(
+        std::fs::read_to_string(sync_dir.join("projects.jsonl")).unwrap(),
+        ""
+    );
+    for file in ["repos.jsonl", "lists.jsonl", "tasks.jsonl", "skills.jsonl"] {
+        assert!(
+            !sync_dir.join(file).exists(),
+            "{} should not be written",
+            file
+        );
+    }
+
+    let status = manager.status(&db).await.unwrap();
+    let jsonl = status.jsonl_counts.unwrap();
+    assert_eq!(jsonl.notes, 2);
+    assert_eq!(jsonl.projects, 0);
+    assert_eq!(status.db_counts.unwrap().projects, 1);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_incremental_export_rewrites_only_changed_records() {
+    use crate::db::{Note, NoteRepository};
+
+    let temp_dir = TempDir::new().unwrap();
+    let sync_dir = temp_dir.path().to_path_buf();
+    std::fs::create_dir_all(sync_dir.join(".git")).unwrap();
+    let db = setup_test_db().await;
+
+    let mut ids = Vec::new();
+    for title in ["First", "Second", "Third"] {
+        let note = db
+            .notes()
+            .create(&Note {
+                id: String::new(),
+                title: title.to_string(),
+                content: "content".to_string(),
+                tags: vec![],
+                parent_id: None,
+                idx: None,
+                repo_ids: vec![],
+                project_ids: vec![],
+                subnote_count: None,
+                created_at: Some("2024-01-01 00:00:00".to_string()),
+                updated_at: Some("2024-01-01 00:00:00".to_string()),
+            })
+            .await
+            .unwrap();
+        ids.push(note.id);
+    }
+
+    let mut mock_git = MockGitOps::new();
+    mock_git
+        .expect_add_files()
+        .returning(|_, _| Ok(mock_output(0, "", "")));
+    mock_git
+        .expect_commit()
+        .returning(|_, _| Ok(mock_output(0, "", "")));
+
+    let manager = SyncManager::with_sync_dir(mock_git, sync_dir.clone());
+
+    // No previous export recorded: falls back to a full export
+    manager.export_incremental(&db, None, false).await.unwrap();
+    let before = std::fs::read_to_string(sync_dir.join("notes.jsonl")).unwrap();
+    let projects_before = std::fs::read(sync_dir.join("projects.jsonl")).unwrap();
+
+    let mut edited = db.notes().get(&ids[1]).await.unwrap();
+    edited.content = "edited".to_string();
+    edited.updated_at = None;
+    db.notes().update(&edited).await.unwrap();
+
+    let summary = manager.export_incremental(&db, None, false).await.unwrap();
+    assert_eq!(summary.notes, 3);
+
+    let after = std::fs::read_to_string(sync_dir.join("notes.jsonl")).unwrap();
+    let before: Vec<&str> = before.lines().collect();
+    let after: Vec<&str> = after.lines().collect();
+    assert_eq!(before.len(), after.len());
+
+    let edited_line = before
+        .iter()
+        .position(|line| line.contains(&ids[1]))
+        .unwrap();
+    for (i, (old, new)) in before.iter().zip(&after).enumerate() {
+        if i == edited_line {
+            assert_ne!(old, new);
+            assert!(new.contains("\"edited\""));
+        } else {
+            assert_eq!(old, new, "unchanged note line {} was rewritten", i);
+        }
+    }
+    assert_eq!(
+        std::fs::read(sync_dir.join("projects.jsonl")).unwrap(),
+        projects_before
+    );
+
+    // Deleted records are dropped from the file
+    db.notes().delete(&ids[0]).await.unwrap();
+    let summary = manager.export_incremental(&db, None, false).await.unwrap();
+    assert_eq!(summary.notes, 2);
+    let notes = std::fs::read_to_string(sync_dir.join("notes.jsonl")).unwrap();
+    assert_eq!(notes.lines().count(), 2);
+    assert!(!notes.contains(&ids[0]));
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn test_status_initialized_clean() {
     let temp_dir = TempDir::new().unwrap();
@@ -164,6 +430,9 @@ async fn test_status_initialized_clean() {
     mock_git
         .expect_status_porcelain()
         .returning(|_| Ok(mock_output(0, "", "")));
+    mock_git
+        .expect_rev_list_count()
+        .returning(|_, _| Ok(mock_output(0, "0\n", "")));
 
     let manager = SyncManager::with_sync_dir(mock_git, temp_dir.path().to_path_buf());
     let status = manager.status(&db).await.unwrap();
@@ -176,6 +445,36 @@ async fn test_status_initialized_clean() {
     assert!(status.git_status.as_ref().unwrap().clean);
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_status_reports_ahead_behind() {
+    let temp_dir = TempDir::new().unwrap();
+    std::fs::create_dir_all(temp_dir.path().join(".git")).unwrap();
+    let db = setup_test_db().await;
+
+    let mut mock_git = MockGitOps::new();
+    mock_git
+        .expect_remote_get_url()
+        .returning(|_, _| Ok(mock_output(0, "https://github.com/test/repo.git\n", "")));
+    mock_git
+        .expect_status_porcelain()
+        .returning(|_| Ok(mock_output(0, "", "")));
+    mock_git
+        .expect_rev_list_count()
+        .with(always(), eq("origin/main..HEAD"))
+        .returning(|_, _| Ok(mock_output(0, "2\n", "")));
+    mock_git
+        .expect_rev_list_count()
+        .with(always(), eq("HEAD..origin/main"))
+        .returning(|_, _| Ok(mock_output(0, "5\n", "")));
+
+    let manager = SyncManager::with_sync_dir(mock_git, temp_dir.path().to_path_buf());
+    let status = manager.status(&db).await.unwrap();
+
+    let git_status = status.git_status.unwrap();
+    assert_eq!(git_status.ahead, 2);
+    assert_eq!(git_status.behind, 5);
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn test_status_initialized_dirty() {
     let temp_dir = TempDir::new().unwrap();
@@ -260,6 +559,107 @@ async fn test_export_with_push_true_calls_push() {
     assert!(result.is_ok());
 }
 
+fn no_backoff() -> RetryPolicy {
+    RetryPolicy {
+        max_retries: 3,
+        initial_backoff: Duration::ZERO,
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_export_retries_transient_push_failure() {
+    let temp_dir = TempDir::new().unwrap();
+    std::fs::create_dir_all(temp_dir.path().join(".git")).unwrap();
+    let db = setup_test_db().await;
+
+    let mut mock_git = MockGitOps::new();
+    mock_git
+        .expect_remote_get_url()
+        .returning(|_, _| Ok(mock_output(0, "https://github.com/test/repo.git\n", "")));
+    mock_git
+        .expect_add_files()
+        .returning(|_, _| Ok(mock_output(0, "", "")));
+    mock_git
+        .expect_commit()
+        .returning(|_, _| Ok(mock_output(0, "commit successful", "")));
+
+    let mut seq = Sequence::new();
+    mock_git
+        .expect_push()
+        .times(2)
+        .in_sequence(&mut seq)
+        .returning(|_, _, _| {
+            Err(GitError::NonZeroExit {
+                code: 128,
+                output: "fatal: unable to access 'https://github.com/test/repo.git/': \
+                         Could not resolve host: github.com"
+                    .to_string(),
+            })
+        });
+    mock_git
+        .expect_push()
+        .times(1)
+        .in_sequence(&mut seq)
+        .returning(|_, _, _| Ok(mock_output(0, "pushed successfully", "")));
+
+    let manager = SyncManager::with_sync_dir(mock_git, temp_dir.path().to_path_buf())
+        .with_retry_policy(no_backoff());
+    let result = manager.export(&db, None, true).await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_export_does_not_retry_auth_failure() {
+    let temp_dir = TempDir::new().unwrap();
+    std::fs::create_dir_all(temp_dir.path().join(".git")).unwrap();
+    let db = setup_test_db().await;
+
+    let mut mock_git = MockGitOps::new();
+    mock_git
+        .expect_remote_get_url()
+        .returning(|_, _| Ok(mock_output(0, "https://github.com/test/repo.git\n", "")));
+    mock_git
+        .expect_add_files()
+        .returning(|_, _| Ok(mock_output(0, "", "")));
+    mock_git
+        .expect_commit()
+        .returning(|_, _| Ok(mock_output(0, "commit successful", "")));
+    mock_git.expect_push().times(1).returning(|_, _, _| {
+        Err(GitError::NonZeroExit {
+            code: 128,
+            output: "remote: Invalid username or password.\n\
+                     fatal: Authentication failed for 'https://github.com/test/repo.git/'"
+                .to_string(),
+        })
+    });
+
+    let manager = SyncManager::with_sync_dir(mock_git, temp_dir.path().to_path_buf())
+        .with_retry_policy(no_backoff());
+    let result = manager.export(&db, None, true).await;
+
+    assert!(matches!(
+        result,
+        Err(SyncError::Git(GitError::NonZeroExit { code: 128, .. }))
+    ));
+}
+
+#[test]
+fn test_git_error_transience() {
+    let transient = GitError::NonZeroExit {
+        code: 128,
+        output: "fatal: the remote end hung up unexpectedly".to_string(),
+    };
+    let rejected = GitError::NonZeroExit {
+        code: 1,
+        output: " ! [rejected]        main -> main (non-fast-forward)".to_string(),
+    };
+
+    assert!(transient.is_transient());
+    assert!(!rejected.is_transient());
+    assert!(!GitError::GitNotFound.is_transient());
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn test_import_with_pull_false_does_not_pull() {
     let temp_dir = TempDir::new().unwrap();
@@ -614,3 +1014,137 @@ async fn test_export_adds_skills_jsonl_to_git_files() {
 
     assert!(result.is_ok());
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_project_export_writes_only_to_project_workspace() {
+    use crate::db::{
+        Note, NoteRepository, Project, ProjectRepository, Task, TaskList, TaskListRepository,
+        TaskListStatus, TaskRepository, TaskStatus,
+    };
+
+    let temp_dir = TempDir::new().unwrap();
+    let default_dir = temp_dir.path().join("sync");
+    let project_dir = temp_dir.path().join("sync-projects").join("aaaa0001");
+    std::fs::create_dir_all(default_dir.join(".git")).unwrap();
+    std::fs::create_dir_all(project_dir.join(".git")).unwrap();
+    let db = setup_test_db().await;
+
+    for (id, list_id, task_id, note_id) in [
+        ("aaaa0001", "aaaa0002", "aaaa0003", "aaaa0004"),
+        ("bbbb0001", "bbbb0002", "bbbb0003", "bbbb0004"),
+    ] {
+        db.projects()
+            .create(&Project {
+                id: id.to_string(),
+                title: format!("Project {}", id),
+                description: None,
+                tags: vec![],
+                external_refs: vec![],
+                repo_ids: vec![],
+                task_list_ids: vec![],
+                note_ids: vec![],
+                created_at: None,
+                updated_at: None,
+            })
+            .await
+            .unwrap();
+        db.task_lists()
+            .create(&TaskList {
+                id: list_id.to_string(),
+                title: format!("List {}", id),
+                description: None,
+                notes: None,
+                project_id: id.to_string(),
+                tags: vec![],
+                status: TaskListStatus::Active,
+                external_refs: vec![],
+                repo_ids: vec![],
+                created_at: None,
+                updated_at: None,
+                archived_at: None,
+            })
+            .await
+            .unwrap();
+        db.tasks()
+            .create(&Task {
+                id: task_id.to_string(),
+                list_id: list_id.to_string(),
+                parent_id: None,
+                title: format!("Task {}", id),
+                description: None,
+                status: TaskStatus::Todo,
+                priority: None,
+                tags: vec![],
+                external_refs: vec![],
+                created_at: None,
+                updated_at: None,
+            })
+            .await
+            .unwrap();
+        db.notes()
+            .create(&Note {
+                id: note_id.to_string(),
+                title: format!("Note {}", id),
+                content: "content".to_string(),
+                tags: vec![],
+                parent_id: None,
+                idx: None,
+                repo_ids: vec![],
+                project_ids: vec![id.to_string()],
+                subnote_count: None,
+                created_at: None,
+                updated_at: None,
+            })
+            .await
+            .unwrap();
+    }
+
+    let mut mock_git = MockGitOps::new();
+    mock_git
+        .expect_add_files()
+        .withf({
+            let project_dir = project_dir.clone();
+            move |dir, _| dir == project_dir.as_path()
+        })
+        .times(1)
+        .returning(|_, _| Ok(mock_output(0, "", "")));
+    mock_git
+        .expect_commit()
+        .withf({
+            let project_dir = project_dir.clone();
+            move |dir, _| dir == project_dir.as_path()
+        })
+        .times(1)
+        .returning(|_, _| Ok(mock_output(0, "", "")));
+
+    let manager = SyncManager::with_sync_dir(mock_git, default_dir.clone());
+    let project_manager = manager
+        .clone()
+        .with_project("aaaa0001", project_dir.clone());
+    assert_eq!(project_manager.project(), Some("aaaa0001"));
+    assert_eq!(manager.project(), None);
+
+    let summary = project_manager.export(&db, None, false).await.unwrap();
+    assert_eq!(summary.projects, 1);
+    assert_eq!(summary.task_lists, 1);
+    assert_eq!(summary.tasks, 1);
+    assert_eq!(summary.notes, 1);
+
+    let projects: Vec<Project> =
+        crate::sync::read_jsonl(&project_dir.join("projects.jsonl")).unwrap();
+    assert_eq!(projects.len(), 1);
+    assert_eq!(projects[0].id, "aaaa0001");
+    let tasks: Vec<Task> = crate::sync::read_jsonl(&project_dir.join("tasks.jsonl")).unwrap();
+    assert_eq!(tasks.len(), 1);
+    assert_eq!(tasks[0].list_id, "aaaa0002");
+    let notes: Vec<Note> = crate::sync::read_jsonl(&project_dir.join("notes.jsonl")).unwrap();
+    assert_eq!(notes.len(), 1);
+    assert_eq!(notes[0].project_ids, vec!["aaaa0001".to_string()]);
+
+    // The default workspace is untouched
+    let default_entries: Vec<_> = std::fs::read_dir(&default_dir)
+        .unwrap()
+        .map(|e| e.unwrap().file_name())
+        .collect();
+    assert_eq!(default_entries, vec![std::ffi::OsString::from(".git")]);
+}
@@ -3,6 +3,10 @@
 //! This module provides functionality to export the c5t database to JSONL files
 //! and sync them via Git to enable multi-machine synchronization.
 
+mod diff;
+#[cfg(test)]
+mod diff_test;
+mod entity;
 mod export;
 #[cfg(test)]
 mod export_test;
@@ -21,12 +25,28 @@ mod manager_test;
 mod paths;
 #[cfg(test)]
 mod paths_test;
+mod preview;
+#[cfg(test)]
+mod preview_test;
 
+pub use diff::{EntityDiff, SyncDiff, diff_all};
+pub use entity::SyncEntity;
+pub(crate) use export::sort_skill_for_export;
 pub use export::{ExportError, ExportSummary, export_all};
 #[cfg(test)]
 pub use git::MockGitOps;
-pub use git::{GitError, GitOps, RealGit};
-pub use import::{ImportError, ImportSummary, import_all};
-pub use jsonl::{JsonlError, read_jsonl, write_jsonl};
+pub use git::{DEFAULT_GIT_RETRIES, GitError, GitOps, RealGit, RetryPolicy};
+pub(crate) use import::normalize_task_priority;
+pub use import::{
+    Conflict, ConflictStrategy, ImportError, ImportOptions, ImportSummary, Resolution, import_all,
+};
+pub use jsonl::{
+    GZIP_SUFFIX, JsonlError, SyncRecord, merge_jsonl, read_jsonl, read_jsonl_ids, read_live_jsonl,
+    sync_file, sync_file_for_export, write_jsonl,
+};
 pub use manager::{EntityCounts, GitStatus, InitResult, SyncError, SyncManager, SyncStatus};
-pub use paths::{clear_base_path, get_data_dir, get_db_path, get_sync_dir, set_base_path};
+pub use paths::{
+    clear_base_path, get_data_dir, get_db_path, get_repo_clone_dir, get_sync_dir, get_sync_dir_for,
+    set_base_path,
+};
+pub use preview::{EntityChange, FieldChange, ImportPreview, NewEntity, preview_import};
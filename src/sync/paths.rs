@@ -94,6 +94,27 @@ pub fn get_sync_dir() -> PathBuf {
     get_data_dir().join("sync")
 }
 
+/// Get the sync workspace for a single project (data_dir/sync-projects/{project_id}).
+///
+/// Kept outside the default sync directory so each project workspace is its
+/// own git repository and never shows up in the all-data one.
+///
+/// # Returns
+/// Path to the project's sync directory: `{data_dir}/sync-projects/{project_id}/`
+pub fn get_sync_dir_for(project_id: &str) -> PathBuf {
+    get_data_dir().join("sync-projects").join(project_id)
+}
+
+/// Get the default checkout location for a repo (data_dir/repos/{repo_id}).
+///
+/// Used by `c5t repo clone` when the repo has no path of its own.
+///
+/// # Returns
+/// Path to the checkout: `{data_dir}/repos/{repo_id}/`
+pub fn get_repo_clone_dir(repo_id: &str) -> PathBuf {
+    get_data_dir().join("repos").join(repo_id)
+}
+
 /// Get database file path (data_dir/context.db).
 ///
 /// # Returns
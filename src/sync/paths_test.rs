@@ -28,3 +28,17 @@ fn test_get_db_path_ends_with_context_db() {
     #[cfg(not(debug_assertions))]
     assert!(path.ends_with("c5t/context.db"));
 }
+
+#[test]
+fn test_get_sync_dir_for_is_outside_default_sync_dir() {
+    let path = get_sync_dir_for("abc12345");
+    assert!(path.ends_with("sync-projects/abc12345"));
+    assert!(!path.starts_with(get_sync_dir()));
+}
+
+#[test]
+fn test_get_repo_clone_dir_is_under_data_dir() {
+    let path = get_repo_clone_dir("abc12345");
+    assert!(path.ends_with("repos/abc12345"));
+    assert!(path.starts_with(get_data_dir()));
+}
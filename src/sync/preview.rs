@@ -0,0 +1,172 @@
+//! Field-level preview of what an import would change.
+
+use crate::db::{
+    Database, DbError, DbResult, Note, NoteRepository, Project, ProjectRepository, Repo,
+    RepoRepository, Skill, SkillRepository, Task, TaskList, TaskListRepository, TaskRepository,
+};
+use serde::Serialize;
+use serde_json::Value;
+use std::path::Path;
+
+use super::SyncEntity;
+use super::import::ImportError;
+use super::jsonl::{JsonlError, read_live_jsonl, sync_file};
+
+/// Fields left out of diffs: timestamps are bookkeeping, and the rest are
+/// computed on read rather than stored with the entity.
+pub(super) const IGNORED_FIELDS: &[&str] = &[
+    "created_at",
+    "updated_at",
+    "subnote_count",
+    "scripts",
+    "references",
+    "assets",
+];
+
+/// A single field that an import would change.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FieldChange {
+    pub field: String,
+    pub old: Value,
+    pub new: Value,
+}
+
+/// An existing entity that an import would update.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct EntityChange {
+    pub entity_type: String,
+    pub id: String,
+    pub changes: Vec<FieldChange>,
+}
+
+/// An entity that an import would create.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct NewEntity {
+    pub entity_type: String,
+    pub id: String,
+}
+
+/// What an import would do, without applying it.
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+pub struct ImportPreview {
+    pub created: Vec<NewEntity>,
+    pub updated: Vec<EntityChange>,
+    pub unchanged: usize,
+}
+
+impl ImportPreview {
+    fn record<T: Serialize>(
+        &mut self,
+        entity: SyncEntity,
+        id: &str,
+        current: DbResult<T>,
+        incoming: &T,
+    ) -> Result<(), ImportError> {
+        let current = match current {
+            Ok(current) => current,
+            Err(DbError::NotFound { .. }) => {
+                self.created.push(NewEntity {
+                    entity_type: entity.to_string(),
+                    id: id.to_string(),
+                });
+                return Ok(());
+            }
+            Err(e) => return Err(e.into()),
+        };
+
+        let old = serde_json::to_value(&current).map_err(JsonlError::from)?;
+        let new = serde_json::to_value(incoming).map_err(JsonlError::from)?;
+        let changes = diff_fields(&old, &new);
+
+        if changes.is_empty() {
+            self.unchanged += 1;
+        } else {
+            self.updated.push(EntityChange {
+                entity_type: entity.to_string(),
+                id: id.to_string(),
+                changes,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Compare two serialized entities field by field, in the incoming field order.
+fn diff_fields(old: &Value, new: &Value) -> Vec<FieldChange> {
+    let (Some(old), Some(new)) = (old.as_object(), new.as_object()) else {
+        return Vec::new();
+    };
+
+    new.iter()
+        .filter(|(field, _)| !IGNORED_FIELDS.contains(&field.as_str()))
+        .filter_map(|(field, new_value)| {
+            let old_value = old.get(field).unwrap_or(&Value::Null);
+            (old_value != new_value).then(|| FieldChange {
+                field: field.clone(),
+                old: old_value.clone(),
+                new: new_value.clone(),
+            })
+        })
+        .collect()
+}
+
+/// Compare the JSONL files in `input_dir` with the database without writing anything.
+///
+/// Each incoming record is matched to the current row by ID: unknown IDs are
+/// reported as created, known ones as updated with their changed fields.
+/// Task transitions and skill attachments are not diffed.
+pub async fn preview_import<D: Database>(
+    db: &D,
+    input_dir: &Path,
+    entities: &[SyncEntity],
+) -> Result<ImportPreview, ImportError> {
+    let mut preview = ImportPreview::default();
+
+    for &entity in entities {
+        let path = sync_file(input_dir, entity.files()[0]);
+        if !path.exists() {
+            continue;
+        }
+
+        match entity {
+            SyncEntity::Repos => {
+                for repo in read_live_jsonl::<Repo>(&path)? {
+                    let current = db.repos().get(&repo.id).await;
+                    preview.record(entity, &repo.id, current, &repo)?;
+                }
+            }
+            SyncEntity::Projects => {
+                for project in read_live_jsonl::<Project>(&path)? {
+                    let current = db.projects().get(&project.id).await;
+                    preview.record(entity, &project.id, current, &project)?;
+                }
+            }
+            SyncEntity::TaskLists => {
+                for list in read_live_jsonl::<TaskList>(&path)? {
+                    let current = db.task_lists().get(&list.id).await;
+                    preview.record(entity, &list.id, current, &list)?;
+                }
+            }
+            SyncEntity::Tasks => {
+                for task in read_live_jsonl::<Task>(&path)? {
+                    let current = db.tasks().get(&task.id).await;
+                    preview.record(entity, &task.id, current, &task)?;
+                }
+            }
+            SyncEntity::Notes => {
+                for note in read_live_jsonl::<Note>(&path)? {
+                    let current = db.notes().get(&note.id).await;
+                    preview.record(entity, &note.id, current, &note)?;
+                }
+            }
+            SyncEntity::Skills => {
+                for skill in read_live_jsonl::<Skill>(&path)? {
+                    let current = db.skills().get(&skill.id).await;
+                    preview.record(entity, &skill.id, current, &skill)?;
+                }
+            }
+        }
+    }
+
+    Ok(preview)
+}
@@ -0,0 +1,86 @@
+use crate::db::{Database, Note, NoteRepository, SqliteDatabase};
+use crate::sync::SyncEntity;
+use crate::sync::jsonl::write_jsonl;
+use crate::sync::preview::*;
+use serde_json::json;
+use tempfile::TempDir;
+
+async fn setup_test_db() -> SqliteDatabase {
+    let db = SqliteDatabase::in_memory().await.unwrap();
+    db.migrate().unwrap();
+    db
+}
+
+fn note(id: &str, title: &str, content: &str) -> Note {
+    Note {
+        id: id.to_string(),
+        title: title.to_string(),
+        content: content.to_string(),
+        tags: vec!["design".to_string()],
+        parent_id: None,
+        idx: None,
+        repo_ids: vec![],
+        project_ids: vec![],
+        subnote_count: None,
+        created_at: Some("2025-01-01 00:00:00".to_string()),
+        updated_at: Some("2025-01-01 00:00:00".to_string()),
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_preview_reports_single_content_change() {
+    let db = setup_test_db().await;
+    let temp_dir = TempDir::new().unwrap();
+
+    db.notes()
+        .create(&note("note0001", "Design", "Old body"))
+        .await
+        .unwrap();
+    db.notes()
+        .create(&note("note0002", "Unchanged", "Same body"))
+        .await
+        .unwrap();
+
+    let mut edited = note("note0001", "Design", "New body");
+    edited.updated_at = Some("2025-02-01 00:00:00".to_string());
+    write_jsonl(
+        &temp_dir.path().join("notes.jsonl"),
+        &[
+            edited,
+            note("note0002", "Unchanged", "Same body"),
+            note("note0003", "Brand new", "Fresh"),
+        ],
+    )
+    .unwrap();
+
+    let preview = preview_import(&db, temp_dir.path(), &SyncEntity::ALL)
+        .await
+        .unwrap();
+
+    assert_eq!(preview.updated.len(), 1);
+    let update = &preview.updated[0];
+    assert_eq!(update.entity_type, "notes");
+    assert_eq!(update.id, "note0001");
+    assert_eq!(
+        update.changes,
+        vec![FieldChange {
+            field: "content".to_string(),
+            old: json!("Old body"),
+            new: json!("New body"),
+        }]
+    );
+
+    assert_eq!(preview.unchanged, 1);
+    assert_eq!(
+        preview.created,
+        vec![NewEntity {
+            entity_type: "notes".to_string(),
+            id: "note0003".to_string(),
+        }]
+    );
+
+    // Nothing was written
+    let current = db.notes().get("note0001").await.unwrap();
+    assert_eq!(current.content, "Old body");
+    assert!(db.notes().get("note0003").await.is_err());
+}